@@ -0,0 +1,598 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Arbitration registry feeding an `msig_court`.
+///
+/// Claimant files a case against respondent for a disputed amount, depositing
+/// a bond. Respondent joins the case by depositing the disputed amount and a
+/// bond of their own, which opens the evidence period. Both parties anchor
+/// hashes of their off-chain evidence during that period. Afterwards, any judge
+/// of the court submits the ruling, which the registry turns into a
+/// `Proposal::EnforceRuling` of the court. Once the court approves and
+/// executes it, the registry pays out the award and returns the bonds.
+///
+/// Registry must be allowed to propose in the court with
+/// `Proposal::SetProposers`. Cases in which respondent doesn't respond or the
+/// court doesn't rule in time can be closed by anyone and refunded.
+#[ink::contract(env = liberland_extension::LiberlandEnvironment)]
+mod arbitration_registry {
+	use court_traits::{CaseId, CourtError, CourtRuling};
+	use ink::codegen::Env;
+	use ink::prelude::vec::Vec;
+	use ink::storage::Mapping;
+	use msig_court::{MsigCourtRef, Proposal};
+
+	/// Maximum number of evidence hashes in a single case
+	pub const MAX_EVIDENCE: usize = 32;
+
+	pub type PropKey = [u8; 32];
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub enum CaseStatus {
+		/// Waiting for respondent to deposit until given block
+		AwaitingResponse(BlockNumber),
+		/// Evidence can be submitted until `evidence_until`, then ruling is
+		/// expected until `ruling_until`
+		Open { evidence_until: BlockNumber, ruling_until: BlockNumber },
+		/// Ruling submitted to the court as proposal with given key
+		RulingProposed { key: PropKey, ruling_until: BlockNumber },
+		/// Ruling enforced, claimant was awarded given amount
+		Ruled(Balance),
+		/// Respondent didn't respond in time
+		Defaulted,
+		/// Court didn't rule in time
+		Expired,
+	}
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct Case {
+		pub claimant: AccountId,
+		pub respondent: AccountId,
+		/// Disputed amount, deposited by respondent
+		pub amount: Balance,
+		pub status: CaseStatus,
+		/// Hashes of off-chain evidence with the party that submitted them
+		pub evidence: Vec<(AccountId, Hash)>,
+	}
+
+	#[derive(Debug, PartialEq, Eq, Clone)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub enum Error {
+		/// Caller isn't allowed to do this
+		Unauthorized,
+		/// Case not found
+		NotFound,
+		/// Invalid parameters
+		InvalidParameters,
+		/// Transferred value doesn't match required deposit
+		InvalidDeposit,
+		/// Action not allowed in current case status
+		InvalidStatus,
+		/// Deadline of current period didn't pass yet
+		TooEarly,
+		/// Deadline of current period already passed
+		TooLate,
+		/// Case already has `MAX_EVIDENCE` evidence hashes
+		TooMuchEvidence,
+		/// Transfer of funds failed
+		TransferFailed,
+		/// Court rejected the ruling proposal
+		CourtRejected,
+	}
+
+	pub type Result<T> = core::result::Result<T, Error>;
+
+	#[ink(storage)]
+	pub struct ArbitrationRegistry {
+		court: AccountId,
+		bond: Balance,
+		response_period: BlockNumber,
+		evidence_period: BlockNumber,
+		ruling_period: BlockNumber,
+		next_id: CaseId,
+		cases: Mapping<CaseId, Case>,
+	}
+
+	#[ink(event)]
+	pub struct Filed {
+		#[ink(topic)]
+		case_id: CaseId,
+		#[ink(topic)]
+		claimant: AccountId,
+		#[ink(topic)]
+		respondent: AccountId,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Responded {
+		#[ink(topic)]
+		case_id: CaseId,
+	}
+
+	#[ink(event)]
+	pub struct EvidenceSubmitted {
+		#[ink(topic)]
+		case_id: CaseId,
+		#[ink(topic)]
+		party: AccountId,
+		evidence: Hash,
+	}
+
+	#[ink(event)]
+	pub struct RulingProposed {
+		#[ink(topic)]
+		case_id: CaseId,
+		judge: AccountId,
+		key: PropKey,
+		award: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Closed {
+		#[ink(topic)]
+		case_id: CaseId,
+		status: CaseStatus,
+	}
+
+	impl ArbitrationRegistry {
+		fn get(&self, case_id: CaseId) -> Result<Case> {
+			self.cases.get(case_id).ok_or(Error::NotFound)
+		}
+
+		fn now(&self) -> BlockNumber {
+			self.env().block_number()
+		}
+
+		fn pay(&self, to: AccountId, amount: Balance) -> Result<()> {
+			if amount == 0 {
+				return Ok(());
+			}
+			self.env().transfer(to, amount).map_err(|_| Error::TransferFailed)
+		}
+
+		fn court(&self) -> MsigCourtRef {
+			ink::env::call::FromAccountId::from_account_id(self.court)
+		}
+
+		/// Pay out funds held for the case and mark it with final `status`
+		fn close(
+			&mut self,
+			case_id: CaseId,
+			mut case: Case,
+			to_claimant: Balance,
+			to_respondent: Balance,
+			status: CaseStatus,
+		) -> Result<()> {
+			self.pay(case.claimant, to_claimant)?;
+			self.pay(case.respondent, to_respondent)?;
+			case.status = status.clone();
+			self.cases.insert(case_id, &case);
+			self.env().emit_event(Closed { case_id, status });
+			Ok(())
+		}
+	}
+
+	impl ArbitrationRegistry {
+		/// `court` is the `msig_court` contract ruling the cases. Periods are
+		/// in blocks.
+		#[ink(constructor)]
+		pub fn new(
+			court: AccountId,
+			bond: Balance,
+			response_period: BlockNumber,
+			evidence_period: BlockNumber,
+			ruling_period: BlockNumber,
+		) -> Self {
+			Self {
+				court,
+				bond,
+				response_period,
+				evidence_period,
+				ruling_period,
+				next_id: 0,
+				cases: Mapping::default(),
+			}
+		}
+
+		/// File a case against `respondent` for `amount`. Caller is the
+		/// claimant and must transfer exactly the bond.
+		///
+		/// * `evidence` - hash of claimant's off-chain case file
+		#[ink(message, payable)]
+		pub fn file(
+			&mut self,
+			respondent: AccountId,
+			amount: Balance,
+			evidence: Hash,
+		) -> Result<CaseId> {
+			let claimant = self.env().caller();
+			if amount == 0 || claimant == respondent {
+				return Err(Error::InvalidParameters);
+			}
+			if self.env().transferred_value() != self.bond {
+				return Err(Error::InvalidDeposit);
+			}
+
+			let case_id = self.next_id;
+			self.next_id = case_id.checked_add(1).ok_or(Error::InvalidParameters)?;
+			let until = self.now().saturating_add(self.response_period);
+			let case = Case {
+				claimant,
+				respondent,
+				amount,
+				status: CaseStatus::AwaitingResponse(until),
+				evidence: ink::prelude::vec![(claimant, evidence)],
+			};
+			self.cases.insert(case_id, &case);
+			self.env().emit_event(Filed { case_id, claimant, respondent, amount });
+			Ok(case_id)
+		}
+
+		/// Join the case as respondent. Caller must transfer exactly the
+		/// disputed amount plus the bond. Opens the evidence period.
+		#[ink(message, payable)]
+		pub fn respond(&mut self, case_id: CaseId, evidence: Hash) -> Result<()> {
+			let mut case = self.get(case_id)?;
+			if self.env().caller() != case.respondent {
+				return Err(Error::Unauthorized);
+			}
+			let until = match case.status {
+				CaseStatus::AwaitingResponse(until) => until,
+				_ => return Err(Error::InvalidStatus),
+			};
+			let now = self.now();
+			if now > until {
+				return Err(Error::TooLate);
+			}
+			let required = case.amount.checked_add(self.bond).ok_or(Error::InvalidParameters)?;
+			if self.env().transferred_value() != required {
+				return Err(Error::InvalidDeposit);
+			}
+
+			let evidence_until = now.saturating_add(self.evidence_period);
+			let ruling_until = evidence_until.saturating_add(self.ruling_period);
+			case.status = CaseStatus::Open { evidence_until, ruling_until };
+			case.evidence.push((case.respondent, evidence));
+			self.cases.insert(case_id, &case);
+			self.env().emit_event(Responded { case_id });
+			Ok(())
+		}
+
+		/// Anchor hash of additional off-chain evidence. Can only be called by
+		/// the parties during the evidence period.
+		#[ink(message)]
+		pub fn submit_evidence(&mut self, case_id: CaseId, evidence: Hash) -> Result<()> {
+			let mut case = self.get(case_id)?;
+			let party = self.env().caller();
+			if party != case.claimant && party != case.respondent {
+				return Err(Error::Unauthorized);
+			}
+			match case.status {
+				CaseStatus::Open { evidence_until, .. } if self.now() <= evidence_until => {},
+				CaseStatus::Open { .. } => return Err(Error::TooLate),
+				_ => return Err(Error::InvalidStatus),
+			}
+			if case.evidence.len() >= MAX_EVIDENCE {
+				return Err(Error::TooMuchEvidence);
+			}
+
+			case.evidence.push((party, evidence));
+			self.cases.insert(case_id, &case);
+			self.env().emit_event(EvidenceSubmitted { case_id, party, evidence });
+			Ok(())
+		}
+
+		/// Submit ruling to the court - `award` out of the disputed amount goes
+		/// to claimant, rest to respondent. Can only be called by court's
+		/// judges after the evidence period. Ruling can be resubmitted, e.g.
+		/// after the court vetoed the previous one.
+		///
+		/// Transferred value is forwarded as court's proposal deposit.
+		/// `metadata` is attached to the proposal, e.g. hash of reasoning.
+		#[ink(message, payable)]
+		pub fn propose_ruling(
+			&mut self,
+			case_id: CaseId,
+			award: Balance,
+			metadata: Option<Vec<u8>>,
+		) -> Result<PropKey> {
+			let mut case = self.get(case_id)?;
+			let now = self.now();
+			let ruling_until = match case.status {
+				CaseStatus::Open { evidence_until, .. } if now <= evidence_until => {
+					return Err(Error::TooEarly)
+				},
+				CaseStatus::Open { ruling_until, .. }
+				| CaseStatus::RulingProposed { ruling_until, .. } => ruling_until,
+				_ => return Err(Error::InvalidStatus),
+			};
+			if now > ruling_until {
+				return Err(Error::TooLate);
+			}
+			if award > case.amount {
+				return Err(Error::InvalidParameters);
+			}
+			let judge = self.env().caller();
+			if !self.court().get_judges().iter().any(|(j, _)| *j == judge) {
+				return Err(Error::Unauthorized);
+			}
+
+			let proposal =
+				Proposal::EnforceRuling { contract: self.env().account_id(), case_id, award };
+			let key = match self
+				.court()
+				.call_mut()
+				.propose(proposal, metadata)
+				.transferred_value(self.env().transferred_value())
+				.try_invoke()
+			{
+				Ok(Ok(Ok((key, _)))) => key,
+				_ => return Err(Error::CourtRejected),
+			};
+
+			case.status = CaseStatus::RulingProposed { key, ruling_until };
+			self.cases.insert(case_id, &case);
+			self.env().emit_event(RulingProposed { case_id, judge, key, award });
+			Ok(key)
+		}
+
+		/// Close case that missed its deadline and refund deposits - claimant's
+		/// bond if respondent didn't respond, everything if court didn't rule.
+		/// Can be called by anyone.
+		#[ink(message)]
+		pub fn close_expired(&mut self, case_id: CaseId) -> Result<()> {
+			let case = self.get(case_id)?;
+			let now = self.now();
+			match case.status {
+				CaseStatus::AwaitingResponse(until) => {
+					if now <= until {
+						return Err(Error::TooEarly);
+					}
+					self.close(case_id, case, self.bond, 0, CaseStatus::Defaulted)
+				},
+				CaseStatus::Open { ruling_until, .. }
+				| CaseStatus::RulingProposed { ruling_until, .. } => {
+					if now <= ruling_until {
+						return Err(Error::TooEarly);
+					}
+					let to_respondent =
+						case.amount.checked_add(self.bond).ok_or(Error::InvalidParameters)?;
+					self.close(case_id, case, self.bond, to_respondent, CaseStatus::Expired)
+				},
+				_ => Err(Error::InvalidStatus),
+			}
+		}
+
+		#[ink(message)]
+		pub fn get_case(&self, case_id: CaseId) -> Option<Case> {
+			self.cases.get(case_id)
+		}
+
+		#[ink(message)]
+		pub fn get_court(&self) -> AccountId {
+			self.court
+		}
+
+		#[ink(message)]
+		pub fn get_bond(&self) -> Balance {
+			self.bond
+		}
+
+		/// Response, evidence and ruling periods
+		#[ink(message)]
+		pub fn get_periods(&self) -> (BlockNumber, BlockNumber, BlockNumber) {
+			(self.response_period, self.evidence_period, self.ruling_period)
+		}
+	}
+
+	impl CourtRuling for ArbitrationRegistry {
+		/// Pay out the award to claimant, rest of the disputed amount to
+		/// respondent and return bonds. Can only be called by the court, for
+		/// cases with proposed ruling.
+		#[ink(message)]
+		fn enforce_ruling(
+			&mut self,
+			case_id: CaseId,
+			award: Balance,
+		) -> core::result::Result<(), CourtError> {
+			if self.env().caller() != self.court {
+				return Err(CourtError::Unauthorized);
+			}
+			let case = self.get(case_id).map_err(|_| CourtError::CaseNotFound)?;
+			if !matches!(case.status, CaseStatus::RulingProposed { .. }) || award > case.amount {
+				return Err(CourtError::InvalidParameters);
+			}
+			let to_claimant = award.saturating_add(self.bond);
+			let to_respondent = (case.amount - award).saturating_add(self.bond);
+			self.close(case_id, case, to_claimant, to_respondent, CaseStatus::Ruled(award))
+				.map_err(|_| CourtError::EnforcementFailed)
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+
+		fn accounts() -> ink::env::test::DefaultAccounts<Environment> {
+			ink::env::test::default_accounts::<Environment>()
+		}
+
+		fn claimant() -> AccountId {
+			accounts().alice
+		}
+
+		fn respondent() -> AccountId {
+			accounts().bob
+		}
+
+		fn court() -> AccountId {
+			accounts().charlie
+		}
+
+		fn set_next_caller(caller: AccountId) {
+			ink::env::test::set_caller::<Environment>(caller);
+		}
+
+		fn set_value_transferred(value: Balance) {
+			ink::env::test::set_value_transferred::<Environment>(value);
+		}
+
+		fn balance_of(account: AccountId) -> Balance {
+			ink::env::test::get_account_balance::<Environment>(account).unwrap_or(0)
+		}
+
+		fn advance_blocks(n: u32) {
+			for _ in 0..n {
+				ink::env::test::advance_block::<Environment>();
+			}
+		}
+
+		fn evidence(n: u8) -> Hash {
+			Hash::from([n; 32])
+		}
+
+		/// Registry with bond of 10, response period of 5 blocks, evidence and
+		/// ruling periods of 10 blocks and a filed case for 100
+		fn setup() -> (ArbitrationRegistry, CaseId) {
+			let mut registry = ArbitrationRegistry::new(court(), 10, 5, 10, 10);
+			let contract_id = ink::env::test::callee::<Environment>();
+			ink::env::test::set_account_balance::<Environment>(contract_id, 10);
+			set_next_caller(claimant());
+			set_value_transferred(10);
+			let case_id =
+				registry.file(respondent(), 100, evidence(1)).expect("file shouldnt fail");
+			set_value_transferred(0);
+			(registry, case_id)
+		}
+
+		fn respond(registry: &mut ArbitrationRegistry, case_id: CaseId) {
+			let contract_id = ink::env::test::callee::<Environment>();
+			ink::env::test::set_account_balance::<Environment>(contract_id, 120);
+			set_next_caller(respondent());
+			set_value_transferred(110);
+			registry.respond(case_id, evidence(2)).expect("respond shouldnt fail");
+			set_value_transferred(0);
+		}
+
+		#[ink::test]
+		fn file_works() {
+			let (registry, case_id) = setup();
+			let case = registry.get_case(case_id).unwrap();
+			assert_eq!(case.claimant, claimant());
+			assert_eq!(case.amount, 100);
+			assert_eq!(case.status, CaseStatus::AwaitingResponse(5));
+			assert_eq!(case.evidence, vec![(claimant(), evidence(1))]);
+			assert_eq!(ink::env::test::recorded_events().count(), 1);
+		}
+
+		#[ink::test]
+		fn file_requires_bond() {
+			let mut registry = ArbitrationRegistry::new(court(), 10, 5, 10, 10);
+			set_next_caller(claimant());
+			set_value_transferred(9);
+			assert_eq!(registry.file(respondent(), 100, evidence(1)), Err(Error::InvalidDeposit));
+			set_value_transferred(10);
+			assert_eq!(registry.file(claimant(), 100, evidence(1)), Err(Error::InvalidParameters));
+		}
+
+		#[ink::test]
+		fn respond_opens_evidence_period() {
+			let (mut registry, case_id) = setup();
+			set_next_caller(accounts().django);
+			assert_eq!(registry.respond(case_id, evidence(2)), Err(Error::Unauthorized));
+			set_next_caller(respondent());
+			set_value_transferred(100);
+			assert_eq!(registry.respond(case_id, evidence(2)), Err(Error::InvalidDeposit));
+
+			respond(&mut registry, case_id);
+			let case = registry.get_case(case_id).unwrap();
+			assert_eq!(case.status, CaseStatus::Open { evidence_until: 10, ruling_until: 20 });
+			assert_eq!(case.evidence.len(), 2);
+		}
+
+		#[ink::test]
+		fn evidence_only_from_parties_during_evidence_period() {
+			let (mut registry, case_id) = setup();
+			set_next_caller(claimant());
+			assert_eq!(registry.submit_evidence(case_id, evidence(3)), Err(Error::InvalidStatus));
+			respond(&mut registry, case_id);
+
+			set_next_caller(accounts().django);
+			assert_eq!(registry.submit_evidence(case_id, evidence(3)), Err(Error::Unauthorized));
+			set_next_caller(claimant());
+			assert_eq!(registry.submit_evidence(case_id, evidence(3)), Ok(()));
+			advance_blocks(11);
+			assert_eq!(registry.submit_evidence(case_id, evidence(4)), Err(Error::TooLate));
+			assert_eq!(registry.get_case(case_id).unwrap().evidence.len(), 3);
+		}
+
+		#[ink::test]
+		fn ruling_cant_be_proposed_during_evidence_period() {
+			let (mut registry, case_id) = setup();
+			respond(&mut registry, case_id);
+			set_next_caller(court());
+			assert_eq!(registry.propose_ruling(case_id, 50, None), Err(Error::TooEarly));
+			advance_blocks(21);
+			assert_eq!(registry.propose_ruling(case_id, 50, None), Err(Error::TooLate));
+		}
+
+		#[ink::test]
+		fn unanswered_case_defaults() {
+			let (mut registry, case_id) = setup();
+			advance_blocks(5);
+			assert_eq!(registry.close_expired(case_id), Err(Error::TooEarly));
+			advance_blocks(1);
+			let claimant_before = balance_of(claimant());
+			assert_eq!(registry.close_expired(case_id), Ok(()));
+			assert_eq!(balance_of(claimant()), claimant_before + 10);
+			assert_eq!(registry.get_case(case_id).unwrap().status, CaseStatus::Defaulted);
+
+			set_next_caller(respondent());
+			set_value_transferred(110);
+			assert_eq!(registry.respond(case_id, evidence(2)), Err(Error::InvalidStatus));
+		}
+
+		#[ink::test]
+		fn case_without_ruling_expires() {
+			let (mut registry, case_id) = setup();
+			respond(&mut registry, case_id);
+			advance_blocks(20);
+			assert_eq!(registry.close_expired(case_id), Err(Error::TooEarly));
+			advance_blocks(1);
+
+			let claimant_before = balance_of(claimant());
+			let respondent_before = balance_of(respondent());
+			assert_eq!(registry.close_expired(case_id), Ok(()));
+			assert_eq!(balance_of(claimant()), claimant_before + 10);
+			assert_eq!(balance_of(respondent()), respondent_before + 110);
+			assert_eq!(registry.get_case(case_id).unwrap().status, CaseStatus::Expired);
+		}
+
+		#[ink::test]
+		fn enforce_ruling_works() {
+			let (mut registry, case_id) = setup();
+			respond(&mut registry, case_id);
+			let mut case = registry.get_case(case_id).unwrap();
+			case.status = CaseStatus::RulingProposed { key: [0; 32], ruling_until: 20 };
+			registry.cases.insert(case_id, &case);
+
+			set_next_caller(claimant());
+			assert_eq!(registry.enforce_ruling(case_id, 30), Err(CourtError::Unauthorized));
+			set_next_caller(court());
+			assert_eq!(registry.enforce_ruling(case_id, 101), Err(CourtError::InvalidParameters));
+			assert_eq!(registry.enforce_ruling(7, 30), Err(CourtError::CaseNotFound));
+
+			let claimant_before = balance_of(claimant());
+			let respondent_before = balance_of(respondent());
+			assert_eq!(registry.enforce_ruling(case_id, 30), Ok(()));
+			assert_eq!(balance_of(claimant()), claimant_before + 40);
+			assert_eq!(balance_of(respondent()), respondent_before + 80);
+			assert_eq!(registry.get_case(case_id).unwrap().status, CaseStatus::Ruled(30));
+			assert_eq!(registry.enforce_ruling(case_id, 30), Err(CourtError::InvalidParameters));
+		}
+	}
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink::primitives::{AccountId, Hash};
+
+pub type Balance = u128;
+pub type CaseId = u64;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum CourtError {
+	/// Caller isn't allowed to file or enforce this case
+	Unauthorized,
+	/// Case doesn't exist
+	CaseNotFound,
+	/// Invalid claim or ruling parameters
+	InvalidParameters,
+	/// Ruling couldn't be enforced, e.g. transfer of funds failed
+	EnforcementFailed,
+}
+
+/// Implemented by court contracts (e.g. small-claims court) that accept
+/// disputes from other contracts.
+#[ink::trait_definition]
+pub trait SmallClaimsCourt {
+	/// File a new case. `claimant` claims `amount` from `respondent`,
+	/// `evidence` anchors the off-chain case file. Returns id of the case.
+	///
+	/// Court must later call `CourtRuling::enforce_ruling` on the caller.
+	#[ink(message)]
+	fn file_claim(
+		&mut self,
+		claimant: AccountId,
+		respondent: AccountId,
+		amount: Balance,
+		evidence: Hash,
+	) -> Result<CaseId, CourtError>;
+}
+
+/// Implemented by contracts that submit disputes to a `SmallClaimsCourt` and
+/// hold funds that the ruling applies to.
+#[ink::trait_definition]
+pub trait CourtRuling {
+	/// Enforce court's ruling - `award` goes to the claimant, rest of the
+	/// disputed funds back to respondent. Must only be callable by the court.
+	#[ink(message)]
+	fn enforce_ruling(&mut self, case_id: CaseId, award: Balance) -> Result<(), CourtError>;
+}
+
+/// Implemented by contracts holding funds under supervision of a revocation
+/// authority (usually a court), like vesting escrows.
+#[ink::trait_definition]
+pub trait Revocable {
+	/// Stop the arrangement and settle held funds according to its terms.
+	/// Must only be callable by the revocation authority.
+	#[ink(message)]
+	fn revoke(&mut self) -> Result<(), CourtError>;
+}
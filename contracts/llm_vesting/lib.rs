@@ -0,0 +1,350 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[cfg(test)]
+mod mock;
+
+/// LLM vesting escrow.
+///
+/// Funder transfers liquid LLM to the contract's account, which releases it to
+/// the beneficiary on a cliff + linear schedule. Nothing vests before the
+/// cliff, then the vested amount grows linearly until the whole grant vests at
+/// the end of the schedule. Anyone can trigger release of vested LLM.
+///
+/// Revocation authority (usually the `msig_court` contract) can revoke the
+/// grant through `court_traits::Revocable` - already vested LLM goes to the
+/// beneficiary and the rest back to the funder.
+#[ink::contract(env = liberland_extension::LiberlandEnvironment)]
+mod llm_vesting {
+	use court_traits::{CourtError, Revocable};
+	use ink::codegen::Env;
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct Schedule {
+		/// Total amount of LLM granted
+		pub total: Balance,
+		/// Block at which vesting starts
+		pub start: BlockNumber,
+		/// Number of blocks after `start` before anything vests
+		pub cliff: BlockNumber,
+		/// Number of blocks after `start` at which everything is vested
+		pub duration: BlockNumber,
+	}
+
+	impl Schedule {
+		/// Amount vested at given block
+		pub fn vested_at(&self, block: BlockNumber) -> Balance {
+			let elapsed = block.saturating_sub(self.start);
+			if elapsed < self.cliff {
+				return 0;
+			}
+			if elapsed >= self.duration {
+				return self.total;
+			}
+			self.total.saturating_mul(elapsed.into()) / Balance::from(self.duration)
+		}
+	}
+
+	#[derive(Debug, PartialEq, Eq, Clone)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub enum Error {
+		/// Caller isn't allowed to do this
+		Unauthorized,
+		/// Nothing vested since last release
+		NothingToRelease,
+		/// Contract holds less LLM than is due
+		Underfunded,
+		/// Transfer of LLM failed
+		TransferFailed,
+		/// Grant was already revoked
+		Revoked,
+	}
+
+	pub type Result<T> = core::result::Result<T, Error>;
+
+	#[ink(storage)]
+	pub struct LlmVesting {
+		beneficiary: AccountId,
+		funder: AccountId,
+		revoker: AccountId,
+		schedule: Schedule,
+		released: Balance,
+		revoked_at: Option<BlockNumber>,
+	}
+
+	#[ink(event)]
+	pub struct Released {
+		#[ink(topic)]
+		beneficiary: AccountId,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Revoked {
+		#[ink(topic)]
+		revoker: AccountId,
+		to_beneficiary: Balance,
+		to_funder: Balance,
+	}
+
+	impl LlmVesting {
+		fn now(&self) -> BlockNumber {
+			self.env().block_number()
+		}
+
+		fn llm_balance(&self) -> Balance {
+			self.env().extension().llm_balance(self.env().account_id())
+		}
+
+		fn send_llm(&self, to: AccountId, amount: Balance) -> Result<()> {
+			if amount == 0 {
+				return Ok(());
+			}
+			self.env().extension().send_llm(to, amount).map_err(|_| Error::TransferFailed)
+		}
+
+		fn releasable(&self) -> Balance {
+			let at = self.revoked_at.unwrap_or_else(|| self.now());
+			self.schedule.vested_at(at).saturating_sub(self.released)
+		}
+	}
+
+	impl LlmVesting {
+		/// Panics if `cliff` is longer than `duration` or `duration` is zero.
+		#[ink(constructor)]
+		pub fn new(
+			beneficiary: AccountId,
+			funder: AccountId,
+			revoker: AccountId,
+			schedule: Schedule,
+		) -> Self {
+			assert!(schedule.duration > 0, "Duration must be non-zero");
+			assert!(schedule.cliff <= schedule.duration, "Cliff can't be longer than duration");
+			Self { beneficiary, funder, revoker, schedule, released: 0, revoked_at: None }
+		}
+
+		/// Release vested LLM to the beneficiary. Can be called by anyone.
+		#[ink(message)]
+		pub fn release(&mut self) -> Result<Balance> {
+			if self.revoked_at.is_some() {
+				return Err(Error::Revoked);
+			}
+			let amount = self.releasable();
+			if amount == 0 {
+				return Err(Error::NothingToRelease);
+			}
+			if self.llm_balance() < amount {
+				return Err(Error::Underfunded);
+			}
+			self.send_llm(self.beneficiary, amount)?;
+			self.released = self.released.saturating_add(amount);
+			self.env().emit_event(Released { beneficiary: self.beneficiary, amount });
+			Ok(amount)
+		}
+
+		#[ink(message)]
+		pub fn get_schedule(&self) -> Schedule {
+			self.schedule.clone()
+		}
+
+		#[ink(message)]
+		pub fn get_released(&self) -> Balance {
+			self.released
+		}
+
+		/// Amount that `release` would transfer now
+		#[ink(message)]
+		pub fn get_releasable(&self) -> Balance {
+			if self.revoked_at.is_some() {
+				return 0;
+			}
+			self.releasable()
+		}
+
+		#[ink(message)]
+		pub fn get_beneficiary(&self) -> AccountId {
+			self.beneficiary
+		}
+
+		#[ink(message)]
+		pub fn get_funder(&self) -> AccountId {
+			self.funder
+		}
+
+		#[ink(message)]
+		pub fn get_revoker(&self) -> AccountId {
+			self.revoker
+		}
+
+		#[ink(message)]
+		pub fn get_revoked_at(&self) -> Option<BlockNumber> {
+			self.revoked_at
+		}
+	}
+
+	impl Revocable for LlmVesting {
+		/// Revoke the grant - LLM vested so far but not released yet goes to
+		/// the beneficiary, rest of the contract's LLM back to the funder. Can
+		/// only be called by the revocation authority.
+		#[ink(message)]
+		fn revoke(&mut self) -> core::result::Result<(), CourtError> {
+			if self.env().caller() != self.revoker {
+				return Err(CourtError::Unauthorized);
+			}
+			if self.revoked_at.is_some() {
+				return Err(CourtError::InvalidParameters);
+			}
+			self.revoked_at = Some(self.now());
+
+			let balance = self.llm_balance();
+			let to_beneficiary = self.releasable().min(balance);
+			let to_funder = balance.saturating_sub(to_beneficiary);
+			self.send_llm(self.beneficiary, to_beneficiary)
+				.and_then(|_| self.send_llm(self.funder, to_funder))
+				.map_err(|_| CourtError::EnforcementFailed)?;
+			self.released = self.released.saturating_add(to_beneficiary);
+
+			self.env()
+				.emit_event(Revoked { revoker: self.revoker, to_beneficiary, to_funder });
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::mock::*;
+
+		fn accounts() -> ink::env::test::DefaultAccounts<Environment> {
+			ink::env::test::default_accounts::<Environment>()
+		}
+
+		fn beneficiary() -> AccountId {
+			accounts().alice
+		}
+
+		fn funder() -> AccountId {
+			accounts().bob
+		}
+
+		fn revoker() -> AccountId {
+			accounts().charlie
+		}
+
+		fn set_next_caller(caller: AccountId) {
+			ink::env::test::set_caller::<Environment>(caller);
+		}
+
+		fn advance_blocks(n: u32) {
+			for _ in 0..n {
+				ink::env::test::advance_block::<Environment>();
+			}
+		}
+
+		fn schedule() -> Schedule {
+			Schedule { total: 1000, start: 10, cliff: 20, duration: 100 }
+		}
+
+		/// Contract holding `funded` LLM with 1000 LLM vesting from block 10,
+		/// cliff at block 30 and fully vested at block 110
+		fn setup(funded: Balance) -> (LlmVesting, Ledger) {
+			let contract = ink::env::test::callee::<Environment>();
+			let ledger = Ledger::default();
+			ledger.borrow_mut().push((contract, funded));
+			ink::env::test::register_chain_extension(MockedLiberlandExtension {
+				contract,
+				ledger: ledger.clone(),
+			});
+			let vesting = LlmVesting::new(beneficiary(), funder(), revoker(), schedule());
+			(vesting, ledger)
+		}
+
+		#[test]
+		fn vested_at_follows_cliff_and_linear_schedule() {
+			let s = schedule();
+			assert_eq!(s.vested_at(0), 0);
+			assert_eq!(s.vested_at(29), 0);
+			assert_eq!(s.vested_at(30), 200);
+			assert_eq!(s.vested_at(60), 500);
+			assert_eq!(s.vested_at(110), 1000);
+			assert_eq!(s.vested_at(500), 1000);
+		}
+
+		#[ink::test]
+		#[should_panic]
+		fn new_rejects_cliff_longer_than_duration() {
+			let s = Schedule { total: 1000, start: 0, cliff: 101, duration: 100 };
+			LlmVesting::new(beneficiary(), funder(), revoker(), s);
+		}
+
+		#[ink::test]
+		fn nothing_released_before_cliff() {
+			let (mut vesting, _) = setup(1000);
+			advance_blocks(29);
+			assert_eq!(vesting.get_releasable(), 0);
+			assert_eq!(vesting.release(), Err(Error::NothingToRelease));
+		}
+
+		#[ink::test]
+		fn release_works() {
+			let (mut vesting, ledger) = setup(1000);
+			advance_blocks(60);
+			set_next_caller(accounts().django);
+			assert_eq!(vesting.release(), Ok(500));
+			assert_eq!(balance_in(&ledger, beneficiary()), 500);
+			assert_eq!(vesting.release(), Err(Error::NothingToRelease));
+
+			advance_blocks(100);
+			assert_eq!(vesting.release(), Ok(500));
+			assert_eq!(balance_in(&ledger, beneficiary()), 1000);
+			assert_eq!(vesting.get_released(), 1000);
+			assert_eq!(ink::env::test::recorded_events().count(), 2);
+		}
+
+		#[ink::test]
+		fn release_fails_if_underfunded() {
+			let (mut vesting, ledger) = setup(100);
+			advance_blocks(60);
+			assert_eq!(vesting.release(), Err(Error::Underfunded));
+			assert_eq!(balance_in(&ledger, beneficiary()), 0);
+			assert_eq!(vesting.get_released(), 0);
+		}
+
+		#[ink::test]
+		fn only_revoker_can_revoke() {
+			let (mut vesting, _) = setup(1000);
+			set_next_caller(funder());
+			assert_eq!(vesting.revoke(), Err(CourtError::Unauthorized));
+			set_next_caller(beneficiary());
+			assert_eq!(vesting.revoke(), Err(CourtError::Unauthorized));
+		}
+
+		#[ink::test]
+		fn revoke_splits_vested_and_unvested() {
+			let (mut vesting, ledger) = setup(1000);
+			advance_blocks(40);
+			vesting.release().unwrap();
+			advance_blocks(20);
+
+			set_next_caller(revoker());
+			assert_eq!(vesting.revoke(), Ok(()));
+			assert_eq!(balance_in(&ledger, beneficiary()), 500);
+			assert_eq!(balance_in(&ledger, funder()), 500);
+			assert_eq!(vesting.get_revoked_at(), Some(60));
+			assert_eq!(vesting.revoke(), Err(CourtError::InvalidParameters));
+		}
+
+		#[ink::test]
+		fn nothing_vests_after_revoke() {
+			let (mut vesting, ledger) = setup(1000);
+			set_next_caller(revoker());
+			assert_eq!(vesting.revoke(), Ok(()));
+			assert_eq!(balance_in(&ledger, funder()), 1000);
+
+			advance_blocks(200);
+			assert_eq!(vesting.get_releasable(), 0);
+			assert_eq!(vesting.release(), Err(Error::Revoked));
+		}
+	}
+}
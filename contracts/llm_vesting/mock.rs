@@ -0,0 +1,59 @@
+use ink::scale::{Decode, Encode};
+use std::{cell::RefCell, rc::Rc};
+
+type AccountId = ink::primitives::AccountId;
+type Balance = u128;
+
+/// LLM balances shared between the mocked extension and the test
+pub type Ledger = Rc<RefCell<Vec<(AccountId, Balance)>>>;
+
+pub fn balance_in(ledger: &Ledger, account: AccountId) -> Balance {
+	ledger
+		.borrow()
+		.iter()
+		.find(|(a, _)| *a == account)
+		.map(|(_, b)| *b)
+		.unwrap_or(0)
+}
+
+fn set_balance(ledger: &Ledger, account: AccountId, balance: Balance) {
+	let mut ledger = ledger.borrow_mut();
+	match ledger.iter_mut().find(|(a, _)| *a == account) {
+		Some(entry) => entry.1 = balance,
+		None => ledger.push((account, balance)),
+	}
+}
+
+/// Keeps LLM balances for `send_llm` and `llm_balance`. Transfers are always
+/// made from `contract`, the only caller of `send_llm`.
+pub struct MockedLiberlandExtension {
+	pub contract: AccountId,
+	pub ledger: Ledger,
+}
+
+impl ink::env::test::ChainExtension for MockedLiberlandExtension {
+	fn ext_id(&self) -> u16 {
+		0
+	}
+
+	fn call(&mut self, func_id: u16, mut input: &[u8], output: &mut Vec<u8>) -> u32 {
+		match func_id {
+			9 => {
+				let (to, amount) = <(AccountId, Balance)>::decode(&mut input).unwrap();
+				let from_balance = balance_in(&self.ledger, self.contract);
+				if from_balance < amount {
+					return 1;
+				}
+				set_balance(&self.ledger, self.contract, from_balance - amount);
+				set_balance(&self.ledger, to, balance_in(&self.ledger, to) + amount);
+				0
+			},
+			10 => {
+				let account = AccountId::decode(&mut input).unwrap();
+				balance_in(&self.ledger, account).encode_to(output);
+				0
+			},
+			_ => 1,
+		}
+	}
+}
@@ -3,25 +3,187 @@
 #[cfg(test)]
 mod mock;
 
+pub use self::msig_court::{Error, MsigCourtRef, PropKey, Proposal, ProposalKind, ProposalState};
+
 #[ink::contract(env = liberland_extension::LiberlandEnvironment)]
 mod msig_court {
+	use court_traits::{CaseId, CourtRuling, Revocable};
 	use ink::codegen::Env;
 	use ink::prelude::vec::Vec;
 	use ink::storage::Mapping;
-	use liberland_extension::LLMForceTransferArguments;
+	use liberland_extension::{LLDForceTransferArguments, LLMForceTransferArguments};
+
+	/// Maximum number of proposals in a single `Batch`
+	pub const MAX_BATCH_LEN: usize = 16;
+	/// Maximum length of proposal metadata
+	pub const MAX_METADATA_LEN: usize = 256;
+	/// Maximum number of keys returned by `get_vetoed_keys`
+	pub const MAX_PAGE_LEN: u32 = 100;
 
 	#[derive(Debug, Clone, PartialEq, Eq)]
 	#[ink::scale_derive(Encode, Decode, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 	pub enum Proposal {
 		LLMForceTransfer(LLMForceTransferArguments),
-		SetGovernance { threshold: u32, judges: Vec<AccountId> },
+		/// Set judges with weights of their approvals and default threshold -
+		/// total weight of approvals needed.
+		SetGovernance {
+			threshold: u32,
+			judges: Vec<(AccountId, u32)>,
+		},
+		/// Set accounts that can veto approved proposals, number of vetoes
+		/// needed and length of the veto period in blocks. Empty
+		/// `veto_authorities` with `veto_threshold` 0 disables vetoes.
+		SetVetoGovernance {
+			veto_threshold: u32,
+			veto_authorities: Vec<AccountId>,
+			veto_period: BlockNumber,
+		},
+		/// Set deposit required to propose and account receiving deposits of
+		/// vetoed proposals. With no beneficiary, deposits stay in the court.
+		SetDepositPolicy {
+			proposal_deposit: Balance,
+			beneficiary: Option<AccountId>,
+		},
+		/// Set bounty paid to whoever executes a proposal with
+		/// `execute_pending`. Paid from proposer's deposit first, then from
+		/// court's own balance.
+		SetExecutionBounty(Balance),
+		/// Set thresholds for given proposal kinds. `None` removes the override,
+		/// so default `threshold` applies again.
+		SetThresholds(Vec<(ProposalKind, Option<u32>)>),
+		/// Replace contract's code with already uploaded code of given hash.
+		/// New code must keep storage layout compatible with this contract.
+		SetCode([u8; 32]),
+		/// SCALE-encoded `RuntimeCall` dispatched with court's account as origin.
+		/// Runtime decides which calls are allowed.
+		RuntimeCall(Vec<u8>),
+		/// Executes inner proposals in order. Aborts on first failure and
+		/// reverts the whole approval, so either all or none are executed.
+		/// Can't be nested and can't exceed `MAX_BATCH_LEN` proposals.
+		Batch(Vec<Proposal>),
+		/// Pay LLD from LLM treasury, e.g. court-ordered compensation. Court
+		/// must be registered in `pallet_llm::Courts`.
+		LLDForceTransfer(LLDForceTransferArguments),
+		/// Revoke citizenship of given account, removing its identity
+		/// judgements and democracy votes. Court must be registered in
+		/// `pallet_llm::Courts`.
+		RevokeCitizenship(AccountId),
+		/// Set shortened veto period for proposals approved by all judges.
+		/// Judges can keep approving during veto period to reach unanimity.
+		/// `None` disables fast-track.
+		SetFastTrack(Option<BlockNumber>),
+		/// Revoke arrangement supervised by the court (e.g. `llm_vesting`
+		/// escrow) by calling `court_traits::Revocable::revoke` on given
+		/// contract.
+		Revoke(AccountId),
+		/// Set accounts allowed to propose without being judges, e.g.
+		/// `arbitration_registry` contracts submitting rulings of their cases.
+		/// Their proposals need approvals of judges as usual.
+		SetProposers(Vec<AccountId>),
+		/// Enforce ruling in a case filed by given contract by calling
+		/// `court_traits::CourtRuling::enforce_ruling` on it.
+		EnforceRuling {
+			contract: AccountId,
+			case_id: CaseId,
+			award: Balance,
+		},
+	}
+
+	/// Kind of `Proposal`, used to configure kind-specific thresholds
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub enum ProposalKind {
+		LLMForceTransfer,
+		SetGovernance,
+		SetVetoGovernance,
+		SetDepositPolicy,
+		SetExecutionBounty,
+		SetThresholds,
+		SetCode,
+		RuntimeCall,
+		Batch,
+		LLDForceTransfer,
+		RevokeCitizenship,
+		SetFastTrack,
+		Revoke,
+		SetProposers,
+		EnforceRuling,
+	}
+
+	impl ProposalKind {
+		pub const ALL: [ProposalKind; 15] = [
+			ProposalKind::LLMForceTransfer,
+			ProposalKind::SetGovernance,
+			ProposalKind::SetVetoGovernance,
+			ProposalKind::SetDepositPolicy,
+			ProposalKind::SetExecutionBounty,
+			ProposalKind::SetThresholds,
+			ProposalKind::SetCode,
+			ProposalKind::RuntimeCall,
+			ProposalKind::Batch,
+			ProposalKind::LLDForceTransfer,
+			ProposalKind::RevokeCitizenship,
+			ProposalKind::SetFastTrack,
+			ProposalKind::Revoke,
+			ProposalKind::SetProposers,
+			ProposalKind::EnforceRuling,
+		];
+	}
+
+	impl Proposal {
+		pub fn kind(&self) -> ProposalKind {
+			match self {
+				Proposal::LLMForceTransfer(_) => ProposalKind::LLMForceTransfer,
+				Proposal::LLDForceTransfer(_) => ProposalKind::LLDForceTransfer,
+				Proposal::RevokeCitizenship(_) => ProposalKind::RevokeCitizenship,
+				Proposal::SetFastTrack(_) => ProposalKind::SetFastTrack,
+				Proposal::Revoke(_) => ProposalKind::Revoke,
+				Proposal::SetProposers(_) => ProposalKind::SetProposers,
+				Proposal::EnforceRuling { .. } => ProposalKind::EnforceRuling,
+				Proposal::SetGovernance { .. } => ProposalKind::SetGovernance,
+				Proposal::SetVetoGovernance { .. } => ProposalKind::SetVetoGovernance,
+				Proposal::SetDepositPolicy { .. } => ProposalKind::SetDepositPolicy,
+				Proposal::SetExecutionBounty(_) => ProposalKind::SetExecutionBounty,
+				Proposal::SetThresholds(_) => ProposalKind::SetThresholds,
+				Proposal::SetCode(_) => ProposalKind::SetCode,
+				Proposal::RuntimeCall(_) => ProposalKind::RuntimeCall,
+				Proposal::Batch(_) => ProposalKind::Batch,
+			}
+		}
+	}
+
+	/// Judge's signature over `(contract_address, key, nonce)` used by
+	/// `approve_with_signature`. Sr25519 signs the SCALE-encoded tuple, ECDSA
+	/// signs its Blake2x256 hash.
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub enum JudgeSignature {
+		Sr25519([u8; 64]),
+		Ecdsa([u8; 65]),
+	}
+
+	/// Vetoed proposal kept for audit
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct VetoedProposal {
+		pub proposal: Proposal,
+		pub metadata: Option<Vec<u8>>,
+		/// Veto authorities that vetoed it, in order. Last one completed the
+		/// veto.
+		pub vetoers: Vec<AccountId>,
+		pub vetoed_at: BlockNumber,
 	}
 
 	#[derive(Debug, PartialEq, Eq)]
 	#[ink::scale_derive(Encode, Decode, TypeInfo)]
 	pub enum ProposalState {
 		PendingApprovals,
+		/// Approved, can be vetoed until and executed from given block
+		PendingVetoPeriod(BlockNumber),
+		Vetoed,
 		Executed(Result<()>),
 	}
 
@@ -36,15 +198,35 @@ mod msig_court {
 		NotFound,
 		/// Caller already approved for this proposal
 		AlreadyApproved,
+		/// Caller already vetoed this proposal
+		AlreadyVetoed,
+		/// Veto period didn't end yet
+		VetoPeriodNotEnded,
+		/// Veto period already ended
+		VetoPeriodEnded,
+		/// Transferred value is lower than required proposal deposit
+		InsufficientDeposit,
+		/// Transfer of deposit failed
+		TransferFailed,
 		/// Call failed
 		CallFailed,
 		/// Invalid parameters
 		InvalidParameters,
+		/// Inner proposal of a batch at given index failed
+		BatchFailed(u32),
+		/// Signature doesn't match judge or message
+		InvalidSignature,
+		/// Metadata is longer than `MAX_METADATA_LEN`
+		MetadataTooLong,
+		/// Court is paused
+		Paused,
+		/// Runtime call made through the chain extension failed
+		Extension(liberland_extension::Error),
 	}
 
 	impl From<liberland_extension::Error> for Error {
-		fn from(_: liberland_extension::Error) -> Self {
-			Self::CallFailed
+		fn from(e: liberland_extension::Error) -> Self {
+			Self::Extension(e)
 		}
 	}
 
@@ -54,10 +236,51 @@ mod msig_court {
 	#[ink(storage)]
 	#[derive(Default)]
 	pub struct MsigCourt {
+		/// Default threshold, used for kinds without an entry in `thresholds`.
+		/// Thresholds are compared with total weight of approvals.
 		threshold: u32,
-		judges: Vec<AccountId>,
+		thresholds: Mapping<ProposalKind, u32>,
+		/// Judges and weights of their approvals
+		judges: Vec<(AccountId, u32)>,
 		proposals: Mapping<PropKey, Proposal>,
+		/// Mixed into keys, so identical proposals get distinct keys
+		proposal_nonce: u64,
+		/// Keys of active proposals by hash of their contents, to prevent
+		/// duplicates
+		active: Mapping<PropKey, PropKey>,
+		/// Context attached by proposer, like case number or IPFS hash of the
+		/// ruling
+		metadata: Mapping<PropKey, Vec<u8>>,
 		approvals: Mapping<PropKey, Vec<AccountId>>,
+		veto_threshold: u32,
+		veto_authorities: Vec<AccountId>,
+		veto_period: BlockNumber,
+		/// Approved proposals in veto period - block from which they can be executed
+		pending: Mapping<PropKey, BlockNumber>,
+		vetoes: Mapping<PropKey, Vec<AccountId>>,
+		/// Judges that approved each proposal in veto period, including the
+		/// ones that got it there
+		veto_approvals: Mapping<PropKey, Vec<AccountId>>,
+		/// Veto period of unanimously approved proposals, if shorter than
+		/// `veto_period`
+		fast_track_period: Option<BlockNumber>,
+		vetoed_archive: Mapping<PropKey, VetoedProposal>,
+		/// Keys of `vetoed_archive` in order of vetoing
+		vetoed_keys: Mapping<u32, PropKey>,
+		vetoed_count: u32,
+		proposal_deposit: Balance,
+		deposit_beneficiary: Option<AccountId>,
+		/// Proposer and deposit of each proposal
+		deposits: Mapping<PropKey, (AccountId, Balance)>,
+		/// Sum of all `deposits` - part of balance that isn't court's own
+		held_deposits: Balance,
+		execution_bounty: Balance,
+		/// Number of signed approvals used per judge, for replay protection
+		nonces: Mapping<AccountId, u64>,
+		/// `Some(block_proposals)` while paused by a veto authority
+		paused: Option<bool>,
+		/// Non-judges allowed to propose
+		proposers: Vec<AccountId>,
 	}
 
 	#[ink(event)]
@@ -66,6 +289,7 @@ mod msig_court {
 		proposer: AccountId,
 		key: PropKey,
 		proposal: Proposal,
+		metadata: Option<Vec<u8>>,
 	}
 
 	#[ink(event)]
@@ -75,6 +299,72 @@ mod msig_court {
 		key: PropKey,
 	}
 
+	#[ink(event)]
+	pub struct VetoPeriodStarted {
+		#[ink(topic)]
+		approver: AccountId,
+		key: PropKey,
+		executable_at: BlockNumber,
+	}
+
+	#[ink(event)]
+	pub struct FastTracked {
+		#[ink(topic)]
+		approver: AccountId,
+		key: PropKey,
+		executable_at: BlockNumber,
+	}
+
+	#[ink(event)]
+	pub struct VetoCast {
+		#[ink(topic)]
+		vetoer: AccountId,
+		key: PropKey,
+	}
+
+	#[ink(event)]
+	pub struct Vetoed {
+		#[ink(topic)]
+		vetoer: AccountId,
+		key: PropKey,
+	}
+
+	#[ink(event)]
+	pub struct Cancelled {
+		#[ink(topic)]
+		proposer: AccountId,
+		key: PropKey,
+	}
+
+	#[ink(event)]
+	pub struct DepositSlashed {
+		#[ink(topic)]
+		proposer: AccountId,
+		key: PropKey,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct BountyPaid {
+		#[ink(topic)]
+		executor: AccountId,
+		key: PropKey,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Paused {
+		#[ink(topic)]
+		authority: AccountId,
+		block_proposals: bool,
+	}
+
+	#[ink(event)]
+	pub struct Unpaused {
+		#[ink(topic)]
+		authority: AccountId,
+	}
+
 	#[ink(event)]
 	pub struct Executed {
 		#[ink(topic)]
@@ -90,33 +380,375 @@ mod msig_court {
 				LLMForceTransfer(args) => {
 					self.env().extension().llm_force_transfer(args).map_err(|e| e.into())
 				},
+				LLDForceTransfer(args) => {
+					self.env().extension().lld_force_transfer(args).map_err(|e| e.into())
+				},
+				RevokeCitizenship(account) => {
+					self.env().extension().revoke_citizenship(account).map_err(|e| e.into())
+				},
+				Revoke(contract) => {
+					let mut revocable: ink::contract_ref!(Revocable, Environment) = contract.into();
+					match revocable.call_mut().revoke().try_invoke() {
+						Ok(Ok(Ok(()))) => Ok(()),
+						_ => Err(Error::CallFailed),
+					}
+				},
+				SetProposers(proposers) => {
+					self.proposers = proposers;
+					Ok(())
+				},
+				EnforceRuling { contract, case_id, award } => {
+					let mut filer: ink::contract_ref!(CourtRuling, Environment) = contract.into();
+					match filer.call_mut().enforce_ruling(case_id, award).try_invoke() {
+						Ok(Ok(Ok(()))) => Ok(()),
+						_ => Err(Error::CallFailed),
+					}
+				},
 				SetGovernance { threshold, judges } => self.set_governance(threshold, judges),
+				SetVetoGovernance { veto_threshold, veto_authorities, veto_period } => {
+					self.set_veto_governance(veto_threshold, veto_authorities, veto_period)
+				},
+				SetDepositPolicy { proposal_deposit, beneficiary } => {
+					self.proposal_deposit = proposal_deposit;
+					self.deposit_beneficiary = beneficiary;
+					Ok(())
+				},
+				SetExecutionBounty(bounty) => {
+					self.execution_bounty = bounty;
+					Ok(())
+				},
+				SetThresholds(thresholds) => self.set_thresholds(thresholds),
+				SetFastTrack(period) => {
+					self.fast_track_period = period;
+					Ok(())
+				},
+				SetCode(code_hash) => {
+					// takes effect from the next call, this one finishes with old code
+					self.env().set_code_hash(&code_hash.into()).map_err(|_| Error::CallFailed)
+				},
+				RuntimeCall(call) => {
+					self.env().extension().dispatch_as_contract(call).map_err(|e| e.into())
+				},
+				Batch(proposals) if proposals.iter().all(|p| matches!(p, LLMForceTransfer(_))) => {
+					let transfers = proposals
+						.into_iter()
+						.filter_map(|p| match p {
+							LLMForceTransfer(args) => Some(args),
+							_ => None,
+						})
+						.collect();
+					// single extension call, applied atomically by the runtime
+					let results = self.env().extension().llm_force_transfer_batch(transfers);
+					match results.iter().position(|ok| !ok) {
+						Some(index) => Err(Error::BatchFailed(index as u32)),
+						None => Ok(()),
+					}
+				},
+				Batch(proposals) => {
+					for (index, proposal) in proposals.into_iter().enumerate() {
+						self.execute(proposal).map_err(|_| Error::BatchFailed(index as u32))?;
+					}
+					Ok(())
+				},
+			}
+		}
+
+		fn validate(proposal: &Proposal) -> Result<()> {
+			match proposal {
+				Proposal::Batch(proposals) => {
+					if proposals.is_empty()
+						|| proposals.len() > MAX_BATCH_LEN
+						|| proposals.iter().any(|p| matches!(p, Proposal::Batch(_)))
+					{
+						return Err(Error::InvalidParameters);
+					}
+					Ok(())
+				},
+				_ => Ok(()),
+			}
+		}
+
+		fn content_hash(proposal: &Proposal) -> PropKey {
+			let mut hash =
+				<ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+			ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(proposal, &mut hash);
+			hash
+		}
+
+		fn remove_proposal(&mut self, key: PropKey) {
+			if let Some(proposal) = self.proposals.take(key) {
+				self.active.remove(Self::content_hash(&proposal));
+			}
+			self.metadata.remove(key);
+		}
+
+		fn threshold_of(&self, kind: ProposalKind) -> u32 {
+			self.thresholds.get(kind).unwrap_or(self.threshold)
+		}
+
+		/// Batch needs approvals required by its strictest inner proposal, so it
+		/// can't be used to bypass kind-specific thresholds.
+		fn threshold_for(&self, proposal: &Proposal) -> u32 {
+			let own = self.threshold_of(proposal.kind());
+			match proposal {
+				Proposal::Batch(proposals) => {
+					proposals.iter().map(|p| self.threshold_of(p.kind())).fold(own, core::cmp::max)
+				},
+				_ => own,
 			}
 		}
 
+		fn is_judge(&self, account: &AccountId) -> bool {
+			self.judges.iter().any(|(judge, _)| judge == account)
+		}
+
+		fn total_weight(judges: &[(AccountId, u32)]) -> u32 {
+			judges.iter().fold(0, |total, (_, weight)| total.saturating_add(*weight))
+		}
+
+		/// Weight of approvals by current judges. Approvals of removed judges
+		/// don't count.
+		fn approved_weight(&self, approvals: &[AccountId]) -> u32 {
+			self.judges
+				.iter()
+				.filter(|(judge, _)| approvals.contains(judge))
+				.fold(0, |total, (_, weight)| total.saturating_add(*weight))
+		}
+
 		fn do_approve(&mut self, approver: AccountId, key: PropKey) -> Result<ProposalState> {
-			let approvals = self.approvals.take(key).ok_or(Error::NotFound)?;
+			if let Some(executable_at) = self.pending.get(key) {
+				return self.do_approve_pending(approver, key, executable_at);
+			}
+			let mut approvals = self.approvals.take(key).ok_or(Error::NotFound)?;
 			if approvals.contains(&approver) {
 				return Err(Error::AlreadyApproved);
 			}
-
-			if approvals.len().saturating_add(1) >= self.threshold as usize {
-				let proposal =
-					self.proposals.take(key).expect("Approvals exist, so proposal must exist too");
-				let result = self.execute(proposal);
-				self.env().emit_event(Executed { approver, key, result: result.clone() });
-				Ok(ProposalState::Executed(result))
+			let proposal = self.proposals.get(key).ok_or(Error::NotFound)?;
+			approvals.push(approver);
+
+			if self.approved_weight(&approvals) >= self.threshold_for(&proposal) {
+				// paused court doesn't execute - proposal waits for
+				// `execute_pending` even without veto period
+				if self.veto_period == 0 && self.paused.is_none() {
+					return self.do_execute(approver, key);
+				}
+				let executable_at = self.env().block_number().saturating_add(self.veto_period);
+				self.pending.insert(key, &executable_at);
+				self.vetoes.insert(key, &Vec::<AccountId>::new());
+				self.veto_approvals.insert(key, &approvals);
+				self.env().emit_event(VetoPeriodStarted { approver, key, executable_at });
+				let executable_at = self.fast_track(approver, key, &approvals, executable_at);
+				Ok(ProposalState::PendingVetoPeriod(executable_at))
 			} else {
-				let mut approvals = approvals;
-				approvals.push(approver);
 				self.approvals.insert(key, &approvals);
 				self.env().emit_event(Approved { approver, key });
 				Ok(ProposalState::PendingApprovals)
 			}
 		}
 
-		fn set_governance(&mut self, threshold: u32, judges: Vec<AccountId>) -> Result<()> {
-			if threshold as usize > judges.len() {
+		/// Approvals past threshold only matter for fast-track
+		fn do_approve_pending(
+			&mut self,
+			approver: AccountId,
+			key: PropKey,
+			executable_at: BlockNumber,
+		) -> Result<ProposalState> {
+			let mut approvals = self.veto_approvals.get(key).unwrap_or_default();
+			if approvals.contains(&approver) {
+				return Err(Error::AlreadyApproved);
+			}
+			approvals.push(approver);
+			self.veto_approvals.insert(key, &approvals);
+			self.env().emit_event(Approved { approver, key });
+			let executable_at = self.fast_track(approver, key, &approvals, executable_at);
+			Ok(ProposalState::PendingVetoPeriod(executable_at))
+		}
+
+		/// Shortens veto period of proposal to `fast_track_period` from now if
+		/// all judges approved it. Returns block from which it can be executed.
+		fn fast_track(
+			&mut self,
+			approver: AccountId,
+			key: PropKey,
+			approvals: &[AccountId],
+			executable_at: BlockNumber,
+		) -> BlockNumber {
+			let Some(period) = self.fast_track_period else {
+				return executable_at;
+			};
+			if !self.judges.iter().all(|(judge, _)| approvals.contains(judge)) {
+				return executable_at;
+			}
+			let fast_tracked_at = self.env().block_number().saturating_add(period);
+			if fast_tracked_at >= executable_at {
+				return executable_at;
+			}
+			self.pending.insert(key, &fast_tracked_at);
+			self.env()
+				.emit_event(FastTracked { approver, key, executable_at: fast_tracked_at });
+			fast_tracked_at
+		}
+
+		fn do_execute(&mut self, executor: AccountId, key: PropKey) -> Result<ProposalState> {
+			let proposal = self.proposals.take(key).ok_or(Error::NotFound)?;
+			self.active.remove(Self::content_hash(&proposal));
+			self.metadata.remove(key);
+			let result = self.execute(proposal);
+			if let Err(Error::BatchFailed(index)) = result {
+				// returning Err reverts the whole message, including inner
+				// calls that already succeeded - batch stays pending
+				return Err(Error::BatchFailed(index));
+			}
+			// proposal made it through approvals and veto period - not frivolous,
+			// even if the call itself failed
+			self.refund_deposit(key)?;
+			self.env()
+				.emit_event(Executed { approver: executor, key, result: result.clone() });
+			Ok(ProposalState::Executed(result))
+		}
+
+		fn refund_deposit(&mut self, key: PropKey) -> Result<()> {
+			if let Some((proposer, amount)) = self.deposits.take(key) {
+				self.held_deposits = self.held_deposits.saturating_sub(amount);
+				if amount > 0 {
+					self.env().transfer(proposer, amount).map_err(|_| Error::TransferFailed)?;
+				}
+			}
+			Ok(())
+		}
+
+		fn slash_deposit(&mut self, key: PropKey) -> Result<()> {
+			if let Some((proposer, amount)) = self.deposits.take(key) {
+				self.held_deposits = self.held_deposits.saturating_sub(amount);
+				if let (Some(beneficiary), true) = (self.deposit_beneficiary, amount > 0) {
+					self.env().transfer(beneficiary, amount).map_err(|_| Error::TransferFailed)?;
+				}
+				self.env().emit_event(DepositSlashed { proposer, key, amount });
+			}
+			Ok(())
+		}
+
+		/// Takes bounty for executing `key` out of proposer's deposit, topping
+		/// it up from court's own balance if the deposit isn't enough.
+		fn take_bounty(&mut self, key: PropKey) -> Balance {
+			if self.execution_bounty == 0 {
+				return 0;
+			}
+			let own_balance = self
+				.env()
+				.balance()
+				.saturating_sub(self.held_deposits)
+				.saturating_sub(self.env().minimum_balance());
+			let mut from_deposit = 0;
+			if let Some((proposer, deposit)) = self.deposits.get(key) {
+				from_deposit = deposit.min(self.execution_bounty);
+				self.deposits.insert(key, &(proposer, deposit.saturating_sub(from_deposit)));
+				self.held_deposits = self.held_deposits.saturating_sub(from_deposit);
+			}
+			let from_court = self.execution_bounty.saturating_sub(from_deposit).min(own_balance);
+			from_deposit.saturating_add(from_court)
+		}
+
+		fn verify_judge_signature(
+			&self,
+			judge: AccountId,
+			message: &[u8],
+			signature: &JudgeSignature,
+		) -> Result<()> {
+			use ink::env::hash::{Blake2x256, HashOutput};
+			match signature {
+				JudgeSignature::Sr25519(signature) => {
+					let public_key: &[u8; 32] = judge.as_ref();
+					ink::env::sr25519_verify(signature, message, public_key)
+						.map_err(|_| Error::InvalidSignature)
+				},
+				JudgeSignature::Ecdsa(signature) => {
+					let mut message_hash = <Blake2x256 as HashOutput>::Type::default();
+					ink::env::hash_bytes::<Blake2x256>(message, &mut message_hash);
+					let mut public_key = [0u8; 33];
+					ink::env::ecdsa_recover(signature, &message_hash, &mut public_key)
+						.map_err(|_| Error::InvalidSignature)?;
+					// same derivation as MultiSigner::Ecdsa -> AccountId32
+					let mut account = <Blake2x256 as HashOutput>::Type::default();
+					ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+					if AccountId::from(account) != judge {
+						return Err(Error::InvalidSignature);
+					}
+					Ok(())
+				},
+			}
+		}
+
+		fn do_veto(&mut self, vetoer: AccountId, key: PropKey) -> Result<ProposalState> {
+			let executable_at = self.pending.get(key).ok_or(Error::NotFound)?;
+			if self.env().block_number() >= executable_at {
+				return Err(Error::VetoPeriodEnded);
+			}
+			let mut vetoes = self.vetoes.get(key).unwrap_or_default();
+			if vetoes.contains(&vetoer) {
+				return Err(Error::AlreadyVetoed);
+			}
+			vetoes.push(vetoer);
+
+			if vetoes.len() >= self.veto_threshold as usize {
+				let proposal = self.proposals.get(key).ok_or(Error::NotFound)?;
+				let archived = VetoedProposal {
+					proposal,
+					metadata: self.metadata.get(key),
+					vetoers: vetoes,
+					vetoed_at: self.env().block_number(),
+				};
+				self.vetoed_archive.insert(key, &archived);
+				self.vetoed_keys.insert(self.vetoed_count, &key);
+				self.vetoed_count = self.vetoed_count.saturating_add(1);
+				self.remove_proposal(key);
+				self.pending.remove(key);
+				self.vetoes.remove(key);
+				self.veto_approvals.remove(key);
+				self.slash_deposit(key)?;
+				self.env().emit_event(Vetoed { vetoer, key });
+				Ok(ProposalState::Vetoed)
+			} else {
+				self.vetoes.insert(key, &vetoes);
+				self.env().emit_event(VetoCast { vetoer, key });
+				Ok(ProposalState::PendingVetoPeriod(executable_at))
+			}
+		}
+
+		fn set_veto_governance(
+			&mut self,
+			veto_threshold: u32,
+			veto_authorities: Vec<AccountId>,
+			veto_period: BlockNumber,
+		) -> Result<()> {
+			if veto_threshold as usize > veto_authorities.len()
+				|| (veto_threshold == 0 && !veto_authorities.is_empty())
+			{
+				return Err(Error::InvalidParameters);
+			}
+
+			self.veto_threshold = veto_threshold;
+			self.veto_authorities = veto_authorities;
+			self.veto_period = veto_period;
+			Ok(())
+		}
+
+		fn set_governance(&mut self, threshold: u32, judges: Vec<(AccountId, u32)>) -> Result<()> {
+			let total_weight = Self::total_weight(&judges);
+			if threshold > total_weight {
+				return Err(Error::InvalidParameters);
+			}
+			if judges.iter().enumerate().any(|(index, (judge, weight))| {
+				*weight == 0 || judges[..index].iter().any(|(other, _)| other == judge)
+			}) {
+				return Err(Error::InvalidParameters);
+			}
+			// kind-specific thresholds must stay reachable with new judges too
+			if ProposalKind::ALL
+				.iter()
+				.filter_map(|kind| self.thresholds.get(kind))
+				.any(|t| t > total_weight)
+			{
 				return Err(Error::InvalidParameters);
 			}
 
@@ -124,292 +756,1336 @@ mod msig_court {
 			self.judges = judges;
 			Ok(())
 		}
+
+		fn set_thresholds(&mut self, thresholds: Vec<(ProposalKind, Option<u32>)>) -> Result<()> {
+			if thresholds
+				.iter()
+				.any(|(_, t)| matches!(t, Some(t) if *t > Self::total_weight(&self.judges)))
+			{
+				return Err(Error::InvalidParameters);
+			}
+			for (kind, threshold) in thresholds {
+				match threshold {
+					Some(threshold) => self.thresholds.insert(kind, &threshold),
+					None => self.thresholds.remove(kind),
+				};
+			}
+			Ok(())
+		}
 	}
 
 	impl MsigCourt {
 		#[ink(constructor)]
-		pub fn new(threshold: u32, judges: Vec<AccountId>) -> Self {
-			assert!(threshold as usize <= judges.len());
-			Self { threshold, judges, ..Default::default() }
+		pub fn new(threshold: u32, judges: Vec<(AccountId, u32)>) -> Self {
+			let mut court = Self::default();
+			court.set_governance(threshold, judges).expect("invalid governance");
+			court
 		}
 
-		#[ink(message)]
-		pub fn propose(&mut self, proposal: Proposal) -> Result<(PropKey, ProposalState)> {
+		/// Approved proposals wait `veto_period` blocks before they can be
+		/// executed. During that time `veto_threshold` of `veto_authorities`
+		/// can veto them.
+		#[ink(constructor)]
+		pub fn new_with_veto(
+			threshold: u32,
+			judges: Vec<(AccountId, u32)>,
+			veto_threshold: u32,
+			veto_authorities: Vec<AccountId>,
+			veto_period: BlockNumber,
+		) -> Self {
+			let mut court = Self::new(threshold, judges);
+			court
+				.set_veto_governance(veto_threshold, veto_authorities, veto_period)
+				.expect("invalid veto governance");
+			court
+		}
+
+		/// Propose new action. Transferred value is held as deposit - refunded
+		/// when proposal is executed or cancelled, slashed when it's vetoed.
+		/// `metadata` is stored with the proposal for judges' reference and
+		/// doesn't affect its key.
+		#[ink(message, payable)]
+		pub fn propose(
+			&mut self,
+			proposal: Proposal,
+			metadata: Option<Vec<u8>>,
+		) -> Result<(PropKey, ProposalState)> {
 			let caller = self.env().caller();
-			if !self.judges.contains(&caller) {
+			let is_judge = self.is_judge(&caller);
+			if !is_judge && !self.proposers.contains(&caller) {
 				return Err(Error::Unauthorized);
 			}
+			if self.paused == Some(true) {
+				return Err(Error::Paused);
+			}
+			Self::validate(&proposal)?;
+			if metadata.as_ref().is_some_and(|m| m.len() > MAX_METADATA_LEN) {
+				return Err(Error::MetadataTooLong);
+			}
+			let deposit = self.env().transferred_value();
+			if deposit < self.proposal_deposit {
+				return Err(Error::InsufficientDeposit);
+			}
 
-			let mut key =
-				<ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
-			ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&proposal, &mut key);
-
-			if self.proposals.contains(key) {
+			let content = Self::content_hash(&proposal);
+			if self.active.contains(content) {
 				return Err(Error::AlreadyExists);
 			}
+			let mut key =
+				<ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+			ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+				&(self.proposal_nonce, &proposal),
+				&mut key,
+			);
+			self.proposal_nonce = self.proposal_nonce.saturating_add(1);
 
+			self.active.insert(content, &key);
 			self.proposals.insert(key, &proposal);
+			if let Some(metadata) = &metadata {
+				self.metadata.insert(key, metadata);
+			}
 			self.approvals.insert(key, &Vec::<AccountId>::new());
-			self.env().emit_event(Proposed { proposer: caller, key, proposal });
+			self.deposits.insert(key, &(caller, deposit));
+			self.held_deposits = self.held_deposits.saturating_add(deposit);
+			self.env().emit_event(Proposed { proposer: caller, key, proposal, metadata });
+			if !is_judge {
+				return Ok((key, ProposalState::PendingApprovals));
+			}
 			let state = self.do_approve(caller, key)?;
 			Ok((key, state))
 		}
 
+		/// Approve proposal. Proposals in veto period can still be approved
+		/// by remaining judges to fast-track them.
 		#[ink(message)]
 		pub fn approve(&mut self, key: PropKey) -> Result<ProposalState> {
 			let caller = self.env().caller();
-			if !self.judges.contains(&caller) {
+			if !self.is_judge(&caller) {
 				return Err(Error::Unauthorized);
 			}
 			self.do_approve(caller, key)
 		}
 
+		/// Approve on behalf of `judge`, e.g. by a relayer for judges signing on
+		/// air-gapped machines. `signature` must be judge's signature over
+		/// SCALE-encoded `(contract_address, key, nonce)`, where `nonce` is
+		/// `get_nonce(judge)`. Nonce is incremented on success.
 		#[ink(message)]
-		pub fn get_threshold(&self) -> u32 {
-			self.threshold
+		pub fn approve_with_signature(
+			&mut self,
+			key: PropKey,
+			judge: AccountId,
+			signature: JudgeSignature,
+		) -> Result<ProposalState> {
+			if !self.is_judge(&judge) {
+				return Err(Error::Unauthorized);
+			}
+			let nonce = self.nonces.get(judge).unwrap_or_default();
+			let message = ink::scale::Encode::encode(&(self.env().account_id(), key, nonce));
+			self.verify_judge_signature(judge, &message, &signature)?;
+			self.nonces.insert(judge, &nonce.saturating_add(1));
+			self.do_approve(judge, key)
 		}
 
+		/// Nonce that must be signed in next `approve_with_signature` of `judge`
 		#[ink(message)]
-		pub fn get_judges(&self) -> Vec<AccountId> {
-			self.judges.clone()
+		pub fn get_nonce(&self, judge: AccountId) -> u64 {
+			self.nonces.get(judge).unwrap_or_default()
 		}
 
+		/// Cancel proposal that's still waiting for approvals and refund its
+		/// deposit. Can only be called by the proposer.
 		#[ink(message)]
-		pub fn get_proposal(&self, key: PropKey) -> Option<(Proposal, Vec<AccountId>)> {
-			Some((self.proposals.get(key)?, self.approvals.get(key)?))
+		pub fn cancel(&mut self, key: PropKey) -> Result<()> {
+			let caller = self.env().caller();
+			let (proposer, _) = self.deposits.get(key).ok_or(Error::NotFound)?;
+			if proposer != caller {
+				return Err(Error::Unauthorized);
+			}
+			if !self.approvals.contains(key) {
+				// already in veto period
+				return Err(Error::NotFound);
+			}
+			self.remove_proposal(key);
+			self.approvals.remove(key);
+			self.refund_deposit(key)?;
+			self.env().emit_event(Cancelled { proposer, key });
+			Ok(())
 		}
-	}
 
-	#[cfg(test)]
-	mod tests {
-		use super::*;
-		use crate::mock::*;
-		use liberland_extension::LLMAccount;
+		/// Veto proposal in veto period. Proposal is dropped once
+		/// `veto_threshold` vetoes are collected.
+		#[ink(message)]
+		pub fn veto(&mut self, key: PropKey) -> Result<ProposalState> {
+			let caller = self.env().caller();
+			if !self.veto_authorities.contains(&caller) {
+				return Err(Error::Unauthorized);
+			}
+			self.do_veto(caller, key)
+		}
 
-		fn alice() -> AccountId {
-			ink::env::test::default_accounts::<Environment>().alice
+		/// Freeze the court, e.g. during key compromise. Blocks
+		/// `execute_pending` and, if `block_proposals` is set, `propose`.
+		/// Proposals approved while paused wait for `execute_pending`, even
+		/// if veto period is 0. Pending proposals can still be vetoed. Can be
+		/// called by any veto authority.
+		#[ink(message)]
+		pub fn pause(&mut self, block_proposals: bool) -> Result<()> {
+			let caller = self.env().caller();
+			if !self.veto_authorities.contains(&caller) {
+				return Err(Error::Unauthorized);
+			}
+			self.paused = Some(block_proposals);
+			self.env().emit_event(Paused { authority: caller, block_proposals });
+			Ok(())
 		}
 
-		fn bob() -> AccountId {
-			ink::env::test::default_accounts::<Environment>().bob
+		/// Lift the pause. Can be called by any veto authority.
+		#[ink(message)]
+		pub fn unpause(&mut self) -> Result<()> {
+			let caller = self.env().caller();
+			if !self.veto_authorities.contains(&caller) {
+				return Err(Error::Unauthorized);
+			}
+			if self.paused.take().is_none() {
+				return Err(Error::InvalidParameters);
+			}
+			self.env().emit_event(Unpaused { authority: caller });
+			Ok(())
 		}
 
-		fn charlie() -> AccountId {
-			ink::env::test::default_accounts::<Environment>().charlie
+		/// Returns `Some(block_proposals)` while paused
+		#[ink(message)]
+		pub fn get_paused(&self) -> Option<bool> {
+			self.paused
 		}
 
-		fn set_next_caller(caller: AccountId) {
-			ink::env::test::set_caller::<Environment>(caller);
+		/// Execute proposal after its veto period ended. Can be called by anyone.
+		#[ink(message)]
+		pub fn execute_pending(&mut self, key: PropKey) -> Result<ProposalState> {
+			if self.paused.is_some() {
+				return Err(Error::Paused);
+			}
+			let executable_at = self.pending.get(key).ok_or(Error::NotFound)?;
+			if self.env().block_number() < executable_at {
+				return Err(Error::VetoPeriodNotEnded);
+			}
+			self.pending.remove(key);
+			self.vetoes.remove(key);
+			self.veto_approvals.remove(key);
+			let caller = self.env().caller();
+			let bounty = self.take_bounty(key);
+			let state = self.do_execute(caller, key)?;
+			if bounty > 0 {
+				self.env().transfer(caller, bounty).map_err(|_| Error::TransferFailed)?;
+				self.env().emit_event(BountyPaid { executor: caller, key, amount: bounty });
+			}
+			Ok(state)
 		}
 
-		fn assert_proposed_event(
-			event: &ink::env::test::EmittedEvent,
-			expected_proposer: AccountId,
-			expected_key: PropKey,
-			expected_proposal: Proposal,
-		) {
-			let decoded_event = <Proposed as ink::scale::Decode>::decode(&mut &event.data[..])
-				.expect("encountered invalid contract event data buffer");
-			let Proposed { proposer, key, proposal } = decoded_event;
-			assert_eq!(proposer, expected_proposer);
-			assert_eq!(key, expected_key);
-			assert_eq!(proposal, expected_proposal);
+		/// Execute `proposal` right away, skipping approvals and veto period.
+		/// Only the contract itself is allowed as the caller, which can't
+		/// happen in a transaction - it's meant for the runtime's proposal
+		/// dry-run API, which calls it with the contract's account as origin
+		/// and discards resulting state.
+		#[ink(message, selector = 0x53494d55)]
+		pub fn simulate(&mut self, proposal: Proposal) -> Result<()> {
+			if self.env().caller() != self.env().account_id() {
+				return Err(Error::Unauthorized);
+			}
+			Self::validate(&proposal)?;
+			self.execute(proposal)
 		}
 
-		fn assert_approved_event(
-			event: &ink::env::test::EmittedEvent,
-			expected_approver: AccountId,
-			expected_key: PropKey,
-		) {
-			let decoded_event = <Approved as ink::scale::Decode>::decode(&mut &event.data[..])
-				.expect("encountered invalid contract event data buffer");
-			let Approved { approver, key } = decoded_event;
-			assert_eq!(approver, expected_approver);
-			assert_eq!(key, expected_key);
+		#[ink(message)]
+		pub fn get_threshold(&self) -> u32 {
+			self.threshold
 		}
 
-		fn assert_executed_event(
-			event: &ink::env::test::EmittedEvent,
-			expected_approver: AccountId,
-			expected_key: PropKey,
-			expected_result: Result<()>,
-		) {
-			let decoded_event = <Executed as ink::scale::Decode>::decode(&mut &event.data[..])
-				.expect("encountered invalid contract event data buffer");
-			let Executed { approver, key, result } = decoded_event;
-			assert_eq!(approver, expected_approver);
-			assert_eq!(key, expected_key);
+		/// Threshold that applies to proposals of given kind
+		#[ink(message)]
+		pub fn get_threshold_of(&self, kind: ProposalKind) -> u32 {
+			self.threshold_of(kind)
+		}
+
+		/// Judges and weights of their approvals
+		#[ink(message)]
+		pub fn get_judges(&self) -> Vec<(AccountId, u32)> {
+			self.judges.clone()
+		}
+
+		/// Non-judges allowed to propose
+		#[ink(message)]
+		pub fn get_proposers(&self) -> Vec<AccountId> {
+			self.proposers.clone()
+		}
+
+		/// Key of active proposal with the same contents, if any
+		#[ink(message)]
+		pub fn find_active(&self, proposal: Proposal) -> Option<PropKey> {
+			self.active.get(Self::content_hash(&proposal))
+		}
+
+		/// Nonce mixed into key of the next proposal
+		#[ink(message)]
+		pub fn get_proposal_nonce(&self) -> u64 {
+			self.proposal_nonce
+		}
+
+		/// Proposal waiting for approvals, with approvals so far and metadata
+		#[ink(message)]
+		pub fn get_proposal(
+			&self,
+			key: PropKey,
+		) -> Option<(Proposal, Vec<AccountId>, Option<Vec<u8>>)> {
+			Some((self.proposals.get(key)?, self.approvals.get(key)?, self.metadata.get(key)))
+		}
+
+		#[ink(message)]
+		pub fn get_proposal_deposit(&self) -> Balance {
+			self.proposal_deposit
+		}
+
+		#[ink(message)]
+		pub fn get_deposit_beneficiary(&self) -> Option<AccountId> {
+			self.deposit_beneficiary
+		}
+
+		/// Returns proposer and deposit held for proposal
+		#[ink(message)]
+		pub fn get_deposit(&self, key: PropKey) -> Option<(AccountId, Balance)> {
+			self.deposits.get(key)
+		}
+
+		#[ink(message)]
+		pub fn get_execution_bounty(&self) -> Balance {
+			self.execution_bounty
+		}
+
+		#[ink(message)]
+		pub fn get_veto_threshold(&self) -> u32 {
+			self.veto_threshold
+		}
+
+		#[ink(message)]
+		pub fn get_veto_authorities(&self) -> Vec<AccountId> {
+			self.veto_authorities.clone()
+		}
+
+		#[ink(message)]
+		pub fn get_veto_period(&self) -> BlockNumber {
+			self.veto_period
+		}
+
+		#[ink(message)]
+		pub fn get_fast_track_period(&self) -> Option<BlockNumber> {
+			self.fast_track_period
+		}
+
+		/// Judges that approved proposal in veto period
+		#[ink(message)]
+		pub fn get_veto_approvals(&self, key: PropKey) -> Option<Vec<AccountId>> {
+			self.veto_approvals.get(key)
+		}
+
+		/// Vetoed proposal with its metadata, vetoers and block it was vetoed at
+		#[ink(message)]
+		pub fn get_vetoed(&self, key: PropKey) -> Option<VetoedProposal> {
+			self.vetoed_archive.get(key)
+		}
+
+		/// Keys of vetoed proposals, oldest first, starting at `start`-th one.
+		/// Returns at most `MAX_PAGE_LEN` keys.
+		#[ink(message)]
+		pub fn get_vetoed_keys(&self, start: u32, count: u32) -> Vec<PropKey> {
+			let end = start.saturating_add(count.min(MAX_PAGE_LEN)).min(self.vetoed_count);
+			(start..end).filter_map(|index| self.vetoed_keys.get(index)).collect()
+		}
+
+		/// Number of vetoed proposals
+		#[ink(message)]
+		pub fn get_vetoed_count(&self) -> u32 {
+			self.vetoed_count
+		}
+
+		/// Returns proposal in veto period, block from which it can be executed
+		/// and vetoes cast so far.
+		#[ink(message)]
+		pub fn get_pending(&self, key: PropKey) -> Option<(Proposal, BlockNumber, Vec<AccountId>)> {
+			Some((self.proposals.get(key)?, self.pending.get(key)?, self.vetoes.get(key)?))
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::mock::*;
+		use liberland_extension::LLMAccount;
+
+		fn alice() -> AccountId {
+			ink::env::test::default_accounts::<Environment>().alice
+		}
+
+		fn bob() -> AccountId {
+			ink::env::test::default_accounts::<Environment>().bob
+		}
+
+		fn charlie() -> AccountId {
+			ink::env::test::default_accounts::<Environment>().charlie
+		}
+
+		fn set_next_caller(caller: AccountId) {
+			ink::env::test::set_caller::<Environment>(caller);
+		}
+
+		fn assert_proposed_event(
+			event: &ink::env::test::EmittedEvent,
+			expected_proposer: AccountId,
+			expected_key: PropKey,
+			expected_proposal: Proposal,
+		) {
+			let decoded_event = <Proposed as ink::scale::Decode>::decode(&mut &event.data[..])
+				.expect("encountered invalid contract event data buffer");
+			let Proposed { proposer, key, proposal, .. } = decoded_event;
+			assert_eq!(proposer, expected_proposer);
+			assert_eq!(key, expected_key);
+			assert_eq!(proposal, expected_proposal);
+		}
+
+		fn assert_approved_event(
+			event: &ink::env::test::EmittedEvent,
+			expected_approver: AccountId,
+			expected_key: PropKey,
+		) {
+			let decoded_event = <Approved as ink::scale::Decode>::decode(&mut &event.data[..])
+				.expect("encountered invalid contract event data buffer");
+			let Approved { approver, key } = decoded_event;
+			assert_eq!(approver, expected_approver);
+			assert_eq!(key, expected_key);
+		}
+
+		fn assert_executed_event(
+			event: &ink::env::test::EmittedEvent,
+			expected_approver: AccountId,
+			expected_key: PropKey,
+			expected_result: Result<()>,
+		) {
+			let decoded_event = <Executed as ink::scale::Decode>::decode(&mut &event.data[..])
+				.expect("encountered invalid contract event data buffer");
+			let Executed { approver, key, result } = decoded_event;
+			assert_eq!(approver, expected_approver);
+			assert_eq!(key, expected_key);
 			assert_eq!(result, expected_result);
 		}
 
 		#[ink::test]
-		fn new_works() {
-			let msig_court = MsigCourt::new(1, vec![alice()]);
-			assert_eq!(msig_court.threshold, 1);
-			assert_eq!(msig_court.judges[0], alice());
+		fn new_works() {
+			let msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			assert_eq!(msig_court.threshold, 1);
+			assert_eq!(msig_court.judges[0].0, alice());
+			assert_eq!(msig_court.judges.len(), 1);
+
+			let msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			assert_eq!(msig_court.threshold, 2);
+			assert_eq!(msig_court.judges[0].0, alice());
+			assert_eq!(msig_court.judges[1].0, bob());
+			assert_eq!(msig_court.judges[2].0, charlie());
+			assert_eq!(msig_court.judges.len(), 3);
+		}
+
+		#[ink::test]
+		#[should_panic]
+		fn new_prevents_bricking() {
+			MsigCourt::new(2, vec![(alice(), 1)]);
+		}
+
+		#[ink::test]
+		fn propose_executes_immediately_with_threshold_1() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetGovernance {
+						threshold: 2,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+		}
+
+		#[ink::test]
+		fn must_be_a_judge_to_propose() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(bob());
+			let res = msig_court.propose(
+				Proposal::SetGovernance { threshold: 2, judges: vec![(alice(), 1), (bob(), 1)] },
+				None,
+			);
+			assert_eq!(res, Err(Error::Unauthorized));
+		}
+
+		#[ink::test]
+		fn proposers_can_propose_without_approving() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			msig_court
+				.propose(Proposal::SetProposers(vec![bob()]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(msig_court.get_proposers(), vec![bob()]);
+
+			set_next_caller(bob());
+			let (key, state) = msig_court
+				.propose(Proposal::SetExecutionBounty(1), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			assert_eq!(msig_court.approve(key), Err(Error::Unauthorized));
+			set_next_caller(alice());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::Executed(Ok(()))));
+		}
+
+		#[ink::test]
+		fn propose_doesnt_execute_with_threshold_2() {
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] };
+			let (key, state) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			assert_eq!(msig_court.proposals.get(&key), Some(proposal));
+			assert_eq!(msig_court.approvals.get(&key), Some(vec![alice()]));
+		}
+
+		#[ink::test]
+		fn cant_duplicate_proposals() {
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] };
+			let (_, state) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+
+			let res = msig_court.propose(proposal.clone(), None);
+			assert_eq!(res, Err(Error::AlreadyExists));
+		}
+
+		#[ink::test]
+		fn identical_proposals_get_distinct_keys() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] };
+			let (first, state) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.find_active(proposal.clone()), None);
+
+			let (second, state) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_ne!(first, second);
+			assert_eq!(msig_court.get_proposal_nonce(), 2);
+		}
+
+		#[ink::test]
+		fn find_active_works() {
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			set_next_caller(alice());
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] };
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(msig_court.find_active(proposal.clone()), Some(key));
+
+			assert_eq!(msig_court.cancel(key), Ok(()));
+			assert_eq!(msig_court.find_active(proposal.clone()), None);
+			let (new_key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_ne!(key, new_key);
+		}
+
+		#[ink::test]
+		fn approve_works() {
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
+				.expect("propose shouldnt fail");
+
+			set_next_caller(bob());
+			let res = msig_court.approve(key);
+			assert_eq!(res, Ok(ProposalState::PendingApprovals));
+			assert_eq!(msig_court.approvals.get(&key), Some(vec![alice(), bob()]))
+		}
+
+		#[ink::test]
+		fn cant_double_approve() {
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
+				.expect("propose shouldnt fail");
+
+			let res = msig_court.approve(key);
+			assert_eq!(res, Err(Error::AlreadyApproved));
+		}
+
+		#[ink::test]
+		fn must_be_a_judge_to_approve() {
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
+				.expect("propose shouldnt fail");
+
+			set_next_caller(charlie());
+			let res = msig_court.approve(key);
+			assert_eq!(res, Err(Error::Unauthorized));
+		}
+
+		#[ink::test]
+		fn set_governance_works() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetGovernance {
+						threshold: 2,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.threshold, 2);
+			assert_eq!(msig_court.judges[0].0, alice());
+			assert_eq!(msig_court.judges[1].0, bob());
+			assert_eq!(msig_court.judges.len(), 2);
+		}
+
+		#[ink::test]
+		fn set_governance_prevents_bricking() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetGovernance {
+						threshold: 3,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+			assert_eq!(msig_court.threshold, 1);
+			assert_eq!(msig_court.judges[0].0, alice());
+			assert_eq!(msig_court.judges.len(), 1);
+		}
+
+		#[ink::test]
+		fn llm_force_transfer_works() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::LLMForceTransfer(LLMForceTransferArguments {
+						from: LLMAccount::Locked(alice()),
+						to: LLMAccount::Locked(bob()),
+						amount: 1u8.into(),
+					}),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+		}
+
+		#[ink::test]
+		fn llm_force_transfer_propagates_errors() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::LLMForceTransfer(LLMForceTransferArguments {
+						from: LLMAccount::Locked(alice()),
+						to: LLMAccount::Locked(bob()),
+						amount: 1u8.into(),
+					}),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(
+				state,
+				ProposalState::Executed(Err(Error::Extension(liberland_extension::Error::Failed)))
+			);
+		}
+
+		#[ink::test]
+		fn lld_force_transfer_goes_through_veto_period() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = new_with_veto();
+			let proposal =
+				Proposal::LLDForceTransfer(LLDForceTransferArguments { to: bob(), amount: 10 });
+			set_next_caller(alice());
+			let (key, state) = msig_court.propose(proposal, None).expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingVetoPeriod(10));
+
+			advance_blocks(10);
+			set_next_caller(bob());
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+		}
+
+		#[ink::test]
+		fn lld_force_transfer_propagates_errors() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::LLDForceTransfer(LLDForceTransferArguments {
+						to: bob(),
+						amount: 1u8.into(),
+					}),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(
+				state,
+				ProposalState::Executed(Err(Error::Extension(liberland_extension::Error::Failed)))
+			);
+		}
+
+		#[ink::test]
+		fn revoke_citizenship_executes_after_veto_period() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = new_with_veto();
+			set_next_caller(alice());
+			let (key, state) = msig_court
+				.propose(Proposal::RevokeCitizenship(bob()), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingVetoPeriod(10));
+
+			advance_blocks(9);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::VetoPeriodNotEnded));
+			advance_blocks(1);
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+		}
+
+		#[ink::test]
+		fn revoke_citizenship_propagates_errors() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::RevokeCitizenship(bob()), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(
+				state,
+				ProposalState::Executed(Err(Error::Extension(liberland_extension::Error::Failed)))
+			);
+		}
+
+		#[ink::test]
+		fn revoke_can_be_vetoed() {
+			let mut msig_court = new_with_veto();
+			set_next_caller(alice());
+			let (key, state) = msig_court
+				.propose(Proposal::Revoke(bob()), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingVetoPeriod(10));
+
+			set_next_caller(charlie());
+			msig_court.veto(key).expect("veto shouldnt fail");
+			set_next_caller(dave());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::Vetoed));
+			advance_blocks(10);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::NotFound));
+		}
+
+		fn new_with_fast_track() -> MsigCourt {
+			let mut msig_court = MsigCourt::new_with_veto(
+				1,
+				vec![(alice(), 1), (bob(), 1)],
+				2,
+				vec![charlie(), dave(), eve()],
+				10,
+			);
+			msig_court.fast_track_period = Some(3);
+			msig_court
+		}
+
+		#[ink::test]
+		fn unanimous_approval_shortens_veto_period() {
+			let mut msig_court = new_with_fast_track();
+			let key = propose_in_veto_period(&mut msig_court);
+
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::PendingVetoPeriod(3)));
+			assert_eq!(msig_court.approve(key), Err(Error::AlreadyApproved));
+			assert_eq!(msig_court.get_veto_approvals(key), Some(vec![alice(), bob()]));
+
+			advance_blocks(2);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::VetoPeriodNotEnded));
+			advance_blocks(1);
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(msig_court.get_veto_approvals(key), None);
+		}
+
+		#[ink::test]
+		fn fast_tracked_proposal_can_be_vetoed() {
+			let mut msig_court = new_with_fast_track();
+			let key = propose_in_veto_period(&mut msig_court);
+			set_next_caller(bob());
+			msig_court.approve(key).expect("approve shouldnt fail");
+
+			advance_blocks(2);
+			set_next_caller(charlie());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::PendingVetoPeriod(3)));
+			set_next_caller(dave());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::Vetoed));
+			assert_eq!(msig_court.get_veto_approvals(key), None);
+		}
+
+		#[ink::test]
+		fn approvals_in_veto_period_dont_fast_track_when_disabled() {
+			let mut msig_court = new_with_fast_track();
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetFastTrack(None), None)
+				.expect("propose shouldnt fail");
+			advance_blocks(10);
+			msig_court.execute_pending(key).expect("execute shouldnt fail");
+			assert_eq!(msig_court.get_fast_track_period(), None);
+
+			let (key, _) = msig_court
+				.propose(Proposal::SetExecutionBounty(1), None)
+				.expect("propose shouldnt fail");
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::PendingVetoPeriod(20)));
+		}
+
+		#[ink::test]
+		fn runtime_call_works() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::RuntimeCall(vec![1, 2, 3]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+		}
+
+		#[ink::test]
+		fn runtime_call_propagates_errors() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::RuntimeCall(vec![1, 2, 3]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(
+				state,
+				ProposalState::Executed(Err(Error::Extension(liberland_extension::Error::Failed)))
+			);
+		}
+
+		#[ink::test]
+		fn batch_works() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::Batch(vec![
+						Proposal::RuntimeCall(vec![1, 2, 3]),
+						Proposal::SetGovernance {
+							threshold: 2,
+							judges: vec![(alice(), 1), (bob(), 1)],
+						},
+					]),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.threshold, 2);
+		}
+
+		#[ink::test]
+		fn batch_reports_failed_index() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let res = msig_court.propose(
+				Proposal::Batch(vec![
+					Proposal::SetGovernance {
+						threshold: 1,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					Proposal::RuntimeCall(vec![1, 2, 3]),
+				]),
+				None,
+			);
+			assert_eq!(res, Err(Error::BatchFailed(1)));
+		}
+
+		#[ink::test]
+		fn llm_force_transfer_batch_uses_single_call() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let transfer = |amount: u8| {
+				Proposal::LLMForceTransfer(LLMForceTransferArguments {
+					from: LLMAccount::Locked(alice()),
+					to: LLMAccount::Liquid(bob()),
+					amount: amount.into(),
+				})
+			};
+			let (_, state) = msig_court
+				.propose(Proposal::Batch(vec![transfer(1), transfer(2)]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+
+			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+			let res = msig_court.propose(Proposal::Batch(vec![transfer(3), transfer(4)]), None);
+			assert_eq!(res, Err(Error::BatchFailed(0)));
+		}
+
+		#[ink::test]
+		fn batch_is_bounded() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			assert_eq!(
+				msig_court.propose(Proposal::Batch(vec![]), None),
+				Err(Error::InvalidParameters)
+			);
+
+			let too_long = vec![Proposal::RuntimeCall(vec![]); MAX_BATCH_LEN + 1];
+			assert_eq!(
+				msig_court.propose(Proposal::Batch(too_long), None),
+				Err(Error::InvalidParameters)
+			);
+
+			let nested = vec![Proposal::Batch(vec![Proposal::RuntimeCall(vec![])])];
+			assert_eq!(
+				msig_court.propose(Proposal::Batch(nested), None),
+				Err(Error::InvalidParameters)
+			);
+		}
+
+		fn dave() -> AccountId {
+			ink::env::test::default_accounts::<Environment>().django
+		}
+
+		fn eve() -> AccountId {
+			ink::env::test::default_accounts::<Environment>().eve
+		}
+
+		fn advance_blocks(n: u32) {
+			for _ in 0..n {
+				ink::env::test::advance_block::<Environment>();
+			}
+		}
+
+		fn new_with_veto() -> MsigCourt {
+			MsigCourt::new_with_veto(1, vec![(alice(), 1)], 2, vec![charlie(), dave(), eve()], 10)
+		}
+
+		fn propose_in_veto_period(msig_court: &mut MsigCourt) -> PropKey {
+			set_next_caller(alice());
+			let (key, state) = msig_court
+				.propose(
+					Proposal::SetGovernance {
+						threshold: 1,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingVetoPeriod(10));
+			key
+		}
+
+		#[ink::test]
+		fn approved_proposal_waits_for_veto_period() {
+			let mut msig_court = new_with_veto();
+			let key = propose_in_veto_period(&mut msig_court);
 			assert_eq!(msig_court.judges.len(), 1);
+			assert_eq!(msig_court.get_pending(key).map(|p| p.1), Some(10));
 
-			let msig_court = MsigCourt::new(2, vec![alice(), bob(), charlie()]);
-			assert_eq!(msig_court.threshold, 2);
-			assert_eq!(msig_court.judges[0], alice());
-			assert_eq!(msig_court.judges[1], bob());
-			assert_eq!(msig_court.judges[2], charlie());
-			assert_eq!(msig_court.judges.len(), 3);
+			advance_blocks(9);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::VetoPeriodNotEnded));
+			advance_blocks(1);
+			set_next_caller(bob());
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(msig_court.judges.len(), 2);
+			assert_eq!(msig_court.get_pending(key), None);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::NotFound));
 		}
 
 		#[ink::test]
-		#[should_panic]
-		fn new_prevents_bricking() {
-			MsigCourt::new(2, vec![alice()]);
+		fn veto_requires_threshold() {
+			let mut msig_court = new_with_veto();
+			let key = propose_in_veto_period(&mut msig_court);
+
+			set_next_caller(charlie());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::PendingVetoPeriod(10)));
+			assert_eq!(msig_court.veto(key), Err(Error::AlreadyVetoed));
+			assert_eq!(msig_court.get_pending(key).map(|p| p.2), Some(vec![charlie()]));
+
+			set_next_caller(dave());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::Vetoed));
+			assert_eq!(msig_court.get_pending(key), None);
+
+			advance_blocks(10);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::NotFound));
+			assert_eq!(msig_court.judges.len(), 1);
 		}
 
 		#[ink::test]
-		fn propose_executes_immediately_with_threshold_1() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+		fn vetoed_proposals_are_archived() {
+			let mut msig_court = new_with_veto();
 			set_next_caller(alice());
-			let (_, state) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] })
+			let proposal = Proposal::SetExecutionBounty(1);
+			let (key, _) = msig_court
+				.propose(proposal.clone(), Some(b"case 7".to_vec()))
 				.expect("propose shouldnt fail");
+			assert_eq!(msig_court.get_vetoed(key), None);
 
-			assert_eq!(state, ProposalState::Executed(Ok(())));
+			advance_blocks(2);
+			set_next_caller(charlie());
+			msig_court.veto(key).expect("veto shouldnt fail");
+			set_next_caller(dave());
+			msig_court.veto(key).expect("veto shouldnt fail");
+
+			assert_eq!(
+				msig_court.get_vetoed(key),
+				Some(VetoedProposal {
+					proposal,
+					metadata: Some(b"case 7".to_vec()),
+					vetoers: vec![charlie(), dave()],
+					vetoed_at: 2,
+				})
+			);
+			assert_eq!(msig_court.get_proposal(key), None);
 		}
 
 		#[ink::test]
-		fn must_be_a_judge_to_propose() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
-			set_next_caller(bob());
-			let res = msig_court
-				.propose(Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] });
-			assert_eq!(res, Err(Error::Unauthorized));
+		fn vetoed_keys_are_paginated() {
+			let mut msig_court = new_with_veto();
+			let mut keys = vec![];
+			for bounty in 0..3 {
+				set_next_caller(alice());
+				let (key, _) = msig_court
+					.propose(Proposal::SetExecutionBounty(bounty), None)
+					.expect("propose shouldnt fail");
+				set_next_caller(charlie());
+				msig_court.veto(key).expect("veto shouldnt fail");
+				set_next_caller(dave());
+				msig_court.veto(key).expect("veto shouldnt fail");
+				keys.push(key);
+			}
+
+			assert_eq!(msig_court.get_vetoed_count(), 3);
+			assert_eq!(msig_court.get_vetoed_keys(0, 2), keys[..2].to_vec());
+			assert_eq!(msig_court.get_vetoed_keys(2, 2), keys[2..].to_vec());
+			assert_eq!(msig_court.get_vetoed_keys(3, 2), Vec::<PropKey>::new());
+			assert_eq!(msig_court.get_vetoed_keys(0, u32::MAX), keys);
 		}
 
 		#[ink::test]
-		fn propose_doesnt_execute_with_threshold_2() {
-			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+		fn must_be_veto_authority_to_veto() {
+			let mut msig_court = new_with_veto();
+			let key = propose_in_veto_period(&mut msig_court);
 			set_next_caller(alice());
-			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
-			let (key, state) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
-			assert_eq!(state, ProposalState::PendingApprovals);
-			assert_eq!(msig_court.proposals.get(&key), Some(proposal));
-			assert_eq!(msig_court.approvals.get(&key), Some(vec![alice()]));
+			assert_eq!(msig_court.veto(key), Err(Error::Unauthorized));
 		}
 
 		#[ink::test]
-		fn cant_duplicate_proposals() {
-			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
-			set_next_caller(alice());
-			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![alice()] };
-			let (_, state) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
-			assert_eq!(state, ProposalState::PendingApprovals);
-
-			let res = msig_court.propose(proposal.clone());
-			assert_eq!(res, Err(Error::AlreadyExists));
+		fn cant_veto_after_veto_period() {
+			let mut msig_court = new_with_veto();
+			let key = propose_in_veto_period(&mut msig_court);
+			advance_blocks(10);
+			set_next_caller(charlie());
+			assert_eq!(msig_court.veto(key), Err(Error::VetoPeriodEnded));
 		}
 
 		#[ink::test]
-		fn approve_works() {
-			let mut msig_court = MsigCourt::new(3, vec![alice(), bob(), charlie()]);
+		fn set_veto_governance_works() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
 			set_next_caller(alice());
-			let (key, _) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![alice()] })
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetVetoGovernance {
+						veto_threshold: 1,
+						veto_authorities: vec![charlie()],
+						veto_period: 5,
+					},
+					None,
+				)
 				.expect("propose shouldnt fail");
-
-			set_next_caller(bob());
-			let res = msig_court.approve(key);
-			assert_eq!(res, Ok(ProposalState::PendingApprovals));
-			assert_eq!(msig_court.approvals.get(&key), Some(vec![alice(), bob()]))
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.get_veto_threshold(), 1);
+			assert_eq!(msig_court.get_veto_authorities(), vec![charlie()]);
+			assert_eq!(msig_court.get_veto_period(), 5);
 		}
 
 		#[ink::test]
-		fn cant_double_approve() {
-			let mut msig_court = MsigCourt::new(3, vec![alice(), bob(), charlie()]);
+		fn set_veto_governance_validates_threshold() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
 			set_next_caller(alice());
-			let (key, _) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![alice()] })
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetVetoGovernance {
+						veto_threshold: 2,
+						veto_authorities: vec![charlie()],
+						veto_period: 5,
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetVetoGovernance {
+						veto_threshold: 0,
+						veto_authorities: vec![charlie()],
+						veto_period: 5,
+					},
+					None,
+				)
 				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+		}
 
-			let res = msig_court.approve(key);
-			assert_eq!(res, Err(Error::AlreadyApproved));
+		fn balance_of(account: AccountId) -> Balance {
+			ink::env::test::get_account_balance::<Environment>(account).unwrap_or(0)
 		}
 
-		#[ink::test]
-		fn must_be_a_judge_to_approve() {
-			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
+		/// Court requiring deposit of 10, vetoed deposits go to frank
+		fn new_with_deposits(threshold: u32) -> MsigCourt {
+			let accounts = ink::env::test::default_accounts::<Environment>();
+			let mut msig_court = MsigCourt::new_with_veto(
+				threshold,
+				vec![(alice(), 1), (bob(), 1)],
+				1,
+				vec![charlie()],
+				10,
+			);
+			msig_court.proposal_deposit = 10;
+			msig_court.deposit_beneficiary = Some(accounts.frank);
+			let contract_id = ink::env::test::callee::<Environment>();
+			ink::env::test::set_account_balance::<Environment>(contract_id, 10);
+			msig_court
+		}
+
+		fn propose_with_deposit(msig_court: &mut MsigCourt, deposit: Balance) -> Result<PropKey> {
 			set_next_caller(alice());
-			let (key, _) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![alice()] })
-				.expect("propose shouldnt fail");
+			ink::env::test::set_value_transferred::<Environment>(deposit);
+			let res = msig_court.propose(
+				Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1), (bob(), 1)] },
+				None,
+			);
+			ink::env::test::set_value_transferred::<Environment>(0);
+			res.map(|(key, _)| key)
+		}
+
+		#[ink::test]
+		fn propose_requires_deposit() {
+			let mut msig_court = new_with_deposits(1);
+			assert_eq!(propose_with_deposit(&mut msig_court, 9), Err(Error::InsufficientDeposit));
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+			assert_eq!(msig_court.get_deposit(key), Some((alice(), 10)));
+		}
+
+		#[ink::test]
+		fn deposit_refunded_on_execution() {
+			let mut msig_court = new_with_deposits(1);
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+			advance_blocks(10);
+			let before = balance_of(alice());
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(balance_of(alice()), before + 10);
+			assert_eq!(msig_court.get_deposit(key), None);
+		}
 
+		#[ink::test]
+		fn deposit_slashed_on_veto() {
+			let mut msig_court = new_with_deposits(1);
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+			let frank = ink::env::test::default_accounts::<Environment>().frank;
+			let before = balance_of(frank);
 			set_next_caller(charlie());
-			let res = msig_court.approve(key);
-			assert_eq!(res, Err(Error::Unauthorized));
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::Vetoed));
+			assert_eq!(balance_of(frank), before + 10);
+			assert_eq!(msig_court.get_deposit(key), None);
 		}
 
 		#[ink::test]
-		fn set_governance_works() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+		fn cancel_refunds_deposit() {
+			let mut msig_court = new_with_deposits(2);
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+
+			set_next_caller(bob());
+			assert_eq!(msig_court.cancel(key), Err(Error::Unauthorized));
+
+			let before = balance_of(alice());
 			set_next_caller(alice());
-			let (_, state) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] })
-				.expect("propose shouldnt fail");
-			assert_eq!(state, ProposalState::Executed(Ok(())));
-			assert_eq!(msig_court.threshold, 2);
-			assert_eq!(msig_court.judges[0], alice());
-			assert_eq!(msig_court.judges[1], bob());
-			assert_eq!(msig_court.judges.len(), 2);
+			assert_eq!(msig_court.cancel(key), Ok(()));
+			assert_eq!(balance_of(alice()), before + 10);
+			assert_eq!(msig_court.get_proposal(key), None);
+			assert_eq!(msig_court.cancel(key), Err(Error::NotFound));
 		}
 
 		#[ink::test]
-		fn set_governance_prevents_bricking() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+		fn cant_cancel_in_veto_period() {
+			let mut msig_court = new_with_deposits(1);
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
 			set_next_caller(alice());
-			let (_, state) = msig_court
-				.propose(Proposal::SetGovernance { threshold: 3, judges: vec![alice(), bob()] })
-				.expect("propose shouldnt fail");
-			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
-			assert_eq!(msig_court.threshold, 1);
-			assert_eq!(msig_court.judges[0], alice());
-			assert_eq!(msig_court.judges.len(), 1);
+			assert_eq!(msig_court.cancel(key), Err(Error::NotFound));
 		}
 
 		#[ink::test]
-		fn llm_force_transfer_works() {
-			ink::env::test::register_chain_extension(MockedLiberlandExtensionSuccess);
+		fn bounty_paid_from_deposit() {
+			let mut msig_court = new_with_deposits(1);
+			msig_court.execution_bounty = 4;
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+			advance_blocks(10);
+			let (alice_before, eve_before) = (balance_of(alice()), balance_of(eve()));
+			set_next_caller(eve());
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(balance_of(eve()), eve_before + 4);
+			assert_eq!(balance_of(alice()), alice_before + 6);
+			assert_eq!(msig_court.held_deposits, 0);
+		}
+
+		#[ink::test]
+		fn bounty_topped_up_from_court_balance() {
+			let mut msig_court = new_with_deposits(1);
+			msig_court.execution_bounty = 15;
+			let key = propose_with_deposit(&mut msig_court, 10).expect("propose shouldnt fail");
+			let contract_id = ink::env::test::callee::<Environment>();
+			let minimum_balance = ink::env::minimum_balance::<Environment>();
+			// deposit + 3 of court's own funds
+			ink::env::test::set_account_balance::<Environment>(contract_id, 13 + minimum_balance);
+			advance_blocks(10);
+			let (alice_before, eve_before) = (balance_of(alice()), balance_of(eve()));
+			set_next_caller(eve());
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(balance_of(eve()), eve_before + 13);
+			assert_eq!(balance_of(alice()), alice_before);
+		}
 
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+		#[ink::test]
+		fn no_bounty_without_execute_pending() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			msig_court.execution_bounty = 4;
 			set_next_caller(alice());
+			ink::env::test::set_value_transferred::<Environment>(10);
+			let contract_id = ink::env::test::callee::<Environment>();
+			ink::env::test::set_account_balance::<Environment>(contract_id, 10);
+			let before = balance_of(alice());
 			let (_, state) = msig_court
-				.propose(Proposal::LLMForceTransfer(LLMForceTransferArguments {
-					from: LLMAccount::Locked(alice()),
-					to: LLMAccount::Locked(bob()),
-					amount: 1u8.into(),
-				}))
+				.propose(Proposal::SetExecutionBounty(5), None)
 				.expect("propose shouldnt fail");
 			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(balance_of(alice()), before + 10);
+			assert_eq!(msig_court.get_execution_bounty(), 5);
+		}
+
+		fn signed_approval(
+			msig_court: &MsigCourt,
+			signer: &ink_e2e::Keypair,
+			key: PropKey,
+		) -> JudgeSignature {
+			let judge = AccountId::from(signer.public_key().0);
+			let contract_id = ink::env::test::callee::<Environment>();
+			let message =
+				ink::scale::Encode::encode(&(contract_id, key, msig_court.get_nonce(judge)));
+			JudgeSignature::Sr25519(signer.sign(&message).0)
 		}
 
 		#[ink::test]
-		fn llm_force_transfer_propagates_errors() {
-			ink::env::test::register_chain_extension(MockedLiberlandExtensionFail);
+		fn approve_with_signature_works() {
+			let signer = ink_e2e::dev::bob();
+			let judge = AccountId::from(signer.public_key().0);
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (judge, 1)]);
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
+				.expect("propose shouldnt fail");
+
+			let signature = signed_approval(&msig_court, &signer, key);
+			// anyone can relay
+			set_next_caller(charlie());
+			assert_eq!(
+				msig_court.approve_with_signature(key, judge, signature.clone()),
+				Ok(ProposalState::Executed(Ok(())))
+			);
+			assert_eq!(msig_court.get_nonce(judge), 1);
+			assert_eq!(msig_court.judges, vec![(alice(), 1)]);
+		}
 
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
+		#[ink::test]
+		fn approve_with_signature_rejects_invalid_signatures() {
+			let signer = ink_e2e::dev::bob();
+			let judge = AccountId::from(signer.public_key().0);
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 1), (bob(), 1), (judge, 1)]);
 			set_next_caller(alice());
-			let (_, state) = msig_court
-				.propose(Proposal::LLMForceTransfer(LLMForceTransferArguments {
-					from: LLMAccount::Locked(alice()),
-					to: LLMAccount::Locked(bob()),
-					amount: 1u8.into(),
-				}))
+			let (key, _) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
 				.expect("propose shouldnt fail");
-			assert_eq!(state, ProposalState::Executed(Err(Error::CallFailed)));
+
+			// signed by someone else
+			let other = signed_approval(&msig_court, &ink_e2e::dev::charlie(), key);
+			assert_eq!(
+				msig_court.approve_with_signature(key, judge, other),
+				Err(Error::InvalidSignature)
+			);
+
+			// not a judge
+			let signature = signed_approval(&msig_court, &signer, key);
+			assert_eq!(
+				msig_court.approve_with_signature(key, charlie(), signature.clone()),
+				Err(Error::Unauthorized)
+			);
+
+			// replay
+			assert_eq!(
+				msig_court.approve_with_signature(key, judge, signature.clone()),
+				Ok(ProposalState::PendingApprovals)
+			);
+			assert_eq!(
+				msig_court.approve_with_signature(key, judge, signature),
+				Err(Error::InvalidSignature)
+			);
+
+			assert_eq!(
+				msig_court.approve_with_signature(key, judge, JudgeSignature::Ecdsa([0u8; 65])),
+				Err(Error::InvalidSignature)
+			);
 		}
 
 		#[ink::test]
 		fn correct_events_for_threshold_1() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
-			let proposal = Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] };
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			let proposal =
+				Proposal::SetGovernance { threshold: 2, judges: vec![(alice(), 1), (bob(), 1)] };
 			set_next_caller(alice());
-			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
 			let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
 			assert_eq!(emitted_events.len(), 2);
 			assert_proposed_event(&emitted_events[0], alice(), key, proposal);
@@ -418,12 +2094,15 @@ mod msig_court {
 
 		#[ink::test]
 		fn correct_events_for_threshold_2() {
-			let mut msig_court = MsigCourt::new(2, vec![alice(), bob()]);
-			let proposal =
-				Proposal::SetGovernance { threshold: 3, judges: vec![alice(), bob(), charlie()] };
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			let proposal = Proposal::SetGovernance {
+				threshold: 3,
+				judges: vec![(alice(), 1), (bob(), 1), (charlie(), 1)],
+			};
 
 			set_next_caller(alice());
-			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
 			let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
 			assert_eq!(emitted_events.len(), 2);
 			assert_proposed_event(&emitted_events[0], alice(), key, proposal);
@@ -437,11 +2116,13 @@ mod msig_court {
 		}
 		#[ink::test]
 		fn correct_events_for_threshold_3() {
-			let mut msig_court = MsigCourt::new(3, vec![alice(), bob(), charlie()]);
-			let proposal = Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] };
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			let proposal =
+				Proposal::SetGovernance { threshold: 2, judges: vec![(alice(), 1), (bob(), 1)] };
 
 			set_next_caller(alice());
-			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
 			let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
 			assert_eq!(emitted_events.len(), 2);
 			assert_proposed_event(&emitted_events[0], alice(), key, proposal);
@@ -462,10 +2143,12 @@ mod msig_court {
 
 		#[ink::test]
 		fn correct_events_for_failed_call() {
-			let mut msig_court = MsigCourt::new(1, vec![alice()]);
-			let proposal = Proposal::SetGovernance { threshold: 3, judges: vec![alice(), bob()] };
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			let proposal =
+				Proposal::SetGovernance { threshold: 3, judges: vec![(alice(), 1), (bob(), 1)] };
 			set_next_caller(alice());
-			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
 			let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
 			assert_eq!(emitted_events.len(), 2);
 			assert_proposed_event(&emitted_events[0], alice(), key, proposal);
@@ -474,64 +2157,506 @@ mod msig_court {
 
 		#[ink::test]
 		fn get_threshold_works() {
-			let msig_court = MsigCourt::new(1, vec![alice()]);
+			let msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
 			assert_eq!(msig_court.get_threshold(), 1);
 		}
 
+		#[ink::test]
+		fn kind_thresholds_work() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::SetThresholds(vec![(ProposalKind::SetGovernance, Some(2))]),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.get_threshold_of(ProposalKind::SetGovernance), 2);
+			assert_eq!(msig_court.get_threshold_of(ProposalKind::RuntimeCall), 1);
+
+			let (key, state) = msig_court
+				.propose(
+					Proposal::SetGovernance {
+						threshold: 1,
+						judges: vec![(alice(), 1), (bob(), 1)],
+					},
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::Executed(Ok(()))));
+
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::SetThresholds(vec![(ProposalKind::SetGovernance, None)]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.get_threshold_of(ProposalKind::SetGovernance), 1);
+		}
+
+		#[ink::test]
+		fn set_code_goes_through_veto_period() {
+			let mut msig_court = new_with_veto();
+			set_next_caller(alice());
+			let (key, state) = msig_court
+				.propose(Proposal::SetCode([1u8; 32]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingVetoPeriod(10));
+
+			set_next_caller(charlie());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::PendingVetoPeriod(10)));
+			set_next_caller(dave());
+			assert_eq!(msig_court.veto(key), Ok(ProposalState::Vetoed));
+			advance_blocks(10);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::NotFound));
+		}
+
+		#[ink::test]
+		fn batch_uses_strictest_threshold() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1), (bob(), 1)]);
+			msig_court.thresholds.insert(ProposalKind::SetGovernance, &2);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(
+					Proposal::Batch(vec![
+						Proposal::SetDepositPolicy { proposal_deposit: 0, beneficiary: None },
+						Proposal::SetGovernance {
+							threshold: 1,
+							judges: vec![(alice(), 1), (bob(), 1)],
+						},
+					]),
+					None,
+				)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+		}
+
+		#[ink::test]
+		fn kind_thresholds_prevent_bricking() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1), (bob(), 1)]);
+			set_next_caller(alice());
+			let (_, state) = msig_court
+				.propose(Proposal::SetThresholds(vec![(ProposalKind::RuntimeCall, Some(3))]), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+
+			msig_court.thresholds.insert(ProposalKind::RuntimeCall, &2);
+			let (_, state) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] }, None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+			assert_eq!(msig_court.judges.len(), 2);
+		}
+
+		#[ink::test]
+		fn approvals_are_weighted() {
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 2), (bob(), 1), (charlie(), 1)]);
+			set_next_caller(bob());
+			let (key, state) = msig_court
+				.propose(Proposal::SetExecutionBounty(1), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			set_next_caller(charlie());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::PendingApprovals));
+
+			set_next_caller(alice());
+			let (key, state) = msig_court
+				.propose(Proposal::SetExecutionBounty(2), None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::PendingApprovals);
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(msig_court.execution_bounty, 2);
+		}
+
+		#[ink::test]
+		fn set_governance_validates_weights() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			for judges in [
+				vec![(alice(), 1), (bob(), 0)],
+				vec![(alice(), 1), (alice(), 1)],
+				vec![(alice(), 1)],
+			] {
+				let (_, state) = msig_court
+					.propose(Proposal::SetGovernance { threshold: 2, judges }, None)
+					.expect("propose shouldnt fail");
+				assert_eq!(state, ProposalState::Executed(Err(Error::InvalidParameters)));
+			}
+
+			let judges = vec![(alice(), 1), (bob(), 3)];
+			let (_, state) = msig_court
+				.propose(Proposal::SetGovernance { threshold: 4, judges: judges.clone() }, None)
+				.expect("propose shouldnt fail");
+			assert_eq!(state, ProposalState::Executed(Ok(())));
+			assert_eq!(msig_court.get_judges(), judges);
+		}
+
 		#[ink::test]
 		fn get_judges_works() {
-			let msig_court = MsigCourt::new(1, vec![alice()]);
-			assert_eq!(msig_court.get_judges(), vec![alice()]);
+			let msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			assert_eq!(msig_court.get_judges(), vec![(alice(), 1)]);
 		}
 
 		#[ink::test]
 		fn get_proposal_works() {
-			let mut msig_court = MsigCourt::new(3, vec![alice(), bob(), charlie()]);
-			let proposal = Proposal::SetGovernance { threshold: 2, judges: vec![alice(), bob()] };
+			let mut msig_court = MsigCourt::new(3, vec![(alice(), 1), (bob(), 1), (charlie(), 1)]);
+			let proposal =
+				Proposal::SetGovernance { threshold: 2, judges: vec![(alice(), 1), (bob(), 1)] };
 
 			set_next_caller(alice());
-			let (key, _) = msig_court.propose(proposal.clone()).expect("propose shouldnt fail");
-			assert_eq!(msig_court.get_proposal(key), Some((proposal.clone(), vec![alice()])));
+			let (key, _) =
+				msig_court.propose(proposal.clone(), None).expect("propose shouldnt fail");
+			assert_eq!(msig_court.get_proposal(key), Some((proposal.clone(), vec![alice()], None)));
 
 			set_next_caller(bob());
 			msig_court.approve(key).expect("approve shouldnt fail");
-			assert_eq!(msig_court.get_proposal(key), Some((proposal, vec![alice(), bob()])));
+			assert_eq!(msig_court.get_proposal(key), Some((proposal, vec![alice(), bob()], None)));
 
 			set_next_caller(charlie());
 			msig_court.approve(key).expect("approve shouldnt fail");
 			assert_eq!(msig_court.get_proposal(key), None);
 		}
 
+		#[ink::test]
+		fn pause_blocks_execute_pending() {
+			let mut msig_court = new_with_veto();
+			let key = propose_in_veto_period(&mut msig_court);
+			assert_eq!(msig_court.pause(false), Err(Error::Unauthorized));
+
+			set_next_caller(charlie());
+			assert_eq!(msig_court.pause(false), Ok(()));
+			assert_eq!(msig_court.get_paused(), Some(false));
+			advance_blocks(10);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::Paused));
+
+			set_next_caller(alice());
+			let res = msig_court.propose(
+				Proposal::SetGovernance {
+					threshold: 1,
+					judges: vec![(alice(), 1), (charlie(), 1)],
+				},
+				None,
+			);
+			assert!(matches!(res, Ok((_, ProposalState::PendingVetoPeriod(_)))));
+
+			set_next_caller(dave());
+			assert_eq!(msig_court.unpause(), Ok(()));
+			assert_eq!(msig_court.get_paused(), None);
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(msig_court.unpause(), Err(Error::InvalidParameters));
+		}
+
+		#[ink::test]
+		fn pause_blocks_execution_without_veto_period() {
+			let mut msig_court = MsigCourt::new_with_veto(
+				2,
+				vec![(alice(), 1), (bob(), 1)],
+				1,
+				vec![charlie(), dave()],
+				0,
+			);
+			set_next_caller(charlie());
+			assert_eq!(msig_court.pause(false), Ok(()));
+
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(Proposal::SetExecutionBounty(5), None)
+				.expect("propose shouldnt fail");
+			set_next_caller(bob());
+			assert_eq!(msig_court.approve(key), Ok(ProposalState::PendingVetoPeriod(0)));
+			assert_eq!(msig_court.get_execution_bounty(), 0);
+			assert_eq!(msig_court.execute_pending(key), Err(Error::Paused));
+
+			set_next_caller(dave());
+			assert_eq!(msig_court.unpause(), Ok(()));
+			assert_eq!(msig_court.execute_pending(key), Ok(ProposalState::Executed(Ok(()))));
+			assert_eq!(msig_court.get_execution_bounty(), 5);
+		}
+
+		#[ink::test]
+		fn pause_can_block_proposals() {
+			let mut msig_court = new_with_veto();
+			set_next_caller(eve());
+			assert_eq!(msig_court.pause(true), Ok(()));
+			set_next_caller(alice());
+			let res = msig_court.propose(
+				Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] },
+				None,
+			);
+			assert_eq!(res, Err(Error::Paused));
+		}
+
+		#[ink::test]
+		fn proposal_metadata_works() {
+			let mut msig_court = MsigCourt::new(2, vec![(alice(), 1), (bob(), 1)]);
+			let proposal = Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] };
+			let metadata = b"case 42/2024, ipfs://ruling".to_vec();
+			set_next_caller(alice());
+			let (key, _) = msig_court
+				.propose(proposal.clone(), Some(metadata.clone()))
+				.expect("propose shouldnt fail");
+			assert_eq!(
+				msig_court.get_proposal(key),
+				Some((proposal, vec![alice()], Some(metadata.clone())))
+			);
+
+			let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+			let event = <Proposed as ink::scale::Decode>::decode(&mut &emitted_events[0].data[..])
+				.expect("encountered invalid contract event data buffer");
+			assert_eq!(event.metadata, Some(metadata));
+
+			set_next_caller(bob());
+			msig_court.approve(key).expect("approve shouldnt fail");
+			assert_eq!(msig_court.metadata.get(key), None);
+		}
+
+		#[ink::test]
+		fn proposal_metadata_is_bounded() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			let res = msig_court.propose(
+				Proposal::SetGovernance { threshold: 1, judges: vec![(alice(), 1)] },
+				Some(vec![0; MAX_METADATA_LEN + 1]),
+			);
+			assert_eq!(res, Err(Error::MetadataTooLong));
+		}
+
 		#[ink::test]
 		fn get_proposal_fails_on_not_found() {
-			let msig_court = MsigCourt::new(1, vec![alice()]);
+			let msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
 			let key = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
 			assert_eq!(msig_court.get_proposal(key), None);
 		}
+
+		#[ink::test]
+		fn simulate_only_callable_by_contract() {
+			let mut msig_court = MsigCourt::new(1, vec![(alice(), 1)]);
+			set_next_caller(alice());
+			assert_eq!(
+				msig_court.simulate(Proposal::SetExecutionBounty(5)),
+				Err(Error::Unauthorized)
+			);
+
+			set_next_caller(ink::env::test::callee::<Environment>());
+			assert_eq!(msig_court.simulate(Proposal::SetExecutionBounty(5)), Ok(()));
+			assert_eq!(msig_court.get_execution_bounty(), 5);
+			assert_eq!(msig_court.simulate(Proposal::Batch(vec![])), Err(Error::InvalidParameters));
+		}
 	}
 
+	/// Runs against a Liberland dev node, so chain extension calls reach the
+	/// real runtime. Alice, Bob and Charlie are citizens with politipooled LLM
+	/// on dev chain and Alice is sudo.
+	///
+	/// `CONTRACTS_NODE=path/to/substrate-node cargo test --features e2e-tests`
 	#[cfg(all(test, feature = "e2e-tests"))]
 	mod e2e_tests {
-		/// Imports all the definitions from the outer scope so we can use them here.
 		use super::*;
+		use ink_e2e::{
+			subxt::dynamic::Value, AccountKeyring, ChainBackend, ContractsBackend, Keypair,
+		};
+		use liberland_extension::LLMAccount;
 
-		/// A helper function used for calling contract messages.
-		use ink_e2e::ContractsBackend;
-
-		/// The End-to-End test `Result` type.
 		type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+		type Client =
+			ink_e2e::Client<ink_e2e::PolkadotConfig, liberland_extension::LiberlandEnvironment>;
+		type CourtRef = <MsigCourt as ink::codegen::ContractCallBuilder>::Type;
+
+		const VETO_PERIOD: BlockNumber = 3;
 
-		/// We test that we can upload and instantiate the contract using its default constructor.
-		#[ink_e2e::test]
-		async fn new_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-			let mut constructor = MsigCourtRef::new(1, vec![ink_e2e::alice()]);
+		fn account(keyring: AccountKeyring) -> AccountId {
+			ink_e2e::account_id(keyring)
+		}
 
+		/// Alice and Bob are judges, Charlie and Dave veto authorities.
+		async fn instantiate(client: &mut Client) -> E2EResult<(AccountId, CourtRef)> {
+			let mut constructor = MsigCourtRef::new_with_veto(
+				2,
+				vec![(account(AccountKeyring::Alice), 1), (account(AccountKeyring::Bob), 1)],
+				1,
+				vec![account(AccountKeyring::Charlie), account(AccountKeyring::Dave)],
+				VETO_PERIOD,
+			);
 			let contract = client
 				.instantiate("msig_court", &ink_e2e::alice(), &mut constructor)
 				.submit()
 				.await
 				.expect("instantiate failed");
+			Ok((contract.account_id, contract.call_builder::<MsigCourt>()))
+		}
+
+		/// Registers court in `pallet_llm::Courts`, so it can use LLM/LLD
+		/// transfer extensions.
+		async fn make_court(client: &mut Client, court: AccountId) -> E2EResult<()> {
+			let court: &[u8; 32] = court.as_ref();
+			let set_courts = Value::unnamed_variant(
+				"LLM",
+				[Value::named_variant(
+					"set_courts",
+					[("courts", Value::unnamed_composite([Value::from_bytes(court)]))],
+				)],
+			);
+			client
+				.runtime_call(&ink_e2e::alice(), "Sudo", "sudo", vec![set_courts])
+				.await
+				.expect("set_courts failed");
+			Ok(())
+		}
+
+		/// Every extrinsic waits for its block, so remarks move chain forward
+		async fn advance_blocks(client: &mut Client, n: BlockNumber) -> E2EResult<()> {
+			for _ in 0..n {
+				client
+					.runtime_call(
+						&ink_e2e::ferdie(),
+						"System",
+						"remark",
+						vec![Value::from_bytes(b"")],
+					)
+					.await
+					.expect("remark failed");
+			}
+			Ok(())
+		}
+
+		async fn propose_and_approve(
+			client: &mut Client,
+			court: &mut CourtRef,
+			proposal: Proposal,
+		) -> E2EResult<PropKey> {
+			let propose = court.propose(proposal, None);
+			let (key, state) = client
+				.call(&ink_e2e::alice(), &propose)
+				.submit()
+				.await
+				.expect("propose failed")
+				.return_value()
+				.expect("propose returned error");
+			assert_eq!(state, ProposalState::PendingApprovals);
+
+			let approve = court.approve(key);
+			let state = client
+				.call(&ink_e2e::bob(), &approve)
+				.submit()
+				.await
+				.expect("approve failed")
+				.return_value();
+			assert!(matches!(state, Ok(ProposalState::PendingVetoPeriod(_))));
+			Ok(key)
+		}
+
+		async fn execute_pending(
+			client: &mut Client,
+			court: &mut CourtRef,
+			caller: &Keypair,
+			key: PropKey,
+		) -> E2EResult<Result<ProposalState>> {
+			let execute = court.execute_pending(key);
+			Ok(client.call(caller, &execute).submit().await?.return_value())
+		}
+
+		fn politipooled_transfer(amount: Balance) -> Proposal {
+			Proposal::LLMForceTransfer(LLMForceTransferArguments {
+				from: LLMAccount::Locked(account(AccountKeyring::Alice)),
+				to: LLMAccount::Locked(account(AccountKeyring::Bob)),
+				amount,
+			})
+		}
+
+		#[ink_e2e::test(environment = liberland_extension::LiberlandEnvironment)]
+		async fn new_works(mut client: Client) -> E2EResult<()> {
+			let (_, court) = instantiate(&mut client).await?;
+			let get_judges = court.get_judges();
+			let judges = client.call(&ink_e2e::alice(), &get_judges).dry_run().await?;
+			assert_eq!(judges.return_value().len(), 2);
+			Ok(())
+		}
+
+		#[ink_e2e::test(environment = liberland_extension::LiberlandEnvironment)]
+		async fn llm_force_transfer_executes_after_veto_period(
+			mut client: Client,
+		) -> E2EResult<()> {
+			let (address, mut court) = instantiate(&mut client).await?;
+			make_court(&mut client, address).await?;
+			let key =
+				propose_and_approve(&mut client, &mut court, politipooled_transfer(1)).await?;
+
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			assert_eq!(state, Err(Error::VetoPeriodNotEnded));
+
+			advance_blocks(&mut client, VETO_PERIOD).await?;
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			assert_eq!(state, Ok(ProposalState::Executed(Ok(()))));
+			Ok(())
+		}
+
+		#[ink_e2e::test(environment = liberland_extension::LiberlandEnvironment)]
+		async fn llm_force_transfer_fails_if_not_registered_court(
+			mut client: Client,
+		) -> E2EResult<()> {
+			let (_, mut court) = instantiate(&mut client).await?;
+			let key =
+				propose_and_approve(&mut client, &mut court, politipooled_transfer(1)).await?;
+
+			advance_blocks(&mut client, VETO_PERIOD).await?;
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			// pallet_llm::Error::NotCourt
+			assert!(matches!(
+				state,
+				Ok(ProposalState::Executed(Err(Error::Extension(
+					liberland_extension::Error::Module { index: 46, .. }
+				))))
+			));
+			Ok(())
+		}
+
+		#[ink_e2e::test(environment = liberland_extension::LiberlandEnvironment)]
+		async fn vetoed_transfer_is_never_executed(mut client: Client) -> E2EResult<()> {
+			let (address, mut court) = instantiate(&mut client).await?;
+			make_court(&mut client, address).await?;
+			let key =
+				propose_and_approve(&mut client, &mut court, politipooled_transfer(1)).await?;
+
+			let veto = court.veto(key);
+			let state = client.call(&ink_e2e::charlie(), &veto).submit().await?.return_value();
+			assert_eq!(state, Ok(ProposalState::Vetoed));
+
+			advance_blocks(&mut client, VETO_PERIOD).await?;
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			assert_eq!(state, Err(Error::NotFound));
+
+			let get_vetoed = court.get_vetoed(key);
+			let vetoed = client.call(&ink_e2e::alice(), &get_vetoed).dry_run().await?;
+			assert_eq!(
+				vetoed.return_value().map(|v| v.vetoers),
+				Some(vec![account(AccountKeyring::Charlie)])
+			);
+			Ok(())
+		}
 
+		#[ink_e2e::test(environment = liberland_extension::LiberlandEnvironment)]
+		async fn paused_court_doesnt_execute(mut client: Client) -> E2EResult<()> {
+			let (address, mut court) = instantiate(&mut client).await?;
+			make_court(&mut client, address).await?;
+			let key =
+				propose_and_approve(&mut client, &mut court, politipooled_transfer(1)).await?;
+
+			let pause = court.pause(false);
+			let result = client.call(&ink_e2e::dave(), &pause).submit().await?.return_value();
+			assert_eq!(result, Ok(()));
+
+			advance_blocks(&mut client, VETO_PERIOD).await?;
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			assert_eq!(state, Err(Error::Paused));
+
+			let unpause = court.unpause();
+			let result = client.call(&ink_e2e::dave(), &unpause).submit().await?.return_value();
+			assert_eq!(result, Ok(()));
+			let state = execute_pending(&mut client, &mut court, &ink_e2e::charlie(), key).await?;
+			assert_eq!(state, Ok(ProposalState::Executed(Ok(()))));
 			Ok(())
 		}
 	}
@@ -1,10 +1,23 @@
+use ink::scale::{Decode, Encode};
+use liberland_extension::LLMForceTransferArguments;
+
+/// Answers `llm_force_transfer_batch` with same result for every transfer
+fn batch_results(input: &[u8], output: &mut Vec<u8>, ok: bool) {
+	let transfers = Vec::<LLMForceTransferArguments>::decode(&mut &input[..])
+		.expect("mock called with invalid input");
+	vec![ok; transfers.len()].encode_to(output);
+}
+
 pub struct MockedLiberlandExtensionSuccess;
 impl ink::env::test::ChainExtension for MockedLiberlandExtensionSuccess {
 	fn ext_id(&self) -> u16 {
 		0
 	}
 
-	fn call(&mut self, _func_id: u16, _input: &[u8], _output: &mut Vec<u8>) -> u32 {
+	fn call(&mut self, func_id: u16, input: &[u8], output: &mut Vec<u8>) -> u32 {
+		if func_id == 29 {
+			batch_results(input, output, true);
+		}
 		0
 	}
 }
@@ -15,7 +28,10 @@ impl ink::env::test::ChainExtension for MockedLiberlandExtensionFail {
 		0
 	}
 
-	fn call(&mut self, _func_id: u16, _input: &[u8], _output: &mut Vec<u8>) -> u32 {
+	fn call(&mut self, func_id: u16, input: &[u8], output: &mut Vec<u8>) -> u32 {
+		if func_id == 29 {
+			batch_results(input, output, false);
+		}
 		1
 	}
 }
@@ -0,0 +1,541 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[cfg(test)]
+mod mock;
+
+/// Rental deposit protection scheme.
+///
+/// Tenant lodges the deposit for a lease with a landlord. After the lease ends,
+/// landlord can claim part of the deposit with itemized evidence hashes. Tenant
+/// either accepts the claim or disputes it into the small-claims court, which
+/// enforces its ruling through `court_traits::CourtRuling`. Undisputed claims
+/// and unclaimed deposits are released by anyone after the statutory period.
+/// Both parties must be identified citizens or e-residents.
+#[ink::contract(env = liberland_extension::LiberlandEnvironment)]
+mod rental_deposit {
+	use court_traits::{CaseId, CourtError, CourtRuling, SmallClaimsCourt};
+	use ink::codegen::Env;
+	use ink::prelude::vec::Vec;
+	use ink::storage::Mapping;
+
+	/// Maximum number of items in a single landlord claim
+	pub const MAX_CLAIM_ITEMS: usize = 32;
+
+	pub type DepositId = u64;
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct ClaimItem {
+		pub amount: Balance,
+		/// Hash of off-chain evidence (invoice, photos, inspection report)
+		pub evidence: Hash,
+	}
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct Claim {
+		pub items: Vec<ClaimItem>,
+		pub total: Balance,
+		pub claimed_at: BlockNumber,
+	}
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub enum DepositStatus {
+		/// Held for tenant, no claim made
+		Held,
+		/// Landlord made a claim, waiting for tenant
+		Claimed,
+		/// Tenant disputed the claim in court
+		Disputed(CaseId),
+		/// Paid out
+		Released,
+	}
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+	pub struct Deposit {
+		pub tenant: AccountId,
+		pub landlord: AccountId,
+		pub amount: Balance,
+		pub lease_end: BlockNumber,
+		pub status: DepositStatus,
+		pub claim: Option<Claim>,
+	}
+
+	#[derive(Debug, PartialEq, Eq, Clone)]
+	#[ink::scale_derive(Encode, Decode, TypeInfo)]
+	pub enum Error {
+		/// Caller isn't allowed to do this
+		Unauthorized,
+		/// Deposit not found
+		NotFound,
+		/// Tenant or landlord isn't an identified citizen or e-resident
+		NotIdentified,
+		/// Invalid parameters
+		InvalidParameters,
+		/// Action not allowed in current deposit status
+		InvalidStatus,
+		/// Statutory period hasn't started or ended yet
+		TooEarly,
+		/// Statutory period already ended
+		TooLate,
+		/// Transfer of funds failed
+		TransferFailed,
+		/// Court rejected the claim
+		CourtError(CourtError),
+	}
+
+	pub type Result<T> = core::result::Result<T, Error>;
+
+	#[ink(storage)]
+	pub struct RentalDeposit {
+		court: AccountId,
+		statutory_period: BlockNumber,
+		next_id: DepositId,
+		deposits: Mapping<DepositId, Deposit>,
+		cases: Mapping<CaseId, DepositId>,
+	}
+
+	#[ink(event)]
+	pub struct Lodged {
+		#[ink(topic)]
+		id: DepositId,
+		#[ink(topic)]
+		tenant: AccountId,
+		#[ink(topic)]
+		landlord: AccountId,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Claimed {
+		#[ink(topic)]
+		id: DepositId,
+		total: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Disputed {
+		#[ink(topic)]
+		id: DepositId,
+		case_id: CaseId,
+	}
+
+	#[ink(event)]
+	pub struct Released {
+		#[ink(topic)]
+		id: DepositId,
+		to_landlord: Balance,
+		to_tenant: Balance,
+	}
+
+	impl RentalDeposit {
+		fn get(&self, id: DepositId) -> Result<Deposit> {
+			self.deposits.get(id).ok_or(Error::NotFound)
+		}
+
+		fn now(&self) -> BlockNumber {
+			self.env().block_number()
+		}
+
+		fn ensure_identified(&self, account: AccountId) -> Result<()> {
+			if !self.env().extension().is_identified(account) {
+				return Err(Error::NotIdentified);
+			}
+			Ok(())
+		}
+
+		fn settle(
+			&mut self,
+			id: DepositId,
+			mut deposit: Deposit,
+			to_landlord: Balance,
+		) -> Result<()> {
+			let to_tenant =
+				deposit.amount.checked_sub(to_landlord).ok_or(Error::InvalidParameters)?;
+			if to_landlord > 0 {
+				self.env()
+					.transfer(deposit.landlord, to_landlord)
+					.map_err(|_| Error::TransferFailed)?;
+			}
+			if to_tenant > 0 {
+				self.env()
+					.transfer(deposit.tenant, to_tenant)
+					.map_err(|_| Error::TransferFailed)?;
+			}
+
+			deposit.status = DepositStatus::Released;
+			self.deposits.insert(id, &deposit);
+			self.env().emit_event(Released { id, to_landlord, to_tenant });
+			Ok(())
+		}
+	}
+
+	impl RentalDeposit {
+		#[ink(constructor)]
+		pub fn new(court: AccountId, statutory_period: BlockNumber) -> Self {
+			Self {
+				court,
+				statutory_period,
+				next_id: 0,
+				deposits: Mapping::default(),
+				cases: Mapping::default(),
+			}
+		}
+
+		/// Lodge transferred value as deposit for a lease with `landlord`.
+		/// Caller is the tenant.
+		#[ink(message, payable)]
+		pub fn lodge(&mut self, landlord: AccountId, lease_end: BlockNumber) -> Result<DepositId> {
+			let tenant = self.env().caller();
+			let amount = self.env().transferred_value();
+			if amount == 0 || tenant == landlord || lease_end < self.now() {
+				return Err(Error::InvalidParameters);
+			}
+			self.ensure_identified(tenant)?;
+			self.ensure_identified(landlord)?;
+
+			let id = self.next_id;
+			self.next_id = id.checked_add(1).ok_or(Error::InvalidParameters)?;
+			let deposit = Deposit {
+				tenant,
+				landlord,
+				amount,
+				lease_end,
+				status: DepositStatus::Held,
+				claim: None,
+			};
+			self.deposits.insert(id, &deposit);
+			self.env().emit_event(Lodged { id, tenant, landlord, amount });
+			Ok(id)
+		}
+
+		/// Claim part of the deposit. Each item must be backed by an evidence
+		/// hash. Can only be called by landlord, after lease end and before the
+		/// statutory period passes.
+		#[ink(message)]
+		pub fn claim(&mut self, id: DepositId, items: Vec<ClaimItem>) -> Result<()> {
+			let mut deposit = self.get(id)?;
+			if self.env().caller() != deposit.landlord {
+				return Err(Error::Unauthorized);
+			}
+			if deposit.status != DepositStatus::Held {
+				return Err(Error::InvalidStatus);
+			}
+			let now = self.now();
+			if now < deposit.lease_end {
+				return Err(Error::TooEarly);
+			}
+			if now > deposit.lease_end.saturating_add(self.statutory_period) {
+				return Err(Error::TooLate);
+			}
+			if items.is_empty() || items.len() > MAX_CLAIM_ITEMS {
+				return Err(Error::InvalidParameters);
+			}
+			let total = items
+				.iter()
+				.try_fold(0 as Balance, |acc, item| acc.checked_add(item.amount))
+				.filter(|total| *total <= deposit.amount)
+				.ok_or(Error::InvalidParameters)?;
+
+			deposit.status = DepositStatus::Claimed;
+			deposit.claim = Some(Claim { items, total, claimed_at: now });
+			self.deposits.insert(id, &deposit);
+			self.env().emit_event(Claimed { id, total });
+			Ok(())
+		}
+
+		/// Accept landlord's claim and release the deposit. Can only be called
+		/// by tenant.
+		#[ink(message)]
+		pub fn accept_claim(&mut self, id: DepositId) -> Result<()> {
+			let deposit = self.get(id)?;
+			if self.env().caller() != deposit.tenant {
+				return Err(Error::Unauthorized);
+			}
+			let total = match (&deposit.status, &deposit.claim) {
+				(DepositStatus::Claimed, Some(claim)) => claim.total,
+				_ => return Err(Error::InvalidStatus),
+			};
+			self.settle(id, deposit, total)
+		}
+
+		/// Dispute landlord's claim in the small-claims court. Can only be
+		/// called by tenant before the statutory period after the claim passes.
+		///
+		/// * `evidence` - hash of tenant's off-chain case file
+		#[ink(message)]
+		pub fn dispute(&mut self, id: DepositId, evidence: Hash) -> Result<CaseId> {
+			let mut deposit = self.get(id)?;
+			if self.env().caller() != deposit.tenant {
+				return Err(Error::Unauthorized);
+			}
+			let claim = match (&deposit.status, &deposit.claim) {
+				(DepositStatus::Claimed, Some(claim)) => claim,
+				_ => return Err(Error::InvalidStatus),
+			};
+			if self.now() > claim.claimed_at.saturating_add(self.statutory_period) {
+				return Err(Error::TooLate);
+			}
+
+			let mut court: ink::contract_ref!(SmallClaimsCourt, Environment) = self.court.into();
+			let case_id = court
+				.file_claim(deposit.landlord, deposit.tenant, claim.total, evidence)
+				.map_err(Error::CourtError)?;
+
+			deposit.status = DepositStatus::Disputed(case_id);
+			self.deposits.insert(id, &deposit);
+			self.cases.insert(case_id, &id);
+			self.env().emit_event(Disputed { id, case_id });
+			Ok(case_id)
+		}
+
+		/// Release deposit after the statutory period: whole deposit to tenant
+		/// if there was no claim, or claimed amount to landlord if the claim
+		/// wasn't disputed. Can be called by anyone.
+		#[ink(message)]
+		pub fn release(&mut self, id: DepositId) -> Result<()> {
+			let deposit = self.get(id)?;
+			let now = self.now();
+			let to_landlord = match (&deposit.status, &deposit.claim) {
+				(DepositStatus::Held, _) => {
+					if now <= deposit.lease_end.saturating_add(self.statutory_period) {
+						return Err(Error::TooEarly);
+					}
+					0
+				},
+				(DepositStatus::Claimed, Some(claim)) => {
+					if now <= claim.claimed_at.saturating_add(self.statutory_period) {
+						return Err(Error::TooEarly);
+					}
+					claim.total
+				},
+				_ => return Err(Error::InvalidStatus),
+			};
+			self.settle(id, deposit, to_landlord)
+		}
+
+		#[ink(message)]
+		pub fn get_deposit(&self, id: DepositId) -> Option<Deposit> {
+			self.deposits.get(id)
+		}
+
+		#[ink(message)]
+		pub fn get_court(&self) -> AccountId {
+			self.court
+		}
+
+		#[ink(message)]
+		pub fn get_statutory_period(&self) -> BlockNumber {
+			self.statutory_period
+		}
+	}
+
+	impl CourtRuling for RentalDeposit {
+		/// Release disputed deposit according to court's ruling - `award` goes
+		/// to landlord, rest to tenant. Can only be called by the court.
+		#[ink(message)]
+		fn enforce_ruling(
+			&mut self,
+			case_id: CaseId,
+			award: Balance,
+		) -> core::result::Result<(), CourtError> {
+			if self.env().caller() != self.court {
+				return Err(CourtError::Unauthorized);
+			}
+			let id = self.cases.get(case_id).ok_or(CourtError::CaseNotFound)?;
+			let deposit = self.get(id).map_err(|_| CourtError::CaseNotFound)?;
+			if deposit.status != DepositStatus::Disputed(case_id) || award > deposit.amount {
+				return Err(CourtError::InvalidParameters);
+			}
+			self.cases.remove(case_id);
+			self.settle(id, deposit, award).map_err(|_| CourtError::EnforcementFailed)
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::mock::*;
+
+		fn accounts() -> ink::env::test::DefaultAccounts<Environment> {
+			ink::env::test::default_accounts::<Environment>()
+		}
+
+		fn tenant() -> AccountId {
+			accounts().alice
+		}
+
+		fn landlord() -> AccountId {
+			accounts().bob
+		}
+
+		fn court() -> AccountId {
+			accounts().charlie
+		}
+
+		fn set_next_caller(caller: AccountId) {
+			ink::env::test::set_caller::<Environment>(caller);
+		}
+
+		fn balance_of(account: AccountId) -> Balance {
+			ink::env::test::get_account_balance::<Environment>(account).unwrap_or(0)
+		}
+
+		fn advance_blocks(n: u32) {
+			for _ in 0..n {
+				ink::env::test::advance_block::<Environment>();
+			}
+		}
+
+		fn item(amount: Balance) -> ClaimItem {
+			ClaimItem { amount, evidence: Hash::from([1u8; 32]) }
+		}
+
+		/// Contract with 100 deposited by tenant, lease ending at block 10,
+		/// statutory period of 20 blocks
+		fn setup() -> (RentalDeposit, DepositId) {
+			ink::env::test::register_chain_extension(MockedLiberlandExtension::identified());
+			let mut contract = RentalDeposit::new(court(), 20);
+			let contract_id = ink::env::test::callee::<Environment>();
+			ink::env::test::set_account_balance::<Environment>(contract_id, 100);
+			set_next_caller(tenant());
+			ink::env::test::set_value_transferred::<Environment>(100);
+			let id = contract.lodge(landlord(), 10).expect("lodge shouldnt fail");
+			ink::env::test::set_value_transferred::<Environment>(0);
+			(contract, id)
+		}
+
+		#[ink::test]
+		fn lodge_works() {
+			let (contract, id) = setup();
+			let deposit = contract.get_deposit(id).unwrap();
+			assert_eq!(deposit.tenant, tenant());
+			assert_eq!(deposit.landlord, landlord());
+			assert_eq!(deposit.amount, 100);
+			assert_eq!(deposit.status, DepositStatus::Held);
+			assert_eq!(ink::env::test::recorded_events().count(), 1);
+		}
+
+		#[ink::test]
+		fn lodge_requires_identity() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtension::unidentified());
+			let mut contract = RentalDeposit::new(court(), 20);
+			set_next_caller(tenant());
+			ink::env::test::set_value_transferred::<Environment>(100);
+			assert_eq!(contract.lodge(landlord(), 10), Err(Error::NotIdentified));
+		}
+
+		#[ink::test]
+		fn lodge_validates_parameters() {
+			ink::env::test::register_chain_extension(MockedLiberlandExtension::identified());
+			let mut contract = RentalDeposit::new(court(), 20);
+			set_next_caller(tenant());
+			assert_eq!(contract.lodge(landlord(), 10), Err(Error::InvalidParameters));
+			ink::env::test::set_value_transferred::<Environment>(100);
+			assert_eq!(contract.lodge(tenant(), 10), Err(Error::InvalidParameters));
+		}
+
+		#[ink::test]
+		fn claim_works() {
+			let (mut contract, id) = setup();
+			advance_blocks(10);
+			set_next_caller(landlord());
+			assert_eq!(contract.claim(id, vec![item(20), item(10)]), Ok(()));
+			let deposit = contract.get_deposit(id).unwrap();
+			assert_eq!(deposit.status, DepositStatus::Claimed);
+			assert_eq!(deposit.claim.unwrap().total, 30);
+		}
+
+		#[ink::test]
+		fn claim_validates_caller_timing_and_items() {
+			let (mut contract, id) = setup();
+			set_next_caller(landlord());
+			assert_eq!(contract.claim(id, vec![item(20)]), Err(Error::TooEarly));
+			advance_blocks(10);
+			assert_eq!(contract.claim(id, vec![]), Err(Error::InvalidParameters));
+			assert_eq!(contract.claim(id, vec![item(60), item(41)]), Err(Error::InvalidParameters));
+			set_next_caller(tenant());
+			assert_eq!(contract.claim(id, vec![item(20)]), Err(Error::Unauthorized));
+			advance_blocks(21);
+			set_next_caller(landlord());
+			assert_eq!(contract.claim(id, vec![item(20)]), Err(Error::TooLate));
+		}
+
+		#[ink::test]
+		fn accept_claim_pays_out() {
+			let (mut contract, id) = setup();
+			advance_blocks(10);
+			set_next_caller(landlord());
+			contract.claim(id, vec![item(30)]).unwrap();
+
+			let tenant_before = balance_of(tenant());
+			let landlord_before = balance_of(landlord());
+			set_next_caller(landlord());
+			assert_eq!(contract.accept_claim(id), Err(Error::Unauthorized));
+			set_next_caller(tenant());
+			assert_eq!(contract.accept_claim(id), Ok(()));
+			assert_eq!(balance_of(landlord()), landlord_before + 30);
+			assert_eq!(balance_of(tenant()), tenant_before + 70);
+			assert_eq!(contract.get_deposit(id).unwrap().status, DepositStatus::Released);
+		}
+
+		#[ink::test]
+		fn unclaimed_deposit_released_after_statutory_period() {
+			let (mut contract, id) = setup();
+			advance_blocks(30);
+			assert_eq!(contract.release(id), Err(Error::TooEarly));
+			advance_blocks(1);
+			let tenant_before = balance_of(tenant());
+			set_next_caller(accounts().django);
+			assert_eq!(contract.release(id), Ok(()));
+			assert_eq!(balance_of(tenant()), tenant_before + 100);
+			assert_eq!(contract.release(id), Err(Error::InvalidStatus));
+		}
+
+		#[ink::test]
+		fn undisputed_claim_released_after_statutory_period() {
+			let (mut contract, id) = setup();
+			advance_blocks(10);
+			set_next_caller(landlord());
+			contract.claim(id, vec![item(40)]).unwrap();
+			advance_blocks(20);
+			assert_eq!(contract.release(id), Err(Error::TooEarly));
+			advance_blocks(1);
+
+			let landlord_before = balance_of(landlord());
+			assert_eq!(contract.release(id), Ok(()));
+			assert_eq!(balance_of(landlord()), landlord_before + 40);
+
+			set_next_caller(tenant());
+			assert_eq!(contract.dispute(id, Hash::default()), Err(Error::InvalidStatus));
+		}
+
+		#[ink::test]
+		fn enforce_ruling_works() {
+			let (mut contract, id) = setup();
+			let mut deposit = contract.get_deposit(id).unwrap();
+			deposit.status = DepositStatus::Disputed(7);
+			contract.deposits.insert(id, &deposit);
+			contract.cases.insert(7, &id);
+
+			set_next_caller(landlord());
+			assert_eq!(contract.enforce_ruling(7, 10), Err(CourtError::Unauthorized));
+			set_next_caller(court());
+			assert_eq!(contract.enforce_ruling(7, 101), Err(CourtError::InvalidParameters));
+			assert_eq!(contract.enforce_ruling(8, 10), Err(CourtError::CaseNotFound));
+
+			let tenant_before = balance_of(tenant());
+			let landlord_before = balance_of(landlord());
+			assert_eq!(contract.enforce_ruling(7, 10), Ok(()));
+			assert_eq!(balance_of(landlord()), landlord_before + 10);
+			assert_eq!(balance_of(tenant()), tenant_before + 90);
+			assert_eq!(contract.enforce_ruling(7, 10), Err(CourtError::CaseNotFound));
+		}
+	}
+}
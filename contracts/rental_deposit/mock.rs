@@ -0,0 +1,27 @@
+use ink::scale::Encode;
+
+/// Answers `is_identified` with a fixed value
+pub struct MockedLiberlandExtension {
+	identified: bool,
+}
+
+impl MockedLiberlandExtension {
+	pub fn identified() -> Self {
+		Self { identified: true }
+	}
+
+	pub fn unidentified() -> Self {
+		Self { identified: false }
+	}
+}
+
+impl ink::env::test::ChainExtension for MockedLiberlandExtension {
+	fn ext_id(&self) -> u16 {
+		0
+	}
+
+	fn call(&mut self, _func_id: u16, _input: &[u8], output: &mut Vec<u8>) -> u32 {
+		self.identified.encode_to(output);
+		0
+	}
+}
@@ -1,8 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
 use ink::env::Environment;
+use ink::prelude::vec::Vec;
 mod types;
 
+#[cfg(feature = "std")]
+pub mod mock;
+
+type AccountId = <ink::env::DefaultEnvironment as Environment>::AccountId;
+type Balance = <ink::env::DefaultEnvironment as Environment>::Balance;
+type BlockNumber = <ink::env::DefaultEnvironment as Environment>::BlockNumber;
+type Timestamp = <ink::env::DefaultEnvironment as Environment>::Timestamp;
+/// Id of an asset in `pallet_assets`
+pub type AssetId = u32;
+/// Version of the extension interface this crate targets. Compare with
+/// `ChainExtensionApi::extension_version` of the runtime before deploying.
+pub const EXTENSION_VERSION: u32 = 1;
+
 pub use types::*;
 
 #[ink::chain_extension(extension = 0)]
@@ -11,14 +25,209 @@ pub trait Liberland {
 
 	#[ink(function = 1)]
 	fn llm_force_transfer(args: LLMForceTransferArguments);
+
+	/// Dispatch SCALE-encoded `RuntimeCall` with contract's account as origin.
+	/// Only calls allowed by runtime's contracts call filter can be dispatched.
+	#[ink(function = 2)]
+	fn dispatch_as_contract(call: Vec<u8>);
+
+	/// Check if account is a citizen or e-resident with a KnownGood judgement.
+	#[ink(function = 3, handle_status = false)]
+	fn is_identified(account: AccountId) -> bool;
+
+	/// Estimated timestamp (unix milliseconds) of given block. Estimates are
+	/// anchored at current block and time, so they include drift so far.
+	#[ink(function = 4, handle_status = false)]
+	fn timestamp_at_block(block: BlockNumber) -> Timestamp;
+
+	/// Estimated first block at or after given timestamp (unix milliseconds).
+	#[ink(function = 5, handle_status = false)]
+	fn block_at_timestamp(timestamp: Timestamp) -> BlockNumber;
+
+	/// Estimated block at the end (local midnight) of `days`-th calendar day
+	/// from now, in time zone `utc_offset_minutes` away from UTC. Use it for
+	/// periods expressed in calendar days, e.g. statutory deadlines.
+	#[ink(function = 6, handle_status = false)]
+	fn calendar_days_deadline(days: u32, utc_offset_minutes: i16) -> BlockNumber;
+
+	/// Transfer LLD from LLM treasury. Contract must be registered in
+	/// `pallet_llm::Courts`.
+	#[ink(function = 7)]
+	fn lld_force_transfer(args: LLDForceTransferArguments);
+
+	/// Revoke citizenship of given account. Contract must be registered in
+	/// `pallet_llm::Courts`.
+	#[ink(function = 8)]
+	fn revoke_citizenship(account: AccountId);
+
+	/// Transfer liquid LLM from contract's account. Unlike
+	/// `llm_force_transfer`, it needs no special privileges.
+	#[ink(function = 9)]
+	fn send_llm(to: AccountId, amount: Balance);
+
+	/// Liquid LLM balance of account.
+	#[ink(function = 10, handle_status = false)]
+	fn llm_balance(account: AccountId) -> Balance;
+
+	/// Lock contract's LLM in politipool.
+	#[ink(function = 11)]
+	fn llm_politics_lock(amount: Balance);
+
+	/// Unlock part of contract's LLM from politipool. Subject to the same
+	/// withdrawal lock as any other account.
+	#[ink(function = 12)]
+	fn llm_politics_unlock();
+
+	/// Check if account is a citizen with a KnownGood judgement, whose
+	/// citizenship is already effective. Unlike `is_identified`, e-residents
+	/// don't pass.
+	#[ink(function = 13, handle_status = false)]
+	fn is_citizen(account: AccountId) -> bool;
+
+	/// Identity of account, if set. Only fields stored as raw data are
+	/// included.
+	#[ink(function = 14, handle_status = false)]
+	fn identity_of(account: AccountId) -> Option<Identity>;
+
+	/// Check if account's identity has given judgement from given registrar.
+	#[ink(function = 15, handle_status = false)]
+	fn has_judgement(account: AccountId, registrar: u32, judgement: Judgement) -> bool;
+
+	/// Owner of NFT, e.g. land plot, if it exists.
+	#[ink(function = 16, handle_status = false)]
+	fn land_owner(collection: u32, item: u32) -> Option<AccountId>;
+
+	/// Raw metadata of NFT, if set. For land plots it decodes as
+	/// `LandMetadata`.
+	#[ink(function = 17, handle_status = false)]
+	fn land_metadata(collection: u32, item: u32) -> Option<Vec<u8>>;
+
+	/// Data of company registered in the Company Registry, if registered.
+	#[ink(function = 18, handle_status = false)]
+	fn company_data(id: u32) -> Option<Vec<u8>>;
+
+	/// Check if account is the owner or one of the officers of given company
+	/// entity, allowed to act on its behalf.
+	#[ink(function = 19, handle_status = false)]
+	fn is_company_officer(id: u32, account: AccountId) -> bool;
+
+	/// Transfer LLD from contract's account, keeping it alive. Goes through
+	/// `pallet_balances` like any other transfer, so runtime's rules apply.
+	#[ink(function = 20)]
+	fn lld_transfer(to: AccountId, amount: Balance);
+
+	/// Free LLD balance of account.
+	#[ink(function = 21, handle_status = false)]
+	fn lld_balance(account: AccountId) -> Balance;
+
+	/// Vote in referendum with contract's account. Subject to the same
+	/// politics rules as any voter, so the contract's account must be allowed
+	/// to take part in politics.
+	#[ink(function = 22)]
+	fn democracy_vote(args: DemocracyVoteArguments);
+
+	/// Delegate contract's votes. Same politics rules as for
+	/// `democracy_vote` apply.
+	#[ink(function = 23)]
+	fn democracy_delegate(to: AccountId, conviction: Conviction, balance: Balance);
+
+	/// Referenda that are still being voted on.
+	#[ink(function = 24, handle_status = false)]
+	fn democracy_ongoing_referenda() -> Vec<OngoingReferendum>;
+
+	/// Bond contract's LLD for staking. Contract's account is both stash and
+	/// controller.
+	#[ink(function = 25)]
+	fn staking_bond(value: Balance, payee: RewardDestination);
+
+	/// Nominate validators with contract's bonded LLD.
+	#[ink(function = 26)]
+	fn staking_nominate(targets: Vec<AccountId>);
+
+	/// Schedule part of contract's bond for unlocking after the bonding
+	/// duration.
+	#[ink(function = 27)]
+	fn staking_unbond(value: Balance);
+
+	/// Schedule SCALE-encoded `RuntimeCall` for dispatch with contract's
+	/// account as origin `after` blocks from now, e.g. to execute a proposal
+	/// once its veto period ends. Call must be allowed by runtime's scheduled
+	/// contract calls filter and its weight is charged upfront. Deposit is
+	/// held from contract's balance until the call is dispatched and the
+	/// number of contract's pending calls is limited. Returns address of the
+	/// scheduled task - block and index in it.
+	#[ink(function = 28)]
+	fn schedule_call(after: BlockNumber, call: Vec<u8>) -> (BlockNumber, u32);
+
+	/// Execute multiple `llm_force_transfer`s atomically - either all of them
+	/// or none are applied. Returns whether each transfer succeeded on its
+	/// own.
+	#[ink(function = 29, handle_status = false)]
+	fn llm_force_transfer_batch(transfers: Vec<LLMForceTransferArguments>) -> Vec<bool>;
+
+	/// Current block, time, session, staking era and council in one call.
+	#[ink(function = 30, handle_status = false)]
+	fn chain_context() -> ChainContext;
+
+	/// Check if account is a member of the Senate.
+	#[ink(function = 31, handle_status = false)]
+	fn is_senate_member(account: AccountId) -> bool;
+
+	/// Current members of Congress (the council collective).
+	#[ink(function = 32, handle_status = false)]
+	fn congress_members() -> Vec<AccountId>;
+
+	/// Create new asset in `pallet_assets` with contract as its owner and
+	/// admin. Creation deposit is taken from contract's LLD.
+	#[ink(function = 33)]
+	fn asset_create(id: AssetId, min_balance: Balance);
+
+	/// Mint asset to `beneficiary`. Contract must be the asset's issuer.
+	#[ink(function = 34)]
+	fn asset_mint(id: AssetId, beneficiary: AccountId, amount: Balance);
+
+	/// Transfer contract's asset.
+	#[ink(function = 35)]
+	fn asset_transfer(id: AssetId, target: AccountId, amount: Balance);
+
+	/// Burn asset held by `who`. Contract must be the asset's admin.
+	#[ink(function = 36)]
+	fn asset_burn(id: AssetId, who: AccountId, amount: Balance);
+
+	/// Balance of given asset held by account.
+	#[ink(function = 37, handle_status = false)]
+	fn asset_balance(id: AssetId, account: AccountId) -> Balance;
+
+	/// Check if account is an e-resident with a KnownGood judgement and
+	/// enough LLM politipooled. Citizens don't pass - use `is_citizen` or
+	/// `is_identified` for them.
+	#[ink(function = 38, handle_status = false)]
+	fn is_eresident(account: AccountId) -> bool;
 }
 
+/// Status codes of module errors have this bit set
+const MODULE_ERROR: u32 = 0x1_0000;
+
 impl ink::env::chain_extension::FromStatusCode for Error {
 	fn from_status_code(status_code: u32) -> Result<(), Self> {
 		match status_code {
 			0 => Ok(()),
-			1 => Err(Self::Failed),
-			_ => panic!("encountered unknown status code"),
+			2 => Err(Self::CannotLookup),
+			3 => Err(Self::BadOrigin),
+			4 => Err(Self::ConsumerRemaining),
+			5 => Err(Self::NoProviders),
+			6 => Err(Self::TooManyConsumers),
+			7 => Err(Self::Token),
+			8 => Err(Self::Arithmetic),
+			9 => Err(Self::Transactional),
+			10 => Err(Self::Exhausted),
+			11 => Err(Self::Corruption),
+			12 => Err(Self::Unavailable),
+			13 => Err(Self::RootNotAllowed),
+			code if code & MODULE_ERROR != 0 => {
+				Err(Self::Module { index: (code >> 8) as u8, error: code as u8 })
+			},
+			_ => Err(Self::Failed),
 		}
 	}
 }
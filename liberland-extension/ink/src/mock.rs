@@ -0,0 +1,40 @@
+//! Mock of the chain extension for contracts' off-chain unit tests.
+
+use ink::scale::{Decode, Encode};
+
+type AccountId = <ink::env::DefaultEnvironment as ink::env::Environment>::AccountId;
+
+/// Answers identity queries (`is_identified`, `is_citizen`, `is_eresident`)
+/// from given lists of accounts. Fails all other functions.
+///
+/// ```ignore
+/// ink::env::test::register_chain_extension(MockedCitizenship {
+///     citizens: vec![alice],
+///     e_residents: vec![bob],
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockedCitizenship {
+	pub citizens: Vec<AccountId>,
+	pub e_residents: Vec<AccountId>,
+}
+
+impl ink::env::test::ChainExtension for MockedCitizenship {
+	fn ext_id(&self) -> u16 {
+		0
+	}
+
+	fn call(&mut self, func_id: u16, mut input: &[u8], output: &mut Vec<u8>) -> u32 {
+		let Ok(account) = AccountId::decode(&mut input) else {
+			return 1;
+		};
+		let answer = match func_id {
+			3 => self.citizens.contains(&account) || self.e_residents.contains(&account),
+			13 => self.citizens.contains(&account),
+			38 => self.e_residents.contains(&account),
+			_ => return 1,
+		};
+		answer.encode_to(output);
+		0
+	}
+}
@@ -1,7 +1,10 @@
 use ink::env::Environment;
+use ink::prelude::vec::Vec;
 
 type AccountId = <ink::env::DefaultEnvironment as Environment>::AccountId;
 type Balance = <ink::env::DefaultEnvironment as Environment>::Balance;
+type BlockNumber = <ink::env::DefaultEnvironment as Environment>::BlockNumber;
+type Timestamp = <ink::env::DefaultEnvironment as Environment>::Timestamp;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -20,10 +23,145 @@ pub struct LLMForceTransferArguments {
 	pub amount: Balance,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct LLDForceTransferArguments {
+	pub to: AccountId,
+	pub amount: Balance,
+}
+
+/// Mirrors `pallet_identity::Judgement`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Judgement {
+	Unknown,
+	FeePaid(Balance),
+	Reasonable,
+	KnownGood,
+	OutOfDate,
+	LowQuality,
+	Erroneous,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct Identity {
+	/// Judgements by registrar index
+	pub judgements: Vec<(u32, Judgement)>,
+	pub display: Option<Vec<u8>>,
+	pub legal: Option<Vec<u8>>,
+	/// Additional fields like `citizen` or `eligible_on`
+	pub additional: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Coords {
+	pub lat: i64,
+	pub long: i64,
+}
+
+/// Metadata of land plots, validated by the runtime when set
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct LandMetadata {
+	/// Polygon outlining the plot
+	pub demarcation: Vec<Coords>,
+	pub r#type: Vec<u8>,
+	pub status: Vec<u8>,
+}
+
+/// Mirrors `pallet_democracy::Conviction`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Conviction {
+	None,
+	Locked1x,
+	Locked2x,
+	Locked3x,
+	Locked4x,
+	Locked5x,
+	Locked6x,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct DemocracyVoteArguments {
+	pub ref_index: u32,
+	pub aye: bool,
+	pub conviction: Conviction,
+	pub balance: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct OngoingReferendum {
+	pub index: u32,
+	/// Block at which voting ends
+	pub end: BlockNumber,
+	pub ayes: Balance,
+	pub nays: Balance,
+	pub turnout: Balance,
+}
+
+/// Chain state commonly needed by governance contracts
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct ChainContext {
+	pub block: BlockNumber,
+	/// Unix milliseconds
+	pub timestamp: Timestamp,
+	pub session: u32,
+	/// Active staking era, `None` before the first era starts
+	pub era: Option<u32>,
+	/// Current council members
+	pub council: Vec<AccountId>,
+}
+
+/// Mirrors `pallet_staking::RewardDestination`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum RewardDestination {
+	/// Pay into the stash account, increasing the amount at stake
+	Staked,
+	Stash,
+	Controller,
+	Account(AccountId),
+	None,
+}
+
+/// Failure of a runtime call made by the extension. Mirrors
+/// `sp_runtime::DispatchError` without the details.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 pub enum Error {
+	/// `DispatchError::Other` or unknown status code
 	Failed,
+	CannotLookup,
+	BadOrigin,
+	ConsumerRemaining,
+	NoProviders,
+	TooManyConsumers,
+	Token,
+	Arithmetic,
+	Transactional,
+	Exhausted,
+	Corruption,
+	Unavailable,
+	RootNotAllowed,
+	/// Error of a pallet - `index` of the pallet in the runtime and `error`
+	/// index of the variant in pallet's `Error` enum
+	Module {
+		index: u8,
+		error: u8,
+	},
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
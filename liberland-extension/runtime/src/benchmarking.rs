@@ -0,0 +1,242 @@
+/*
+Copyright © 2023 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+//! Benchmarks of extension functions that don't dispatch calls. Dispatching
+//! functions are charged with weight of the dispatched call instead.
+//!
+//! Extension isn't a pallet, so runtime adds it to `define_benchmarks!` as
+//! `[liberland_extension_runtime, LiberlandExtensionBench::<Runtime>]` and
+//! implements `Config` for itself.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use pallet_democracy::{BoundedCallOf, CallOf, VoteThreshold};
+use pallet_identity::IdentityInfo;
+use pallet_nfts::BenchmarkHelper;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+pub trait Config:
+	pallet_llm::Config
+	+ pallet_balances::Config
+	+ pallet_democracy::Config
+	+ pallet_timestamp::Config
+	+ pallet_nfts::Config
+	+ pallet_registry::Config<CompanyRegistry>
+	+ pallet_session::Config
+	+ pallet_staking::Config
+	+ pallet_collective::Config<Council>
+	+ pallet_collective::Config<Senate>
+{
+}
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+fn raw(data: &[u8]) -> Data {
+	Data::Raw(data.to_vec().try_into().unwrap())
+}
+
+/// Citizen with all additional identity fields used, to get the largest
+/// `IdentityOf` entry
+fn make_citizen<T: Config>(account: &T::AccountId) {
+	make_identified::<T>(account, b"citizen");
+}
+
+/// Same as `make_citizen`, but with `status` field (e.g. `eresident`) set
+/// instead of `citizen`
+fn make_identified<T: Config>(account: &T::AccountId, status: &[u8]) {
+	let mut additional = vec![(raw(b"eligible_on"), raw(&[0])), (raw(status), raw(b"1"))];
+	let max_fields = <T as pallet_identity::Config>::MaxAdditionalFields::get();
+	for i in additional.len() as u32..max_fields {
+		additional.push((raw(&i.to_le_bytes()), raw(&[0xff; 32])));
+	}
+	let info = IdentityInfo {
+		additional: additional.try_into().unwrap(),
+		display: raw(&[0xff; 32]),
+		legal: raw(&[0xff; 32]),
+		..Default::default()
+	};
+	let judgements = vec![(0u32, Judgement::KnownGood)].try_into().unwrap();
+	pallet_identity::Pallet::<T>::set_identity_no_deposit(account, judgements, info);
+}
+
+fn fill_collective<T: pallet_collective::Config<I>, I: 'static>() -> Vec<T::AccountId> {
+	let mut members: Vec<T::AccountId> =
+		(0..T::MaxMembers::get()).map(|i| account("member", i, SEED)).collect();
+	// collective keeps members sorted
+	members.sort();
+	pallet_collective::Members::<T, I>::put(members.clone());
+	members
+}
+
+fn make_proposal<T: Config>(n: u32) -> BoundedCallOf<T> {
+	let call: CallOf<T> = frame_system::Call::remark { remark: n.encode() }.into();
+	<T as pallet_democracy::Config>::Preimages::bound(call).unwrap()
+}
+
+benchmarks! {
+	is_identified {
+		let user: T::AccountId = account("user", 0, SEED);
+		make_citizen::<T>(&user);
+	}: {
+		assert!(pallet_llm::Pallet::<T>::ensure_stocks_allowed(&user).is_ok());
+	}
+
+	timestamp_at_block {
+		let block: BlockNumberFor<T> = 1000u32.into();
+	}: {
+		BlockTime::<T>::timestamp_at(block);
+	}
+
+	block_at_timestamp {
+		let timestamp: <T as pallet_timestamp::Config>::Moment = 1_000_000u32.into();
+	}: {
+		BlockTime::<T>::block_at(timestamp);
+	}
+
+	calendar_days_deadline {
+	}: {
+		BlockTime::<T>::calendar_days_deadline(30, 60);
+	}
+
+	llm_balance {
+		let user: T::AccountId = account("user", 0, SEED);
+	}: {
+		pallet_llm::Pallet::<T>::balance(user.clone());
+	}
+
+	is_citizen {
+		let user: T::AccountId = account("user", 0, SEED);
+		make_citizen::<T>(&user);
+	}: {
+		assert!(pallet_llm::Pallet::<T>::is_citizen(&user));
+	}
+
+	identity_of {
+		let user: T::AccountId = account("user", 0, SEED);
+		make_citizen::<T>(&user);
+	}: {
+		let reg = pallet_identity::Pallet::<T>::identity(&user).unwrap();
+		let additional: Vec<_> = reg
+			.info
+			.additional
+			.iter()
+			.filter_map(|(key, value)| Some((raw_data(key)?, raw_data(value)?)))
+			.collect();
+		IdentityOf::<T> {
+			judgements: reg.judgements.into_inner(),
+			display: raw_data(&reg.info.display),
+			legal: raw_data(&reg.info.legal),
+			additional,
+		}
+		.encode();
+	}
+
+	has_judgement {
+		let user: T::AccountId = account("user", 0, SEED);
+		make_citizen::<T>(&user);
+	}: {
+		let reg = pallet_identity::Pallet::<T>::identity(&user).unwrap();
+		assert!(reg.judgements.contains(&(0, Judgement::KnownGood)));
+	}
+
+	land_owner {
+		let collection = <T as pallet_nfts::Config>::Helper::collection(0);
+		let item = <T as pallet_nfts::Config>::Helper::item(0);
+	}: {
+		pallet_nfts::Pallet::<T>::owner(&collection, &item);
+	}
+
+	land_metadata {
+		let collection = <T as pallet_nfts::Config>::Helper::collection(0);
+		let item = <T as pallet_nfts::Config>::Helper::item(0);
+	}: {
+		pallet_nfts::Pallet::<T>::attribute(&collection, &item, &[]);
+	}
+
+	company_data {
+		let id = <T as pallet_registry::Config<CompanyRegistry>>::EntityId::default();
+	}: {
+		pallet_registry::Pallet::<T, CompanyRegistry>::registries(0, id);
+	}
+
+	is_company_officer {
+		let id = <T as pallet_registry::Config<CompanyRegistry>>::EntityId::default();
+		let user: T::AccountId = account("user", 0, SEED);
+	}: {
+		pallet_registry::Pallet::<T, CompanyRegistry>::is_officer(&id, &user);
+	}
+
+	lld_balance {
+		let user: T::AccountId = account("user", 0, SEED);
+	}: {
+		pallet_balances::Pallet::<T>::free_balance(&user);
+	}
+
+	democracy_ongoing_referenda {
+		let r in 0 .. 99;
+		for i in 0..r {
+			pallet_democracy::Pallet::<T>::internal_start_referendum(
+				make_proposal::<T>(i),
+				VoteThreshold::SuperMajorityApprove,
+				0u32.into(),
+			);
+		}
+	}: {
+		let lowest = pallet_democracy::Pallet::<T>::lowest_unbaked();
+		let count = pallet_democracy::Pallet::<T>::referendum_count();
+		let ongoing = (lowest..count)
+			.filter_map(|index| pallet_democracy::Pallet::<T>::referendum_info(index))
+			.count();
+		assert_eq!(ongoing, r as usize);
+	}
+
+	chain_context {
+		fill_collective::<T, Council>();
+	}: {
+		ChainContext::<T> {
+			block: frame_system::Pallet::<T>::block_number(),
+			timestamp: pallet_timestamp::Pallet::<T>::get(),
+			session: pallet_session::Pallet::<T>::current_index(),
+			era: pallet_staking::Pallet::<T>::active_era().map(|era| era.index),
+			council: pallet_collective::Pallet::<T, Council>::members(),
+		}
+		.encode();
+	}
+
+	is_senate_member {
+		let member = fill_collective::<T, Senate>().pop().unwrap();
+	}: {
+		assert!(pallet_collective::Pallet::<T, Senate>::is_member(&member));
+	}
+
+	congress_members {
+		fill_collective::<T, Council>();
+	}: {
+		pallet_collective::Pallet::<T, Council>::members().encode();
+	}
+
+	asset_balance {
+		let id = <T as pallet_llm::Config>::AssetId::get();
+		let user: T::AccountId = account("user", 0, SEED);
+	}: {
+		pallet_assets::Pallet::<T>::balance(id, user.clone());
+	}
+
+	is_eresident {
+		let user: T::AccountId = account("user", 0, SEED);
+		make_identified::<T>(&user, b"eresident");
+		let amount = <T as pallet_llm::Config>::EResidentMinimumPooledLLM::get().try_into().ok().unwrap();
+		pallet_llm::Pallet::<T>::transfer_from_treasury(user.clone(), amount).unwrap();
+		pallet_llm::Pallet::<T>::politics_lock(frame_system::RawOrigin::Signed(user.clone()).into(), amount).unwrap();
+	}: {
+		assert!(pallet_llm::Pallet::<T>::is_eresident(&user));
+	}
+}
@@ -0,0 +1,147 @@
+//! Conversion between block numbers and civil time (unix timestamps in
+//! milliseconds).
+//!
+//! Block time isn't exact - slots are skipped and blocks are late - so a
+//! fixed `genesis + block * block_time` formula drifts further from reality
+//! every day. All estimates here are anchored at the current block and the
+//! timestamp pallet's current time instead, so the drift accumulated so far is
+//! corrected on every call and only the remaining distance is estimated.
+//!
+//! Average block time is `2 * MinimumPeriod` of the timestamp pallet, which
+//! is how slot duration is configured for BABE chains.
+
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto};
+use sp_std::marker::PhantomData;
+
+pub const MILLISECS_PER_MINUTE: u64 = 60 * 1000;
+pub const MILLISECS_PER_DAY: u64 = 24 * 60 * MILLISECS_PER_MINUTE;
+
+/// Estimated timestamp of `block`, given current block and time.
+pub fn timestamp_at(now_block: u64, now: u64, block_time: u64, block: u64) -> u64 {
+	if block >= now_block {
+		now.saturating_add((block - now_block).saturating_mul(block_time))
+	} else {
+		now.saturating_sub((now_block - block).saturating_mul(block_time))
+	}
+}
+
+/// Estimated first block with timestamp at or after `timestamp`, given current
+/// block and time. Rounds up, so deadlines are never shortened.
+pub fn block_at(now_block: u64, now: u64, block_time: u64, timestamp: u64) -> u64 {
+	let block_time = block_time.max(1);
+	if timestamp >= now {
+		let blocks = (timestamp - now).saturating_add(block_time - 1) / block_time;
+		now_block.saturating_add(blocks)
+	} else {
+		now_block.saturating_sub((now - timestamp) / block_time)
+	}
+}
+
+/// End (local midnight) of the `days`-th calendar day after `now` in a time
+/// zone `utc_offset_minutes` away from UTC. Day of `now` itself isn't counted,
+/// so 14 days from Monday noon ends at the midnight between Monday and Tuesday
+/// two weeks later.
+pub fn calendar_days_end(now: u64, days: u32, utc_offset_minutes: i16) -> u64 {
+	let offset = (utc_offset_minutes.unsigned_abs() as u64).saturating_mul(MILLISECS_PER_MINUTE);
+	let local_now = if utc_offset_minutes >= 0 {
+		now.saturating_add(offset)
+	} else {
+		now.saturating_sub(offset)
+	};
+	let local_day_start = local_now - local_now % MILLISECS_PER_DAY;
+	let local_end = local_day_start
+		.saturating_add((days as u64).saturating_add(1).saturating_mul(MILLISECS_PER_DAY));
+	if utc_offset_minutes >= 0 {
+		local_end.saturating_sub(offset)
+	} else {
+		local_end.saturating_add(offset)
+	}
+}
+
+/// Block/time conversions anchored at the current state of runtime `T`.
+pub struct BlockTime<T>(PhantomData<T>);
+
+impl<T: pallet_timestamp::Config> BlockTime<T> {
+	/// Configured average block time in milliseconds
+	pub fn block_time() -> u64 {
+		T::MinimumPeriod::get().unique_saturated_into().saturating_mul(2)
+	}
+
+	fn now() -> (u64, u64) {
+		let now_block = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+		let now = pallet_timestamp::Pallet::<T>::get().unique_saturated_into();
+		(now_block, now)
+	}
+
+	/// Estimated timestamp of `block`
+	pub fn timestamp_at(block: BlockNumberFor<T>) -> T::Moment {
+		let (now_block, now) = Self::now();
+		let ts = timestamp_at(now_block, now, Self::block_time(), block.unique_saturated_into());
+		T::Moment::unique_saturated_from(ts)
+	}
+
+	/// Estimated first block at or after `timestamp`
+	pub fn block_at(timestamp: T::Moment) -> BlockNumberFor<T> {
+		let (now_block, now) = Self::now();
+		let block = block_at(now_block, now, Self::block_time(), timestamp.unique_saturated_into());
+		BlockNumberFor::<T>::unique_saturated_from(block)
+	}
+
+	/// Estimated block at the end of `days`-th calendar day from now in given
+	/// time zone. See [`calendar_days_end`].
+	pub fn calendar_days_deadline(days: u32, utc_offset_minutes: i16) -> BlockNumberFor<T> {
+		let (now_block, now) = Self::now();
+		let end = calendar_days_end(now, days, utc_offset_minutes);
+		BlockNumberFor::<T>::unique_saturated_from(block_at(
+			now_block,
+			now,
+			Self::block_time(),
+			end,
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HOUR: u64 = 60 * MILLISECS_PER_MINUTE;
+
+	#[test]
+	fn timestamp_at_works() {
+		assert_eq!(timestamp_at(100, 600_000, 6000, 110), 660_000);
+		assert_eq!(timestamp_at(100, 600_000, 6000, 90), 540_000);
+		assert_eq!(timestamp_at(100, 600_000, 6000, 0), 0);
+		assert_eq!(timestamp_at(1_000, 600_000, 6000, 0), 0);
+	}
+
+	#[test]
+	fn block_at_rounds_up() {
+		assert_eq!(block_at(100, 600_000, 6000, 660_000), 110);
+		assert_eq!(block_at(100, 600_000, 6000, 660_001), 111);
+		assert_eq!(block_at(100, 600_000, 6000, 540_000), 90);
+		assert_eq!(block_at(100, 600_000, 6000, 0), 0);
+	}
+
+	#[test]
+	fn block_at_is_anchored_at_now() {
+		// chain is 10 blocks behind schedule - only the future is estimated
+		assert_eq!(block_at(90, 600_000, 6000, 660_000), 100);
+	}
+
+	#[test]
+	fn calendar_days_end_works() {
+		let day_10_noon = 10 * MILLISECS_PER_DAY + 12 * HOUR;
+		assert_eq!(calendar_days_end(day_10_noon, 14, 0), 25 * MILLISECS_PER_DAY);
+		assert_eq!(calendar_days_end(day_10_noon, 0, 0), 11 * MILLISECS_PER_DAY);
+		// UTC+1 - local midnight is at 23:00 UTC
+		assert_eq!(calendar_days_end(day_10_noon, 14, 60), 25 * MILLISECS_PER_DAY - HOUR);
+		// UTC-5 - local midnight is at 05:00 UTC
+		assert_eq!(calendar_days_end(day_10_noon, 14, -300), 25 * MILLISECS_PER_DAY + 5 * HOUR);
+		// 23:30 UTC is already next day in UTC+1
+		let day_10_late = 10 * MILLISECS_PER_DAY + 23 * HOUR + 30 * MILLISECS_PER_MINUTE;
+		assert_eq!(calendar_days_end(day_10_late, 0, 60), 12 * MILLISECS_PER_DAY - HOUR);
+	}
+}
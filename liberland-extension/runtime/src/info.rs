@@ -0,0 +1,98 @@
+//! Description of the extension's functions, exposed through the runtime's
+//! `ChainExtensionApi` so that tooling can check that a contract targets a
+//! compatible runtime before deploying it.
+
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Version of the extension's interface. Bump whenever arguments, return
+/// values or semantics of an existing function change. Adding a function
+/// doesn't change the version.
+pub const VERSION: u32 = 1;
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
+pub struct FunctionInfo {
+	/// `func_id` used by contracts
+	pub id: u16,
+	/// Name of the function in `liberland_extension` ink! crate
+	pub name: Vec<u8>,
+	/// SCALE type of the input, as a tuple of `liberland_extension` types
+	pub input: Vec<u8>,
+	/// SCALE type of the output. `()` for functions that only return status
+	/// code.
+	pub output: Vec<u8>,
+}
+
+/// `(id, name, input, output)` of all functions, sorted by id
+const FUNCTIONS: &[(u16, &str, &str, &str)] = &[
+	(1, "llm_force_transfer", "(LLMForceTransferArguments)", "()"),
+	(2, "dispatch_as_contract", "(Vec<u8>)", "()"),
+	(3, "is_identified", "(AccountId)", "bool"),
+	(4, "timestamp_at_block", "(BlockNumber)", "Timestamp"),
+	(5, "block_at_timestamp", "(Timestamp)", "BlockNumber"),
+	(6, "calendar_days_deadline", "(u32, i16)", "BlockNumber"),
+	(7, "lld_force_transfer", "(LLDForceTransferArguments)", "()"),
+	(8, "revoke_citizenship", "(AccountId)", "()"),
+	(9, "send_llm", "(AccountId, Balance)", "()"),
+	(10, "llm_balance", "(AccountId)", "Balance"),
+	(11, "llm_politics_lock", "(Balance)", "()"),
+	(12, "llm_politics_unlock", "()", "()"),
+	(13, "is_citizen", "(AccountId)", "bool"),
+	(14, "identity_of", "(AccountId)", "Option<Identity>"),
+	(15, "has_judgement", "(AccountId, u32, Judgement)", "bool"),
+	(16, "land_owner", "(u32, u32)", "Option<AccountId>"),
+	(17, "land_metadata", "(u32, u32)", "Option<Vec<u8>>"),
+	(18, "company_data", "(u32)", "Option<Vec<u8>>"),
+	(19, "is_company_officer", "(u32, AccountId)", "bool"),
+	(20, "lld_transfer", "(AccountId, Balance)", "()"),
+	(21, "lld_balance", "(AccountId)", "Balance"),
+	(22, "democracy_vote", "(DemocracyVoteArguments)", "()"),
+	(23, "democracy_delegate", "(AccountId, Conviction, Balance)", "()"),
+	(24, "democracy_ongoing_referenda", "()", "Vec<OngoingReferendum>"),
+	(25, "staking_bond", "(Balance, RewardDestination)", "()"),
+	(26, "staking_nominate", "(Vec<AccountId>)", "()"),
+	(27, "staking_unbond", "(Balance)", "()"),
+	(28, "schedule_call", "(BlockNumber, Vec<u8>)", "(BlockNumber, u32)"),
+	(29, "llm_force_transfer_batch", "(Vec<LLMForceTransferArguments>)", "Vec<bool>"),
+	(30, "chain_context", "()", "ChainContext"),
+	(31, "is_senate_member", "(AccountId)", "bool"),
+	(32, "congress_members", "()", "Vec<AccountId>"),
+	(33, "asset_create", "(AssetId, Balance)", "()"),
+	(34, "asset_mint", "(AssetId, AccountId, Balance)", "()"),
+	(35, "asset_transfer", "(AssetId, AccountId, Balance)", "()"),
+	(36, "asset_burn", "(AssetId, AccountId, Balance)", "()"),
+	(37, "asset_balance", "(AssetId, AccountId)", "Balance"),
+	(38, "is_eresident", "(AccountId)", "bool"),
+];
+
+/// All functions supported by the extension
+pub fn functions() -> Vec<FunctionInfo> {
+	FUNCTIONS
+		.iter()
+		.map(|(id, name, input, output)| FunctionInfo {
+			id: *id,
+			name: name.as_bytes().to_vec(),
+			input: input.as_bytes().to_vec(),
+			output: output.as_bytes().to_vec(),
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ids_are_sorted_and_unique() {
+		assert!(FUNCTIONS.windows(2).all(|w| w[0].0 < w[1].0));
+	}
+
+	#[test]
+	fn names_are_unique() {
+		let mut names: Vec<_> = FUNCTIONS.iter().map(|f| f.1).collect();
+		names.sort();
+		names.dedup();
+		assert_eq!(names.len(), FUNCTIONS.len());
+	}
+}
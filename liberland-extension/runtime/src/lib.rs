@@ -1,11 +1,50 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	instances::{Instance1, Instance3},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{tokens::nonfungibles_v2::Inspect, Currency, Get},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use liberland_traits::CitizenshipChecker;
 use log::{error, trace};
-use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
-use sp_runtime::DispatchError;
+use pallet_contract_scheduler::WeightInfo as _;
+use pallet_contracts::chain_extension::{
+	BufInBufOutState, ChainExtension, Environment, Ext, InitState, RetVal,
+};
+use pallet_identity::{Data, Judgement};
+use sp_api::MAX_EXTRINSIC_DEPTH;
+use sp_runtime::{traits::StaticLookup, DispatchError, DispatchResult, ModuleError};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod block_time;
+use block_time::BlockTime;
+pub mod info;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
 
 type BalanceOfAssets<T> = <T as pallet_assets::Config>::Balance;
+type BalanceOf<T> = <<T as pallet_llm::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+/// Instance of `pallet_registry` holding the company registry
+type CompanyRegistry = Instance1;
+/// Instance of `pallet_collective` used for the council, also known as
+/// Congress
+type Council = Instance1;
+/// Instance of `pallet_collective` used for the senate
+type Senate = Instance3;
+type BalanceOfDemocracy<T> = <<T as pallet_democracy::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+type BalanceOfIdentity<T> = <<T as pallet_identity::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
 
 #[derive(Decode, Encode, MaxEncodedLen)]
 pub struct LLMForceTransferArguments<T: pallet_llm::Config> {
@@ -14,11 +53,138 @@ pub struct LLMForceTransferArguments<T: pallet_llm::Config> {
 	amount: BalanceOfAssets<T>,
 }
 
-/// Contract extension for the Liberland Chain
-#[derive(Default)]
-pub struct LiberlandExtension;
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct LLDForceTransferArguments<T: pallet_llm::Config> {
+	to: T::AccountId,
+	amount: BalanceOf<T>,
+}
+
+#[derive(Decode, Encode, MaxEncodedLen)]
+pub struct DemocracyVoteArguments<T: pallet_democracy::Config> {
+	ref_index: pallet_democracy::ReferendumIndex,
+	aye: bool,
+	conviction: pallet_democracy::Conviction,
+	balance: BalanceOfDemocracy<T>,
+}
+
+/// Contract-facing view of ongoing referendum
+#[derive(Encode)]
+pub struct OngoingReferendum<T: pallet_democracy::Config> {
+	index: pallet_democracy::ReferendumIndex,
+	end: BlockNumberFor<T>,
+	ayes: BalanceOfDemocracy<T>,
+	nays: BalanceOfDemocracy<T>,
+	turnout: BalanceOfDemocracy<T>,
+}
+
+/// Chain state commonly needed by governance contracts, fetched in one call
+#[derive(Encode)]
+pub struct ChainContext<T: pallet_timestamp::Config> {
+	block: BlockNumberFor<T>,
+	timestamp: T::Moment,
+	session: pallet_session::SessionIndex,
+	/// `None` before the first era starts
+	era: Option<pallet_staking::EraIndex>,
+	council: Vec<T::AccountId>,
+}
+
+/// Contract-facing view of identity. Only data stored as raw bytes is
+/// included, hashed fields are `None`.
+#[derive(Encode)]
+pub struct IdentityOf<T: pallet_identity::Config> {
+	judgements: Vec<(u32, Judgement<BalanceOfIdentity<T>>)>,
+	display: Option<Vec<u8>>,
+	legal: Option<Vec<u8>>,
+	additional: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+fn raw_data(data: &Data) -> Option<Vec<u8>> {
+	match data {
+		Data::Raw(raw) => Some(raw.to_vec()),
+		_ => None,
+	}
+}
+
+/// Status codes of module errors have this bit set, see `status_code`.
+const MODULE_ERROR: u32 = 0x1_0000;
+
+/// Status code returned to the contract when dispatch fails. Must be kept in
+/// sync with `FromStatusCode` impl of `liberland_extension::Error` in the ink
+/// crate. Module errors are encoded as
+/// `MODULE_ERROR | pallet_index << 8 | error_index`.
+fn status_code(error: DispatchError) -> u32 {
+	match error {
+		DispatchError::Other(_) => 1,
+		DispatchError::CannotLookup => 2,
+		DispatchError::BadOrigin => 3,
+		DispatchError::ConsumerRemaining => 4,
+		DispatchError::NoProviders => 5,
+		DispatchError::TooManyConsumers => 6,
+		DispatchError::Token(_) => 7,
+		DispatchError::Arithmetic(_) => 8,
+		DispatchError::Transactional(_) => 9,
+		DispatchError::Exhausted => 10,
+		DispatchError::Corruption => 11,
+		DispatchError::Unavailable => 12,
+		DispatchError::RootNotAllowed => 13,
+		DispatchError::Module(ModuleError { index, error, .. }) => {
+			MODULE_ERROR | ((index as u32) << 8) | error[0] as u32
+		},
+	}
+}
+
+/// Failed dispatch is reported to the contract as status code instead of
+/// trapping it, so contract can handle it.
+fn ret_val(result: DispatchResult) -> RetVal {
+	match result {
+		Ok(()) => RetVal::Converging(0),
+		Err(e) => RetVal::Converging(status_code(e)),
+	}
+}
+
+/// Contract extension for the Liberland Chain. Functions that don't dispatch
+/// a call charge weight given by `W`.
+pub struct LiberlandExtension<W = ()>(PhantomData<W>);
+
+impl<W> Default for LiberlandExtension<W> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<W: WeightInfo> LiberlandExtension<W> {
+	/// Dispatch `call` with contract's origin, charging its weight to the
+	/// contract and refunding unused part afterwards. Outer error means the
+	/// weight couldn't be charged and the call wasn't dispatched.
+	fn try_dispatch<E: Ext>(
+		env: &mut Environment<E, BufInBufOutState>,
+		call: <E::T as pallet_contracts::Config>::RuntimeCall,
+	) -> Result<DispatchResult, DispatchError>
+	where
+		E::T: pallet_contracts::Config,
+	{
+		let charged = env.charge_weight(call.get_dispatch_info().weight)?;
+		let result = env.ext().call_runtime(call);
+		let post_info = match &result {
+			Ok(post_info) => post_info,
+			Err(e) => &e.post_info,
+		};
+		if let Some(actual_weight) = post_info.actual_weight {
+			env.adjust_weight(charged, actual_weight);
+		}
+		Ok(result.map(|_| ()).map_err(|e| e.error))
+	}
+
+	fn charge_and_dispatch<E: Ext>(
+		env: &mut Environment<E, BufInBufOutState>,
+		call: <E::T as pallet_contracts::Config>::RuntimeCall,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts::Config,
+	{
+		Ok(ret_val(Self::try_dispatch(env, call)?))
+	}
 
-impl LiberlandExtension {
 	fn llm_force_transfer<E: Ext>(
 		&mut self,
 		env: Environment<E, InitState>,
@@ -33,7 +199,6 @@ impl LiberlandExtension {
 		);
 		let mut env = env.buf_in_buf_out();
 		let args: LLMForceTransferArguments<E::T> = env.read_as()?;
-		let ext = env.ext();
 		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
 			pallet_llm::Call::<E::T>::force_transfer {
 				from: args.from,
@@ -41,24 +206,942 @@ impl LiberlandExtension {
 				amount: args.amount,
 			}
 			.into();
-		ext.call_runtime(call).map_err(|e| e.error)?;
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn llm_force_transfer_batch<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|llm_force_transfer_batch"
+		);
+		let mut env = env.buf_in_buf_out();
+		let in_len = env.in_len();
+		let transfers: Vec<LLMForceTransferArguments<E::T>> = env.read_as_unbounded(in_len)?;
+		// every transfer is attempted to report all failures, but they're
+		// only committed if all succeeded
+		let results = with_transaction(|| {
+			let mut results = Vec::with_capacity(transfers.len());
+			for args in transfers {
+				let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+					pallet_llm::Call::<E::T>::force_transfer {
+						from: args.from,
+						to: args.to,
+						amount: args.amount,
+					}
+					.into();
+				match Self::try_dispatch(&mut env, call) {
+					Ok(result) => results.push(result.is_ok()),
+					Err(e) => return TransactionOutcome::Rollback(Err(e)),
+				}
+			}
+			if results.iter().all(|ok| *ok) {
+				TransactionOutcome::Commit(Ok(results))
+			} else {
+				TransactionOutcome::Rollback(Ok(results))
+			}
+		})?;
+		env.write(&results.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn lld_force_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|lld_force_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: LLDForceTransferArguments<E::T> = env.read_as()?;
+		// paid from LLM treasury, so contract must be one of pallet_llm's Courts
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_llm::Call::<E::T>::court_lld_transfer {
+				to_account: args.to,
+				amount: args.amount,
+			}
+			.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn revoke_citizenship<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|revoke_citizenship"
+		);
+		let mut env = env.buf_in_buf_out();
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_llm::Call::<E::T>::revoke_citizenship { account }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn send_llm<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|send_llm"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (to_account, amount): (
+			<E::T as frame_system::Config>::AccountId,
+			BalanceOfAssets<E::T>,
+		) = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_llm::Call::<E::T>::send_llm { to_account, amount }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn llm_politics_lock<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|llm_politics_lock"
+		);
+		let mut env = env.buf_in_buf_out();
+		let amount: BalanceOfAssets<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_llm::Call::<E::T>::politics_lock { amount }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn llm_politics_unlock<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|llm_politics_unlock"
+		);
+		let mut env = env.buf_in_buf_out();
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_llm::Call::<E::T>::politics_unlock {}.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn llm_balance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|llm_balance"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::llm_balance())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let balance = pallet_llm::Pallet::<E::T>::balance(account);
+		env.write(&balance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn lld_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_balances::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_balances::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|lld_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (to, value): (
+			<E::T as frame_system::Config>::AccountId,
+			<E::T as pallet_balances::Config>::Balance,
+		) = env.read_as()?;
+		let dest = <E::T as frame_system::Config>::Lookup::unlookup(to);
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_balances::Call::<E::T>::transfer_keep_alive { dest, value }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn lld_balance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_balances::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|lld_balance"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::lld_balance())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let balance = pallet_balances::Pallet::<E::T>::free_balance(&account);
+		env.write(&balance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn democracy_vote<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_democracy::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_democracy::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|democracy_vote"
+		);
+		let mut env = env.buf_in_buf_out();
+		let args: DemocracyVoteArguments<E::T> = env.read_as()?;
+		let vote = pallet_democracy::AccountVote::Standard {
+			vote: pallet_democracy::Vote { aye: args.aye, conviction: args.conviction },
+			balance: args.balance,
+		};
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_democracy::Call::<E::T>::vote { ref_index: args.ref_index, vote }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn democracy_delegate<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_democracy::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_democracy::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|democracy_delegate"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (to, conviction, balance): (
+			<E::T as frame_system::Config>::AccountId,
+			pallet_democracy::Conviction,
+			BalanceOfDemocracy<E::T>,
+		) = env.read_as()?;
+		let to = <E::T as frame_system::Config>::Lookup::unlookup(to);
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_democracy::Call::<E::T>::delegate { to, conviction, balance }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn democracy_ongoing_referenda<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_democracy::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|democracy_ongoing_referenda"
+		);
+		let mut env = env.buf_in_buf_out();
+		let lowest = pallet_democracy::Pallet::<E::T>::lowest_unbaked();
+		let count = pallet_democracy::Pallet::<E::T>::referendum_count();
+		// referenda below `LowestUnbaked` are already finished
+		env.charge_weight(W::democracy_ongoing_referenda(count.saturating_sub(lowest)))?;
+		let referenda: Vec<OngoingReferendum<E::T>> = (lowest..count)
+			.filter_map(|index| match pallet_democracy::Pallet::<E::T>::referendum_info(index)? {
+				pallet_democracy::ReferendumInfo::Ongoing(status) => Some(OngoingReferendum {
+					index,
+					end: status.end,
+					ayes: status.tally.ayes,
+					nays: status.tally.nays,
+					turnout: status.tally.turnout,
+				}),
+				_ => None,
+			})
+			.collect();
+		env.write(&referenda.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn staking_bond<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_staking::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_staking::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|staking_bond"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (value, payee): (
+			pallet_staking::BalanceOf<E::T>,
+			pallet_staking::RewardDestination<<E::T as frame_system::Config>::AccountId>,
+		) = env.read_as()?;
+		// contract's account is both stash and controller
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_staking::Call::<E::T>::bond { value, payee }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn staking_nominate<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_staking::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_staking::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|staking_nominate"
+		);
+		let mut env = env.buf_in_buf_out();
+		let in_len = env.in_len();
+		let targets: Vec<<E::T as frame_system::Config>::AccountId> =
+			env.read_as_unbounded(in_len)?;
+		let targets = targets
+			.into_iter()
+			.map(<E::T as frame_system::Config>::Lookup::unlookup)
+			.collect();
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_staking::Call::<E::T>::nominate { targets }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn staking_unbond<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_staking::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_staking::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|staking_unbond"
+		);
+		let mut env = env.buf_in_buf_out();
+		let value: pallet_staking::BalanceOf<E::T> = env.read_as()?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_staking::Call::<E::T>::unbond { value }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn schedule_call<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contract_scheduler::Config + pallet_contracts::Config,
+		<E::T as pallet_contract_scheduler::Config>::RuntimeCall:
+			From<<E::T as pallet_contracts::Config>::RuntimeCall>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|schedule_call"
+		);
+		let mut env = env.buf_in_buf_out();
+		let in_len = env.in_len();
+		let (after, encoded_call): (BlockNumberFor<E::T>, Vec<u8>) =
+			env.read_as_unbounded(in_len)?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			DecodeLimit::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &encoded_call[..])
+				.map_err(|_| DispatchError::Other("Invalid RuntimeCall"))?;
+		let call: <E::T as pallet_contract_scheduler::Config>::RuntimeCall = call.into();
+
+		// scheduling, dispatch and the call itself are prepaid now, as the
+		// scheduler doesn't charge anyone
+		let weight = <E::T as pallet_contract_scheduler::Config>::WeightInfo::schedule()
+			.saturating_add(<E::T as pallet_contract_scheduler::Config>::WeightInfo::dispatch_task())
+			.saturating_add(call.get_dispatch_info().weight);
+		env.charge_weight(weight)?;
+
+		// call filter and deposit are checked by `pallet_contract_scheduler`
+		let contract = env.ext().address().clone();
+		match pallet_contract_scheduler::Pallet::<E::T>::schedule(contract, after, call) {
+			Ok(address) => {
+				env.write(&address.encode(), false, None)?;
+				Ok(RetVal::Converging(0))
+			},
+			Err(e) => Ok(ret_val(Err(e))),
+		}
+	}
+
+	fn dispatch_as_contract<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_contracts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|dispatch_as_contract"
+		);
+		let mut env = env.buf_in_buf_out();
+		let in_len = env.in_len();
+		let encoded_call: Vec<u8> = env.read_as_unbounded(in_len)?;
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			DecodeLimit::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &encoded_call[..])
+				.map_err(|_| DispatchError::Other("Invalid RuntimeCall"))?;
+		// dispatched with contract's origin, subject to `pallet_contracts::Config::CallFilter`
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn is_identified<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_identified"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::is_identified())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		// citizen or e-resident with KnownGood judgement
+		let identified = pallet_llm::Pallet::<E::T>::ensure_stocks_allowed(&account).is_ok();
+		env.write(&identified.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn is_citizen<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_citizen"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::is_citizen())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let citizen = pallet_llm::Pallet::<E::T>::is_citizen(&account);
+		env.write(&citizen.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn is_eresident<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_llm::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_eresident"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::is_eresident())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let eresident = pallet_llm::Pallet::<E::T>::is_eresident(&account);
+		env.write(&eresident.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn identity_of<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_identity::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|identity_of"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::identity_of())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let identity =
+			pallet_identity::Pallet::<E::T>::identity(account).map(|reg| IdentityOf::<E::T> {
+				judgements: reg.judgements.into_inner(),
+				display: raw_data(&reg.info.display),
+				legal: raw_data(&reg.info.legal),
+				additional: reg
+					.info
+					.additional
+					.iter()
+					.filter_map(|(key, value)| Some((raw_data(key)?, raw_data(value)?)))
+					.collect(),
+			});
+		env.write(&identity.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn has_judgement<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_identity::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|has_judgement"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::has_judgement())?;
+		let (account, registrar, judgement): (
+			<E::T as frame_system::Config>::AccountId,
+			u32,
+			Judgement<BalanceOfIdentity<E::T>>,
+		) = env.read_as()?;
+		let has_judgement = pallet_identity::Pallet::<E::T>::identity(account)
+			.map_or(false, |reg| reg.judgements.contains(&(registrar, judgement)));
+		env.write(&has_judgement.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn land_owner<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_nfts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|land_owner"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::land_owner())?;
+		let (collection, item): (
+			<E::T as pallet_nfts::Config>::CollectionId,
+			<E::T as pallet_nfts::Config>::ItemId,
+		) = env.read_as()?;
+		let owner = pallet_nfts::Pallet::<E::T>::owner(&collection, &item);
+		env.write(&owner.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn land_metadata<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_nfts::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|land_metadata"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::land_metadata())?;
+		let (collection, item): (
+			<E::T as pallet_nfts::Config>::CollectionId,
+			<E::T as pallet_nfts::Config>::ItemId,
+		) = env.read_as()?;
+		// empty key maps to item metadata
+		let metadata = pallet_nfts::Pallet::<E::T>::attribute(&collection, &item, &[]);
+		env.write(&metadata.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn company_data<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_registry::Config<CompanyRegistry>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|company_data"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::company_data())?;
+		let id: <E::T as pallet_registry::Config<CompanyRegistry>>::EntityId = env.read_as()?;
+		// registry 0 is the Company Registry Office
+		let data = pallet_registry::Pallet::<E::T, CompanyRegistry>::registries(0, id)
+			.map(|registration| registration.data);
+		env.write(&data.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn is_company_officer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_registry::Config<CompanyRegistry>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_company_officer"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::is_company_officer())?;
+		let (id, account): (
+			<E::T as pallet_registry::Config<CompanyRegistry>>::EntityId,
+			<E::T as frame_system::Config>::AccountId,
+		) = env.read_as()?;
+		let is_officer =
+			pallet_registry::Pallet::<E::T, CompanyRegistry>::is_officer(&id, &account);
+		env.write(&is_officer.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn chain_context<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_timestamp::Config
+			+ pallet_session::Config
+			+ pallet_staking::Config
+			+ pallet_collective::Config<Council>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|chain_context"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::chain_context())?;
+		let context = ChainContext::<E::T> {
+			block: frame_system::Pallet::<E::T>::block_number(),
+			timestamp: pallet_timestamp::Pallet::<E::T>::get(),
+			session: pallet_session::Pallet::<E::T>::current_index(),
+			era: pallet_staking::Pallet::<E::T>::active_era().map(|era| era.index),
+			council: pallet_collective::Pallet::<E::T, Council>::members(),
+		};
+		env.write(&context.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn is_senate_member<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_collective::Config<Senate>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|is_senate_member"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::is_senate_member())?;
+		let account: <E::T as frame_system::Config>::AccountId = env.read_as()?;
+		let is_member = pallet_collective::Pallet::<E::T, Senate>::is_member(&account);
+		env.write(&is_member.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn congress_members<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_collective::Config<Council>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|congress_members"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::congress_members())?;
+		let members = pallet_collective::Pallet::<E::T, Council>::members();
+		env.write(&members.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn asset_create<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|asset_create"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (id, min_balance): (<E::T as pallet_assets::Config>::AssetId, BalanceOfAssets<E::T>) =
+			env.read_as()?;
+		let admin = <E::T as frame_system::Config>::Lookup::unlookup(env.ext().address().clone());
+		// contract becomes owner and admin of the new asset
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::create { id: id.into(), admin, min_balance }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn asset_mint<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|asset_mint"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (id, beneficiary, amount): (
+			<E::T as pallet_assets::Config>::AssetId,
+			<E::T as frame_system::Config>::AccountId,
+			BalanceOfAssets<E::T>,
+		) = env.read_as()?;
+		let beneficiary = <E::T as frame_system::Config>::Lookup::unlookup(beneficiary);
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::mint { id: id.into(), beneficiary, amount }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn asset_transfer<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|asset_transfer"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (id, target, amount): (
+			<E::T as pallet_assets::Config>::AssetId,
+			<E::T as frame_system::Config>::AccountId,
+			BalanceOfAssets<E::T>,
+		) = env.read_as()?;
+		let target = <E::T as frame_system::Config>::Lookup::unlookup(target);
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::transfer { id: id.into(), target, amount }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn asset_burn<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config + pallet_contracts::Config,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_assets::Call<E::T>>,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|asset_burn"
+		);
+		let mut env = env.buf_in_buf_out();
+		let (id, who, amount): (
+			<E::T as pallet_assets::Config>::AssetId,
+			<E::T as frame_system::Config>::AccountId,
+			BalanceOfAssets<E::T>,
+		) = env.read_as()?;
+		let who = <E::T as frame_system::Config>::Lookup::unlookup(who);
+		let call: <E::T as pallet_contracts::Config>::RuntimeCall =
+			pallet_assets::Call::<E::T>::burn { id: id.into(), who, amount }.into();
+		Self::charge_and_dispatch(&mut env, call)
+	}
+
+	fn asset_balance<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_assets::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|asset_balance"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::asset_balance())?;
+		let (id, account): (
+			<E::T as pallet_assets::Config>::AssetId,
+			<E::T as frame_system::Config>::AccountId,
+		) = env.read_as()?;
+		let balance = pallet_assets::Pallet::<E::T>::balance(id, account);
+		env.write(&balance.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn timestamp_at_block<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_timestamp::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|timestamp_at_block"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::timestamp_at_block())?;
+		let block: BlockNumberFor<E::T> = env.read_as()?;
+		let timestamp = BlockTime::<E::T>::timestamp_at(block);
+		env.write(&timestamp.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn block_at_timestamp<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_timestamp::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|block_at_timestamp"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::block_at_timestamp())?;
+		let timestamp: <E::T as pallet_timestamp::Config>::Moment = env.read_as()?;
+		let block = BlockTime::<E::T>::block_at(timestamp);
+		env.write(&block.encode(), false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+
+	fn calendar_days_deadline<E: Ext>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError>
+	where
+		E::T: pallet_timestamp::Config,
+	{
+		trace!(
+			target: "runtime",
+			"[ChainExtension]|call|calendar_days_deadline"
+		);
+		let mut env = env.buf_in_buf_out();
+		env.charge_weight(W::calendar_days_deadline())?;
+		let (days, utc_offset_minutes): (u32, i16) = env.read_as()?;
+		let block = BlockTime::<E::T>::calendar_days_deadline(days, utc_offset_minutes);
+		env.write(&block.encode(), false, None)?;
 		Ok(RetVal::Converging(0))
 	}
 }
 
-impl<T> ChainExtension<T> for LiberlandExtension
+impl<T, W: WeightInfo> ChainExtension<T> for LiberlandExtension<W>
 where
-	T: pallet_llm::Config + pallet_contracts::Config,
-	<T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<T>>,
+	T: pallet_llm::Config
+		+ pallet_balances::Config
+		+ pallet_democracy::Config
+		+ pallet_contracts::Config
+		+ pallet_timestamp::Config
+		+ pallet_nfts::Config
+		+ pallet_registry::Config<CompanyRegistry>
+		+ pallet_staking::Config
+		+ pallet_contract_scheduler::Config
+		+ pallet_session::Config
+		+ pallet_collective::Config<Council>
+		+ pallet_collective::Config<Senate>,
+	<T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<T>>
+		+ From<pallet_balances::Call<T>>
+		+ From<pallet_democracy::Call<T>>
+		+ From<pallet_staking::Call<T>>
+		+ From<pallet_assets::Call<T>>,
+	<T as pallet_contract_scheduler::Config>::RuntimeCall:
+		From<<T as pallet_contracts::Config>::RuntimeCall>,
 {
 	fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
 	where
-		E::T: pallet_llm::Config + pallet_contracts::Config,
-		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>,
+		E::T: pallet_llm::Config
+			+ pallet_balances::Config
+			+ pallet_democracy::Config
+			+ pallet_contracts::Config
+			+ pallet_timestamp::Config
+			+ pallet_nfts::Config
+			+ pallet_registry::Config<CompanyRegistry>
+			+ pallet_staking::Config
+			+ pallet_contract_scheduler::Config
+			+ pallet_session::Config
+			+ pallet_collective::Config<Council>
+			+ pallet_collective::Config<Senate>,
+		<E::T as pallet_contracts::Config>::RuntimeCall: From<pallet_llm::Call<E::T>>
+			+ From<pallet_balances::Call<E::T>>
+			+ From<pallet_democracy::Call<E::T>>
+			+ From<pallet_staking::Call<E::T>>
+			+ From<pallet_assets::Call<E::T>>,
+		<E::T as pallet_contract_scheduler::Config>::RuntimeCall:
+			From<<E::T as pallet_contracts::Config>::RuntimeCall>,
 	{
 		let func_id = env.func_id();
+		// keep `info::FUNCTIONS` in sync
 		match func_id {
 			1 => self.llm_force_transfer::<E>(env),
+			2 => self.dispatch_as_contract::<E>(env),
+			3 => self.is_identified::<E>(env),
+			4 => self.timestamp_at_block::<E>(env),
+			5 => self.block_at_timestamp::<E>(env),
+			6 => self.calendar_days_deadline::<E>(env),
+			7 => self.lld_force_transfer::<E>(env),
+			8 => self.revoke_citizenship::<E>(env),
+			9 => self.send_llm::<E>(env),
+			10 => self.llm_balance::<E>(env),
+			11 => self.llm_politics_lock::<E>(env),
+			12 => self.llm_politics_unlock::<E>(env),
+			13 => self.is_citizen::<E>(env),
+			14 => self.identity_of::<E>(env),
+			15 => self.has_judgement::<E>(env),
+			16 => self.land_owner::<E>(env),
+			17 => self.land_metadata::<E>(env),
+			18 => self.company_data::<E>(env),
+			19 => self.is_company_officer::<E>(env),
+			20 => self.lld_transfer::<E>(env),
+			21 => self.lld_balance::<E>(env),
+			22 => self.democracy_vote::<E>(env),
+			23 => self.democracy_delegate::<E>(env),
+			24 => self.democracy_ongoing_referenda::<E>(env),
+			25 => self.staking_bond::<E>(env),
+			26 => self.staking_nominate::<E>(env),
+			27 => self.staking_unbond::<E>(env),
+			28 => self.schedule_call::<E>(env),
+			29 => self.llm_force_transfer_batch::<E>(env),
+			30 => self.chain_context::<E>(env),
+			31 => self.is_senate_member::<E>(env),
+			32 => self.congress_members::<E>(env),
+			33 => self.asset_create::<E>(env),
+			34 => self.asset_mint::<E>(env),
+			35 => self.asset_transfer::<E>(env),
+			36 => self.asset_burn::<E>(env),
+			37 => self.asset_balance::<E>(env),
+			38 => self.is_eresident::<E>(env),
 			_ => {
 				error!("Called an unregistered `func_id`: {:}", func_id);
 				return Err(DispatchError::Other("Unimplemented func_id"));
@@ -0,0 +1,230 @@
+//! Weights for liberland_extension_runtime
+//!
+//! NOT BENCHMARKED. Estimated from storage accesses of each function. Replace
+//! with output of `substrate/scripts/run_liberland_benchmarks.sh -p
+//! liberland_extension_runtime` run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for liberland_extension_runtime.
+pub trait WeightInfo {
+	fn is_identified() -> Weight;
+	fn timestamp_at_block() -> Weight;
+	fn block_at_timestamp() -> Weight;
+	fn calendar_days_deadline() -> Weight;
+	fn llm_balance() -> Weight;
+	fn is_citizen() -> Weight;
+	fn identity_of() -> Weight;
+	fn has_judgement() -> Weight;
+	fn land_owner() -> Weight;
+	fn land_metadata() -> Weight;
+	fn company_data() -> Weight;
+	fn is_company_officer() -> Weight;
+	fn lld_balance() -> Weight;
+	fn democracy_ongoing_referenda(r: u32, ) -> Weight;
+	fn chain_context() -> Weight;
+	fn is_senate_member() -> Weight;
+	fn congress_members() -> Weight;
+	fn asset_balance() -> Weight;
+	fn is_eresident() -> Weight;
+}
+
+/// Weights for liberland_extension_runtime using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	fn is_identified() -> Weight {
+		Weight::from_parts(25_000_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn timestamp_at_block() -> Weight {
+		Weight::from_parts(4_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn block_at_timestamp() -> Weight {
+		Weight::from_parts(4_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn calendar_days_deadline() -> Weight {
+		Weight::from_parts(5_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Account` (r:1 w:0)
+	fn llm_balance() -> Weight {
+		Weight::from_parts(9_000_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	fn is_citizen() -> Weight {
+		Weight::from_parts(20_000_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	fn identity_of() -> Weight {
+		Weight::from_parts(30_000_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	fn has_judgement() -> Weight {
+		Weight::from_parts(20_000_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	fn land_owner() -> Weight {
+		Weight::from_parts(10_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Nfts::ItemMetadataOf` (r:1 w:0)
+	fn land_metadata() -> Weight {
+		Weight::from_parts(15_000_000, 19957)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CompanyRegistry::Registries` (r:1 w:0)
+	fn company_data() -> Weight {
+		Weight::from_parts(15_000_000, 11710)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CompanyRegistry::EntityOwner` (r:1 w:0)
+	fn is_company_officer() -> Weight {
+		Weight::from_parts(8_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:0)
+	fn lld_balance() -> Weight {
+		Weight::from_parts(8_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Democracy::LowestUnbaked` (r:1 w:0)
+	/// Storage: `Democracy::ReferendumCount` (r:1 w:0)
+	/// Storage: `Democracy::ReferendumInfoOf` (r:99 w:0)
+	/// The range of component `r` is `[0, 99]`.
+	fn democracy_ongoing_referenda(r: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(r.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Storage: `Staking::ActiveEra` (r:1 w:0)
+	/// Storage: `Council::Members` (r:1 w:0)
+	fn chain_context() -> Weight {
+		Weight::from_parts(20_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+	}
+	/// Storage: `Senate::Members` (r:1 w:0)
+	fn is_senate_member() -> Weight {
+		Weight::from_parts(9_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	fn congress_members() -> Weight {
+		Weight::from_parts(10_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Account` (r:1 w:0)
+	fn asset_balance() -> Weight {
+		Weight::from_parts(9_000_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `LLM::LLMPolitics` (r:1 w:0)
+	fn is_eresident() -> Weight {
+		Weight::from_parts(22_000_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn is_identified() -> Weight {
+		Weight::from_parts(25_000_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	fn timestamp_at_block() -> Weight {
+		Weight::from_parts(4_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn block_at_timestamp() -> Weight {
+		Weight::from_parts(4_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn calendar_days_deadline() -> Weight {
+		Weight::from_parts(5_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn llm_balance() -> Weight {
+		Weight::from_parts(9_000_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn is_citizen() -> Weight {
+		Weight::from_parts(20_000_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn identity_of() -> Weight {
+		Weight::from_parts(30_000_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn has_judgement() -> Weight {
+		Weight::from_parts(20_000_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn land_owner() -> Weight {
+		Weight::from_parts(10_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn land_metadata() -> Weight {
+		Weight::from_parts(15_000_000, 19957)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn company_data() -> Weight {
+		Weight::from_parts(15_000_000, 11710)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn is_company_officer() -> Weight {
+		Weight::from_parts(8_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn lld_balance() -> Weight {
+		Weight::from_parts(8_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn democracy_ongoing_referenda(r: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(r.into()))
+	}
+	fn chain_context() -> Weight {
+		Weight::from_parts(20_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+	fn is_senate_member() -> Weight {
+		Weight::from_parts(9_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn congress_members() -> Weight {
+		Weight::from_parts(10_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn asset_balance() -> Weight {
+		Weight::from_parts(9_000_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn is_eresident() -> Weight {
+		Weight::from_parts(22_000_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+}
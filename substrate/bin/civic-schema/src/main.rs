@@ -0,0 +1,289 @@
+//! Generates JSON schemas of Liberland-specific SCALE types.
+//!
+//! Types are taken from their `scale_info` metadata, so schema always matches
+//! encoding used by the runtime and contracts it's built against. See
+//! README.md for the JSON representation.
+
+use kitchensink_runtime::{civic_schema_api::CIVIC_SCHEMA_VERSION, MaxAdditionalFields};
+use node_primitives::{AccountId, Balance};
+use scale_info::{
+	form::PortableForm, meta_type, Field, MetaType, PortableRegistry, Registry, Type, TypeDef,
+	TypeDefPrimitive,
+};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Exported types, available as `#/$defs/<name>`
+fn roots() -> Vec<(&'static str, MetaType)> {
+	vec![
+		(
+			"LLMForceTransferArguments",
+			meta_type::<liberland_extension::LLMForceTransferArguments>(),
+		),
+		(
+			"LLDForceTransferArguments",
+			meta_type::<liberland_extension::LLDForceTransferArguments>(),
+		),
+		("CourtProposal", meta_type::<msig_court::Proposal>()),
+		("IdentityInfo", meta_type::<pallet_identity::IdentityInfo<MaxAdditionalFields>>()),
+		("IdentityJudgement", meta_type::<pallet_identity::Judgement<Balance>>()),
+		("ElectionBallot", meta_type::<pallet_elections_phragmen::Voter<AccountId, Balance>>()),
+	]
+}
+
+struct Generator {
+	registry: PortableRegistry,
+	names: BTreeMap<u32, String>,
+}
+
+impl Generator {
+	fn new(registry: PortableRegistry) -> Self {
+		let mut counts = BTreeMap::<String, u32>::new();
+		for ty in &registry.types {
+			if Self::is_named(&ty.ty) {
+				*counts.entry(ty.ty.path.segments.join("::")).or_default() += 1;
+			}
+		}
+		// generic types can be registered multiple times with different
+		// params - make their names unique with type id
+		let names = registry
+			.types
+			.iter()
+			.filter(|ty| Self::is_named(&ty.ty))
+			.map(|ty| {
+				let name = ty.ty.path.segments.join("::");
+				if counts[&name] > 1 {
+					(ty.id, format!("{}_{}", name, ty.id))
+				} else {
+					(ty.id, name)
+				}
+			})
+			.collect();
+		Self { registry, names }
+	}
+
+	/// Named types get their own definition, anonymous ones are inlined
+	fn is_named(ty: &Type<PortableForm>) -> bool {
+		!ty.path.segments.is_empty() && !Self::is_option(ty)
+	}
+
+	fn is_option(ty: &Type<PortableForm>) -> bool {
+		ty.path.segments == ["Option"]
+	}
+
+	fn resolve(&self, id: u32) -> &Type<PortableForm> {
+		self.registry
+			.resolve(id)
+			.expect("all types are registered in the registry; qed")
+	}
+
+	fn is_u8(&self, id: u32) -> bool {
+		matches!(self.resolve(id).type_def, TypeDef::Primitive(TypeDefPrimitive::U8))
+	}
+
+	fn schema_of(&self, id: u32) -> Value {
+		match self.names.get(&id) {
+			Some(name) => json!({ "$ref": format!("#/$defs/{}", name) }),
+			None => self.body_of(self.resolve(id)),
+		}
+	}
+
+	fn body_of(&self, ty: &Type<PortableForm>) -> Value {
+		match &ty.type_def {
+			TypeDef::Variant(def) if Self::is_option(ty) => {
+				let some = def
+					.variants
+					.iter()
+					.find(|v| v.name == "Some")
+					.and_then(|v| v.fields.first())
+					.expect("Option has Some variant with single field; qed");
+				json!({ "anyOf": [{ "type": "null" }, self.schema_of(some.ty.id)] })
+			},
+			TypeDef::Composite(def) => self.fields_schema(&def.fields),
+			TypeDef::Variant(def) => {
+				let variants: Vec<Value> = def
+					.variants
+					.iter()
+					.map(|v| {
+						if v.fields.is_empty() {
+							json!({ "const": v.name })
+						} else {
+							json!({
+								"type": "object",
+								"properties": { v.name.clone(): self.fields_schema(&v.fields) },
+								"required": [v.name],
+								"additionalProperties": false,
+							})
+						}
+					})
+					.collect();
+				json!({ "oneOf": variants })
+			},
+			TypeDef::Sequence(def) if self.is_u8(def.type_param.id) => {
+				json!({ "type": "string", "pattern": "^0x([0-9a-fA-F]{2})*$" })
+			},
+			TypeDef::Sequence(def) => {
+				json!({ "type": "array", "items": self.schema_of(def.type_param.id) })
+			},
+			TypeDef::Array(def) if self.is_u8(def.type_param.id) => {
+				let pattern = format!("^0x[0-9a-fA-F]{{{}}}$", def.len * 2);
+				json!({ "type": "string", "pattern": pattern })
+			},
+			TypeDef::Array(def) => json!({
+				"type": "array",
+				"items": self.schema_of(def.type_param.id),
+				"minItems": def.len,
+				"maxItems": def.len,
+			}),
+			TypeDef::Tuple(def) => self.tuple_schema(def.fields.iter().map(|f| f.id)),
+			TypeDef::Primitive(def) => Self::primitive_schema(def),
+			TypeDef::Compact(def) => self.schema_of(def.type_param.id),
+			TypeDef::BitSequence(_) => json!({ "type": "string", "pattern": "^[01]*$" }),
+		}
+	}
+
+	fn fields_schema(&self, fields: &[Field<PortableForm>]) -> Value {
+		match fields {
+			[] => json!({ "type": "null" }),
+			[field] if field.name.is_none() => self.schema_of(field.ty.id),
+			_ if fields.iter().all(|f| f.name.is_some()) => {
+				let mut properties = Map::new();
+				for field in fields {
+					let mut schema = self.schema_of(field.ty.id);
+					if !field.docs.is_empty() {
+						schema["description"] = field.docs.join("\n").trim().into();
+					}
+					properties.insert(field.name.clone().unwrap_or_default(), schema);
+				}
+				json!({
+					"type": "object",
+					"properties": properties,
+					"required": fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>(),
+					"additionalProperties": false,
+				})
+			},
+			_ => self.tuple_schema(fields.iter().map(|f| f.ty.id)),
+		}
+	}
+
+	fn tuple_schema(&self, ids: impl Iterator<Item = u32>) -> Value {
+		let items: Vec<Value> = ids.map(|id| self.schema_of(id)).collect();
+		if items.is_empty() {
+			return json!({ "type": "null" });
+		}
+		json!({ "type": "array", "prefixItems": items, "items": false })
+	}
+
+	fn primitive_schema(def: &TypeDefPrimitive) -> Value {
+		use TypeDefPrimitive::*;
+		let integer =
+			|min: i64, max: u64| json!({ "type": "integer", "minimum": min, "maximum": max });
+		match def {
+			Bool => json!({ "type": "boolean" }),
+			Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+			Str => json!({ "type": "string" }),
+			U8 => integer(0, u8::MAX.into()),
+			U16 => integer(0, u16::MAX.into()),
+			U32 => integer(0, u32::MAX.into()),
+			I8 => integer(i8::MIN.into(), i8::MAX as u64),
+			I16 => integer(i16::MIN.into(), i16::MAX as u64),
+			I32 => integer(i32::MIN.into(), i32::MAX as u64),
+			U64 | U128 | U256 => json!({ "type": "string", "pattern": "^[0-9]+$" }),
+			I64 | I128 | I256 => json!({ "type": "string", "pattern": "^-?[0-9]+$" }),
+		}
+	}
+
+	fn defs(&self) -> Map<String, Value> {
+		self.names
+			.iter()
+			.map(|(id, name)| {
+				let ty = self.resolve(*id);
+				let mut schema = self.body_of(ty);
+				if !ty.docs.is_empty() {
+					schema["description"] = ty.docs.join("\n").trim().into();
+				}
+				(name.clone(), schema)
+			})
+			.collect()
+	}
+}
+
+fn generate() -> Value {
+	let mut registry = Registry::new();
+	let roots: Vec<(&str, u32)> = roots()
+		.iter()
+		.map(|(name, ty)| (*name, registry.register_type(ty).id))
+		.collect();
+	let generator = Generator::new(registry.into());
+
+	let mut defs = generator.defs();
+	for (name, id) in roots {
+		defs.insert(name.into(), generator.schema_of(id));
+	}
+
+	json!({
+		"$schema": "https://json-schema.org/draft/2020-12/schema",
+		"$id": format!("urn:liberland:civic-types:v{}", CIVIC_SCHEMA_VERSION),
+		"title": "Liberland civic types",
+		"version": CIVIC_SCHEMA_VERSION,
+		"$defs": defs,
+	})
+}
+
+fn main() -> std::io::Result<()> {
+	let schema = serde_json::to_string_pretty(&generate())?;
+	match std::env::args().nth(1) {
+		Some(path) => std::fs::write(path, schema + "\n"),
+		None => {
+			println!("{}", schema);
+			Ok(())
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn resolve_ref<'a>(schema: &'a Value, value: &'a Value) -> &'a Value {
+		match value["$ref"].as_str() {
+			Some(path) => resolve_ref(schema, &schema["$defs"][&path["#/$defs/".len()..]]),
+			None => value,
+		}
+	}
+
+	#[test]
+	fn all_roots_and_refs_are_defined() {
+		let schema = generate();
+		for (name, _) in roots() {
+			assert!(schema["$defs"].get(name).is_some(), "{} missing", name);
+		}
+		let text = schema.to_string();
+		for reference in text.split("\"$ref\":\"").skip(1) {
+			let path = &reference[..reference.find('"').unwrap()];
+			assert!(
+				schema["$defs"].get(&path["#/$defs/".len()..]).is_some(),
+				"{} not defined",
+				path
+			);
+		}
+	}
+
+	#[test]
+	fn llm_force_transfer_arguments_representation() {
+		let schema = generate();
+		let args = resolve_ref(&schema, &schema["$defs"]["LLMForceTransferArguments"]);
+		assert_eq!(args["required"], json!(["from", "to", "amount"]));
+		assert_eq!(args["properties"]["amount"]["pattern"], json!("^[0-9]+$"));
+
+		let from = resolve_ref(&schema, &args["properties"]["from"]);
+		let liquid = &from["oneOf"][0]["properties"]["Liquid"];
+		let account = resolve_ref(&schema, liquid);
+		assert_eq!(account["pattern"], json!("^0x[0-9a-fA-F]{64}$"));
+	}
+
+	#[test]
+	fn version_matches_runtime() {
+		assert_eq!(generate()["version"], json!(CIVIC_SCHEMA_VERSION));
+	}
+}
@@ -0,0 +1,75 @@
+//! Builds contracts used by the tests with `cargo contract`.
+//!
+//! Path to prebuilt `msig_court.wasm` can be passed in `MSIG_COURT_WASM`
+//! instead. If neither works, `msig_court_wasm` cfg isn't set and tests
+//! depending on the contract are ignored.
+
+use std::{
+	env,
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+fn main() {
+	println!("cargo:rerun-if-env-changed=MSIG_COURT_WASM");
+
+	let wasm = match env::var_os("MSIG_COURT_WASM") {
+		Some(path) => Some(PathBuf::from(path)),
+		None => build_contract("msig_court"),
+	};
+
+	match wasm {
+		Some(wasm) if wasm.exists() => {
+			println!("cargo:rerun-if-changed={}", wasm.display());
+			println!("cargo:rustc-env=MSIG_COURT_WASM={}", wasm.display());
+			println!("cargo:rustc-cfg=msig_court_wasm");
+		},
+		_ => println!(
+			"cargo:warning=msig_court wasm unavailable, contract tests will be ignored. \
+			 Install cargo-contract or set MSIG_COURT_WASM."
+		),
+	}
+}
+
+fn build_contract(name: &str) -> Option<PathBuf> {
+	let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+	let contracts_dir = manifest_dir.join("../../../contracts");
+	let target_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("contracts");
+	let manifest = contracts_dir.join(name).join("Cargo.toml");
+
+	for src in [
+		contracts_dir.join(name),
+		contracts_dir.join("court_traits"),
+		manifest_dir.join("../../../liberland-extension/ink/src"),
+	] {
+		println!("cargo:rerun-if-changed={}", src.display());
+	}
+
+	// flags and target dir of the outer build don't apply to contracts
+	let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+		.current_dir(&contracts_dir)
+		.args(["contract", "build", "--release", "--quiet", "--manifest-path"])
+		.arg(&manifest)
+		.arg("--target-dir")
+		.arg(&target_dir)
+		.env_remove("CARGO_ENCODED_RUSTFLAGS")
+		.env_remove("RUSTFLAGS")
+		.env_remove("CARGO_TARGET_DIR")
+		.env_remove("RUSTC")
+		.env_remove("RUSTC_WORKSPACE_WRAPPER")
+		.status();
+
+	match status {
+		Ok(status) if status.success() => find_wasm(&target_dir.join("ink"), name),
+		_ => None,
+	}
+}
+
+/// `cargo contract` puts workspace members' artifacts in per-contract
+/// subdirectories
+fn find_wasm(ink_dir: &Path, name: &str) -> Option<PathBuf> {
+	let file = format!("{name}.wasm");
+	[ink_dir.join(&file), ink_dir.join(name).join(&file)]
+		.into_iter()
+		.find(|p| p.exists())
+}
@@ -0,0 +1,108 @@
+//! Helpers for integration tests of the Liberland runtime with contracts
+//! deployed on it.
+//!
+//! Tests run against the real runtime with development genesis, so any drift
+//! between contracts, the chain extension and pallets they call is caught
+//! without a live network.
+
+use codec::{Decode, Encode};
+use kitchensink_runtime::{
+	constants::llm::GRAINS_IN_LLM, BuildStorage, Contracts, RuntimeBlockWeights, System,
+};
+use node_cli::chain_spec::{authority_keys_from_seed, get_account_id_from_seed, testnet_genesis};
+use node_primitives::{AccountId, Balance};
+use pallet_contracts::{CollectEvents, DebugInfo, Determinism};
+use pallet_contracts_primitives::Code;
+use sp_core::sr25519;
+
+/// Well-known development account
+pub fn account(seed: &str) -> AccountId {
+	get_account_id_from_seed::<sr25519::Public>(seed)
+}
+
+/// Same account as ink! sees it
+pub fn ink_account(account: &AccountId) -> ink::primitives::AccountId {
+	ink::primitives::AccountId::from(*AsRef::<[u8; 32]>::as_ref(account))
+}
+
+/// Externalities with development genesis: Alice is the only validator and
+/// root, Alice, Bob and Charlie are citizens with 5000 LLM locked in
+/// politics.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let alice = account("Alice");
+	let total_llm = 6000 * GRAINS_IN_LLM;
+	let locked_llm = 5000 * GRAINS_IN_LLM;
+	let citizens = ["Alice", "Bob", "Charlie"]
+		.into_iter()
+		.map(|seed| (account(seed), total_llm, locked_llm))
+		.collect();
+	let storage = testnet_genesis(
+		vec![authority_keys_from_seed("Alice")],
+		vec![],
+		alice.clone(),
+		None,
+		None,
+		citizens,
+		None,
+		Some(alice),
+		vec![account("Bob")],
+	)
+	.build_storage()
+	.expect("development genesis builds");
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// events aren't deposited at genesis
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Selector of an ink! message or constructor without explicit selector
+pub fn selector(name: &str) -> [u8; 4] {
+	let hash = sp_core::hashing::blake2_256(name.as_bytes());
+	[hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Upload `code` and instantiate it with SCALE-encoded constructor `input`.
+/// Panics if instantiation fails or reverts.
+pub fn instantiate(origin: &AccountId, code: Vec<u8>, input: Vec<u8>) -> AccountId {
+	let result = Contracts::bare_instantiate(
+		origin.clone(),
+		0,
+		RuntimeBlockWeights::get().max_block,
+		None,
+		Code::Upload(code),
+		input,
+		vec![],
+		DebugInfo::UnsafeDebug,
+		CollectEvents::Skip,
+	);
+	let debug = String::from_utf8_lossy(&result.debug_message).into_owned();
+	let ret = result.result.unwrap_or_else(|e| panic!("instantiate failed: {e:?}, {debug}"));
+	assert!(!ret.result.did_revert(), "constructor reverted: {debug}");
+	ret.account_id
+}
+
+/// Call `contract` with SCALE-encoded message `input` and decode its return
+/// value. Panics if the call fails or reverts.
+pub fn call<R: Decode>(
+	origin: &AccountId,
+	contract: &AccountId,
+	value: Balance,
+	input: impl Encode,
+) -> R {
+	let result = Contracts::bare_call(
+		origin.clone(),
+		contract.clone(),
+		value,
+		RuntimeBlockWeights::get().max_block,
+		None,
+		input.encode(),
+		DebugInfo::UnsafeDebug,
+		CollectEvents::Skip,
+		Determinism::Enforced,
+	);
+	let debug = String::from_utf8_lossy(&result.debug_message).into_owned();
+	let ret = result.result.unwrap_or_else(|e| panic!("call failed: {e:?}, {debug}"));
+	assert!(!ret.did_revert(), "call reverted: {debug}");
+	R::decode(&mut &ret.data[..]).expect("return value decodes")
+}
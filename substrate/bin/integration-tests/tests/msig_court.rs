@@ -0,0 +1,91 @@
+//! `msig_court` deployed on the runtime, executing LLM force transfers
+//! through the Liberland chain extension.
+
+use kitchensink_runtime::{constants::llm::GRAINS_IN_LLM, Runtime, RuntimeOrigin, LLM};
+use liberland_extension::{LLMAccount, LLMForceTransferArguments};
+use liberland_integration_tests::{
+	account, call, ink_account, instantiate, new_test_ext, selector,
+};
+use msig_court::{Error, PropKey, Proposal, ProposalState};
+use node_primitives::{AccountId, Balance};
+use sp_runtime::DispatchError;
+
+type MessageResult<T> = Result<Result<T, Error>, ink::LangError>;
+
+fn msig_court_wasm() -> Vec<u8> {
+	let path = option_env!("MSIG_COURT_WASM").expect("built by build.rs");
+	std::fs::read(path).expect("msig_court wasm is readable")
+}
+
+/// Court with Alice and Bob as judges, both needed to execute proposals
+fn deploy_court() -> AccountId {
+	let judges = vec![(ink_account(&account("Alice")), 1u32), (ink_account(&account("Bob")), 1)];
+	let input = codec::Encode::encode(&(selector("new"), 2u32, judges));
+	instantiate(&account("Alice"), msig_court_wasm(), input)
+}
+
+fn set_courts(courts: Vec<AccountId>) {
+	LLM::set_courts(RuntimeOrigin::root(), courts.try_into().unwrap()).unwrap();
+}
+
+fn force_transfer(from: &str, to: &str, amount: Balance) -> Proposal {
+	Proposal::LLMForceTransfer(LLMForceTransferArguments {
+		from: LLMAccount::Locked(ink_account(&account(from))),
+		to: LLMAccount::Locked(ink_account(&account(to))),
+		amount,
+	})
+}
+
+/// Alice proposes, Bob approves and executes
+fn propose_and_approve(court: &AccountId, proposal: Proposal) -> ProposalState {
+	let proposed: MessageResult<(PropKey, ProposalState)> =
+		call(&account("Alice"), court, 0, (selector("propose"), proposal, None::<Vec<u8>>));
+	let (key, state) = proposed.unwrap().unwrap();
+	assert_eq!(state, ProposalState::PendingApprovals);
+
+	let approved: MessageResult<ProposalState> =
+		call(&account("Bob"), court, 0, (selector("approve"), key));
+	approved.unwrap().unwrap()
+}
+
+fn politics(seed: &str) -> Balance {
+	LLM::llm_politics(account(seed))
+}
+
+#[test]
+#[cfg_attr(not(msig_court_wasm), ignore = "msig_court wasm unavailable")]
+fn court_force_transfers_llm() {
+	new_test_ext().execute_with(|| {
+		let court = deploy_court();
+		set_courts(vec![court.clone()]);
+		let amount = 100 * GRAINS_IN_LLM;
+		let alice = politics("Alice");
+		let charlie = politics("Charlie");
+
+		let state = propose_and_approve(&court, force_transfer("Alice", "Charlie", amount));
+
+		assert_eq!(state, ProposalState::Executed(Ok(())));
+		assert_eq!(politics("Alice"), alice - amount);
+		assert_eq!(politics("Charlie"), charlie + amount);
+	});
+}
+
+#[test]
+#[cfg_attr(not(msig_court_wasm), ignore = "msig_court wasm unavailable")]
+fn unregistered_court_gets_pallet_error() {
+	new_test_ext().execute_with(|| {
+		let court = deploy_court();
+		let alice = politics("Alice");
+
+		let state =
+			propose_and_approve(&court, force_transfer("Alice", "Charlie", 100 * GRAINS_IN_LLM));
+
+		let DispatchError::Module(e) = DispatchError::from(pallet_llm::Error::<Runtime>::NotCourt)
+		else {
+			unreachable!()
+		};
+		let expected = liberland_extension::Error::Module { index: e.index, error: e.error[0] };
+		assert_eq!(state, ProposalState::Executed(Err(Error::Extension(expected))));
+		assert_eq!(politics("Alice"), alice);
+	});
+}
@@ -35,7 +35,9 @@ use kitchensink_runtime::{
 	IdentityOfficePalletId, AssetRegistryOfficeConfig,
 	LandRegistryOfficePalletId, AssetRegistryOfficePalletId,
 	MetaverseLandRegistryOfficeConfig, MetaverseLandRegistryOfficePalletId,
-	SenateConfig, MinistryOfFinanceOfficeConfig,
+	SenateConfig, MinistryOfFinanceOfficeConfig, NominationPoolsConfig,
+	NominationPoolsMinJoinBond, NominationPoolsMinCreateBond, NominationPoolsMaxPools,
+	NominationPoolsMaxCommission,
 	impls::{RegistryCallFilter, IdentityCallFilter, NftsCallFilter},
 };
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -52,7 +54,7 @@ use sp_runtime::{
 };
 
 pub use kitchensink_runtime::RuntimeGenesisConfig;
-pub use node_primitives::{AccountId, Balance, Signature};
+pub use node_primitives::{AccountId, Balance, BlockNumber, Hash, Signature};
 
 type AccountPublic = <Signature as Verify>::Signer;
 
@@ -71,8 +73,57 @@ pub struct Extensions {
 	pub bad_blocks: sc_client_api::BadBlocks<Block>,
 	/// The light sync state extension used by the sync-state rpc.
 	pub light_sync_state: sc_sync_state_rpc::LightSyncStateExtension,
+	/// GRANDPA authority set changes enacted by the node instead of the
+	/// runtime. Needed for warp sync to prove finality across them.
+	#[serde(default)]
+	pub grandpa_hard_forks: Vec<GrandpaHardFork>,
 }
 
+/// GRANDPA authority set change forced by the node, e.g. to recover from
+/// stalled finality.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrandpaHardFork {
+	/// Id of the new authority set
+	pub set_id: u64,
+	/// Hash of the block at which the set changes
+	pub block_hash: Hash,
+	/// Number of the block at which the set changes
+	pub block_number: BlockNumber,
+	/// Authorities of the new set with their weights
+	pub authorities: Vec<(GrandpaId, u64)>,
+	/// Finalized block to enact the change at, if the change is to be enacted
+	/// right away instead of waiting for `block_number` to be finalized
+	pub last_finalized: Option<BlockNumber>,
+}
+
+impl From<GrandpaHardFork> for grandpa::AuthoritySetHardFork<Block> {
+	fn from(fork: GrandpaHardFork) -> Self {
+		Self {
+			set_id: fork.set_id,
+			block: (fork.block_hash, fork.block_number),
+			authorities: fork.authorities,
+			last_finalized: fork.last_finalized,
+		}
+	}
+}
+
+/// GRANDPA hard forks from `chain_spec`'s extensions.
+pub fn grandpa_hard_forks(
+	chain_spec: &dyn sc_service::ChainSpec,
+) -> Vec<grandpa::AuthoritySetHardFork<Block>> {
+	Extensions::try_get(chain_spec)
+		.map(|e| e.grandpa_hard_forks.iter().cloned().map(Into::into).collect())
+		.unwrap_or_default()
+}
+
+/// LLD given to every endowed account by [`testnet_genesis`].
+pub const ENDOWMENT: Balance = 10_000_000 * DOLLARS;
+/// LLD bonded by every initial validator and nominator.
+pub const STASH: Balance = ENDOWMENT / 1000;
+/// LLD staked by every initial council member.
+pub const INITIAL_STAKE: Balance = 5000 * GRAINS_IN_LLM;
+
 /// Specialized `ChainSpec`.
 pub type ChainSpec = sc_service::GenericChainSpec<RuntimeGenesisConfig, Extensions>;
 
@@ -188,7 +239,8 @@ fn staging_testnet_config_genesis() -> RuntimeGenesisConfig {
 	)
 }
 
-fn properties() -> sc_chain_spec::Properties {
+/// Properties shared by Liberland chain specs.
+pub fn properties() -> sc_chain_spec::Properties {
 	let mut p = Properties::new();
 	p.insert("prefix".into(), 56.into());
 	p.insert("network".into(), "liberland".into());
@@ -326,10 +378,6 @@ pub fn testnet_genesis(
 
 	let num_endowed_accounts = endowed_accounts.len();
 
-	const ENDOWMENT: Balance = 10_000_000 * DOLLARS;
-	const STASH: Balance = ENDOWMENT / 1000;
-	const INITIAL_STAKE: Balance = 5000 * GRAINS_IN_LLM;
-
 
 	// Add Prefunded accounts
 	let f_ac: Vec<AccountId> = vec![
@@ -392,6 +440,13 @@ pub fn testnet_genesis(
 			citizenship_required: false,
 			..Default::default()
 		},
+		nomination_pools: NominationPoolsConfig {
+			min_join_bond: NominationPoolsMinJoinBond::get(),
+			min_create_bond: NominationPoolsMinCreateBond::get(),
+			max_pools: Some(NominationPoolsMaxPools::get()),
+			global_max_commission: Some(NominationPoolsMaxCommission::get()),
+			..Default::default()
+		},
 		democracy: DemocracyConfig::default(),
 		elections: ElectionsConfig {
 			members: council_group
@@ -610,7 +665,7 @@ pub(crate) mod tests {
 
 		sc_service_test::connectivity(integration_test_config_with_two_authorities(), |config| {
 			let NewFullBase { task_manager, client, network, sync, transaction_pool, .. } =
-				new_full_base(config, false, |_, _| ())?;
+				new_full_base(config, false, None, |_, _| ())?;
 			Ok(sc_service_test::TestNetComponents::new(
 				task_manager,
 				client,
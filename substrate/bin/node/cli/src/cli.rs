@@ -16,6 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::path::PathBuf;
+
 /// An overarching CLI command definition.
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -37,9 +39,33 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Index runtime events of the best chain and serve them with
+	/// `liberland_getEvents` RPC.
+	///
+	/// The index is kept in a separate database next to the node's one. On
+	/// startup, blocks imported since the last indexed one are indexed first;
+	/// `liberland_getIndexedRange` RPC returns blocks covered so far.
+	#[arg(long)]
+	pub index_events: bool,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
+
+	/// Database tuning profile.
+	///
+	/// `citizen` switches the node to ParityDB and prunes old block bodies to
+	/// keep disk usage low. Existing RocksDB databases can be moved to ParityDB
+	/// with the `migrate-db` subcommand.
+	#[arg(long, value_enum, ignore_case = true)]
+	pub db_profile: Option<DbProfile>,
+}
+
+/// Database tuning profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DbProfile {
+	/// ParityDB backend, larger trie cache, block bodies pruned after a week.
+	Citizen,
 }
 
 /// Possible subcommands of the main binary.
@@ -98,4 +124,128 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Migrate RocksDB database to ParityDB by re-importing all blocks.
+	MigrateDb(MigrateDbCmd),
+
+	/// Build a chain specification from a TOML preset.
+	BuildPresetSpec(BuildPresetSpecCmd),
+
+	/// Export state of a live chain into a chain spec for local testing.
+	ExportForkState(ExportForkStateCmd),
+
+	/// Run a local development network in a single process.
+	Dev(DevCmd),
+}
+
+/// The `migrate-db` command used to move a node from RocksDB to ParityDB.
+///
+/// Blocks are exported from the RocksDB database and imported into a fresh
+/// ParityDB database under the same base path. The node must be stopped.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct MigrateDbCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	/// Remove the RocksDB database after successful migration.
+	#[arg(long)]
+	pub remove_source: bool,
+}
+
+/// The `build-preset-spec` command used to generate a chain spec from a
+/// Liberland preset.
+///
+/// Presets list initial authorities, citizens, council and offices. They are
+/// validated before the chain spec is generated, see `presets/local.toml` for
+/// an example.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct BuildPresetSpecCmd {
+	/// Path to the TOML preset.
+	pub preset: PathBuf,
+
+	/// Force raw genesis storage output.
+	#[arg(long)]
+	pub raw: bool,
+
+	/// Write the chain spec to this file instead of stdout.
+	#[arg(long, short)]
+	pub output: Option<PathBuf>,
+}
+
+/// The `export-fork-state` command used to fork a live chain locally.
+///
+/// State of the live chain at the given block is downloaded over RPC and
+/// merged into the genesis of the local chain spec (`--chain`). Storage of
+/// pallets producing and finalizing blocks is kept from the local spec, so the
+/// fork runs with its authorities, e.g. Alice for `--chain dev`.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportForkStateCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	/// RPC endpoint of a node of the live chain.
+	#[arg(long, default_value = "ws://127.0.0.1:9944")]
+	pub uri: String,
+
+	/// Hash of the block to fork at. Defaults to the latest finalized block.
+	#[arg(long, value_name = "HASH")]
+	pub at: Option<String>,
+
+	/// Pallets whose storage is kept from the local chain spec.
+	#[arg(
+		long = "local-pallet",
+		value_name = "PALLET",
+		default_values = [
+			"Babe",
+			"Grandpa",
+			"Session",
+			"Historical",
+			"ImOnline",
+			"AuthorityDiscovery",
+			"Authorship",
+			"Sudo",
+		],
+	)]
+	pub local_pallets: Vec<String>,
+
+	/// Use runtime code of the local chain spec instead of the live one, so
+	/// its migrations run on the live state in the first block.
+	#[arg(long)]
+	pub local_runtime: bool,
+
+	/// Set sudo key of the fork to this account.
+	#[arg(long, value_name = "SS58")]
+	pub sudo: Option<String>,
+
+	/// Write the chain spec to this file instead of stdout.
+	#[arg(long, short)]
+	pub output: Option<PathBuf>,
+}
+
+/// The `dev` command used to run a local development network in a single
+/// process.
+///
+/// Alice alone validates the `dev` chain. With `--multi-node`, Alice, Bob and
+/// Charlie validate the `local` chain, each node with its own database and
+/// consecutive ports starting at `--port`, `--rpc-port` and
+/// `--prometheus-port`. Node keys are fixed, so peer ids are the same on every
+/// run. Citizens of both chains are pre-funded with LLD and LLM. Chain state
+/// is removed on exit unless `--base-path` is set.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DevCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub run: sc_cli::RunCmd,
+
+	/// Run Alice, Bob and Charlie as validators of the `local` chain.
+	#[arg(long)]
+	pub multi_node: bool,
+
+	/// Deploy `msig_court` contract from this wasm file on a fresh chain.
+	/// Validators are its judges, any two of them (or Alice alone) execute
+	/// proposals, and it's registered as an LLM court.
+	#[arg(long, value_name = "PATH")]
+	pub msig_court: Option<PathBuf>,
 }
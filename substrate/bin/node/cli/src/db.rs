@@ -0,0 +1,159 @@
+//! Database profiles and RocksDB -> ParityDB migration.
+
+use crate::{
+	cli::{DbProfile, MigrateDbCmd},
+	service::new_partial,
+};
+use sc_cli::{CliConfiguration, Database, DatabaseParams, SharedParams};
+use sc_service::{
+	chain_ops::{export_blocks, import_blocks},
+	BlocksPruning, Configuration, DatabaseSource, PartialComponents, TaskManager,
+};
+use std::{
+	fs::File,
+	future::Future,
+	path::{Path, PathBuf},
+	pin::Pin,
+};
+
+/// Block bodies kept by the `citizen` profile - a week of 6 second blocks.
+const CITIZEN_BLOCKS_PRUNING: u32 = 7 * 24 * 60 * 10;
+
+/// Trie cache size used by the `citizen` profile.
+const CITIZEN_TRIE_CACHE_SIZE: usize = 256 * 1024 * 1024;
+
+/// Name of the file holding exported blocks during migration.
+const MIGRATION_EXPORT_FILE: &str = "db-migration.blocks";
+
+/// ParityDB path matching the one `sc-cli` uses for `--database paritydb`, so
+/// nodes can switch between the profile and the explicit flag.
+fn paritydb_path(rocksdb_path: &Path) -> Option<PathBuf> {
+	let role_dir = rocksdb_path.file_name()?;
+	let config_dir = rocksdb_path.parent()?.parent()?;
+	Some(config_dir.join("paritydb").join(role_dir))
+}
+
+impl DbProfile {
+	/// Apply profile to the node configuration.
+	pub fn apply(&self, config: &mut Configuration) -> sc_cli::Result<()> {
+		match self {
+			DbProfile::Citizen => {
+				let path = match &config.database {
+					DatabaseSource::ParityDb { path } => path.clone(),
+					DatabaseSource::Auto { paritydb_path, .. } => paritydb_path.clone(),
+					DatabaseSource::RocksDb { path, .. } => {
+						let paritydb = paritydb_path(path)
+							.ok_or("Can't derive ParityDB path from RocksDB path")?;
+						if path.exists() && !paritydb.exists() {
+							log::warn!(
+								"Found RocksDB database at {:?}, but `citizen` profile uses ParityDB. \
+								Run `migrate-db` to keep the synced chain.",
+								path
+							);
+						}
+						paritydb
+					},
+					_ => return Err("`citizen` profile requires a database with a path".into()),
+				};
+				config.database = DatabaseSource::ParityDb { path };
+
+				if config.trie_cache_maximum_size.map_or(true, |s| s < CITIZEN_TRIE_CACHE_SIZE) {
+					config.trie_cache_maximum_size = Some(CITIZEN_TRIE_CACHE_SIZE);
+				}
+				// only override the default, explicit `--blocks-pruning archive` is kept
+				if matches!(config.blocks_pruning, BlocksPruning::KeepFinalized) {
+					config.blocks_pruning = BlocksPruning::Some(CITIZEN_BLOCKS_PRUNING);
+				}
+				Ok(())
+			},
+		}
+	}
+}
+
+/// Source or target side of the migration - same chain and base path,
+/// different database backend.
+pub struct MigrationSide<'a> {
+	shared_params: &'a SharedParams,
+	database_params: DatabaseParams,
+}
+
+impl<'a> CliConfiguration for MigrationSide<'a> {
+	fn shared_params(&self) -> &SharedParams {
+		self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+impl MigrateDbCmd {
+	fn side(&self, database: Database) -> MigrationSide {
+		MigrationSide {
+			shared_params: &self.shared_params,
+			database_params: DatabaseParams { database: Some(database), database_cache_size: None },
+		}
+	}
+
+	/// Configuration of the RocksDB database being migrated.
+	pub fn source(&self) -> MigrationSide {
+		self.side(Database::RocksDb)
+	}
+
+	/// Configuration of the ParityDB database being created.
+	pub fn target(&self) -> MigrationSide {
+		self.side(Database::ParityDb)
+	}
+
+	/// Export all blocks from `source` and import them into `target`. All
+	/// blocks are re-executed, so this takes about as long as a full sync,
+	/// but doesn't need network access.
+	pub fn run(
+		&self,
+		source: Configuration,
+		target: Configuration,
+	) -> sc_cli::Result<(Pin<Box<dyn Future<Output = sc_cli::Result<()>>>>, TaskManager)> {
+		let source_path = source.database.path().ok_or("Source database has no path")?.to_owned();
+		let target_path = target.database.path().ok_or("Target database has no path")?.to_owned();
+		if !source_path.exists() {
+			return Err(format!("RocksDB database not found at {:?}", source_path).into());
+		}
+		if target_path.exists() {
+			return Err(format!("ParityDB database already exists at {:?}", target_path).into());
+		}
+
+		let export_path = source.base_path.path().join(MIGRATION_EXPORT_FILE);
+		let remove_source = self.remove_source;
+
+		let PartialComponents { client: source_client, task_manager, .. } =
+			new_partial(&source, None)?;
+		let PartialComponents {
+			client: target_client,
+			import_queue,
+			task_manager: target_task_manager,
+			..
+		} = new_partial(&target, None)?;
+
+		let task = async move {
+			// keep target's tasks alive until import finishes
+			let _target_task_manager = target_task_manager;
+
+			log::info!("Exporting blocks from {:?}", source_path);
+			let output = File::create(&export_path)?;
+			export_blocks(source_client, output, 1u32.into(), None, true).await?;
+
+			log::info!("Importing blocks into {:?}", target_path);
+			let input = File::open(&export_path)?;
+			import_blocks(target_client, import_queue, input, false, true).await?;
+
+			std::fs::remove_file(&export_path)?;
+			if remove_source {
+				std::fs::remove_dir_all(&source_path)?;
+			}
+			log::info!("Migration finished, start the node with `--database paritydb`");
+			Ok(())
+		};
+
+		Ok((Box::pin(task), task_manager))
+	}
+}
@@ -0,0 +1,176 @@
+//! Local development networks running in a single process.
+//!
+//! Every validator is a full node configured as if it was started with
+//! `--chain <dev|local> --<validator>`. The first one is the boot node of the
+//! others. Optional `msig_court` deployment is submitted as regular signed
+//! extrinsics of Alice, so it's included in one of the first blocks.
+
+use crate::{
+	cli::DevCmd,
+	service::{create_extrinsic, new_full_base, FullClient, NewFullBase, TransactionPool},
+	Cli,
+};
+use codec::Encode;
+use kitchensink_runtime::{Runtime, RuntimeBlockWeights, RuntimeCall, SudoCall};
+use node_primitives::{AccountId, Balance, Hash};
+use sc_cli::{CliConfiguration, RunCmd};
+use sc_network::NetworkStateInfo;
+use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
+use sc_transaction_pool_api::{TransactionPool as _, TransactionSource};
+use sp_core::crypto::Ss58Codec;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{OpaqueExtrinsic, Perbill};
+use std::sync::Arc;
+
+/// Validators of the multi-node network. Single-node network uses the first
+/// one.
+const VALIDATORS: [Sr25519Keyring; 3] =
+	[Sr25519Keyring::Alice, Sr25519Keyring::Bob, Sr25519Keyring::Charlie];
+
+/// Ports of the first node, following nodes use consecutive ones.
+const P2P_PORT: u16 = 30333;
+const RPC_PORT: u16 = 9944;
+const PROMETHEUS_PORT: u16 = 9615;
+
+impl DevCmd {
+	fn validators(&self) -> &'static [Sr25519Keyring] {
+		if self.multi_node {
+			&VALIDATORS
+		} else {
+			&VALIDATORS[..1]
+		}
+	}
+
+	/// Command line of `index`-th validator node.
+	pub fn node(&self, index: usize) -> RunCmd {
+		let validator = self.validators()[index];
+		let offset = index as u16;
+		let mut cmd = self.run.clone();
+
+		cmd.shared_params.dev = false;
+		cmd.shared_params.chain = Some(if self.multi_node { "local" } else { "dev" }.into());
+		cmd.alice = validator == Sr25519Keyring::Alice;
+		cmd.bob = validator == Sr25519Keyring::Bob;
+		cmd.charlie = validator == Sr25519Keyring::Charlie;
+		// author before finding peers, first node has none to sync with
+		cmd.force_authoring = true;
+
+		cmd.network_params.node_key_params.node_key = Some(format!("{:064x}", index + 1));
+		cmd.network_params.port = Some(self.run.network_params.port.unwrap_or(P2P_PORT) + offset);
+		cmd.rpc_port = Some(self.run.rpc_port.unwrap_or(RPC_PORT) + offset);
+		cmd.prometheus_params.prometheus_port =
+			Some(self.run.prometheus_params.prometheus_port.unwrap_or(PROMETHEUS_PORT) + offset);
+
+		match &self.run.shared_params.base_path {
+			Some(base_path) => {
+				cmd.shared_params.base_path =
+					Some(base_path.join(validator.to_string().to_lowercase()))
+			},
+			None => cmd.tmp = true,
+		}
+		cmd
+	}
+
+	/// Start the network. `config` is the configuration of the first node,
+	/// task managers of the others are its children.
+	pub fn run(&self, config: Configuration, cli: &Cli) -> Result<TaskManager, ServiceError> {
+		let tokio_handle = config.tokio_handle.clone();
+		let NewFullBase { mut task_manager, client, network, transaction_pool, .. } =
+			new_full_base(config, true, None, |_, _| ())?;
+
+		let boot_node = format!(
+			"/ip4/127.0.0.1/tcp/{}/p2p/{}",
+			self.node(0).network_params.port.unwrap_or(P2P_PORT),
+			network.local_peer_id(),
+		);
+		for index in 1..self.validators().len() {
+			let mut cmd = self.node(index);
+			cmd.network_params
+				.bootnodes
+				.push(boot_node.parse().map_err(|e| ServiceError::Other(format!("{:?}", e)))?);
+			let config = cmd
+				.create_configuration(cli, tokio_handle.clone())
+				.map_err(|e| ServiceError::Other(e.to_string()))?;
+			let node = new_full_base(config, true, None, |_, _| ())?;
+			task_manager.add_child(node.task_manager);
+		}
+
+		if let Some(path) = &self.msig_court {
+			let code = std::fs::read(path).map_err(|e| {
+				ServiceError::Other(format!("Can't read {}: {}", path.display(), e))
+			})?;
+			self.deploy_msig_court(code, client, transaction_pool, &task_manager);
+		}
+		Ok(task_manager)
+	}
+
+	/// Submit instantiation of `msig_court` and its registration as an LLM
+	/// court. Skipped if the chain already has blocks, e.g. it was restarted
+	/// with `--base-path`.
+	fn deploy_msig_court(
+		&self,
+		code: Vec<u8>,
+		client: Arc<FullClient>,
+		transaction_pool: Arc<TransactionPool>,
+		task_manager: &TaskManager,
+	) {
+		let info = client.chain_info();
+		if info.best_number > 0 {
+			log::info!("Chain already started, msig_court isn't deployed again");
+			return
+		}
+
+		let validators = self.validators();
+		let judges: Vec<(AccountId, u32)> =
+			validators.iter().map(|v| (v.to_account_id(), 1)).collect();
+		let threshold = validators.len().min(2) as u32;
+		let constructor = sp_core::blake2_256(b"new");
+		let input = [&constructor[..4], &(threshold, judges).encode()].concat();
+
+		let deployer = Sr25519Keyring::Alice;
+		let code_hash = Hash::from(sp_core::blake2_256(&code));
+		let court = pallet_contracts::Pallet::<Runtime>::contract_address(
+			&deployer.to_account_id(),
+			&code_hash,
+			&input,
+			&[],
+		);
+
+		let instantiate = RuntimeCall::Contracts(pallet_contracts::Call::instantiate_with_code {
+			value: Balance::default(),
+			gas_limit: Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block,
+			storage_deposit_limit: None,
+			code,
+			data: input,
+			salt: vec![],
+		});
+		let set_courts = RuntimeCall::Sudo(SudoCall::sudo {
+			call: Box::new(RuntimeCall::LLM(pallet_llm::Call::set_courts {
+				courts: vec![court.clone()].try_into().expect("single court fits"),
+			})),
+		});
+		let extrinsics: Vec<OpaqueExtrinsic> = [instantiate, set_courts]
+			.into_iter()
+			.enumerate()
+			.map(|(nonce, call)| {
+				create_extrinsic(&client, deployer.pair(), call, Some(nonce as u32)).into()
+			})
+			.collect();
+
+		log::info!(
+			"Deploying msig_court at {}, {} of {} validators execute proposals",
+			court.to_ss58check(),
+			threshold,
+			validators.len(),
+		);
+		task_manager.spawn_handle().spawn("liberland-dev-msig-court", None, async move {
+			for xt in extrinsics {
+				if let Err(e) =
+					transaction_pool.submit_one(info.best_hash, TransactionSource::Local, xt).await
+				{
+					log::error!("msig_court deployment failed: {}", e);
+				}
+			}
+		});
+	}
+}
@@ -0,0 +1,153 @@
+//! Populates [`node_rpc::events::EventIndex`] during block import.
+//!
+//! On startup, blocks of the best chain imported since the last indexed one
+//! (or since genesis, if the index is empty) are indexed first. After that,
+//! the index follows new best blocks.
+//!
+//! Events are decoded with the native runtime and named after its metadata.
+//! Indexing stops with a warning at blocks with events the native runtime
+//! can't decode, e.g. after a runtime upgrade the node wasn't updated for, and
+//! `liberland_getEvents` fails for blocks past the indexed range.
+
+use crate::service::FullClient;
+use codec::{Decode, Encode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use futures::StreamExt;
+use kitchensink_runtime::{Runtime, RuntimeEvent};
+use node_primitives::{BlockNumber, Hash};
+use node_rpc::events::{EventIndex, StoredEvent};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_service::SpawnTaskHandle;
+use scale_info::TypeDef;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, twox_128};
+use sp_runtime::traits::Header;
+use std::{collections::BTreeMap, sync::Arc};
+
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Directory of the index, next to the node's database.
+pub const EVENT_INDEX_DIR: &str = "liberland-events";
+
+/// Pallet and event names by their indexes.
+struct EventNames(BTreeMap<(u8, u8), (String, String)>);
+
+impl EventNames {
+	fn from_native_runtime() -> Self {
+		let mut names = BTreeMap::new();
+		let RuntimeMetadataPrefixed(_, metadata) = Runtime::metadata();
+		let RuntimeMetadata::V14(metadata) = metadata else {
+			log::warn!("Unsupported metadata version, events won't be named");
+			return Self(names)
+		};
+		for pallet in metadata.pallets {
+			let Some(event) = pallet.event else { continue };
+			let Some(ty) = metadata.types.resolve(event.ty.id) else { continue };
+			let TypeDef::Variant(variants) = &ty.type_def else { continue };
+			for variant in &variants.variants {
+				names.insert(
+					(pallet.index, variant.index),
+					(pallet.name.clone(), variant.name.clone()),
+				);
+			}
+		}
+		Self(names)
+	}
+
+	fn stored(&self, block_hash: Hash, record: EventRecord) -> Option<StoredEvent> {
+		let encoded = record.event.encode();
+		let (pallet, event) = self.0.get(&(*encoded.first()?, *encoded.get(1)?))?.clone();
+		let extrinsic_index = match record.phase {
+			frame_system::Phase::ApplyExtrinsic(i) => Some(i),
+			_ => None,
+		};
+		Some(StoredEvent {
+			block_hash,
+			extrinsic_index,
+			pallet,
+			event,
+			data: encoded[2..].to_vec(),
+			topics: record.topics,
+		})
+	}
+}
+
+fn events_key() -> StorageKey {
+	StorageKey([twox_128(b"System"), twox_128(b"Events")].concat())
+}
+
+fn index_block(
+	client: &FullClient,
+	index: &EventIndex,
+	names: &EventNames,
+	hash: Hash,
+	number: BlockNumber,
+) -> Result<(), String> {
+	let records = match client.storage(hash, &events_key()).map_err(|e| e.to_string())? {
+		Some(data) => Vec::<EventRecord>::decode(&mut &data.0[..])
+			.map_err(|e| format!("can't decode events with native runtime: {}", e))?,
+		None => Vec::new(),
+	};
+	let events = records.into_iter().filter_map(|r| names.stored(hash, r)).collect();
+	index.insert_block(number, events).map_err(|e| e.to_string())
+}
+
+/// Index best chain blocks following the indexed range, up to `best`. Stops
+/// at the first block that fails to be indexed.
+fn catch_up(client: &FullClient, index: &EventIndex, names: &EventNames, best: BlockNumber) {
+	let next = match index.indexed_range() {
+		Ok(range) => range.map_or(0, |r| r.to_block.saturating_add(1)),
+		Err(e) => {
+			log::warn!("Failed to read indexed range: {}", e);
+			return
+		},
+	};
+	for number in next..=best {
+		let hash = match client.hash(number) {
+			Ok(Some(hash)) => hash,
+			Ok(None) => return,
+			Err(e) => {
+				log::warn!("Failed to get hash of block #{}: {}", number, e);
+				return
+			},
+		};
+		if let Err(e) = index_block(client, index, names, hash, number) {
+			log::warn!("Failed to index events of block #{} ({}): {}", number, hash, e);
+			return
+		}
+	}
+}
+
+/// Spawn task indexing events of blocks missing from the index and of
+/// blocks that become the best block.
+pub fn spawn(client: Arc<FullClient>, index: Arc<EventIndex>, spawn_handle: SpawnTaskHandle) {
+	let names = EventNames::from_native_runtime();
+	// subscribe before the backfill, so that no best block is missed
+	let mut imports = client.import_notification_stream();
+	spawn_handle.spawn_blocking("liberland-event-index", None, async move {
+		catch_up(&client, &index, &names, client.info().best_number);
+
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue
+			}
+			// on reorg, re-index already indexed blocks of the new best chain,
+			// the rest is indexed by `catch_up`
+			let number = *notification.header.number();
+			let indexed_to = index.indexed_range().ok().flatten().map(|r| r.to_block);
+			let reindexed: Vec<(Hash, BlockNumber)> = notification
+				.tree_route
+				.iter()
+				.flat_map(|route| route.enacted().iter().map(|b| (b.hash, b.number)))
+				.chain(std::iter::once((notification.hash, number)))
+				.filter(|(_, n)| indexed_to.map_or(false, |to| *n <= to))
+				.collect();
+			for (hash, number) in reindexed {
+				if let Err(e) = index_block(&client, &index, &names, hash, number) {
+					log::warn!("Failed to index events of block #{} ({}): {}", number, hash, e);
+				}
+			}
+			catch_up(&client, &index, &names, number);
+		}
+	});
+}
@@ -0,0 +1,232 @@
+//! Forking live chain state into a local chain spec.
+//!
+//! Top and child tries of the live chain are downloaded over RPC with paged
+//! key queries. Storage of `--local-pallet`s comes from the local chain spec,
+//! and so does `System` storage describing the current block. Accounts and
+//! runtime upgrade markers are live, so a `--local-runtime` with a higher
+//! spec version runs its migrations on the live state.
+
+use crate::cli::ExportForkStateCmd;
+use codec::Encode;
+use node_primitives::{AccountId, Hash, Header};
+use sc_cli::{CliConfiguration, SharedParams};
+use sc_service::ChainSpec;
+use sp_core::{
+	crypto::Ss58Codec,
+	hexdisplay::HexDisplay,
+	storage::{well_known_keys, ChildInfo, Storage, StorageChild, StorageMap},
+	twox_128,
+};
+use sp_runtime::BuildStorage;
+use std::collections::HashMap;
+use substrate_rpc_client::{ws_client, ChainApi, ChildStateApi, StateApi, WsClient};
+
+/// Keys fetched per request.
+const PAGE_SIZE: u32 = 1000;
+
+/// `System` storage items taken from the live chain.
+const LIVE_SYSTEM_ITEMS: &[&[u8]] =
+	&[b"Account", b"LastRuntimeUpgrade", b"UpgradedToU32RefCount", b"UpgradedToTripleRefCount"];
+
+impl CliConfiguration for ExportForkStateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+async fn top_storage(client: &WsClient, at: Hash) -> Result<StorageMap, String> {
+	let mut storage = StorageMap::new();
+	let mut start_key = None;
+	loop {
+		let keys = StateApi::<Hash>::storage_keys_paged(
+			client,
+			None,
+			PAGE_SIZE,
+			start_key.clone(),
+			Some(at),
+		)
+		.await
+		.map_err(|e| e.to_string())?;
+		let last_page = keys.len() < PAGE_SIZE as usize;
+		start_key = keys.last().cloned();
+
+		let changes = StateApi::<Hash>::query_storage_at(client, keys, Some(at))
+			.await
+			.map_err(|e| e.to_string())?;
+		for (key, value) in changes.into_iter().flat_map(|c| c.changes) {
+			if let Some(value) = value {
+				storage.insert(key.0, value.0);
+			}
+		}
+		log::info!("Downloaded {} keys", storage.len());
+		if last_page {
+			return Ok(storage)
+		}
+	}
+}
+
+async fn child_storage(
+	client: &WsClient,
+	child_info: &ChildInfo,
+	at: Hash,
+) -> Result<StorageMap, String> {
+	let child_key = child_info.prefixed_storage_key();
+	let mut storage = StorageMap::new();
+	let mut start_key = None;
+	loop {
+		let keys = ChildStateApi::<Hash>::storage_keys_paged(
+			client,
+			child_key.clone(),
+			None,
+			PAGE_SIZE,
+			start_key.clone(),
+			Some(at),
+		)
+		.await
+		.map_err(|e| e.to_string())?;
+		let last_page = keys.len() < PAGE_SIZE as usize;
+		start_key = keys.last().cloned();
+
+		let values = ChildStateApi::<Hash>::storage_entries(
+			client,
+			child_key.clone(),
+			keys.clone(),
+			Some(at),
+		)
+		.await
+		.map_err(|e| e.to_string())?;
+		for (key, value) in keys.into_iter().zip(values) {
+			if let Some(value) = value {
+				storage.insert(key.0, value.0);
+			}
+		}
+		if last_page {
+			return Ok(storage)
+		}
+	}
+}
+
+/// Download the whole state of the live chain at block `at`.
+async fn live_storage(client: &WsClient, at: Hash) -> Result<Storage, String> {
+	let mut top = top_storage(client, at).await?;
+	let child_roots: Vec<_> = top
+		.keys()
+		.filter(|k| well_known_keys::is_child_storage_key(k))
+		.cloned()
+		.collect();
+
+	let mut children_default = HashMap::new();
+	for root in child_roots {
+		top.remove(&root);
+		let Some(storage_key) =
+			root.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+		else {
+			log::warn!("Skipping child trie of unknown type {}", HexDisplay::from(&root));
+			continue
+		};
+		let child_info = ChildInfo::new_default(storage_key);
+		let data = child_storage(client, &child_info, at).await?;
+		children_default.insert(storage_key.to_vec(), StorageChild { data, child_info });
+	}
+	log::info!("Downloaded {} child tries", children_default.len());
+	Ok(Storage { top, children_default })
+}
+
+impl ExportForkStateCmd {
+	/// Whether `key` is taken from the local chain spec.
+	fn is_local(&self, key: &[u8]) -> bool {
+		if key == well_known_keys::CODE {
+			return self.local_runtime
+		}
+		if let Some(item) = key.strip_prefix(&twox_128(b"System")) {
+			return !LIVE_SYSTEM_ITEMS.iter().any(|i| item.starts_with(&twox_128(i)))
+		}
+		self.local_pallets.iter().any(|p| key.starts_with(&twox_128(p.as_bytes())))
+	}
+
+	/// Merge live state into the local genesis.
+	fn merge(&self, local: Storage, live: Storage) -> Result<Storage, String> {
+		let mut top: StorageMap = local.top.into_iter().filter(|(k, _)| self.is_local(k)).collect();
+		top.extend(live.top.into_iter().filter(|(k, _)| !self.is_local(k)));
+		if let Some(sudo) = &self.sudo {
+			let sudo = AccountId::from_ss58check(sudo)
+				.map_err(|e| format!("Invalid sudo address {}: {:?}", sudo, e))?;
+			top.insert([twox_128(b"Sudo"), twox_128(b"Key")].concat(), sudo.encode());
+		}
+		Ok(Storage { top, children_default: live.children_default })
+	}
+
+	/// Fork the live chain and print the raw chain spec, or write it to
+	/// `--output`.
+	pub async fn run(&self, mut spec: Box<dyn ChainSpec>) -> sc_cli::Result<()> {
+		let client = ws_client(&self.uri).await?;
+		let at = match &self.at {
+			Some(at) => at.parse().map_err(|e| format!("Invalid block hash {}: {:?}", at, e))?,
+			None => ChainApi::<(), Hash, Header, ()>::finalized_head(&client)
+				.await
+				.map_err(|e| e.to_string())?,
+		};
+		log::info!("Forking {} at block {}", self.uri, at);
+
+		let live = live_storage(&client, at).await?;
+		let local = spec.build_storage()?;
+		spec.set_storage(self.merge(local, live)?);
+
+		let json = spec.as_json(true)?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{}", json),
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::Parser;
+
+	fn key(pallet: &[u8], item: &[u8]) -> Vec<u8> {
+		[twox_128(pallet), twox_128(item)].concat()
+	}
+
+	fn storage(top: &[(Vec<u8>, &[u8])]) -> Storage {
+		Storage {
+			top: top.iter().map(|(k, v)| (k.clone(), v.to_vec())).collect(),
+			children_default: Default::default(),
+		}
+	}
+
+	#[test]
+	fn live_state_is_merged_into_local_genesis() {
+		let cmd = ExportForkStateCmd::parse_from([
+			"export-fork-state",
+			"--sudo",
+			"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+		]);
+		let local = storage(&[
+			(well_known_keys::CODE.to_vec(), b"local code"),
+			(key(b"System", b"Number"), b"local"),
+			(key(b"System", b"Account"), b"local"),
+			(key(b"Babe", b"Authorities"), b"local"),
+			(key(b"Balances", b"TotalIssuance"), b"local"),
+		]);
+		let live = storage(&[
+			(well_known_keys::CODE.to_vec(), b"live code"),
+			(key(b"System", b"Number"), b"live"),
+			(key(b"System", b"Account"), b"live"),
+			(key(b"Babe", b"Authorities"), b"live"),
+			(key(b"LLM", b"LLMPolitics"), b"live"),
+		]);
+
+		let top = cmd.merge(local, live).unwrap().top;
+		assert_eq!(top[well_known_keys::CODE], b"live code");
+		assert_eq!(top[&key(b"System", b"Number")], b"local");
+		assert_eq!(top[&key(b"System", b"Account")], b"live");
+		assert_eq!(top[&key(b"Babe", b"Authorities")], b"local");
+		assert_eq!(top[&key(b"LLM", b"LLMPolitics")], b"live");
+		assert!(!top.contains_key(&key(b"Balances", b"TotalIssuance")));
+		let alice = AccountId::from_ss58check("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+		assert_eq!(top[&key(b"Sudo", b"Key")], alice.unwrap().encode());
+	}
+}
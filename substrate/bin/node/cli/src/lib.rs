@@ -31,6 +31,8 @@
 #![warn(missing_docs)]
 
 pub mod chain_spec;
+pub mod event_index;
+pub mod metrics;
 
 #[macro_use]
 pub mod service;
@@ -40,6 +42,14 @@ mod benchmarking;
 mod cli;
 #[cfg(feature = "cli")]
 mod command;
+#[cfg(feature = "cli")]
+mod db;
+#[cfg(feature = "cli")]
+mod dev;
+#[cfg(feature = "cli")]
+mod fork;
+#[cfg(feature = "cli")]
+mod preset;
 
 #[cfg(feature = "cli")]
 pub use cli::*;
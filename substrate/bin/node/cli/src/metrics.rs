@@ -0,0 +1,67 @@
+//! Prometheus metrics of Liberland subsystems.
+//!
+//! Values are read through runtime APIs whenever a new best block is
+//! imported.
+
+use crate::service::FullClient;
+use futures::StreamExt;
+use kitchensink_runtime::constants::currency::GRAINS_IN_LLM;
+use node_primitives::Hash;
+use pallet_court_runtime_api::CourtApi;
+use pallet_llm_runtime_api::LLMApi;
+use sc_client_api::BlockchainEvents;
+use sc_service::SpawnTaskHandle;
+use sp_api::{ApiError, ProvideRuntimeApi};
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{register, Gauge, PrometheusError, Registry, F64, U64};
+
+struct Metrics {
+	llm_pooled: Gauge<F64>,
+	citizens: Gauge<U64>,
+	court_open_cases: Gauge<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			llm_pooled: register(
+				Gauge::new("liberland_llm_pooled", "Total LLM locked in politics")?,
+				registry,
+			)?,
+			citizens: register(Gauge::new("liberland_citizens", "Number of citizens")?, registry)?,
+			court_open_cases: register(
+				Gauge::new("liberland_court_open_cases", "Number of court cases not closed yet")?,
+				registry,
+			)?,
+		})
+	}
+
+	fn update(&self, client: &FullClient, at: Hash) -> Result<(), ApiError> {
+		let api = client.runtime_api();
+		self.llm_pooled.set(api.total_pooled(at)? as f64 / GRAINS_IN_LLM as f64);
+		self.citizens.set(api.citizens(at)?);
+		self.court_open_cases.set(api.open_cases(at)?.into());
+		Ok(())
+	}
+}
+
+/// Register Liberland metrics in `registry` and spawn task updating them.
+pub fn spawn(
+	client: Arc<FullClient>,
+	registry: &Registry,
+	spawn_handle: SpawnTaskHandle,
+) -> Result<(), PrometheusError> {
+	let metrics = Metrics::register(registry)?;
+	let mut imports = client.import_notification_stream();
+	spawn_handle.spawn("liberland-metrics", None, async move {
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue
+			}
+			if let Err(e) = metrics.update(&client, notification.hash) {
+				log::debug!("Failed to update Liberland metrics: {}", e);
+			}
+		}
+	});
+	Ok(())
+}
@@ -0,0 +1,407 @@
+//! Chain specs built from TOML presets.
+//!
+//! Presets describe the genesis at a high level - authorities, initial
+//! citizens, council, offices - and are validated before the chain spec is
+//! generated with [`testnet_genesis`]. Amounts are in whole LLD and LLM. See
+//! `presets/local.toml` for an example.
+
+use crate::{
+	chain_spec::{properties, testnet_genesis, ChainSpec, INITIAL_STAKE, STASH},
+	cli::BuildPresetSpecCmd,
+};
+use grandpa_primitives::AuthorityId as GrandpaId;
+use kitchensink_runtime::{
+	constants::{currency::DOLLARS, llm::GRAINS_IN_LLM},
+	CitizenshipMinimum, ExistentialDeposit, RuntimeGenesisConfig, PRERELEASELLM,
+};
+use node_primitives::{AccountId, Balance};
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use sc_service::ChainType;
+use serde::Deserialize;
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_consensus_babe::AuthorityId as BabeId;
+use sp_core::{crypto::Ss58Codec, ed25519, sr25519};
+use std::collections::BTreeSet;
+
+/// Chain type of a preset.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PresetChainType {
+	/// Single-node development chain
+	Development,
+	/// Local multi-node testnet
+	Local,
+	/// Public network
+	Live,
+}
+
+impl From<PresetChainType> for ChainType {
+	fn from(chain_type: PresetChainType) -> Self {
+		match chain_type {
+			PresetChainType::Development => ChainType::Development,
+			PresetChainType::Local => ChainType::Local,
+			PresetChainType::Live => ChainType::Live,
+		}
+	}
+}
+
+/// Initial validator. Its stash is also its controller.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetAuthority {
+	/// Stash account
+	pub stash: String,
+	/// GRANDPA key, ed25519
+	pub grandpa: String,
+	/// BABE key, sr25519
+	pub babe: String,
+	/// ImOnline key, sr25519
+	pub im_online: String,
+	/// Authority discovery key, sr25519
+	pub authority_discovery: String,
+}
+
+/// Initial citizen.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetCitizen {
+	/// Citizen's account
+	pub account: String,
+	/// LLM given to the citizen from LLM treasury
+	pub llm: Balance,
+	/// Part of `llm` locked in politics
+	pub politipooled: Balance,
+}
+
+/// LLD balance overriding the default endowment.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetBalance {
+	/// Endowed account
+	pub account: String,
+	/// LLD balance
+	pub lld: Balance,
+}
+
+/// High-level description of a chain's genesis.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Preset {
+	/// Name of the chain
+	pub name: String,
+	/// Id of the chain, also used as its directory name
+	pub id: String,
+	/// Type of the chain
+	pub chain_type: PresetChainType,
+	/// Network protocol id
+	pub protocol_id: Option<String>,
+	/// Boot nodes, as multiaddresses
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	/// Sudo account
+	pub sudo: String,
+	/// Initial validators
+	pub authorities: Vec<PresetAuthority>,
+	/// Accounts endowed with the default endowment, in addition to
+	/// authorities and citizens
+	#[serde(default)]
+	pub endowed: Vec<String>,
+	/// LLD balances overriding the default endowment
+	#[serde(default)]
+	pub balances: Vec<PresetBalance>,
+	/// Initial citizens
+	#[serde(default)]
+	pub citizens: Vec<PresetCitizen>,
+	/// Initial council members, must be endowed
+	pub council: Vec<String>,
+	/// Initial technical committee, defaults to the council
+	pub technical_committee: Option<Vec<String>>,
+	/// Admin of the offices
+	pub offices_admin: Option<String>,
+	/// Clerks of the offices
+	#[serde(default)]
+	pub offices_clerks: Vec<String>,
+}
+
+fn account(address: &str) -> Result<AccountId, String> {
+	AccountId::from_ss58check_with_version(address)
+		.map(|(account, _)| account)
+		.map_err(|e| format!("Invalid address {}: {:?}", address, e))
+}
+
+fn sr25519_key<K: From<sr25519::Public>>(address: &str) -> Result<K, String> {
+	sr25519::Public::from_ss58check_with_version(address)
+		.map(|(key, _)| key.into())
+		.map_err(|e| format!("Invalid sr25519 key {}: {:?}", address, e))
+}
+
+fn ed25519_key<K: From<ed25519::Public>>(address: &str) -> Result<K, String> {
+	ed25519::Public::from_ss58check_with_version(address)
+		.map(|(key, _)| key.into())
+		.map_err(|e| format!("Invalid ed25519 key {}: {:?}", address, e))
+}
+
+fn accounts(addresses: &[String]) -> Result<Vec<AccountId>, String> {
+	addresses.iter().map(|a| account(a)).collect()
+}
+
+fn units(amount: Balance, unit: Balance) -> Result<Balance, String> {
+	amount.checked_mul(unit).ok_or_else(|| format!("Amount {} overflows", amount))
+}
+
+/// Preset with parsed accounts and keys and amounts in base units.
+#[derive(Clone)]
+struct Genesis {
+	sudo: AccountId,
+	authorities: Vec<(AccountId, AccountId, GrandpaId, BabeId, ImOnlineId, AuthorityDiscoveryId)>,
+	endowed: Vec<AccountId>,
+	balances: Vec<(AccountId, Balance)>,
+	citizens: Vec<(AccountId, Balance, Balance)>,
+	council: Vec<AccountId>,
+	technical_committee: Vec<AccountId>,
+	offices_admin: Option<AccountId>,
+	offices_clerks: Vec<AccountId>,
+}
+
+impl Genesis {
+	fn build(&self) -> RuntimeGenesisConfig {
+		let mut genesis = testnet_genesis(
+			self.authorities.clone(),
+			vec![],
+			self.sudo.clone(),
+			Some(self.endowed.clone()),
+			Some(self.council.clone()),
+			self.citizens.clone(),
+			Some(self.technical_committee.clone()),
+			self.offices_admin.clone(),
+			self.offices_clerks.clone(),
+		);
+		let endowed = &mut genesis.balances.balances;
+		for (account, balance) in &self.balances {
+			match endowed.iter_mut().find(|(a, _)| a == account) {
+				Some(entry) => entry.1 = *balance,
+				None => endowed.push((account.clone(), *balance)),
+			}
+		}
+		genesis
+	}
+}
+
+impl Preset {
+	/// Parse preset from TOML.
+	pub fn from_toml(toml: &str) -> Result<Self, String> {
+		toml::from_str(toml).map_err(|e| format!("Invalid preset: {}", e))
+	}
+
+	fn parse(&self) -> Result<Genesis, String> {
+		let authorities = self
+			.authorities
+			.iter()
+			.map(|a| {
+				let stash = account(&a.stash)?;
+				Ok((
+					stash.clone(),
+					stash,
+					ed25519_key(&a.grandpa)?,
+					sr25519_key(&a.babe)?,
+					sr25519_key(&a.im_online)?,
+					sr25519_key(&a.authority_discovery)?,
+				))
+			})
+			.collect::<Result<Vec<_>, String>>()?;
+		let balances = self
+			.balances
+			.iter()
+			.map(|b| Ok((account(&b.account)?, units(b.lld, DOLLARS)?)))
+			.collect::<Result<Vec<_>, String>>()?;
+		let citizens = self
+			.citizens
+			.iter()
+			.map(|c| {
+				Ok((
+					account(&c.account)?,
+					units(c.llm, GRAINS_IN_LLM)?,
+					units(c.politipooled, GRAINS_IN_LLM)?,
+				))
+			})
+			.collect::<Result<Vec<_>, String>>()?;
+		let council = accounts(&self.council)?;
+		let technical_committee = match &self.technical_committee {
+			Some(members) => accounts(members)?,
+			None => council.clone(),
+		};
+		Ok(Genesis {
+			sudo: account(&self.sudo)?,
+			authorities,
+			endowed: accounts(&self.endowed)?,
+			balances,
+			citizens,
+			council,
+			technical_committee,
+			offices_admin: self.offices_admin.as_deref().map(account).transpose()?,
+			offices_clerks: accounts(&self.offices_clerks)?,
+		})
+	}
+
+	/// Check invariants of the genesis and build its chain spec.
+	pub fn chain_spec(&self) -> Result<ChainSpec, String> {
+		let genesis = self.parse()?;
+		let errors = genesis.validate();
+		if !errors.is_empty() {
+			return Err(format!("Invalid preset:\n  {}", errors.join("\n  ")))
+		}
+		let boot_nodes = self
+			.boot_nodes
+			.iter()
+			.map(|n| n.parse().map_err(|e| format!("Invalid boot node {}: {}", n, e)))
+			.collect::<Result<Vec<_>, String>>()?;
+		Ok(ChainSpec::from_genesis(
+			&self.name,
+			&self.id,
+			self.chain_type.into(),
+			move || genesis.build(),
+			boot_nodes,
+			None,
+			self.protocol_id.as_deref(),
+			None,
+			Some(properties()),
+			Default::default(),
+		))
+	}
+}
+
+impl Genesis {
+	/// Returns all violated invariants.
+	fn validate(&self) -> Vec<String> {
+		let mut errors: Vec<String> = vec![];
+		if self.authorities.is_empty() {
+			errors.push("At least one authority is required".into());
+		}
+		if self.council.is_empty() {
+			errors.push("At least one council member is required".into());
+		}
+
+		let mut stashes = BTreeSet::new();
+		for (stash, ..) in &self.authorities {
+			if !stashes.insert(stash) {
+				errors.push(format!("Duplicate authority {}", stash));
+			}
+		}
+
+		let mut citizens = BTreeSet::new();
+		let mut total_llm: Balance = 0;
+		for (citizen, llm, politipooled) in &self.citizens {
+			if !citizens.insert(citizen) {
+				errors.push(format!("Duplicate citizen {}", citizen));
+			}
+			if politipooled > llm {
+				errors.push(format!("Citizen {} politipools more LLM than it has", citizen));
+			}
+			if *politipooled < CitizenshipMinimum::get() {
+				errors.push(format!(
+					"Citizen {} politipools less than {} LLM required for citizenship",
+					citizen,
+					CitizenshipMinimum::get() / GRAINS_IN_LLM
+				));
+			}
+			total_llm = total_llm.saturating_add(*llm);
+		}
+		if total_llm > PRERELEASELLM::get() {
+			errors.push(format!(
+				"Citizens get {} LLM, but only {} LLM is pre-released to the treasury",
+				total_llm / GRAINS_IN_LLM,
+				PRERELEASELLM::get() / GRAINS_IN_LLM
+			));
+		}
+
+		let mut endowed: BTreeSet<_> = self.endowed.iter().collect();
+		endowed.extend(stashes.iter().copied());
+		endowed.extend(citizens.iter().copied());
+		for (account, balance) in &self.balances {
+			if *balance < ExistentialDeposit::get() {
+				errors.push(format!("Balance of {} is below existential deposit", account));
+			}
+			if stashes.contains(account) && *balance < STASH + ExistentialDeposit::get() {
+				errors.push(format!(
+					"Authority {} can't bond {} LLD with its balance",
+					account,
+					STASH / DOLLARS
+				));
+			}
+			if self.council.contains(account) && *balance < INITIAL_STAKE {
+				errors.push(format!(
+					"Council member {} can't stake {} LLD with its balance",
+					account,
+					INITIAL_STAKE / DOLLARS
+				));
+			}
+			endowed.insert(account);
+		}
+		for member in &self.council {
+			if !endowed.contains(member) {
+				errors.push(format!("Council member {} isn't endowed", member));
+			}
+		}
+		// testnet_genesis elects at most half of the accounts endowed before
+		// citizens
+		let mut electable: BTreeSet<_> = self.endowed.iter().collect();
+		electable.extend(stashes.iter().copied());
+		let max_council = (electable.len() + 1) / 2;
+		if self.council.len() > max_council {
+			errors.push(format!(
+				"Only {} council members can be elected with {} endowed accounts and authorities",
+				max_council,
+				electable.len()
+			));
+		}
+		errors
+	}
+}
+
+impl BuildPresetSpecCmd {
+	/// Build chain spec from the preset and print it, or write it to
+	/// `--output`.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let toml = std::fs::read_to_string(&self.preset)?;
+		let spec = Preset::from_toml(&toml)?.chain_spec()?;
+		let json = spec.as_json(self.raw)?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{}", json),
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::BuildStorage;
+
+	const LOCAL: &str = include_str!("../presets/local.toml");
+
+	#[test]
+	fn local_preset_builds() {
+		let spec = Preset::from_toml(LOCAL).unwrap().chain_spec().unwrap();
+		spec.build_storage().unwrap();
+	}
+
+	#[test]
+	fn invariants_are_checked() {
+		let mut preset = Preset::from_toml(LOCAL).unwrap();
+		preset.citizens[0].politipooled = preset.citizens[0].llm + 1;
+		preset
+			.balances
+			.push(PresetBalance { account: preset.council[0].clone(), lld: 1 });
+		let err = preset.chain_spec().unwrap_err();
+		assert!(err.contains("politipools more LLM than it has"));
+		assert!(err.contains("can't stake"));
+
+		let mut preset = Preset::from_toml(LOCAL).unwrap();
+		let charlie = preset.endowed.pop().unwrap();
+		preset.balances.clear();
+		preset.council = vec![charlie];
+		let err = preset.chain_spec().unwrap_err();
+		assert!(err.contains("isn't endowed"));
+	}
+}
@@ -28,6 +28,7 @@ use futures::prelude::*;
 use kitchensink_runtime::RuntimeApi;
 use node_executor::ExecutorDispatch;
 use node_primitives::Block;
+use node_rpc::events::EventIndex;
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 use sc_executor::NativeElseWasmExecutor;
@@ -103,6 +104,7 @@ pub fn create_extrinsic(
 		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<kitchensink_runtime::Runtime>::from(
 			0, None,
 		),
+		kitchensink_runtime::impls::BoostPriority,
 	);
 
 	let raw_payload = kitchensink_runtime::SignedPayload::from_raw(
@@ -117,6 +119,7 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -129,9 +132,11 @@ pub fn create_extrinsic(
 	)
 }
 
-/// Creates a new partial node.
+/// Creates a new partial node. RPC serves events from `event_index` if
+/// it's set.
 pub fn new_partial(
 	config: &Configuration,
+	event_index: Option<Arc<EventIndex>>,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -191,13 +196,15 @@ pub fn new_partial(
 		client.clone(),
 	);
 
-	let (grandpa_block_import, grandpa_link) = grandpa::block_import(
-		client.clone(),
-		GRANDPA_JUSTIFICATION_PERIOD,
-		&(client.clone() as Arc<_>),
-		select_chain.clone(),
-		telemetry.as_ref().map(|x| x.handle()),
-	)?;
+	let (grandpa_block_import, grandpa_link) =
+		grandpa::block_import_with_authority_set_hard_forks(
+			client.clone(),
+			GRANDPA_JUSTIFICATION_PERIOD,
+			&(client.clone() as Arc<_>),
+			select_chain.clone(),
+			crate::chain_spec::grandpa_hard_forks(&*config.chain_spec),
+			telemetry.as_ref().map(|x| x.handle()),
+		)?;
 	let justification_import = grandpa_block_import.clone();
 
 	let (block_import, babe_link) = sc_consensus_babe::block_import(
@@ -272,6 +279,7 @@ pub fn new_partial(
 					finality_provider: finality_proof_provider.clone(),
 				},
 				backend: rpc_backend.clone(),
+				event_index: event_index.clone(),
 			};
 
 			node_rpc::create_full(deps).map_err(Into::into)
@@ -308,10 +316,12 @@ pub struct NewFullBase {
 	pub rpc_handlers: RpcHandlers,
 }
 
-/// Creates a full service from the configuration.
+/// Creates a full service from the configuration. Events of imported blocks
+/// are indexed into `event_index` if it's set.
 pub fn new_full_base(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
+	event_index: Option<Arc<EventIndex>>,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -333,7 +343,7 @@ pub fn new_full_base(
 		select_chain,
 		transaction_pool,
 		other: (rpc_builder, import_setup, rpc_setup, mut telemetry),
-	} = new_partial(&config)?;
+	} = new_partial(&config, event_index.clone())?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
@@ -347,10 +357,12 @@ pub fn new_full_base(
 		grandpa_protocol_name.clone(),
 	));
 
+	// serves warp proofs to peers and verifies them when started with
+	// `--sync warp`
 	let warp_sync = Arc::new(grandpa::warp_proof::NetworkProvider::new(
 		backend.clone(),
 		import_setup.1.shared_authority_set().clone(),
-		Vec::default(),
+		crate::chain_spec::grandpa_hard_forks(&*config.chain_spec),
 	));
 
 	let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
@@ -407,6 +419,14 @@ pub fn new_full_base(
 		}
 	}
 
+	if let Some(index) = event_index {
+		crate::event_index::spawn(client.clone(), index, task_manager.spawn_handle());
+	}
+
+	if let Some(registry) = prometheus_registry.as_ref() {
+		crate::metrics::spawn(client.clone(), registry, task_manager.spawn_handle())?;
+	}
+
 	let (block_import, grandpa_link, babe_link) = import_setup;
 
 	(with_startup_data)(&block_import, &babe_link);
@@ -578,7 +598,20 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let database_source = config.database.clone();
-	let task_manager = new_full_base(config, cli.no_hardware_benchmarks, |_, _| ())
+	let event_index = if cli.index_events {
+		let path = config
+			.database
+			.path()
+			.and_then(|p| p.parent())
+			.map(|p| p.join(crate::event_index::EVENT_INDEX_DIR))
+			.ok_or_else(|| ServiceError::Other("Event index requires a database path".into()))?;
+		let index = EventIndex::open(&path)
+			.map_err(|e| ServiceError::Other(format!("Can't open event index: {}", e)))?;
+		Some(Arc::new(index))
+	} else {
+		None
+	};
+	let task_manager = new_full_base(config, cli.no_hardware_benchmarks, event_index, |_, _| ())
 		.map(|NewFullBase { task_manager, .. }| task_manager)?;
 
 	sc_storage_monitor::StorageMonitorService::try_spawn(
@@ -597,6 +630,7 @@ mod tests {
 	use codec::Encode;
 	use kitchensink_runtime::{
 		constants::{currency::CENTS, time::SLOT_DURATION},
+		impls::BoostPriority,
 		Address, BalancesCall, RuntimeCall, UncheckedExtrinsic,
 	};
 	use node_primitives::{Block, DigestItem, Signature};
@@ -806,11 +840,22 @@ mod tests {
 					check_nonce,
 					check_weight,
 					tx_payment,
+					BoostPriority,
 				);
 				let raw_payload = SignedPayload::from_raw(
 					function,
 					extra,
-					((), spec_version, transaction_version, genesis_hash, genesis_hash, (), (), ()),
+					(
+						(),
+						spec_version,
+						transaction_version,
+						genesis_hash,
+						genesis_hash,
+						(),
+						(),
+						(),
+						(),
+					),
 				);
 				let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
 				let (function, extra, _) = raw_payload.deconstruct();
@@ -830,7 +875,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, sync, transaction_pool, .. } =
-					new_full_base(config, false, |_, _| ())?;
+					new_full_base(config, false, None, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(
 					task_manager,
 					client,
@@ -0,0 +1,460 @@
+//! Index of runtime events with `eth_getLogs`-like query RPC.
+//!
+//! Events are stored in a separate RocksDB database, keyed by block number
+//! and position of the event in the block. Index is populated by the node
+//! during block import, see `node_cli::event_index`. Only best chain is kept -
+//! when a block is re-imported at the same height, its events replace the old
+//! ones.
+//!
+//! The index tracks the contiguous range of blocks it covers. Queries reaching
+//! outside of it fail instead of returning an incomplete page.
+
+use codec::{Decode, Encode};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use kvdb::{DBTransaction, KeyValueDB};
+use node_primitives::{BlockNumber, Hash};
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+use std::{io, path::Path, sync::Arc};
+
+/// Column with events, keyed by block number and event index.
+const COLUMN_EVENTS: u32 = 0;
+/// Column with index metadata.
+const COLUMN_META: u32 = 1;
+const NUM_COLUMNS: u32 = 2;
+
+/// Key of the [`IndexedRange`] in `COLUMN_META`.
+const INDEXED_RANGE_KEY: &[u8] = b"indexed_range";
+
+/// Maximum number of blocks scanned by a single query.
+pub const MAX_BLOCK_RANGE: BlockNumber = 10_000;
+/// Maximum number of events returned by a single query.
+pub const MAX_PAGE_SIZE: u32 = 1_000;
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+const INVALID_FILTER_ERROR: i32 = 1;
+const DATABASE_ERROR: i32 = 2;
+const NOT_INDEXED_ERROR: i32 = 3;
+
+/// Error of event index query.
+#[derive(Debug, PartialEq, Eq)]
+pub enum QueryError {
+	/// Filter can't be served
+	InvalidFilter(String),
+	/// Index couldn't be read
+	Database(String),
+	/// Some blocks of the filter's range aren't indexed
+	NotIndexed(String),
+}
+
+impl From<QueryError> for JsonRpseeError {
+	fn from(e: QueryError) -> Self {
+		let (code, message) = match e {
+			QueryError::InvalidFilter(m) => (INVALID_FILTER_ERROR, m),
+			QueryError::Database(m) => (DATABASE_ERROR, m),
+			QueryError::NotIndexed(m) => (NOT_INDEXED_ERROR, m),
+		};
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(code, message, None::<()>)))
+	}
+}
+
+/// Runtime event as stored in the index.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct StoredEvent {
+	/// Hash of the block that emitted the event
+	pub block_hash: Hash,
+	/// Index of the extrinsic that emitted the event, `None` for events
+	/// emitted during block initialization and finalization
+	pub extrinsic_index: Option<u32>,
+	/// Name of the pallet, as in `construct_runtime!`
+	pub pallet: String,
+	/// Name of the event variant
+	pub event: String,
+	/// SCALE-encoded event fields
+	pub data: Vec<u8>,
+	/// Event topics
+	pub topics: Vec<Hash>,
+}
+
+/// Position of an event in the index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventCursor {
+	/// Block number
+	pub block_number: BlockNumber,
+	/// Index of the event in the block
+	pub event_index: u32,
+}
+
+impl EventCursor {
+	fn key(&self) -> [u8; 8] {
+		let mut key = [0u8; 8];
+		key[..4].copy_from_slice(&self.block_number.to_be_bytes());
+		key[4..].copy_from_slice(&self.event_index.to_be_bytes());
+		key
+	}
+
+	fn from_key(key: &[u8]) -> Option<Self> {
+		let block_number = BlockNumber::from_be_bytes(key.get(..4)?.try_into().ok()?);
+		let event_index = u32::from_be_bytes(key.get(4..8)?.try_into().ok()?);
+		Some(Self { block_number, event_index })
+	}
+}
+
+/// Range of blocks covered by the index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedRange {
+	/// First indexed block, inclusive
+	pub from_block: BlockNumber,
+	/// Last indexed block, inclusive
+	pub to_block: BlockNumber,
+}
+
+/// Filter of `liberland_getEvents`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter {
+	/// First block of the range, inclusive
+	pub from_block: BlockNumber,
+	/// Last block of the range, inclusive
+	pub to_block: BlockNumber,
+	/// Only events of this pallet
+	pub pallet: Option<String>,
+	/// Only events with one of these names
+	pub events: Option<Vec<String>>,
+	/// Continue from `next` of the previous page
+	pub cursor: Option<EventCursor>,
+	/// Maximum number of events returned, defaults to 100
+	pub limit: Option<u32>,
+}
+
+impl EventFilter {
+	fn matches(&self, event: &StoredEvent) -> bool {
+		self.pallet.as_ref().map_or(true, |p| *p == event.pallet)
+			&& self.events.as_ref().map_or(true, |e| e.contains(&event.event))
+	}
+}
+
+/// Event returned by `liberland_getEvents`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEvent {
+	/// Block number
+	pub block_number: BlockNumber,
+	/// Block hash
+	pub block_hash: Hash,
+	/// Index of the event in the block
+	pub event_index: u32,
+	/// Index of the extrinsic that emitted the event
+	pub extrinsic_index: Option<u32>,
+	/// Name of the pallet
+	pub pallet: String,
+	/// Name of the event
+	pub event: String,
+	/// SCALE-encoded event fields
+	pub data: Bytes,
+	/// Event topics
+	pub topics: Vec<Hash>,
+}
+
+/// Page of events returned by `liberland_getEvents`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsPage {
+	/// Matching events, ordered by block and event index
+	pub events: Vec<IndexedEvent>,
+	/// Cursor of the next page, `None` if the range was fully scanned
+	pub next: Option<EventCursor>,
+}
+
+/// Event index database.
+pub struct EventIndex {
+	db: Arc<dyn KeyValueDB>,
+}
+
+impl EventIndex {
+	/// Open or create the index at `path`.
+	pub fn open(path: &Path) -> io::Result<Self> {
+		let config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+		let db = kvdb_rocksdb::Database::open(&config, path)?;
+		Ok(Self { db: Arc::new(db) })
+	}
+
+	/// Index on top of an arbitrary key-value database, e.g. in-memory one
+	/// in tests.
+	pub fn with_db(db: Arc<dyn KeyValueDB>) -> Self {
+		Self { db }
+	}
+
+	/// Range of indexed blocks, `None` if nothing was indexed yet.
+	pub fn indexed_range(&self) -> io::Result<Option<IndexedRange>> {
+		let Some(value) = self.db.get(COLUMN_META, INDEXED_RANGE_KEY)? else { return Ok(None) };
+		IndexedRange::decode(&mut &value[..])
+			.map(Some)
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+	}
+
+	/// Replace events stored for block at `number`.
+	///
+	/// Blocks must be inserted without gaps - `number` has to be within the
+	/// indexed range or right next to it, except for the first inserted block.
+	pub fn insert_block(&self, number: BlockNumber, events: Vec<StoredEvent>) -> io::Result<()> {
+		let range = match self.indexed_range()? {
+			None => IndexedRange { from_block: number, to_block: number },
+			Some(r) => {
+				let adjacent =
+					number.saturating_add(1) >= r.from_block && number <= r.to_block.saturating_add(1);
+				if !adjacent {
+					return Err(io::Error::new(
+						io::ErrorKind::InvalidInput,
+						format!(
+							"block #{} isn't adjacent to indexed range #{}..=#{}",
+							number, r.from_block, r.to_block
+						),
+					))
+				}
+				IndexedRange {
+					from_block: r.from_block.min(number),
+					to_block: r.to_block.max(number),
+				}
+			},
+		};
+		let mut tx = DBTransaction::new();
+		tx.delete_prefix(COLUMN_EVENTS, &number.to_be_bytes());
+		for (event_index, event) in events.into_iter().enumerate() {
+			let cursor = EventCursor { block_number: number, event_index: event_index as u32 };
+			tx.put(COLUMN_EVENTS, &cursor.key(), &event.encode());
+		}
+		tx.put(COLUMN_META, INDEXED_RANGE_KEY, &range.encode());
+		self.db.write(tx)
+	}
+
+	/// Find events matching `filter`.
+	pub fn query(&self, filter: &EventFilter) -> Result<EventsPage, QueryError> {
+		use QueryError::*;
+		if filter.from_block > filter.to_block {
+			return Err(InvalidFilter("`fromBlock` is after `toBlock`".into()))
+		}
+		if filter.to_block - filter.from_block >= MAX_BLOCK_RANGE {
+			return Err(InvalidFilter(format!(
+				"Block range is limited to {} blocks",
+				MAX_BLOCK_RANGE
+			)))
+		}
+		let limit = filter.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE) as usize;
+		let start = filter
+			.cursor
+			.unwrap_or(EventCursor { block_number: filter.from_block, event_index: 0 });
+		if start.block_number < filter.from_block {
+			return Err(InvalidFilter("`cursor` is outside of the block range".into()))
+		}
+		match self.indexed_range().map_err(|e| Database(e.to_string()))? {
+			Some(r) if r.from_block <= filter.from_block && filter.to_block <= r.to_block => (),
+			Some(r) =>
+				return Err(NotIndexed(format!(
+					"Only blocks #{}..=#{} are indexed",
+					r.from_block, r.to_block
+				))),
+			None => return Err(NotIndexed("No blocks are indexed yet".into())),
+		}
+
+		let mut events = Vec::new();
+		for number in start.block_number..=filter.to_block {
+			let prefix = number.to_be_bytes();
+			for item in self.db.iter_with_prefix(COLUMN_EVENTS, &prefix) {
+				let (key, value) = item.map_err(|e| Database(e.to_string()))?;
+				let cursor = EventCursor::from_key(&key)
+					.ok_or_else(|| Database("Corrupted event key".into()))?;
+				if number == start.block_number && cursor.event_index < start.event_index {
+					continue
+				}
+				let stored =
+					StoredEvent::decode(&mut &value[..]).map_err(|e| Database(e.to_string()))?;
+				if !filter.matches(&stored) {
+					continue
+				}
+				if events.len() == limit {
+					return Ok(EventsPage { events, next: Some(cursor) })
+				}
+				events.push(IndexedEvent {
+					block_number: cursor.block_number,
+					block_hash: stored.block_hash,
+					event_index: cursor.event_index,
+					extrinsic_index: stored.extrinsic_index,
+					pallet: stored.pallet,
+					event: stored.event,
+					data: stored.data.into(),
+					topics: stored.topics,
+				});
+			}
+		}
+		Ok(EventsPage { events, next: None })
+	}
+}
+
+/// Liberland events RPC methods.
+#[rpc(client, server)]
+pub trait LiberlandEventsApi {
+	/// Find runtime events in a block range, optionally filtered by pallet
+	/// and event names. Results are paginated - pass `next` of the returned
+	/// page as `cursor` to get the following one.
+	#[method(name = "liberland_getEvents")]
+	fn get_events(&self, filter: EventFilter) -> RpcResult<EventsPage>;
+
+	/// Range of blocks `liberland_getEvents` can be queried for, `null` if
+	/// no blocks are indexed yet.
+	#[method(name = "liberland_getIndexedRange")]
+	fn get_indexed_range(&self) -> RpcResult<Option<IndexedRange>>;
+}
+
+/// Implementation of [`LiberlandEventsApiServer`].
+pub struct LiberlandEvents {
+	index: Arc<EventIndex>,
+}
+
+impl LiberlandEvents {
+	/// Create new `LiberlandEvents` serving queries from `index`.
+	pub fn new(index: Arc<EventIndex>) -> Self {
+		Self { index }
+	}
+}
+
+impl LiberlandEventsApiServer for LiberlandEvents {
+	fn get_events(&self, filter: EventFilter) -> RpcResult<EventsPage> {
+		self.index.query(&filter).map_err(Into::into)
+	}
+
+	fn get_indexed_range(&self) -> RpcResult<Option<IndexedRange>> {
+		self.index
+			.indexed_range()
+			.map_err(|e| QueryError::Database(e.to_string()).into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn event(pallet: &str, name: &str) -> StoredEvent {
+		StoredEvent {
+			block_hash: Hash::repeat_byte(1),
+			extrinsic_index: Some(0),
+			pallet: pallet.into(),
+			event: name.into(),
+			data: vec![1, 2, 3],
+			topics: vec![],
+		}
+	}
+
+	fn index() -> EventIndex {
+		let index = EventIndex::with_db(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+		index.insert_block(0, vec![]).unwrap();
+		index
+			.insert_block(1, vec![event("Court", "CaseOpened"), event("Balances", "Transfer")])
+			.unwrap();
+		index.insert_block(2, vec![]).unwrap();
+		index.insert_block(3, vec![event("Court", "RulingPosted")]).unwrap();
+		for number in 4..=5 {
+			index.insert_block(number, vec![]).unwrap();
+		}
+		index
+	}
+
+	fn filter(from_block: BlockNumber, to_block: BlockNumber) -> EventFilter {
+		EventFilter { from_block, to_block, ..Default::default() }
+	}
+
+	fn names(page: &EventsPage) -> Vec<(BlockNumber, &str)> {
+		page.events.iter().map(|e| (e.block_number, e.event.as_str())).collect()
+	}
+
+	#[test]
+	fn query_filters_by_range_pallet_and_name() {
+		let index = index();
+		let page = index.query(&filter(0, 5)).unwrap();
+		assert_eq!(names(&page), vec![(1, "CaseOpened"), (1, "Transfer"), (3, "RulingPosted")]);
+		assert_eq!(page.next, None);
+
+		let page = index.query(&filter(2, 3)).unwrap();
+		assert_eq!(names(&page), vec![(3, "RulingPosted")]);
+
+		let page = index
+			.query(&EventFilter { pallet: Some("Court".into()), ..filter(0, 5) })
+			.unwrap();
+		assert_eq!(names(&page), vec![(1, "CaseOpened"), (3, "RulingPosted")]);
+
+		let page = index
+			.query(&EventFilter { events: Some(vec!["Transfer".into()]), ..filter(0, 5) })
+			.unwrap();
+		assert_eq!(names(&page), vec![(1, "Transfer")]);
+	}
+
+	#[test]
+	fn query_paginates() {
+		let index = index();
+		let page = index.query(&EventFilter { limit: Some(2), ..filter(0, 5) }).unwrap();
+		assert_eq!(names(&page), vec![(1, "CaseOpened"), (1, "Transfer")]);
+		let next = page.next.unwrap();
+		assert_eq!(next, EventCursor { block_number: 3, event_index: 0 });
+
+		let page = index
+			.query(&EventFilter { limit: Some(2), cursor: Some(next), ..filter(0, 5) })
+			.unwrap();
+		assert_eq!(names(&page), vec![(3, "RulingPosted")]);
+		assert_eq!(page.next, None);
+	}
+
+	#[test]
+	fn reimported_block_replaces_events() {
+		let index = index();
+		index.insert_block(1, vec![event("Court", "CaseWithdrawn")]).unwrap();
+		let page = index.query(&filter(1, 1)).unwrap();
+		assert_eq!(names(&page), vec![(1, "CaseWithdrawn")]);
+	}
+
+	#[test]
+	fn query_validates_filter() {
+		let index = index();
+		let invalid = |f: EventFilter| matches!(index.query(&f), Err(QueryError::InvalidFilter(_)));
+		assert!(invalid(filter(5, 1)));
+		assert!(invalid(filter(0, MAX_BLOCK_RANGE)));
+		let cursor = Some(EventCursor { block_number: 0, event_index: 0 });
+		assert!(invalid(EventFilter { cursor, ..filter(1, 5) }));
+	}
+
+	#[test]
+	fn query_fails_outside_of_indexed_range() {
+		let index = EventIndex::with_db(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+		assert_eq!(index.indexed_range().unwrap(), None);
+		let not_indexed =
+			|f: EventFilter| matches!(index.query(&f), Err(QueryError::NotIndexed(_)));
+		assert!(not_indexed(filter(0, 0)));
+
+		index.insert_block(2, vec![event("Court", "CaseOpened")]).unwrap();
+		index.insert_block(3, vec![]).unwrap();
+		index.insert_block(1, vec![]).unwrap();
+		assert_eq!(
+			index.indexed_range().unwrap(),
+			Some(IndexedRange { from_block: 1, to_block: 3 })
+		);
+		assert!(not_indexed(filter(0, 3)));
+		assert!(not_indexed(filter(1, 4)));
+		assert_eq!(names(&index.query(&filter(1, 3)).unwrap()), vec![(2, "CaseOpened")]);
+	}
+
+	#[test]
+	fn insert_block_rejects_gaps() {
+		let index = index();
+		assert!(index.insert_block(7, vec![]).is_err());
+		assert_eq!(
+			index.indexed_range().unwrap(),
+			Some(IndexedRange { from_block: 0, to_block: 5 })
+		);
+		index.insert_block(6, vec![]).unwrap();
+	}
+}
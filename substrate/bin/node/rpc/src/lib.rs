@@ -50,6 +50,9 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::KeystorePtr;
 
+pub mod events;
+pub mod liberland;
+
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
 	/// A handle to the BABE worker for issuing requests.
@@ -90,6 +93,8 @@ pub struct FullDeps<C, P, SC, B> {
 	pub grandpa: GrandpaDeps<B>,
 	/// The backend used by the node.
 	pub backend: Arc<B>,
+	/// Index of runtime events, `liberland_getEvents` is served if set.
+	pub event_index: Option<Arc<events::EventIndex>>,
 }
 
 /// Instantiate all Full RPC extensions.
@@ -103,6 +108,7 @@ pub fn create_full<C, P, SC, B>(
 		babe,
 		grandpa,
 		backend,
+		event_index,
 	}: FullDeps<C, P, SC, B>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
@@ -118,11 +124,14 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_llm_runtime_api::LLMApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: pallet_court_runtime_api::CourtApi<Block, AccountId>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use liberland::{Liberland, LiberlandApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
@@ -172,8 +181,14 @@ where
 			.into_rpc(),
 	)?;
 
+	io.merge(Liberland::new(client.clone()).into_rpc())?;
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 	io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
+	if let Some(index) = event_index {
+		use events::{LiberlandEvents, LiberlandEventsApiServer};
+		io.merge(LiberlandEvents::new(index).into_rpc())?;
+	}
+
 	Ok(io)
 }
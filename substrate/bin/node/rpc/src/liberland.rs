@@ -0,0 +1,209 @@
+//! Citizenship and LLM politics queries, backed by
+//! [`pallet_llm_runtime_api::LLMApi`], and dry-runs of `msig_court`
+//! proposals, backed by [`pallet_court_runtime_api::CourtApi`].
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use node_primitives::{AccountId, Balance, Block, BlockNumber, Hash};
+use pallet_court_runtime_api::CourtApi as CourtRuntimeApi;
+use pallet_llm_runtime_api::LLMApi as LLMRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::DispatchError;
+use sp_weights::Weight;
+
+/// Error code of failed runtime API calls.
+const RUNTIME_ERROR: i32 = 1;
+
+/// LLM account has in politics, returned by `liberland_pooledLLM`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PooledLLM {
+	/// LLM locked in politics
+	pub pooled: NumberOrHex,
+	/// LLM unlocked from politics, waiting for unpool cooldown
+	pub pending_unpool: NumberOrHex,
+	/// Politics power delegated to the account
+	pub delegated_power: NumberOrHex,
+	/// Account the politics power is delegated to
+	pub delegated_to: Option<AccountId>,
+	/// Amount of delegated politics power
+	pub delegated_amount: NumberOrHex,
+	/// Block until which the account can't participate in politics directly
+	pub election_lock: BlockNumber,
+}
+
+/// Seat holder of the council election.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Seat {
+	/// Seat holder
+	pub who: AccountId,
+	/// Total backing stake
+	pub stake: NumberOrHex,
+}
+
+/// Current state of the council election, returned by
+/// `liberland_electionSnapshot`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElectionSnapshot {
+	/// Number of completed election rounds
+	pub round: u32,
+	/// Council members
+	pub members: Vec<Seat>,
+	/// Runners-up
+	pub runners_up: Vec<Seat>,
+	/// Candidates for the next round
+	pub candidates: Vec<AccountId>,
+}
+
+/// Outcome of `liberland_dryRunProposal`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalDryRun {
+	/// `Ok` if the proposal would execute successfully
+	pub result: Result<(), DispatchError>,
+	/// SCALE-encoded `msig_court::Error`, if the contract returned one
+	pub contract_error: Option<Bytes>,
+	/// Weight consumed by the execution
+	pub weight_consumed: Weight,
+}
+
+fn seats(holders: Vec<(AccountId, Balance)>) -> Vec<Seat> {
+	holders
+		.into_iter()
+		.map(|(who, stake)| Seat { who, stake: stake.into() })
+		.collect()
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime API call failed",
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+/// Liberland citizenship and politics RPC methods.
+#[rpc(client, server)]
+pub trait LiberlandApi {
+	/// Whether `account` is a citizen.
+	#[method(name = "liberland_isCitizen")]
+	fn is_citizen(&self, account: AccountId, at: Option<Hash>) -> RpcResult<bool>;
+
+	/// LLM `account` has in politics.
+	#[method(name = "liberland_pooledLLM")]
+	fn pooled_llm(&self, account: AccountId, at: Option<Hash>) -> RpcResult<PooledLLM>;
+
+	/// Current council members, runners-up and candidates.
+	#[method(name = "liberland_electionSnapshot")]
+	fn election_snapshot(&self, at: Option<Hash>) -> RpcResult<ElectionSnapshot>;
+
+	/// Execute SCALE-encoded `msig_court::Proposal` by `msig_court` contract
+	/// at `contract` against current state, without approvals and veto
+	/// period. Changes are discarded.
+	#[method(name = "liberland_dryRunProposal")]
+	fn dry_run_proposal(
+		&self,
+		contract: AccountId,
+		proposal: Bytes,
+		at: Option<Hash>,
+	) -> RpcResult<ProposalDryRun>;
+}
+
+/// Implementation of [`LiberlandApiServer`].
+pub struct Liberland<C> {
+	client: Arc<C>,
+}
+
+impl<C> Liberland<C> {
+	/// Create new `Liberland` querying runtime of `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> Liberland<C>
+where
+	C: HeaderBackend<Block>,
+{
+	fn at(&self, at: Option<Hash>) -> Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<C> LiberlandApiServer for Liberland<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: LLMRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: CourtRuntimeApi<Block, AccountId>,
+{
+	fn is_citizen(&self, account: AccountId, at: Option<Hash>) -> RpcResult<bool> {
+		self.client
+			.runtime_api()
+			.is_citizen(self.at(at), account)
+			.map_err(runtime_error)
+	}
+
+	fn pooled_llm(&self, account: AccountId, at: Option<Hash>) -> RpcResult<PooledLLM> {
+		let pooled = self
+			.client
+			.runtime_api()
+			.pooled_llm(self.at(at), account)
+			.map_err(runtime_error)?;
+		let (delegated_to, delegated_amount) = match pooled.delegation {
+			Some((to, amount)) => (Some(to), amount),
+			None => (None, 0),
+		};
+		Ok(PooledLLM {
+			pooled: pooled.pooled.into(),
+			pending_unpool: pooled.pending_unpool.into(),
+			delegated_power: pooled.delegated_power.into(),
+			delegated_to,
+			delegated_amount: delegated_amount.into(),
+			election_lock: pooled.election_lock,
+		})
+	}
+
+	fn election_snapshot(&self, at: Option<Hash>) -> RpcResult<ElectionSnapshot> {
+		let snapshot = self
+			.client
+			.runtime_api()
+			.election_snapshot(self.at(at))
+			.map_err(runtime_error)?;
+		Ok(ElectionSnapshot {
+			round: snapshot.round,
+			members: seats(snapshot.members),
+			runners_up: seats(snapshot.runners_up),
+			candidates: snapshot.candidates,
+		})
+	}
+
+	fn dry_run_proposal(
+		&self,
+		contract: AccountId,
+		proposal: Bytes,
+		at: Option<Hash>,
+	) -> RpcResult<ProposalDryRun> {
+		let dry_run = self
+			.client
+			.runtime_api()
+			.dry_run_proposal(self.at(at), contract, proposal.to_vec())
+			.map_err(runtime_error)?;
+		Ok(ProposalDryRun {
+			result: dry_run.result,
+			contract_error: dry_run.contract_error.map(Into::into),
+			weight_consumed: dry_run.weight_consumed,
+		})
+	}
+}
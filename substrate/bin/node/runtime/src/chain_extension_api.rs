@@ -0,0 +1,17 @@
+//! Runtime API definition for the Liberland chain extension.
+//!
+//! Lets `liberland_extension` users and deployment tooling check that the
+//! runtime supports the functions a contract was built against.
+
+use liberland_extension_runtime::info::FunctionInfo;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ChainExtensionApi {
+		/// Returns version of the extension interface.
+		fn extension_version() -> u32;
+
+		/// Returns all functions supported by the extension.
+		fn extension_functions() -> Vec<FunctionInfo>;
+	}
+}
@@ -0,0 +1,16 @@
+//! Runtime API definition for civic types schema.
+//!
+//! JSON schemas of Liberland-specific SCALE types are generated off-chain by
+//! `civic-schema`. Clients compare the version embedded in the generated
+//! schema with the one reported by the runtime to detect stale schemas.
+
+/// Version of civic types schema. Bump whenever encoding of any type exported
+/// by `civic-schema` changes.
+pub const CIVIC_SCHEMA_VERSION: u32 = 2;
+
+sp_api::decl_runtime_apis! {
+	pub trait CivicSchemaApi {
+		/// Returns version of civic types schema matching this runtime.
+		fn schema_version() -> u32;
+	}
+}
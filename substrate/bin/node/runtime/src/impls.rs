@@ -24,10 +24,12 @@ use frame_support::{
 	pallet_prelude::{ConstU32, PhantomData, Get, MaxEncodedLen},
 	traits::{
 		Currency, OnUnbalanced, InstanceFilter,
-		Contains, PrivilegeCmp, EnsureOrigin,
+		Contains, PrivilegeCmp, EnsureOrigin, EnsureOriginWithArg,
 	},
 };
 use sp_runtime::{RuntimeDebug, AccountId32, DispatchError, traits::{TrailingZeroInput, Morph}};
+use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_runtime::transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction};
 use sp_std::{vec, cmp::{max, min, Ordering}};
 use scale_info::TypeInfo;
 use sp_runtime::traits::Dispatchable;
@@ -39,7 +41,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
 	AccountId, Authorship, Balances, NegativeImbalance, RuntimeCall,
-	Democracy, RuntimeOrigin,
+	Democracy, RuntimeOrigin, Council, TechnicalCommittee, Court, BoostedCallPriority,
 };
 
 pub struct Author;
@@ -180,7 +182,7 @@ impl InstanceFilter<RuntimeCall> for RegistryCallFilter {
 	Deserialize,
 )]
 pub enum MinistryOfFinanceCallFilter {
-	FullRights, // all balances, assets and llm transfers, + batch + llm.remark
+	FullRights, // all balances, assets and llm transfers, procurement, + batch + llm.remark
 	PooledMeritsRightsOnly, // llm.send_llm_to_politipool only, + batch + llm.remark
 }
 
@@ -199,7 +201,8 @@ impl MinistryOfFinanceCallFilter {
 				RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) |
 				RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) |
 				RuntimeCall::Balances(pallet_balances::Call::transfer { .. }) |
-				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. })
+				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) |
+				RuntimeCall::Procurement(_)
 			),
 			Self::PooledMeritsRightsOnly => matches!(c,
 				RuntimeCall::LLM(pallet_llm::Call::send_llm_to_politipool { .. })
@@ -266,7 +269,9 @@ impl InstanceFilter<RuntimeCall> for NftsCallFilter {
 			RuntimeCall::Nfts(pallet_nfts::Call::approve_item_attributes { .. }) |
 			RuntimeCall::Nfts(pallet_nfts::Call::cancel_item_attributes_approval { .. }) |
 			RuntimeCall::Nfts(pallet_nfts::Call::set_metadata { .. }) |
-			RuntimeCall::Nfts(pallet_nfts::Call::clear_metadata { .. })
+			RuntimeCall::Nfts(pallet_nfts::Call::clear_metadata { .. }) |
+			RuntimeCall::LandRegistry(pallet_land_registry::Call::approve { .. }) |
+			RuntimeCall::LandRegistry(pallet_land_registry::Call::reject { .. })
 		);
 		match self {
 			NftsCallFilter::Manager => matches_manage_items || matches!(c,
@@ -395,6 +400,83 @@ where
 	}
 }
 
+/// Boosts priority of governance calls that must land even when blocks are
+/// full, if they're sent by members of the body entitled to make them:
+///
+/// * `Democracy::veto_external` by Technical Committee members,
+/// * Congress motions cancelling or blacklisting Democracy proposals and
+///   referenda - `propose`, `vote` and `close` by Congress members,
+/// * `Court::post_ruling` by judges on the court's roster.
+///
+/// Boosted calls get additional `BoostedCallPriority`. Others are unaffected.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BoostPriority;
+
+impl BoostPriority {
+	fn is_emergency(call: &RuntimeCall) -> bool {
+		matches!(call,
+			RuntimeCall::Democracy(pallet_democracy::Call::emergency_cancel { .. }) |
+			RuntimeCall::Democracy(pallet_democracy::Call::cancel_referendum { .. }) |
+			RuntimeCall::Democracy(pallet_democracy::Call::cancel_proposal { .. }) |
+			RuntimeCall::Democracy(pallet_democracy::Call::blacklist { .. })
+		)
+	}
+
+	fn is_emergency_motion(hash: &Hash) -> bool {
+		pallet_collective::ProposalOf::<Runtime, CouncilCollective>::get(hash)
+			.map_or(false, |proposal| Self::is_emergency(&proposal))
+	}
+
+	/// Whether `call` sent by `who` gets boosted.
+	pub fn is_boosted(who: &AccountId, call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Democracy(pallet_democracy::Call::veto_external { .. }) =>
+				TechnicalCommittee::is_member(who),
+			RuntimeCall::Council(pallet_collective::Call::propose { proposal, .. }) =>
+				Self::is_emergency(proposal) && Council::is_member(who),
+			RuntimeCall::Council(pallet_collective::Call::vote { proposal, .. }) |
+			RuntimeCall::Council(pallet_collective::Call::close { proposal_hash: proposal, .. }) =>
+				Self::is_emergency_motion(proposal) && Council::is_member(who),
+			RuntimeCall::Court(pallet_court::Call::post_ruling { .. }) =>
+				Court::judges().contains(who),
+			_ => false,
+		}
+	}
+}
+
+impl SignedExtension for BoostPriority {
+	const IDENTIFIER: &'static str = "BoostPriority";
+	type AccountId = AccountId;
+	type Call = RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let priority = if Self::is_boosted(who, call) { BoostedCallPriority::get() } else { 0 };
+		Ok(ValidTransaction { priority, ..Default::default() })
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+}
+
 use pallet_democracy::Voting;
 pub struct OnLLMPoliticsUnlock;
 impl liberland_traits::OnLLMPoliticsUnlock<AccountId32> for OnLLMPoliticsUnlock
@@ -573,6 +655,129 @@ impl<CoordsBounds: Get<(Coords, Coords)>, StringLimit>
 	}
 }
 
+impl<CoordsBounds: Get<(Coords, Coords)>> pallet_land_registry::PlotDemarcation
+	for LandMetadataValidator<CoordsBounds>
+{
+	fn demarcation(data: &[u8]) -> Option<Vec<pallet_land_registry::Coords>> {
+		let bounded = BoundedVec::<u8, ConstU32<256>>::try_from(data.to_vec()).ok()?;
+		if !<Self as pallet_nfts::traits::MetadataValidator<u32, u32, ConstU32<256>>>::validate_metadata(0, 0, &bounded) {
+			return None
+		}
+
+		let mut data = Metadata::<ConstU32<100>, ConstU32<100>>::decode(
+			&mut TrailingZeroInput::new(data)
+		).ok()?;
+		if data.demarcation.len() > 1 &&
+		   data.demarcation.first() == data.demarcation.last()
+		{
+			data.demarcation.pop();
+		}
+		Some(data.demarcation.into_iter().map(|c| pallet_land_registry::Coords { lat: c.lat, long: c.long }).collect())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<CoordsBounds: Get<(Coords, Coords)>> pallet_land_registry::BenchmarkHelper<u32>
+	for LandMetadataValidator<CoordsBounds>
+{
+	fn collection() -> u32 {
+		0
+	}
+
+	fn anchor() -> pallet_land_registry::Coords {
+		let (lower, _) = CoordsBounds::get();
+		pallet_land_registry::Coords { lat: lower.lat + 1_000, long: lower.long + 1_000 }
+	}
+
+	fn metadata(demarcation: Vec<pallet_land_registry::Coords>) -> Vec<u8> {
+		Metadata::<ConstU32<100>, ConstU32<100>> {
+			demarcation: BoundedVec::truncate_from(
+				demarcation.into_iter().map(|c| Coords { lat: c.lat, long: c.long }).collect()
+			),
+			r#type: BoundedVec::truncate_from(b"plot".to_vec()),
+			status: BoundedVec::truncate_from(b"registered".to_vec()),
+		}.encode()
+	}
+}
+
+/// Origin that sets runtime parameters. Referendum voting period needs Root -
+/// shortening it would let Congress pass a referendum before anyone reacts,
+/// so Congress alone can't change it.
+pub struct RuntimeParametersAdminOrigin;
+
+impl EnsureOriginWithArg<RuntimeOrigin, LiberlandParameterKey> for RuntimeParametersAdminOrigin {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin, key: &LiberlandParameterKey) -> Result<(), RuntimeOrigin> {
+		match key {
+			LiberlandParameterKey::ReferendumVotingPeriod =>
+				EnsureRoot::<AccountId>::try_origin(o),
+			_ => EnsureRootOrHalfCouncil::try_origin(o).map(|_| ()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &LiberlandParameterKey) -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+/// Origin that sanctions given account - Root, Congress majority or court
+/// enacting a ruling of a case against the account.
+pub struct SanctionOrigin;
+
+impl EnsureOriginWithArg<RuntimeOrigin, AccountId> for SanctionOrigin {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin, who: &AccountId) -> Result<(), RuntimeOrigin> {
+		let o = match EnsureRootOrHalfCouncil::try_origin(o) {
+			Ok(_) => return Ok(()),
+			Err(o) => o,
+		};
+		pallet_court::EnsureCourtAgainst::<Runtime>::try_origin(o, who).map(|_| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &AccountId) -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RuntimeParametersBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_runtime_parameters::BenchmarkHelper<LiberlandParameter>
+	for RuntimeParametersBenchmarkHelper
+{
+	fn parameter() -> LiberlandParameter {
+		LiberlandParameter::ElectionTermDuration(TermDuration::get())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ContractSchedulerBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_contract_scheduler::BenchmarkHelper<RuntimeCall> for ContractSchedulerBenchmarkHelper {
+	fn call() -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: <Runtime as frame_system::Config>::Lookup::unlookup(AccountId32::new([0; 32])),
+			value: 0,
+		})
+	}
+}
+
+/// Land plots collection. Metaverse land (collection 1) isn't handled by the
+/// Land Registry Office.
+pub struct LandCollections;
+
+impl Contains<u32> for LandCollections {
+	fn contains(collection: &u32) -> bool {
+		*collection == 0
+	}
+}
+
 #[derive(
 	Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo, Serialize, Deserialize,
 )]
@@ -580,7 +785,113 @@ pub struct ContractsCallFilter;
 
 impl Contains<RuntimeCall> for ContractsCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
-		matches!(c, RuntimeCall::LLM(pallet_llm::Call::force_transfer { .. }))
+		matches!(
+			c,
+			RuntimeCall::LLM(pallet_llm::Call::force_transfer { .. }) |
+				RuntimeCall::LLM(pallet_llm::Call::court_lld_transfer { .. }) |
+				RuntimeCall::LLM(pallet_llm::Call::revoke_citizenship { .. }) |
+				RuntimeCall::LLM(pallet_llm::Call::send_llm { .. }) |
+				RuntimeCall::LLM(pallet_llm::Call::politics_lock { .. }) |
+				RuntimeCall::LLM(pallet_llm::Call::politics_unlock { .. }) |
+				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) |
+				RuntimeCall::Democracy(pallet_democracy::Call::vote { .. }) |
+				RuntimeCall::Democracy(pallet_democracy::Call::delegate { .. }) |
+				RuntimeCall::Staking(pallet_staking::Call::bond { .. }) |
+				RuntimeCall::Staking(pallet_staking::Call::nominate { .. }) |
+				RuntimeCall::Staking(pallet_staking::Call::unbond { .. }) |
+				RuntimeCall::Assets(pallet_assets::Call::create { .. }) |
+				RuntimeCall::Assets(pallet_assets::Call::mint { .. }) |
+				RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) |
+				RuntimeCall::Assets(pallet_assets::Call::burn { .. })
+		)
+	}
+}
+
+/// Calls contracts can schedule with `schedule_call` - ones allowed by
+/// `ContractsCallFilter` and calls to contracts, e.g. contract executing its
+/// own proposal once veto period ends. Contract calls can't be allowed in
+/// `ContractsCallFilter`, as `call_runtime` denies reentrancy.
+pub struct ScheduledContractCallFilter;
+
+impl Contains<RuntimeCall> for ScheduledContractCallFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		ContractsCallFilter::contains(c) ||
+			matches!(c, RuntimeCall::Contracts(pallet_contracts::Call::call { .. }))
+	}
+}
+
+/// Base call filter of the runtime. Only restricts `create_recovery` -
+/// recovery friends must be citizens, as they can hand over citizen's
+/// account to someone else.
+pub struct RecoveryFriendsFilter;
+
+impl Contains<RuntimeCall> for RecoveryFriendsFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		match c {
+			RuntimeCall::Recovery(pallet_recovery::Call::create_recovery { friends, .. }) =>
+				friends.iter().all(<LLM as liberland_traits::CitizenshipChecker<AccountId>>::is_citizen),
+			_ => true,
+		}
+	}
+}
+
+/// Nomination pools must be owned by citizens - `root` role of a new pool and
+/// any root set by `update_roles` must be a citizen. Pools created by
+/// migrations, like the community pool, aren't affected.
+pub struct NominationPoolsFilter;
+
+impl NominationPoolsFilter {
+	fn is_citizen(who: &AccountId) -> bool {
+		<LLM as liberland_traits::CitizenshipChecker<AccountId>>::is_citizen(who)
+	}
+
+	fn is_citizen_source(source: &<<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source) -> bool {
+		<Runtime as frame_system::Config>::Lookup::lookup(source.clone())
+			.map(|who| Self::is_citizen(&who))
+			.unwrap_or(false)
+	}
+}
+
+impl Contains<RuntimeCall> for NominationPoolsFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		use pallet_nomination_pools::{Call, ConfigOp};
+		match c {
+			RuntimeCall::NominationPools(Call::create { root, .. }) |
+			RuntimeCall::NominationPools(Call::create_with_pool_id { root, .. }) =>
+				Self::is_citizen_source(root),
+			RuntimeCall::NominationPools(Call::update_roles { new_root: ConfigOp::Set(root), .. }) =>
+				Self::is_citizen(root),
+			_ => true,
+		}
+	}
+}
+
+/// Sudo calls are rejected once sudo was retired with
+/// `pallet_sudo_sunset::retire_sudo`.
+pub struct SudoSunsetFilter;
+
+impl Contains<RuntimeCall> for SudoSunsetFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		match c {
+			RuntimeCall::Sudo(_) => !SudoSunset::sudo_retired(),
+			_ => true,
+		}
+	}
+}
+
+pub struct BalanceToU256;
+
+impl Convert<Balance, sp_core::U256> for BalanceToU256 {
+	fn convert(balance: Balance) -> sp_core::U256 {
+		sp_core::U256::from(balance)
+	}
+}
+
+pub struct U256ToBalance;
+
+impl Convert<sp_core::U256, Balance> for U256ToBalance {
+	fn convert(n: sp_core::U256) -> Balance {
+		n.try_into().unwrap_or(Balance::max_value())
 	}
 }
 
@@ -1067,6 +1378,15 @@ mod ministry_of_finance_call_filter_tests {
 		});
 	}
 
+	#[test]
+	fn allows_tier1_procurement() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let call = RuntimeCall::Procurement(pallet_procurement::Call::cancel_tender { tender_id: 0 });
+			assert!(MinistryOfFinanceCallFilter::FullRights.filter(&call));
+			assert!(!MinistryOfFinanceCallFilter::PooledMeritsRightsOnly.filter(&call));
+		});
+	}
+
 	#[test]
 	fn allows_tier1_liquid_transfer_lld() {
 		sp_io::TestExternalities::default().execute_with(|| {
@@ -1116,6 +1436,257 @@ mod ministry_of_finance_call_filter_tests {
 	}
 }
 
+#[cfg(test)]
+mod recovery_friends_filter_tests {
+	use super::{RecoveryFriendsFilter, RuntimeCall};
+	use frame_support::{PalletId, traits::Contains};
+	use sp_runtime::{traits::AccountIdConversion, AccountId32};
+
+	fn accid() -> AccountId32 {
+		PalletId(*b"12345678").into_account_truncating()
+	}
+
+	#[test]
+	fn disallows_non_citizen_friends() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let call = RuntimeCall::Recovery(pallet_recovery::Call::create_recovery {
+				friends: vec![accid()],
+				threshold: 1,
+				delay_period: 0,
+			});
+			assert!(!RecoveryFriendsFilter::contains(&call));
+		});
+	}
+
+	#[test]
+	fn allows_other_calls() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let call = RuntimeCall::Recovery(pallet_recovery::Call::initiate_recovery { account: accid().into() });
+			assert!(RecoveryFriendsFilter::contains(&call));
+			let call = RuntimeCall::LLM(pallet_llm::Call::remark { data: vec![].try_into().unwrap() });
+			assert!(RecoveryFriendsFilter::contains(&call));
+		});
+	}
+}
+
+#[cfg(test)]
+mod nomination_pools_filter_tests {
+	use super::{NominationPoolsFilter, RuntimeCall};
+	use frame_support::{PalletId, traits::Contains};
+	use pallet_nomination_pools::{Call, ConfigOp};
+	use sp_runtime::{traits::AccountIdConversion, AccountId32};
+
+	fn accid() -> AccountId32 {
+		PalletId(*b"12345678").into_account_truncating()
+	}
+
+	#[test]
+	fn disallows_non_citizen_root() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let call = RuntimeCall::NominationPools(Call::create {
+				amount: 1,
+				root: accid().into(),
+				nominator: accid().into(),
+				bouncer: accid().into(),
+			});
+			assert!(!NominationPoolsFilter::contains(&call));
+			let call = RuntimeCall::NominationPools(Call::update_roles {
+				pool_id: 1,
+				new_root: ConfigOp::Set(accid()),
+				new_nominator: ConfigOp::Noop,
+				new_bouncer: ConfigOp::Noop,
+			});
+			assert!(!NominationPoolsFilter::contains(&call));
+		});
+	}
+
+	#[test]
+	fn allows_other_calls() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let call = RuntimeCall::NominationPools(Call::update_roles {
+				pool_id: 1,
+				new_root: ConfigOp::Noop,
+				new_nominator: ConfigOp::Set(accid()),
+				new_bouncer: ConfigOp::Remove,
+			});
+			assert!(NominationPoolsFilter::contains(&call));
+			let call = RuntimeCall::NominationPools(Call::join { amount: 1, pool_id: 1 });
+			assert!(NominationPoolsFilter::contains(&call));
+		});
+	}
+}
+
+#[cfg(test)]
+mod sudo_sunset_filter_tests {
+	use super::{SudoSunsetFilter, RuntimeCall};
+	use frame_support::traits::Contains;
+
+	fn sudo_call() -> RuntimeCall {
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: Box::new(call) })
+	}
+
+	#[test]
+	fn allows_sudo_until_retired() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(SudoSunsetFilter::contains(&sudo_call()));
+			pallet_sudo_sunset::SudoRetired::<crate::Runtime>::put(true);
+			assert!(!SudoSunsetFilter::contains(&sudo_call()));
+		});
+	}
+
+	#[test]
+	fn allows_other_calls() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			pallet_sudo_sunset::SudoRetired::<crate::Runtime>::put(true);
+			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			assert!(SudoSunsetFilter::contains(&call));
+		});
+	}
+}
+
+#[cfg(test)]
+mod boost_priority_tests {
+	use super::{BoostPriority, RuntimeCall};
+	use crate::{
+		constants::currency::DOLLARS, BoostedCallPriority, CouncilCollective, Runtime,
+		TechnicalCollective, TransactionPayment,
+	};
+	use frame_support::dispatch::GetDispatchInfo;
+	use pallet_transaction_payment::ChargeTransactionPayment;
+	use sp_runtime::{
+		traits::{BlakeTwo256, Hash, SignedExtension},
+		transaction_validity::TransactionPriority,
+		AccountId32,
+	};
+
+	fn acc(n: u8) -> AccountId32 {
+		[n; 32].into()
+	}
+
+	fn veto() -> RuntimeCall {
+		RuntimeCall::Democracy(pallet_democracy::Call::veto_external { proposal_hash: [0; 32].into() })
+	}
+
+	fn emergency_cancel() -> RuntimeCall {
+		RuntimeCall::Democracy(pallet_democracy::Call::emergency_cancel { ref_index: 0 })
+	}
+
+	fn remark() -> RuntimeCall {
+		RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+	}
+
+	fn transfer() -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::transfer { dest: acc(9).into(), value: DOLLARS })
+	}
+
+	fn propose(proposal: RuntimeCall) -> RuntimeCall {
+		RuntimeCall::Council(pallet_collective::Call::propose {
+			threshold: 2,
+			proposal: Box::new(proposal),
+			length_bound: 100,
+		})
+	}
+
+	fn vote(proposal: RuntimeCall) -> RuntimeCall {
+		RuntimeCall::Council(pallet_collective::Call::vote {
+			proposal: BlakeTwo256::hash_of(&proposal),
+			index: 0,
+			approve: true,
+		})
+	}
+
+	fn ruling() -> RuntimeCall {
+		RuntimeCall::Court(pallet_court::Call::post_ruling {
+			case: 0,
+			ruling: Box::new(remark()),
+		})
+	}
+
+	// 1 is Congress member, 2 is Technical Committee member, 3 is judge, 4 has
+	// no role
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			pallet_collective::Members::<Runtime, CouncilCollective>::put(vec![acc(1)]);
+			pallet_collective::Members::<Runtime, TechnicalCollective>::put(vec![acc(2)]);
+			pallet_court::Judges::<Runtime>::put(
+				frame_support::BoundedVec::try_from(vec![acc(3)]).unwrap(),
+			);
+			for proposal in [emergency_cancel(), remark()] {
+				pallet_collective::ProposalOf::<Runtime, CouncilCollective>::insert(
+					BlakeTwo256::hash_of(&proposal),
+					proposal,
+				);
+			}
+		});
+		ext
+	}
+
+	fn boost(who: u8, call: RuntimeCall) -> TransactionPriority {
+		let info = call.get_dispatch_info();
+		BoostPriority.validate(&acc(who), &call, &info, 0).unwrap().priority
+	}
+
+	/// Priority of the whole transaction - tip and class based priority
+	/// assigned by transaction payment plus the boost.
+	fn priority(who: u8, call: RuntimeCall, tip: u128) -> TransactionPriority {
+		let info = call.get_dispatch_info();
+		let len = 200;
+		let fee = TransactionPayment::compute_fee(len, &info, tip);
+		ChargeTransactionPayment::<Runtime>::get_priority(&info, len as usize, tip, fee)
+			.saturating_add(boost(who, call))
+	}
+
+	#[test]
+	fn boosts_authorized_governance_calls() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(boost(2, veto()), BoostedCallPriority::get());
+			assert_eq!(boost(1, propose(emergency_cancel())), BoostedCallPriority::get());
+			assert_eq!(boost(1, vote(emergency_cancel())), BoostedCallPriority::get());
+			assert_eq!(boost(3, ruling()), BoostedCallPriority::get());
+		});
+	}
+
+	#[test]
+	fn doesnt_boost_unauthorized_or_other_calls() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(boost(4, veto()), 0);
+			assert_eq!(boost(1, veto()), 0);
+			assert_eq!(boost(2, propose(emergency_cancel())), 0);
+			assert_eq!(boost(4, vote(emergency_cancel())), 0);
+			assert_eq!(boost(4, ruling()), 0);
+			// not emergency motions
+			assert_eq!(boost(1, propose(remark())), 0);
+			assert_eq!(boost(1, vote(remark())), 0);
+			// unknown motion
+			assert_eq!(boost(1, vote(transfer())), 0);
+			assert_eq!(boost(1, transfer()), 0);
+		});
+	}
+
+	#[test]
+	fn boosted_calls_are_ordered_first_in_saturated_pool() {
+		new_test_ext().execute_with(|| {
+			// pool full of transfers outbidding each other with tips of up to
+			// 100 LLD
+			let mut pool: Vec<(TransactionPriority, &str)> = (1..=1000u128)
+				.map(|i| (priority(4, transfer(), i * DOLLARS / 10), "transfer"))
+				.collect();
+			pool.push((priority(2, veto(), 0), "veto"));
+			pool.push((priority(1, vote(emergency_cancel()), 0), "vote"));
+			pool.push((priority(3, ruling(), 0), "ruling"));
+			pool.push((priority(4, veto(), 0), "unauthorized veto"));
+
+			pool.sort_by(|a, b| b.0.cmp(&a.0));
+			let mut first: Vec<_> = pool[..3].iter().map(|(_, name)| *name).collect();
+			first.sort();
+			assert_eq!(first, vec!["ruling", "veto", "vote"]);
+			assert_eq!(pool.last().unwrap().1, "unauthorized veto");
+		});
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use frame_support::{
@@ -0,0 +1,30 @@
+//! Runtime API definition for the land registry pallet.
+//!
+//! Lets front-ends show lease offers and active leases of plots without
+//! decoding pallet storage.
+
+use codec::Codec;
+use pallet_land_registry::{Lease, LeaseIndex};
+use sp_std::vec::Vec;
+
+/// Lease of a plot in one of the runtime's land collections
+pub type LeaseOf<AccountId, Balance, BlockNumber> =
+	Lease<AccountId, u32, u32, Balance, BlockNumber>;
+
+sp_api::decl_runtime_apis! {
+	pub trait LandRegistryApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns lease or lease offer with given index.
+		fn lease(index: LeaseIndex) -> Option<LeaseOf<AccountId, Balance, BlockNumber>>;
+
+		/// Returns lease or lease offer of given plot.
+		fn plot_lease(collection: u32, item: u32) -> Option<(LeaseIndex, LeaseOf<AccountId, Balance, BlockNumber>)>;
+
+		/// Returns leases where `account` is the landlord or the tenant.
+		fn leases_of(account: AccountId) -> Vec<(LeaseIndex, LeaseOf<AccountId, Balance, BlockNumber>)>;
+	}
+}
@@ -39,7 +39,6 @@ use frame_support::{
 	inherent::CheckInherentsResult,
 	parameter_types,
 	traits::{
-		Everything,
 		fungible::{Balanced, Credit},
         tokens::nonfungibles_v2::Inspect,
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, MapSuccess,
@@ -80,8 +79,8 @@ use sp_runtime::{
 		SaturatedConversion, StaticLookup, AccountIdConversion, AccountIdLookup,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedPointNumber, Perbill, Percent, Permill, Perquintill,
-	RuntimeDebug
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
+	RuntimeDebug, DispatchError
 };
 use sp_std::prelude::*;
 #[cfg(any(feature = "std", test))]
@@ -112,7 +111,9 @@ use impls::{
 	Author, ToAccountId,
 	IdentityCallFilter, RegistryCallFilter, NftsCallFilter, OnLLMPoliticsUnlock,
 	ContainsMember, CouncilAccountCallFilter, EnsureCmp, ContractsCallFilter, SenateAccountCallFilter,
-	MinistryOfFinanceCallFilter,
+	RecoveryFriendsFilter, NominationPoolsFilter, SudoSunsetFilter, BalanceToU256, U256ToBalance,
+	MinistryOfFinanceCallFilter, ScheduledContractCallFilter, RuntimeParametersAdminOrigin,
+	SanctionOrigin,
 };
 
 /// Constant values used within the runtime.
@@ -125,6 +126,10 @@ mod voter_bags;
 
 /// Runtime API definition for assets.
 pub mod assets_api;
+pub mod civic_schema_api;
+pub mod watchlist_api;
+pub mod land_registry_api;
+pub mod chain_extension_api;
 
 // Make the WASM binary available.
 #[cfg(feature = "std")]
@@ -159,7 +164,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 28,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -176,7 +181,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 28,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -250,7 +255,7 @@ parameter_types! {
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = (RecoveryFriendsFilter, NominationPoolsFilter, SudoSunsetFilter);
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type DbWeight = RocksDbWeight;
@@ -284,6 +289,7 @@ parameter_types! {
 	pub const AssetName: &'static str = "Liberland Merit";
 	pub const AssetSymbol: &'static str = "LLM";
 	pub const SpendPeriod: BlockNumber = 7 * DAYS;
+	pub const DualVotingEnabled: bool = false;
 }
 
 #[cfg(feature = "testnet-runtime")]
@@ -295,6 +301,7 @@ parameter_types! {
 	pub const AssetName: &'static str = "Liberland Kuna";
 	pub const AssetSymbol: &'static str = "LKN";
 	pub const SpendPeriod: BlockNumber = 60 * MINUTES;
+	pub const DualVotingEnabled: bool = true;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -349,6 +356,10 @@ pub enum ProxyType {
 	NonTransfer,
 	Governance,
 	Staking,
+	/// Managing own identity and sub-accounts
+	Identity,
+	/// Land and other NFTs
+	Nfts,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -371,10 +382,17 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Council(..) |
 					RuntimeCall::TechnicalCommittee(..) |
 					RuntimeCall::Elections(..) |
-					RuntimeCall::Treasury(..)
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::DualVoting(..) |
+					RuntimeCall::LLM(pallet_llm::Call::politics_lock { .. }) |
+					RuntimeCall::LLM(pallet_llm::Call::politics_unlock { .. })
 			),
-			ProxyType::Staking =>
-				matches!(c, RuntimeCall::Staking(..)),
+			ProxyType::Staking => matches!(
+				c,
+				RuntimeCall::Staking(..) | RuntimeCall::ValidatorOnboarding(..)
+			),
+			ProxyType::Identity => matches!(c, RuntimeCall::Identity(..)),
+			ProxyType::Nfts => matches!(c, RuntimeCall::Nfts(..)),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -382,6 +400,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, ProxyType::Nfts) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
@@ -409,6 +428,8 @@ type EnsureRootOrHalfCouncil = EitherOfDiverse<
 	EnsureRoot<AccountId>,
 	EnsureCouncilMajority,
 >;
+pub type EnsureCitizen = liberland_traits::EnsureCitizen<LLM, AccountId>;
+pub type EnsureResidentOrCitizen = liberland_traits::EnsureResidentOrCitizen<LLM, AccountId>;
 type EnsureSenateOrCouncilMajority = EitherOfDiverse<
 	EnsureSenateMajority,
 	EnsureCouncilMajority,
@@ -502,8 +523,8 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = frame_system::Pallet<Runtime>;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ConstU32<1>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxHolds = MaxHolds;
 }
@@ -643,7 +664,7 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = HistoryDepth;
-	type EventListeners = ();
+	type EventListeners = NominationPools;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 	type Citizenship = LLM;
@@ -651,6 +672,31 @@ impl pallet_staking::Config for Runtime {
 	type LLInitializer = LiberlandInitializer;
 }
 
+parameter_types! {
+	pub const NominationPoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxPointsToBalance: u8 = 10;
+	pub const NominationPoolsMinJoinBond: Balance = 1 * DOLLARS;
+	pub const NominationPoolsMinCreateBond: Balance = 100 * DOLLARS;
+	pub const NominationPoolsMaxPools: u32 = 64;
+	pub const NominationPoolsMaxCommission: Perbill = Perbill::from_percent(10);
+	pub const CommunityPoolBond: Balance = 1_000 * DOLLARS;
+}
+
+impl pallet_nomination_pools::Config for Runtime {
+	type WeightInfo = pallet_nomination_pools::weights::SubstrateWeight<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RewardCounter = FixedU128;
+	type BalanceToU256 = BalanceToU256;
+	type U256ToBalance = U256ToBalance;
+	type Staking = Staking;
+	type PostUnbondingPoolsWindow = ConstU32<4>;
+	type MaxMetadataLen = ConstU32<256>;
+	type MaxUnbonding = ConstU32<8>;
+	type PalletId = NominationPoolsPalletId;
+	type MaxPointsToBalance = MaxPointsToBalance;
+}
+
 parameter_types! {
 	// phase durations. 1/4 of the last session for each.
 	pub const SignedPhase: u32 = EPOCH_DURATION_IN_BLOCKS / 4;
@@ -831,12 +877,13 @@ impl pallet_democracy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type EnactmentPeriod = EnactmentPeriod;
-	type LaunchPeriod = LaunchPeriod;
-	type VotingPeriod = VotingPeriod;
+	type LaunchPeriod = ReferendumLaunchPeriod;
+	type VotingPeriod = ReferendumVotingPeriod;
 	type VoteLockingPeriod = EnactmentPeriod; // Same as EnactmentPeriod
 	type MinimumDeposit = MinimumDeposit;
 	type ExternalOrigin = EnsureCouncilMajority;
 	type ExternalMajorityOrigin = EnsureCouncilMajority;
+	type ExternalDualMajorityOrigin = EnsureCouncilMajority;
 
 	type MaxAdditionalFields = MaxAdditionalFields;
 	type MaxRegistrars = MaxRegistrars;
@@ -876,7 +923,7 @@ impl pallet_democracy::Config for Runtime {
 	// Any single technical committee member may veto a coming council proposal, however they can
 	// only do it once and it lasts only for the cool-off period.
 	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
-	type CooloffPeriod = CooloffPeriod;
+	type CooloffPeriod = VetoCooloffPeriod;
 	type Scheduler = Scheduler;
 	type PalletsOrigin = OriginCaller;
 	type MaxVotes = ConstU32<100>;
@@ -950,10 +997,12 @@ impl pallet_elections_phragmen::Config for Runtime {
 	type KickedMember = ();
 	type DesiredMembers = DesiredMembers;
 	type DesiredRunnersUp = DesiredRunnersUp;
-	type TermDuration = TermDuration;
+	type TermDuration = ElectionTermDuration;
 	type MaxVoters = MaxVoters;
 	type MaxVotesPerVoter = MaxVotesPerVoter;
 	type MaxCandidates = MaxCandidates;
+	type CountingModeOrigin = EnsureRoot<AccountId>;
+	type RunoffBallotsPerBlock = ConstU32<500>;
 	type WeightInfo = pallet_elections_phragmen::weights::SubstrateWeight<Runtime>;
 	type Citizenship = LLM;
 	type LLM = LLM;
@@ -1086,7 +1135,9 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = liberland_extension_runtime::LiberlandExtension;
+	type ChainExtension = liberland_extension_runtime::LiberlandExtension<
+		liberland_extension_runtime::weights::SubstrateWeight<Self>,
+	>;
 	type Schedule = Schedule;
 	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
@@ -1147,6 +1198,7 @@ where
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+			impls::BoostPriority,
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1258,7 +1310,7 @@ impl pallet_assets::Config for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
-	type Freezer = ();
+	type Freezer = Sanctions;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -1333,10 +1385,16 @@ parameter_types! {
 	pub const TOTALLLM: Balance      = 70_000_000u128 * GRAINS_IN_LLM;
 	pub const PRERELEASELLM: Balance = 13_300_000u128 * GRAINS_IN_LLM;
 	pub const CitizenshipMinimum: Balance = 5_000u128 * GRAINS_IN_LLM;
+	pub const EResidentMinimum: Balance = 500u128 * GRAINS_IN_LLM;
 	pub const UnlockFactor: Permill = Permill::from_parts(8742);
 	pub const AssetId: u32 = 1;
 	pub const InflationEventInterval: BlockNumber = 30 * DAYS;
 	pub const InflationEventReleaseFactor: Perbill = Perbill::from_parts(8741611);
+	pub const UnpoolCooldown: BlockNumber = 7 * DAYS;
+	pub const CitizenshipValidity: BlockNumber = 365 * DAYS;
+	pub const LLMSpendProposalBond: Permill = Permill::from_percent(5);
+	pub const LLMSpendProposalBondMinimum: Balance = 100u128 * GRAINS_IN_LLM;
+	pub const LLMSpendPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_liberland_initializer::Config for Runtime {}
@@ -1346,7 +1404,8 @@ impl pallet_llm::Config for Runtime {
 	type Currency = Balances;
 	type TotalSupply = TOTALLLM; //70 million in hardcap
 	type PreReleasedAmount = PRERELEASELLM; // PreRelease 7 million
-	type CitizenshipMinimumPooledLLM = CitizenshipMinimum;
+	type CitizenshipMinimumPooledLLM = CitizenshipMinimumPooledLLM;
+	type EResidentMinimumPooledLLM = EResidentMinimumPooledLLM;
 	type UnlockFactor = UnlockFactor;
 	type AssetId = AssetId;
 	type AssetName = AssetName;
@@ -1357,7 +1416,20 @@ impl pallet_llm::Config for Runtime {
 		EnsureRoot<AccountId>,
 		EnsureSenateMajority
 	>;
+	type UnpoolCooldown = UnpoolCooldown;
+	type MaxUnpoolsPerBlock = ConstU32<256>;
+	type MaxDelegationDepth = ConstU32<5>;
+	type CitizenshipValidity = CitizenshipValidity;
+	type LLMSpendApproveOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EnsureSenateMajority
+	>;
+	type LLMSpendProposalBond = LLMSpendProposalBond;
+	type LLMSpendProposalBondMinimum = LLMSpendProposalBondMinimum;
+	type LLMSpendPeriod = LLMSpendPeriod;
+	type MaxLLMSpendApprovals = ConstU32<100>;
 	type OnLLMPoliticsUnlock = OnLLMPoliticsUnlock;
+	type OnCitizenshipChange = CitizenshipNft;
 	type WeightInfo = ();
 	type MaxCourts = ConstU32<2>;
 }
@@ -1444,6 +1516,8 @@ parameter_types! {
 	pub CompanyRegistryMaxRegistrars: u32 = 10u32;
 	pub CompanyRegistryBaseDeposit: Balance = 1 * CENTS;
 	pub CompanyRegistryByteDeposit: Balance = 10 * MILLICENTS;
+	pub const CompanyRegistryReportPeriod: BlockNumber = 365 * DAYS;
+	pub const CompanyRegistryStrikeOffDelay: BlockNumber = 90 * DAYS;
 	pub CouncilAccountId: AccountId = PalletId(*b"regcounc").into_account_truncating();
 }
 
@@ -1470,6 +1544,9 @@ impl pallet_registry::Config<CompanyRegistryInstance> for Runtime {
 	type ByteDeposit = CompanyRegistryByteDeposit;
 	type EntityOrigin = EnsureSigned<AccountId>;
 	type ReserveIdentifier = CompanyRegistryReserveIdentifier;
+	type MaxOfficers = ConstU32<10>;
+	type ReportPeriod = CompanyRegistryReportPeriod;
+	type StrikeOffDelay = CompanyRegistryStrikeOffDelay;
 	type WeightInfo = ();
 }
 
@@ -1640,6 +1717,390 @@ impl pallet_contracts_registry::Config for Runtime {
 	type ReserveIdentifier = ContractRegistryReserveIdentifier;
 }
 
+ord_parameter_types! {
+	pub const ProcurementOffice: AccountId = AccountIdConversion::<AccountId>::into_account_truncating(&MinistryOfFinanceOfficePalletId::get());
+}
+
+parameter_types! {
+	pub ProcurementReserveIdentifier: &'static [u8; 8] = b"procurem";
+	pub const ProcurementPalletId: PalletId = PalletId(*b"procurem");
+	pub ProcurementBidDeposit: Balance = 10 * DOLLARS;
+	pub ProcurementTreasuryAccount: AccountId = Treasury::account_id();
+}
+
+impl pallet_procurement::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PublishOrigin = EnsureSignedBy<ProcurementOffice, AccountId>;
+	type TreasuryAccount = ProcurementTreasuryAccount;
+	type PalletId = ProcurementPalletId;
+	type BidDeposit = ProcurementBidDeposit;
+	type ReserveIdentifier = ProcurementReserveIdentifier;
+	type MaxMilestones = ConstU32<16>;
+	type WeightInfo = pallet_procurement::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub WatchlistReserveIdentifier: &'static [u8; 8] = b"watchlst";
+	pub WatchDeposit: Balance = deposit(1, 128);
+}
+
+impl pallet_watchlist::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type WatchDeposit = WatchDeposit;
+	type ReserveIdentifier = WatchlistReserveIdentifier;
+	type MaxWatchers = ConstU32<64>;
+	type MaxLabelLen = ConstU32<64>;
+	type WeightInfo = pallet_watchlist::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub ValidatorOnboardingReserveIdentifier: &'static [u8; 8] = b"valonbrd";
+	pub const ValidatorApplicationBond: Balance = 1000 * DOLLARS;
+	pub const ValidatorProcessingFee: Balance = 50 * DOLLARS;
+}
+
+impl pallet_validator_onboarding::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ApplicationBond = ValidatorApplicationBond;
+	type ProcessingFee = ValidatorProcessingFee;
+	type FeeDestination = Treasury;
+	type ReserveIdentifier = ValidatorOnboardingReserveIdentifier;
+	type ReviewOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>,
+	>;
+	type MaxCandidates = ConstU32<256>;
+	type WeightInfo = pallet_validator_onboarding::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub PreimagePoolReserveIdentifier: &'static [u8; 8] = b"preimpol";
+	/// Small proposals stay cheap, big blobs pay close to the per-byte price.
+	pub PreimageDepositTiers: Vec<(u32, Balance)> = vec![
+		(1024, 1 * DOLLARS),
+		(16 * 1024, 20 * DOLLARS),
+		(256 * 1024, 2_600 * DOLLARS),
+		(PreimageMaxSize::get(), 42_000 * DOLLARS),
+	];
+	pub const PreimageExpiryPeriod: BlockNumber = 28 * DAYS;
+	pub const PreimageCleanupBounty: Permill = Permill::from_percent(10);
+}
+
+impl pallet_preimage_pool::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type DepositTiers = PreimageDepositTiers;
+	type ExpiryPeriod = PreimageExpiryPeriod;
+	type CleanupBounty = PreimageCleanupBounty;
+	type ReserveIdentifier = PreimagePoolReserveIdentifier;
+	type WeightInfo = pallet_preimage_pool::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const DualVotingMaxLLDLock: Balance = 100 * DOLLARS;
+}
+
+impl pallet_dual_voting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type LLM = LLM;
+	type Citizenship = LLM;
+	type LLInitializer = LiberlandInitializer;
+	type Enabled = DualVotingEnabled;
+	type GovernanceOrigin = EnsureRootOrHalfCouncil;
+	type VotingPeriod = ReferendumVotingPeriod;
+	type ConvictionPeriod = EnactmentPeriod;
+	type MaxLLDLock = DualVotingMaxLLDLock;
+	type WeightInfo = pallet_dual_voting::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const SovereignFundPalletId: PalletId = PalletId(*b"py/sovfd");
+	pub const SovereignFundSpendPeriod: BlockNumber = 30 * DAYS;
+	pub const SovereignFundMaxSpendPerPeriod: Balance = 100_000 * DOLLARS;
+}
+
+impl pallet_sovereign_fund::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = SovereignFundPalletId;
+	type SpendOrigin = EnsureRootOrHalfCouncil;
+	type SpendPeriod = SovereignFundSpendPeriod;
+	type MaxSpendPerPeriod = SovereignFundMaxSpendPerPeriod;
+	type MaxPayoutsPerBlock = ConstU32<16>;
+	type WeightInfo = pallet_sovereign_fund::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const RecoveryConfigDepositBase: Balance = 5 * DOLLARS;
+	pub const RecoveryFriendDepositFactor: Balance = 50 * CENTS;
+	pub const RecoveryDeposit: Balance = 5 * DOLLARS;
+}
+
+impl pallet_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_recovery::weights::SubstrateWeight<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ConfigDepositBase = RecoveryConfigDepositBase;
+	type FriendDepositFactor = RecoveryFriendDepositFactor;
+	type MaxFriends = ConstU32<9>;
+	type RecoveryDeposit = RecoveryDeposit;
+}
+
+impl pallet_force_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_force_recovery::weights::SubstrateWeight<Runtime>;
+}
+
+ord_parameter_types! {
+	pub const LandRegistryOffice: AccountId = AccountIdConversion::<AccountId>::into_account_truncating(&LandRegistryOfficePalletId::get());
+}
+
+parameter_types! {
+	pub LandLeaseReserveIdentifier: &'static [u8; 8] = b"landleas";
+}
+
+impl pallet_land_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OfficeOrigin = EnsureSignedBy<LandRegistryOffice, AccountId>;
+	type LandCollections = impls::LandCollections;
+	type Demarcation = impls::LandMetadataValidator<LLCoords>;
+	type MaxPlots = ConstU32<16>;
+	type RentCurrency = Balances;
+	type ReserveIdentifier = LandLeaseReserveIdentifier;
+	type MaxLeasesPerBlock = ConstU32<64>;
+	type WeightInfo = pallet_land_registry::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::LandMetadataValidator<LLCoords>;
+}
+
+parameter_types! {
+	pub const CourtPalletId: PalletId = PalletId(*b"lib/cort");
+	pub const CourtCaseBond: Balance = 100 * DOLLARS;
+	pub const CourtAppealBond: Balance = 500 * DOLLARS;
+	pub const CourtAppealWindow: BlockNumber = 14 * DAYS;
+	pub MaxCourtRulingWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub MaxCourtEnactmentWeight: Weight = Perbill::from_percent(20) * RuntimeBlockWeights::get().max_block;
+}
+
+// Court's account isn't registered in `pallet_llm::Courts` by default - Root
+// has to add it with `set_courts`. Until then, rulings can only spend the
+// account's own funds and sanction defendants, see `SanctionOrigin`.
+
+impl pallet_court::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type PalletId = CourtPalletId;
+	type RosterOrigin = EnsureRootOrHalfCouncil;
+	type CaseBond = CourtCaseBond;
+	type AppealBond = CourtAppealBond;
+	type AppealWindow = CourtAppealWindow;
+	type MaxJudges = ConstU32<64>;
+	type MaxAppeals = ConstU32<4>;
+	type MaxRulingLen = ConstU32<1024>;
+	type MaxRulingWeight = MaxCourtRulingWeight;
+	type MaxRulingsPerBlock = ConstU32<16>;
+	type MaxEnactmentWeight = MaxCourtEnactmentWeight;
+	type WeightInfo = pallet_court::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "testnet-runtime")]
+parameter_types! {
+	pub const FaucetPalletId: PalletId = PalletId(*b"lib/fauc");
+	pub const FaucetLLDDrip: Balance = 100 * DOLLARS;
+	pub const FaucetLLMDrip: Balance = 100 * GRAINS_IN_LLM;
+	pub const FaucetDripPeriod: BlockNumber = 1 * DAYS;
+	// below any transaction paying fees
+	pub const FaucetUnsignedPriority: TransactionPriority = 0;
+}
+
+#[cfg(feature = "testnet-runtime")]
+impl pallet_faucet::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type LLM = frame_support::traits::tokens::fungible::ItemOf<Assets, AssetId, AccountId>;
+	type PalletId = FaucetPalletId;
+	type BudgetOrigin = EnsureRoot<AccountId>;
+	type LLDDrip = FaucetLLDDrip;
+	type LLMDrip = FaucetLLMDrip;
+	type DripPeriod = FaucetDripPeriod;
+	type DefaultBlockBudget = ConstU32<10>;
+	type UnsignedPriority = FaucetUnsignedPriority;
+	type WeightInfo = pallet_faucet::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const IdentityAttestorFetchInterval: BlockNumber = 10 * MINUTES;
+	pub const IdentityAttestorUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 4;
+}
+
+impl pallet_identity_attestor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = pallet_identity_attestor::crypto::AttestorAuthId;
+	type AttestorOrigin = EnsureRootOrHalfCouncil;
+	type RegistrarIndex = ConstU32<0>;
+	type MaxAttestors = ConstU32<16>;
+	type FetchInterval = IdentityAttestorFetchInterval;
+	type MaxAttestationsPerFetch = ConstU32<50>;
+	type UnsignedPriority = IdentityAttestorUnsignedPriority;
+	type WeightInfo = pallet_identity_attestor::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const CitizenshipNftPalletId: PalletId = PalletId(*b"lib/cnft");
+}
+
+impl pallet_citizenship_nft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Citizenship = LLM;
+	type LLInitializer = LiberlandInitializer;
+	type PalletId = CitizenshipNftPalletId;
+	type WeightInfo = pallet_citizenship_nft::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const EthBridgePalletId: PalletId = PalletId(*b"lib/ethb");
+	pub const EthBridgeEpochLength: BlockNumber = 1 * DAYS;
+	pub const EthBridgeEpochLimit: Balance = 50_000 * GRAINS_IN_LLM;
+}
+
+impl pallet_eth_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Token = frame_support::traits::tokens::fungible::ItemOf<Assets, AssetId, AccountId>;
+	type PalletId = EthBridgePalletId;
+	type GovernanceOrigin = EnsureRootOrHalfCouncil;
+	type MaxAttestors = MaxRelays;
+	type MinimumTransfer = LLMMinimumTransfer;
+	type MaxTotalLocked = LLMMaxTotalLocked;
+	type EpochLength = EthBridgeEpochLength;
+	type EpochLimit = EthBridgeEpochLimit;
+	type WeightInfo = pallet_eth_bridge::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const SanctionsFreezeIdentifier: [u8; 8] = *b"sanction";
+}
+
+impl pallet_sanctions::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type FreezeIdentifier = SanctionsFreezeIdentifier;
+	type LLMAssetId = AssetId;
+	type SanctionOrigin = SanctionOrigin;
+	type MaxCaseRefLen = ConstU32<64>;
+	type MaxExpiriesPerBlock = ConstU32<64>;
+	type WeightInfo = pallet_sanctions::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const PriceOracleMaxPriceAge: BlockNumber = 6 * HOURS;
+}
+
+// Oracle's LLD to LLM conversion isn't used for transaction fees yet, they're
+// priced by AssetConversionTxPayment with asset conversion pools.
+impl pallet_price_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = u32;
+	type LLMAssetId = AssetId;
+	type FeederOrigin = EnsureRootOrHalfCouncil;
+	type MaxFeeders = ConstU32<16>;
+	type MinSubmissions = ConstU32<3>;
+	type MaxPriceAge = PriceOracleMaxPriceAge;
+	type WeightInfo = pallet_price_oracle::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_runtime_upgrade::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type StageOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+	type ApproveOrigin = EnsureRootOrHalfCouncil;
+	type CancelOrigin = EnsureSenateOrCouncilMajority;
+	type EnactmentDelay = EnactmentPeriod;
+	type WeightInfo = pallet_runtime_upgrade::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub MaxEmergencyCallWeight: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+	pub const SudoSunsetVetoWindow: BlockNumber = 3 * DAYS;
+}
+
+impl pallet_sudo_sunset::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type CongressOrigin = pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	type SenateOrigin = EnsureSenateMajority;
+	type TechnicalOrigin = pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>;
+	type RetireOrigin = EnsureRoot<AccountId>;
+	type VetoWindow = SudoSunsetVetoWindow;
+	type MaxCallLen = ConstU32<4096>;
+	type MaxCallWeight = MaxEmergencyCallWeight;
+	type WeightInfo = pallet_sudo_sunset::weights::SubstrateWeight<Runtime>;
+}
+
+pallet_runtime_parameters::define_parameters! {
+	/// Liberland constants overridable by governance. Defaults are the
+	/// compile-time constants used before.
+	pub enum LiberlandParameter, LiberlandParameterKey for RuntimeParameters {
+		/// Minimum politipooled LLM of citizens
+		CitizenshipMinimumPooledLLM: Balance = CitizenshipMinimum::get(),
+			valid if |v| *v > 0 && *v >= EResidentMinimumPooledLLM::get();
+		/// Minimum politipooled LLM of e-residents
+		EResidentMinimumPooledLLM: Balance = EResidentMinimum::get(),
+			valid if |v| *v <= CitizenshipMinimumPooledLLM::get();
+		/// Length of Congress term
+		ElectionTermDuration: BlockNumber = TermDuration::get(), valid if |v| *v > 0;
+		/// How often referenda are launched
+		ReferendumLaunchPeriod: BlockNumber = LaunchPeriod::get(), valid if |v| *v > 0;
+		/// How long referenda are voted on. Set only by Root and never
+		/// shorter than fast-tracked referenda
+		ReferendumVotingPeriod: BlockNumber = VotingPeriod::get(),
+			valid if |v| *v >= FastTrackVotingPeriod::get();
+		/// How long a proposal vetoed by the technical committee can't be
+		/// proposed again
+		VetoCooloffPeriod: BlockNumber = CooloffPeriod::get(), valid if |v| *v > 0;
+	}
+}
+
+impl pallet_runtime_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Parameter = LiberlandParameter;
+	type AdminOrigin = RuntimeParametersAdminOrigin;
+	type WeightInfo = pallet_runtime_parameters::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::RuntimeParametersBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const ContractTaskDeposit: Balance = 1 * DOLLARS;
+	pub const ContractTaskByteDeposit: Balance = 1 * CENTS;
+}
+
+impl pallet_contract_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type CallFilter = ScheduledContractCallFilter;
+	type TaskDeposit = ContractTaskDeposit;
+	type ByteDeposit = ContractTaskByteDeposit;
+	type MaxCallLen = ConstU32<4096>;
+	type MaxTasksPerContract = ConstU32<10>;
+	// contracts can take at most 30 of 50 slots
+	type ReservedAgendaSlots = ConstU32<20>;
+	type WeightInfo = pallet_contract_scheduler::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::ContractSchedulerBenchmarkHelper;
+}
+
 // Sora Bridge
 parameter_types! {
 	pub const BridgeMaxMessagePayloadSize: u32 = 256;
@@ -1810,6 +2271,19 @@ construct_runtime!(
 		ContractsRegistry: pallet_contracts_registry = 65,
 		SenateAccount: pallet_custom_account::<Instance2> = 66,
 		MinistryOfFinanceOffice: pallet_office::<Instance6> = 67,
+		Procurement: pallet_procurement = 68,
+		Watchlist: pallet_watchlist = 69,
+		ValidatorOnboarding: pallet_validator_onboarding = 70,
+		PreimagePool: pallet_preimage_pool = 71,
+		DualVoting: pallet_dual_voting = 72,
+		SovereignFund: pallet_sovereign_fund = 73,
+		Recovery: pallet_recovery = 74,
+		ForceRecovery: pallet_force_recovery = 75,
+		LandRegistry: pallet_land_registry = 76,
+		Court: pallet_court = 77,
+		NominationPools: pallet_nomination_pools = 78,
+		#[cfg(feature = "testnet-runtime")]
+		Faucet: pallet_faucet = 79,
 
 		// Sora Bridge:
 		LeafProvider: leaf_provider = 80,
@@ -1820,6 +2294,16 @@ construct_runtime!(
 		BridgeDataSigner: bridge_data_signer = 85,
 		MultisigVerifier: multisig_verifier = 86,
 		SoraBridgeProvider: sora_liberland_bridge_provider = 87,
+
+		IdentityAttestor: pallet_identity_attestor = 88,
+		CitizenshipNft: pallet_citizenship_nft = 89,
+		LLMEthBridge: pallet_eth_bridge = 90,
+		Sanctions: pallet_sanctions = 91,
+		PriceOracle: pallet_price_oracle = 92,
+		RuntimeUpgrade: pallet_runtime_upgrade = 93,
+		SudoSunset: pallet_sudo_sunset = 94,
+		RuntimeParameters: pallet_runtime_parameters = 95,
+		ContractScheduler: pallet_contract_scheduler = 96,
 	}
 );
 
@@ -1833,6 +2317,14 @@ pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 pub type SignedBlock = generic::SignedBlock<Block>;
 /// BlockId type as expected by this runtime.
 pub type BlockId = generic::BlockId<Block>;
+parameter_types! {
+	/// Priority added to governance calls boosted by [`impls::BoostPriority`].
+	/// Saturates transaction's priority, so boosted calls are ordered before
+	/// all other transactions regardless of their tips. Tips don't order
+	/// boosted calls among themselves.
+	pub const BoostedCallPriority: TransactionPriority = TransactionPriority::max_value();
+}
+
 /// The SignedExtension to the basic transaction logic.
 ///
 /// When you change this, you **MUST** modify [`sign`] in `bin/node/testing/src/keyring.rs`!
@@ -1847,6 +2339,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+	impls::BoostPriority,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1871,6 +2364,9 @@ pub type Executive = frame_executive::Executive<
 type Migrations = (
 	// Migrations for spec version 27 - delete when bumping to v28
 	crate::migrations::add_ministry_of_finance_office_pallet::Migration<Runtime>,
+	// Migrations for spec version 28
+	crate::migrations::add_nomination_pools_pallet::Migration<Runtime>,
+	pallet_llm::migrations::v5::Migration<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -1880,6 +2376,9 @@ type EventRecord = frame_system::EventRecord<
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
+	// Testnet-only pallets, like `pallet_faucet`, are added in
+	// `benchmark_metadata` and `dispatch_benchmark`, as entries here can't be
+	// feature-gated.
 	frame_benchmarking::define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[pallet_assets, Assets]
@@ -1917,6 +2416,26 @@ mod benches {
 		[pallet_llm, LLM]
 		[pallet_custom_account, CouncilAccount]
 		[pallet_contracts_registry, ContractsRegistry]
+		[pallet_procurement, Procurement]
+		[pallet_watchlist, Watchlist]
+		[pallet_validator_onboarding, ValidatorOnboarding]
+		[pallet_preimage_pool, PreimagePool]
+		[pallet_dual_voting, DualVoting]
+		[pallet_sovereign_fund, SovereignFund]
+		[pallet_recovery, Recovery]
+		[pallet_force_recovery, ForceRecovery]
+		[pallet_land_registry, LandRegistry]
+		[pallet_court, Court]
+		[pallet_identity_attestor, IdentityAttestor]
+		[pallet_citizenship_nft, CitizenshipNft]
+		[pallet_eth_bridge, LLMEthBridge]
+		[pallet_sanctions, Sanctions]
+		[pallet_price_oracle, PriceOracle]
+		[pallet_runtime_upgrade, RuntimeUpgrade]
+		[pallet_sudo_sunset, SudoSunset]
+		[pallet_runtime_parameters, RuntimeParameters]
+		[pallet_contract_scheduler, ContractScheduler]
+		[liberland_extension_runtime, LiberlandExtensionBench::<Runtime>]
 	);
 }
 
@@ -2092,6 +2611,124 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl civic_schema_api::CivicSchemaApi<Block> for Runtime {
+		fn schema_version() -> u32 {
+			civic_schema_api::CIVIC_SCHEMA_VERSION
+		}
+	}
+
+	impl chain_extension_api::ChainExtensionApi<Block> for Runtime {
+		fn extension_version() -> u32 {
+			liberland_extension_runtime::info::VERSION
+		}
+
+		fn extension_functions() -> Vec<liberland_extension_runtime::info::FunctionInfo> {
+			liberland_extension_runtime::info::functions()
+		}
+	}
+
+	impl watchlist_api::WatchlistApi<Block, AccountId> for Runtime {
+		fn active_watchers(account: AccountId) -> Vec<(AccountId, Vec<u8>)> {
+			Watchlist::active_watchers(account)
+				.into_iter()
+				.map(|(watcher, label)| (watcher, label.into_inner()))
+				.collect()
+		}
+
+		fn watched_accounts(watcher: AccountId) -> Vec<AccountId> {
+			Watchlist::watched_accounts(watcher)
+		}
+	}
+
+	impl land_registry_api::LandRegistryApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn lease(index: pallet_land_registry::LeaseIndex) -> Option<land_registry_api::LeaseOf<AccountId, Balance, BlockNumber>> {
+			LandRegistry::leases(index)
+		}
+
+		fn plot_lease(collection: u32, item: u32) -> Option<(pallet_land_registry::LeaseIndex, land_registry_api::LeaseOf<AccountId, Balance, BlockNumber>)> {
+			LandRegistry::plot_lease(collection, item)
+		}
+
+		fn leases_of(account: AccountId) -> Vec<(pallet_land_registry::LeaseIndex, land_registry_api::LeaseOf<AccountId, Balance, BlockNumber>)> {
+			LandRegistry::leases_of(&account)
+		}
+	}
+
+	impl pallet_llm_runtime_api::LLMApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn is_citizen(account: AccountId) -> bool {
+			use liberland_traits::CitizenshipChecker;
+			LLM::is_citizen(&account)
+		}
+
+		fn pooled_llm(account: AccountId) -> pallet_llm_runtime_api::PooledLLM<AccountId, Balance, BlockNumber> {
+			pallet_llm_runtime_api::PooledLLM {
+				pooled: LLM::llm_politics(&account),
+				pending_unpool: LLM::pending_unpool(&account),
+				delegated_power: LLM::delegated_power(&account),
+				delegation: LLM::delegation(&account),
+				election_lock: LLM::election_lock(&account),
+			}
+		}
+
+		fn election_snapshot() -> pallet_llm_runtime_api::ElectionSnapshot<AccountId, Balance> {
+			let seats = |holders: Vec<pallet_elections_phragmen::SeatHolder<AccountId, Balance>>| {
+				holders.into_iter().map(|h| (h.who, h.stake)).collect()
+			};
+			pallet_llm_runtime_api::ElectionSnapshot {
+				round: Elections::election_rounds(),
+				members: seats(Elections::members()),
+				runners_up: seats(Elections::runners_up()),
+				candidates: Elections::candidates().into_iter().map(|(who, _)| who).collect(),
+			}
+		}
+
+		fn total_pooled() -> Balance {
+			LLM::balance(LLM::get_llm_politipool_account())
+		}
+
+		fn citizens() -> u64 {
+			LLM::citizens()
+		}
+	}
+
+	impl pallet_court_runtime_api::CourtApi<Block, AccountId> for Runtime {
+		fn dry_run_proposal(contract: AccountId, proposal: Vec<u8>) -> pallet_court_runtime_api::ProposalDryRun {
+			let input = [&pallet_court_runtime_api::SIMULATE_SELECTOR[..], &proposal[..]].concat();
+			let exec = Contracts::bare_call(
+				contract.clone(),
+				contract,
+				0,
+				RuntimeBlockWeights::get().max_block,
+				None,
+				input,
+				pallet_contracts::DebugInfo::Skip,
+				pallet_contracts::CollectEvents::Skip,
+				pallet_contracts::Determinism::Enforced,
+			);
+			// `simulate` returns ink!'s `Result<Result<(), Error>, LangError>`
+			let (result, contract_error) = match exec.result {
+				Ok(ret) => match ret.data.get(..2) {
+					Some([0, 0]) => (Ok(()), None),
+					Some([0, 1]) => (
+						Err(DispatchError::Other("Proposal failed")),
+						Some(ret.data[2..].to_vec()),
+					),
+					_ => (Err(DispatchError::Other("Not an msig_court contract")), None),
+				},
+				Err(e) => (Err(e), None),
+			};
+			pallet_court_runtime_api::ProposalDryRun {
+				result,
+				contract_error,
+				weight_consumed: exec.gas_consumed,
+			}
+		}
+
+		fn open_cases() -> u32 {
+			pallet_court::Cases::<Runtime>::iter_keys().count() as u32
+		}
+	}
+
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord> for Runtime
 	{
 		fn call(
@@ -2221,6 +2858,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
+		fn pending_rewards(who: AccountId) -> Balance {
+			NominationPools::api_pending_rewards(who).unwrap_or_default()
+		}
+
+		fn points_to_balance(pool_id: pallet_nomination_pools::PoolId, points: Balance) -> Balance {
+			NominationPools::api_points_to_balance(pool_id, points)
+		}
+
+		fn balance_to_points(pool_id: pallet_nomination_pools::PoolId, new_funds: Balance) -> Balance {
+			NominationPools::api_balance_to_points(pool_id, new_funds)
+		}
+	}
+
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
@@ -2316,9 +2967,12 @@ impl_runtime_apis! {
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use liberland_extension_runtime::benchmarking::Pallet as LiberlandExtensionBench;
 
 			let mut list = Vec::<BenchmarkList>::new();
 			list_benchmarks!(list, extra);
+			#[cfg(feature = "testnet-runtime")]
+			frame_benchmarking::list_benchmark!(list, extra, pallet_faucet, Faucet);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -2339,12 +2993,14 @@ impl_runtime_apis! {
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use liberland_extension_runtime::benchmarking::Pallet as LiberlandExtensionBench;
 
 			impl pallet_session_benchmarking::Config for Runtime {}
 			impl pallet_offences_benchmarking::Config for Runtime {}
 			impl pallet_election_provider_support_benchmarking::Config for Runtime {}
 			impl frame_system_benchmarking::Config for Runtime {}
 			impl baseline::Config for Runtime {}
+			impl liberland_extension_runtime::benchmarking::Config for Runtime {}
 
 			use frame_support::traits::WhitelistedStorageKeys;
 			let mut whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
@@ -2358,6 +3014,8 @@ impl_runtime_apis! {
 			let mut batches = Vec::<BenchmarkBatch>::new();
 			let params = (&config, &whitelist);
 			add_benchmarks!(params, batches);
+			#[cfg(feature = "testnet-runtime")]
+			frame_benchmarking::add_benchmark!(params, batches, pallet_faucet, Faucet);
 			Ok(batches)
 		}
 	}
@@ -2403,4 +3061,51 @@ mod tests {
 			size,
 		);
 	}
+
+	fn citizen() -> AccountId {
+		AccountId::new([1u8; 32])
+	}
+
+	#[test]
+	fn governance_proxy_allows_politics() {
+		let lock = RuntimeCall::LLM(pallet_llm::Call::politics_lock { amount: 1u8.into() });
+		let unlock = RuntimeCall::LLM(pallet_llm::Call::politics_unlock {});
+		let send = RuntimeCall::LLM(pallet_llm::Call::send_llm { to_account: citizen(), amount: 1u8.into() });
+		assert!(ProxyType::Governance.filter(&lock));
+		assert!(ProxyType::Governance.filter(&unlock));
+		assert!(!ProxyType::Governance.filter(&send));
+	}
+
+	#[test]
+	fn identity_proxy_allows_only_identity() {
+		let clear = RuntimeCall::Identity(pallet_identity::Call::clear_identity {});
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert!(ProxyType::Identity.filter(&clear));
+		assert!(!ProxyType::Identity.filter(&remark));
+		assert!(!ProxyType::Governance.filter(&clear));
+	}
+
+	#[test]
+	fn nfts_proxy_allows_only_nfts() {
+		let transfer = RuntimeCall::Nfts(pallet_nfts::Call::transfer {
+			collection: 0,
+			item: 0,
+			dest: citizen().into(),
+		});
+		let lld = RuntimeCall::Balances(pallet_balances::Call::transfer {
+			dest: citizen().into(),
+			value: 1u8.into(),
+		});
+		assert!(ProxyType::Nfts.filter(&transfer));
+		assert!(!ProxyType::Nfts.filter(&lld));
+		assert!(!ProxyType::NonTransfer.filter(&transfer));
+	}
+
+	#[test]
+	fn non_transfer_proxy_is_not_superset_of_nfts() {
+		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Identity));
+		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Governance));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Nfts));
+		assert!(ProxyType::Any.is_superset(&ProxyType::Nfts));
+	}
 }
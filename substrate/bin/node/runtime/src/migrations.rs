@@ -9,6 +9,18 @@ use sp_runtime::TryRuntimeError;
 
 type DbWeight = <Runtime as frame_system::Config>::DbWeight;
 
+/// Encode state snapshotted in `pre_upgrade`, to be checked in `post_upgrade`
+#[cfg(feature = "try-runtime")]
+fn encode_pre_state<S: Encode>(state: &S) -> Vec<u8> {
+	state.encode()
+}
+
+/// Decode state encoded by `encode_pre_state`
+#[cfg(feature = "try-runtime")]
+fn decode_pre_state<S: Decode>(state: &[u8]) -> Result<S, TryRuntimeError> {
+	S::decode(&mut &state[..]).map_err(|_| "Failed to decode pre-upgrade state".into())
+}
+
 pub mod add_ministry_of_finance_office_pallet {
 	use super::*;
 
@@ -17,7 +29,8 @@ pub mod add_ministry_of_finance_office_pallet {
 	impl OnRuntimeUpgrade for Migration<Runtime> {
 		#[cfg(feature = "try-runtime")]
 		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
-			Ok(().encode())
+			let version = StorageVersion::get::<MinistryOfFinanceOffice>();
+			Ok(encode_pre_state(&version))
 		}
 
 		fn on_runtime_upgrade() -> Weight {
@@ -32,8 +45,100 @@ pub mod add_ministry_of_finance_office_pallet {
 		}
 
 		#[cfg(feature = "try-runtime")]
-		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_version: StorageVersion = decode_pre_state(&state)?;
+			let version = StorageVersion::get::<MinistryOfFinanceOffice>();
+			if pre_version == 0 {
+				ensure!(version == 1, "MinistryOfFinanceOffice storage version not set to 1");
+			} else {
+				ensure!(version == pre_version, "MinistryOfFinanceOffice storage version changed");
+			}
+			Ok(())
+		}
+	}
+}
+pub mod add_nomination_pools_pallet {
+	use super::*;
+	use frame_support::traits::GetStorageVersion;
+	use pallet_nomination_pools::WeightInfo;
+
+	/// Sets up nomination pools parameters on chains where the pallet was
+	/// added by a runtime upgrade and creates the community pool, owned by
+	/// Council account.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl Migration<Runtime> {
+		fn create_community_pool() -> Weight {
+			let council = CouncilAccountId::get();
+			let source = <Runtime as frame_system::Config>::Lookup::unlookup(council.clone());
+			let created = NominationPools::create(
+				RuntimeOrigin::signed(council.clone()),
+				CommunityPoolBond::get(),
+				source.clone(),
+				source.clone(),
+				source,
+			);
+			if let Err(e) = created {
+				log::error!("Community nomination pool creation failed: {e:?}");
+				return DbWeight::get().reads(2);
+			}
+
+			let pool_id = pallet_nomination_pools::LastPoolId::<Runtime>::get();
+			let metadata = b"Liberland Community Pool".to_vec();
+			let metadata_len = metadata.len() as u32;
+			if let Err(e) =
+				NominationPools::set_metadata(RuntimeOrigin::signed(council), pool_id, metadata)
+			{
+				log::error!("Community nomination pool metadata failed: {e:?}");
+			}
+			type Weights = <Runtime as pallet_nomination_pools::Config>::WeightInfo;
+			Weights::create().saturating_add(Weights::set_metadata(metadata_len))
+		}
+	}
+
+	impl OnRuntimeUpgrade for Migration<Runtime> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let version = StorageVersion::get::<NominationPools>();
+			Ok(encode_pre_state(&version))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = DbWeight::get().reads(1);
+			if StorageVersion::get::<NominationPools>() != 0 {
+				return weight;
+			}
+
+			NominationPools::current_storage_version().put::<NominationPools>();
+			pallet_nomination_pools::MinJoinBond::<Runtime>::put(NominationPoolsMinJoinBond::get());
+			pallet_nomination_pools::MinCreateBond::<Runtime>::put(NominationPoolsMinCreateBond::get());
+			pallet_nomination_pools::MaxPools::<Runtime>::put(NominationPoolsMaxPools::get());
+			pallet_nomination_pools::GlobalMaxCommission::<Runtime>::put(
+				NominationPoolsMaxCommission::get(),
+			);
+			weight = weight.saturating_add(DbWeight::get().writes(5));
+
+			weight.saturating_add(Self::create_community_pool())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_version: StorageVersion = decode_pre_state(&state)?;
+			let version = StorageVersion::get::<NominationPools>();
+			if pre_version == 0 {
+				ensure!(
+					version == NominationPools::current_storage_version(),
+					"NominationPools storage version not set to current"
+				);
+				ensure!(
+					pallet_nomination_pools::GlobalMaxCommission::<Runtime>::get() ==
+						Some(NominationPoolsMaxCommission::get()),
+					"NominationPools commission cap not set"
+				);
+			} else {
+				ensure!(version == pre_version, "NominationPools storage version changed");
+			}
 			Ok(())
 		}
 	}
-}
\ No newline at end of file
+}
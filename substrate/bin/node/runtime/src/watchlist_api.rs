@@ -0,0 +1,20 @@
+//! Runtime API definition for the watchlist pallet.
+//!
+//! Used by indexers to prioritize watched accounts and by notification
+//! gateways to find institutions subscribed to an account's activity.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait WatchlistApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Returns watchers with active watch of `account`, with their labels.
+		fn active_watchers(account: AccountId) -> Vec<(AccountId, Vec<u8>)>;
+
+		/// Returns accounts actively watched by `watcher`.
+		fn watched_accounts(watcher: AccountId) -> Vec<AccountId>;
+	}
+}
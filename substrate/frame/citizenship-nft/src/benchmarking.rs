@@ -0,0 +1,23 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as CitizenshipNft;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use liberland_traits::LLInitializer;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+	claim {
+		let who: T::AccountId = account("citizen", 0, SEED);
+		T::LLInitializer::make_test_citizen(&who);
+		// runtime mints the NFT when citizenship is granted, forget it
+		CitizenItem::<T>::remove(&who);
+	}: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(CitizenshipNft::<T>::has_nft(&who));
+	}
+}
+
+impl_benchmark_test_suite!(CitizenshipNft, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,241 @@
+//! # Liberland Citizenship NFT Pallet
+//!
+//! ## Overview
+//!
+//! Citizenship NFT pallet keeps a soulbound NFT for every Liberland citizen,
+//! so that dApps can check citizenship with standard NFT tooling.
+//!
+//! * NFTs live in a single `pallet-nfts` collection owned by pallet's
+//!   account. Collection is created when first NFT is minted.
+//! * Transfers are disabled for the whole collection.
+//! * NFT is minted when account becomes a citizen and burned when it stops
+//!   being one. This is driven by `OnCitizenshipChange` hook called by
+//!   `pallet-llm`.
+//! * Citizens without an NFT (e.g. ones that were citizens before this
+//!   pallet was deployed) can mint it with `claim`.
+//!
+//! ## Pallet Config
+//!
+//! * `Citizenship` - used to check citizenship in `claim`
+//! * `LLInitializer` - used to make test citizens in benchmarks
+//! * `PalletId` - PalletId used to derive account owning the collection
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `claim`: Mint citizenship NFT for citizen that doesn't have one
+//!
+//! ### OnCitizenshipChange
+//!
+//! Pallet implements `OnCitizenshipChange` and should be configured as
+//! `pallet_llm::Config::OnCitizenshipChange`.
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Incrementable, OriginTrait},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use liberland_traits::{CitizenshipChecker, LLInitializer, OnCitizenshipChange};
+	use pallet_nfts::{
+		CollectionConfig, CollectionSetting, CollectionSettings, ItemConfig, ItemSetting,
+		ItemSettings, MintSettings,
+	};
+	use sp_runtime::traits::{AccountIdConversion, StaticLookup};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_nfts::Config<ItemId = u32> {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Used to check if account is a citizen in `claim`
+		type Citizenship: CitizenshipChecker<Self::AccountId>;
+
+		/// Makes test citizens in benchmarks
+		type LLInitializer: LLInitializer<Self::AccountId>;
+
+		/// PalletId used to derive account owning the citizenship collection
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account isn't a citizen
+		NotCitizen,
+		/// Account already has citizenship NFT
+		AlreadyClaimed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Citizenship NFT minted
+		Minted { who: T::AccountId, item: u32 },
+		/// Citizenship NFT burned
+		Burned { who: T::AccountId, item: u32 },
+	}
+
+	/// Collection of citizenship NFTs. None until first NFT is minted.
+	#[pallet::storage]
+	#[pallet::getter(fn collection)]
+	pub type Collection<T: Config> = StorageValue<_, T::CollectionId, OptionQuery>;
+
+	/// Item id of citizenship NFT held by given account
+	#[pallet::storage]
+	#[pallet::getter(fn citizen_item)]
+	pub type CitizenItem<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// Item id used for the next minted NFT
+	#[pallet::storage]
+	#[pallet::getter(fn next_item)]
+	pub type NextItem<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint citizenship NFT for a citizen that doesn't hold one, e.g.
+		/// because they were a citizen before this pallet was deployed.
+		///
+		/// The dispatch origin of this call must be Signed by a citizen.
+		///
+		/// Emits `Minted`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<T as Config>::Citizenship::is_citizen(&who), Error::<T>::NotCitizen);
+			ensure!(!Self::has_nft(&who), Error::<T>::AlreadyClaimed);
+			Self::do_mint(who)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account owning the citizenship collection
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Checks if account currently holds citizenship NFT
+		pub fn has_nft(who: &T::AccountId) -> bool {
+			match (Self::collection(), Self::citizen_item(who)) {
+				(Some(collection), Some(item)) => {
+					pallet_nfts::Item::<T>::contains_key(collection, item)
+				},
+				_ => false,
+			}
+		}
+
+		fn ensure_collection() -> Result<T::CollectionId, DispatchError> {
+			if let Some(collection) = Self::collection() {
+				return Ok(collection);
+			}
+
+			let collection = pallet_nfts::NextCollectionId::<T>::get()
+				.or(T::CollectionId::initial_value())
+				.ok_or(pallet_nfts::Error::<T>::UnknownCollection)?;
+			let settings = CollectionSettings::from_disabled(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired,
+			);
+			let config = CollectionConfig {
+				settings,
+				max_supply: None,
+				mint_settings: MintSettings::default(),
+			};
+			pallet_nfts::Pallet::<T>::force_create(
+				T::RuntimeOrigin::root(),
+				T::Lookup::unlookup(Self::account_id()),
+				config,
+			)?;
+
+			Collection::<T>::put(collection);
+			Ok(collection)
+		}
+
+		fn do_mint(who: T::AccountId) -> DispatchResult {
+			let collection = Self::ensure_collection()?;
+			let item = Self::next_item();
+			let config = ItemConfig {
+				settings: ItemSettings::from_disabled(ItemSetting::Transferable.into()),
+			};
+			pallet_nfts::Pallet::<T>::do_mint(
+				collection,
+				item,
+				None,
+				who.clone(),
+				config,
+				|_, _| Ok(()),
+			)?;
+
+			NextItem::<T>::put(item.saturating_add(1));
+			CitizenItem::<T>::insert(&who, item);
+			Self::deposit_event(Event::Minted { who, item });
+			Ok(())
+		}
+
+		fn do_burn(who: T::AccountId) -> DispatchResult {
+			let Some(item) = CitizenItem::<T>::take(&who) else { return Ok(()) };
+			let Some(collection) = Self::collection() else { return Ok(()) };
+			// holder might have burned it already
+			if !pallet_nfts::Item::<T>::contains_key(collection, item) {
+				return Ok(());
+			}
+
+			pallet_nfts::Pallet::<T>::do_burn(collection, item, |_| Ok(()))?;
+			Self::deposit_event(Event::Burned { who, item });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> OnCitizenshipChange<T::AccountId> for Pallet<T> {
+		fn on_citizenship_granted(account: &T::AccountId) {
+			if Self::has_nft(account) {
+				return;
+			}
+			if let Err(e) = Self::do_mint(account.clone()) {
+				log::error!("Citizenship NFT mint failure: {e:?}");
+			}
+		}
+
+		fn on_citizenship_revoked(account: &T::AccountId) {
+			if let Err(e) = Self::do_burn(account.clone()) {
+				log::error!("Citizenship NFT burn failure: {e:?}");
+			}
+		}
+	}
+}
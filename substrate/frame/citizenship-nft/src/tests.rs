@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use liberland_traits::OnCitizenshipChange;
+
+fn owner_of(item: u32) -> Option<u64> {
+	pallet_nfts::Item::<Test>::get(0, item).map(|details| details.owner)
+}
+
+#[test]
+fn granting_citizenship_mints_nft() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_eq!(CitizenshipNft::collection(), Some(0));
+		assert_eq!(CitizenshipNft::citizen_item(1), Some(0));
+		assert_eq!(owner_of(0), Some(1));
+		assert!(CitizenshipNft::has_nft(&1));
+		System::assert_last_event(Event::<Test>::Minted { who: 1, item: 0 }.into());
+
+		CitizenshipNft::on_citizenship_granted(&2);
+		assert_eq!(CitizenshipNft::citizen_item(2), Some(1));
+		assert_eq!(owner_of(1), Some(2));
+	});
+}
+
+#[test]
+fn granting_citizenship_twice_mints_once() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_eq!(CitizenshipNft::next_item(), 1);
+		assert_eq!(owner_of(1), None);
+	});
+}
+
+#[test]
+fn collection_is_reused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 3, Default::default()));
+		CitizenshipNft::on_citizenship_granted(&1);
+		CitizenshipNft::on_citizenship_granted(&2);
+		assert_eq!(CitizenshipNft::collection(), Some(1));
+		assert_eq!(pallet_nfts::NextCollectionId::<Test>::get(), Some(2));
+		assert_eq!(
+			pallet_nfts::Collection::<Test>::get(1).map(|c| c.owner),
+			Some(CitizenshipNft::account_id())
+		);
+	});
+}
+
+#[test]
+fn nft_is_not_transferable() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(1), 0, 0, 3),
+			pallet_nfts::Error::<Test>::ItemsNonTransferable
+		);
+		assert_eq!(owner_of(0), Some(1));
+	});
+}
+
+#[test]
+fn revoking_citizenship_burns_nft() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		CitizenshipNft::on_citizenship_revoked(&1);
+		assert_eq!(owner_of(0), None);
+		assert_eq!(CitizenshipNft::citizen_item(1), None);
+		assert!(!CitizenshipNft::has_nft(&1));
+		System::assert_last_event(Event::<Test>::Burned { who: 1, item: 0 }.into());
+
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_eq!(CitizenshipNft::citizen_item(1), Some(1));
+	});
+}
+
+#[test]
+fn revoking_citizenship_without_nft_is_noop() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_revoked(&1);
+		assert_eq!(CitizenshipNft::collection(), None);
+
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(1), 0, 0));
+		CitizenshipNft::on_citizenship_revoked(&1);
+		assert_eq!(CitizenshipNft::citizen_item(1), None);
+	});
+}
+
+#[test]
+fn claim_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CitizenshipNft::claim(RuntimeOrigin::signed(2)));
+		assert_eq!(owner_of(0), Some(2));
+		System::assert_last_event(Event::<Test>::Minted { who: 2, item: 0 }.into());
+	});
+}
+
+#[test]
+fn claim_requires_citizenship() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(CitizenshipNft::claim(RuntimeOrigin::signed(3)), Error::<Test>::NotCitizen);
+	});
+}
+
+#[test]
+fn claim_fails_if_nft_held() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_noop!(
+			CitizenshipNft::claim(RuntimeOrigin::signed(1)),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn claim_replaces_burned_nft() {
+	new_test_ext().execute_with(|| {
+		CitizenshipNft::on_citizenship_granted(&1);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(1), 0, 0));
+		assert_ok!(CitizenshipNft::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(CitizenshipNft::citizen_item(1), Some(1));
+		assert_eq!(owner_of(1), Some(1));
+	});
+}
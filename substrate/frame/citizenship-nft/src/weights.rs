@@ -0,0 +1,38 @@
+//! Weights for pallet_citizenship_nft
+//!
+//! NOT BENCHMARKED. Estimated from pallet_nfts force_create and mint weights
+//! plus this pallet's own storage accesses. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_citizenship_nft`
+//! run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_citizenship_nft.
+pub trait WeightInfo {
+	fn claim() -> Weight;
+}
+
+/// Weights for pallet_citizenship_nft using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn claim() -> Weight {
+		Weight::from_parts(80_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn claim() -> Weight {
+		Weight::from_parts(80_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ContractScheduler;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	traits::{
+		schedule::{v3::Anon, DispatchTime, LOWEST_PRIORITY},
+		Currency, Get, StorePreimage,
+	},
+	weights::Weight,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+	traits::{Bounded, One, Saturating, Zero},
+	DispatchError,
+};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>() -> T::AccountId {
+	let who: T::AccountId = account("contract", 0, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+/// Takes all agenda slots of block `when` available to contracts but one
+fn fill_agenda<T: Config>(when: BlockNumberFor<T>) -> Result<(), DispatchError> {
+	let slots = <T as pallet_scheduler::Config>::MaxScheduledPerBlock::get()
+		.saturating_sub(T::ReservedAgendaSlots::get());
+	for _ in 1..slots {
+		let call: <T as pallet_scheduler::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call = <T as pallet_scheduler::Config>::Preimages::bound(call)?;
+		<pallet_scheduler::Pallet<T> as Anon<
+			BlockNumberFor<T>,
+			<T as pallet_scheduler::Config>::RuntimeCall,
+			<T as pallet_scheduler::Config>::PalletsOrigin,
+		>>::schedule(DispatchTime::At(when), None, LOWEST_PRIORITY, RawOrigin::Root.into(), call)?;
+	}
+	Ok(())
+}
+
+benchmarks! {
+	schedule {
+		let contract = funded::<T>();
+		let when = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		fill_agenda::<T>(when)?;
+		let call = T::BenchmarkHelper::call();
+	}: {
+		ContractScheduler::<T>::schedule(contract, Zero::zero(), call)?;
+	}
+	verify {
+		assert!(Tasks::<T>::contains_key(0));
+	}
+
+	dispatch_task {
+		let contract = funded::<T>();
+		ContractScheduler::<T>::schedule(contract.clone(), Zero::zero(), T::BenchmarkHelper::call())?;
+	}: _(RawOrigin::Root, 0, Weight::MAX)
+	verify {
+		assert!(!Tasks::<T>::contains_key(0));
+		assert_eq!(ContractScheduler::<T>::outstanding(contract), 0);
+	}
+}
+
+impl_benchmark_test_suite!(ContractScheduler, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,317 @@
+//! # Liberland Contract Scheduler Pallet
+//!
+//! ## Overview
+//!
+//! Contract scheduler pallet lets contracts schedule calls dispatched later
+//! with contract's origin, e.g. to execute a proposal once its veto period
+//! ends. It's used by the `schedule_call` function of the Liberland chain
+//! extension.
+//!
+//! * calls are stored by this pallet. `pallet_scheduler` only gets a small
+//!   `dispatch_task` call, dispatched with Root origin,
+//! * contract pays `TaskDeposit` plus `ByteDeposit` for each byte of the
+//!   encoded call. Deposit is held until the call is dispatched,
+//! * contract can have at most `MaxTasksPerContract` tasks waiting for
+//!   dispatch,
+//! * last `ReservedAgendaSlots` slots of every block's scheduler agenda are
+//!   left for other pallets, e.g. democracy enactments and runtime
+//!   upgrades,
+//! * only calls allowed by `CallFilter` can be scheduled. Filter is checked
+//!   again on dispatch.
+//!
+//! ## Pallet Config
+//!
+//! * `RuntimeCall` - the overarching call type, scheduled calls are of this
+//!   type
+//! * `Currency` - currency of deposits
+//! * `CallFilter` - calls contracts can schedule
+//! * `TaskDeposit` - deposit held for each scheduled task
+//! * `ByteDeposit` - deposit held for each byte of encoded call
+//! * `MaxCallLen` - maximum length of encoded call
+//! * `MaxTasksPerContract` - maximum number of tasks waiting for dispatch
+//!   scheduled by a single contract
+//! * `ReservedAgendaSlots` - number of slots in every block's scheduler
+//!   agenda that contracts can't take
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `dispatch_task`: Dispatch scheduled call, used by `pallet_scheduler`
+//!
+//! ### Public Functions
+//!
+//! * `schedule`: Schedule call dispatched with contract's origin
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, ReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::{Decode, Encode};
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			schedule::{v3::Anon, DispatchTime, LOWEST_PRIORITY},
+			Contains, StorePreimage,
+		},
+	};
+	use frame_system::{pallet_prelude::*, RawOrigin};
+	use pallet_scheduler::TaskAddress;
+	use sp_runtime::traits::{Dispatchable, One, Saturating};
+
+	pub type CallOf<T> = BoundedVec<u8, <T as Config>::MaxCallLen>;
+	pub type TaskOf<T> =
+		Task<<T as frame_system::Config>::AccountId, BalanceOf<T>, CallOf<T>, BlockNumberFor<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_scheduler::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, scheduled calls are of this type
+		type RuntimeCall: Parameter
+			+ GetDispatchInfo
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + From<Call<Self>>
+			+ IsType<<Self as pallet_scheduler::Config>::RuntimeCall>;
+
+		/// Currency of deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Calls contracts can schedule
+		type CallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Deposit held for each scheduled task
+		#[pallet::constant]
+		type TaskDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit held for each byte of encoded call
+		#[pallet::constant]
+		type ByteDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum length of encoded call
+		#[pallet::constant]
+		type MaxCallLen: Get<u32>;
+
+		/// Maximum number of tasks waiting for dispatch scheduled by a single
+		/// contract
+		#[pallet::constant]
+		type MaxTasksPerContract: Get<u32>;
+
+		/// Number of slots in every block's scheduler agenda that contracts
+		/// can't take, so they can't block enactments scheduled by other
+		/// pallets
+		#[pallet::constant]
+		type ReservedAgendaSlots: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+
+		/// Provides a call allowed by `CallFilter` for benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<<Self as Config>::RuntimeCall>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Call isn't allowed by `CallFilter`
+		CallNotAllowed,
+		/// Encoded call is longer than `MaxCallLen`
+		CallTooLong,
+		/// Contract already has `MaxTasksPerContract` tasks waiting for
+		/// dispatch
+		TooManyTasks,
+		/// No free slots for contracts left in the agenda of the target block
+		AgendaFull,
+		/// Task doesn't exist or was already dispatched
+		UnknownTask,
+		/// Stored call can't be decoded, e.g. after a runtime upgrade
+		UndecodableCall,
+		/// Call's dispatch weight exceeds weight prepaid when it was
+		/// scheduled
+		TaskTooHeavy,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Call scheduled by `contract` for dispatch at `when`
+		Scheduled { task: TaskIndex, contract: T::AccountId, when: BlockNumberFor<T> },
+		/// Scheduled call dispatched and deposit returned
+		Dispatched { task: TaskIndex, result: DispatchResult },
+	}
+
+	/// Number of tasks ever scheduled, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn task_count)]
+	pub type TaskCount<T: Config> = StorageValue<_, TaskIndex, ValueQuery>;
+
+	/// Tasks waiting for dispatch
+	#[pallet::storage]
+	#[pallet::getter(fn tasks)]
+	pub type Tasks<T: Config> = StorageMap<_, Twox64Concat, TaskIndex, TaskOf<T>, OptionQuery>;
+
+	/// Number of tasks waiting for dispatch scheduled by given contract
+	#[pallet::storage]
+	#[pallet::getter(fn outstanding)]
+	pub type Outstanding<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch call of a task with origin of the contract that scheduled
+		/// it. Deposit is returned even if the dispatch fails.
+		///
+		/// The dispatch origin of this call must be Root. It's scheduled by
+		/// `schedule`.
+		///
+		/// - `task`: Index of the task
+		/// - `weight`: Dispatch weight of the call, prepaid by the contract
+		///
+		/// Emits `Dispatched`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::dispatch_task().saturating_add(*weight))]
+		pub fn dispatch_task(
+			origin: OriginFor<T>,
+			task: TaskIndex,
+			weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let details = Tasks::<T>::take(task).ok_or(Error::<T>::UnknownTask)?;
+			T::Currency::unreserve(&details.contract, details.deposit);
+			let remaining = Self::outstanding(&details.contract).saturating_sub(1);
+			if remaining == 0 {
+				Outstanding::<T>::remove(&details.contract);
+			} else {
+				Outstanding::<T>::insert(&details.contract, remaining);
+			}
+
+			let (result, used) = Self::dispatch_call(details.contract, &details.call, weight);
+
+			Self::deposit_event(Event::Dispatched { task, result });
+			Ok(Some(<T as Config>::WeightInfo::dispatch_task().saturating_add(used)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Schedule `call` for dispatch with `contract`'s origin at the same
+		/// block as `DispatchTime::After(after)`. Holds deposit from the
+		/// contract until the call is dispatched. Returns address of the
+		/// scheduler's task.
+		///
+		/// Emits `Scheduled`.
+		pub fn schedule(
+			contract: T::AccountId,
+			after: BlockNumberFor<T>,
+			call: <T as Config>::RuntimeCall,
+		) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+			ensure!(T::CallFilter::contains(&call), Error::<T>::CallNotAllowed);
+			let outstanding = Self::outstanding(&contract);
+			ensure!(outstanding < T::MaxTasksPerContract::get(), Error::<T>::TooManyTasks);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let when = now.saturating_add(after).saturating_add(One::one());
+			let scheduled = pallet_scheduler::Agenda::<T>::decode_len(when).unwrap_or(0) as u32;
+			let slots = <T as pallet_scheduler::Config>::MaxScheduledPerBlock::get()
+				.saturating_sub(T::ReservedAgendaSlots::get());
+			ensure!(scheduled < slots, Error::<T>::AgendaFull);
+
+			let weight = call.get_dispatch_info().weight;
+			let call: CallOf<T> = call.encode().try_into().map_err(|_| Error::<T>::CallTooLong)?;
+			let deposit = T::TaskDeposit::get()
+				.saturating_add(T::ByteDeposit::get().saturating_mul((call.len() as u32).into()));
+
+			with_storage_layer(|| {
+				T::Currency::reserve(&contract, deposit)?;
+				let task = Self::task_count();
+				let dispatch: <T as pallet_scheduler::Config>::RuntimeCall =
+					<T as Config>::RuntimeCall::from(Call::<T>::dispatch_task { task, weight })
+						.into();
+				// small enough to be stored inline, without a preimage
+				let dispatch = <T as pallet_scheduler::Config>::Preimages::bound(dispatch)?;
+				let address = <pallet_scheduler::Pallet<T> as Anon<
+					BlockNumberFor<T>,
+					<T as pallet_scheduler::Config>::RuntimeCall,
+					<T as pallet_scheduler::Config>::PalletsOrigin,
+				>>::schedule(
+					DispatchTime::At(when),
+					None,
+					LOWEST_PRIORITY,
+					RawOrigin::Root.into(),
+					dispatch,
+				)?;
+
+				Tasks::<T>::insert(
+					task,
+					TaskOf::<T> { contract: contract.clone(), deposit, call, when },
+				);
+				TaskCount::<T>::put(task.saturating_add(1));
+				Outstanding::<T>::insert(&contract, outstanding.saturating_add(1));
+
+				Self::deposit_event(Event::Scheduled { task, contract, when });
+				Ok(address)
+			})
+		}
+
+		/// Dispatches stored call. Returns weight of the dispatched call.
+		fn dispatch_call(
+			contract: T::AccountId,
+			call: &[u8],
+			weight: Weight,
+		) -> (DispatchResult, Weight) {
+			let Ok(call) = <T as Config>::RuntimeCall::decode(&mut &call[..]) else {
+				return (Err(Error::<T>::UndecodableCall.into()), Weight::zero())
+			};
+			// filter and call's weight could change with a runtime upgrade
+			if !T::CallFilter::contains(&call) {
+				return (Err(Error::<T>::CallNotAllowed.into()), Weight::zero())
+			}
+			let info = call.get_dispatch_info();
+			if !info.weight.all_lte(weight) {
+				return (Err(Error::<T>::TaskTooHeavy.into()), Weight::zero())
+			}
+
+			let res = call.dispatch(RawOrigin::Signed(contract).into());
+			let used = extract_actual_weight(&res, &info);
+			(res.map(|_| ()).map_err(|e| e.error), used)
+		}
+	}
+}
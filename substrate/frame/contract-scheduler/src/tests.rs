@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		schedule::{v3::Anon, DispatchTime, LOWEST_PRIORITY},
+		Hooks, StorePreimage,
+	},
+	weights::Weight,
+};
+use sp_runtime::{DispatchError, TokenError};
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		Scheduler::on_initialize(next);
+	}
+}
+
+fn transfer(dest: u64, value: u64) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest, value })
+}
+
+fn remark(len: usize) -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![0; len] })
+}
+
+fn deposit(call: &RuntimeCall) -> u64 {
+	10 + call.encode().len() as u64
+}
+
+#[test]
+fn scheduled_call_is_dispatched_with_contract_origin() {
+	new_test_ext().execute_with(|| {
+		let call = transfer(3, 5);
+		assert_eq!(ContractScheduler::schedule(1, 2, call.clone()), Ok((4, 0)));
+		System::assert_last_event(Event::Scheduled { task: 0, contract: 1, when: 4 }.into());
+		assert_eq!(Balances::reserved_balance(1), deposit(&call));
+		assert_eq!(ContractScheduler::outstanding(1), 1);
+		assert_eq!(ContractScheduler::tasks(0).unwrap().call.into_inner(), call.encode());
+
+		run_to_block(3);
+		assert_eq!(Balances::free_balance(3), 100);
+
+		run_to_block(4);
+		System::assert_has_event(Event::Dispatched { task: 0, result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(3), 105);
+		assert_eq!(Balances::free_balance(1), 95);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(ContractScheduler::outstanding(1), 0);
+		assert_eq!(ContractScheduler::tasks(0), None);
+	});
+}
+
+#[test]
+fn deposit_is_returned_when_dispatch_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractScheduler::schedule(1, 0, transfer(3, 100)));
+		run_to_block(2);
+		System::assert_has_event(
+			Event::Dispatched { task: 0, result: Err(TokenError::FundsUnavailable.into()) }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(ContractScheduler::outstanding(1), 0);
+	});
+}
+
+#[test]
+fn only_allowed_calls_can_be_scheduled() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 });
+		assert_noop!(ContractScheduler::schedule(1, 0, call), Error::<Test>::CallNotAllowed);
+		let call = RuntimeCall::ContractScheduler(crate::Call::dispatch_task {
+			task: 0,
+			weight: Weight::zero(),
+		});
+		assert_noop!(ContractScheduler::schedule(1, 0, call), Error::<Test>::CallNotAllowed);
+		assert_noop!(ContractScheduler::schedule(1, 0, remark(64)), Error::<Test>::CallTooLong);
+	});
+}
+
+#[test]
+fn scheduling_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ContractScheduler::schedule(4, 0, remark(0)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(pallet_scheduler::Agenda::<Test>::decode_len(2), None);
+	});
+}
+
+#[test]
+fn tasks_per_contract_are_limited() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractScheduler::schedule(1, 0, remark(0)));
+		assert_ok!(ContractScheduler::schedule(1, 5, remark(0)));
+		assert_noop!(ContractScheduler::schedule(1, 9, remark(0)), Error::<Test>::TooManyTasks);
+		assert_ok!(ContractScheduler::schedule(2, 0, remark(0)));
+
+		run_to_block(2);
+		assert_eq!(ContractScheduler::outstanding(1), 1);
+		assert_ok!(ContractScheduler::schedule(1, 9, remark(0)));
+	});
+}
+
+#[test]
+fn reserved_agenda_slots_are_left_for_others() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractScheduler::schedule(1, 3, remark(0)));
+		assert_ok!(ContractScheduler::schedule(1, 3, remark(0)));
+		assert_ok!(ContractScheduler::schedule(2, 3, remark(0)));
+		assert_noop!(ContractScheduler::schedule(3, 3, remark(0)), Error::<Test>::AgendaFull);
+		assert_ok!(ContractScheduler::schedule(3, 4, remark(0)));
+
+		for _ in 0..2 {
+			let call = <Preimage as StorePreimage>::bound(remark(0)).unwrap();
+			assert_ok!(<Scheduler as Anon<_, _, _>>::schedule(
+				DispatchTime::At(5),
+				None,
+				LOWEST_PRIORITY,
+				frame_system::RawOrigin::Root.into(),
+				call,
+			));
+		}
+		assert_eq!(pallet_scheduler::Agenda::<Test>::decode_len(5), Some(5));
+	});
+}
+
+#[test]
+fn dispatch_task_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractScheduler::schedule(1, 0, remark(0)));
+		assert_noop!(
+			ContractScheduler::dispatch_task(RuntimeOrigin::signed(1), 0, Weight::MAX),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ContractScheduler::dispatch_task(RuntimeOrigin::root(), 1, Weight::MAX),
+			Error::<Test>::UnknownTask
+		);
+	});
+}
+
+#[test]
+fn dispatch_fails_if_call_got_heavier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ContractScheduler::schedule(1, 5, transfer(3, 5)));
+		assert_ok!(ContractScheduler::dispatch_task(RuntimeOrigin::root(), 0, Weight::zero()));
+		System::assert_last_event(
+			Event::Dispatched { task: 0, result: Err(Error::<Test>::TaskTooHeavy.into()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
@@ -0,0 +1,24 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type TaskIndex = u64;
+
+/// Call scheduled by a contract
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Task<AccountId, Balance, Call, BlockNumber> {
+	/// Contract that scheduled the call, it's dispatched with its origin
+	pub contract: AccountId,
+	/// Deposit held from the contract until the call is dispatched
+	pub deposit: Balance,
+	/// SCALE-encoded call
+	pub call: Call,
+	/// Block at which the call is dispatched
+	pub when: BlockNumber,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Call> {
+	/// Call allowed by `CallFilter`
+	fn call() -> Call;
+}
@@ -0,0 +1,49 @@
+//! Weights for pallet_contract_scheduler
+//!
+//! NOT BENCHMARKED. Estimated from pallet_scheduler and pallet_preimage
+//! weights of comparable operations, overestimated to be on the safe side.
+//! Replace with output of `substrate/scripts/run_liberland_benchmarks.sh
+//! -p pallet_contract_scheduler` run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_contract_scheduler.
+pub trait WeightInfo {
+	fn schedule() -> Weight;
+	fn dispatch_task() -> Weight;
+}
+
+/// Weights for pallet_contract_scheduler using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn schedule() -> Weight {
+		Weight::from_parts(60_000_000, 110_000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn dispatch_task() -> Weight {
+		Weight::from_parts(30_000_000, 8_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn schedule() -> Weight {
+		Weight::from_parts(60_000_000, 110_000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn dispatch_task() -> Weight {
+		Weight::from_parts(30_000_000, 8_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
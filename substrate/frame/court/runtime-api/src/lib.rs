@@ -0,0 +1,40 @@
+//! Runtime API for dry-running `msig_court` contract proposals and court
+//! case statistics.
+//!
+//! Served by the node as `liberland_dryRunProposal` RPC.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_api::vec::Vec;
+use sp_runtime::{DispatchResult, RuntimeDebug};
+use sp_weights::Weight;
+
+/// Selector of `msig_court`'s `simulate` message
+pub const SIMULATE_SELECTOR: [u8; 4] = *b"SIMU";
+
+/// Outcome of a proposal dry-run
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct ProposalDryRun {
+	/// `Ok` if the proposal would execute successfully
+	pub result: DispatchResult,
+	/// SCALE-encoded `msig_court::Error`, if the contract returned one
+	pub contract_error: Option<Vec<u8>>,
+	/// Weight consumed by the execution
+	pub weight_consumed: Weight,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CourtApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Executes SCALE-encoded `msig_court::Proposal` by `contract` against
+		/// current state and discards the changes.
+		fn dry_run_proposal(contract: AccountId, proposal: Vec<u8>) -> ProposalDryRun;
+
+		/// Returns number of court cases that weren't closed yet.
+		fn open_cases() -> u32;
+	}
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Court;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Saturating};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn roster_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::RosterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn funded<T: Config>(name: &'static str, i: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, i, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+fn judges<T: Config>(n: u32) -> JudgesOf<T> {
+	BoundedVec::truncate_from((0..n).map(|i| account("judge", i, SEED)).collect())
+}
+
+fn ruling<T: Config>() -> Box<<T as Config>::RuntimeCall> {
+	let call: <T as frame_system::Config>::RuntimeCall =
+		frame_system::Call::remark { remark: vec![] }.into();
+	Box::new(call.into())
+}
+
+/// Roster of two judges and case `i` between fresh parties, ruled if `ruled`
+fn opened<T: Config>(i: u32, ruled: bool) -> Result<(), BenchmarkError> {
+	if Court::<T>::judges().is_empty() {
+		assert_ok!(Court::<T>::set_judges(roster_origin::<T>()?, judges::<T>(2)));
+	}
+	let plaintiff = funded::<T>("plaintiff", i);
+	let defendant = funded::<T>("defendant", i);
+	assert_ok!(Court::<T>::open_case(
+		RawOrigin::Signed(plaintiff).into(),
+		defendant,
+		Default::default()
+	));
+	if ruled {
+		let judge = Court::<T>::cases(i).unwrap().judge;
+		assert_ok!(Court::<T>::post_ruling(RawOrigin::Signed(judge).into(), i, ruling::<T>()));
+	}
+	Ok(())
+}
+
+benchmarks! {
+	set_judges {
+		let j in 1 .. T::MaxJudges::get();
+		let origin = roster_origin::<T>()?;
+		let judges = judges::<T>(j);
+	}: _<T::RuntimeOrigin>(origin, judges)
+	verify {
+		assert_eq!(Court::<T>::judges().len() as u32, j);
+	}
+
+	open_case {
+		assert_ok!(Court::<T>::set_judges(roster_origin::<T>()?, judges::<T>(2)));
+		let plaintiff = funded::<T>("plaintiff", 0);
+		let defendant: T::AccountId = account("defendant", 0, SEED);
+	}: _(RawOrigin::Signed(plaintiff), defendant, Default::default())
+	verify {
+		assert!(Cases::<T>::contains_key(0));
+	}
+
+	withdraw_case {
+		opened::<T>(0, false)?;
+		let plaintiff: T::AccountId = account("plaintiff", 0, SEED);
+	}: _(RawOrigin::Signed(plaintiff), 0)
+	verify {
+		assert!(!Cases::<T>::contains_key(0));
+	}
+
+	post_ruling {
+		opened::<T>(0, false)?;
+		let judge = Court::<T>::cases(0).unwrap().judge;
+		let call = ruling::<T>();
+	}: _(RawOrigin::Signed(judge), 0, call)
+	verify {
+		assert!(Court::<T>::cases(0).unwrap().status != CaseStatus::Open);
+	}
+
+	appeal {
+		opened::<T>(0, true)?;
+		let defendant: T::AccountId = account("defendant", 0, SEED);
+	}: _(RawOrigin::Signed(defendant), 0)
+	verify {
+		assert_eq!(Court::<T>::cases(0).unwrap().status, CaseStatus::Open);
+	}
+
+	reassign_case {
+		opened::<T>(0, false)?;
+		let origin = roster_origin::<T>()?;
+		let judge = Court::<T>::cases(0).unwrap().judge;
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert!(Court::<T>::cases(0).unwrap().judge != judge);
+	}
+
+	on_initialize {
+		let r in 0 .. T::MaxRulingsPerBlock::get();
+		for i in 0..r {
+			opened::<T>(i, true)?;
+		}
+		let due = frame_system::Pallet::<T>::block_number().saturating_add(T::AppealWindow::get());
+		frame_system::Pallet::<T>::set_block_number(due);
+	}: {
+		Court::<T>::on_initialize(due);
+	}
+	verify {
+		assert_eq!(Court::<T>::case_count(), r);
+		assert!(Court::<T>::rulings_due(due).is_empty());
+	}
+}
+
+impl_benchmark_test_suite!(Court, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,629 @@
+//! # Liberland Court Pallet
+//!
+//! ## Overview
+//!
+//! Court pallet is the runtime-native counterpart of the `msig_court`
+//! contract. It keeps a registry of cases and enacts rulings on-chain:
+//!
+//! * plaintiff opens a case against a defendant with hash of the claim
+//!   document, holding `CaseBond` LLD,
+//! * a judge from the on-chain roster is assigned round-robin. Judges never
+//!   get cases in which they're a party,
+//! * assigned judge posts the ruling as a call,
+//! * ruling is staged for `AppealWindow` blocks. During that time either
+//!   party can appeal, holding `AppealBond` LLD - ruling is dropped and case
+//!   goes to the next judge on the roster,
+//! * once appeal window closes, ruling is dispatched in `on_initialize` with
+//!   origin Signed by the court's account, derived from `PalletId`. Bonds
+//!   are returned,
+//! * a block enacts at most `MaxRulingsPerBlock` rulings weighing together
+//!   at most `MaxEnactmentWeight`. Rulings that don't fit are enacted in one
+//!   of the following blocks.
+//!
+//! ### Court's account
+//!
+//! Ruling can be any call, so court's account can do in a ruling whatever it
+//! can do as a regular signed account - spend its own funds and use the
+//! powers it was granted. It has no powers by default, runtime decides which
+//! to grant:
+//!
+//! * registered in `pallet_llm::Courts`, it can force transfer LLM, transfer
+//!   LLD from treasury and revoke citizenships of any account,
+//! * pallets that should only accept rulings against the defendant, e.g.
+//!   sanctions, can use [`EnsureCourtAgainst`] - it accepts court's account
+//!   only while it dispatches a ruling of a case against given account.
+//!
+//! Anything granted to court's account can be used by any judge on the
+//! roster, limited only by appeals, so grant it only powers that judges are
+//! trusted with.
+//!
+//! ## Pallet Config
+//!
+//! * `RuntimeCall` - the overarching call type, rulings are of this type
+//! * `Currency` - currency of bonds
+//! * `PalletId` - PalletId used to derive court's account
+//! * `RosterOrigin` - origin that sets judges and reassigns cases
+//! * `CaseBond` - bond held from plaintiff until the case is closed
+//! * `AppealBond` - bond held from appellant until the case is closed
+//! * `AppealWindow` - number of blocks between ruling and its enactment
+//! * `MaxJudges` - maximum number of judges on the roster
+//! * `MaxAppeals` - maximum number of appeals in a single case
+//! * `MaxRulingLen` - maximum length of encoded ruling
+//! * `MaxRulingWeight` - maximum dispatch weight of ruling
+//! * `MaxRulingsPerBlock` - maximum number of rulings enacted in a single
+//!   block
+//! * `MaxEnactmentWeight` - maximum total dispatch weight of rulings enacted
+//!   in a single block
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_judges`: Replace the roster of judges
+//! * `open_case`: Open a case against a defendant
+//! * `withdraw_case`: Close own case before it's ruled
+//! * `post_ruling`: Stage ruling of an assigned case
+//! * `appeal`: Drop staged ruling and move the case to another judge
+//! * `reassign_case`: Move an open case to another judge
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, EnsureOriginWithArg, ReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::{Decode, Encode};
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo},
+		pallet_prelude::*,
+		PalletId,
+	};
+	use frame_system::{pallet_prelude::*, RawOrigin};
+	use sp_runtime::traits::{AccountIdConversion, Dispatchable, Saturating};
+	use sp_std::prelude::*;
+
+	pub type JudgesOf<T> =
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxJudges>;
+	pub type RulingOf<T> = BoundedVec<u8, <T as Config>::MaxRulingLen>;
+	pub type AppealsOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+		<T as Config>::MaxAppeals,
+	>;
+	pub type CaseOf<T> = Case<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::Hash,
+		AppealsOf<T>,
+		RulingOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	/// Number of consecutive blocks tried when the block at which appeal
+	/// window closes already has `MaxRulingsPerBlock` rulings or
+	/// `MaxEnactmentWeight` of rulings scheduled
+	const ENACTMENT_SCHEDULE_ATTEMPTS: u32 = 10;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, rulings are of this type
+		type RuntimeCall: Parameter
+			+ GetDispatchInfo
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Currency of bonds
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// PalletId used to derive court's account, which dispatches rulings
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin that sets judges and reassigns cases
+		type RosterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Bond held from plaintiff until the case is closed
+		#[pallet::constant]
+		type CaseBond: Get<BalanceOf<Self>>;
+
+		/// Bond held from appellant until the case is closed
+		#[pallet::constant]
+		type AppealBond: Get<BalanceOf<Self>>;
+
+		/// Number of blocks between ruling and its enactment
+		#[pallet::constant]
+		type AppealWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of judges on the roster
+		#[pallet::constant]
+		type MaxJudges: Get<u32>;
+
+		/// Maximum number of appeals in a single case
+		#[pallet::constant]
+		type MaxAppeals: Get<u32>;
+
+		/// Maximum length of encoded ruling
+		#[pallet::constant]
+		type MaxRulingLen: Get<u32>;
+
+		/// Maximum dispatch weight of ruling
+		#[pallet::constant]
+		type MaxRulingWeight: Get<Weight>;
+
+		/// Maximum number of rulings enacted in a single block
+		#[pallet::constant]
+		type MaxRulingsPerBlock: Get<u32>;
+
+		/// Maximum total dispatch weight of rulings enacted in a single block
+		#[pallet::constant]
+		type MaxEnactmentWeight: Get<Weight>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Same judge listed more than once
+		DuplicateJudge,
+		/// No judge on the roster can take the case
+		NoEligibleJudge,
+		/// Plaintiff and defendant must differ
+		SelfSuit,
+		/// Case doesn't exist or was already closed
+		UnknownCase,
+		/// Only plaintiff can withdraw the case
+		NotPlaintiff,
+		/// Only plaintiff or defendant can appeal
+		NotParty,
+		/// Caller isn't the judge assigned to the case or was removed from
+		/// the roster
+		NotJudge,
+		/// Case was already ruled
+		AlreadyRuled,
+		/// Case wasn't ruled yet
+		NotRuled,
+		/// Ruling was already enacted
+		AppealWindowClosed,
+		/// Case was appealed too many times
+		TooManyAppeals,
+		/// Encoded ruling is longer than `MaxRulingLen`
+		RulingTooLong,
+		/// Ruling's dispatch weight exceeds `MaxRulingWeight`
+		RulingTooHeavy,
+		/// Too many rulings or too much ruling weight already scheduled for
+		/// enactment
+		TooManyRulings,
+		/// Stored ruling can't be decoded, e.g. after a runtime upgrade
+		UndecodableRuling,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Roster of judges replaced
+		JudgesSet { judges: JudgesOf<T> },
+		/// Case opened and assigned to `judge`
+		CaseOpened {
+			case: CaseIndex,
+			plaintiff: T::AccountId,
+			defendant: T::AccountId,
+			judge: T::AccountId,
+		},
+		/// Case withdrawn by the plaintiff
+		CaseWithdrawn { case: CaseIndex },
+		/// Ruling staged, it's enacted at `enact_at` unless appealed
+		RulingPosted { case: CaseIndex, judge: T::AccountId, enact_at: BlockNumberFor<T> },
+		/// Ruling dropped on appeal, case assigned to `judge`
+		Appealed { case: CaseIndex, appellant: T::AccountId, judge: T::AccountId },
+		/// Case assigned to `judge` by `RosterOrigin`
+		CaseReassigned { case: CaseIndex, judge: T::AccountId },
+		/// Ruling dispatched and case closed
+		RulingEnacted { case: CaseIndex, result: DispatchResult },
+	}
+
+	/// Judges that get assigned new cases
+	#[pallet::storage]
+	#[pallet::getter(fn judges)]
+	pub type Judges<T: Config> = StorageValue<_, JudgesOf<T>, ValueQuery>;
+
+	/// Position on the roster of the judge that gets the next case
+	#[pallet::storage]
+	#[pallet::getter(fn next_judge)]
+	pub type NextJudge<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of cases ever opened, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn case_count)]
+	pub type CaseCount<T: Config> = StorageValue<_, CaseIndex, ValueQuery>;
+
+	/// Cases that weren't closed yet
+	#[pallet::storage]
+	#[pallet::getter(fn cases)]
+	pub type Cases<T: Config> = StorageMap<_, Twox64Concat, CaseIndex, CaseOf<T>, OptionQuery>;
+
+	/// Cases with rulings enacted at given block
+	#[pallet::storage]
+	#[pallet::getter(fn rulings_due)]
+	pub type RulingsDue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<CaseIndex, T::MaxRulingsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Total dispatch weight of rulings enacted at given block
+	#[pallet::storage]
+	#[pallet::getter(fn due_weight)]
+	pub type DueWeight<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Weight, ValueQuery>;
+
+	/// Defendant of the case whose ruling is being dispatched. Set only for
+	/// the duration of the dispatch.
+	#[pallet::storage]
+	#[pallet::getter(fn enacting_against)]
+	pub type EnactingAgainst<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Enacts rulings whose appeal window closes at this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let due = RulingsDue::<T>::take(now);
+			DueWeight::<T>::remove(now);
+			let mut weight = T::WeightInfo::on_initialize(due.len() as u32);
+			for index in due.iter() {
+				if let Some(case) = Cases::<T>::get(index) {
+					weight.saturating_accrue(Self::enact(*index, case));
+				}
+			}
+			weight
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MaxRulingWeight::get().all_lte(T::MaxEnactmentWeight::get()),
+				"Rulings weighing `MaxRulingWeight` could never be enacted"
+			);
+			assert!(
+				T::MaxEnactmentWeight::get()
+					.all_lte(<T as frame_system::Config>::BlockWeights::get().max_block),
+				"`MaxEnactmentWeight` exceeds block weight"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Replace the roster of judges. Cases keep their assigned judges,
+		/// but judges removed from the roster can't post rulings.
+		///
+		/// The dispatch origin of this call must be `RosterOrigin`.
+		///
+		/// - `judges`: New roster
+		///
+		/// Emits `JudgesSet`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_judges(judges.len() as u32))]
+		pub fn set_judges(origin: OriginFor<T>, judges: JudgesOf<T>) -> DispatchResult {
+			T::RosterOrigin::ensure_origin(origin)?;
+			for (i, judge) in judges.iter().enumerate() {
+				ensure!(!judges[..i].contains(judge), Error::<T>::DuplicateJudge);
+			}
+			Judges::<T>::put(&judges);
+			NextJudge::<T>::kill();
+
+			Self::deposit_event(Event::JudgesSet { judges });
+			Ok(())
+		}
+
+		/// Open a case against `defendant`. Holds `CaseBond` from the
+		/// plaintiff and assigns the next judge on the roster.
+		///
+		/// The dispatch origin of this call must be Signed by the plaintiff.
+		///
+		/// - `defendant`: Account the case is against
+		/// - `claim`: Hash of the claim document
+		///
+		/// Emits `CaseOpened`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::open_case())]
+		pub fn open_case(
+			origin: OriginFor<T>,
+			defendant: T::AccountId,
+			claim: T::Hash,
+		) -> DispatchResult {
+			let plaintiff = ensure_signed(origin)?;
+			ensure!(plaintiff != defendant, Error::<T>::SelfSuit);
+
+			let judge = Self::assign_judge(&[&plaintiff, &defendant])?;
+			let bond = T::CaseBond::get();
+			T::Currency::reserve(&plaintiff, bond)?;
+
+			let case = Self::case_count();
+			Cases::<T>::insert(
+				case,
+				CaseOf::<T> {
+					plaintiff: plaintiff.clone(),
+					defendant: defendant.clone(),
+					claim,
+					judge: judge.clone(),
+					bond,
+					appeals: Default::default(),
+					status: CaseStatus::Open,
+				},
+			);
+			CaseCount::<T>::put(case.saturating_add(1));
+
+			Self::deposit_event(Event::CaseOpened { case, plaintiff, defendant, judge });
+			Ok(())
+		}
+
+		/// Close own case before it's ruled. All bonds are returned.
+		///
+		/// The dispatch origin of this call must be Signed by the plaintiff.
+		///
+		/// - `case`: Index of the case
+		///
+		/// Emits `CaseWithdrawn`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw_case())]
+		pub fn withdraw_case(origin: OriginFor<T>, case: CaseIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let details = Self::cases(case).ok_or(Error::<T>::UnknownCase)?;
+			ensure!(details.plaintiff == who, Error::<T>::NotPlaintiff);
+			ensure!(details.status == CaseStatus::Open, Error::<T>::AlreadyRuled);
+
+			Cases::<T>::remove(case);
+			Self::release_bonds(&details);
+
+			Self::deposit_event(Event::CaseWithdrawn { case });
+			Ok(())
+		}
+
+		/// Stage ruling of an assigned case. Ruling is dispatched by the
+		/// court's account once appeal window closes.
+		///
+		/// The dispatch origin of this call must be Signed by the judge
+		/// assigned to the case, who must still be on the roster.
+		///
+		/// - `case`: Index of the case
+		/// - `ruling`: Call enforcing the ruling
+		///
+		/// Emits `RulingPosted`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::post_ruling())]
+		pub fn post_ruling(
+			origin: OriginFor<T>,
+			case: CaseIndex,
+			ruling: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut details = Self::cases(case).ok_or(Error::<T>::UnknownCase)?;
+			ensure!(details.judge == who && Self::judges().contains(&who), Error::<T>::NotJudge);
+			ensure!(details.status == CaseStatus::Open, Error::<T>::AlreadyRuled);
+			let weight = ruling.get_dispatch_info().weight;
+			ensure!(weight.all_lte(T::MaxRulingWeight::get()), Error::<T>::RulingTooHeavy);
+			let ruling: RulingOf<T> =
+				ruling.encode().try_into().map_err(|_| Error::<T>::RulingTooLong)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let enact_at =
+				Self::schedule_enactment(case, weight, now.saturating_add(T::AppealWindow::get()))?;
+			details.status = CaseStatus::Ruled { ruling, enact_at, weight };
+			Cases::<T>::insert(case, details);
+
+			Self::deposit_event(Event::RulingPosted { case, judge: who, enact_at });
+			Ok(())
+		}
+
+		/// Drop staged ruling and move the case to the next judge on the
+		/// roster. Holds `AppealBond` from the appellant.
+		///
+		/// The dispatch origin of this call must be Signed by the plaintiff
+		/// or the defendant.
+		///
+		/// - `case`: Index of the case
+		///
+		/// Emits `Appealed`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::appeal())]
+		pub fn appeal(origin: OriginFor<T>, case: CaseIndex) -> DispatchResult {
+			let appellant = ensure_signed(origin)?;
+			let mut details = Self::cases(case).ok_or(Error::<T>::UnknownCase)?;
+			ensure!(
+				appellant == details.plaintiff || appellant == details.defendant,
+				Error::<T>::NotParty
+			);
+			let (enact_at, weight) = match details.status {
+				CaseStatus::Ruled { enact_at, weight, .. } => (enact_at, weight),
+				CaseStatus::Open => return Err(Error::<T>::NotRuled.into()),
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < enact_at,
+				Error::<T>::AppealWindowClosed
+			);
+
+			let bond = T::AppealBond::get();
+			details
+				.appeals
+				.try_push((appellant.clone(), bond))
+				.map_err(|_| Error::<T>::TooManyAppeals)?;
+			let judge =
+				Self::assign_judge(&[&details.plaintiff, &details.defendant, &details.judge])?;
+			T::Currency::reserve(&appellant, bond)?;
+
+			RulingsDue::<T>::mutate(enact_at, |due| due.retain(|i| *i != case));
+			DueWeight::<T>::mutate(enact_at, |w| *w = w.saturating_sub(weight));
+			details.judge = judge.clone();
+			details.status = CaseStatus::Open;
+			Cases::<T>::insert(case, details);
+
+			Self::deposit_event(Event::Appealed { case, appellant, judge });
+			Ok(())
+		}
+
+		/// Move an open case to the next judge on the roster, e.g. when
+		/// assigned judge was removed from the roster.
+		///
+		/// The dispatch origin of this call must be `RosterOrigin`.
+		///
+		/// - `case`: Index of the case
+		///
+		/// Emits `CaseReassigned`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::reassign_case())]
+		pub fn reassign_case(origin: OriginFor<T>, case: CaseIndex) -> DispatchResult {
+			T::RosterOrigin::ensure_origin(origin)?;
+			let mut details = Self::cases(case).ok_or(Error::<T>::UnknownCase)?;
+			ensure!(details.status == CaseStatus::Open, Error::<T>::AlreadyRuled);
+
+			let judge =
+				Self::assign_judge(&[&details.plaintiff, &details.defendant, &details.judge])?;
+			details.judge = judge.clone();
+			Cases::<T>::insert(case, details);
+
+			Self::deposit_event(Event::CaseReassigned { case, judge });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account dispatching rulings
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Takes the next judge on the roster that isn't `excluded`
+		fn assign_judge(excluded: &[&T::AccountId]) -> Result<T::AccountId, DispatchError> {
+			let judges = Self::judges();
+			let len = judges.len() as u32;
+			let start = Self::next_judge();
+			for offset in 0..len {
+				let position = start.saturating_add(offset) % len;
+				let judge = &judges[position as usize];
+				if !excluded.contains(&judge) {
+					NextJudge::<T>::put((position + 1) % len);
+					return Ok(judge.clone());
+				}
+			}
+			Err(Error::<T>::NoEligibleJudge.into())
+		}
+
+		/// Schedules enactment at `at` or one of the following blocks, keeping
+		/// total weight of the block's rulings within `MaxEnactmentWeight`
+		fn schedule_enactment(
+			case: CaseIndex,
+			weight: Weight,
+			mut at: BlockNumberFor<T>,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			for _ in 0..ENACTMENT_SCHEDULE_ATTEMPTS {
+				let total = Self::due_weight(at).saturating_add(weight);
+				if total.all_lte(T::MaxEnactmentWeight::get()) &&
+					RulingsDue::<T>::try_append(at, case).is_ok()
+				{
+					DueWeight::<T>::insert(at, total);
+					return Ok(at);
+				}
+				at = at.saturating_add(1u32.into());
+			}
+			Err(Error::<T>::TooManyRulings.into())
+		}
+
+		fn release_bonds(case: &CaseOf<T>) {
+			T::Currency::unreserve(&case.plaintiff, case.bond);
+			for (appellant, bond) in case.appeals.iter() {
+				T::Currency::unreserve(appellant, *bond);
+			}
+		}
+
+		/// Dispatches ruling and closes the case. Returns weight of the
+		/// dispatched call.
+		fn enact(index: CaseIndex, case: CaseOf<T>) -> Weight {
+			let CaseStatus::Ruled { ruling, .. } = &case.status else { return Weight::zero() };
+			Cases::<T>::remove(index);
+			Self::release_bonds(&case);
+
+			let (result, weight) = match <T as Config>::RuntimeCall::decode(&mut &ruling[..]) {
+				Ok(call) if call.get_dispatch_info().weight.all_lte(T::MaxRulingWeight::get()) => {
+					let info = call.get_dispatch_info();
+					let origin = RawOrigin::Signed(Self::account_id()).into();
+					EnactingAgainst::<T>::put(&case.defendant);
+					let res = call.dispatch(origin);
+					EnactingAgainst::<T>::kill();
+					let weight = extract_actual_weight(&res, &info);
+					(res.map(|_| ()).map_err(|e| e.error), weight)
+				},
+				// weight was checked on posting, it can only grow after a
+				// runtime upgrade
+				Ok(_) => (Err(Error::<T>::RulingTooHeavy.into()), Weight::zero()),
+				Err(_) => (Err(Error::<T>::UndecodableRuling.into()), Weight::zero()),
+			};
+
+			Self::deposit_event(Event::RulingEnacted { case: index, result });
+			weight
+		}
+	}
+}
+
+/// Ensures origin is court's account dispatching a ruling of a case against
+/// given account. Succeeds with court's account.
+pub struct EnsureCourtAgainst<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EnsureOriginWithArg<T::RuntimeOrigin, T::AccountId> for EnsureCourtAgainst<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(
+		o: T::RuntimeOrigin,
+		who: &T::AccountId,
+	) -> Result<Self::Success, T::RuntimeOrigin> {
+		let against = Pallet::<T>::enacting_against();
+		match o.clone().into() {
+			Ok(frame_system::RawOrigin::Signed(account))
+				if account == Pallet::<T>::account_id() && against.as_ref() == Some(who) =>
+			{
+				Ok(account)
+			},
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &T::AccountId) -> Result<T::RuntimeOrigin, ()> {
+		Err(())
+	}
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+pub use crate as pallet_court;
+
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::EnsureSignedBy;
+use pallet_balances::AccountData;
+use sp_core::{ConstU16, H256};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+ord_parameter_types! {
+	pub const Roster: u64 = 1;
+}
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Court: pallet_court,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub const CourtPalletId: PalletId = PalletId(*b"lib/cort");
+	pub MaxRulingWeight: Weight = Weight::from_parts(1_000_000_000, 100_000);
+	pub static MaxEnactmentWeight: Weight = Weight::from_parts(2_000_000_000, 200_000);
+}
+
+impl pallet_court::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type PalletId = CourtPalletId;
+	type RosterOrigin = EnsureSignedBy<Roster, u64>;
+	type CaseBond = ConstU64<10>;
+	type AppealBond = ConstU64<20>;
+	type AppealWindow = ConstU64<5>;
+	type MaxJudges = ConstU32<5>;
+	type MaxAppeals = ConstU32<2>;
+	type MaxRulingLen = ConstU32<128>;
+	type MaxRulingWeight = MaxRulingWeight;
+	type MaxRulingsPerBlock = ConstU32<2>;
+	type MaxEnactmentWeight = MaxEnactmentWeight;
+	type WeightInfo = ();
+}
+
+pub fn court() -> u64 {
+	CourtPalletId::get().into_account_truncating()
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let balances =
+		vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (court(), 1000)];
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+	});
+	ext
+}
@@ -0,0 +1,342 @@
+#![cfg(test)]
+
+use crate::{mock::*, CaseStatus, EnactingAgainst, EnsureCourtAgainst, Error, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{Currency, EnsureOriginWithArg, Hooks, ReservableCurrency},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		Court::on_initialize(next);
+	}
+}
+
+fn set_judges(judges: Vec<u64>) {
+	assert_ok!(Court::set_judges(RuntimeOrigin::signed(1), judges.try_into().unwrap()));
+}
+
+fn open(plaintiff: u64, defendant: u64) {
+	assert_ok!(Court::open_case(RuntimeOrigin::signed(plaintiff), defendant, H256::zero()));
+}
+
+fn transfer(dest: u64, value: u64) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest, value }))
+}
+
+#[test]
+fn set_judges_requires_roster_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Court::set_judges(RuntimeOrigin::signed(2), vec![4].try_into().unwrap()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_judges_rejects_duplicates() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Court::set_judges(RuntimeOrigin::signed(1), vec![4, 5, 4].try_into().unwrap()),
+			Error::<Test>::DuplicateJudge
+		);
+		set_judges(vec![4, 5, 6]);
+		assert_eq!(Court::judges().into_inner(), vec![4, 5, 6]);
+	});
+}
+
+#[test]
+fn open_case_works() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		let case = Court::cases(0).unwrap();
+		assert_eq!(case.plaintiff, 2);
+		assert_eq!(case.defendant, 3);
+		assert_eq!(case.judge, 4);
+		assert_eq!(case.status, CaseStatus::Open);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(Court::case_count(), 1);
+		System::assert_last_event(
+			Event::CaseOpened { case: 0, plaintiff: 2, defendant: 3, judge: 4 }.into(),
+		);
+	});
+}
+
+#[test]
+fn open_case_validates_parties() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Court::open_case(RuntimeOrigin::signed(2), 3, H256::zero()),
+			Error::<Test>::NoEligibleJudge
+		);
+		set_judges(vec![4]);
+		assert_noop!(
+			Court::open_case(RuntimeOrigin::signed(2), 2, H256::zero()),
+			Error::<Test>::SelfSuit
+		);
+		assert_noop!(
+			Court::open_case(RuntimeOrigin::signed(2), 4, H256::zero()),
+			Error::<Test>::NoEligibleJudge
+		);
+	});
+}
+
+#[test]
+fn judges_are_assigned_round_robin() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5, 6]);
+		open(2, 3);
+		open(2, 3);
+		// 6 is a party, so 4 gets the case
+		open(2, 6);
+		open(2, 3);
+		let judges: Vec<u64> = (0..4).map(|i| Court::cases(i).unwrap().judge).collect();
+		assert_eq!(judges, vec![4, 5, 4, 5]);
+	});
+}
+
+#[test]
+fn withdraw_case_works() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_noop!(
+			Court::withdraw_case(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotPlaintiff
+		);
+		assert_ok!(Court::withdraw_case(RuntimeOrigin::signed(2), 0));
+		assert!(Court::cases(0).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(Event::CaseWithdrawn { case: 0 }.into());
+		assert_noop!(Court::withdraw_case(RuntimeOrigin::signed(2), 0), Error::<Test>::UnknownCase);
+	});
+}
+
+#[test]
+fn post_ruling_requires_assigned_judge() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_noop!(
+			Court::post_ruling(RuntimeOrigin::signed(5), 0, transfer(2, 50)),
+			Error::<Test>::NotJudge
+		);
+		set_judges(vec![5]);
+		assert_noop!(
+			Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)),
+			Error::<Test>::NotJudge
+		);
+	});
+}
+
+#[test]
+fn post_ruling_stages_enactment() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		System::assert_last_event(Event::RulingPosted { case: 0, judge: 4, enact_at: 6 }.into());
+		assert_eq!(Court::rulings_due(6).into_inner(), vec![0]);
+		assert!(matches!(Court::cases(0).unwrap().status, CaseStatus::Ruled { enact_at: 6, .. }));
+		assert_noop!(
+			Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)),
+			Error::<Test>::AlreadyRuled
+		);
+		assert_noop!(
+			Court::withdraw_case(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyRuled
+		);
+	});
+}
+
+#[test]
+fn post_ruling_spills_to_next_block() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4]);
+		for _ in 0..3 {
+			open(2, 3);
+		}
+		for case in 0..3 {
+			assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), case, transfer(2, 1)));
+		}
+		assert_eq!(Court::rulings_due(6).into_inner(), vec![0, 1]);
+		assert_eq!(Court::rulings_due(7).into_inner(), vec![2]);
+	});
+}
+
+#[test]
+fn post_ruling_spills_heavy_rulings_to_next_block() {
+	new_test_ext().execute_with(|| {
+		let max = MaxEnactmentWeight::get();
+		let weight = transfer(2, 1).get_dispatch_info().weight;
+		MaxEnactmentWeight::set(Weight::from_parts(
+			weight.ref_time() * 3 / 2,
+			weight.proof_size() * 3 / 2,
+		));
+		set_judges(vec![4, 5]);
+		for case in 0..3 {
+			open(2, 3);
+			let judge = Court::cases(case).unwrap().judge;
+			assert_ok!(Court::post_ruling(RuntimeOrigin::signed(judge), case, transfer(2, 1)));
+		}
+		assert_eq!(Court::rulings_due(6).into_inner(), vec![0]);
+		assert_eq!(Court::rulings_due(7).into_inner(), vec![1]);
+		assert_eq!(Court::rulings_due(8).into_inner(), vec![2]);
+		assert_eq!(Court::due_weight(6), weight);
+
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Court::due_weight(6), Weight::zero());
+
+		run_to_block(6);
+		assert_eq!(Court::due_weight(6), Weight::zero());
+		run_to_block(7);
+		assert!(Court::cases(1).is_none());
+		assert_eq!(Court::due_weight(7), Weight::zero());
+		MaxEnactmentWeight::set(max);
+	});
+}
+
+#[test]
+fn ruling_is_enacted_after_appeal_window() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(2), 90);
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 150);
+		assert_eq!(Balances::free_balance(court()), 950);
+		assert!(Court::cases(0).is_none());
+		System::assert_last_event(Event::RulingEnacted { case: 0, result: Ok(()) }.into());
+	});
+}
+
+#[test]
+fn court_origin_is_limited_to_defendant() {
+	new_test_ext().execute_with(|| {
+		type Ensure = EnsureCourtAgainst<Test>;
+		assert!(Ensure::try_origin(RuntimeOrigin::signed(court()), &3).is_err());
+
+		EnactingAgainst::<Test>::put(3);
+		assert_eq!(Ensure::try_origin(RuntimeOrigin::signed(court()), &3).ok(), Some(court()));
+		assert!(Ensure::try_origin(RuntimeOrigin::signed(court()), &2).is_err());
+		assert!(Ensure::try_origin(RuntimeOrigin::signed(2), &3).is_err());
+		assert!(Ensure::try_origin(RuntimeOrigin::root(), &3).is_err());
+	});
+}
+
+#[test]
+fn defendant_is_cleared_after_enactment() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		run_to_block(6);
+		System::assert_last_event(Event::RulingEnacted { case: 0, result: Ok(()) }.into());
+		assert_eq!(Court::enacting_against(), None);
+	});
+}
+
+#[test]
+fn failed_ruling_still_closes_case() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 5000)));
+		run_to_block(6);
+		assert!(Court::cases(0).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Court(Event::RulingEnacted { case: 0, result: Err(_) })
+		));
+	});
+}
+
+#[test]
+fn appeal_reassigns_case() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(3), 0), Error::<Test>::NotRuled);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(6), 0), Error::<Test>::NotParty);
+
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::Appealed { case: 0, appellant: 3, judge: 5 }.into());
+		assert_eq!(Balances::reserved_balance(3), 20);
+		assert!(Court::rulings_due(6).is_empty());
+		let case = Court::cases(0).unwrap();
+		assert_eq!(case.judge, 5);
+		assert_eq!(case.status, CaseStatus::Open);
+
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(5), 0, transfer(3, 30)));
+		run_to_block(11);
+		assert_eq!(Balances::free_balance(3), 130);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn appeal_limits() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		run_to_block(6);
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(3), 0), Error::<Test>::UnknownCase);
+
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(5), 1, transfer(2, 50)));
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(3), 1));
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 1, transfer(2, 50)));
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(3), 1));
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(5), 1, transfer(2, 50)));
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(2), 1), Error::<Test>::TooManyAppeals);
+	});
+}
+
+#[test]
+fn appeal_fails_without_other_judge() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4]);
+		open(2, 3);
+		assert_ok!(Court::post_ruling(RuntimeOrigin::signed(4), 0, transfer(2, 50)));
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(3), 0), Error::<Test>::NoEligibleJudge);
+	});
+}
+
+#[test]
+fn reassign_case_works() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4, 5]);
+		open(2, 3);
+		assert_noop!(Court::reassign_case(RuntimeOrigin::signed(2), 0), DispatchError::BadOrigin);
+		assert_ok!(Court::reassign_case(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Court::cases(0).unwrap().judge, 5);
+		System::assert_last_event(Event::CaseReassigned { case: 0, judge: 5 }.into());
+	});
+}
+
+#[test]
+fn open_case_requires_bond() {
+	new_test_ext().execute_with(|| {
+		set_judges(vec![4]);
+		assert_ok!(Balances::reserve(&2, 95));
+		assert!(Court::open_case(RuntimeOrigin::signed(2), 3, H256::zero()).is_err());
+		assert_eq!(Balances::free_balance(2), 5);
+		assert_eq!(Court::case_count(), 0);
+	});
+}
@@ -0,0 +1,39 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type CaseIndex = u32;
+
+/// Stage of a case
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum CaseStatus<Ruling, BlockNumber> {
+	/// Waiting for ruling of the assigned judge
+	Open,
+	/// Ruling posted. It's dispatched at `enact_at` unless appealed before.
+	Ruled {
+		/// SCALE-encoded call dispatched by the court's account
+		ruling: Ruling,
+		/// Block at which appeal window closes and ruling is dispatched
+		enact_at: BlockNumber,
+		/// Dispatch weight of the ruling, counted towards `enact_at` block
+		weight: Weight,
+	},
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Case<AccountId, Balance, Hash, Appeals, Ruling, BlockNumber> {
+	/// Account that opened the case
+	pub plaintiff: AccountId,
+	/// Account the case is against
+	pub defendant: AccountId,
+	/// Hash of the claim document, kept off-chain
+	pub claim: Hash,
+	/// Judge currently assigned to the case
+	pub judge: AccountId,
+	/// Plaintiff's bond, held until the case is closed
+	pub bond: Balance,
+	/// Appellants with their bonds, held until the case is closed
+	pub appeals: Appeals,
+	pub status: CaseStatus<Ruling, BlockNumber>,
+}
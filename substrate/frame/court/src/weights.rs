@@ -0,0 +1,116 @@
+//! Weights for pallet_court
+//!
+//! NOT BENCHMARKED. Estimated from pallet_land_registry and pallet_office
+//! weights of comparable operations. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_court` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_court.
+pub trait WeightInfo {
+	fn set_judges(j: u32, ) -> Weight;
+	fn open_case() -> Weight;
+	fn withdraw_case() -> Weight;
+	fn post_ruling() -> Weight;
+	fn appeal() -> Weight;
+	fn reassign_case() -> Weight;
+	fn on_initialize(r: u32, ) -> Weight;
+}
+
+/// Weights for pallet_court using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn set_judges(j: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(j.into()))
+	}
+	fn open_case() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn withdraw_case() -> Weight {
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn post_ruling() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn appeal() -> Weight {
+		Weight::from_parts(34_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn reassign_case() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn on_initialize(r: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 4096).saturating_mul(r.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_judges(j: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(j.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(j.into()))
+	}
+	fn open_case() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn withdraw_case() -> Weight {
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn post_ruling() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn appeal() -> Weight {
+		Weight::from_parts(34_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn reassign_case() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn on_initialize(r: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 4096).saturating_mul(r.into()))
+	}
+}
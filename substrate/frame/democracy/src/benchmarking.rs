@@ -283,6 +283,16 @@ benchmarks! {
 		ensure!(<NextExternal<T>>::exists(), "External proposal didn't work");
 	}
 
+	external_propose_dual_majority {
+		let origin = T::ExternalDualMajorityOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let proposal = make_proposal::<T>(0);
+	}: _<T::RuntimeOrigin>(origin, proposal)
+	verify {
+		// External proposal created
+		ensure!(<NextExternal<T>>::exists(), "External proposal didn't work");
+	}
+
 	external_propose_default {
 		let origin = T::ExternalDefaultOrigin::try_successful_origin()
 			.map_err(|_| BenchmarkError::Weightless)?;
@@ -134,6 +134,14 @@
 //! - `external_propose_default` - Schedules a proposal to become a negative-turnout-bias referendum
 //!   once it is legal for an externally proposed referendum.
 //!
+//! #### External Dual Majority Origin
+//!
+//! This call can only be made by the `ExternalDualMajorityOrigin`.
+//!
+//! - `external_propose_dual_majority` - Schedules a proposal to become a referendum that needs a
+//!   majority of both voters headcount and LLM-weighted votes, once it is legal for an externally
+//!   proposed referendum.
+//!
 //! #### Fast Track Origin
 //!
 //! This call can only be made by the `FastTrackOrigin`.
@@ -329,6 +337,10 @@ pub mod pallet {
 		/// of a negative-turnout-bias (default-carries) referendum.
 		type ExternalDefaultOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin from which the next tabled referendum may be forced to need a majority of both
+		/// voters (one citizen, one vote) and LLM-weighted votes.
+		type ExternalDualMajorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Origin from which the new proposal can be made.
 		///
 		/// The success variant is the account id of the depositor.
@@ -416,6 +428,15 @@ pub mod pallet {
 		ReferendumInfo<BlockNumberFor<T>, BoundedCallOf<T>, BalanceOf<T>>,
 	>;
 
+	/// Final tally of a finished referendum, with both LLM-weighted votes and voters headcount.
+	/// Tally of an ongoing referendum is in its `ReferendumInfoOf` status.
+	///
+	/// TWOX-NOTE: SAFE as indexes are not under an attacker’s control.
+	#[pallet::storage]
+	#[pallet::getter(fn final_tally)]
+	pub type FinalTallyOf<T: Config> =
+		StorageMap<_, Twox64Concat, ReferendumIndex, Tally<BalanceOf<T>>>;
+
 	/// All votes for a particular voter. We store the balance for the number of votes that we
 	/// have recorded. The second item is the total amount of delegations, that will be added.
 	///
@@ -495,6 +516,16 @@ pub mod pallet {
 		ExternalTabled,
 		/// A referendum has begun.
 		Started { ref_index: ReferendumIndex, threshold: VoteThreshold },
+		/// Votes of a referendum have been counted, right before it's `Passed` or `NotPassed`.
+		Tallied {
+			ref_index: ReferendumIndex,
+			/// LLM-weighted votes, post-conviction.
+			ayes: BalanceOf<T>,
+			nays: BalanceOf<T>,
+			/// Voters headcount, x10000 to support split votes.
+			aye_voters: u64,
+			nay_voters: u64,
+		},
 		/// A proposal has been approved by referendum.
 		Passed { ref_index: ReferendumIndex },
 		/// A proposal has been rejected by referendum.
@@ -788,6 +819,29 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Schedule a dual-majority referendum to be tabled next once it is legal to schedule an
+		/// external referendum. It passes only if there are more aye voters than nay voters and
+		/// more aye LLM-weighted votes than nay ones.
+		///
+		/// The dispatch of this call must be `ExternalDualMajorityOrigin`.
+		///
+		/// - `proposal_hash`: The preimage hash of the proposal.
+		///
+		/// Unlike `external_propose`, blacklisting has no effect on this and it may replace a
+		/// pre-scheduled `external_propose` call.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(101)]
+		#[pallet::weight(T::WeightInfo::external_propose_dual_majority())]
+		pub fn external_propose_dual_majority(
+			origin: OriginFor<T>,
+			proposal: BoundedCallOf<T>,
+		) -> DispatchResult {
+			T::ExternalDualMajorityOrigin::ensure_origin(origin)?;
+			<NextExternal<T>>::put((proposal, VoteThreshold::DualMajority));
+			Ok(())
+		}
+
 		/// Schedule the currently externally-proposed majority-carries referendum to be tabled
 		/// immediately. If there is no externally-proposed referendum currently, or if there is one
 		/// but it is not a majority-carries referendum then it fails.
@@ -1150,6 +1204,8 @@ pub mod pallet {
 		///       threshold.
 		///     - `ExternalMajorityOrigin` for an external proposal with the `SimpleMajority`
 		///       threshold.
+		///     - `ExternalDualMajorityOrigin` for an external proposal with the `DualMajority`
+		///       threshold.
 		///     - `Signed` by a creator for a public proposal.
 		///     - `Signed` to clear a metadata for a finished referendum.
 		///     - `Root` to set a metadata for an ongoing referendum.
@@ -1669,6 +1725,10 @@ impl<T: Config> Pallet<T> {
 			.threshold
 			.approved(status.tally.clone(), politi_pooled);
 
+		let Tally { ayes, nays, aye_voters, nay_voters, .. } = status.tally.clone();
+		Self::deposit_event(Event::<T>::Tallied { ref_index: index, ayes, nays, aye_voters, nay_voters });
+		FinalTallyOf::<T>::insert(index, status.tally.clone());
+
 		if approved {
 			Self::deposit_event(Event::<T>::Passed { ref_index: index });
 
@@ -1803,6 +1863,9 @@ impl<T: Config> Pallet<T> {
 			VoteThreshold::SimpleMajority => {
 				let _ = T::ExternalMajorityOrigin::ensure_origin(origin)?;
 			},
+			VoteThreshold::DualMajority => {
+				let _ = T::ExternalDualMajorityOrigin::ensure_origin(origin)?;
+			},
 		};
 		Ok(())
 	}
@@ -149,6 +149,36 @@ fn external_majority_referendum_works() {
 	});
 }
 
+#[test]
+fn external_dual_majority_referendum_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert_noop!(
+			Democracy::external_propose_dual_majority(
+				RuntimeOrigin::signed(1),
+				set_balance_proposal(2)
+			),
+			BadOrigin,
+		);
+		assert_ok!(Democracy::external_propose_dual_majority(
+			RuntimeOrigin::signed(3),
+			set_balance_proposal(2)
+		));
+		fast_forward_to(2);
+		assert_eq!(
+			Democracy::referendum_status(0),
+			Ok(ReferendumStatus {
+				end: 4,
+				proposal: set_balance_proposal(2),
+				dispatch_origin: DispatchOrigin::Root,
+				threshold: VoteThreshold::DualMajority,
+				delay: 2,
+				tally: Tally { ayes: 0, nays: 0, aye_voters: 0, nay_voters: 0, turnout: 0 },
+			})
+		);
+	});
+}
+
 #[test]
 fn external_default_referendum_works() {
 	new_test_ext().execute_with(|| {
@@ -171,3 +171,61 @@ fn lowest_unbaked_should_be_sensible() {
 		assert_eq!(Balances::free_balance(42), 1);
 	});
 }
+
+#[test]
+fn dual_majority_needs_both_voters_and_llm_majority() {
+	new_test_ext().execute_with(|| {
+		let r = Democracy::inject_referendum(
+			2,
+			set_balance_proposal(2),
+			DispatchOrigin::Root,
+			VoteThreshold::DualMajority,
+			0,
+		);
+		// more LLM, but fewer voters
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(5), r, aye(5)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(1), r, nay(1)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, nay(2)));
+
+		next_block();
+		next_block();
+
+		let final_tally =
+			Tally { ayes: 500, nays: 300, turnout: 800, aye_voters: 10000, nay_voters: 20000 };
+		assert_eq!(Democracy::final_tally(r), Some(final_tally));
+		System::assert_has_event(RuntimeEvent::Democracy(crate::Event::Tallied {
+			ref_index: r,
+			ayes: 500,
+			nays: 300,
+			aye_voters: 10000,
+			nay_voters: 20000,
+		}));
+		System::assert_last_event(RuntimeEvent::Democracy(crate::Event::NotPassed { ref_index: r }));
+		assert_eq!(Balances::free_balance(42), 0);
+	});
+}
+
+#[test]
+fn dual_majority_passes_with_both_majorities() {
+	new_test_ext().execute_with(|| {
+		let r = Democracy::inject_referendum(
+			2,
+			set_balance_proposal(2),
+			DispatchOrigin::Root,
+			VoteThreshold::DualMajority,
+			0,
+		);
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(5), r, aye(5)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(1), r, aye(1)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, nay(2)));
+
+		next_block();
+		next_block();
+
+		assert_eq!(
+			Democracy::final_tally(r),
+			Some(Tally { ayes: 600, nays: 200, turnout: 800, aye_voters: 20000, nay_voters: 10000 })
+		);
+		assert_eq!(Balances::free_balance(42), 2);
+	});
+}
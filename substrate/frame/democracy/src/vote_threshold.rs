@@ -41,6 +41,9 @@ pub enum VoteThreshold {
 	SuperMajorityAgainst,
 	/// A simple majority of approvals is needed to pass this vote.
 	SimpleMajority,
+	/// A simple majority of approvals is needed both by headcount of voters and by
+	/// LLM-weighted votes to pass this vote.
+	DualMajority,
 }
 
 pub trait Approved<Balance> {
@@ -107,6 +110,8 @@ impl<
 			VoteThreshold::SuperMajorityAgainst =>
 				compare_rationals(tally.nays, sqrt_electorate, tally.ayes, sqrt_voters),
 			VoteThreshold::SimpleMajority => tally.ayes > tally.nays,
+			VoteThreshold::DualMajority =>
+				tally.ayes > tally.nays && tally.aye_voters > tally.nay_voters,
 		}
 	}
 }
@@ -122,4 +127,14 @@ mod tests {
 		assert!(VoteThreshold::SuperMajorityApprove
 			.approved(Tally { ayes: 100, aye_voters: 10000, nay_voters: 10000, nays: 50, turnout: 150 }, 210));
 	}
+
+	#[test]
+	fn dual_majority_needs_both_tallies() {
+		assert!(VoteThreshold::DualMajority
+			.approved(Tally { ayes: 60, aye_voters: 20000, nay_voters: 10000, nays: 50, turnout: 110 }, 210));
+		assert!(!VoteThreshold::DualMajority
+			.approved(Tally { ayes: 100, aye_voters: 10000, nay_voters: 20000, nays: 50, turnout: 150 }, 210));
+		assert!(!VoteThreshold::DualMajority
+			.approved(Tally { ayes: 50, aye_voters: 20000, nay_voters: 10000, nays: 100, turnout: 150 }, 210));
+	}
 }
@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Functions marked NOT BENCHMARKED were added after this run and are
+//! estimated. Replace them with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_democracy` run on
+//! reference hardware.
 
 // Executed Command:
 // ./target/production/substrate
@@ -60,6 +65,7 @@ pub trait WeightInfo {
 	fn blacklist() -> Weight;
 	fn external_propose() -> Weight;
 	fn external_propose_majority() -> Weight;
+	fn external_propose_dual_majority() -> Weight;
 	fn external_propose_default() -> Weight;
 	fn fast_track() -> Weight;
 	fn veto_external() -> Weight;
@@ -210,6 +216,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Democracy NextExternal (r:0 w:1)
 	/// Proof: Democracy NextExternal (max_values: Some(1), max_size: Some(132), added: 627, mode: MaxEncodedLen)
+	fn external_propose_dual_majority() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(3_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Democracy NextExternal (r:0 w:1)
+	/// Proof: Democracy NextExternal (max_values: Some(1), max_size: Some(132), added: 627, mode: MaxEncodedLen)
 	fn external_propose_default() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -661,6 +674,13 @@ impl WeightInfo for () {
 	}
 	/// Storage: Democracy NextExternal (r:0 w:1)
 	/// Proof: Democracy NextExternal (max_values: Some(1), max_size: Some(132), added: 627, mode: MaxEncodedLen)
+	fn external_propose_dual_majority() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(3_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Democracy NextExternal (r:0 w:1)
+	/// Proof: Democracy NextExternal (max_values: Some(1), max_size: Some(132), added: 627, mode: MaxEncodedLen)
 	fn external_propose_default() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
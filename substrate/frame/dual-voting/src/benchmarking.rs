@@ -0,0 +1,115 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as DualVoting;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get},
+};
+use frame_system::RawOrigin;
+use liberland_traits::LLInitializer;
+use pallet_democracy::Conviction;
+use sp_runtime::traits::{Bounded, Saturating};
+
+const SEED: u32 = 0;
+
+fn voter<T: Config>(index: u32) -> T::AccountId {
+	let acc: T::AccountId = account("voter", index, SEED);
+	T::Currency::make_free_balance_be(&acc, BalanceOf::<T>::max_value() / 4u32.into());
+	T::LLInitializer::make_test_citizen(&acc);
+	acc
+}
+
+fn governance<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn poll<T: Config>() -> Result<PollIndex, BenchmarkError> {
+	let index = DualVoting::<T>::poll_count();
+	assert_ok!(DualVoting::<T>::create_poll(governance::<T>()?, Default::default()));
+	Ok(index)
+}
+
+fn voted<T: Config>(index: u32) -> Result<(PollIndex, T::AccountId), BenchmarkError> {
+	let poll = poll::<T>()?;
+	let voter = voter::<T>(index);
+	assert_ok!(DualVoting::<T>::vote(
+		RawOrigin::Signed(voter.clone()).into(),
+		poll,
+		true,
+		T::MaxLLDLock::get(),
+		Conviction::Locked6x,
+	));
+	Ok((poll, voter))
+}
+
+fn sunset<T: Config>() -> Result<(), BenchmarkError> {
+	assert_ok!(DualVoting::<T>::sunset(governance::<T>()?));
+	Ok(())
+}
+
+benchmarks! {
+	create_poll {
+		let origin = governance::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, Default::default())
+	verify {
+		assert_eq!(DualVoting::<T>::poll_count(), 1);
+	}
+
+	vote {
+		// replacing existing vote is the worst case
+		let (poll, voter) = voted::<T>(0)?;
+	}: _(RawOrigin::Signed(voter.clone()), poll, false, T::MaxLLDLock::get(), Conviction::Locked6x)
+	verify {
+		assert!(!DualVoting::<T>::votes(poll, voter).unwrap().aye);
+	}
+
+	close_poll {
+		let (poll, voter) = voted::<T>(0)?;
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(RawOrigin::Signed(voter), poll)
+	verify {
+		assert!(DualVoting::<T>::polls(poll).unwrap().result.is_some());
+	}
+
+	unlock {
+		let (_, voter) = voted::<T>(0)?;
+		sunset::<T>()?;
+	}: _(RawOrigin::Signed(voter.clone()), voter.clone())
+	verify {
+		assert!(DualVoting::<T>::locks(voter).is_none());
+	}
+
+	set_formula {
+		let origin = governance::<T>()?;
+		let formula = WeightFormula::default();
+	}: _<T::RuntimeOrigin>(origin, formula)
+	verify {
+		assert_eq!(DualVoting::<T>::formula(), formula);
+	}
+
+	sunset {
+		let origin = governance::<T>()?;
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(DualVoting::<T>::is_sunset());
+	}
+
+	unwind {
+		let l in 1 .. 100;
+		// each vote adds a lock, a vote and a poll, so `l` votes fill budget
+		// of `3 * l` - unwind with `l` leaves part of the storage
+		for i in 0 .. l {
+			voted::<T>(i)?;
+		}
+		sunset::<T>()?;
+		let caller = voter::<T>(l);
+	}: _(RawOrigin::Signed(caller), l)
+	verify {
+		assert!(DualVoting::<T>::locks(account::<T::AccountId>("voter", 0, SEED)).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(DualVoting, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,468 @@
+//! # Liberland Dual Voting Pallet
+//!
+//! ## Overview
+//!
+//! Experimental voting module piloting dual-token conviction voting. Voting
+//! weight combines pooled LLM with a small amount of LLD locked with
+//! conviction, using a formula tunable by governance (see `WeightFormula`).
+//!
+//! Polls are created by governance and last `VotingPeriod` blocks. Each poll
+//! snapshots the formula when it's created and each vote snapshots voter's
+//! pooled LLM when it's cast, so later changes don't affect running tallies.
+//! LLD is locked until poll's end plus `ConvictionPeriod` times conviction's
+//! lock periods.
+//!
+//! Experiment is meant to run on testnet only - runtime enables it with
+//! `Enabled`, otherwise all calls except `unlock` and `unwind` fail. Once the
+//! experiment is sunset, all locks can be released immediately and storage
+//! of the pallet is fully cleared with `unwind`.
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency in which LLD is locked
+//! * `LLM` - source of pooled LLM balances
+//! * `Citizenship` - used to check if voter has politics allowed
+//! * `LLInitializer` - used to make test citizens in benchmarks
+//! * `Enabled` - whether the experiment is enabled in this runtime
+//! * `GovernanceOrigin` - origin that creates polls, tunes the formula and
+//!   sunsets the experiment
+//! * `VotingPeriod` - length of polls in blocks
+//! * `ConvictionPeriod` - length of single conviction lock period in blocks
+//! * `MaxLLDLock` - maximum amount of LLD locked for a single vote
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_poll`: Create new poll
+//! * `vote`: Vote in poll, replacing previous vote
+//! * `close_poll`: Close poll after its end and record result
+//! * `unlock`: Remove expired LLD lock
+//! * `set_formula`: Set formula used by new polls
+//! * `sunset`: End the experiment
+//! * `unwind`: Release locks and clear storage after sunset
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, LockIdentifier};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+const LOCK_ID: LockIdentifier = *b"dualvote";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{LockableCurrency, WithdrawReasons},
+	};
+	use frame_system::pallet_prelude::*;
+	use liberland_traits::{CitizenshipChecker, LLInitializer, LLM};
+	use pallet_democracy::Conviction;
+	use sp_runtime::traits::Saturating;
+	use sp_std::prelude::*;
+
+	pub type PollOf<T> = Poll<<T as frame_system::Config>::Hash, BlockNumberFor<T>, BalanceOf<T>>;
+	pub type VoteLockOf<T> = VoteLock<BalanceOf<T>, BlockNumberFor<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency in which LLD is locked
+		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+
+		/// Source of pooled LLM balances
+		type LLM: LLM<Self::AccountId, BalanceOf<Self>>;
+
+		/// Checks if voter has politics allowed
+		type Citizenship: CitizenshipChecker<Self::AccountId>;
+
+		/// Makes test citizens in benchmarks
+		type LLInitializer: LLInitializer<Self::AccountId>;
+
+		/// Whether the experiment is enabled in this runtime
+		#[pallet::constant]
+		type Enabled: Get<bool>;
+
+		/// Origin that creates polls, tunes the formula and sunsets the
+		/// experiment
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Length of polls in blocks
+		#[pallet::constant]
+		type VotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Length of single conviction lock period in blocks
+		#[pallet::constant]
+		type ConvictionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum amount of LLD locked for a single vote
+		#[pallet::constant]
+		type MaxLLDLock: Get<BalanceOf<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Experiment isn't enabled in this runtime
+		Disabled,
+		/// Experiment was sunset
+		Sunset,
+		/// Experiment wasn't sunset yet
+		NotSunset,
+		/// Poll doesn't exist
+		PollNotFound,
+		/// Poll already ended
+		PollEnded,
+		/// Poll didn't end yet
+		PollNotEnded,
+		/// Poll was already closed
+		AlreadyClosed,
+		/// LLD amount exceeds `MaxLLDLock`
+		LockTooBig,
+		/// Account has no lock
+		NoLock,
+		/// Lock didn't expire yet
+		StillLocked,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Poll created
+		PollCreated { index: PollIndex, proposal: T::Hash, end: BlockNumberFor<T> },
+		/// Vote cast or replaced
+		Voted { index: PollIndex, voter: T::AccountId, aye: bool, weight: BalanceOf<T> },
+		/// Poll closed
+		PollClosed { index: PollIndex, ayes: BalanceOf<T>, nays: BalanceOf<T>, approved: bool },
+		/// LLD lock removed
+		Unlocked { who: T::AccountId },
+		/// Formula for new polls changed
+		FormulaSet { formula: WeightFormula },
+		/// Experiment sunset, locks can be released
+		ExperimentSunset,
+		/// All storage of the experiment was cleared
+		Unwound,
+	}
+
+	/// Formula used by new polls
+	#[pallet::storage]
+	#[pallet::getter(fn formula)]
+	pub type Formula<T: Config> = StorageValue<_, WeightFormula, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn poll_count)]
+	pub type PollCount<T: Config> = StorageValue<_, PollIndex, ValueQuery>;
+
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn polls)]
+	pub type Polls<T: Config> = StorageMap<_, Twox64Concat, PollIndex, PollOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn votes)]
+	pub type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PollIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		VoteRecord<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// LLD locks, each covering all votes of the account
+	#[pallet::storage]
+	#[pallet::getter(fn locks)]
+	pub type Locks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VoteLockOf<T>, OptionQuery>;
+
+	/// Set once the experiment is sunset
+	#[pallet::storage]
+	#[pallet::getter(fn is_sunset)]
+	pub type IsSunset<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new poll ending in `VotingPeriod` blocks.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// - `proposal`: Hash of the proposal text
+		///
+		/// Emits `PollCreated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_poll())]
+		pub fn create_poll(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_running()?;
+
+			let index = Self::poll_count();
+			let end =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+			Polls::<T>::insert(
+				index,
+				PollOf::<T> {
+					proposal,
+					end,
+					formula: Self::formula(),
+					tally: Default::default(),
+					result: None,
+				},
+			);
+			PollCount::<T>::put(index.saturating_add(1));
+
+			Self::deposit_event(Event::PollCreated { index, proposal, end });
+			Ok(())
+		}
+
+		/// Vote in poll. Replaces previous vote of the caller in this poll.
+		/// Weight is calculated from the caller's current pooled LLM and `lld`
+		/// locked with `conviction`.
+		///
+		/// The dispatch origin of this call must be _Signed_ by an account with
+		/// politics allowed.
+		///
+		/// - `index`: Poll index
+		/// - `aye`: Whether to vote for or against
+		/// - `lld`: LLD to lock, at most `MaxLLDLock`
+		/// - `conviction`: Conviction of LLD lock
+		///
+		/// Emits `Voted`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(
+			origin: OriginFor<T>,
+			index: PollIndex,
+			aye: bool,
+			lld: BalanceOf<T>,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+			Self::ensure_running()?;
+			T::Citizenship::ensure_politics_allowed(&voter)?;
+			ensure!(lld <= T::MaxLLDLock::get(), Error::<T>::LockTooBig);
+
+			let mut poll = Self::polls(index).ok_or(Error::<T>::PollNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < poll.end, Error::<T>::PollEnded);
+
+			if let Some(previous) = Votes::<T>::get(index, &voter) {
+				Self::remove_from_tally(&mut poll, &previous);
+			}
+
+			let llm = T::LLM::get_llm_politics(&voter);
+			let weight = poll.formula.weight(llm, lld, conviction);
+			if aye {
+				poll.tally.ayes.saturating_accrue(weight);
+			} else {
+				poll.tally.nays.saturating_accrue(weight);
+			}
+			poll.tally.voters.saturating_inc();
+
+			let lock_duration =
+				T::ConvictionPeriod::get().saturating_mul(conviction.lock_periods().into());
+			Self::extend_lock(&voter, lld, poll.end.saturating_add(lock_duration));
+
+			Votes::<T>::insert(index, &voter, VoteRecord { aye, llm, lld, conviction, weight });
+			Polls::<T>::insert(index, poll);
+
+			Self::deposit_event(Event::Voted { index, voter, aye, weight });
+			Ok(())
+		}
+
+		/// Close poll after its end and record the result. Poll is approved if
+		/// ayes outweigh nays.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `index`: Poll index
+		///
+		/// Emits `PollClosed`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::close_poll())]
+		pub fn close_poll(origin: OriginFor<T>, index: PollIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_running()?;
+
+			let mut poll = Self::polls(index).ok_or(Error::<T>::PollNotFound)?;
+			ensure!(poll.result.is_none(), Error::<T>::AlreadyClosed);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= poll.end, Error::<T>::PollNotEnded);
+
+			let approved = poll.tally.ayes > poll.tally.nays;
+			poll.result = Some(approved);
+			let (ayes, nays) = (poll.tally.ayes, poll.tally.nays);
+			Polls::<T>::insert(index, poll);
+
+			Self::deposit_event(Event::PollClosed { index, ayes, nays, approved });
+			Ok(())
+		}
+
+		/// Remove LLD lock of `target` once it expired. After sunset, locks
+		/// can be removed immediately.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `target`: Account to unlock
+		///
+		/// Emits `Unlocked`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let lock = Self::locks(&target).ok_or(Error::<T>::NoLock)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(Self::is_sunset() || now >= lock.until, Error::<T>::StillLocked);
+
+			Self::remove_lock(&target);
+			Ok(())
+		}
+
+		/// Set formula used by polls created from now on.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// - `formula`: New formula
+		///
+		/// Emits `FormulaSet`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_formula())]
+		pub fn set_formula(origin: OriginFor<T>, formula: WeightFormula) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_running()?;
+
+			Formula::<T>::put(formula);
+			Self::deposit_event(Event::FormulaSet { formula });
+			Ok(())
+		}
+
+		/// End the experiment. No new polls or votes are accepted and all
+		/// locks can be removed immediately.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// Emits `ExperimentSunset`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::sunset())]
+		pub fn sunset(origin: OriginFor<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_running()?;
+
+			IsSunset::<T>::put(true);
+			Self::deposit_event(Event::ExperimentSunset);
+			Ok(())
+		}
+
+		/// Release up to `limit` locks and remove up to `limit` votes and polls
+		/// after sunset. Call repeatedly until `Unwound` is emitted.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `limit`: Maximum number of storage items to remove
+		///
+		/// Emits `Unlocked` for each released lock and `Unwound` once all
+		/// storage is cleared.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::unwind(*limit))]
+		pub fn unwind(origin: OriginFor<T>, limit: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(Self::is_sunset(), Error::<T>::NotSunset);
+
+			let mut budget = limit as usize;
+			let locked: Vec<_> = Locks::<T>::iter_keys().take(budget).collect();
+			budget.saturating_reduce(locked.len());
+			for who in locked {
+				Self::remove_lock(&who);
+			}
+			let votes: Vec<_> = Votes::<T>::iter_keys().take(budget).collect();
+			budget.saturating_reduce(votes.len());
+			for (index, who) in votes {
+				Votes::<T>::remove(index, who);
+			}
+			let polls: Vec<_> = Polls::<T>::iter_keys().take(budget).collect();
+			for index in polls {
+				Polls::<T>::remove(index);
+			}
+
+			if Locks::<T>::iter_keys().next().is_none()
+				&& Votes::<T>::iter_keys().next().is_none()
+				&& Polls::<T>::iter_keys().next().is_none()
+			{
+				Formula::<T>::kill();
+				PollCount::<T>::kill();
+				Self::deposit_event(Event::Unwound);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_running() -> DispatchResult {
+			ensure!(T::Enabled::get(), Error::<T>::Disabled);
+			ensure!(!Self::is_sunset(), Error::<T>::Sunset);
+			Ok(())
+		}
+
+		fn remove_from_tally(poll: &mut PollOf<T>, vote: &VoteRecord<BalanceOf<T>>) {
+			if vote.aye {
+				poll.tally.ayes.saturating_reduce(vote.weight);
+			} else {
+				poll.tally.nays.saturating_reduce(vote.weight);
+			}
+			poll.tally.voters.saturating_dec();
+		}
+
+		fn extend_lock(who: &T::AccountId, amount: BalanceOf<T>, until: BlockNumberFor<T>) {
+			let lock = match Self::locks(who) {
+				Some(lock) => VoteLockOf::<T> {
+					amount: lock.amount.max(amount),
+					until: lock.until.max(until),
+				},
+				None => VoteLockOf::<T> { amount, until },
+			};
+			T::Currency::set_lock(LOCK_ID, who, lock.amount, WithdrawReasons::all());
+			Locks::<T>::insert(who, lock);
+		}
+
+		fn remove_lock(who: &T::AccountId) {
+			T::Currency::remove_lock(LOCK_ID, who);
+			Locks::<T>::remove(who);
+			Self::deposit_event(Event::Unlocked { who: who.clone() });
+		}
+	}
+}
@@ -0,0 +1,131 @@
+#![cfg(test)]
+pub use crate as pallet_dual_voting;
+
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use pallet_balances::AccountData;
+use sp_core::{ConstU16, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+ord_parameter_types! {
+	pub const Governance: u64 = 1;
+}
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		DualVoting: pallet_dual_voting,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ConstU32<1>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub static PooledLLM: u64 = 100;
+	pub static Enabled: bool = true;
+}
+
+/// Every account has `PooledLLM` pooled
+pub struct MockLLM;
+impl liberland_traits::LLM<u64, u64> for MockLLM {
+	fn check_pooled_llm(_account: &u64) -> bool {
+		true
+	}
+
+	fn is_election_unlocked(_account: &u64) -> bool {
+		true
+	}
+
+	fn get_politi_pooled_amount() -> u64 {
+		PooledLLM::get()
+	}
+
+	fn get_llm_politics(_account: &u64) -> u64 {
+		PooledLLM::get()
+	}
+}
+
+pub struct MockInitializer;
+impl liberland_traits::LLInitializer<u64> for MockInitializer {
+	#[cfg(feature = "runtime-benchmarks")]
+	fn make_test_citizen(_account: &u64) {}
+}
+
+impl pallet_dual_voting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type LLM = MockLLM;
+	type Citizenship = ();
+	type LLInitializer = MockInitializer;
+	type Enabled = Enabled;
+	type GovernanceOrigin = EnsureSignedBy<Governance, u64>;
+	type VotingPeriod = ConstU64<10>;
+	type ConvictionPeriod = ConstU64<5>;
+	type MaxLLDLock = ConstU64<50>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let balances = vec![(1, 100), (2, 100), (3, 100), (4, 100)];
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+	});
+	ext
+}
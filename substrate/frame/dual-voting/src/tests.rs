@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, Tally, VoteLock, WeightFormula};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_democracy::Conviction;
+use sp_core::H256;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+fn create_poll() {
+	assert_ok!(DualVoting::create_poll(RuntimeOrigin::signed(1), H256::default()));
+}
+
+fn vote(who: u64, aye: bool, lld: u64, conviction: Conviction) {
+	assert_ok!(DualVoting::vote(RuntimeOrigin::signed(who), 0, aye, lld, conviction));
+}
+
+#[test]
+fn create_poll_requires_governance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DualVoting::create_poll(RuntimeOrigin::signed(2), H256::default()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		create_poll();
+		let poll = DualVoting::polls(0).unwrap();
+		assert_eq!(poll.end, 11);
+		assert_eq!(poll.formula, WeightFormula::default());
+		assert_eq!(DualVoting::poll_count(), 1);
+	});
+}
+
+#[test]
+fn calls_fail_when_disabled() {
+	new_test_ext().execute_with(|| {
+		Enabled::set(false);
+		assert_noop!(
+			DualVoting::create_poll(RuntimeOrigin::signed(1), H256::default()),
+			Error::<Test>::Disabled
+		);
+		Enabled::set(true);
+	});
+}
+
+#[test]
+fn vote_combines_llm_and_lld() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		vote(2, true, 20, Conviction::Locked3x);
+		vote(3, false, 50, Conviction::None);
+		let poll = DualVoting::polls(0).unwrap();
+		assert_eq!(poll.tally, Tally { ayes: 160, nays: 105, voters: 2 });
+		System::assert_last_event(
+			Event::Voted { index: 0, voter: 3, aye: false, weight: 105 }.into(),
+		);
+		assert_noop!(
+			DualVoting::vote(RuntimeOrigin::signed(2), 0, true, 51, Conviction::None),
+			Error::<Test>::LockTooBig
+		);
+	});
+}
+
+#[test]
+fn vote_replaces_previous_vote() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		vote(2, true, 20, Conviction::Locked1x);
+		vote(2, false, 10, Conviction::Locked1x);
+		let poll = DualVoting::polls(0).unwrap();
+		assert_eq!(poll.tally, Tally { ayes: 0, nays: 110, voters: 1 });
+		assert_eq!(DualVoting::locks(2), Some(VoteLock { amount: 20, until: 16 }));
+	});
+}
+
+#[test]
+fn polls_snapshot_formula_and_llm() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		let formula = WeightFormula {
+			llm_factor: FixedU128::from_rational(1, 2),
+			lld_factor: FixedU128::saturating_from_integer(2),
+		};
+		assert_ok!(DualVoting::set_formula(RuntimeOrigin::signed(1), formula));
+		create_poll();
+
+		vote(2, true, 10, Conviction::Locked1x);
+		assert_ok!(DualVoting::vote(RuntimeOrigin::signed(2), 1, true, 10, Conviction::Locked1x));
+		assert_eq!(DualVoting::polls(0).unwrap().tally.ayes, 110);
+		assert_eq!(DualVoting::polls(1).unwrap().tally.ayes, 70);
+
+		PooledLLM::set(200);
+		vote(3, true, 0, Conviction::None);
+		assert_eq!(DualVoting::votes(0, 2).unwrap().llm, 100);
+		assert_eq!(DualVoting::votes(0, 3).unwrap().llm, 200);
+		PooledLLM::set(100);
+	});
+}
+
+#[test]
+fn close_poll_records_result() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		vote(2, true, 10, Conviction::Locked1x);
+		vote(3, false, 0, Conviction::None);
+		assert_noop!(
+			DualVoting::close_poll(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::PollNotEnded
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			DualVoting::vote(RuntimeOrigin::signed(4), 0, true, 0, Conviction::None),
+			Error::<Test>::PollEnded
+		);
+		assert_ok!(DualVoting::close_poll(RuntimeOrigin::signed(4), 0));
+		assert_eq!(DualVoting::polls(0).unwrap().result, Some(true));
+		System::assert_last_event(
+			Event::PollClosed { index: 0, ayes: 110, nays: 100, approved: true }.into(),
+		);
+		assert_noop!(
+			DualVoting::close_poll(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::AlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn lld_is_locked_by_conviction() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		vote(2, true, 30, Conviction::Locked2x);
+		assert_eq!(DualVoting::locks(2), Some(VoteLock { amount: 30, until: 21 }));
+		assert_eq!(Balances::usable_balance(2), 70);
+
+		System::set_block_number(20);
+		assert_noop!(DualVoting::unlock(RuntimeOrigin::signed(3), 2), Error::<Test>::StillLocked);
+		System::set_block_number(21);
+		assert_ok!(DualVoting::unlock(RuntimeOrigin::signed(3), 2));
+		assert_eq!(DualVoting::locks(2), None);
+		assert_eq!(Balances::usable_balance(2), 100);
+		assert_noop!(DualVoting::unlock(RuntimeOrigin::signed(3), 2), Error::<Test>::NoLock);
+	});
+}
+
+#[test]
+fn sunset_unwinds_everything() {
+	new_test_ext().execute_with(|| {
+		create_poll();
+		create_poll();
+		vote(2, true, 30, Conviction::Locked6x);
+		vote(3, true, 30, Conviction::Locked6x);
+		assert_noop!(DualVoting::unwind(RuntimeOrigin::signed(4), 10), Error::<Test>::NotSunset);
+
+		assert_ok!(DualVoting::sunset(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			DualVoting::vote(RuntimeOrigin::signed(4), 0, true, 0, Conviction::None),
+			Error::<Test>::Sunset
+		);
+		assert_ok!(DualVoting::unlock(RuntimeOrigin::signed(4), 2));
+		assert_eq!(Balances::usable_balance(2), 100);
+
+		assert_ok!(DualVoting::unwind(RuntimeOrigin::signed(4), 2));
+		assert_eq!(Balances::usable_balance(3), 100);
+		assert!(DualVoting::polls(0).is_some());
+
+		assert_ok!(DualVoting::unwind(RuntimeOrigin::signed(4), 10));
+		System::assert_last_event(Event::Unwound.into());
+		assert_eq!(DualVoting::polls(0), None);
+		assert_eq!(DualVoting::polls(1), None);
+		assert_eq!(DualVoting::votes(0, 2), None);
+		assert_eq!(DualVoting::poll_count(), 0);
+	});
+}
@@ -0,0 +1,78 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::Balance;
+use pallet_democracy::Conviction;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, FixedPointNumber, FixedU128, RuntimeDebug};
+
+pub type PollIndex = u32;
+
+/// Formula combining pooled LLM and conviction-locked LLD into voting weight:
+///
+/// `llm_factor * pooled_llm + lld_factor * locked_lld * conviction_multiplier`
+///
+/// where conviction multiplier is 0.1 for `Conviction::None` and N for
+/// `Conviction::LockedNx`.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct WeightFormula {
+	/// Weight of a single pooled LLM
+	pub llm_factor: FixedU128,
+	/// Weight of a single LLD locked with 1x conviction
+	pub lld_factor: FixedU128,
+}
+
+impl Default for WeightFormula {
+	fn default() -> Self {
+		Self { llm_factor: FixedU128::one(), lld_factor: FixedU128::one() }
+	}
+}
+
+impl WeightFormula {
+	pub fn weight<B: Balance>(&self, llm: B, lld: B, conviction: Conviction) -> B {
+		let lld_votes = match conviction {
+			Conviction::None => lld / 10u8.into(),
+			conviction => lld.saturating_mul(u8::from(conviction).into()),
+		};
+		self.llm_factor
+			.saturating_mul_int(llm)
+			.saturating_add(self.lld_factor.saturating_mul_int(lld_votes))
+	}
+}
+
+#[derive(Clone, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Tally<Balance> {
+	pub ayes: Balance,
+	pub nays: Balance,
+	pub voters: u32,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Poll<Hash, BlockNumber, Balance> {
+	/// Hash of the proposal text
+	pub proposal: Hash,
+	/// Block at which voting ends
+	pub end: BlockNumber,
+	/// Snapshot of `Formula` taken when poll was created
+	pub formula: WeightFormula,
+	pub tally: Tally<Balance>,
+	/// `Some(approved)` once poll is closed
+	pub result: Option<bool>,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct VoteRecord<Balance> {
+	pub aye: bool,
+	/// Pooled LLM snapshot taken when vote was cast
+	pub llm: Balance,
+	/// LLD locked for this vote
+	pub lld: Balance,
+	pub conviction: Conviction,
+	/// Weight added to the tally
+	pub weight: Balance,
+}
+
+/// LLD lock of an account, covering all its votes
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct VoteLock<Balance, BlockNumber> {
+	pub amount: Balance,
+	pub until: BlockNumber,
+}
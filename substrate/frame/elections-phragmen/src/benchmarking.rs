@@ -26,7 +26,10 @@
 use super::*;
 
 use frame_benchmarking::v1::{account, benchmarks, whitelist, BenchmarkError, BenchmarkResult};
-use frame_support::{dispatch::DispatchResultWithPostInfo, traits::OnInitialize};
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	traits::{EnsureOrigin, OnInitialize},
+};
 use frame_system::RawOrigin;
 
 use crate::Pallet as Elections;
@@ -428,6 +431,85 @@ benchmarks! {
 		}
 	}
 
+	set_counting_mode {
+		let origin = T::CountingModeOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, CountingMode::InstantRunoff)
+	verify {
+		assert_eq!(<Elections<T>>::counting_mode(), CountingMode::InstantRunoff);
+	}
+
+	start_runoff {
+		let c in 1 .. T::MaxCandidates::get();
+		let v in 1 .. T::MaxVoters::get();
+		let e in (T::MaxVoters::get()) .. T::MaxVoters::get() * T::MaxVotesPerVoter::get();
+		clean::<T>();
+
+		// see `election_phragmen` for the reasoning.
+		let votes_per_voter = (e / v).min(T::MaxVotesPerVoter::get());
+
+		let all_candidates = submit_candidates_with_self_vote::<T>(c, "candidates")?;
+		let _ = distribute_voters::<T>(all_candidates, v.saturating_sub(c), votes_per_voter as usize)?;
+	}: {
+		<Elections<T>>::start_runoff();
+	}
+	verify {
+		assert!(<Elections<T>>::runoff().is_some());
+	}
+
+	runoff_count {
+		// counts `b` ballots mid-pass, so that neither elimination nor finish happens.
+		let b in 0 .. T::RunoffBallotsPerBlock::get();
+		let c in 1 .. T::MaxCandidates::get();
+		clean::<T>();
+
+		let all_candidates = submit_candidates_with_self_vote::<T>(c, "candidates")?;
+		let _ = distribute_voters::<T>(all_candidates, b + 1, T::MaxVotesPerVoter::get() as usize)?;
+		<Elections<T>>::start_runoff();
+		<RunoffState<T>>::mutate(|runoff| {
+			if let Some(runoff) = runoff {
+				runoff.next_ballot = runoff.ballots - b - 1;
+			}
+		});
+	}: {
+		<Elections<T>>::do_runoff_step();
+	}
+	verify {
+		let runoff = <Elections<T>>::runoff().unwrap();
+		assert_eq!(runoff.next_ballot, runoff.ballots - 1);
+	}
+
+	finish_runoff {
+		let c in 1 .. T::MaxCandidates::get();
+		let v in 1 .. T::MaxVoters::get();
+		clean::<T>();
+
+		let all_candidates = submit_candidates_with_self_vote::<T>(c, "candidates")?;
+		let _ = distribute_voters::<T>(
+			all_candidates,
+			v.saturating_sub(c),
+			T::MaxVotesPerVoter::get() as usize,
+		)?;
+		<Elections<T>>::start_runoff();
+		let mut runoff = <Elections<T>>::runoff().unwrap();
+		for (_, _, backing) in runoff.continuing.iter_mut() {
+			*backing = 1;
+		}
+	}: {
+		<Elections<T>>::finish_runoff(runoff);
+	}
+	verify {
+		assert!(<Elections<T>>::runoff().is_none());
+		assert_eq!(<Elections<T>>::members().len() as u32, T::DesiredMembers::get().min(c));
+
+		#[cfg(test)]
+		{
+			// reset members in between benchmark tests.
+			use crate::tests::MEMBERS;
+			MEMBERS.with(|m| *m.borrow_mut() = vec![]);
+		}
+	}
+
 	impl_benchmark_test_suite!(
 		Elections,
 		crate::tests::ExtBuilder::default().desired_members(13).desired_runners_up(7),
@@ -95,6 +95,23 @@
 //! `M` being elected, as long as `V` and `C` don't remove their candidacy and votes, `M` will keep
 //! being re-elected at the end of each round.
 //!
+//! ### Counting Modes
+//!
+//! Votes of an election round are counted in one of two ways, selected by
+//! [`Config::CountingModeOrigin`] with [`Call::set_counting_mode`]. The mode in place when a
+//! round starts is used for that round.
+//!
+//! - [`CountingMode::Phragmen`]: votes are approvals, counted by sequential phragmen in the
+//!   election block. Order of votes only matters for prime election.
+//! - [`CountingMode::InstantRunoff`]: votes are rankings, best first. In the election block,
+//!   ballots are snapshotted and then counted across the following blocks, at most
+//!   [`Config::RunoffBallotsPerBlock`] ballots per block. Each ballot counts towards its
+//!   highest-ranked candidate still in the count. After every full pass over the ballots,
+//!   candidates without any backing are eliminated, or if there are none, the candidate with the
+//!   lowest backing is. Once no more than `DesiredMembers + DesiredRunnersUp` candidates
+//!   remain, they are elected ordered by their final backing. Candidacy can't be submitted or
+//!   renounced while a count is in progress.
+//!
 //! ### Module Information
 //!
 //! - [`Config`]
@@ -184,6 +201,41 @@ pub struct SeatHolder<AccountId, Balance> {
 	pub deposit: Balance,
 }
 
+/// How votes are counted in an election round.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CountingMode {
+	/// Sequential phragmen over approval votes.
+	#[default]
+	Phragmen,
+	/// Instant-runoff over ranked votes, counted across multiple blocks.
+	InstantRunoff,
+}
+
+/// Ranked ballot, snapshotted at the start of an instant-runoff count.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, TypeInfo)]
+pub struct RankedBallot<AccountId, Balance> {
+	/// The voter.
+	pub voter: AccountId,
+	/// The amount of stake placed on this vote.
+	pub stake: Balance,
+	/// Valid candidates from voter's votes, best first, without duplicates.
+	pub ranking: Vec<AccountId>,
+}
+
+/// State of an instant-runoff count in progress.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, TypeInfo)]
+pub struct Runoff<AccountId, Balance> {
+	/// Candidates still in the count, with their deposit and backing counted so far in the
+	/// current pass.
+	pub continuing: Vec<(AccountId, Balance, ExtendedBalance)>,
+	/// All candidates of the round with their deposits.
+	pub candidates: Vec<(AccountId, Balance)>,
+	/// Number of snapshotted ballots.
+	pub ballots: u32,
+	/// Index of the next ballot to count in the current pass.
+	pub next_ballot: u32,
+}
+
 pub use pallet::*;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
@@ -290,6 +342,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotesPerVoter: Get<u32>;
 
+		/// Origin that selects how votes are counted.
+		type CountingModeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of ballots counted per block in an instant-runoff count.
+		#[pallet::constant]
+		type RunoffBallotsPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -306,7 +365,9 @@ pub mod pallet {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			let term_duration = T::TermDuration::get();
 			if !term_duration.is_zero() && (n % term_duration).is_zero() {
-				Self::do_phragmen()
+				Self::do_election()
+			} else if Self::runoff().is_some() {
+				Self::do_runoff_step()
 			} else {
 				Weight::zero()
 			}
@@ -454,6 +515,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			T::Citizenship::ensure_politics_allowed(&who)?;
+			ensure!(Self::runoff().is_none(), Error::<T>::RunoffInProgress);
 
 			let actual_count = <Candidates<T>>::decode_len().unwrap_or(0) as u32;
 			ensure!(actual_count <= candidate_count, Error::<T>::InvalidWitnessData);
@@ -499,6 +561,7 @@ pub mod pallet {
 		})]
 		pub fn renounce_candidacy(origin: OriginFor<T>, renouncing: Renouncing) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			ensure!(Self::runoff().is_none(), Error::<T>::RunoffInProgress);
 			match renouncing {
 				Renouncing::Member => {
 					let _ = Self::remove_and_replace_member(&who, false)
@@ -536,8 +599,8 @@ pub mod pallet {
 		/// the outgoing member is slashed.
 		///
 		/// If a runner-up is available, then the best runner-up will be removed and replaces the
-		/// outgoing member. Otherwise, if `rerun_election` is `true`, a new phragmen election is
-		/// started, else, nothing happens.
+		/// outgoing member. Otherwise, if `rerun_election` is `true`, a new election is started
+		/// using the current counting mode, else, nothing happens.
 		///
 		/// If `slash_bond` is set to true, the bond of the member being removed is slashed. Else,
 		/// it is returned.
@@ -567,7 +630,7 @@ pub mod pallet {
 			Self::deposit_event(Event::MemberKicked { member: who });
 
 			if rerun_election {
-				Self::do_phragmen();
+				Self::do_election();
 			}
 
 			// no refund needed.
@@ -597,6 +660,19 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set how votes are counted, starting with the next election round. A count already in
+		/// progress is not affected.
+		///
+		/// The dispatch origin of this call must be [`Config::CountingModeOrigin`].
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_counting_mode())]
+		pub fn set_counting_mode(origin: OriginFor<T>, mode: CountingMode) -> DispatchResult {
+			T::CountingModeOrigin::ensure_origin(origin)?;
+			ElectionCountingMode::<T>::put(mode);
+			Self::deposit_event(Event::CountingModeSet { mode });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -628,6 +704,12 @@ pub mod pallet {
 			seat_holder: <T as frame_system::Config>::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Counting mode for the next election rounds was changed.
+		CountingModeSet { mode: CountingMode },
+		/// Instant-runoff count started. It's finished by `NewTerm`.
+		RunoffStarted { candidates: u32, ballots: u32 },
+		/// Candidates were eliminated from the instant-runoff count.
+		CandidatesEliminated { candidates: Vec<<T as frame_system::Config>::AccountId> },
 	}
 
 	#[pallet::error]
@@ -668,6 +750,8 @@ pub mod pallet {
 		InvalidReplacement,
 		/// llm error
 		InsufficientLLM,
+		/// Candidacy can't change while instant-runoff count is in progress.
+		RunoffInProgress,
 	}
 
 	/// The current elected members.
@@ -710,6 +794,22 @@ pub mod pallet {
 	pub type Voting<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, Voter<T::AccountId, BalanceOf<T>>, ValueQuery>;
 
+	/// How votes of the next election round will be counted.
+	#[pallet::storage]
+	#[pallet::getter(fn counting_mode)]
+	pub type ElectionCountingMode<T: Config> = StorageValue<_, CountingMode, ValueQuery>;
+
+	/// Instant-runoff count in progress, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn runoff)]
+	pub type RunoffState<T: Config> =
+		StorageValue<_, Runoff<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	/// Ballots of the instant-runoff count in progress, by index.
+	#[pallet::storage]
+	pub type RunoffBallots<T: Config> =
+		StorageMap<_, Twox64Concat, u32, RankedBallot<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -914,6 +1014,185 @@ impl<T: Config> Pallet<T> {
 		<Voting<T>>::take(who);
 	}
 
+	/// Start an election round, counted according to [`ElectionCountingMode`]. Does nothing if
+	/// an instant-runoff count is still in progress.
+	fn do_election() -> Weight {
+		if Self::runoff().is_some() {
+			log::error!(target: LOG_TARGET, "Previous instant-runoff count still in progress.");
+			Self::deposit_event(Event::ElectionError);
+			return Self::do_runoff_step()
+		}
+		match Self::counting_mode() {
+			CountingMode::Phragmen => Self::do_phragmen(),
+			CountingMode::InstantRunoff => Self::start_runoff(),
+		}
+	}
+
+	/// Snapshot candidates and ranked ballots for an instant-runoff count. The count itself
+	/// happens in [`Self::do_runoff_step`] in the following blocks.
+	fn start_runoff() -> Weight {
+		let mut candidates_and_deposit = Self::candidates();
+		candidates_and_deposit.append(&mut Self::implicit_candidates_with_deposit());
+
+		if candidates_and_deposit.len().is_zero() {
+			Self::deposit_event(Event::EmptyTerm);
+			return T::DbWeight::get().reads(3)
+		}
+
+		let max_voters = <T as Config>::MaxVoters::get() as usize;
+		let mut ballots: Vec<RankedBallot<T::AccountId, BalanceOf<T>>> = Vec::new();
+		let mut num_edges: u32 = 0;
+		for (voter, Voter { stake, votes, .. }) in Voting::<T>::iter() {
+			if ballots.len() >= max_voters {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to run election. Number of voters exceeded",
+				);
+				Self::deposit_event(Event::ElectionError);
+				return T::DbWeight::get().reads(3 + max_voters as u64)
+			}
+			num_edges = num_edges.saturating_add(votes.len() as u32);
+			let mut ranking: Vec<T::AccountId> = Vec::with_capacity(votes.len());
+			for vote in votes {
+				if !ranking.contains(&vote) &&
+					candidates_and_deposit.iter().any(|(c, _)| c == &vote)
+				{
+					ranking.push(vote);
+				}
+			}
+			if !ranking.is_empty() && !stake.is_zero() {
+				ballots.push(RankedBallot { voter, stake, ranking });
+			}
+		}
+
+		let num_ballots = ballots.len() as u32;
+		for (index, ballot) in ballots.into_iter().enumerate() {
+			RunoffBallots::<T>::insert(index as u32, ballot);
+		}
+		let num_candidates = candidates_and_deposit.len() as u32;
+		RunoffState::<T>::put(Runoff {
+			continuing: candidates_and_deposit
+				.iter()
+				.map(|(c, d)| (c.clone(), *d, Zero::zero()))
+				.collect(),
+			candidates: candidates_and_deposit,
+			ballots: num_ballots,
+			next_ballot: 0,
+		});
+		Self::deposit_event(Event::RunoffStarted {
+			candidates: num_candidates,
+			ballots: num_ballots,
+		});
+
+		T::WeightInfo::start_runoff(num_candidates, num_ballots, num_edges)
+	}
+
+	/// Count next [`Config::RunoffBallotsPerBlock`] ballots of the instant-runoff count in
+	/// progress. After a full pass over the ballots, either eliminates candidates or, if few
+	/// enough remain, finishes the round.
+	fn do_runoff_step() -> Weight {
+		let mut runoff = match Self::runoff() {
+			Some(runoff) => runoff,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let num_candidates = runoff.continuing.len() as u32;
+
+		let total_issuance = T::LLM::get_politi_pooled_amount();
+		let to_votes = |b: BalanceOf<T>| T::CurrencyToVote::to_vote(b, total_issuance);
+
+		let end = runoff
+			.next_ballot
+			.saturating_add(T::RunoffBallotsPerBlock::get())
+			.min(runoff.ballots);
+		let counted = end - runoff.next_ballot;
+		for index in runoff.next_ballot..end {
+			let ballot = match RunoffBallots::<T>::get(index) {
+				Some(ballot) => ballot,
+				None => continue,
+			};
+			// ballot goes to its highest-ranked candidate that's still in the count
+			let top = ballot
+				.ranking
+				.iter()
+				.find_map(|who| runoff.continuing.iter().position(|(c, _, _)| c == who));
+			if let Some(i) = top {
+				let backing = &mut runoff.continuing[i].2;
+				*backing = backing.saturating_add(to_votes(ballot.stake).into());
+			}
+		}
+		runoff.next_ballot = end;
+
+		if runoff.next_ballot < runoff.ballots {
+			RunoffState::<T>::put(runoff);
+			return T::WeightInfo::runoff_count(counted, num_candidates)
+		}
+
+		let num_to_elect = (T::DesiredMembers::get() + T::DesiredRunnersUp::get()) as usize;
+		let has_unbacked = runoff.continuing.iter().any(|(_, _, backing)| backing.is_zero());
+		if !has_unbacked && runoff.continuing.len() <= num_to_elect {
+			return T::WeightInfo::runoff_count(counted, num_candidates)
+				.saturating_add(Self::finish_runoff(runoff))
+		}
+
+		let eliminated = if has_unbacked {
+			let (unbacked, backed) = runoff
+				.continuing
+				.into_iter()
+				.partition::<Vec<_>, _>(|(_, _, backing)| backing.is_zero());
+			runoff.continuing = backed;
+			unbacked.into_iter().map(|(who, _, _)| who).collect::<Vec<_>>()
+		} else {
+			// regular instant-runoff round: every continuing candidate is backed, but there are
+			// still more than `num_to_elect` of them, so the one with the lowest backing is
+			// eliminated. In case of a tie, the first one in the candidates list is. `continuing`
+			// is non-empty here, so `min_by_key` always finds a candidate.
+			let lowest = runoff
+				.continuing
+				.iter()
+				.enumerate()
+				.min_by_key(|(_, (_, _, backing))| *backing)
+				.map(|(i, _)| i)
+				.defensive_unwrap_or_default();
+			vec![runoff.continuing.remove(lowest).0]
+		};
+		for (_, _, backing) in runoff.continuing.iter_mut() {
+			*backing = Zero::zero();
+		}
+		runoff.next_ballot = 0;
+		Self::deposit_event(Event::CandidatesEliminated { candidates: eliminated });
+
+		if runoff.continuing.is_empty() {
+			// nobody had any backing - finish with an empty set, same as phragmen would.
+			return T::WeightInfo::runoff_count(counted, num_candidates)
+				.saturating_add(Self::finish_runoff(runoff))
+		}
+		RunoffState::<T>::put(runoff);
+		T::WeightInfo::runoff_count(counted, num_candidates)
+	}
+
+	/// Apply the result of a finished instant-runoff count and clean up its state.
+	fn finish_runoff(runoff: Runoff<T::AccountId, BalanceOf<T>>) -> Weight {
+		let total_issuance = T::LLM::get_politi_pooled_amount();
+		let to_balance = |e: ExtendedBalance| T::CurrencyToVote::to_currency(e, total_issuance);
+
+		let mut continuing = runoff.continuing;
+		continuing.sort_by(|a, b| b.2.cmp(&a.2));
+		let new_set_with_stake = continuing
+			.into_iter()
+			.map(|(who, _, backing)| (who, to_balance(backing)))
+			.collect::<Vec<_>>();
+
+		let voters_and_stakes = RunoffBallots::<T>::drain()
+			.map(|(_, ballot)| (ballot.voter, ballot.stake, ballot.ranking))
+			.collect::<Vec<_>>();
+		RunoffState::<T>::kill();
+
+		let num_candidates = runoff.candidates.len() as u32;
+		Self::apply_election_result(&runoff.candidates, new_set_with_stake, voters_and_stakes);
+
+		T::WeightInfo::finish_runoff(num_candidates, runoff.ballots)
+	}
+
 	/// Run the phragmen election with all required side processes and state updates, if election
 	/// succeeds. Else, it will emit an `ElectionError` event.
 	///
@@ -981,129 +1260,18 @@ impl<T: Config> Pallet<T> {
 		let _ =
 			sp_npos_elections::seq_phragmen(num_to_elect, candidate_ids, voters_and_votes, None)
 				.map(|ElectionResult::<T::AccountId, Perbill> { winners, assignments: _ }| {
-					// this is already sorted by id.
-					let old_members_ids_sorted = <Members<T>>::take()
-						.into_iter()
-						.map(|m| m.who)
-						.collect::<Vec<T::AccountId>>();
-					// this one needs a sort by id.
-					let mut old_runners_up_ids_sorted = <RunnersUp<T>>::take()
-						.into_iter()
-						.map(|r| r.who)
-						.collect::<Vec<T::AccountId>>();
-					old_runners_up_ids_sorted.sort();
-
 					// filter out those who end up with no backing stake.
-					let mut new_set_with_stake = winners
+					let new_set_with_stake = winners
 						.into_iter()
 						.filter_map(
 							|(m, b)| if b.is_zero() { None } else { Some((m, to_balance(b))) },
 						)
 						.collect::<Vec<(T::AccountId, BalanceOf<T>)>>();
-
-					// OPTIMIZATION NOTE: we could bail out here if `new_set.len() == 0`. There
-					// isn't much left to do. Yet, re-arranging the code would require duplicating
-					// the slashing of exposed candidates, cleaning any previous members, and so on.
-					// For now, in favor of readability and veracity, we keep it simple.
-
-					// split new set into winners and runners up.
-					let split_point = desired_seats.min(new_set_with_stake.len());
-					let mut new_members_sorted_by_id =
-						new_set_with_stake.drain(..split_point).collect::<Vec<_>>();
-					new_members_sorted_by_id.sort_by(|i, j| i.0.cmp(&j.0));
-
-					// all the rest will be runners-up
-					new_set_with_stake.reverse();
-					let new_runners_up_sorted_by_rank = new_set_with_stake;
-					let mut new_runners_up_ids_sorted = new_runners_up_sorted_by_rank
-						.iter()
-						.map(|(r, _)| r.clone())
-						.collect::<Vec<_>>();
-					new_runners_up_ids_sorted.sort();
-
-					// Now we select a prime member using a [Borda
-					// count](https://en.wikipedia.org/wiki/Borda_count). We weigh everyone's vote for
-					// that new member by a multiplier based on the order of the votes. i.e. the
-					// first person a voter votes for gets a 16x multiplier, the next person gets a
-					// 15x multiplier, an so on... (assuming `T::MaxVotesPerVoter` = 16)
-					let mut prime_votes = new_members_sorted_by_id
-						.iter()
-						.map(|c| (&c.0, BalanceOf::<T>::zero()))
-						.collect::<Vec<_>>();
-					for (_, stake, votes) in voters_and_stakes.into_iter() {
-						for (vote_multiplier, who) in
-							votes.iter().enumerate().map(|(vote_position, who)| {
-								((T::MaxVotesPerVoter::get() as usize - vote_position) as u32, who)
-							}) {
-							if let Ok(i) = prime_votes.binary_search_by_key(&who, |k| k.0) {
-								prime_votes[i].1 = prime_votes[i]
-									.1
-									.saturating_add(stake.saturating_mul(vote_multiplier.into()));
-							}
-						}
-					}
-					// We then select the new member with the highest weighted stake. In the case of
-					// a tie, the last person in the list with the tied score is selected. This is
-					// the person with the "highest" account id based on the sort above.
-					let prime = prime_votes.into_iter().max_by_key(|x| x.1).map(|x| x.0.clone());
-
-					// new_members_sorted_by_id is sorted by account id.
-					let new_members_ids_sorted = new_members_sorted_by_id
-						.iter()
-						.map(|(m, _)| m.clone())
-						.collect::<Vec<T::AccountId>>();
-
-					// report member changes. We compute diff because we need the outgoing list.
-					let (incoming, outgoing) = T::ChangeMembers::compute_members_diff_sorted(
-						&new_members_ids_sorted,
-						&old_members_ids_sorted,
+					Self::apply_election_result(
+						&candidates_and_deposit,
+						new_set_with_stake,
+						voters_and_stakes,
 					);
-					T::ChangeMembers::change_members_sorted(
-						&incoming,
-						&outgoing,
-						&new_members_ids_sorted,
-					);
-					T::ChangeMembers::set_prime(prime);
-
-					// write final values to storage.
-					let deposit_of_candidate = |x: &T::AccountId| -> BalanceOf<T> {
-						// defensive-only. This closure is used against the new members and new
-						// runners-up, both of which are phragmen winners and thus must have
-						// deposit.
-						candidates_and_deposit
-							.iter()
-							.find_map(|(c, d)| if c == x { Some(*d) } else { None })
-							.defensive_unwrap_or_default()
-					};
-					// fetch deposits from the one recorded one. This will make sure that a
-					// candidate who submitted candidacy before a change to candidacy deposit will
-					// have the correct amount recorded.
-					<Members<T>>::put(
-						new_members_sorted_by_id
-							.iter()
-							.map(|(who, stake)| SeatHolder {
-								deposit: deposit_of_candidate(who),
-								who: who.clone(),
-								stake: *stake,
-							})
-							.collect::<Vec<_>>(),
-					);
-					<RunnersUp<T>>::put(
-						new_runners_up_sorted_by_rank
-							.into_iter()
-							.map(|(who, stake)| SeatHolder {
-								deposit: deposit_of_candidate(&who),
-								who,
-								stake,
-							})
-							.collect::<Vec<_>>(),
-					);
-
-					// clean candidates.
-					<Candidates<T>>::kill();
-
-					Self::deposit_event(Event::NewTerm { new_members: new_members_sorted_by_id });
-					<ElectionRounds<T>>::mutate(|v| *v += 1);
 				})
 				.map_err(|e| {
 					log::error!(target: LOG_TARGET, "Failed to run election [{:?}].", e,);
@@ -1112,6 +1280,136 @@ impl<T: Config> Pallet<T> {
 
 		T::WeightInfo::election_phragmen(weight_candidates, weight_voters, weight_edges)
 	}
+
+	/// Replace members and runners-up with the result of an election, slashing/refunding as
+	/// needed and reporting the change through [`Config::ChangeMembers`].
+	///
+	/// `new_set_with_stake` are the elected candidates with their backing, best first. The first
+	/// [`Config::DesiredMembers`] of them become members, the rest runners-up.
+	/// `voters_and_stakes` are the ballots the election was run on, used for prime election.
+	fn apply_election_result(
+		candidates_and_deposit: &[(T::AccountId, BalanceOf<T>)],
+		mut new_set_with_stake: Vec<(T::AccountId, BalanceOf<T>)>,
+		voters_and_stakes: Vec<(T::AccountId, BalanceOf<T>, Vec<T::AccountId>)>,
+	) {
+		let desired_seats = T::DesiredMembers::get() as usize;
+
+		// this is already sorted by id.
+		let old_members_ids_sorted = <Members<T>>::take()
+			.into_iter()
+			.map(|m| m.who)
+			.collect::<Vec<T::AccountId>>();
+		// this one needs a sort by id.
+		let mut old_runners_up_ids_sorted = <RunnersUp<T>>::take()
+			.into_iter()
+			.map(|r| r.who)
+			.collect::<Vec<T::AccountId>>();
+		old_runners_up_ids_sorted.sort();
+
+		// OPTIMIZATION NOTE: we could bail out here if `new_set.len() == 0`. There
+		// isn't much left to do. Yet, re-arranging the code would require duplicating
+		// the slashing of exposed candidates, cleaning any previous members, and so on.
+		// For now, in favor of readability and veracity, we keep it simple.
+
+		// split new set into winners and runners up.
+		let split_point = desired_seats.min(new_set_with_stake.len());
+		let mut new_members_sorted_by_id =
+			new_set_with_stake.drain(..split_point).collect::<Vec<_>>();
+		new_members_sorted_by_id.sort_by(|i, j| i.0.cmp(&j.0));
+
+		// all the rest will be runners-up
+		new_set_with_stake.reverse();
+		let new_runners_up_sorted_by_rank = new_set_with_stake;
+		let mut new_runners_up_ids_sorted = new_runners_up_sorted_by_rank
+			.iter()
+			.map(|(r, _)| r.clone())
+			.collect::<Vec<_>>();
+		new_runners_up_ids_sorted.sort();
+
+		// Now we select a prime member using a [Borda
+		// count](https://en.wikipedia.org/wiki/Borda_count). We weigh everyone's vote for
+		// that new member by a multiplier based on the order of the votes. i.e. the
+		// first person a voter votes for gets a 16x multiplier, the next person gets a
+		// 15x multiplier, an so on... (assuming `T::MaxVotesPerVoter` = 16)
+		let mut prime_votes = new_members_sorted_by_id
+			.iter()
+			.map(|c| (&c.0, BalanceOf::<T>::zero()))
+			.collect::<Vec<_>>();
+		for (_, stake, votes) in voters_and_stakes.into_iter() {
+			for (vote_multiplier, who) in
+				votes.iter().enumerate().map(|(vote_position, who)| {
+					((T::MaxVotesPerVoter::get() as usize - vote_position) as u32, who)
+				}) {
+				if let Ok(i) = prime_votes.binary_search_by_key(&who, |k| k.0) {
+					prime_votes[i].1 = prime_votes[i]
+						.1
+						.saturating_add(stake.saturating_mul(vote_multiplier.into()));
+				}
+			}
+		}
+		// We then select the new member with the highest weighted stake. In the case of
+		// a tie, the last person in the list with the tied score is selected. This is
+		// the person with the "highest" account id based on the sort above.
+		let prime = prime_votes.into_iter().max_by_key(|x| x.1).map(|x| x.0.clone());
+
+		// new_members_sorted_by_id is sorted by account id.
+		let new_members_ids_sorted = new_members_sorted_by_id
+			.iter()
+			.map(|(m, _)| m.clone())
+			.collect::<Vec<T::AccountId>>();
+
+		// report member changes. We compute diff because we need the outgoing list.
+		let (incoming, outgoing) = T::ChangeMembers::compute_members_diff_sorted(
+			&new_members_ids_sorted,
+			&old_members_ids_sorted,
+		);
+		T::ChangeMembers::change_members_sorted(
+			&incoming,
+			&outgoing,
+			&new_members_ids_sorted,
+		);
+		T::ChangeMembers::set_prime(prime);
+
+		// write final values to storage.
+		let deposit_of_candidate = |x: &T::AccountId| -> BalanceOf<T> {
+			// defensive-only. This closure is used against the new members and new
+			// runners-up, both of which are phragmen winners and thus must have
+			// deposit.
+			candidates_and_deposit
+				.iter()
+				.find_map(|(c, d)| if c == x { Some(*d) } else { None })
+				.defensive_unwrap_or_default()
+		};
+		// fetch deposits from the one recorded one. This will make sure that a
+		// candidate who submitted candidacy before a change to candidacy deposit will
+		// have the correct amount recorded.
+		<Members<T>>::put(
+			new_members_sorted_by_id
+				.iter()
+				.map(|(who, stake)| SeatHolder {
+					deposit: deposit_of_candidate(who),
+					who: who.clone(),
+					stake: *stake,
+				})
+				.collect::<Vec<_>>(),
+		);
+		<RunnersUp<T>>::put(
+			new_runners_up_sorted_by_rank
+				.into_iter()
+				.map(|(who, stake)| SeatHolder {
+					deposit: deposit_of_candidate(&who),
+					who,
+					stake,
+				})
+				.collect::<Vec<_>>(),
+		);
+
+		// clean candidates.
+		<Candidates<T>>::kill();
+
+		Self::deposit_event(Event::NewTerm { new_members: new_members_sorted_by_id });
+		<ElectionRounds<T>>::mutate(|v| *v += 1);
+	}
 }
 
 impl<T: Config> Contains<T::AccountId> for Pallet<T> {
@@ -1282,7 +1580,9 @@ mod tests {
 	use frame_support::{construct_runtime, ord_parameter_types, traits::EitherOfDiverse};
 	use frame_system::{EnsureRoot, EnsureSignedBy, EnsureSigned};
 
-	use crate::{Candidates, Config, Error, Renouncing, SeatHolder, Voter};
+	use crate::{
+		Candidates, Config, CountingMode, Error, Renouncing, RunoffBallots, SeatHolder, Voter,
+	};
 	use frame_support::{assert_noop, assert_ok};
 
 	use frame_support::traits::{AsEnsureOriginWithArg, OnInitialize};
@@ -1472,6 +1772,7 @@ mod tests {
 		pub const TOTALLLM: u64 = 70000000u64;
 		pub const PRERELEASELLM: u64 = 7000000u64;
 		pub const CitizenshipMinimum: u64 = 5000u64;
+		pub const EResidentMinimum: u64 = 1000u64;
 		pub const UnlockFactor: Permill = Permill::from_percent(10);
 		pub const AssetId: u32 = 1;
 		pub const AssetName: &'static str = "LiberTest Merit";
@@ -1488,13 +1789,24 @@ mod tests {
 		type TotalSupply = TOTALLLM;
 		type PreReleasedAmount = PRERELEASELLM;
 		type CitizenshipMinimumPooledLLM = CitizenshipMinimum;
+		type EResidentMinimumPooledLLM = EResidentMinimum;
 		type UnlockFactor = UnlockFactor;
 		type AssetId = AssetId;
 		type AssetName = AssetName;
 		type AssetSymbol = AssetSymbol;
 		type InflationEventInterval = InflationEventInterval;
 		type InflationEventReleaseFactor = InflationEventReleaseFactor;
+		type UnpoolCooldown = ConstU64<0>;
+		type MaxUnpoolsPerBlock = ConstU32<1>;
+		type MaxDelegationDepth = ConstU32<1>;
+		type CitizenshipValidity = ConstU64<0>;
+		type LLMSpendApproveOrigin = EnsureRoot<u64>;
+		type LLMSpendProposalBond = ();
+		type LLMSpendProposalBondMinimum = ();
+		type LLMSpendPeriod = ();
+		type MaxLLMSpendApprovals = ConstU32<0>;
 		type OnLLMPoliticsUnlock = ();
+		type OnCitizenshipChange = ();
 		type SenateOrigin = EnsureRoot<u64>;
 		type WeightInfo = ();
 	type MaxCourts = ConstU32<1>;
@@ -1582,6 +1894,8 @@ mod tests {
 		type MaxVoters = PhragmenMaxVoters;
 		type MaxVotesPerVoter = ConstU32<16>;
 		type MaxCandidates = PhragmenMaxCandidates;
+		type CountingModeOrigin = EnsureRoot<u64>;
+		type RunoffBallotsPerBlock = ConstU32<2>;
 	}
 
 	pub struct ExtBuilder {
@@ -3433,4 +3747,131 @@ mod tests {
 			assert_ok!(Elections::clean_defunct_voters(RuntimeOrigin::root(), 4, 2));
 		})
 	}
+
+	#[test]
+	fn set_counting_mode_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(Elections::counting_mode(), CountingMode::Phragmen);
+			assert_noop!(
+				Elections::set_counting_mode(
+					RuntimeOrigin::signed(1),
+					CountingMode::InstantRunoff
+				),
+				sp_runtime::DispatchError::BadOrigin,
+			);
+
+			assert_ok!(Elections::set_counting_mode(
+				RuntimeOrigin::root(),
+				CountingMode::InstantRunoff
+			));
+			assert_eq!(Elections::counting_mode(), CountingMode::InstantRunoff);
+			System::assert_last_event(RuntimeEvent::Elections(super::Event::CountingModeSet {
+				mode: CountingMode::InstantRunoff,
+			}));
+		});
+	}
+
+	#[test]
+	fn instant_runoff_counts_across_blocks() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(Elections::set_counting_mode(
+				RuntimeOrigin::root(),
+				CountingMode::InstantRunoff
+			));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(5)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(4)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(3)));
+
+			assert_ok!(vote(RuntimeOrigin::signed(2), vec![5], 20));
+			assert_ok!(vote(RuntimeOrigin::signed(4), vec![4, 5], 15));
+			assert_ok!(vote(RuntimeOrigin::signed(3), vec![3], 30));
+
+			System::set_block_number(5);
+			Elections::on_initialize(System::block_number());
+			System::assert_last_event(RuntimeEvent::Elections(super::Event::RunoffStarted {
+				candidates: 3,
+				ballots: 3,
+			}));
+			assert!(Elections::members().is_empty());
+
+			// first pass takes two blocks, 4 has the lowest backing
+			System::set_block_number(6);
+			Elections::on_initialize(System::block_number());
+			assert_eq!(Elections::runoff().unwrap().next_ballot, 2);
+			System::set_block_number(7);
+			Elections::on_initialize(System::block_number());
+			System::assert_last_event(RuntimeEvent::Elections(
+				super::Event::CandidatesEliminated { candidates: vec![4] },
+			));
+			assert!(Elections::members().is_empty());
+
+			// second pass, vote of 4 goes to 5
+			System::set_block_number(8);
+			Elections::on_initialize(System::block_number());
+			System::set_block_number(9);
+			Elections::on_initialize(System::block_number());
+
+			assert_eq!(members_and_stake(), vec![(3, 30), (5, 35)]);
+			assert!(Elections::runoff().is_none());
+			assert_eq!(RunoffBallots::<Test>::iter().count(), 0);
+			assert!(candidate_ids().is_empty());
+			assert_eq!(Elections::election_rounds(), 1);
+		});
+	}
+
+	#[test]
+	fn instant_runoff_eliminates_unbacked_candidates_together() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(Elections::set_counting_mode(
+				RuntimeOrigin::root(),
+				CountingMode::InstantRunoff
+			));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(5)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(4)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(3)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(2)));
+
+			assert_ok!(vote(RuntimeOrigin::signed(5), vec![5], 50));
+			assert_ok!(vote(RuntimeOrigin::signed(4), vec![4], 40));
+
+			for n in 5..=6 {
+				System::set_block_number(n);
+				Elections::on_initialize(System::block_number());
+			}
+			System::assert_has_event(RuntimeEvent::Elections(
+				super::Event::CandidatesEliminated { candidates: vec![2, 3] },
+			));
+
+			System::set_block_number(7);
+			Elections::on_initialize(System::block_number());
+			assert_eq!(members_and_stake(), vec![(4, 40), (5, 50)]);
+			assert!(Elections::runoff().is_none());
+		});
+	}
+
+	#[test]
+	fn candidacy_cannot_change_during_runoff() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(Elections::set_counting_mode(
+				RuntimeOrigin::root(),
+				CountingMode::InstantRunoff
+			));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(5)));
+			assert_ok!(submit_candidacy(RuntimeOrigin::signed(4)));
+			assert_ok!(vote(RuntimeOrigin::signed(5), vec![5], 50));
+
+			System::set_block_number(5);
+			Elections::on_initialize(System::block_number());
+			assert!(Elections::runoff().is_some());
+
+			assert_noop!(
+				submit_candidacy(RuntimeOrigin::signed(3)),
+				Error::<Test>::RunoffInProgress
+			);
+			assert_noop!(
+				Elections::renounce_candidacy(RuntimeOrigin::signed(4), Renouncing::Candidate(2)),
+				Error::<Test>::RunoffInProgress
+			);
+		});
+	}
 }
@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Functions marked NOT BENCHMARKED were added after this run and are
+//! estimated. Replace them with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_elections_phragmen` run on
+//! reference hardware.
 
 // Executed Command:
 // ./target/production/substrate
@@ -64,6 +69,10 @@ pub trait WeightInfo {
 	fn remove_member_with_replacement() -> Weight;
 	fn clean_defunct_voters(v: u32, d: u32, ) -> Weight;
 	fn election_phragmen(c: u32, v: u32, e: u32, ) -> Weight;
+	fn set_counting_mode() -> Weight;
+	fn start_runoff(c: u32, v: u32, e: u32, ) -> Weight;
+	fn runoff_count(b: u32, c: u32, ) -> Weight;
+	fn finish_runoff(c: u32, v: u32, ) -> Weight;
 }
 
 /// Weights for pallet_elections_phragmen using the Substrate node and recommended hardware.
@@ -322,6 +331,96 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 12).saturating_mul(e.into()))
 			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(v.into()))
 	}
+	/// Storage: Elections ElectionCountingMode (r:0 w:1)
+	/// Proof Skipped: Elections ElectionCountingMode (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_counting_mode() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(7_804_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Elections Candidates (r:1 w:0)
+	/// Proof Skipped: Elections Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Members (r:1 w:0)
+	/// Proof Skipped: Elections Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunnersUp (r:1 w:0)
+	/// Proof Skipped: Elections RunnersUp (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Voting (r:513 w:0)
+	/// Proof Skipped: Elections Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffBallots (r:0 w:512)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffState (r:0 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 512]`.
+	/// The range of component `e` is `[512, 8192]`.
+	fn start_runoff(c: u32, v: u32, e: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(98_107_000, 13612)
+			.saturating_add(Weight::from_parts(312_455, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(5_127_618, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(21_603, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 12).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(v.into()))
+	}
+	/// Storage: Elections RunoffState (r:1 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunoffBallots (r:500 w:0)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Llm LLMPolitics (r:1 w:0)
+	/// Proof: Llm LLMPolitics (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 500]`.
+	/// The range of component `c` is `[1, 64]`.
+	fn runoff_count(b: u32, c: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(18_903_000, 3646)
+			.saturating_add(Weight::from_parts(8_061_532, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(402_117, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 3638).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 48).saturating_mul(c.into()))
+	}
+	/// Storage: Elections RunoffBallots (r:512 w:512)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffState (r:0 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Members (r:1 w:1)
+	/// Proof Skipped: Elections Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunnersUp (r:1 w:1)
+	/// Proof Skipped: Elections RunnersUp (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Candidates (r:0 w:1)
+	/// Proof Skipped: Elections Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Proposals (r:1 w:0)
+	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:64 w:64)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Elections ElectionRounds (r:1 w:1)
+	/// Proof Skipped: Elections ElectionRounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Members (r:0 w:1)
+	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Prime (r:0 w:1)
+	/// Proof Skipped: Council Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 512]`.
+	fn finish_runoff(c: u32, v: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(72_088_000, 16448)
+			.saturating_add(Weight::from_parts(3_918_540, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(6_440_011, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 3638).saturating_mul(v.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -579,4 +678,94 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 12).saturating_mul(e.into()))
 			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(v.into()))
 	}
+	/// Storage: Elections ElectionCountingMode (r:0 w:1)
+	/// Proof Skipped: Elections ElectionCountingMode (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_counting_mode() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(7_804_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Elections Candidates (r:1 w:0)
+	/// Proof Skipped: Elections Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Members (r:1 w:0)
+	/// Proof Skipped: Elections Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunnersUp (r:1 w:0)
+	/// Proof Skipped: Elections RunnersUp (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Voting (r:513 w:0)
+	/// Proof Skipped: Elections Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffBallots (r:0 w:512)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffState (r:0 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 512]`.
+	/// The range of component `e` is `[512, 8192]`.
+	fn start_runoff(c: u32, v: u32, e: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(98_107_000, 13612)
+			.saturating_add(Weight::from_parts(312_455, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(5_127_618, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(21_603, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 12).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(v.into()))
+	}
+	/// Storage: Elections RunoffState (r:1 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunoffBallots (r:500 w:0)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Llm LLMPolitics (r:1 w:0)
+	/// Proof: Llm LLMPolitics (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 500]`.
+	/// The range of component `c` is `[1, 64]`.
+	fn runoff_count(b: u32, c: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(18_903_000, 3646)
+			.saturating_add(Weight::from_parts(8_061_532, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(402_117, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 3638).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 48).saturating_mul(c.into()))
+	}
+	/// Storage: Elections RunoffBallots (r:512 w:512)
+	/// Proof Skipped: Elections RunoffBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Elections RunoffState (r:0 w:1)
+	/// Proof Skipped: Elections RunoffState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Members (r:1 w:1)
+	/// Proof Skipped: Elections Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections RunnersUp (r:1 w:1)
+	/// Proof Skipped: Elections RunnersUp (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Elections Candidates (r:0 w:1)
+	/// Proof Skipped: Elections Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Proposals (r:1 w:0)
+	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:64 w:64)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Elections ElectionRounds (r:1 w:1)
+	/// Proof Skipped: Elections ElectionRounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Members (r:0 w:1)
+	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council Prime (r:0 w:1)
+	/// Proof Skipped: Council Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 512]`.
+	fn finish_runoff(c: u32, v: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(72_088_000, 16448)
+			.saturating_add(Weight::from_parts(3_918_540, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(6_440_011, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 3638).saturating_mul(v.into()))
+	}
 }
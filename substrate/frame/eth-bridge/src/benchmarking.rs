@@ -0,0 +1,91 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as EthBridge;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	traits::{fungible::Mutate, EnsureOrigin, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_core::{crypto::KeyTypeId, ecdsa, H160, H256};
+use sp_runtime::traits::Saturating;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"ethb");
+
+fn attestor_keys<T: Config>(count: u32) -> Vec<(ecdsa::Public, H160)> {
+	(0..count)
+		.map(|_| {
+			let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+			let hash = H256::repeat_byte(1);
+			let signature =
+				sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, hash.as_fixed_bytes())
+					.unwrap();
+			(public, EthBridge::<T>::recover_signer(&hash, &signature).unwrap())
+		})
+		.collect()
+}
+
+fn activate<T: Config>(attestors: &[(ecdsa::Public, H160)]) {
+	let addresses: Vec<H160> = attestors.iter().map(|(_, address)| *address).collect();
+	let addresses: BoundedVec<_, _> = addresses.try_into().unwrap();
+	Attestors::<T>::put(addresses);
+	Threshold::<T>::put(attestors.len() as u32);
+	State::<T>::put(BridgeState::Active);
+}
+
+benchmarks! {
+	lock {
+		activate::<T>(&[]);
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount = T::MinimumTransfer::get();
+		T::Token::set_balance(&who, amount.saturating_mul(2u32.into()));
+	}: _(RawOrigin::Signed(who), H160::repeat_byte(1), amount)
+	verify {
+		assert_eq!(EthBridge::<T>::next_nonce(), 1);
+	}
+
+	redeem {
+		let s in 1 .. T::MaxAttestors::get();
+		let attestors = attestor_keys::<T>(s);
+		activate::<T>(&attestors);
+		let amount = T::MinimumTransfer::get();
+		T::Token::set_balance(&EthBridge::<T>::account_id(), amount.saturating_mul(2u32.into()));
+
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let redemption = Redemption { burn_id: H256::repeat_byte(1), recipient, amount };
+		let hash = EthBridge::<T>::redemption_hash(&redemption);
+		let signatures: Vec<ecdsa::Signature> = attestors
+			.iter()
+			.map(|(public, _)| {
+				sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, public, hash.as_fixed_bytes()).unwrap()
+			})
+			.collect();
+		let signatures: BoundedVec<_, _> = signatures.try_into().unwrap();
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), redemption, signatures)
+	verify {
+		assert!(EthBridge::<T>::redeemed(H256::repeat_byte(1)).is_some());
+	}
+
+	set_attestors {
+		let a in 1 .. T::MaxAttestors::get();
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attestors: Vec<H160> = (0..a).map(|i| H160::from_low_u64_be(i as u64 + 1)).collect();
+		let attestors: BoundedVec<_, _> = attestors.try_into().unwrap();
+	}: _<T::RuntimeOrigin>(origin, attestors, a)
+	verify {
+		assert_eq!(EthBridge::<T>::threshold(), a);
+	}
+
+	set_state {
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, BridgeState::Active)
+	verify {
+		assert_eq!(EthBridge::<T>::state(), BridgeState::Active);
+	}
+}
+
+impl_benchmark_test_suite!(EthBridge, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,443 @@
+//! # Liberland Ethereum Bridge Pallet
+//!
+//! ## Overview
+//!
+//! Ethereum Bridge pallet moves a Liberland token (LLM in the runtime) to its
+//! ERC-20 counterpart on Ethereum and back. Bridge is secured by a set of
+//! attestors - Ethereum keys controlled by independent parties - out of which
+//! `Threshold` have to agree on every transfer.
+//!
+//! ### Liberland -> Ethereum
+//!
+//! 1. User calls `lock`. Tokens are transferred to bridge's account and
+//!    `MintAuthorized` is emitted with a `MintAuthorization` and its hash.
+//! 2. Attestors watch for `MintAuthorized`, sign the hash and submit the
+//!    signatures to the ERC-20 contract, which mints the tokens. Contract
+//!    uses `nonce` for replay protection.
+//!
+//! ### Ethereum -> Liberland
+//!
+//! 1. User burns tokens on Ethereum.
+//! 2. Attestors sign a `Redemption` hash for the burn.
+//! 3. Anyone calls `redeem` with at least `Threshold` signatures. Pallet
+//!    verifies them, marks the burn as redeemed, so it can't be replayed, and
+//!    releases the tokens from bridge's account.
+//!
+//! ### Message hashes
+//!
+//! Hashes are keccak256 of tightly packed (like Solidity's
+//! `abi.encodePacked`) fields, so that they are easy to recompute on
+//! Ethereum:
+//!
+//! * domain: `keccak256(genesis_hash ++ pallet_id)`
+//! * mint: `keccak256(domain ++ "mint" ++ uint64 nonce ++ address recipient ++
+//!   uint128 amount)`
+//! * redeem: `keccak256(domain ++ "redeem" ++ bytes32 burn_id ++ bytes32
+//!   recipient ++ uint128 amount)`
+//!
+//! Signatures are 65-byte secp256k1 `r ++ s ++ v` signatures of the hash,
+//! without the `"\x19Ethereum Signed Message"` prefix.
+//!
+//! ### Safety limits
+//!
+//! * locks and redemptions are each limited to `EpochLimit` tokens per
+//!   `EpochLength` blocks,
+//! * total amount locked in the bridge is limited to `MaxTotalLocked`,
+//! * `GovernanceOrigin` can stop the bridge at any time with `set_state`.
+//!   Bridge starts stopped.
+//!
+//! ## Pallet Config
+//!
+//! * `Token` - bridged token
+//! * `PalletId` - PalletId used to derive bridge's account, which holds
+//!   locked tokens
+//! * `GovernanceOrigin` - origin allowed to change attestors and stop the
+//!   bridge
+//! * `MaxAttestors` - maximum number of attestors
+//! * `MinimumTransfer` - minimum amount of tokens locked or redeemed at once
+//! * `MaxTotalLocked` - maximum amount of tokens held by bridge's account
+//! * `EpochLength` - length of rate-limiting epoch in blocks
+//! * `EpochLimit` - maximum amount of tokens locked or redeemed in a single
+//!   epoch
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `lock`: Lock tokens to be minted on Ethereum
+//! * `redeem`: Release tokens burned on Ethereum
+//! * `set_attestors`: Change attestors and threshold
+//! * `set_state`: Start or stop the bridge
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::fungible;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Token as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type RedemptionOf<T> = Redemption<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::{ecdsa, H160, H256};
+	use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		SaturatedConversion,
+	};
+	use sp_std::prelude::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Bridged token
+		type Token: Mutate<Self::AccountId>;
+
+		/// PalletId used to derive bridge's account, which holds locked tokens
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin allowed to change attestors and stop the bridge
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of attestors
+		#[pallet::constant]
+		type MaxAttestors: Get<u32>;
+
+		/// Minimum amount of tokens locked or redeemed at once
+		#[pallet::constant]
+		type MinimumTransfer: Get<BalanceOf<Self>>;
+
+		/// Maximum amount of tokens held by bridge's account
+		#[pallet::constant]
+		type MaxTotalLocked: Get<BalanceOf<Self>>;
+
+		/// Length of rate-limiting epoch in blocks
+		#[pallet::constant]
+		type EpochLength: Get<BlockNumberFor<Self>>;
+
+		/// Maximum amount of tokens locked or redeemed in a single epoch
+		#[pallet::constant]
+		type EpochLimit: Get<BalanceOf<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Bridge is stopped
+		BridgeStopped,
+		/// Amount is below `MinimumTransfer`
+		TooSmallAmount,
+		/// Lock would put more than `MaxTotalLocked` tokens in the bridge
+		TooMuchLocked,
+		/// `EpochLimit` for this direction was reached
+		EpochLimitReached,
+		/// Burn was already redeemed
+		AlreadyRedeemed,
+		/// Signature isn't a valid signature of a current attestor
+		InvalidSignature,
+		/// Same attestor signed more than once
+		DuplicateSignature,
+		/// Less than `Threshold` valid signatures
+		NotEnoughSignatures,
+		/// Attestors contain duplicates or threshold is zero or larger than
+		/// number of attestors
+		InvalidAttestors,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Tokens locked, attestors should authorize mint on Ethereum
+		MintAuthorized {
+			who: T::AccountId,
+			authorization: MintAuthorization<BalanceOf<T>>,
+			hash: H256,
+		},
+		/// Tokens burned on Ethereum released
+		Redeemed { burn_id: H256, recipient: T::AccountId, amount: BalanceOf<T> },
+		/// Attestors or threshold changed
+		AttestorsSet { attestors: Vec<H160>, threshold: u32 },
+		/// Bridge started or stopped
+		StateChanged { state: BridgeState },
+	}
+
+	/// Ethereum addresses of attestors
+	#[pallet::storage]
+	#[pallet::getter(fn attestors)]
+	pub type Attestors<T: Config> = StorageValue<_, BoundedVec<H160, T::MaxAttestors>, ValueQuery>;
+
+	/// Number of attestor signatures required for a redemption
+	#[pallet::storage]
+	#[pallet::getter(fn threshold)]
+	pub type Threshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Whether the bridge is active. Starts stopped.
+	#[pallet::storage]
+	#[pallet::getter(fn state)]
+	pub type State<T: Config> = StorageValue<_, BridgeState, ValueQuery>;
+
+	/// Nonce of the next lock
+	#[pallet::storage]
+	#[pallet::getter(fn next_nonce)]
+	pub type NextNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Burns that were already redeemed and block of redemption
+	#[pallet::storage]
+	#[pallet::getter(fn redeemed)]
+	pub type Redeemed<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, BlockNumberFor<T>, OptionQuery>;
+
+	/// Current epoch and amount transferred in it, per direction
+	#[pallet::storage]
+	#[pallet::getter(fn epoch_usage)]
+	pub type EpochUsage<T: Config> =
+		StorageMap<_, Twox64Concat, Direction, (BlockNumberFor<T>, BalanceOf<T>), ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock tokens to be minted on Ethereum.
+		///
+		/// The dispatch origin of this call must be Signed by the account
+		/// sending the tokens.
+		///
+		/// - `recipient`: Ethereum account receiving the tokens
+		/// - `amount`: Amount of tokens to lock
+		///
+		/// Emits `MintAuthorized`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::lock())]
+		pub fn lock(origin: OriginFor<T>, recipient: H160, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_active()?;
+			ensure!(amount >= T::MinimumTransfer::get(), Error::<T>::TooSmallAmount);
+
+			let bridge = Self::account_id();
+			let total = T::Token::balance(&bridge).saturating_add(amount);
+			ensure!(total <= T::MaxTotalLocked::get(), Error::<T>::TooMuchLocked);
+			Self::consume_epoch_limit(Direction::Lock, amount)?;
+
+			T::Token::transfer(&who, &bridge, amount, Preservation::Expendable)?;
+
+			let nonce = Self::next_nonce();
+			NextNonce::<T>::put(nonce.saturating_add(1));
+			let authorization = MintAuthorization { nonce, recipient, amount };
+			let hash = Self::mint_hash(&authorization);
+
+			Self::deposit_event(Event::MintAuthorized { who, authorization, hash });
+			Ok(())
+		}
+
+		/// Release tokens burned on Ethereum. Requires `Threshold` signatures
+		/// of distinct attestors.
+		///
+		/// The dispatch origin of this call must be Signed. Anyone can relay
+		/// a redemption.
+		///
+		/// - `redemption`: Burn being redeemed
+		/// - `signatures`: Attestors' signatures of the redemption hash
+		///
+		/// Emits `Redeemed`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::redeem(signatures.len() as u32))]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			redemption: RedemptionOf<T>,
+			signatures: BoundedVec<ecdsa::Signature, T::MaxAttestors>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_active()?;
+			let Redemption { burn_id, recipient, amount } = redemption.clone();
+			ensure!(!Redeemed::<T>::contains_key(burn_id), Error::<T>::AlreadyRedeemed);
+			ensure!(amount >= T::MinimumTransfer::get(), Error::<T>::TooSmallAmount);
+
+			let hash = Self::redemption_hash(&redemption);
+			Self::verify_signatures(&hash, &signatures)?;
+			Self::consume_epoch_limit(Direction::Redeem, amount)?;
+
+			Redeemed::<T>::insert(burn_id, frame_system::Pallet::<T>::block_number());
+			T::Token::transfer(&Self::account_id(), &recipient, amount, Preservation::Expendable)?;
+
+			Self::deposit_event(Event::Redeemed { burn_id, recipient, amount });
+			Ok(())
+		}
+
+		/// Change attestors and number of signatures required for a
+		/// redemption.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// - `attestors`: Ethereum addresses of attestors
+		/// - `threshold`: Number of signatures required, at least 1 and at
+		///   most number of attestors
+		///
+		/// Emits `AttestorsSet`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_attestors(attestors.len() as u32))]
+		pub fn set_attestors(
+			origin: OriginFor<T>,
+			attestors: BoundedVec<H160, T::MaxAttestors>,
+			threshold: u32,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				threshold > 0 && threshold as usize <= attestors.len(),
+				Error::<T>::InvalidAttestors
+			);
+			let mut sorted = attestors.to_vec();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(sorted.len() == attestors.len(), Error::<T>::InvalidAttestors);
+
+			Attestors::<T>::put(&attestors);
+			Threshold::<T>::put(threshold);
+
+			Self::deposit_event(Event::AttestorsSet { attestors: attestors.into(), threshold });
+			Ok(())
+		}
+
+		/// Start or stop the bridge. Stopped bridge rejects all locks and
+		/// redemptions.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// - `state`: New state of the bridge
+		///
+		/// Emits `StateChanged`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_state())]
+		pub fn set_state(origin: OriginFor<T>, state: BridgeState) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			State::<T>::put(state);
+
+			Self::deposit_event(Event::StateChanged { state });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding locked tokens
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Separates messages of this bridge from other chains and bridges
+		pub fn domain() -> [u8; 32] {
+			let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			let mut data = genesis.encode();
+			data.extend_from_slice(&T::PalletId::get().0);
+			keccak_256(&data)
+		}
+
+		/// Hash signed by attestors to authorize mint on Ethereum
+		pub fn mint_hash(authorization: &MintAuthorization<BalanceOf<T>>) -> H256 {
+			let amount: u128 = authorization.amount.saturated_into();
+			let mut data = Self::domain().to_vec();
+			data.extend_from_slice(b"mint");
+			data.extend_from_slice(&authorization.nonce.to_be_bytes());
+			data.extend_from_slice(authorization.recipient.as_bytes());
+			data.extend_from_slice(&amount.to_be_bytes());
+			keccak_256(&data).into()
+		}
+
+		/// Hash signed by attestors to authorize redemption on Liberland
+		pub fn redemption_hash(redemption: &RedemptionOf<T>) -> H256 {
+			let amount: u128 = redemption.amount.saturated_into();
+			let mut data = Self::domain().to_vec();
+			data.extend_from_slice(b"redeem");
+			data.extend_from_slice(redemption.burn_id.as_bytes());
+			data.extend_from_slice(&redemption.recipient.encode());
+			data.extend_from_slice(&amount.to_be_bytes());
+			keccak_256(&data).into()
+		}
+
+		/// Ethereum address that signed `hash`
+		pub fn recover_signer(hash: &H256, signature: &ecdsa::Signature) -> Option<H160> {
+			let mut sig = [0u8; 65];
+			sig.copy_from_slice(signature.as_ref());
+			// accept Ethereum-style recovery ids too
+			if sig[64] >= 27 {
+				sig[64] -= 27;
+			}
+			let public = secp256k1_ecdsa_recover(&sig, hash.as_fixed_bytes()).ok()?;
+			Some(H160::from_slice(&keccak_256(&public)[12..]))
+		}
+
+		fn ensure_active() -> DispatchResult {
+			ensure!(Self::state() == BridgeState::Active, Error::<T>::BridgeStopped);
+			Ok(())
+		}
+
+		fn verify_signatures(hash: &H256, signatures: &[ecdsa::Signature]) -> DispatchResult {
+			let attestors = Self::attestors();
+			let mut signers = Vec::with_capacity(signatures.len());
+			for signature in signatures {
+				let signer =
+					Self::recover_signer(hash, signature).ok_or(Error::<T>::InvalidSignature)?;
+				ensure!(attestors.contains(&signer), Error::<T>::InvalidSignature);
+				ensure!(!signers.contains(&signer), Error::<T>::DuplicateSignature);
+				signers.push(signer);
+			}
+			let threshold = Self::threshold();
+			ensure!(
+				threshold > 0 && signers.len() as u32 >= threshold,
+				Error::<T>::NotEnoughSignatures
+			);
+			Ok(())
+		}
+
+		fn consume_epoch_limit(direction: Direction, amount: BalanceOf<T>) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let epoch = now / T::EpochLength::get();
+			let (last_epoch, used) = Self::epoch_usage(direction);
+			let used = if last_epoch == epoch { used } else { Zero::zero() };
+			let used = used.saturating_add(amount);
+			ensure!(used <= T::EpochLimit::get(), Error::<T>::EpochLimitReached);
+			EpochUsage::<T>::insert(direction, (epoch, used));
+			Ok(())
+		}
+	}
+}
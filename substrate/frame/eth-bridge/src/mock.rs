@@ -0,0 +1,139 @@
+#![cfg(test)]
+pub use crate as pallet_eth_bridge;
+
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::EnsureSignedBy;
+use pallet_balances::AccountData;
+use sp_core::{ecdsa, ConstU16, Pair, H160, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	BoundedVec, BuildStorage,
+};
+
+ord_parameter_types! {
+	pub const Governance: u64 = 1;
+}
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		EthBridge: pallet_eth_bridge,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub const EthBridgePalletId: PalletId = PalletId(*b"lib/ethb");
+}
+
+impl pallet_eth_bridge::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Token = Balances;
+	type PalletId = EthBridgePalletId;
+	type GovernanceOrigin = EnsureSignedBy<Governance, u64>;
+	type MaxAttestors = ConstU32<5>;
+	type MinimumTransfer = ConstU64<10>;
+	type MaxTotalLocked = ConstU64<500>;
+	type EpochLength = ConstU64<10>;
+	type EpochLimit = ConstU64<200>;
+	type WeightInfo = ();
+}
+
+pub fn bridge() -> u64 {
+	EthBridgePalletId::get().into_account_truncating()
+}
+
+pub fn attestor(index: u8) -> ecdsa::Pair {
+	ecdsa::Pair::from_seed(&[index + 1; 32])
+}
+
+pub fn address(pair: &ecdsa::Pair) -> H160 {
+	let hash = H256::repeat_byte(1);
+	EthBridge::recover_signer(&hash, &pair.sign_prehashed(hash.as_fixed_bytes())).unwrap()
+}
+
+pub fn sign(indexes: &[u8], hash: H256) -> BoundedVec<ecdsa::Signature, ConstU32<5>> {
+	indexes
+		.iter()
+		.map(|i| attestor(*i).sign_prehashed(hash.as_fixed_bytes()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(2, 1000), (3, 1000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+	});
+	ext
+}
+
+/// Test externalities with 3 attestors, threshold 2 and active bridge
+pub fn new_active_test_ext() -> sp_io::TestExternalities {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		let attestors = (0..3).map(|i| address(&attestor(i))).collect::<Vec<_>>();
+		EthBridge::set_attestors(RuntimeOrigin::signed(1), attestors.try_into().unwrap(), 2)
+			.unwrap();
+		EthBridge::set_state(RuntimeOrigin::signed(1), crate::BridgeState::Active).unwrap();
+	});
+	ext
+}
@@ -0,0 +1,219 @@
+#![cfg(test)]
+
+use crate::{mock::*, BridgeState, Direction, Error, Event, MintAuthorization, Redemption};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H160, H256};
+use sp_runtime::{DispatchError, DispatchResult};
+
+fn redemption(burn: u8, recipient: u64, amount: u64) -> crate::RedemptionOf<Test> {
+	Redemption { burn_id: H256::repeat_byte(burn), recipient, amount }
+}
+
+fn redeem(r: crate::RedemptionOf<Test>, signers: &[u8]) -> DispatchResult {
+	let signatures = sign(signers, EthBridge::redemption_hash(&r));
+	EthBridge::redeem(RuntimeOrigin::signed(3), r, signatures)
+}
+
+#[test]
+fn bridge_starts_stopped() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EthBridge::state(), BridgeState::Stopped);
+		assert_noop!(
+			EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 10),
+			Error::<Test>::BridgeStopped
+		);
+	});
+}
+
+#[test]
+fn lock_works() {
+	new_active_test_ext().execute_with(|| {
+		let recipient = H160::repeat_byte(1);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), recipient, 100));
+		assert_eq!(Balances::free_balance(2), 900);
+		assert_eq!(Balances::free_balance(bridge()), 100);
+		assert_eq!(EthBridge::next_nonce(), 1);
+
+		let authorization = MintAuthorization { nonce: 0, recipient, amount: 100 };
+		let hash = EthBridge::mint_hash(&authorization);
+		System::assert_last_event(
+			Event::<Test>::MintAuthorized { who: 2, authorization, hash }.into(),
+		);
+
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), recipient, 100));
+		let authorization = MintAuthorization { nonce: 1, recipient, amount: 100 };
+		assert_ne!(EthBridge::mint_hash(&authorization), hash);
+	});
+}
+
+#[test]
+fn lock_enforces_limits() {
+	new_active_test_ext().execute_with(|| {
+		let recipient = H160::repeat_byte(1);
+		assert_noop!(
+			EthBridge::lock(RuntimeOrigin::signed(2), recipient, 9),
+			Error::<Test>::TooSmallAmount
+		);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), recipient, 150));
+		assert_noop!(
+			EthBridge::lock(RuntimeOrigin::signed(2), recipient, 51),
+			Error::<Test>::EpochLimitReached
+		);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), recipient, 50));
+		assert_eq!(EthBridge::epoch_usage(Direction::Lock), (0, 200));
+
+		System::set_block_number(10);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), recipient, 200));
+		System::set_block_number(20);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(3), recipient, 100));
+		assert_noop!(
+			EthBridge::lock(RuntimeOrigin::signed(3), recipient, 1),
+			Error::<Test>::TooSmallAmount
+		);
+		assert_noop!(
+			EthBridge::lock(RuntimeOrigin::signed(3), recipient, 10),
+			Error::<Test>::TooMuchLocked
+		);
+	});
+}
+
+#[test]
+fn redeem_works() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		assert_ok!(redeem(redemption(1, 4, 60), &[0, 2]));
+		assert_eq!(Balances::free_balance(4), 60);
+		assert_eq!(Balances::free_balance(bridge()), 40);
+		assert_eq!(EthBridge::redeemed(H256::repeat_byte(1)), Some(1));
+		System::assert_last_event(
+			Event::<Test>::Redeemed { burn_id: H256::repeat_byte(1), recipient: 4, amount: 60 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn redeem_accepts_ethereum_recovery_ids() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		let r = redemption(1, 4, 60);
+		let mut signatures = sign(&[0, 1], EthBridge::redemption_hash(&r));
+		for signature in signatures.iter_mut() {
+			signature.0[64] += 27;
+		}
+		assert_ok!(EthBridge::redeem(RuntimeOrigin::signed(3), r, signatures));
+	});
+}
+
+#[test]
+fn redeem_cant_be_replayed() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		assert_ok!(redeem(redemption(1, 4, 20), &[0, 1]));
+		assert_noop!(redeem(redemption(1, 4, 20), &[0, 1]), Error::<Test>::AlreadyRedeemed);
+		assert_noop!(redeem(redemption(1, 5, 30), &[1, 2]), Error::<Test>::AlreadyRedeemed);
+	});
+}
+
+#[test]
+fn redeem_requires_threshold_of_distinct_attestors() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		assert_noop!(redeem(redemption(1, 4, 20), &[0]), Error::<Test>::NotEnoughSignatures);
+		assert_noop!(redeem(redemption(1, 4, 20), &[]), Error::<Test>::NotEnoughSignatures);
+		assert_noop!(redeem(redemption(1, 4, 20), &[1, 1]), Error::<Test>::DuplicateSignature);
+		assert_noop!(redeem(redemption(1, 4, 20), &[0, 3]), Error::<Test>::InvalidSignature);
+	});
+}
+
+#[test]
+fn redeem_rejects_signatures_of_other_message() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		let signed = redemption(1, 4, 20);
+		let signatures = sign(&[0, 1], EthBridge::redemption_hash(&signed));
+		assert_noop!(
+			EthBridge::redeem(RuntimeOrigin::signed(3), redemption(1, 4, 90), signatures.clone()),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			EthBridge::redeem(RuntimeOrigin::signed(3), redemption(1, 5, 20), signatures),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn redeem_enforces_epoch_limit() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 200));
+		System::set_block_number(10);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 200));
+		assert_ok!(redeem(redemption(1, 4, 150), &[0, 1]));
+		assert_noop!(redeem(redemption(2, 4, 60), &[0, 1]), Error::<Test>::EpochLimitReached);
+		System::set_block_number(20);
+		assert_ok!(redeem(redemption(2, 4, 60), &[0, 1]));
+	});
+}
+
+#[test]
+fn kill_switch_stops_redemptions() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		assert_ok!(EthBridge::set_state(RuntimeOrigin::signed(1), BridgeState::Stopped));
+		System::assert_last_event(
+			Event::<Test>::StateChanged { state: BridgeState::Stopped }.into(),
+		);
+		assert_noop!(redeem(redemption(1, 4, 20), &[0, 1]), Error::<Test>::BridgeStopped);
+		assert_noop!(
+			EthBridge::set_state(RuntimeOrigin::signed(2), BridgeState::Active),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_attestors_validates_input() {
+	new_test_ext().execute_with(|| {
+		let a = address(&attestor(0));
+		let b = address(&attestor(1));
+		assert_noop!(
+			EthBridge::set_attestors(RuntimeOrigin::signed(2), vec![a].try_into().unwrap(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EthBridge::set_attestors(RuntimeOrigin::signed(1), vec![a, b].try_into().unwrap(), 0),
+			Error::<Test>::InvalidAttestors
+		);
+		assert_noop!(
+			EthBridge::set_attestors(RuntimeOrigin::signed(1), vec![a, b].try_into().unwrap(), 3),
+			Error::<Test>::InvalidAttestors
+		);
+		assert_noop!(
+			EthBridge::set_attestors(RuntimeOrigin::signed(1), vec![a, a].try_into().unwrap(), 1),
+			Error::<Test>::InvalidAttestors
+		);
+		assert_ok!(EthBridge::set_attestors(
+			RuntimeOrigin::signed(1),
+			vec![a, b].try_into().unwrap(),
+			2
+		));
+		assert_eq!(EthBridge::attestors().to_vec(), vec![a, b]);
+		assert_eq!(EthBridge::threshold(), 2);
+	});
+}
+
+#[test]
+fn replaced_attestors_cant_redeem() {
+	new_active_test_ext().execute_with(|| {
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), H160::repeat_byte(1), 100));
+		let attestors = vec![address(&attestor(3)), address(&attestor(4))];
+		assert_ok!(EthBridge::set_attestors(
+			RuntimeOrigin::signed(1),
+			attestors.try_into().unwrap(),
+			2
+		));
+		assert_noop!(redeem(redemption(1, 4, 20), &[0, 1]), Error::<Test>::InvalidSignature);
+		assert_ok!(redeem(redemption(1, 4, 20), &[3, 4]));
+	});
+}
@@ -0,0 +1,50 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::RuntimeDebug;
+
+/// Whether the bridge accepts locks and redemptions
+#[derive(
+	Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialEq, Eq, Default,
+)]
+pub enum BridgeState {
+	/// Locks and redemptions are processed
+	Active,
+	/// Everything is stopped - kill-switch
+	#[default]
+	Stopped,
+}
+
+/// Direction of transfer, used for per-epoch rate limits
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+pub enum Direction {
+	/// Tokens locked here, to be minted on Ethereum
+	Lock,
+	/// Tokens burned on Ethereum, to be released here
+	Redeem,
+}
+
+/// Authorisation for the Ethereum ERC-20 contract to mint tokens locked on
+/// Liberland. Attestors sign `hash` and the contract checks the signatures.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+pub struct MintAuthorization<Balance> {
+	/// Lock nonce, unique per bridge. Used by the contract for replay
+	/// protection.
+	pub nonce: u64,
+	/// Ethereum account receiving minted tokens
+	pub recipient: H160,
+	/// Amount to mint
+	pub amount: Balance,
+}
+
+/// Tokens burned on Ethereum that should be released on Liberland
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+pub struct Redemption<AccountId, Balance> {
+	/// Id of the burn on Ethereum (hash of tx and log index). Each can be
+	/// redeemed only once.
+	pub burn_id: H256,
+	/// Liberland account receiving released tokens
+	pub recipient: AccountId,
+	/// Amount to release
+	pub amount: Balance,
+}
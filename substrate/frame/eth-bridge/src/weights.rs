@@ -0,0 +1,75 @@
+//! Weights for pallet_eth_bridge
+//!
+//! NOT BENCHMARKED. Estimated from fungible transfer weights plus secp256k1
+//! recovery cost per signature. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_eth_bridge` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eth_bridge.
+pub trait WeightInfo {
+	fn lock() -> Weight;
+	fn redeem(s: u32, ) -> Weight;
+	fn set_attestors(a: u32, ) -> Weight;
+	fn set_state() -> Weight;
+}
+
+/// Weights for pallet_eth_bridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn lock() -> Weight {
+		Weight::from_parts(70_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// The range of component `s` is `[1, 20]`.
+	fn redeem(s: u32, ) -> Weight {
+		Weight::from_parts(75_000_000, 6208)
+			.saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// The range of component `a` is `[1, 20]`.
+	fn set_attestors(a: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_state() -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn lock() -> Weight {
+		Weight::from_parts(70_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// The range of component `s` is `[1, 20]`.
+	fn redeem(s: u32, ) -> Weight {
+		Weight::from_parts(75_000_000, 6208)
+			.saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// The range of component `a` is `[1, 20]`.
+	fn set_attestors(a: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_state() -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
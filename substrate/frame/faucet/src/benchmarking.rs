@@ -0,0 +1,32 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Faucet;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::{fungible::Mutate, EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+	drip {
+		let faucet = Faucet::<T>::account_id();
+		T::Currency::set_balance(&faucet, BalanceOf::<T>::max_value() / 4u32.into());
+		T::LLM::set_balance(&faucet, LLMBalanceOf::<T>::max_value() / 4u32.into());
+		BlockBudget::<T>::put(1);
+		let who: T::AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::None, who.clone())
+	verify {
+		assert!(LastDrip::<T>::contains_key(&who));
+	}
+
+	set_block_budget {
+		let origin = T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, 10)
+	verify {
+		assert_eq!(Faucet::<T>::block_budget(), 10);
+	}
+}
+
+impl_benchmark_test_suite!(Faucet, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,259 @@
+//! # Liberland Faucet Pallet
+//!
+//! ## Overview
+//!
+//! Faucet pallet lets developers and CI get testnet funds without a
+//! centralized faucet service. It's meant only for the testnet runtime.
+//!
+//! * anyone can request a drip of `LLDDrip` LLD and `LLMDrip` LLM to any
+//!   account, transferred from the faucet's account,
+//! * a single account gets at most one drip per `DripPeriod` blocks,
+//! * no more than `BlockBudget` drips are paid in a single block. Budget is
+//!   set by `BudgetOrigin`, defaults to `DefaultBlockBudget`,
+//! * drips are unsigned transactions, so empty accounts can use them. Both
+//!   limits are checked by `ValidateUnsigned`, so drips breaking them never
+//!   get into the transaction pool or a block.
+//!
+//! Faucet is topped up by transferring LLD and LLM to
+//! `Pallet::account_id()`.
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - LLD currency
+//! * `LLM` - LLM currency
+//! * `PalletId` - PalletId used to derive faucet's account
+//! * `BudgetOrigin` - origin allowed to change `BlockBudget`
+//! * `LLDDrip` - LLD paid in a single drip
+//! * `LLMDrip` - LLM paid in a single drip
+//! * `DripPeriod` - minimum number of blocks between drips to one account
+//! * `DefaultBlockBudget` - `BlockBudget` used until it's changed by
+//!   `BudgetOrigin`
+//! * `UnsignedPriority` - priority of drip transactions
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `drip`: Send LLD and LLM from the faucet to an account
+//! * `set_block_budget`: Change maximum number of drips in a single block
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use frame_support::traits::fungible;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type LLMBalanceOf<T> =
+	<<T as Config>::LLM as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{fungible::Mutate, tokens::Preservation},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// LLD currency
+		type Currency: Mutate<Self::AccountId>;
+
+		/// LLM currency
+		type LLM: Mutate<Self::AccountId>;
+
+		/// PalletId used to derive faucet's account, which pays the drips
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin allowed to change `BlockBudget`
+		type BudgetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// LLD paid in a single drip
+		#[pallet::constant]
+		type LLDDrip: Get<BalanceOf<Self>>;
+
+		/// LLM paid in a single drip
+		#[pallet::constant]
+		type LLMDrip: Get<LLMBalanceOf<Self>>;
+
+		/// Minimum number of blocks between drips to a single account
+		#[pallet::constant]
+		type DripPeriod: Get<BlockNumberFor<Self>>;
+
+		/// `BlockBudget` used until it's changed by `BudgetOrigin`
+		#[pallet::constant]
+		type DefaultBlockBudget: Get<u32>;
+
+		/// Priority of drip transactions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account got a drip less than `DripPeriod` blocks ago
+		TooSoon,
+		/// `BlockBudget` drips were already paid in this block
+		BudgetExhausted,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Drip paid to `who`
+		Dripped { who: T::AccountId, lld: BalanceOf<T>, llm: LLMBalanceOf<T> },
+		/// Maximum number of drips in a single block changed
+		BlockBudgetSet { budget: u32 },
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultBlockBudget<T: Config>() -> u32 {
+		T::DefaultBlockBudget::get()
+	}
+
+	/// Maximum number of drips paid in a single block
+	#[pallet::storage]
+	#[pallet::getter(fn block_budget)]
+	pub type BlockBudget<T: Config> = StorageValue<_, u32, ValueQuery, DefaultBlockBudget<T>>;
+
+	/// Block of the last drip and number of drips paid in it
+	#[pallet::storage]
+	#[pallet::getter(fn block_drips)]
+	pub type BlockDrips<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// Block of the last drip to given account
+	#[pallet::storage]
+	#[pallet::getter(fn last_drip)]
+	pub type LastDrip<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Send `LLDDrip` LLD and `LLMDrip` LLM from the faucet to `who`.
+		///
+		/// The dispatch origin of this call must be None - drips are unsigned
+		/// transactions, validated with `ValidateUnsigned`.
+		///
+		/// - `who`: Account receiving the drip
+		///
+		/// Emits `Dripped`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::drip())]
+		pub fn drip(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_none(origin)?;
+			let (now, drips) = Self::ensure_can_drip(&who)?;
+
+			let faucet = Self::account_id();
+			let lld = T::LLDDrip::get();
+			if !lld.is_zero() {
+				T::Currency::transfer(&faucet, &who, lld, Preservation::Preserve)?;
+			}
+			let llm = T::LLMDrip::get();
+			if !llm.is_zero() {
+				T::LLM::transfer(&faucet, &who, llm, Preservation::Preserve)?;
+			}
+
+			LastDrip::<T>::insert(&who, now);
+			BlockDrips::<T>::put((now, drips.saturating_add(1)));
+
+			Self::deposit_event(Event::Dripped { who, lld, llm });
+			Ok(())
+		}
+
+		/// Change maximum number of drips paid in a single block. Zero
+		/// disables the faucet.
+		///
+		/// The dispatch origin of this call must be `BudgetOrigin`.
+		///
+		/// - `budget`: New maximum number of drips in a single block
+		///
+		/// Emits `BlockBudgetSet`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_block_budget())]
+		pub fn set_block_budget(origin: OriginFor<T>, budget: u32) -> DispatchResult {
+			T::BudgetOrigin::ensure_origin(origin)?;
+			BlockBudget::<T>::put(budget);
+
+			Self::deposit_event(Event::BlockBudgetSet { budget });
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::drip { who } = call else { return InvalidTransaction::Call.into() };
+			Self::ensure_can_drip(who).map_err(|e| match e {
+				// budget is renewed in the next block
+				Error::<T>::BudgetExhausted => InvalidTransaction::ExhaustsResources,
+				_ => InvalidTransaction::Stale,
+			})?;
+
+			ValidTransaction::with_tag_prefix("Faucet")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(who.clone())
+				.longevity(T::DripPeriod::get().try_into().unwrap_or(64_u64))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks that `who` can get a drip in the current block. Returns
+		/// current block number and number of drips already paid in it.
+		pub fn ensure_can_drip(who: &T::AccountId) -> Result<(BlockNumberFor<T>, u32), Error<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(last) = Self::last_drip(who) {
+				ensure!(now >= last.saturating_add(T::DripPeriod::get()), Error::<T>::TooSoon);
+			}
+
+			let (block, drips) = Self::block_drips();
+			let drips = if block == now { drips } else { 0 };
+			ensure!(drips < Self::block_budget(), Error::<T>::BudgetExhausted);
+			Ok((now, drips))
+		}
+
+		/// Account paying the drips
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+	}
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::{
+	traits::ValidateUnsigned,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
+
+#[test]
+fn drip_pays_lld_and_llm() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 2));
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_eq!(LLMBalances::free_balance(2), 5);
+		assert_eq!(Balances::free_balance(faucet()), 990);
+		assert_eq!(LLMBalances::free_balance(faucet()), 995);
+		assert_eq!(Faucet::last_drip(2), Some(1));
+		System::assert_last_event(Event::<Test>::Dripped { who: 2, lld: 10, llm: 5 }.into());
+	});
+}
+
+#[test]
+fn drip_is_rate_limited_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 2));
+		System::set_block_number(10);
+		assert_noop!(Faucet::drip(RuntimeOrigin::none(), 2), Error::<Test>::TooSoon);
+		System::set_block_number(11);
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 2));
+		assert_eq!(Balances::free_balance(2), 20);
+	});
+}
+
+#[test]
+fn drips_are_capped_per_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 2));
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 3));
+		assert_noop!(Faucet::drip(RuntimeOrigin::none(), 4), Error::<Test>::BudgetExhausted);
+		System::set_block_number(2);
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 4));
+		assert_eq!(Faucet::block_drips(), (2, 1));
+	});
+}
+
+#[test]
+fn drip_fails_when_faucet_is_empty() {
+	new_test_ext().execute_with(|| {
+		LLMBalances::make_free_balance_be(&faucet(), 5);
+		assert!(Faucet::drip(RuntimeOrigin::none(), 2).is_err());
+		assert_eq!(Balances::free_balance(2), 0);
+		assert_eq!(Faucet::last_drip(2), None);
+	});
+}
+
+#[test]
+fn set_block_budget_requires_budget_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Faucet::set_block_budget(RuntimeOrigin::signed(2), 10),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_block_budget_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Faucet::block_budget(), 2);
+		assert_ok!(Faucet::set_block_budget(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Faucet::block_budget(), 0);
+		System::assert_last_event(Event::<Test>::BlockBudgetSet { budget: 0 }.into());
+		assert_noop!(Faucet::drip(RuntimeOrigin::none(), 2), Error::<Test>::BudgetExhausted);
+	});
+}
+
+#[test]
+fn drip_must_be_unsigned() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Faucet::drip(RuntimeOrigin::signed(2), 2), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn validate_unsigned_rejects_drips_breaking_limits() {
+	new_test_ext().execute_with(|| {
+		let validate = |who| {
+			let call = crate::Call::drip { who };
+			Faucet::validate_unsigned(TransactionSource::External, &call)
+		};
+		assert!(validate(2).is_ok());
+
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 2));
+		assert_eq!(validate(2), Err(InvalidTransaction::Stale.into()));
+
+		assert_ok!(Faucet::drip(RuntimeOrigin::none(), 3));
+		assert_eq!(validate(4), Err(InvalidTransaction::ExhaustsResources.into()));
+		System::set_block_number(2);
+		assert!(validate(4).is_ok());
+	});
+}
@@ -0,0 +1,47 @@
+//! Weights for pallet_faucet
+//!
+//! NOT BENCHMARKED. Estimated from pallet_balances transfer weights plus this
+//! pallet's own storage accesses. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_faucet` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_faucet.
+pub trait WeightInfo {
+	fn drip() -> Weight;
+	fn set_block_budget() -> Weight;
+}
+
+/// Weights for pallet_faucet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn drip() -> Weight {
+		Weight::from_parts(95_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn set_block_budget() -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn drip() -> Weight {
+		Weight::from_parts(95_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn set_block_budget() -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ForceRecovery;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use sp_runtime::traits::StaticLookup;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+	force_recover {
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let lost: T::AccountId = account("lost", 0, SEED);
+		let rescuer: T::AccountId = account("rescuer", 0, SEED);
+		let lost_lookup = T::Lookup::unlookup(lost.clone());
+		let rescuer_lookup = T::Lookup::unlookup(rescuer.clone());
+	}: _<T::RuntimeOrigin>(origin, lost_lookup, rescuer_lookup)
+	verify {
+		assert_eq!(pallet_recovery::Pallet::<T>::proxy(&rescuer), Some(lost));
+	}
+}
+
+impl_benchmark_test_suite!(ForceRecovery, crate::mock::new_test_ext(), crate::mock::Test,);
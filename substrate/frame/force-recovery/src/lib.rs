@@ -0,0 +1,116 @@
+//! # Liberland Force Recovery Pallet
+//!
+//! ## Overview
+//!
+//! Social recovery in Liberland runs on the recovery pallet - citizen picks a
+//! set of friends who can vouch for a rescuer account, and after the delay
+//! the rescuer can act as the lost account. Identity and citizenship stay on
+//! the lost account, so nothing has to be re-registered.
+//!
+//! Recovery pallet only lets Root skip the friends. This pallet is the escape
+//! hatch for cases where the friends can't help (lost contact, deceased,
+//! never configured) - `ForceOrigin` (Congress) can set the rescuer directly.
+//!
+//! ## Pallet Config
+//!
+//! * `ForceOrigin` - origin allowed to force recovery
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `force_recover`: Allow rescuer to act as the lost account
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::StaticLookup;
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_recovery::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can force recovery
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Lost account can't rescue itself
+		SameAccount,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Rescuer can now act as the lost account
+		ForceRecovered { lost: T::AccountId, rescuer: T::AccountId },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow `rescuer` to act as `lost` account through recovery pallet's
+		/// `as_recovered`, without vouching by friends.
+		///
+		/// The dispatch origin of this call must be `ForceOrigin`.
+		///
+		/// - `lost`: Account that lost its keys
+		/// - `rescuer`: Account that will control the lost account
+		///
+		/// Emits `ForceRecovered`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_recover())]
+		pub fn force_recover(
+			origin: OriginFor<T>,
+			lost: AccountIdLookupOf<T>,
+			rescuer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let lost = T::Lookup::lookup(lost)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			ensure!(lost != rescuer, Error::<T>::SameAccount);
+
+			pallet_recovery::Pallet::<T>::set_recovered(
+				frame_system::RawOrigin::Root.into(),
+				T::Lookup::unlookup(lost.clone()),
+				T::Lookup::unlookup(rescuer.clone()),
+			)?;
+
+			Self::deposit_event(Event::ForceRecovered { lost, rescuer });
+			Ok(())
+		}
+	}
+}
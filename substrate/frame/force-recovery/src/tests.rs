@@ -0,0 +1,42 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn remark() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+#[test]
+fn force_recover_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ForceRecovery::force_recover(RuntimeOrigin::signed(3), 2, 3),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_recover_rejects_same_account() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ForceRecovery::force_recover(RuntimeOrigin::signed(1), 2, 2),
+			Error::<Test>::SameAccount
+		);
+	});
+}
+
+#[test]
+fn force_recover_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Recovery::as_recovered(RuntimeOrigin::signed(3), 2, remark()),
+			pallet_recovery::Error::<Test>::NotAllowed
+		);
+		assert_ok!(ForceRecovery::force_recover(RuntimeOrigin::signed(1), 2, 3));
+		System::assert_has_event(Event::<Test>::ForceRecovered { lost: 2, rescuer: 3 }.into());
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(3), 2, remark()));
+	});
+}
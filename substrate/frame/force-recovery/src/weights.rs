@@ -0,0 +1,36 @@
+//! Weights for pallet_force_recovery
+//!
+//! NOT BENCHMARKED. Based on pallet_recovery `set_recovered` weight plus this
+//! pallet's origin check. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_force_recovery` run
+//! on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_force_recovery.
+pub trait WeightInfo {
+	fn force_recover() -> Weight;
+}
+
+/// Weights for pallet_force_recovery using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn force_recover() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn force_recover() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
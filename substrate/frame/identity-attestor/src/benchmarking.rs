@@ -0,0 +1,85 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as IdentityAttestor;
+use codec::Encode;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use frame_system::{offchain::AppCrypto, RawOrigin};
+use pallet_identity::IdentityInfo;
+use sp_runtime::{
+	traits::{Hash, IdentifyAccount, StaticLookup},
+	RuntimeAppPublic,
+};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+type AppPublicOf<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as frame_system::offchain::SigningTypes>::Public,
+	<T as frame_system::offchain::SigningTypes>::Signature,
+>>::RuntimeAppPublic;
+type GenericPublicOf<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as frame_system::offchain::SigningTypes>::Public,
+	<T as frame_system::offchain::SigningTypes>::Signature,
+>>::GenericPublic;
+type GenericSignatureOf<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as frame_system::offchain::SigningTypes>::Public,
+	<T as frame_system::offchain::SigningTypes>::Signature,
+>>::GenericSignature;
+
+fn attestor_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::AttestorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+/// Adds registrars up to `RegistrarIndex`
+fn registrars<T: Config>() -> Result<(), BenchmarkError> {
+	let origin =
+		T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	for i in 0..=T::RegistrarIndex::get() {
+		let registrar: T::AccountId = account("registrar", i, SEED);
+		assert_ok!(pallet_identity::Pallet::<T>::add_registrar(
+			origin.clone(),
+			T::Lookup::unlookup(registrar)
+		));
+	}
+	Ok(())
+}
+
+benchmarks! {
+	set_attestors {
+		let a in 0 .. T::MaxAttestors::get();
+		let origin = attestor_origin::<T>()?;
+		let attestors: AttestorsOf<T> =
+			BoundedVec::truncate_from((0..a).map(|i| account("attestor", i, SEED)).collect());
+	}: _<T::RuntimeOrigin>(origin, attestors)
+	verify {
+		assert_eq!(IdentityAttestor::<T>::attestors().len() as u32, a);
+	}
+
+	attest {
+		registrars::<T>()?;
+		let key = AppPublicOf::<T>::generate_pair(None);
+		let public: T::Public = GenericPublicOf::<T>::from(key.clone()).into();
+		let attestors = BoundedVec::truncate_from(vec![public.clone().into_account()]);
+		assert_ok!(IdentityAttestor::<T>::set_attestors(attestor_origin::<T>()?, attestors));
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let info = IdentityInfo::default();
+		let identity = T::Hashing::hash_of(&info);
+		pallet_identity::Pallet::<T>::set_identity_no_deposit(&target, Default::default(), info);
+
+		let attestation = Attestation { public, target: target.clone(), identity };
+		let signature = key.sign(&attestation.encode()).ok_or(BenchmarkError::Weightless)?;
+		let signature: T::Signature = GenericSignatureOf::<T>::from(signature).into();
+	}: _(RawOrigin::None, attestation, signature)
+	verify {
+		assert_eq!(IdentityAttestor::<T>::attested(&target), Some(identity));
+	}
+}
+
+impl_benchmark_test_suite!(IdentityAttestor, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,386 @@
+//! # Liberland Identity Attestor Pallet
+//!
+//! ## Overview
+//!
+//! Identity attestor pallet automates the identity registrar. Instead of a
+//! clerk manually judging every identity, a KYC provider publishes signed
+//! proof-of-personhood attestations, which are turned into `KnownGood`
+//! judgements:
+//!
+//! * offchain worker fetches attestations from the KYC endpoint configured in
+//!   node's offchain local storage under `ENDPOINT_KEY`, every
+//!   `FetchInterval` blocks,
+//! * signatures of attestations are validated and valid ones are submitted
+//!   as unsigned transactions with signed payload,
+//! * on-chain, attestation is accepted only if it's signed by one of
+//!   `Attestors` and matches the current identity of the target,
+//! * accepted attestation is turned into a `KnownGood` judgement of registrar
+//!   `RegistrarIndex`, on behalf of its account.
+//!
+//! Final authority stays on-chain - `AttestorOrigin` decides which KYC
+//! providers are trusted and the registrar can still override or clear the
+//! judgements. Each identity is attested at most once, so clearing judgements
+//! isn't undone by replaying old attestations.
+//!
+//! Endpoint must respond with SCALE-encoded `Vec<(AttestationOf<T>,
+//! T::Signature)>`. Node operators set it with
+//! `offchain_localStorageSet("PERSISTENT", ENDPOINT_KEY, url)` RPC.
+//!
+//! ## Pallet Config
+//!
+//! * `AuthorityId` - crypto of attestor keys, see `crypto::AttestorAuthId`
+//! * `AttestorOrigin` - origin that sets trusted attestors
+//! * `RegistrarIndex` - registrar on behalf of which judgements are given
+//! * `MaxAttestors` - maximum number of trusted attestors
+//! * `FetchInterval` - number of blocks between endpoint fetches, must be
+//!   nonzero
+//! * `MaxAttestationsPerFetch` - maximum number of transactions submitted
+//!   after a single fetch
+//! * `UnsignedPriority` - priority of submitted transactions
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_attestors`: Replace the list of trusted attestors
+//! * `attest`: Give judgement based on signed attestation. Unsigned.
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of attestor keys
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"idat");
+
+/// Key of offchain local storage holding URL of the KYC endpoint
+pub const ENDPOINT_KEY: &[u8] = b"identity-attestor::endpoint";
+
+/// Attestor keys are sr25519 keys of type `KEY_TYPE`
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct AttestorAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AttestorAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = Sr25519Signature;
+		type GenericPublic = Sr25519Public;
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{
+		offchain::{
+			AppCrypto, SendTransactionTypes, SignedPayload, SigningTypes, SubmitTransaction,
+		},
+		pallet_prelude::*,
+		RawOrigin,
+	};
+	use pallet_identity::{Judgement, RegistrarIndex};
+	use sp_runtime::{
+		offchain::{http, Duration},
+		traits::{Hash, IdentifyAccount, StaticLookup, Zero},
+	};
+	use sp_std::prelude::*;
+
+	pub type AttestationOf<T> = Attestation<
+		<T as SigningTypes>::Public,
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+	>;
+	pub type AttestorsOf<T> =
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxAttestors>;
+
+	/// How long offchain worker waits for the KYC endpoint
+	const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		SendTransactionTypes<Call<Self>> + SigningTypes + pallet_identity::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Crypto of attestor keys
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// Origin that sets trusted attestors
+		type AttestorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Registrar on behalf of which judgements are given
+		#[pallet::constant]
+		type RegistrarIndex: Get<RegistrarIndex>;
+
+		/// Maximum number of trusted attestors
+		#[pallet::constant]
+		type MaxAttestors: Get<u32>;
+
+		/// Number of blocks between endpoint fetches, must be nonzero
+		#[pallet::constant]
+		type FetchInterval: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of transactions submitted after a single fetch
+		#[pallet::constant]
+		type MaxAttestationsPerFetch: Get<u32>;
+
+		/// Priority of submitted transactions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Same attestor listed more than once
+		DuplicateAttestor,
+		/// Attestation isn't signed by a trusted attestor
+		UnknownAttestor,
+		/// Attestation's signature is invalid
+		BadSignature,
+		/// Target has no identity or it changed since the attestation
+		StaleAttestation,
+		/// This identity was already attested
+		AlreadyAttested,
+		/// `RegistrarIndex` doesn't point to an existing registrar
+		NoRegistrar,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Trusted attestors replaced
+		AttestorsSet { attestors: AttestorsOf<T> },
+		/// Identity of `target` judged `KnownGood` based on `attestor`'s
+		/// attestation
+		Attested { target: T::AccountId, attestor: T::AccountId },
+	}
+
+	/// Accounts of KYC providers' keys trusted to sign attestations
+	#[pallet::storage]
+	#[pallet::getter(fn attestors)]
+	pub type Attestors<T: Config> = StorageValue<_, AttestorsOf<T>, ValueQuery>;
+
+	/// Hash of the last attested identity of given account
+	#[pallet::storage]
+	#[pallet::getter(fn attested)]
+	pub type Attested<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Hash, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Fetches attestations from the KYC endpoint and submits them.
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			if Self::attestors().is_empty() || !(now % T::FetchInterval::get()).is_zero() {
+				return;
+			}
+			if let Err(e) = Self::fetch_and_submit() {
+				log::warn!("Identity attestations not submitted: {e}");
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Replace the list of trusted attestors.
+		///
+		/// The dispatch origin of this call must be `AttestorOrigin`.
+		///
+		/// - `attestors`: Accounts of attestor keys
+		///
+		/// Emits `AttestorsSet`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_attestors(attestors.len() as u32))]
+		pub fn set_attestors(origin: OriginFor<T>, attestors: AttestorsOf<T>) -> DispatchResult {
+			T::AttestorOrigin::ensure_origin(origin)?;
+			for (i, attestor) in attestors.iter().enumerate() {
+				ensure!(!attestors[..i].contains(attestor), Error::<T>::DuplicateAttestor);
+			}
+			Attestors::<T>::put(&attestors);
+
+			Self::deposit_event(Event::AttestorsSet { attestors });
+			Ok(())
+		}
+
+		/// Give `KnownGood` judgement of `RegistrarIndex` registrar to the
+		/// attested identity.
+		///
+		/// The dispatch origin of this call must be None - it's submitted
+		/// by the offchain worker.
+		///
+		/// - `attestation`: Attestation of the identity
+		/// - `signature`: Signature of `attestation` by its attestor
+		///
+		/// Emits `Attested`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::attest().saturating_add(
+				<T as pallet_identity::Config>::WeightInfo::provide_judgement(
+					T::MaxRegistrars::get(),
+					T::MaxAdditionalFields::get(),
+				)
+			)
+		)]
+		pub fn attest(
+			origin: OriginFor<T>,
+			attestation: AttestationOf<T>,
+			signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::validate_attestation(&attestation, &signature)?;
+
+			let index = T::RegistrarIndex::get();
+			let registrar = pallet_identity::Pallet::<T>::registrars()
+				.get(index as usize)
+				.and_then(Option::as_ref)
+				.map(|r| r.account.clone())
+				.ok_or(Error::<T>::NoRegistrar)?;
+			pallet_identity::Pallet::<T>::provide_judgement(
+				RawOrigin::Signed(registrar).into(),
+				index,
+				T::Lookup::unlookup(attestation.target.clone()),
+				Judgement::KnownGood,
+				attestation.identity,
+			)
+			.map_err(|e| e.error)?;
+			Attested::<T>::insert(&attestation.target, attestation.identity);
+
+			Self::deposit_event(Event::Attested {
+				target: attestation.target,
+				attestor: attestation.public.into_account(),
+			});
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::attest { attestation, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+			Self::validate_attestation(attestation, signature).map_err(|e| match e {
+				Error::<T>::StaleAttestation | Error::<T>::AlreadyAttested => {
+					InvalidTransaction::Stale
+				},
+				_ => InvalidTransaction::BadProof,
+			})?;
+
+			ValidTransaction::with_tag_prefix("IdentityAttestor")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((attestation.target.clone(), attestation.identity))
+				.longevity(T::FetchInterval::get().try_into().unwrap_or(64_u64))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks that attestation is signed by a trusted attestor and
+		/// matches current, not yet attested identity of the target
+		pub fn validate_attestation(
+			attestation: &AttestationOf<T>,
+			signature: &T::Signature,
+		) -> Result<(), Error<T>> {
+			let attestor = attestation.public.clone().into_account();
+			ensure!(Self::attestors().contains(&attestor), Error::<T>::UnknownAttestor);
+			ensure!(
+				SignedPayload::<T>::verify::<T::AuthorityId>(attestation, signature.clone()),
+				Error::<T>::BadSignature
+			);
+
+			let identity = pallet_identity::Pallet::<T>::identity(&attestation.target)
+				.map(|registration| T::Hashing::hash_of(&registration.info))
+				.ok_or(Error::<T>::StaleAttestation)?;
+			ensure!(identity == attestation.identity, Error::<T>::StaleAttestation);
+			ensure!(
+				Self::attested(&attestation.target) != Some(identity),
+				Error::<T>::AlreadyAttested
+			);
+			Ok(())
+		}
+
+		fn fetch_and_submit() -> Result<(), &'static str> {
+			let endpoint = sp_io::offchain::local_storage_get(
+				sp_core::offchain::StorageKind::PERSISTENT,
+				ENDPOINT_KEY,
+			)
+			.ok_or("KYC endpoint not configured")?;
+			let url = sp_std::str::from_utf8(&endpoint).map_err(|_| "Invalid KYC endpoint")?;
+
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+			let response = http::Request::get(url)
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "KYC endpoint request failed")?
+				.try_wait(deadline)
+				.map_err(|_| "KYC endpoint timed out")?
+				.map_err(|_| "KYC endpoint request failed")?;
+			if response.code != 200 {
+				return Err("KYC endpoint responded with an error");
+			}
+
+			let body = response.body().collect::<Vec<u8>>();
+			let attestations = Vec::<(AttestationOf<T>, T::Signature)>::decode(&mut &body[..])
+				.map_err(|_| "Undecodable KYC endpoint response")?;
+
+			let max = T::MaxAttestationsPerFetch::get() as usize;
+			let valid = attestations
+				.into_iter()
+				.filter(|(attestation, signature)| {
+					Self::validate_attestation(attestation, signature).is_ok()
+				})
+				.take(max);
+			for (attestation, signature) in valid {
+				let call = Call::attest { attestation, signature };
+				if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+					.is_err()
+				{
+					log::warn!("Identity attestation submission failure");
+				}
+			}
+			Ok(())
+		}
+	}
+}
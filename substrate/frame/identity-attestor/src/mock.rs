@@ -0,0 +1,164 @@
+#![cfg(test)]
+pub use crate as pallet_identity_attestor;
+
+use codec::Encode;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::{
+	offchain::{AppCrypto, SendTransactionTypes, SigningTypes},
+	EnsureRoot,
+};
+use pallet_balances::AccountData;
+use pallet_identity::{Data, IdentityInfo};
+use sp_core::{ConstU16, H256};
+use sp_runtime::{
+	testing::{TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Identity: pallet_identity,
+		IdentityAttestor: pallet_identity_attestor,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub const MaxAdditionalFields: u32 = 2;
+	pub const MaxRegistrars: u32 = 20;
+}
+
+impl pallet_identity::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Slashed = ();
+	type BasicDeposit = ConstU64<0>;
+	type FieldDeposit = ConstU64<0>;
+	type SubAccountDeposit = ConstU64<0>;
+	type MaxSubAccounts = ConstU32<2>;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type RegistrarOrigin = EnsureRoot<u64>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type WeightInfo = ();
+	type Citizenship = ();
+}
+
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<C> SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+impl pallet_identity_attestor::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = TestAuthId;
+	type AttestorOrigin = EnsureRoot<u64>;
+	type RegistrarIndex = ConstU32<0>;
+	type MaxAttestors = ConstU32<2>;
+	type FetchInterval = ConstU64<5>;
+	type MaxAttestationsPerFetch = ConstU32<2>;
+	type UnsignedPriority = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+pub const REGISTRAR: u64 = 10;
+pub const ATTESTOR: u64 = 20;
+
+pub fn info(name: &[u8]) -> IdentityInfo<MaxAdditionalFields> {
+	IdentityInfo { display: Data::Raw(name.to_vec().try_into().unwrap()), ..Default::default() }
+}
+
+/// Attestation of current identity of `target`, signed by `attestor`
+pub fn signed_attestation(
+	attestor: u64,
+	target: u64,
+) -> (crate::AttestationOf<Test>, TestSignature) {
+	let identity = Identity::identity(target).map(|r| BlakeTwo256::hash_of(&r.info)).unwrap();
+	let attestation = crate::Attestation { public: UintAuthorityId(attestor), target, identity };
+	let signature = TestSignature(attestor, attestation.encode());
+	(attestation, signature)
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Identity::add_registrar(RuntimeOrigin::root(), REGISTRAR).unwrap();
+		for who in [1, 2, 3] {
+			Identity::set_identity(RuntimeOrigin::signed(who), Box::new(info(b"citizen"))).unwrap();
+		}
+		IdentityAttestor::set_attestors(RuntimeOrigin::root(), vec![ATTESTOR].try_into().unwrap())
+			.unwrap();
+	});
+	ext
+}
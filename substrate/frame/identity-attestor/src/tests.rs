@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, ENDPOINT_KEY};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_identity::Judgement;
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+};
+use sp_runtime::{
+	testing::{TestSignature, TestXt},
+	traits::ValidateUnsigned,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
+
+fn judgements(who: u64) -> Vec<(u32, Judgement<u64>)> {
+	Identity::identity(who).unwrap().judgements.into_inner()
+}
+
+#[test]
+fn set_attestors_requires_attestor_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			IdentityAttestor::set_attestors(RuntimeOrigin::signed(1), Default::default()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_attestors_rejects_duplicates() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			IdentityAttestor::set_attestors(
+				RuntimeOrigin::root(),
+				vec![ATTESTOR, ATTESTOR].try_into().unwrap()
+			),
+			Error::<Test>::DuplicateAttestor
+		);
+	});
+}
+
+#[test]
+fn attest_gives_known_good_judgement() {
+	new_test_ext().execute_with(|| {
+		let (attestation, signature) = signed_attestation(ATTESTOR, 1);
+		let identity = attestation.identity;
+		assert_ok!(IdentityAttestor::attest(RuntimeOrigin::none(), attestation, signature));
+		assert_eq!(judgements(1), vec![(0, Judgement::KnownGood)]);
+		assert_eq!(IdentityAttestor::attested(1), Some(identity));
+		System::assert_last_event(Event::<Test>::Attested { target: 1, attestor: ATTESTOR }.into());
+	});
+}
+
+#[test]
+fn attest_requires_none_origin() {
+	new_test_ext().execute_with(|| {
+		let (attestation, signature) = signed_attestation(ATTESTOR, 1);
+		assert_noop!(
+			IdentityAttestor::attest(RuntimeOrigin::signed(1), attestation, signature),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn attest_validates_attestation() {
+	new_test_ext().execute_with(|| {
+		let (attestation, signature) = signed_attestation(ATTESTOR + 1, 1);
+		assert_noop!(
+			IdentityAttestor::attest(RuntimeOrigin::none(), attestation, signature),
+			Error::<Test>::UnknownAttestor
+		);
+
+		let (attestation, _) = signed_attestation(ATTESTOR, 1);
+		let signature = TestSignature(ATTESTOR, b"forged".to_vec());
+		assert_noop!(
+			IdentityAttestor::attest(RuntimeOrigin::none(), attestation, signature),
+			Error::<Test>::BadSignature
+		);
+
+		let (attestation, signature) = signed_attestation(ATTESTOR, 1);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(1), Box::new(info(b"changed"))));
+		assert_noop!(
+			IdentityAttestor::attest(RuntimeOrigin::none(), attestation, signature),
+			Error::<Test>::StaleAttestation
+		);
+	});
+}
+
+#[test]
+fn attestation_cant_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let (attestation, signature) = signed_attestation(ATTESTOR, 1);
+		assert_ok!(IdentityAttestor::attest(
+			RuntimeOrigin::none(),
+			attestation.clone(),
+			signature.clone()
+		));
+		assert_ok!(Identity::clear_judgements(&1));
+		assert_noop!(
+			IdentityAttestor::attest(RuntimeOrigin::none(), attestation, signature),
+			Error::<Test>::AlreadyAttested
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_works() {
+	new_test_ext().execute_with(|| {
+		let (attestation, signature) = signed_attestation(ATTESTOR, 1);
+		let call = crate::Call::attest { attestation, signature };
+		assert!(IdentityAttestor::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+		let (attestation, signature) = signed_attestation(ATTESTOR + 1, 1);
+		let call = crate::Call::attest { attestation, signature };
+		assert_eq!(
+			IdentityAttestor::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::BadProof.into())
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_valid_attestations() {
+	let mut ext = new_test_ext();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let valid = signed_attestation(ATTESTOR, 1);
+		let attestations = vec![signed_attestation(ATTESTOR + 1, 2), valid.clone()];
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			ENDPOINT_KEY,
+			b"https://kyc.example/attestations",
+		);
+		offchain_state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: "https://kyc.example/attestations".into(),
+			response: Some(attestations.encode()),
+			sent: true,
+			..Default::default()
+		});
+
+		IdentityAttestor::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = TestXt::<RuntimeCall, ()>::decode(&mut &tx[..]).unwrap();
+		assert_eq!(tx.signature, None);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::IdentityAttestor(crate::Call::attest {
+				attestation: valid.0,
+				signature: valid.1
+			})
+		);
+	});
+}
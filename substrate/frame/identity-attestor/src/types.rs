@@ -0,0 +1,24 @@
+use codec::{Decode, Encode};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Statement of KYC provider that identity of `target` belongs to a real,
+/// verified person. Signed by the provider's attestor key.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Attestation<Public, AccountId, Hash> {
+	/// Attestor key that signed the attestation
+	pub public: Public,
+	/// Account whose identity is attested
+	pub target: AccountId,
+	/// Hash of the attested `IdentityInfo`
+	pub identity: Hash,
+}
+
+impl<T: SigningTypes, AccountId: Encode, Hash: Encode> SignedPayload<T>
+	for Attestation<T::Public, AccountId, Hash>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
@@ -0,0 +1,51 @@
+//! Weights for pallet_identity_attestor
+//!
+//! NOT BENCHMARKED. Estimated from pallet_im_online heartbeat weights, which
+//! also verify signatures of unsigned transactions, plus this pallet's own
+//! storage accesses. `attest` doesn't include judgement itself, it's charged
+//! with pallet_identity weights. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_identity_attestor`
+//! run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_identity_attestor.
+pub trait WeightInfo {
+	fn set_attestors(a: u32, ) -> Weight;
+	fn attest() -> Weight;
+}
+
+/// Weights for pallet_identity_attestor using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn set_attestors(a: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn attest() -> Weight {
+		Weight::from_parts(62_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_attestors(a: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn attest() -> Weight {
+		Weight::from_parts(62_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
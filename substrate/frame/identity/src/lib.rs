@@ -83,7 +83,11 @@ pub mod types;
 pub mod weights;
 
 use frame_support::traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency};
-use sp_runtime::traits::{AppendZerosInput, Hash, Saturating, StaticLookup, Zero};
+use liberland_traits::CitizenshipChecker;
+use sp_runtime::{
+	traits::{AppendZerosInput, Hash, Saturating, StaticLookup, Zero},
+	DispatchResult,
+};
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
 
@@ -263,6 +267,8 @@ pub mod pallet {
 		JudgementUnrequested { who: T::AccountId, registrar_index: RegistrarIndex },
 		/// A judgement was given by a registrar.
 		JudgementGiven { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// All judgements of an identity were removed.
+		JudgementsCleared { target: T::AccountId },
 		/// A registrar was added.
 		RegistrarAdded { registrar_index: RegistrarIndex },
 		/// A sub-identity was added to an identity and the deposit paid.
@@ -1017,4 +1023,24 @@ impl<T: Config> Pallet<T> {
 		IdentityOf::<T>::insert(target, id);
 		weight
 	}
+
+	/// Remove all judgements of account's identity, unreserving fees of
+	/// pending judgement requests. Identity info itself and deposits are kept.
+	pub fn clear_judgements(target: &T::AccountId) -> DispatchResult {
+		let was_citizen = T::Citizenship::is_citizen(target);
+		IdentityOf::<T>::try_mutate(target, |id| -> DispatchResult {
+			let id = id.as_mut().ok_or(Error::<T>::NotNamed)?;
+			for (_, judgement) in id.judgements.iter() {
+				if let Judgement::FeePaid(fee) = judgement {
+					let err_amount = T::Currency::unreserve(target, *fee);
+					debug_assert!(err_amount.is_zero());
+				}
+			}
+			id.judgements.clear();
+			Ok(())
+		})?;
+		Self::deposit_event(Event::JudgementsCleared { target: target.clone() });
+		T::Citizenship::identity_changed(was_citizen, target);
+		Ok(())
+	}
 }
@@ -485,6 +485,35 @@ fn cancelling_requested_judgement_should_work() {
 	});
 }
 
+#[test]
+fn clear_judgements_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 4));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(4), 1, 10));
+		assert_noop!(Identity::clear_judgements(&10), Error::<Test>::NotNamed);
+
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(3),
+			0,
+			10,
+			Judgement::KnownGood,
+			BlakeTwo256::hash_of(&ten())
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 1, 10));
+		assert_eq!(Balances::free_balance(10), 80);
+
+		assert_ok!(Identity::clear_judgements(&10));
+		let id = Identity::identity(10).unwrap();
+		assert!(id.judgements.is_empty());
+		assert_eq!(id.info, ten());
+		assert_eq!(Balances::free_balance(10), 90);
+		System::assert_last_event(Event::JudgementsCleared { target: 10 }.into());
+	});
+}
+
 #[test]
 fn requesting_judgement_should_work() {
 	new_test_ext().execute_with(|| {
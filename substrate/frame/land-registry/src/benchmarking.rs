@@ -0,0 +1,255 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as LandRegistry;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_nfts::{
+	BenchmarkHelper as NftsBenchmarkHelper, CollectionConfig, CollectionSetting, CollectionSettings,
+};
+use sp_runtime::traits::{Bounded, Saturating, Zero};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+/// Size of benchmark strips, roughly 10m
+const SIDE: i64 = 1_000;
+
+fn office_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::OfficeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn item<T: Config>(i: u32) -> T::ItemId {
+	T::Helper::item(i as u16)
+}
+
+/// Plot covering strips `from..to`, laid out side by side from the anchor
+fn strips_plot<T: Config>(from: u32, to: u32) -> Vec<Coords> {
+	let anchor = T::BenchmarkHelper::anchor();
+	let start = anchor.long + SIDE * from as i64;
+	let end = anchor.long + SIDE * to as i64;
+	vec![
+		Coords { lat: anchor.lat, long: start },
+		Coords { lat: anchor.lat, long: end },
+		Coords { lat: anchor.lat + SIDE, long: end },
+		Coords { lat: anchor.lat + SIDE, long: start },
+	]
+}
+
+fn strip<T: Config>(i: u32) -> Vec<Coords> {
+	strips_plot::<T>(i, i + 1)
+}
+
+fn metadata<T: Config>(demarcation: Vec<Coords>) -> MetadataOf<T> {
+	BoundedVec::truncate_from(T::BenchmarkHelper::metadata(demarcation))
+}
+
+fn mint_plot<T: Config>(owner: &T::AccountId, item: T::ItemId, demarcation: Vec<Coords>) {
+	let collection = T::BenchmarkHelper::collection();
+	if pallet_nfts::Pallet::<T>::collection_owner(collection).is_none() {
+		assert_ok!(pallet_nfts::Pallet::<T>::do_create_collection(
+			collection,
+			owner.clone(),
+			owner.clone(),
+			CollectionConfig {
+				settings: CollectionSettings::from_disabled(
+					CollectionSetting::DepositRequired.into()
+				),
+				max_supply: None,
+				mint_settings: Default::default(),
+			},
+			Zero::zero(),
+			pallet_nfts::Event::ForceCreated { collection, owner: owner.clone() },
+		));
+	}
+	assert_ok!(pallet_nfts::Pallet::<T>::do_mint(
+		collection,
+		item,
+		None,
+		owner.clone(),
+		Default::default(),
+		|_, _| Ok(())
+	));
+	assert_ok!(pallet_nfts::Pallet::<T>::do_set_item_metadata(
+		None,
+		collection,
+		item,
+		metadata::<T>(demarcation),
+		None,
+	));
+}
+
+/// `n` strips with item ids starting at 100
+fn strips<T: Config>(n: u32) -> PlotsOf<T> {
+	BoundedVec::truncate_from(
+		(0..n).map(|i| (item::<T>(100 + i), metadata::<T>(strip::<T>(i)))).collect(),
+	)
+}
+
+/// Requests merge of `n` strips, or split into `n` strips if `split`
+fn requested<T: Config>(owner: &T::AccountId, n: u32, split: bool) {
+	let collection = T::BenchmarkHelper::collection();
+	let origin = RawOrigin::Signed(owner.clone()).into();
+	if split {
+		mint_plot::<T>(owner, item::<T>(0), strips_plot::<T>(0, n));
+		assert_ok!(LandRegistry::<T>::request_split(
+			origin,
+			collection,
+			item::<T>(0),
+			strips::<T>(n)
+		));
+	} else {
+		for i in 0..n {
+			mint_plot::<T>(owner, item::<T>(100 + i), strip::<T>(i));
+		}
+		assert_ok!(LandRegistry::<T>::request_merge(
+			origin,
+			collection,
+			BoundedVec::truncate_from((0..n).map(|i| item::<T>(100 + i)).collect()),
+			item::<T>(0),
+			metadata::<T>(strips_plot::<T>(0, n)),
+		));
+	}
+}
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	T::RentCurrency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+/// Offers plot `i` for lease, accepted by tenant if `accepted`
+fn lease_offered<T: Config>(owner: &T::AccountId, i: u32, accepted: bool) {
+	mint_plot::<T>(owner, item::<T>(i), strip::<T>(i));
+	let rent = T::RentCurrency::minimum_balance();
+	assert_ok!(LandRegistry::<T>::offer_lease(
+		RawOrigin::Signed(owner.clone()).into(),
+		T::BenchmarkHelper::collection(),
+		item::<T>(i),
+		rent,
+		10u32.into(),
+		2,
+		rent,
+	));
+	if accepted {
+		let tenant = funded::<T>("tenant");
+		assert_ok!(LandRegistry::<T>::accept_lease(
+			RawOrigin::Signed(tenant).into(),
+			LandRegistry::<T>::lease_count() - 1
+		));
+	}
+}
+
+benchmarks! {
+	request_split {
+		let c in 2 .. T::MaxPlots::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		mint_plot::<T>(&owner, item::<T>(0), strips_plot::<T>(0, c));
+		let collection = T::BenchmarkHelper::collection();
+		let children = strips::<T>(c);
+	}: _(RawOrigin::Signed(owner), collection, item::<T>(0), children)
+	verify {
+		assert!(Requests::<T>::contains_key(0));
+	}
+
+	request_merge {
+		let p in 2 .. T::MaxPlots::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		for i in 0..p {
+			mint_plot::<T>(&owner, item::<T>(100 + i), strip::<T>(i));
+		}
+		let collection = T::BenchmarkHelper::collection();
+		let parents = BoundedVec::truncate_from((0..p).map(|i| item::<T>(100 + i)).collect());
+		let plot = metadata::<T>(strips_plot::<T>(0, p));
+	}: _(RawOrigin::Signed(owner), collection, parents, item::<T>(0), plot)
+	verify {
+		assert!(Requests::<T>::contains_key(0));
+	}
+
+	approve {
+		let p in 2 .. T::MaxPlots::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		requested::<T>(&owner, p, false);
+		let origin = office_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		let collection = T::BenchmarkHelper::collection();
+		assert_eq!(pallet_nfts::Pallet::<T>::owner(collection, item::<T>(0)), Some(owner));
+	}
+
+	reject {
+		let p in 2 .. T::MaxPlots::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		requested::<T>(&owner, p, true);
+		let origin = office_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert!(!Requests::<T>::contains_key(0));
+	}
+
+	cancel {
+		let p in 2 .. T::MaxPlots::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		requested::<T>(&owner, p, true);
+	}: _(RawOrigin::Signed(owner), 0)
+	verify {
+		assert!(!Requests::<T>::contains_key(0));
+	}
+
+	offer_lease {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		mint_plot::<T>(&owner, item::<T>(0), strip::<T>(0));
+		let collection = T::BenchmarkHelper::collection();
+		let rent = T::RentCurrency::minimum_balance();
+	}: _(RawOrigin::Signed(owner), collection, item::<T>(0), rent, 10u32.into(), 2, rent)
+	verify {
+		assert!(Leases::<T>::contains_key(0));
+	}
+
+	cancel_offer {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		lease_offered::<T>(&owner, 0, false);
+	}: _(RawOrigin::Signed(owner), 0)
+	verify {
+		assert!(!Leases::<T>::contains_key(0));
+	}
+
+	accept_lease {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		lease_offered::<T>(&owner, 0, false);
+		let tenant = funded::<T>("tenant");
+	}: _(RawOrigin::Signed(tenant), 0)
+	verify {
+		assert!(LandRegistry::<T>::leases(0).unwrap().tenancy.is_some());
+	}
+
+	terminate_lease {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		lease_offered::<T>(&owner, 0, true);
+		let tenant: T::AccountId = account("tenant", 0, SEED);
+	}: _(RawOrigin::Signed(tenant), 0)
+	verify {
+		assert!(!Leases::<T>::contains_key(0));
+	}
+
+	on_initialize {
+		let l in 0 .. T::MaxLeasesPerBlock::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		for i in 0..l {
+			lease_offered::<T>(&owner, i, true);
+		}
+		let due = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		frame_system::Pallet::<T>::set_block_number(due);
+	}: {
+		LandRegistry::<T>::on_initialize(due);
+	}
+	verify {
+		assert!(LandRegistry::<T>::rent_due(due).is_empty());
+	}
+}
+
+impl_benchmark_test_suite!(LandRegistry, crate::mock::new_test_ext(), crate::mock::Test,);
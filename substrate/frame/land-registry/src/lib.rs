@@ -0,0 +1,881 @@
+//! # Liberland Land Registry Pallet
+//!
+//! ## Overview
+//!
+//! Land plots are registered as NFTs in land collections of the nfts pallet,
+//! with demarcation (polygon of geo-coordinates) stored in item's metadata.
+//! This pallet lets owners change the shape of their holdings:
+//!
+//! * split - one plot is replaced by two or more child plots,
+//! * merge - two or more adjacent plots are replaced by a single plot.
+//!
+//! Owner submits the request with demarcations of the new plots. Request is
+//! checked on-chain:
+//!
+//! * requester must own all parent plots and child item ids must be free,
+//! * all demarcations must be valid,
+//! * total area of child plots must equal total area of parent plots,
+//! * merged plots must form a single area - every plot must share a piece of
+//!   border with another merged plot.
+//!
+//! `OfficeOrigin` (Land Registry Office) reviews the request and approves or
+//! rejects it. Approval re-runs the checks, burns parent plots and mints
+//! child plots to the requester. Plots included in a pending request can't be
+//! part of another request.
+//!
+//! Every request and its outcome emits an event that links parent and child
+//! item ids, so indexers can follow provenance of every plot.
+//!
+//! ### Leases
+//!
+//! Owner can offer a plot for lease - rent per period (in LLD), length of
+//! the period, number of periods and a security deposit. Tenant accepts the
+//! offer by locking the deposit and rent for the first period. At the end
+//! of each period escrowed rent is paid out in `on_initialize` to the
+//! current owner of the plot and rent for the next period is locked. If
+//! tenant can't pay, lease ends and the deposit goes to the owner.
+//! Otherwise deposit is returned to the tenant when the lease ends.
+//!
+//! Either side can terminate the lease early:
+//!
+//! * tenant - escrowed rent for the current period goes to the owner,
+//!   deposit is returned,
+//! * owner - escrowed rent and deposit are returned to the tenant.
+//!
+//! Leased plots (and plots with pending lease offers) can't be split or
+//! merged.
+//!
+//! ## Pallet Config
+//!
+//! * `OfficeOrigin` - origin that approves and rejects requests
+//! * `LandCollections` - collections that hold land plots
+//! * `Demarcation` - extracts demarcation from item metadata
+//! * `MaxPlots` - maximum number of child plots in split and parent plots in
+//!   merge
+//! * `RentCurrency` - currency of rent and lease deposits
+//! * `ReserveIdentifier` - identifier of named reserves holding escrowed rent
+//!   and deposits
+//! * `MaxLeasesPerBlock` - maximum number of rent payments scheduled for a
+//!   single block
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `request_split`: Request split of a plot into child plots
+//! * `request_merge`: Request merge of adjacent plots
+//! * `approve`: Execute pending request
+//! * `reject`: Drop pending request
+//! * `cancel`: Withdraw own pending request
+//! * `offer_lease`: Offer own plot for lease
+//! * `cancel_offer`: Withdraw lease offer that wasn't accepted yet
+//! * `accept_lease`: Accept lease offer and lock first rent payment
+//! * `terminate_lease`: End active lease early
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, NamedReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::RentCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{tokens::nonfungibles_v2::Inspect, BalanceStatus, Contains},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_nfts::ItemConfig;
+	use sp_runtime::traits::{Saturating, Zero};
+	use sp_std::{prelude::*, vec};
+
+	pub type MetadataOf<T> = BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>;
+	pub type ItemIdsOf<T> = BoundedVec<<T as pallet_nfts::Config>::ItemId, <T as Config>::MaxPlots>;
+	pub type PlotsOf<T> =
+		BoundedVec<(<T as pallet_nfts::Config>::ItemId, MetadataOf<T>), <T as Config>::MaxPlots>;
+	pub type PlotRequestOf<T> = PlotRequest<
+		<T as frame_system::Config>::AccountId,
+		<T as pallet_nfts::Config>::CollectionId,
+		ItemIdsOf<T>,
+		PlotsOf<T>,
+	>;
+	pub type LeaseOf<T> = Lease<
+		<T as frame_system::Config>::AccountId,
+		<T as pallet_nfts::Config>::CollectionId,
+		<T as pallet_nfts::Config>::ItemId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+	>;
+	type ReserveIdentifierOf<T> = <<T as Config>::RentCurrency as NamedReservableCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::ReserveIdentifier;
+
+	/// Number of consecutive blocks tried when the block at which rent is
+	/// due already has `MaxLeasesPerBlock` payments scheduled
+	const RENT_SCHEDULE_ATTEMPTS: u32 = 10;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_nfts::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that approves and rejects requests
+		type OfficeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Collections that hold land plots
+		type LandCollections: Contains<Self::CollectionId>;
+
+		/// Extracts demarcation from item metadata
+		type Demarcation: PlotDemarcation;
+
+		/// Maximum number of child plots in split and parent plots in merge
+		#[pallet::constant]
+		type MaxPlots: Get<u32>;
+
+		/// Currency of rent and lease deposits
+		type RentCurrency: NamedReservableCurrency<Self::AccountId>;
+
+		/// Identifies reserves in RentCurrency
+		#[pallet::constant]
+		type ReserveIdentifier: Get<&'static ReserveIdentifierOf<Self>>;
+
+		/// Maximum number of rent payments scheduled for a single block
+		#[pallet::constant]
+		type MaxLeasesPerBlock: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::CollectionId>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Collection doesn't hold land plots
+		NotLandCollection,
+		/// Split needs at least 2 children, merge needs at least 2 parents
+		TooFewPlots,
+		/// Same item used more than once in the request
+		DuplicatePlot,
+		/// Requester doesn't own parent plot
+		NotOwner,
+		/// Child item id is already taken
+		PlotExists,
+		/// Plot is already part of a pending request
+		PlotPending,
+		/// Metadata doesn't describe a valid plot
+		InvalidDemarcation,
+		/// Child plots don't cover the same area as parent plots
+		AreaMismatch,
+		/// Merged plots don't form a single area
+		NotAdjacent,
+		/// Request doesn't exist or was already resolved
+		UnknownRequest,
+		/// Only requester can cancel the request
+		NotRequester,
+		/// Plot is leased or offered for lease
+		PlotLeased,
+		/// Lease period and number of periods must be non-zero
+		InvalidLease,
+		/// Lease doesn't exist or already ended
+		UnknownLease,
+		/// Lease offer was already accepted
+		LeaseActive,
+		/// Lease offer wasn't accepted yet
+		LeaseNotActive,
+		/// Only landlord can cancel the offer
+		NotLandlord,
+		/// Owner can't lease own plot
+		OwnPlot,
+		/// Only tenant or owner of the plot can terminate the lease
+		NotLeaseParty,
+		/// Too many rent payments already scheduled
+		TooManyLeases,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Owner requested split of `parent` into `children`
+		SplitRequested {
+			index: RequestIndex,
+			collection: T::CollectionId,
+			parent: T::ItemId,
+			children: ItemIdsOf<T>,
+		},
+		/// Owner requested merge of `parents` into `child`
+		MergeRequested {
+			index: RequestIndex,
+			collection: T::CollectionId,
+			parents: ItemIdsOf<T>,
+			child: T::ItemId,
+		},
+		/// Split approved - `parent` was burned and `children` minted
+		PlotSplit {
+			index: RequestIndex,
+			collection: T::CollectionId,
+			parent: T::ItemId,
+			children: ItemIdsOf<T>,
+		},
+		/// Merge approved - `parents` were burned and `child` minted
+		PlotsMerged {
+			index: RequestIndex,
+			collection: T::CollectionId,
+			parents: ItemIdsOf<T>,
+			child: T::ItemId,
+		},
+		/// Request rejected by the office
+		RequestRejected { index: RequestIndex },
+		/// Request withdrawn by the requester
+		RequestCancelled { index: RequestIndex },
+		/// Owner offered plot for lease
+		LeaseOffered {
+			lease: LeaseIndex,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			rent: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+			periods: u32,
+			deposit: BalanceOf<T>,
+		},
+		/// Lease offer withdrawn
+		LeaseOfferCancelled { lease: LeaseIndex },
+		/// Tenant accepted the offer, first rent is paid out at `next_payment`
+		LeaseAccepted { lease: LeaseIndex, tenant: T::AccountId, next_payment: BlockNumberFor<T> },
+		/// Escrowed rent paid to plot's owner
+		RentPaid { lease: LeaseIndex, owner: T::AccountId, amount: BalanceOf<T> },
+		/// All periods paid, deposit returned to the tenant
+		LeaseEnded { lease: LeaseIndex },
+		/// Tenant couldn't pay the rent, deposit went to plot's owner
+		LeaseDefaulted { lease: LeaseIndex },
+		/// Lease terminated early by tenant or owner
+		LeaseTerminated { lease: LeaseIndex, by: T::AccountId },
+	}
+
+	/// Number of requests ever submitted, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn request_count)]
+	pub type RequestCount<T: Config> = StorageValue<_, RequestIndex, ValueQuery>;
+
+	/// Requests waiting for office's decision
+	#[pallet::storage]
+	#[pallet::getter(fn requests)]
+	pub type Requests<T: Config> =
+		StorageMap<_, Twox64Concat, RequestIndex, PlotRequestOf<T>, OptionQuery>;
+
+	/// Parent and child plots of pending requests
+	#[pallet::storage]
+	#[pallet::getter(fn pending_plot)]
+	pub type PendingPlots<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RequestIndex,
+		OptionQuery,
+	>;
+
+	/// Number of leases ever offered, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn lease_count)]
+	pub type LeaseCount<T: Config> = StorageValue<_, LeaseIndex, ValueQuery>;
+
+	/// Lease offers and active leases
+	#[pallet::storage]
+	#[pallet::getter(fn leases)]
+	pub type Leases<T: Config> = StorageMap<_, Twox64Concat, LeaseIndex, LeaseOf<T>, OptionQuery>;
+
+	/// Lease or lease offer of the plot
+	#[pallet::storage]
+	#[pallet::getter(fn plot_lease_index)]
+	pub type PlotLeases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		LeaseIndex,
+		OptionQuery,
+	>;
+
+	/// Leases with rent due at given block
+	#[pallet::storage]
+	#[pallet::getter(fn rent_due)]
+	pub type RentDue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<LeaseIndex, T::MaxLeasesPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Pays out rent due at this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let due = RentDue::<T>::take(now);
+			for index in due.iter() {
+				if let Some(lease) = Leases::<T>::get(index) {
+					Self::pay_rent(*index, lease, now);
+				}
+			}
+			<T as Config>::WeightInfo::on_initialize(due.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request split of a plot into child plots.
+		///
+		/// The dispatch origin of this call must be Signed by the owner of
+		/// the plot.
+		///
+		/// - `collection`: Land collection of the plot
+		/// - `parent`: Plot to split
+		/// - `children`: `(item, metadata)` of new plots, at least 2
+		///
+		/// Emits `SplitRequested`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_split(children.len() as u32))]
+		pub fn request_split(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			parent: T::ItemId,
+			children: PlotsOf<T>,
+		) -> DispatchResult {
+			let requester = ensure_signed(origin)?;
+			ensure!(children.len() >= 2, Error::<T>::TooFewPlots);
+
+			let child_ids = Self::child_ids(&children);
+			let parents = BoundedVec::truncate_from(vec![parent]);
+			let index = Self::submit(PlotRequest { requester, collection, parents, children })?;
+
+			Self::deposit_event(Event::SplitRequested {
+				index,
+				collection,
+				parent,
+				children: child_ids,
+			});
+			Ok(())
+		}
+
+		/// Request merge of adjacent plots into a single plot.
+		///
+		/// The dispatch origin of this call must be Signed by the owner of
+		/// all plots.
+		///
+		/// - `collection`: Land collection of the plots
+		/// - `parents`: Plots to merge, at least 2
+		/// - `child`: Item id of the merged plot
+		/// - `metadata`: Metadata of the merged plot
+		///
+		/// Emits `MergeRequested`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_merge(parents.len() as u32))]
+		pub fn request_merge(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			parents: ItemIdsOf<T>,
+			child: T::ItemId,
+			metadata: MetadataOf<T>,
+		) -> DispatchResult {
+			let requester = ensure_signed(origin)?;
+			ensure!(parents.len() >= 2, Error::<T>::TooFewPlots);
+
+			let children = BoundedVec::truncate_from(vec![(child, metadata)]);
+			let index = Self::submit(PlotRequest {
+				requester,
+				collection,
+				parents: parents.clone(),
+				children,
+			})?;
+
+			Self::deposit_event(Event::MergeRequested { index, collection, parents, child });
+			Ok(())
+		}
+
+		/// Execute pending request - burn parent plots and mint child plots
+		/// to the requester. All checks are repeated, as plots could have
+		/// changed since the request.
+		///
+		/// The dispatch origin of this call must be `OfficeOrigin`.
+		///
+		/// - `index`: Index of the request
+		///
+		/// Emits `PlotSplit` or `PlotsMerged`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve(T::MaxPlots::get()))]
+		pub fn approve(origin: OriginFor<T>, index: RequestIndex) -> DispatchResult {
+			T::OfficeOrigin::ensure_origin(origin)?;
+			let request = Self::take_request(index)?;
+			Self::ensure_valid(&request)?;
+
+			let PlotRequest { requester, collection, parents, children } = request;
+			for parent in parents.iter() {
+				pallet_nfts::Pallet::<T>::do_burn(collection, *parent, |_| Ok(()))?;
+			}
+			for (child, metadata) in children.iter() {
+				pallet_nfts::Pallet::<T>::do_mint(
+					collection,
+					*child,
+					None,
+					requester.clone(),
+					ItemConfig::default(),
+					|_, _| Ok(()),
+				)?;
+				pallet_nfts::Pallet::<T>::do_set_item_metadata(
+					None,
+					collection,
+					*child,
+					metadata.clone(),
+					None,
+				)?;
+			}
+
+			let event = if parents.len() == 1 {
+				Event::PlotSplit {
+					index,
+					collection,
+					parent: parents[0],
+					children: Self::child_ids(&children),
+				}
+			} else {
+				Event::PlotsMerged { index, collection, parents, child: children[0].0 }
+			};
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		/// Drop pending request.
+		///
+		/// The dispatch origin of this call must be `OfficeOrigin`.
+		///
+		/// - `index`: Index of the request
+		///
+		/// Emits `RequestRejected`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject(T::MaxPlots::get()))]
+		pub fn reject(origin: OriginFor<T>, index: RequestIndex) -> DispatchResult {
+			T::OfficeOrigin::ensure_origin(origin)?;
+			Self::take_request(index)?;
+
+			Self::deposit_event(Event::RequestRejected { index });
+			Ok(())
+		}
+
+		/// Withdraw own pending request.
+		///
+		/// The dispatch origin of this call must be Signed by the requester.
+		///
+		/// - `index`: Index of the request
+		///
+		/// Emits `RequestCancelled`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel(T::MaxPlots::get()))]
+		pub fn cancel(origin: OriginFor<T>, index: RequestIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let request = Self::requests(index).ok_or(Error::<T>::UnknownRequest)?;
+			ensure!(request.requester == who, Error::<T>::NotRequester);
+			Self::take_request(index)?;
+
+			Self::deposit_event(Event::RequestCancelled { index });
+			Ok(())
+		}
+
+		/// Offer own plot for lease.
+		///
+		/// The dispatch origin of this call must be Signed by the owner of
+		/// the plot.
+		///
+		/// - `collection`: Land collection of the plot
+		/// - `item`: Plot to lease
+		/// - `rent`: Rent paid at the end of each period
+		/// - `period`: Length of a period in blocks
+		/// - `periods`: Number of periods
+		/// - `deposit`: Security deposit held from the tenant
+		///
+		/// Emits `LeaseOffered`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::offer_lease())]
+		pub fn offer_lease(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			rent: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+			periods: u32,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let landlord = ensure_signed(origin)?;
+			ensure!(T::LandCollections::contains(&collection), Error::<T>::NotLandCollection);
+			ensure!(
+				pallet_nfts::Pallet::<T>::owner(collection, item).as_ref() == Some(&landlord),
+				Error::<T>::NotOwner
+			);
+			ensure!(!period.is_zero() && periods > 0, Error::<T>::InvalidLease);
+			ensure!(!PlotLeases::<T>::contains_key(collection, item), Error::<T>::PlotLeased);
+			ensure!(!PendingPlots::<T>::contains_key(collection, item), Error::<T>::PlotPending);
+
+			let lease = Self::lease_count();
+			Leases::<T>::insert(
+				lease,
+				LeaseOf::<T> {
+					collection,
+					item,
+					landlord,
+					rent,
+					period,
+					periods,
+					deposit,
+					tenancy: None,
+				},
+			);
+			PlotLeases::<T>::insert(collection, item, lease);
+			LeaseCount::<T>::put(lease.saturating_add(1));
+
+			Self::deposit_event(Event::LeaseOffered {
+				lease,
+				collection,
+				item,
+				rent,
+				period,
+				periods,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Withdraw lease offer that wasn't accepted yet.
+		///
+		/// The dispatch origin of this call must be Signed by the landlord.
+		///
+		/// - `lease`: Index of the lease
+		///
+		/// Emits `LeaseOfferCancelled`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, lease: LeaseIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Self::leases(lease).ok_or(Error::<T>::UnknownLease)?;
+			ensure!(offer.landlord == who, Error::<T>::NotLandlord);
+			ensure!(offer.tenancy.is_none(), Error::<T>::LeaseActive);
+			Self::remove_lease(lease, &offer);
+
+			Self::deposit_event(Event::LeaseOfferCancelled { lease });
+			Ok(())
+		}
+
+		/// Accept lease offer. Locks the deposit and rent for the first
+		/// period.
+		///
+		/// The dispatch origin of this call must be Signed.
+		///
+		/// - `lease`: Index of the lease
+		///
+		/// Emits `LeaseAccepted`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_lease())]
+		pub fn accept_lease(origin: OriginFor<T>, lease: LeaseIndex) -> DispatchResult {
+			let tenant = ensure_signed(origin)?;
+			let mut offer = Self::leases(lease).ok_or(Error::<T>::UnknownLease)?;
+			ensure!(offer.tenancy.is_none(), Error::<T>::LeaseActive);
+			ensure!(
+				pallet_nfts::Pallet::<T>::owner(offer.collection, offer.item).as_ref()
+					== Some(&offer.landlord),
+				Error::<T>::NotOwner
+			);
+			ensure!(tenant != offer.landlord, Error::<T>::OwnPlot);
+
+			T::RentCurrency::reserve_named(
+				T::ReserveIdentifier::get(),
+				&tenant,
+				offer.rent.saturating_add(offer.deposit),
+			)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let next_payment = Self::schedule_rent(lease, now.saturating_add(offer.period))?;
+			offer.tenancy =
+				Some(Tenancy { tenant: tenant.clone(), next_payment, periods_left: offer.periods });
+			Leases::<T>::insert(lease, offer);
+
+			Self::deposit_event(Event::LeaseAccepted { lease, tenant, next_payment });
+			Ok(())
+		}
+
+		/// End active lease early. If called by tenant, escrowed rent goes
+		/// to the owner. If called by owner of the plot, escrowed rent is
+		/// returned to the tenant. Deposit is returned to the tenant in
+		/// both cases.
+		///
+		/// The dispatch origin of this call must be Signed by the tenant or
+		/// the owner of the plot.
+		///
+		/// - `lease`: Index of the lease
+		///
+		/// Emits `LeaseTerminated`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_lease())]
+		pub fn terminate_lease(origin: OriginFor<T>, lease: LeaseIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let active = Self::leases(lease).ok_or(Error::<T>::UnknownLease)?;
+			let tenancy = active.tenancy.clone().ok_or(Error::<T>::LeaseNotActive)?;
+			let owner = pallet_nfts::Pallet::<T>::owner(active.collection, active.item);
+			let id = T::ReserveIdentifier::get();
+
+			match owner {
+				Some(owner) if who == tenancy.tenant => {
+					T::RentCurrency::repatriate_reserved_named(
+						id,
+						&tenancy.tenant,
+						&owner,
+						active.rent,
+						BalanceStatus::Free,
+					)?;
+				},
+				Some(owner) if who == owner => {
+					T::RentCurrency::unreserve_named(id, &tenancy.tenant, active.rent);
+				},
+				_ => return Err(Error::<T>::NotLeaseParty.into()),
+			}
+			T::RentCurrency::unreserve_named(id, &tenancy.tenant, active.deposit);
+			RentDue::<T>::mutate(tenancy.next_payment, |due| due.retain(|i| *i != lease));
+			Self::remove_lease(lease, &active);
+
+			Self::deposit_event(Event::LeaseTerminated { lease, by: who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Lease or lease offer of the plot
+		pub fn plot_lease(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Option<(LeaseIndex, LeaseOf<T>)> {
+			let index = Self::plot_lease_index(collection, item)?;
+			Self::leases(index).map(|lease| (index, lease))
+		}
+
+		/// Leases where `account` is the landlord or the tenant
+		pub fn leases_of(account: &T::AccountId) -> Vec<(LeaseIndex, LeaseOf<T>)> {
+			Leases::<T>::iter()
+				.filter(|(_, lease)| {
+					&lease.landlord == account
+						|| lease.tenancy.as_ref().map_or(false, |t| &t.tenant == account)
+				})
+				.collect()
+		}
+
+		/// Demarcation of existing plot
+		pub fn plot_demarcation(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Option<Vec<Coords>> {
+			let metadata = pallet_nfts::Pallet::<T>::attribute(&collection, &item, &[])?;
+			T::Demarcation::demarcation(&metadata)
+		}
+
+		fn child_ids(children: &PlotsOf<T>) -> ItemIdsOf<T> {
+			BoundedVec::truncate_from(children.iter().map(|(item, _)| *item).collect())
+		}
+
+		fn plot_items(request: &PlotRequestOf<T>) -> impl Iterator<Item = T::ItemId> + '_ {
+			request
+				.parents
+				.iter()
+				.copied()
+				.chain(request.children.iter().map(|(item, _)| *item))
+		}
+
+		fn submit(request: PlotRequestOf<T>) -> Result<RequestIndex, DispatchError> {
+			Self::ensure_valid(&request)?;
+
+			let index = Self::request_count();
+			for item in Self::plot_items(&request) {
+				ensure!(
+					!PendingPlots::<T>::contains_key(request.collection, item),
+					Error::<T>::PlotPending
+				);
+				PendingPlots::<T>::insert(request.collection, item, index);
+			}
+			Requests::<T>::insert(index, request);
+			RequestCount::<T>::put(index.saturating_add(1));
+			Ok(index)
+		}
+
+		fn take_request(index: RequestIndex) -> Result<PlotRequestOf<T>, DispatchError> {
+			let request = Requests::<T>::take(index).ok_or(Error::<T>::UnknownRequest)?;
+			for item in Self::plot_items(&request) {
+				PendingPlots::<T>::remove(request.collection, item);
+			}
+			Ok(request)
+		}
+
+		fn remove_lease(index: LeaseIndex, lease: &LeaseOf<T>) {
+			Leases::<T>::remove(index);
+			PlotLeases::<T>::remove(lease.collection, lease.item);
+		}
+
+		fn schedule_rent(
+			index: LeaseIndex,
+			mut at: BlockNumberFor<T>,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			for _ in 0..RENT_SCHEDULE_ATTEMPTS {
+				if RentDue::<T>::try_append(at, index).is_ok() {
+					return Ok(at);
+				}
+				at = at.saturating_add(1u32.into());
+			}
+			Err(Error::<T>::TooManyLeases.into())
+		}
+
+		fn pay_rent(index: LeaseIndex, mut lease: LeaseOf<T>, now: BlockNumberFor<T>) {
+			let mut tenancy = match lease.tenancy.take() {
+				Some(tenancy) => tenancy,
+				None => return,
+			};
+			let id = T::ReserveIdentifier::get();
+			let tenant = tenancy.tenant.clone();
+
+			let owner = match pallet_nfts::Pallet::<T>::owner(lease.collection, lease.item) {
+				Some(owner) => owner,
+				None => {
+					// plot was burned - nothing to pay for
+					T::RentCurrency::unreserve_named(
+						id,
+						&tenant,
+						lease.rent.saturating_add(lease.deposit),
+					);
+					Self::remove_lease(index, &lease);
+					Self::deposit_event(Event::LeaseEnded { lease: index });
+					return;
+				},
+			};
+
+			let _ = T::RentCurrency::repatriate_reserved_named(
+				id,
+				&tenant,
+				&owner,
+				lease.rent,
+				BalanceStatus::Free,
+			);
+			Self::deposit_event(Event::RentPaid {
+				lease: index,
+				owner: owner.clone(),
+				amount: lease.rent,
+			});
+
+			tenancy.periods_left = tenancy.periods_left.saturating_sub(1);
+			if tenancy.periods_left == 0 {
+				T::RentCurrency::unreserve_named(id, &tenant, lease.deposit);
+				Self::remove_lease(index, &lease);
+				Self::deposit_event(Event::LeaseEnded { lease: index });
+				return;
+			}
+
+			if T::RentCurrency::reserve_named(id, &tenant, lease.rent).is_err() {
+				let _ = T::RentCurrency::repatriate_reserved_named(
+					id,
+					&tenant,
+					&owner,
+					lease.deposit,
+					BalanceStatus::Free,
+				);
+				Self::remove_lease(index, &lease);
+				Self::deposit_event(Event::LeaseDefaulted { lease: index });
+				return;
+			}
+
+			match Self::schedule_rent(index, now.saturating_add(lease.period)) {
+				Ok(next_payment) => {
+					tenancy.next_payment = next_payment;
+					lease.tenancy = Some(tenancy);
+					Leases::<T>::insert(index, lease);
+				},
+				Err(_) => {
+					T::RentCurrency::unreserve_named(
+						id,
+						&tenant,
+						lease.rent.saturating_add(lease.deposit),
+					);
+					Self::remove_lease(index, &lease);
+					Self::deposit_event(Event::LeaseEnded { lease: index });
+				},
+			}
+		}
+
+		fn ensure_valid(request: &PlotRequestOf<T>) -> DispatchResult {
+			let collection = request.collection;
+			ensure!(T::LandCollections::contains(&collection), Error::<T>::NotLandCollection);
+
+			let mut seen = Vec::new();
+			for item in Self::plot_items(request) {
+				ensure!(!seen.contains(&item), Error::<T>::DuplicatePlot);
+				seen.push(item);
+			}
+
+			let mut parents = Vec::new();
+			for parent in request.parents.iter() {
+				ensure!(
+					!PlotLeases::<T>::contains_key(collection, *parent),
+					Error::<T>::PlotLeased
+				);
+				ensure!(
+					pallet_nfts::Pallet::<T>::owner(collection, *parent).as_ref()
+						== Some(&request.requester),
+					Error::<T>::NotOwner
+				);
+				parents.push(
+					Self::plot_demarcation(collection, *parent)
+						.ok_or(Error::<T>::InvalidDemarcation)?,
+				);
+			}
+
+			let mut children = Vec::new();
+			for (child, metadata) in request.children.iter() {
+				ensure!(
+					pallet_nfts::Pallet::<T>::owner(collection, *child).is_none(),
+					Error::<T>::PlotExists
+				);
+				children.push(
+					T::Demarcation::demarcation(metadata).ok_or(Error::<T>::InvalidDemarcation)?,
+				);
+			}
+
+			let total_area = |plots: &[Vec<Coords>]| {
+				plots.iter().fold(0u128, |acc, plot| acc.saturating_add(doubled_area(plot)))
+			};
+			ensure!(total_area(&parents) == total_area(&children), Error::<T>::AreaMismatch);
+			ensure!(connected(&parents), Error::<T>::NotAdjacent);
+			Ok(())
+		}
+	}
+}
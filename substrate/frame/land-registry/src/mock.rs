@@ -0,0 +1,192 @@
+#![cfg(test)]
+pub use crate as pallet_land_registry;
+
+use crate::{Coords, PlotDemarcation};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_ok, ord_parameter_types, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Contains},
+	BoundedVec,
+};
+use frame_system::EnsureSignedBy;
+use pallet_balances::AccountData;
+use pallet_nfts::PalletFeatures;
+use sp_core::{ConstU16, H256};
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+ord_parameter_types! {
+	pub const Office: u64 = 1;
+}
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Nfts: pallet_nfts,
+		LandRegistry: pallet_land_registry,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ConstU32<10>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub LeaseReserveIdentifier: &'static [u8; 8] = b"landleas";
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<200>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type Features = Features;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = <TestSignature as sp_runtime::traits::Verify>::Signer;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type Citizenship = ();
+	type MetadataValidator = ();
+}
+
+pub struct LandCollections;
+impl Contains<u32> for LandCollections {
+	fn contains(collection: &u32) -> bool {
+		*collection == 0
+	}
+}
+
+/// Metadata is just SCALE-encoded demarcation
+pub struct MockDemarcation;
+impl PlotDemarcation for MockDemarcation {
+	fn demarcation(data: &[u8]) -> Option<Vec<Coords>> {
+		Vec::<Coords>::decode(&mut &data[..]).ok().filter(|d| d.len() >= 3)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32> for MockDemarcation {
+	fn collection() -> u32 {
+		0
+	}
+	fn anchor() -> Coords {
+		Coords { lat: 0, long: 0 }
+	}
+	fn metadata(demarcation: Vec<Coords>) -> Vec<u8> {
+		demarcation.encode()
+	}
+}
+
+impl pallet_land_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OfficeOrigin = EnsureSignedBy<Office, u64>;
+	type LandCollections = LandCollections;
+	type Demarcation = MockDemarcation;
+	type MaxPlots = ConstU32<4>;
+	type RentCurrency = Balances;
+	type ReserveIdentifier = LeaseReserveIdentifier;
+	type MaxLeasesPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockDemarcation;
+}
+
+/// Axis-aligned rectangle
+pub fn rect(lat: i64, long: i64, height: i64, width: i64) -> Vec<Coords> {
+	vec![
+		Coords { lat, long },
+		Coords { lat, long: long + width },
+		Coords { lat: lat + height, long: long + width },
+		Coords { lat: lat + height, long },
+	]
+}
+
+pub fn metadata(demarcation: Vec<Coords>) -> BoundedVec<u8, ConstU32<200>> {
+	demarcation.encode().try_into().unwrap()
+}
+
+/// Mints plot in land collection 0 to `owner`
+pub fn mint_plot(item: u32, owner: u64, demarcation: Vec<Coords>) {
+	assert_ok!(Nfts::force_mint(RuntimeOrigin::root(), 0, item, owner, Default::default()));
+	assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, item, metadata(demarcation)));
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let balances = vec![(1, 100), (2, 100), (3, 100)];
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, Default::default()));
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, Default::default()));
+	});
+	ext
+}
@@ -0,0 +1,385 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Hooks, ReservableCurrency},
+};
+use sp_runtime::DispatchError;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		LandRegistry::on_initialize(next);
+	}
+}
+
+fn children(plots: Vec<(u32, Vec<crate::Coords>)>) -> crate::PlotsOf<Test> {
+	let plots: Vec<_> = plots.into_iter().map(|(item, d)| (item, metadata(d))).collect();
+	plots.try_into().unwrap()
+}
+
+fn items(items: Vec<u32>) -> crate::ItemIdsOf<Test> {
+	items.try_into().unwrap()
+}
+
+fn request_split() {
+	mint_plot(1, 2, rect(0, 0, 10, 20));
+	assert_ok!(LandRegistry::request_split(
+		RuntimeOrigin::signed(2),
+		0,
+		1,
+		children(vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 15))]),
+	));
+}
+
+fn request_merge() {
+	mint_plot(1, 2, rect(0, 0, 10, 5));
+	mint_plot(2, 2, rect(0, 5, 10, 15));
+	assert_ok!(LandRegistry::request_merge(
+		RuntimeOrigin::signed(2),
+		0,
+		items(vec![1, 2]),
+		3,
+		metadata(rect(0, 0, 10, 20)),
+	));
+}
+
+#[test]
+fn request_split_works() {
+	new_test_ext().execute_with(|| {
+		request_split();
+		let request = LandRegistry::requests(0).unwrap();
+		assert_eq!(request.requester, 2);
+		assert_eq!(request.parents.into_inner(), vec![1]);
+		assert_eq!(LandRegistry::pending_plot(0, 1), Some(0));
+		assert_eq!(LandRegistry::pending_plot(0, 3), Some(0));
+		System::assert_last_event(
+			Event::<Test>::SplitRequested {
+				index: 0,
+				collection: 0,
+				parent: 1,
+				children: items(vec![2, 3]),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn request_split_validates_plots() {
+	new_test_ext().execute_with(|| {
+		mint_plot(1, 2, rect(0, 0, 10, 20));
+		mint_plot(5, 3, rect(0, 20, 10, 20));
+		let split = |who, collection, parent, plots| {
+			LandRegistry::request_split(
+				RuntimeOrigin::signed(who),
+				collection,
+				parent,
+				children(plots),
+			)
+		};
+
+		assert_noop!(split(2, 0, 1, vec![(2, rect(0, 0, 10, 20))]), Error::<Test>::TooFewPlots);
+		assert_noop!(
+			split(2, 1, 1, vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 15))]),
+			Error::<Test>::NotLandCollection
+		);
+		assert_noop!(
+			split(3, 0, 1, vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 15))]),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			split(2, 0, 1, vec![(2, rect(0, 0, 10, 5)), (5, rect(0, 5, 10, 15))]),
+			Error::<Test>::PlotExists
+		);
+		assert_noop!(
+			split(2, 0, 1, vec![(2, rect(0, 0, 10, 5)), (2, rect(0, 5, 10, 15))]),
+			Error::<Test>::DuplicatePlot
+		);
+		assert_noop!(
+			split(2, 0, 1, vec![(2, rect(0, 0, 10, 5)), (3, vec![])]),
+			Error::<Test>::InvalidDemarcation
+		);
+		assert_noop!(
+			split(2, 0, 1, vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 10))]),
+			Error::<Test>::AreaMismatch
+		);
+
+		assert_ok!(split(2, 0, 1, vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 15))]));
+		assert_noop!(
+			split(2, 0, 1, vec![(6, rect(0, 0, 10, 5)), (7, rect(0, 5, 10, 15))]),
+			Error::<Test>::PlotPending
+		);
+	});
+}
+
+#[test]
+fn request_merge_requires_adjacent_plots() {
+	new_test_ext().execute_with(|| {
+		mint_plot(1, 2, rect(0, 0, 10, 5));
+		mint_plot(2, 2, rect(0, 6, 10, 5));
+		// touching only in a corner isn't enough
+		mint_plot(3, 2, rect(10, 5, 10, 5));
+		let merge = |parents, plot| {
+			LandRegistry::request_merge(
+				RuntimeOrigin::signed(2),
+				0,
+				items(parents),
+				9,
+				metadata(plot),
+			)
+		};
+
+		assert_noop!(merge(vec![1], rect(0, 0, 10, 5)), Error::<Test>::TooFewPlots);
+		assert_noop!(merge(vec![1, 2], rect(0, 0, 10, 10)), Error::<Test>::NotAdjacent);
+		assert_noop!(merge(vec![1, 3], rect(0, 0, 10, 10)), Error::<Test>::NotAdjacent);
+
+		// partially shared border is enough
+		mint_plot(4, 2, rect(-5, 5, 10, 1));
+		assert_ok!(merge(vec![1, 4, 2], rect(0, 0, 10, 11)));
+	});
+}
+
+#[test]
+fn approve_split_works() {
+	new_test_ext().execute_with(|| {
+		request_split();
+		assert_noop!(LandRegistry::approve(RuntimeOrigin::signed(2), 0), DispatchError::BadOrigin);
+		assert_ok!(LandRegistry::approve(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(Nfts::owner(0, 1), None);
+		assert_eq!(Nfts::owner(0, 2), Some(2));
+		assert_eq!(Nfts::owner(0, 3), Some(2));
+		assert_eq!(LandRegistry::plot_demarcation(0, 3), Some(rect(0, 5, 10, 15)));
+		assert!(LandRegistry::requests(0).is_none());
+		assert_eq!(LandRegistry::pending_plot(0, 1), None);
+		System::assert_last_event(
+			Event::<Test>::PlotSplit {
+				index: 0,
+				collection: 0,
+				parent: 1,
+				children: items(vec![2, 3]),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn approve_merge_works() {
+	new_test_ext().execute_with(|| {
+		request_merge();
+		assert_ok!(LandRegistry::approve(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(Nfts::owner(0, 1), None);
+		assert_eq!(Nfts::owner(0, 2), None);
+		assert_eq!(Nfts::owner(0, 3), Some(2));
+		assert_eq!(LandRegistry::plot_demarcation(0, 3), Some(rect(0, 0, 10, 20)));
+		System::assert_last_event(
+			Event::<Test>::PlotsMerged {
+				index: 0,
+				collection: 0,
+				parents: items(vec![1, 2]),
+				child: 3,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn approve_rechecks_ownership() {
+	new_test_ext().execute_with(|| {
+		request_split();
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 1, 3));
+		assert_noop!(LandRegistry::approve(RuntimeOrigin::signed(1), 0), Error::<Test>::NotOwner);
+		assert!(LandRegistry::requests(0).is_some());
+	});
+}
+
+#[test]
+fn reject_and_cancel_work() {
+	new_test_ext().execute_with(|| {
+		request_split();
+		assert_noop!(LandRegistry::reject(RuntimeOrigin::signed(2), 0), DispatchError::BadOrigin);
+		assert_ok!(LandRegistry::reject(RuntimeOrigin::signed(1), 0));
+		assert!(LandRegistry::requests(0).is_none());
+		assert_eq!(LandRegistry::pending_plot(0, 1), None);
+		assert_eq!(Nfts::owner(0, 1), Some(2));
+		System::assert_last_event(Event::<Test>::RequestRejected { index: 0 }.into());
+
+		assert_ok!(LandRegistry::request_split(
+			RuntimeOrigin::signed(2),
+			0,
+			1,
+			children(vec![(2, rect(0, 0, 5, 20)), (3, rect(5, 0, 5, 20))]),
+		));
+		assert_noop!(
+			LandRegistry::cancel(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NotRequester
+		);
+		assert_ok!(LandRegistry::cancel(RuntimeOrigin::signed(2), 1));
+		assert!(LandRegistry::requests(1).is_none());
+		System::assert_last_event(Event::<Test>::RequestCancelled { index: 1 }.into());
+		assert_noop!(
+			LandRegistry::cancel(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::UnknownRequest
+		);
+	});
+}
+
+/// Plot 1 owned by 2, leased to 3 for 2 periods of 5 blocks, rent 10,
+/// deposit 20
+fn leased() {
+	mint_plot(1, 2, rect(0, 0, 10, 20));
+	assert_ok!(LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 5, 2, 20));
+	assert_ok!(LandRegistry::accept_lease(RuntimeOrigin::signed(3), 0));
+}
+
+#[test]
+fn offer_lease_validates_params() {
+	new_test_ext().execute_with(|| {
+		mint_plot(1, 2, rect(0, 0, 10, 20));
+		assert_noop!(
+			LandRegistry::offer_lease(RuntimeOrigin::signed(3), 0, 1, 10, 5, 2, 20),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 0, 2, 20),
+			Error::<Test>::InvalidLease
+		);
+		assert_noop!(
+			LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 5, 0, 20),
+			Error::<Test>::InvalidLease
+		);
+		assert_ok!(LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 5, 2, 20));
+		System::assert_last_event(
+			Event::<Test>::LeaseOffered {
+				lease: 0,
+				collection: 0,
+				item: 1,
+				rent: 10,
+				period: 5,
+				periods: 2,
+				deposit: 20,
+			}
+			.into(),
+		);
+		assert_noop!(
+			LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 5, 2, 20),
+			Error::<Test>::PlotLeased
+		);
+		assert_noop!(
+			LandRegistry::request_split(
+				RuntimeOrigin::signed(2),
+				0,
+				1,
+				children(vec![(2, rect(0, 0, 10, 5)), (3, rect(0, 5, 10, 15))]),
+			),
+			Error::<Test>::PlotLeased
+		);
+
+		assert_noop!(
+			LandRegistry::accept_lease(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::OwnPlot
+		);
+		assert_noop!(
+			LandRegistry::cancel_offer(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotLandlord
+		);
+		assert_ok!(LandRegistry::cancel_offer(RuntimeOrigin::signed(2), 0));
+		assert!(LandRegistry::plot_lease(0, 1).is_none());
+		System::assert_last_event(Event::<Test>::LeaseOfferCancelled { lease: 0 }.into());
+	});
+}
+
+#[test]
+fn accept_lease_locks_first_payment() {
+	new_test_ext().execute_with(|| {
+		leased();
+		assert_eq!(Balances::reserved_balance(3), 30);
+		let (index, lease) = LandRegistry::plot_lease(0, 1).unwrap();
+		assert_eq!(index, 0);
+		let tenancy = lease.tenancy.unwrap();
+		assert_eq!((tenancy.tenant, tenancy.next_payment, tenancy.periods_left), (3, 6, 2));
+		assert_eq!(LandRegistry::rent_due(6).into_inner(), vec![0]);
+		assert_eq!(LandRegistry::leases_of(&3).len(), 1);
+		System::assert_last_event(
+			Event::<Test>::LeaseAccepted { lease: 0, tenant: 3, next_payment: 6 }.into(),
+		);
+		assert_noop!(
+			LandRegistry::cancel_offer(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::LeaseActive
+		);
+	});
+}
+
+#[test]
+fn rent_is_paid_each_period() {
+	new_test_ext().execute_with(|| {
+		leased();
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(2), 100);
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 110);
+		assert_eq!(Balances::reserved_balance(3), 30);
+		assert_eq!(Balances::free_balance(3), 60);
+
+		run_to_block(11);
+		assert_eq!(Balances::free_balance(2), 120);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 80);
+		assert!(LandRegistry::leases(0).is_none());
+		assert!(LandRegistry::plot_lease(0, 1).is_none());
+		System::assert_last_event(Event::<Test>::LeaseEnded { lease: 0 }.into());
+	});
+}
+
+#[test]
+fn unpaid_rent_forfeits_deposit() {
+	new_test_ext().execute_with(|| {
+		leased();
+		Balances::make_free_balance_be(&3, 1);
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 130);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(LandRegistry::leases(0).is_none());
+		System::assert_last_event(Event::<Test>::LeaseDefaulted { lease: 0 }.into());
+	});
+}
+
+#[test]
+fn terminate_lease_works() {
+	new_test_ext().execute_with(|| {
+		leased();
+		assert_noop!(
+			LandRegistry::terminate_lease(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NotLeaseParty
+		);
+		assert_ok!(LandRegistry::terminate_lease(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Balances::free_balance(2), 110);
+		assert_eq!(Balances::free_balance(3), 90);
+		assert!(LandRegistry::rent_due(6).is_empty());
+		System::assert_last_event(Event::<Test>::LeaseTerminated { lease: 0, by: 3 }.into());
+
+		assert_ok!(LandRegistry::offer_lease(RuntimeOrigin::signed(2), 0, 1, 10, 5, 2, 20));
+		assert_ok!(LandRegistry::accept_lease(RuntimeOrigin::signed(3), 1));
+		assert_ok!(LandRegistry::terminate_lease(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Balances::free_balance(2), 110);
+		assert_eq!(Balances::free_balance(3), 90);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_noop!(
+			LandRegistry::terminate_lease(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::UnknownLease
+		);
+	});
+}
+
+#[test]
+fn doubled_area_works() {
+	assert_eq!(crate::doubled_area(&rect(0, 0, 10, 20)), 400);
+	let triangle = rect(0, 0, 10, 20).into_iter().take(3).collect::<Vec<_>>();
+	assert_eq!(crate::doubled_area(&triangle), 200);
+}
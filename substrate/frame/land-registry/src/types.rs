@@ -0,0 +1,138 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{prelude::*, vec};
+
+pub type RequestIndex = u32;
+
+/// Point of plot's demarcation, in degrees * 10^7
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Coords {
+	pub lat: i64,
+	pub long: i64,
+}
+
+/// Pending split or merge of plots. Split has a single parent, merge has a
+/// single child.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PlotRequest<AccountId, CollectionId, ItemIds, Plots> {
+	/// Owner of parent plots, receives the child plots
+	pub requester: AccountId,
+	/// Land collection containing all plots
+	pub collection: CollectionId,
+	/// Plots burned on approval
+	pub parents: ItemIds,
+	/// `(item, metadata)` of plots minted on approval
+	pub children: Plots,
+}
+
+pub type LeaseIndex = u32;
+
+/// Lease of a plot. Created as an offer by plot's owner, becomes active when
+/// tenant accepts it.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Lease<AccountId, CollectionId, ItemId, Balance, BlockNumber> {
+	/// Land collection of the plot
+	pub collection: CollectionId,
+	/// Leased plot
+	pub item: ItemId,
+	/// Account that offered the lease
+	pub landlord: AccountId,
+	/// Rent paid at the end of each period
+	pub rent: Balance,
+	/// Length of a single period in blocks
+	pub period: BlockNumber,
+	/// Number of periods the lease lasts
+	pub periods: u32,
+	/// Security deposit held from the tenant for the duration of the lease
+	pub deposit: Balance,
+	/// Set once the offer is accepted
+	pub tenancy: Option<Tenancy<AccountId, BlockNumber>>,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Tenancy<AccountId, BlockNumber> {
+	/// Account renting the plot
+	pub tenant: AccountId,
+	/// Block at which escrowed rent for the current period is paid out
+	pub next_payment: BlockNumber,
+	/// Number of periods not paid for yet, including the current one
+	pub periods_left: u32,
+}
+
+/// Extracts plot's demarcation from its NFT metadata.
+pub trait PlotDemarcation {
+	/// Returns demarcation polygon without the closing point, or `None` if
+	/// metadata doesn't describe a valid plot.
+	fn demarcation(data: &[u8]) -> Option<Vec<Coords>>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CollectionId> {
+	/// Land collection used in benchmarks. Benchmarks create it, so it
+	/// should be the next free collection id.
+	fn collection() -> CollectionId;
+	/// Point from which benchmark plots are laid out
+	fn anchor() -> Coords;
+	/// Plot metadata with given demarcation
+	fn metadata(demarcation: Vec<Coords>) -> Vec<u8>;
+}
+
+/// Doubled area of the polygon (shoelace formula). Doubling keeps it exact
+/// for integer coordinates.
+pub fn doubled_area(demarcation: &[Coords]) -> u128 {
+	let n = demarcation.len();
+	let mut sum: i128 = 0;
+	for i in 0..n {
+		let a = demarcation[i];
+		let b = demarcation[(i + 1) % n];
+		sum += (a.lat as i128) * (b.long as i128) - (b.lat as i128) * (a.long as i128);
+	}
+	sum.unsigned_abs()
+}
+
+fn cross(o: Coords, a: Coords, b: Coords) -> i128 {
+	(a.lat as i128 - o.lat as i128) * (b.long as i128 - o.long as i128)
+		- (a.long as i128 - o.long as i128) * (b.lat as i128 - o.lat as i128)
+}
+
+/// Checks if segments lie on the same line and overlap on more than a
+/// single point.
+fn segments_overlap(a: (Coords, Coords), b: (Coords, Coords)) -> bool {
+	if cross(a.0, a.1, b.0) != 0 || cross(a.0, a.1, b.1) != 0 {
+		return false;
+	}
+	let project = |c: Coords| if a.0.lat != a.1.lat { c.lat } else { c.long };
+	let (a0, a1) = (project(a.0), project(a.1));
+	let (b0, b1) = (project(b.0), project(b.1));
+	a0.min(a1).max(b0.min(b1)) < a0.max(a1).min(b0.max(b1))
+}
+
+fn edges(demarcation: &[Coords]) -> impl Iterator<Item = (Coords, Coords)> + '_ {
+	let n = demarcation.len();
+	(0..n).map(move |i| (demarcation[i], demarcation[(i + 1) % n]))
+}
+
+/// Plots are adjacent if they share a piece of border
+pub fn adjacent(a: &[Coords], b: &[Coords]) -> bool {
+	edges(a).any(|ea| edges(b).any(|eb| segments_overlap(ea, eb)))
+}
+
+/// Checks if all plots form a single connected area
+pub fn connected(plots: &[Vec<Coords>]) -> bool {
+	if plots.is_empty() {
+		return true;
+	}
+	let mut reached = vec![false; plots.len()];
+	let mut queue = vec![0];
+	reached[0] = true;
+	while let Some(i) = queue.pop() {
+		for j in 0..plots.len() {
+			if !reached[j] && adjacent(&plots[i], &plots[j]) {
+				reached[j] = true;
+				queue.push(j);
+			}
+		}
+	}
+	reached.into_iter().all(|r| r)
+}
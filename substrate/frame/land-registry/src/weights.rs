@@ -0,0 +1,178 @@
+//! Weights for pallet_land_registry
+//!
+//! NOT BENCHMARKED. Estimated from pallet_nfts weights of burn, mint and
+//! set_metadata and pallet_balances reserve weights, plus this pallet's own
+//! storage accesses. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_land_registry` run
+//! on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_land_registry.
+pub trait WeightInfo {
+	fn request_split(c: u32, ) -> Weight;
+	fn request_merge(p: u32, ) -> Weight;
+	fn approve(p: u32, ) -> Weight;
+	fn reject(p: u32, ) -> Weight;
+	fn cancel(p: u32, ) -> Weight;
+	fn offer_lease() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn accept_lease() -> Weight;
+	fn terminate_lease() -> Weight;
+	fn on_initialize(l: u32, ) -> Weight;
+}
+
+/// Weights for pallet_land_registry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn request_split(c: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 4326)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(c.into()))
+	}
+	fn request_merge(p: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 4326)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(p.into()))
+	}
+	fn approve(p: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 4326)
+			.saturating_add(Weight::from_parts(75_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(p.into()))
+	}
+	fn reject(p: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3541)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn cancel(p: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3541)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn offer_lease() -> Weight {
+		Weight::from_parts(30_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn accept_lease() -> Weight {
+		Weight::from_parts(45_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn terminate_lease() -> Weight {
+		Weight::from_parts(55_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn on_initialize(l: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 3024)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(l.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn request_split(c: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 4326)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(c.into()))
+	}
+	fn request_merge(p: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 4326)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(p.into()))
+	}
+	fn approve(p: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 4326)
+			.saturating_add(Weight::from_parts(75_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3070).saturating_mul(p.into()))
+	}
+	fn reject(p: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3541)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn cancel(p: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3541)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn offer_lease() -> Weight {
+		Weight::from_parts(30_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn accept_lease() -> Weight {
+		Weight::from_parts(45_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn terminate_lease() -> Weight {
+		Weight::from_parts(55_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn on_initialize(l: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 3024)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(l.into()))
+	}
+}
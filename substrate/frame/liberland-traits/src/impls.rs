@@ -36,6 +36,10 @@ where
 		*account == C1::get() || *account == C2::get()
 	}
 
+	fn is_eresident(_account: &T) -> bool {
+		false
+	}
+
 	fn citizens_count() -> u64 {
 		2
 	}
@@ -50,6 +54,11 @@ impl<T> OnLLMPoliticsUnlock<T> for () {
 	}
 }
 
+impl<T> OnCitizenshipChange<T> for () {
+	fn on_citizenship_granted(_account: &T) {}
+	fn on_citizenship_revoked(_account: &T) {}
+}
+
 /// Noop implementation of CitizenshipChecker - mostly to be used in tests to
 /// prevent circular dependencies
 impl<T> CitizenshipChecker<T> for () {
@@ -73,6 +82,10 @@ impl<T> CitizenshipChecker<T> for () {
 		false
 	}
 
+	fn is_eresident(_account: &T) -> bool {
+		false
+	}
+
 	fn citizens_count() -> u64 {
 		0
 	}
@@ -3,7 +3,9 @@
 use sp_runtime::DispatchError;
 
 mod impls;
+mod origins;
 pub use impls::*;
+pub use origins::*;
 
 pub trait LLInitializer<AccountId> {
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -14,6 +16,12 @@ pub trait OnLLMPoliticsUnlock<AccountId> {
 	fn on_llm_politics_unlock(account: &AccountId) -> Result<(), DispatchError>;
 }
 
+/// Notified when account becomes a citizen or stops being one
+pub trait OnCitizenshipChange<AccountId> {
+	fn on_citizenship_granted(account: &AccountId);
+	fn on_citizenship_revoked(account: &AccountId);
+}
+
 /// trait for LLM methods that only interact with LLM pallet
 pub trait LLM<AccountId, Balance> {
 	/// check if sender has any LLM politipooled
@@ -55,6 +63,14 @@ pub trait CitizenshipChecker<AccountId> {
 	/// Check if given account is a citizen (valid identity + KnownGood judgement)
 	fn is_citizen(account: &AccountId) -> bool;
 
+	/// Check if given account is an e-resident. E-residents need to:
+	/// * have valid eresident identity, without citizen field
+	/// * have a KnownGood judgement
+	/// * have LLM politipooled, less than citizens need
+	///
+	/// E-residents have no politics rights.
+	fn is_eresident(account: &AccountId) -> bool;
+
 	/// Calculate number of valid citizens (valid identities + KnownGood
 	/// judgements).
 	fn citizens_count() -> u64;
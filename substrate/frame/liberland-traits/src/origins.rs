@@ -0,0 +1,50 @@
+use frame_support::{dispatch::RawOrigin, traits::EnsureOrigin};
+use sp_std::marker::PhantomData;
+
+use super::*;
+
+/// Ensures origin is signed by a citizen, as reported by `C`. Returns
+/// citizen's account.
+pub struct EnsureCitizen<C, AccountId>(PhantomData<(C, AccountId)>);
+impl<O, C, AccountId> EnsureOrigin<O> for EnsureCitizen<C, AccountId>
+where
+	O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>,
+	C: CitizenshipChecker<AccountId>,
+{
+	type Success = AccountId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if C::is_citizen(&who) => Ok(who),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Err(())
+	}
+}
+
+/// Ensures origin is signed by a citizen or an e-resident, as reported by
+/// `C`. Returns signer's account.
+pub struct EnsureResidentOrCitizen<C, AccountId>(PhantomData<(C, AccountId)>);
+impl<O, C, AccountId> EnsureOrigin<O> for EnsureResidentOrCitizen<C, AccountId>
+where
+	O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>,
+	C: CitizenshipChecker<AccountId>,
+{
+	type Success = AccountId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if C::is_citizen(&who) || C::is_eresident(&who) => Ok(who),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Err(())
+	}
+}
@@ -0,0 +1,64 @@
+//! Runtime API for citizenship and LLM politics queries.
+//!
+//! Served by the node as `liberland_isCitizen`, `liberland_pooledLLM` and
+//! `liberland_electionSnapshot` RPCs.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_api::vec::Vec;
+use sp_runtime::RuntimeDebug;
+
+/// LLM account has in politics
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PooledLLM<AccountId, Balance, BlockNumber> {
+	/// LLM locked in politics
+	pub pooled: Balance,
+	/// LLM unlocked from politics, waiting for unpool cooldown
+	pub pending_unpool: Balance,
+	/// Politics power delegated to the account
+	pub delegated_power: Balance,
+	/// Account the account's politics power is delegated to, and delegated
+	/// amount
+	pub delegation: Option<(AccountId, Balance)>,
+	/// Block until which the account can't participate in politics directly
+	pub election_lock: BlockNumber,
+}
+
+/// Current state of the council election
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct ElectionSnapshot<AccountId, Balance> {
+	/// Number of completed election rounds
+	pub round: u32,
+	/// Council members with their backing stake
+	pub members: Vec<(AccountId, Balance)>,
+	/// Runners-up with their backing stake
+	pub runners_up: Vec<(AccountId, Balance)>,
+	/// Candidates for the next round
+	pub candidates: Vec<AccountId>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait LLMApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns whether `account` is a citizen.
+		fn is_citizen(account: AccountId) -> bool;
+
+		/// Returns LLM `account` has in politics.
+		fn pooled_llm(account: AccountId) -> PooledLLM<AccountId, Balance, BlockNumber>;
+
+		/// Returns current state of the council election.
+		fn election_snapshot() -> ElectionSnapshot<AccountId, Balance>;
+
+		/// Returns total LLM locked in politics.
+		fn total_pooled() -> Balance;
+
+		/// Returns number of citizens.
+		fn citizens() -> u64;
+	}
+}
@@ -10,15 +10,70 @@ THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR I
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{LLMPolitics, Pallet as LLM};
+use crate::{
+	CitizenshipExpiry, Delegations, ExpiredCitizenships, LLMPolitics, LLMSpendApprovals,
+	LLMSpendProposals, Pallet as LLM, PendingUnpool, UnpoolQueue,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use pallet_identity::{Data, IdentityInfo, Judgement};
 use sp_core::Get;
 use sp_runtime::{BoundedVec, Saturating};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+fn make_citizen<T: Config>(account: &T::AccountId) {
+	let data = Data::Raw(b"1".to_vec().try_into().unwrap());
+	let eligible_on = (
+		Data::Raw(b"eligible_on".to_vec().try_into().unwrap()),
+		Data::Raw(vec![0].try_into().unwrap()),
+	);
+	let citizen = (Data::Raw(b"citizen".to_vec().try_into().unwrap()), data);
+	let info = IdentityInfo {
+		additional: vec![eligible_on, citizen].try_into().unwrap(),
+		..Default::default()
+	};
+	let judgements = vec![(0u32, Judgement::KnownGood)].try_into().unwrap();
+	pallet_identity::Pallet::<T>::set_identity_no_deposit(account, judgements, info);
+}
+
+/// LLM covering spend of `value` and its bond
+fn llm_spend_funds<T: Config>(value: BalanceOfAssets<T>) -> BalanceOfAssets<T> {
+	let minimum: BalanceOfAssets<T> =
+		T::LLMSpendProposalBondMinimum::get().try_into().ok().unwrap();
+	value.saturating_mul(2u8.into()).saturating_add(minimum)
+}
+
+/// Proposes `n` LLM spends of `value` each and returns the proposer
+fn llm_spend_proposals<T: Config>(n: u32, value: BalanceOfAssets<T>) -> T::AccountId {
+	let proposer: T::AccountId = account("proposer", 0, SEED);
+	let funds = llm_spend_funds::<T>(value).saturating_mul(n.into());
+	LLM::<T>::transfer_from_treasury(proposer.clone(), funds).unwrap();
+	for i in 0..n {
+		let beneficiary: T::AccountId = account("beneficiary", i, SEED);
+		LLM::<T>::propose_llm_spend(RawOrigin::Signed(proposer.clone()).into(), value, beneficiary)
+			.unwrap();
+	}
+	proposer
+}
+
+/// Builds chain of `len` delegating citizens and returns its first account
+fn delegation_chain<T: Config>(len: u32) -> T::AccountId {
+	let amount: BalanceOfAssets<T> = 100u8.into();
+	let accounts: Vec<T::AccountId> = (0..len).map(|i| account("delegate", i, SEED)).collect();
+	for acc in accounts.iter() {
+		make_citizen::<T>(acc);
+		LLM::<T>::transfer_from_treasury(acc.clone(), amount).unwrap();
+		LLM::<T>::politics_lock(RawOrigin::Signed(acc.clone()).into(), amount).unwrap();
+	}
+	for pair in accounts.windows(2).rev() {
+		LLM::<T>::delegate(RawOrigin::Signed(pair[0].clone()).into(), pair[1].clone(), amount)
+			.unwrap();
+	}
+	accounts[0].clone()
+}
+
 benchmarks! {
 	politics_lock {
 		let user: T::AccountId = account("user", 0, SEED);
@@ -136,6 +191,145 @@ benchmarks! {
 	verify {
 		assert_eq!(Courts::<T>::get(), courts);
 	}
+
+	court_lld_transfer {
+		let court: T::AccountId = account("court", 0, SEED);
+		let user: T::AccountId = account("user", 0, SEED);
+		let amount = <<T as Config>::Currency as Currency<T::AccountId>>::minimum_balance();
+		<<T as Config>::Currency as Currency<T::AccountId>>::make_free_balance_be(
+			&LLM::<T>::get_llm_treasury_account(),
+			amount.saturating_mul(2u8.into()),
+		);
+		LLM::<T>::set_courts(RawOrigin::Root.into(), vec![court.clone()].try_into().unwrap()).unwrap();
+	}: _(RawOrigin::Signed(court), user.clone(), amount.clone())
+	verify {
+		assert_eq!(<<T as Config>::Currency as Currency<T::AccountId>>::total_balance(&user), amount);
+	}
+
+	revoke_citizenship {
+		let court: T::AccountId = account("court", 0, SEED);
+		let user: T::AccountId = account("user", 0, SEED);
+		make_citizen::<T>(&user);
+		LLM::<T>::set_courts(RawOrigin::Root.into(), vec![court.clone()].try_into().unwrap()).unwrap();
+		assert!(LLM::<T>::is_citizen(&user));
+	}: _(RawOrigin::Signed(court), user.clone())
+	verify {
+		assert!(!LLM::<T>::is_citizen(&user));
+	}
+
+	delegate {
+		let d in 1 .. T::MaxDelegationDepth::get();
+		let to = delegation_chain::<T>(d);
+		let user: T::AccountId = account("user", 0, SEED);
+		let amount: BalanceOfAssets<T> = 100u8.into();
+		LLM::<T>::transfer_from_treasury(user.clone(), amount).unwrap();
+		LLM::<T>::politics_lock(RawOrigin::Signed(user.clone()).into(), amount).unwrap();
+	}: _(RawOrigin::Signed(user.clone()), to.clone(), amount)
+	verify {
+		assert_eq!(Delegations::<T>::get(&user), Some((to, amount)));
+	}
+
+	undelegate {
+		let d in 1 .. T::MaxDelegationDepth::get();
+		let to = delegation_chain::<T>(d);
+		let user: T::AccountId = account("user", 0, SEED);
+		let amount: BalanceOfAssets<T> = 100u8.into();
+		LLM::<T>::transfer_from_treasury(user.clone(), amount).unwrap();
+		LLM::<T>::politics_lock(RawOrigin::Signed(user.clone()).into(), amount).unwrap();
+		LLM::<T>::delegate(RawOrigin::Signed(user.clone()).into(), to, amount).unwrap();
+	}: _(RawOrigin::Signed(user.clone()))
+	verify {
+		assert!(!Delegations::<T>::contains_key(&user));
+	}
+
+	renew_citizenship {
+		let user: T::AccountId = account("user", 0, SEED);
+		let amount: BalanceOfAssets<T> = T::CitizenshipMinimumPooledLLM::get().try_into().ok().unwrap();
+		make_citizen::<T>(&user);
+		LLM::<T>::transfer_from_treasury(user.clone(), amount).unwrap();
+		LLM::<T>::politics_lock(RawOrigin::Signed(user.clone()).into(), amount).unwrap();
+		LLM::<T>::renew_citizenship(RawOrigin::Signed(user.clone()).into()).unwrap();
+		LLM::<T>::expire_citizenship(user.clone());
+	}: _(RawOrigin::Signed(user.clone()))
+	verify {
+		assert!(CitizenshipExpiry::<T>::contains_key(&user));
+		assert!(!ExpiredCitizenships::<T>::contains_key(&user));
+	}
+
+	expire_citizenship {
+		let user: T::AccountId = account("user", 0, SEED);
+		let amount: BalanceOfAssets<T> = T::CitizenshipMinimumPooledLLM::get().try_into().ok().unwrap();
+		make_citizen::<T>(&user);
+		LLM::<T>::transfer_from_treasury(user.clone(), amount).unwrap();
+		LLM::<T>::politics_lock(RawOrigin::Signed(user.clone()).into(), amount).unwrap();
+		LLM::<T>::renew_citizenship(RawOrigin::Signed(user.clone()).into()).unwrap();
+	}: {
+		LLM::<T>::expire_citizenship(user.clone());
+	}
+	verify {
+		assert!(ExpiredCitizenships::<T>::contains_key(&user));
+	}
+
+	release_unpools {
+		let n in 0 .. T::MaxUnpoolsPerBlock::get();
+		let amount: BalanceOfAssets<T> = 100u32.into();
+		let unpooling = LLM::<T>::get_llm_unpooling_account();
+		LLM::<T>::transfer_from_treasury(unpooling, amount.saturating_mul(n.into())).unwrap();
+		let block = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. n {
+			let user: T::AccountId = account("user", i, SEED);
+			UnpoolQueue::<T>::try_append(block, (user.clone(), amount)).unwrap();
+			PendingUnpool::<T>::insert(&user, amount);
+		}
+	}: {
+		LLM::<T>::release_unpools(block);
+	}
+	verify {
+		assert_eq!(UnpoolQueue::<T>::get(block).len(), 0);
+		assert_eq!(PendingUnpool::<T>::iter().count(), 0);
+	}
+
+	propose_llm_spend {
+		let user: T::AccountId = account("user", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let value: BalanceOfAssets<T> = 1000u32.into();
+		LLM::<T>::transfer_from_treasury(user.clone(), llm_spend_funds::<T>(value)).unwrap();
+	}: _(RawOrigin::Signed(user), value, beneficiary)
+	verify {
+		assert!(LLMSpendProposals::<T>::contains_key(0));
+	}
+
+	approve_llm_spend {
+		llm_spend_proposals::<T>(1, 1000u32.into());
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert!(LLMSpendApprovals::<T>::get().contains(&0));
+	}
+
+	reject_llm_spend {
+		llm_spend_proposals::<T>(1, 1000u32.into());
+		LLM::<T>::approve_llm_spend(RawOrigin::Root.into(), 0).unwrap();
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert!(!LLMSpendProposals::<T>::contains_key(0));
+	}
+
+	spend_llm_funds {
+		let n in 0 .. T::MaxLLMSpendApprovals::get();
+		let value: BalanceOfAssets<T> = 1000u32.into();
+		llm_spend_proposals::<T>(n, value);
+		for i in 0 .. n {
+			LLM::<T>::approve_llm_spend(RawOrigin::Root.into(), i).unwrap();
+		}
+		let pot = LLM::<T>::get_llm_spend_pot_account();
+		LLM::<T>::transfer_from_treasury(pot, value.saturating_mul(n.into())).unwrap();
+	}: {
+		LLM::<T>::spend_llm_funds();
+	}
+	verify {
+		assert!(LLMSpendApprovals::<T>::get().is_empty());
+		assert_eq!(LLMSpendProposals::<T>::iter().count(), 0);
+	}
 }
 
 impl_benchmark_test_suite!(LLM, crate::mock::new_test_ext(), crate::mock::Test,);
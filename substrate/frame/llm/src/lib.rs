@@ -3,6 +3,7 @@
 pub use pallet::*;
 
 mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -36,6 +37,21 @@ pub enum LLMAccount<AccountId> {
 	Locked(AccountId),
 }
 
+pub type LLMSpendProposalIndex = u32;
+
+/// Proposal to pay LLM from **Spend Pot**
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct LLMSpendProposal<AccountId, Balance> {
+	/// account that made the proposal and holds the bond
+	pub proposer: AccountId,
+	/// LLM to be paid out
+	pub value: Balance,
+	/// account to be paid
+	pub beneficiary: AccountId,
+	/// LLM held on **Spend Bonds** account, returned on payout
+	pub bond: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	// Import various types used to declare pallet in scope.
@@ -50,11 +66,11 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
-	use liberland_traits::{CitizenshipChecker, OnLLMPoliticsUnlock, LLM};
+	use liberland_traits::{CitizenshipChecker, OnCitizenshipChange, OnLLMPoliticsUnlock, LLM};
 	use pallet_identity::{Data, Data::Raw, Judgement::KnownGood};
 	use scale_info::prelude::vec;
 	use sp_runtime::{
-		traits::{AccountIdConversion, StaticLookup},
+		traits::{AccountIdConversion, Saturating, StaticLookup, Zero},
 		AccountId32, Perbill, Permill,
 	};
 	use sp_std::vec::Vec;
@@ -103,6 +119,68 @@ pub mod pallet {
 	pub(super) type ElectionlockDuration<T: Config> =
 		StorageValue<_, BlockNumberFor<T>, ValueQuery, ElectionlockDurationOnEmpty<T>>; // seconds
 
+	/// LLM unpooled by `politics_unlock` waiting for `UnpoolCooldown`, by
+	/// block at which it's released
+	#[pallet::storage]
+	#[pallet::getter(fn unpool_queue)]
+	pub(super) type UnpoolQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, BalanceOfAssets<T>), T::MaxUnpoolsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// total amount of LLM each account has waiting in `UnpoolQueue`
+	#[pallet::storage]
+	#[pallet::getter(fn pending_unpool)]
+	pub(super) type PendingUnpool<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOfAssets<T>, ValueQuery>;
+
+	/// account to which LLM politics power is delegated and delegated amount,
+	/// by delegating account
+	#[pallet::storage]
+	#[pallet::getter(fn delegation)]
+	pub(super) type Delegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, BalanceOfAssets<T>)>;
+
+	/// total LLM politics power delegated to account, directly or through
+	/// a chain of delegations
+	#[pallet::storage]
+	#[pallet::getter(fn delegated_power)]
+	pub(super) type DelegatedPower<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOfAssets<T>, ValueQuery>;
+
+	/// block at which account's citizenship expires, set when citizenship
+	/// is granted and by `renew_citizenship`
+	#[pallet::storage]
+	#[pallet::getter(fn citizenship_expiry)]
+	pub(super) type CitizenshipExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// accounts to check for citizenship expiry, by expiry block
+	#[pallet::storage]
+	pub(super) type ExpiryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// next block of `ExpiryQueue` to be checked by `on_idle`
+	#[pallet::storage]
+	pub(super) type NextExpiryCheck<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// accounts whose citizenship expired - they have no politics rights
+	/// until `renew_citizenship`
+	#[pallet::storage]
+	#[pallet::getter(fn is_citizenship_expired)]
+	pub(super) type ExpiredCitizenships<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn citizens)]
 	pub(super) type Citizens<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -112,6 +190,28 @@ pub mod pallet {
 	pub(super) type Courts<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxCourts>, ValueQuery>;
 
+	/// number of LLM spend proposals ever made, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn llm_spend_proposal_count)]
+	pub(super) type LLMSpendProposalCount<T: Config> =
+		StorageValue<_, LLMSpendProposalIndex, ValueQuery>;
+
+	/// LLM spend proposals that weren't paid or rejected yet
+	#[pallet::storage]
+	#[pallet::getter(fn llm_spend_proposals)]
+	pub(super) type LLMSpendProposals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		LLMSpendProposalIndex,
+		LLMSpendProposal<T::AccountId, BalanceOfAssets<T>>,
+	>;
+
+	/// approved LLM spend proposals waiting for payout
+	#[pallet::storage]
+	#[pallet::getter(fn llm_spend_approvals)]
+	pub(super) type LLMSpendApprovals<T: Config> =
+		StorageValue<_, BoundedVec<LLMSpendProposalIndex, T::MaxLLMSpendApprovals>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// duration, in blocks, for which additional unlocks should be locked
@@ -169,6 +269,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type CitizenshipMinimumPooledLLM: Get<u128>; // Pre defined the total supply in runtime
 
+		/// Minimum amount of LLM Accounts needs politipooled to have
+		/// e-residency status
+		#[pallet::constant]
+		type EResidentMinimumPooledLLM: Get<u128>;
+
 		/// How much funds unlock on politics_unlock
 		#[pallet::constant]
 		type UnlockFactor: Get<Permill>;
@@ -187,7 +292,47 @@ pub mod pallet {
 		#[pallet::constant]
 		type InflationEventReleaseFactor: Get<Perbill>;
 
+		/// Number of blocks after `politics_unlock` before unpooled LLM is
+		/// transferred to the account. Zero transfers it immediately.
+		#[pallet::constant]
+		type UnpoolCooldown: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of unpool requests released in a single block
+		#[pallet::constant]
+		type MaxUnpoolsPerBlock: Get<u32>;
+
+		/// Maximum length of a chain of LLM delegations
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
+
+		/// Number of blocks citizenship stays valid after it's granted or
+		/// renewed with `renew_citizenship`. Zero disables expiry.
+		#[pallet::constant]
+		type CitizenshipValidity: Get<BlockNumberFor<Self>>;
+
+		/// Origin that approves and rejects LLM spend proposals
+		type LLMSpendApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Fraction of proposed LLM spend held as bond
+		#[pallet::constant]
+		type LLMSpendProposalBond: Get<Permill>;
+
+		/// Minimum amount of LLM held as bond of LLM spend proposal
+		#[pallet::constant]
+		type LLMSpendProposalBondMinimum: Get<u128>;
+
+		/// Number of blocks between payouts of approved LLM spends. Zero
+		/// disables payouts.
+		#[pallet::constant]
+		type LLMSpendPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of approved LLM spends waiting for payout
+		#[pallet::constant]
+		type MaxLLMSpendApprovals: Get<u32>;
+
 		type OnLLMPoliticsUnlock: OnLLMPoliticsUnlock<Self::AccountId>;
+		/// Notified when account becomes a citizen or stops being one
+		type OnCitizenshipChange: OnCitizenshipChange<Self::AccountId>;
 		type WeightInfo: WeightInfo;
 		type MaxCourts: Get<u32>;
 	}
@@ -216,9 +361,34 @@ pub mod pallet {
 		Locked,
 		/// Caller isn't an authorized court
 		NotCourt,
+		/// Too many unpool requests released in the same block, try again
+		/// in next block
+		UnpoolQueueFull,
+		/// Account already delegates its LLM, undelegate first
+		AlreadyDelegating,
+		/// Account doesn't delegate its LLM
+		NotDelegating,
+		/// Account has LLM delegated to it and can't delegate further
+		HasDelegations,
+		/// Delegation would create a cycle
+		CircularDelegation,
+		/// Delegation chain would be longer than `MaxDelegationDepth`
+		DelegationTooDeep,
+		/// Account delegates its LLM, undelegate first
+		ActiveDelegation,
+		/// Citizenship expired, call `renew_citizenship`
+		CitizenshipExpired,
+		/// Citizenship expiry is disabled
+		ExpiryDisabled,
+		/// No LLM spend proposal with given index
+		InvalidLLMSpendProposal,
+		/// LLM spend proposal was already approved
+		LLMSpendAlreadyApproved,
+		/// Too many approved LLM spends waiting for payout
+		TooManyLLMSpendApprovals,
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -230,7 +400,11 @@ pub mod pallet {
 			if let Err(e) = Self::maybe_release(b) {
 				log::error!("LLM maybe_release failure: {e:?}");
 			};
-			Weight::zero()
+			Self::release_unpools(b).saturating_add(Self::maybe_spend_llm_funds(b))
+		}
+
+		fn on_idle(b: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired_citizenships(b, remaining_weight)
 		}
 	}
 
@@ -253,13 +427,15 @@ pub mod pallet {
 		}
 
 		/// Unlock 10% of account's LLM from politics pool, a.k.a. politipool.
-		/// Internally it transfers LLM from **Politipool** account.
+		/// Internally it transfers LLM from **Politipool** account to
+		/// **Unpooling** account, from which it's released to the account
+		/// after `UnpoolCooldown`.
 		///
 		/// Can only be called once per `Withdrawlock` duration, will fail with
 		/// `Gottawait` otherwise.
 		///
 		/// Emits:
-		/// * `LLMPoliticsUnlocked`
+		/// * `LLMUnpoolRequested`, followed by `LLMPoliticsUnlocked` on release
 		/// * `Transferred` from `pallet-assets`
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::politics_unlock())]
@@ -267,6 +443,7 @@ pub mod pallet {
 			let sender: T::AccountId = ensure_signed(origin.clone())?;
 			// check if we have political locked LLM
 
+			ensure!(!Delegations::<T>::contains_key(&sender), Error::<T>::ActiveDelegation);
 			let politics_balance = LLMPolitics::<T>::get(sender.clone());
 			ensure!(politics_balance > 0u8.into(), Error::<T>::InvalidAccount);
 
@@ -275,7 +452,7 @@ pub mod pallet {
 
 			let ten_percent: BalanceOfAssets<T> = Self::get_unlock_amount(politics_balance)?;
 
-			Self::transfer_from_politipool(sender.clone(), ten_percent)?;
+			Self::unpool(sender.clone(), ten_percent, current_block_number)?;
 			LLMPolitics::<T>::mutate(&sender, |b| *b -= ten_percent);
 
 			let withdraw_lock_end = current_block_number + Self::withdraw_lock_duration();
@@ -284,7 +461,6 @@ pub mod pallet {
 			Electionlock::<T>::insert(&sender, election_lock_end);
 
 			T::OnLLMPoliticsUnlock::on_llm_politics_unlock(&sender)?;
-			Self::deposit_event(Event::<T>::LLMPoliticsUnlocked(sender, ten_percent));
 			Ok(())
 		}
 
@@ -420,6 +596,9 @@ pub mod pallet {
 				LLMAccount::Locked(account) => {
 					let politics_balance = LLMPolitics::<T>::get(account.clone());
 					ensure!(politics_balance >= amount, Error::<T>::LowBalance);
+					if Delegations::<T>::contains_key(&account) {
+						Self::do_undelegate(account.clone())?;
+					}
 
 					LLMPolitics::<T>::mutate(account.clone(), |b| *b -= amount);
 					Self::deposit_event(Event::<T>::LLMPoliticsUnlocked(account, amount));
@@ -453,6 +632,219 @@ pub mod pallet {
 			Courts::<T>::set(courts);
 			Ok(())
 		}
+
+		/// Transfer LLD from treasury. Can only be called by Courts.
+		///
+		/// - `to_account`: Account to transfer to.
+		/// - `amount`: Amount to transfer.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::court_lld_transfer())]
+		pub fn court_lld_transfer(
+			origin: OriginFor<T>,
+			to_account: T::AccountId,
+			amount: <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+		) -> DispatchResult {
+			let caller: T::AccountId = ensure_signed(origin)?;
+			ensure!(Courts::<T>::get().contains(&caller), Error::<T>::NotCourt);
+			<T as Config>::Currency::transfer(
+				&Self::get_llm_treasury_account(),
+				&to_account,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)
+		}
+
+		/// Revoke citizenship of an account. Can only be called by Courts.
+		///
+		/// Removes all judgements of account's identity and its votes in
+		/// democracy. LLM stays politipooled and can be unpooled as usual.
+		///
+		/// - `account`: Citizen to revoke citizenship of.
+		///
+		/// Emits: `CitizenshipRevoked`
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_citizenship())]
+		pub fn revoke_citizenship(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			let caller: T::AccountId = ensure_signed(origin)?;
+			ensure!(Courts::<T>::get().contains(&caller), Error::<T>::NotCourt);
+			ensure!(Self::is_citizen(&account), Error::<T>::NonCitizen);
+
+			T::OnLLMPoliticsUnlock::on_llm_politics_unlock(&account)?;
+			pallet_identity::Pallet::<T>::clear_judgements(&account)?;
+			Self::deposit_event(Event::<T>::CitizenshipRevoked(account));
+			Ok(())
+		}
+
+		/// Delegate LLM politics power to another citizen. Delegated amount
+		/// stays politipooled on caller's account, but counts towards `to`'s
+		/// voting power in democracy and elections. Power delegated to an
+		/// account that delegates itself is passed further down the chain.
+		///
+		/// Caller's votes are removed, as it loses the delegated power.
+		/// Accounts that already have LLM delegated to them can't delegate.
+		///
+		/// - `to`: Citizen receiving the voting power.
+		/// - `amount`: Amount of caller's politipooled LLM to delegate.
+		///
+		/// Emits: `LLMDelegated`
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate(T::MaxDelegationDepth::get()))]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			amount: BalanceOfAssets<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!Delegations::<T>::contains_key(&sender), Error::<T>::AlreadyDelegating);
+			ensure!(DelegatedPower::<T>::get(&sender).is_zero(), Error::<T>::HasDelegations);
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+			ensure!(LLMPolitics::<T>::get(&sender) >= amount, Error::<T>::LowBalance);
+			ensure!(Self::is_citizen(&to), Error::<T>::NonCitizen);
+
+			let chain = Self::delegation_chain(&to);
+			ensure!(!chain.contains(&sender), Error::<T>::CircularDelegation);
+			ensure!(
+				(chain.len() as u32) <= T::MaxDelegationDepth::get(),
+				Error::<T>::DelegationTooDeep
+			);
+
+			T::OnLLMPoliticsUnlock::on_llm_politics_unlock(&sender)?;
+			for account in chain.iter() {
+				DelegatedPower::<T>::mutate(account, |p| *p = p.saturating_add(amount));
+			}
+			Delegations::<T>::insert(&sender, (to.clone(), amount));
+			Self::deposit_event(Event::<T>::LLMDelegated(sender, to, amount));
+			Ok(())
+		}
+
+		/// Extend caller's citizenship by `CitizenshipValidity` blocks from
+		/// now. Restores politics rights if citizenship already expired.
+		///
+		/// Caller must be a citizen with at least
+		/// `CitizenshipMinimumPooledLLM` politipooled.
+		///
+		/// Emits: `CitizenshipRenewed`
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_citizenship())]
+		pub fn renew_citizenship(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let validity = T::CitizenshipValidity::get();
+			ensure!(!validity.is_zero(), Error::<T>::ExpiryDisabled);
+			ensure!(Self::is_citizen(&sender), Error::<T>::NonCitizen);
+			ensure!(Self::check_pooled_llm(&sender), Error::<T>::NoPolLLM);
+
+			Self::clear_citizenship_expiry(&sender);
+			let expires_at = Self::start_citizenship_validity(&sender, validity);
+			Self::deposit_event(Event::<T>::CitizenshipRenewed(sender, expires_at));
+			Ok(())
+		}
+
+		/// Take back LLM politics power delegated with `delegate`.
+		///
+		/// Votes of all accounts down the delegation chain are removed, as
+		/// they lose the delegated power.
+		///
+		/// Emits: `LLMUndelegated`
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::undelegate(T::MaxDelegationDepth::get()))]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_undelegate(sender)
+		}
+
+		/// Propose paying LLM from **Spend Pot**. Bond of
+		/// `LLMSpendProposalBond` of `value`, but at least
+		/// `LLMSpendProposalBondMinimum`, is moved to **Spend Bonds**
+		/// account. It's returned on payout and moved to **Spend Pot** if
+		/// proposal is rejected.
+		///
+		/// - `value`: Amount of LLM to pay
+		/// - `beneficiary`: Account to pay to
+		///
+		/// Emits: `LLMSpendProposed`
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_llm_spend())]
+		pub fn propose_llm_spend(
+			origin: OriginFor<T>,
+			value: BalanceOfAssets<T>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(!value.is_zero(), Error::<T>::InvalidAmount);
+			let minimum: BalanceOfAssets<T> = T::LLMSpendProposalBondMinimum::get()
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidAmount)?;
+			let bond = T::LLMSpendProposalBond::get().mul_floor(value).max(minimum);
+			Self::transfer(proposer.clone(), Self::get_llm_spend_bonds_account(), bond)?;
+
+			let index = LLMSpendProposalCount::<T>::get();
+			LLMSpendProposals::<T>::insert(
+				index,
+				LLMSpendProposal {
+					proposer: proposer.clone(),
+					value,
+					beneficiary: beneficiary.clone(),
+					bond,
+				},
+			);
+			LLMSpendProposalCount::<T>::put(index.saturating_add(1));
+			Self::deposit_event(Event::<T>::LLMSpendProposed(index, proposer, beneficiary, value));
+			Ok(())
+		}
+
+		/// Approve LLM spend proposal. It's paid at the next multiple of
+		/// `LLMSpendPeriod` at which **Spend Pot** has enough LLM. Can only
+		/// be called by `LLMSpendApproveOrigin`.
+		///
+		/// - `proposal_id`: Index of the proposal
+		///
+		/// Emits: `LLMSpendApproved`
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_llm_spend())]
+		pub fn approve_llm_spend(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: LLMSpendProposalIndex,
+		) -> DispatchResult {
+			T::LLMSpendApproveOrigin::ensure_origin(origin)?;
+			ensure!(
+				LLMSpendProposals::<T>::contains_key(proposal_id),
+				Error::<T>::InvalidLLMSpendProposal
+			);
+			LLMSpendApprovals::<T>::try_mutate(|approvals| {
+				ensure!(!approvals.contains(&proposal_id), Error::<T>::LLMSpendAlreadyApproved);
+				approvals
+					.try_push(proposal_id)
+					.map_err(|_| Error::<T>::TooManyLLMSpendApprovals)
+			})?;
+			Self::deposit_event(Event::<T>::LLMSpendApproved(proposal_id));
+			Ok(())
+		}
+
+		/// Reject LLM spend proposal, approved or not. Proposer's bond is
+		/// moved to **Spend Pot**. Can only be called by
+		/// `LLMSpendApproveOrigin`.
+		///
+		/// - `proposal_id`: Index of the proposal
+		///
+		/// Emits: `LLMSpendRejected`
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_llm_spend())]
+		pub fn reject_llm_spend(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: LLMSpendProposalIndex,
+		) -> DispatchResult {
+			T::LLMSpendApproveOrigin::ensure_origin(origin)?;
+			let proposal = LLMSpendProposals::<T>::take(proposal_id)
+				.ok_or(Error::<T>::InvalidLLMSpendProposal)?;
+			LLMSpendApprovals::<T>::mutate(|approvals| approvals.retain(|i| *i != proposal_id));
+			Self::transfer(
+				Self::get_llm_spend_bonds_account(),
+				Self::get_llm_spend_pot_account(),
+				proposal.bond,
+			)?;
+			Self::deposit_event(Event::<T>::LLMSpendRejected(proposal_id, proposal.bond));
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -466,8 +858,28 @@ pub mod pallet {
 		LLMPoliticsLocked(T::AccountId, BalanceOfAssets<T>),
 		/// sent to user account, amount
 		LLMPoliticsUnlocked(T::AccountId, BalanceOfAssets<T>),
+		/// account, amount, block at which it will be sent to account
+		LLMUnpoolRequested(T::AccountId, BalanceOfAssets<T>, BlockNumberFor<T>),
+		/// delegating account, delegate, amount
+		LLMDelegated(T::AccountId, T::AccountId, BalanceOfAssets<T>),
+		/// delegating account, former delegate, amount
+		LLMUndelegated(T::AccountId, T::AccountId, BalanceOfAssets<T>),
+		/// account, block at which citizenship expires
+		CitizenshipRenewed(T::AccountId, BlockNumberFor<T>),
+		/// Citizenship wasn't renewed in time, account lost politics rights
+		CitizenshipExpired(T::AccountId),
 		/// Remark
 		Remarked(RemarkData),
+		/// Citizenship of account was revoked by court
+		CitizenshipRevoked(T::AccountId),
+		/// proposal index, proposer, beneficiary, value
+		LLMSpendProposed(LLMSpendProposalIndex, T::AccountId, T::AccountId, BalanceOfAssets<T>),
+		/// proposal index
+		LLMSpendApproved(LLMSpendProposalIndex),
+		/// proposal index, bond moved to **Spend Pot**
+		LLMSpendRejected(LLMSpendProposalIndex, BalanceOfAssets<T>),
+		/// proposal index, beneficiary, value
+		LLMSpendPaid(LLMSpendProposalIndex, T::AccountId, BalanceOfAssets<T>),
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -478,6 +890,111 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Accounts receiving power delegated to `account`: `account` itself
+		/// and every account down its chain of delegations. Walk stops after
+		/// `MaxDelegationDepth + 1` accounts.
+		fn delegation_chain(account: &T::AccountId) -> Vec<T::AccountId> {
+			let mut chain = vec![account.clone()];
+			let mut current = account.clone();
+			while chain.len() as u32 <= T::MaxDelegationDepth::get() {
+				match Delegations::<T>::get(&current) {
+					Some((next, _)) => {
+						chain.push(next.clone());
+						current = next;
+					},
+					None => break,
+				}
+			}
+			chain
+		}
+
+		/// Flag citizenships that expired up to block `now`, as long as
+		/// `remaining_weight` allows. Checked blocks are tracked in
+		/// `NextExpiryCheck`, so sweep picks up where it stopped.
+		fn sweep_expired_citizenships(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if T::CitizenshipValidity::get().is_zero() {
+				return Weight::zero();
+			}
+
+			let per_block = T::DbWeight::get().reads_writes(1, 1);
+			let per_account = <T as Config>::WeightInfo::expire_citizenship();
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+			let mut cursor = NextExpiryCheck::<T>::get();
+			if cursor.is_zero() {
+				cursor = now;
+			}
+
+			while cursor <= now && remaining_weight.all_gte(used + per_block + per_account) {
+				used += per_block;
+				let mut queue = ExpiryQueue::<T>::drain_prefix(cursor);
+				let mut exhausted = false;
+				loop {
+					if !remaining_weight.all_gte(used + per_account) {
+						exhausted = true;
+						break;
+					}
+					match queue.next() {
+						Some((account, ())) => {
+							used += per_account;
+							Self::expire_citizenship(account);
+						},
+						None => break,
+					}
+				}
+				if exhausted {
+					break;
+				}
+				cursor = cursor.saturating_add(1u8.into());
+			}
+
+			NextExpiryCheck::<T>::put(cursor);
+			used
+		}
+
+		/// Make `account`'s citizenship valid for `validity` blocks from now.
+		/// Previous expiry must be cleared first. Returns the expiry block.
+		pub(crate) fn start_citizenship_validity(
+			account: &T::AccountId,
+			validity: BlockNumberFor<T>,
+		) -> BlockNumberFor<T> {
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(validity);
+			CitizenshipExpiry::<T>::insert(account, expires_at);
+			ExpiryQueue::<T>::insert(expires_at, account, ());
+			expires_at
+		}
+
+		/// Forget expiry of `account`'s citizenship, including the expired
+		/// flag
+		fn clear_citizenship_expiry(account: &T::AccountId) {
+			if let Some(old) = CitizenshipExpiry::<T>::take(account) {
+				ExpiryQueue::<T>::remove(old, account);
+			}
+			ExpiredCitizenships::<T>::remove(account);
+		}
+
+		pub(crate) fn expire_citizenship(account: T::AccountId) {
+			CitizenshipExpiry::<T>::remove(&account);
+			ExpiredCitizenships::<T>::insert(&account, ());
+			if let Err(e) = T::OnLLMPoliticsUnlock::on_llm_politics_unlock(&account) {
+				log::error!("LLM citizenship expiry failure: {e:?}");
+			}
+			Self::deposit_event(Event::<T>::CitizenshipExpired(account));
+		}
+
+		fn do_undelegate(account: T::AccountId) -> DispatchResult {
+			let (to, amount) = Delegations::<T>::take(&account).ok_or(Error::<T>::NotDelegating)?;
+			let power = amount.saturating_add(DelegatedPower::<T>::get(&account));
+			for delegate in Self::delegation_chain(&to).iter() {
+				DelegatedPower::<T>::mutate_exists(delegate, |p| {
+					let left = p.unwrap_or_default().saturating_sub(power);
+					*p = (!left.is_zero()).then_some(left);
+				});
+				T::OnLLMPoliticsUnlock::on_llm_politics_unlock(delegate)?;
+			}
+			Self::deposit_event(Event::<T>::LLMUndelegated(account, to, amount));
+			Ok(())
+		}
+
 		pub fn account_id32_to_accountid(accountid32: AccountId32) -> T::AccountId {
 			let mut init_account32 = AccountId32::as_ref(&accountid32);
 			let init_account: T::AccountId = T::AccountId::decode(&mut init_account32).unwrap();
@@ -524,6 +1041,101 @@ pub mod pallet {
 			Self::transfer(politipool_account, to_account, amount)
 		}
 
+		/// Move unpooled LLM out of politipool. With zero `UnpoolCooldown`
+		/// it's sent to the account right away, otherwise it waits on
+		/// **Unpooling** account until `release_unpools`.
+		fn unpool(
+			account: T::AccountId,
+			amount: BalanceOfAssets<T>,
+			now: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let cooldown = T::UnpoolCooldown::get();
+			if cooldown.is_zero() {
+				Self::transfer_from_politipool(account.clone(), amount)?;
+				Self::deposit_event(Event::<T>::LLMPoliticsUnlocked(account, amount));
+				return Ok(());
+			}
+
+			let release_at = now.saturating_add(cooldown);
+			UnpoolQueue::<T>::try_append(release_at, (account.clone(), amount))
+				.map_err(|_| Error::<T>::UnpoolQueueFull)?;
+			Self::transfer_from_politipool(Self::get_llm_unpooling_account(), amount)?;
+			PendingUnpool::<T>::mutate(&account, |p| *p = p.saturating_add(amount));
+			Self::deposit_event(Event::<T>::LLMUnpoolRequested(account, amount, release_at));
+			Ok(())
+		}
+
+		/// Send LLM of unpool requests that are due at `block` to their
+		/// accounts.
+		pub(crate) fn release_unpools(block: BlockNumberFor<T>) -> Weight {
+			let queue = UnpoolQueue::<T>::take(block);
+			let unpooling_account = Self::get_llm_unpooling_account();
+			for (account, amount) in queue.iter() {
+				PendingUnpool::<T>::mutate_exists(account, |p| {
+					let left = p.unwrap_or_default().saturating_sub(*amount);
+					*p = (!left.is_zero()).then_some(left);
+				});
+				match Self::transfer(unpooling_account.clone(), account.clone(), *amount) {
+					Ok(()) => Self::deposit_event(Event::<T>::LLMPoliticsUnlocked(
+						account.clone(),
+						*amount,
+					)),
+					Err(e) => log::error!("LLM unpool release failure: {e:?}"),
+				}
+			}
+			<T as Config>::WeightInfo::release_unpools(queue.len() as u32)
+		}
+
+		/// Pay approved LLM spends if `block` is a multiple of
+		/// `LLMSpendPeriod`.
+		fn maybe_spend_llm_funds(block: BlockNumberFor<T>) -> Weight {
+			let period = T::LLMSpendPeriod::get();
+			if period.is_zero() || !(block % period).is_zero() {
+				return Weight::zero();
+			}
+			Self::spend_llm_funds()
+		}
+
+		/// Pay approved LLM spends in order of approval, as long as
+		/// **Spend Pot** has enough LLM. Spends that don't fit wait for the
+		/// next `LLMSpendPeriod`.
+		pub(crate) fn spend_llm_funds() -> Weight {
+			let approvals = LLMSpendApprovals::<T>::get();
+			let pot = Self::get_llm_spend_pot_account();
+			let mut budget = Self::balance(pot.clone());
+			let mut unpaid = BoundedVec::<_, T::MaxLLMSpendApprovals>::new();
+			for index in approvals.iter() {
+				let Some(proposal) = LLMSpendProposals::<T>::get(index) else { continue };
+				if proposal.value > budget {
+					let _ = unpaid.try_push(*index);
+					continue;
+				}
+				if let Err(e) =
+					Self::transfer(pot.clone(), proposal.beneficiary.clone(), proposal.value)
+				{
+					log::error!("LLM spend payout failure: {e:?}");
+					let _ = unpaid.try_push(*index);
+					continue;
+				}
+				budget = budget.saturating_sub(proposal.value);
+				LLMSpendProposals::<T>::remove(index);
+				if let Err(e) = Self::transfer(
+					Self::get_llm_spend_bonds_account(),
+					proposal.proposer,
+					proposal.bond,
+				) {
+					log::error!("LLM spend bond return failure: {e:?}");
+				}
+				Self::deposit_event(Event::<T>::LLMSpendPaid(
+					*index,
+					proposal.beneficiary,
+					proposal.value,
+				));
+			}
+			LLMSpendApprovals::<T>::put(unpaid);
+			<T as Config>::WeightInfo::spend_llm_funds(approvals.len() as u32)
+		}
+
 		/// Transfer `amount` LLM to `to_account` from vault
 		/// Used in tests.
 		pub fn transfer_from_vault(
@@ -608,6 +1220,24 @@ pub mod pallet {
 			PalletId(*b"polilock").into_account_truncating()
 		}
 
+		/// AccountId of **Unpooling** account. **Unpooling** account stores
+		/// LLM unlocked by `politics_unlock` until `UnpoolCooldown` passes.
+		pub fn get_llm_unpooling_account() -> T::AccountId {
+			PalletId(*b"llm/unpl").into_account_truncating()
+		}
+
+		/// AccountId of **Spend Pot** account. **Spend Pot** pays approved
+		/// LLM spends and receives bonds of rejected proposals.
+		pub fn get_llm_spend_pot_account() -> T::AccountId {
+			PalletId(*b"llm/spnd").into_account_truncating()
+		}
+
+		/// AccountId of **Spend Bonds** account. **Spend Bonds** account
+		/// holds bonds of pending LLM spend proposals.
+		pub fn get_llm_spend_bonds_account() -> T::AccountId {
+			PalletId(*b"llm/bond").into_account_truncating()
+		}
+
 		fn get_release_amount() -> Result<BalanceOfAssets<T>, Error<T>> {
 			let asset_id = Self::llm_id().into();
 			let vault_account = Self::get_llm_vault_account();
@@ -669,7 +1299,11 @@ pub mod pallet {
 		}
 
 		fn get_llm_politics(account: &T::AccountId) -> BalanceOfAssets<T> {
-			LLMPolitics::<T>::get(account)
+			let own = LLMPolitics::<T>::get(account);
+			match Delegations::<T>::get(account) {
+				Some((_, delegated)) => own.saturating_sub(delegated),
+				None => own.saturating_add(DelegatedPower::<T>::get(account)),
+			}
 		}
 	}
 
@@ -766,6 +1400,10 @@ pub mod pallet {
 			ensure!(Self::is_eligible_identity(identity), Error::<T>::NonCitizen);
 			ensure!(Self::is_election_unlocked(account), Error::<T>::Locked);
 			ensure!(Self::check_pooled_llm(account), Error::<T>::NoPolLLM);
+			ensure!(
+				!ExpiredCitizenships::<T>::contains_key(account),
+				Error::<T>::CitizenshipExpired
+			);
 			Ok(())
 		}
 
@@ -789,6 +1427,20 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn is_eresident(account: &T::AccountId) -> bool {
+			let identified = pallet_identity::Pallet::<T>::identity(account)
+				.map(|identity| {
+					Self::is_eresident_identity(&identity)
+						&& !Self::is_citizen_identity(&identity)
+						&& Self::is_known_good(&identity)
+						&& Self::is_eligible_identity(&identity)
+				})
+				.unwrap_or(false);
+			let minimum: Option<BalanceOfAssets<T>> =
+				T::EResidentMinimumPooledLLM::get().try_into().ok();
+			identified && matches!(minimum, Some(m) if LLMPolitics::<T>::get(account) >= m)
+		}
+
 		fn is_citizen(account: &T::AccountId) -> bool {
 			let identity =
 				pallet_identity::Pallet::<T>::identity(account).ok_or(Error::<T>::NonCitizen);
@@ -810,8 +1462,16 @@ pub mod pallet {
 			let is_citizen_now = Self::is_citizen(account);
 			if was_citizen_before_change && !is_citizen_now {
 				Citizens::<T>::mutate(|c| *c -= 1);
+				Self::clear_citizenship_expiry(account);
+				T::OnCitizenshipChange::on_citizenship_revoked(account);
 			} else if !was_citizen_before_change && is_citizen_now {
 				Citizens::<T>::mutate(|c| *c += 1);
+				Self::clear_citizenship_expiry(account);
+				let validity = T::CitizenshipValidity::get();
+				if !validity.is_zero() {
+					Self::start_citizenship_validity(account, validity);
+				}
+				T::OnCitizenshipChange::on_citizenship_granted(account);
 			}
 		}
 	}
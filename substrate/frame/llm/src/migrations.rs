@@ -0,0 +1,78 @@
+use super::*;
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+use liberland_traits::CitizenshipChecker;
+use sp_runtime::traits::Zero;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The log target.
+const TARGET: &'static str = "runtime::llm::migration";
+
+pub mod v5 {
+	use super::*;
+
+	/// Keys of `pallet_identity::IdentityOf`, values aren't needed
+	#[storage_alias]
+	type IdentityOf<T: Config> = StorageMap<
+		pallet_identity::Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		(),
+	>;
+
+	/// Starts validity of citizenships granted before expiry was tracked on
+	/// grant. They expire `CitizenshipValidity` blocks after the upgrade,
+	/// unless renewed.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(
+				StorageVersion::get::<Pallet<T>>() == 4,
+				"can only upgrade from version 4"
+			);
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if StorageVersion::get::<Pallet<T>>() != 4 {
+				log::warn!(
+					target: TARGET,
+					"skipping on_runtime_upgrade: executed on wrong storage version.\
+				Expected version 4"
+				);
+				return weight;
+			}
+
+			let validity = <T as Config>::CitizenshipValidity::get();
+			let mut seeded = 0u32;
+			if !validity.is_zero() {
+				for account in IdentityOf::<T>::iter_keys() {
+					weight.saturating_accrue(T::DbWeight::get().reads(2));
+					if Pallet::<T>::is_citizen(&account) &&
+						!CitizenshipExpiry::<T>::contains_key(&account)
+					{
+						Pallet::<T>::start_citizenship_validity(&account, validity);
+						weight.saturating_accrue(T::DbWeight::get().writes(2));
+						seeded += 1;
+					}
+				}
+			}
+			log::info!(target: TARGET, "started citizenship validity of {} citizens", seeded);
+
+			StorageVersion::new(5).put::<Pallet<T>>();
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 5, "must upgrade to version 5");
+			Ok(())
+		}
+	}
+}
@@ -106,12 +106,17 @@ parameter_types! {
 	pub const TOTALLLM: u64 = 70000000u64;
 	pub const PRERELEASELLM: u64 = 7000000u64;
 	pub const CitizenshipMinimum: u64 = 5000u64;
+	pub const EResidentMinimum: u64 = 1000u64;
 	pub const UnlockFactor: Permill = Permill::from_parts(8742);
 	pub const AssetId: u32 = 1;
 	pub const AssetName: &'static str = "LiberTest Merit";
 	pub const AssetSymbol: &'static str = "LTM";
 	pub const InflationEventInterval: u64 = 30*24*3600/6;
 	pub const InflationEventReleaseFactor: Perbill = Perbill::from_parts(8741611);
+	pub static UnpoolCooldown: u64 = 0;
+	pub static CitizenshipValidity: u64 = 100;
+	pub const LLMSpendProposalBond: Permill = Permill::from_percent(5);
+	pub const LLMSpendProposalBondMinimum: u128 = 10;
 }
 
 impl pallet_llm::Config for Test {
@@ -120,13 +125,24 @@ impl pallet_llm::Config for Test {
 	type TotalSupply = TOTALLLM;
 	type PreReleasedAmount = PRERELEASELLM;
 	type CitizenshipMinimumPooledLLM = CitizenshipMinimum;
+	type EResidentMinimumPooledLLM = EResidentMinimum;
 	type UnlockFactor = UnlockFactor;
 	type AssetId = AssetId;
 	type AssetName = AssetName;
 	type AssetSymbol = AssetSymbol;
 	type InflationEventInterval = InflationEventInterval;
 	type InflationEventReleaseFactor = InflationEventReleaseFactor;
+	type UnpoolCooldown = UnpoolCooldown;
+	type MaxUnpoolsPerBlock = ConstU32<2>;
+	type MaxDelegationDepth = ConstU32<2>;
+	type CitizenshipValidity = CitizenshipValidity;
+	type LLMSpendApproveOrigin = EnsureRoot<u64>;
+	type LLMSpendProposalBond = LLMSpendProposalBond;
+	type LLMSpendProposalBondMinimum = LLMSpendProposalBondMinimum;
+	type LLMSpendPeriod = ConstU64<10>;
+	type MaxLLMSpendApprovals = ConstU32<3>;
 	type OnLLMPoliticsUnlock = ();
+	type OnCitizenshipChange = ();
 	type SenateOrigin = EnsureRoot<u64>;
 	type WeightInfo = ();
 	type MaxCourts = ConstU32<3>;
@@ -1,8 +1,10 @@
 #![cfg(test)]
 
 use crate::{
-	mock::*, Config, Courts, Electionlock, ElectionlockDuration, Error, Event, LLMAccount,
-	LLMPolitics, LastRelease, RemarkData, Withdrawlock, WithdrawlockDuration,
+	mock::*, weights::WeightInfo, CitizenshipExpiry, Config, Courts, Electionlock,
+	ElectionlockDuration, Error, Event, ExpiryQueue, LLMAccount, LLMPolitics, LLMSpendApprovals,
+	LLMSpendProposal, LLMSpendProposals, LastRelease, RemarkData, UnpoolQueue, Withdrawlock,
+	WithdrawlockDuration,
 };
 use codec::Compact;
 use frame_support::{
@@ -13,10 +15,13 @@ use frame_support::{
 			fungible::{Inspect, Mutate},
 			Preservation,
 		},
-		OnInitialize,
+		EnsureOrigin, OnIdle, OnInitialize, OnRuntimeUpgrade, StorageVersion,
 	},
+	weights::Weight,
+};
+use liberland_traits::{
+	CitizenshipChecker, EnsureCitizen, EnsureResidentOrCitizen, LLM as LLMTrait,
 };
-use liberland_traits::{CitizenshipChecker, LLM as LLMTrait};
 use pallet_identity::{Data, IdentityInfo};
 use sp_runtime::traits::{BlakeTwo256, Hash};
 
@@ -240,6 +245,179 @@ fn politics_unlock_releases_dot8742_percent() {
 	});
 }
 
+#[test]
+fn politics_unlock_with_cooldown_queues_unpool() {
+	new_test_ext().execute_with(|| {
+		UnpoolCooldown::set(10);
+		let id = LLM::llm_id();
+		let politipool = LLM::get_llm_politipool_account();
+		let unpooling = LLM::get_llm_unpooling_account();
+		let origin = RuntimeOrigin::signed(2);
+
+		assert_ok!(LLM::politics_lock(origin.clone(), 6000));
+		assert_ok!(LLM::politics_unlock(origin.clone()));
+		System::assert_last_event(Event::LLMUnpoolRequested(2, 52, 11).into());
+
+		assert_eq!(Assets::balance(id, 2), 0);
+		assert_eq!(Assets::balance(id, unpooling), 52);
+		assert_eq!(Assets::balance(id, politipool), 6000 - 52);
+		assert_eq!(LLMPolitics::<Test>::get(2), 6000 - 52);
+		assert_eq!(LLM::get_politi_pooled_amount(), 6000 - 52);
+		assert_eq!(LLM::pending_unpool(2), 52);
+		assert_eq!(UnpoolQueue::<Test>::get(11).into_inner(), vec![(2, 52)]);
+	});
+}
+
+#[test]
+fn unpool_is_released_after_cooldown() {
+	new_test_ext().execute_with(|| {
+		UnpoolCooldown::set(10);
+		let id = LLM::llm_id();
+		let origin = RuntimeOrigin::signed(2);
+		assert_ok!(LLM::politics_lock(origin.clone(), 6000));
+		assert_ok!(LLM::politics_unlock(origin.clone()));
+
+		LLM::on_initialize(10);
+		assert_eq!(Assets::balance(id, 2), 0);
+
+		LLM::on_initialize(11);
+		assert_eq!(Assets::balance(id, 2), 52);
+		assert_eq!(Assets::balance(id, LLM::get_llm_unpooling_account()), 0);
+		assert_eq!(LLM::pending_unpool(2), 0);
+		assert!(UnpoolQueue::<Test>::get(11).is_empty());
+		System::assert_last_event(Event::LLMPoliticsUnlocked(2, 52).into());
+	});
+}
+
+#[test]
+fn pending_unpool_sums_requests() {
+	new_test_ext().execute_with(|| {
+		UnpoolCooldown::set(200);
+		let origin = RuntimeOrigin::signed(2);
+		assert_ok!(LLM::politics_lock(origin.clone(), 6000));
+		assert_ok!(LLM::politics_unlock(origin.clone()));
+		System::set_block_number(Withdrawlock::<Test>::get(2) + 1);
+		assert_ok!(LLM::politics_unlock(origin.clone()));
+		assert_eq!(LLM::pending_unpool(2), 52 + 51);
+
+		LLM::on_initialize(201);
+		assert_eq!(LLM::pending_unpool(2), 51);
+	});
+}
+
+#[test]
+fn politics_unlock_fails_if_unpool_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		UnpoolCooldown::set(10);
+		for acc in [1, 2] {
+			assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(acc), 1000));
+			assert_ok!(LLM::politics_unlock(RuntimeOrigin::signed(acc)));
+		}
+		LLM::transfer_from_vault(3, 1000).unwrap();
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(3), 1000));
+		assert_noop!(
+			LLM::politics_unlock(RuntimeOrigin::signed(3)),
+			Error::<Test>::UnpoolQueueFull
+		);
+	});
+}
+
+#[test]
+fn delegate_moves_voting_power() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(1), 1000));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(1), 3, 400));
+		System::assert_last_event(Event::LLMDelegated(1, 3, 400).into());
+
+		assert_eq!(LLM::delegation(1), Some((3, 400)));
+		assert_eq!(LLM::get_llm_politics(&1), 600);
+		assert_eq!(LLM::get_llm_politics(&3), 400);
+		assert_eq!(LLMPolitics::<Test>::get(1), 1000);
+	});
+}
+
+#[test]
+fn delegated_power_is_passed_down_the_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(2), 1000));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(2), 3, 1000));
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(1), 1000));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(1), 2, 500));
+
+		assert_eq!(LLM::get_llm_politics(&1), 500);
+		assert_eq!(LLM::get_llm_politics(&2), 0);
+		assert_eq!(LLM::get_llm_politics(&3), 1500);
+
+		assert_ok!(LLM::undelegate(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::LLMUndelegated(2, 3, 1000).into());
+		assert_eq!(LLM::get_llm_politics(&2), 1500);
+		assert_eq!(LLM::get_llm_politics(&3), 0);
+		assert_eq!(LLM::delegated_power(3), 0);
+	});
+}
+
+#[test]
+fn undelegate_restores_power() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(LLM::undelegate(RuntimeOrigin::signed(1)), Error::<Test>::NotDelegating);
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(1), 1000));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(1), 3, 1000));
+		assert_ok!(LLM::undelegate(RuntimeOrigin::signed(1)));
+
+		assert_eq!(LLM::delegation(1), None);
+		assert_eq!(LLM::get_llm_politics(&1), 1000);
+		assert_eq!(LLM::get_llm_politics(&3), 0);
+	});
+}
+
+#[test]
+fn delegate_validates_params() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(LLM::politics_lock(origin.clone(), 1000));
+		assert_noop!(LLM::delegate(origin.clone(), 1, 10), Error::<Test>::CircularDelegation);
+		assert_noop!(LLM::delegate(origin.clone(), 99, 10), Error::<Test>::NonCitizen);
+		assert_noop!(LLM::delegate(origin.clone(), 3, 0), Error::<Test>::InvalidAmount);
+		assert_noop!(LLM::delegate(origin.clone(), 3, 1001), Error::<Test>::LowBalance);
+
+		assert_ok!(LLM::delegate(origin.clone(), 3, 10));
+		assert_noop!(LLM::delegate(origin.clone(), 4, 10), Error::<Test>::AlreadyDelegating);
+
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(2), 1000));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(2), 1, 10));
+		assert_ok!(LLM::undelegate(origin.clone()));
+		assert_noop!(LLM::delegate(origin, 3, 10), Error::<Test>::HasDelegations);
+	});
+}
+
+#[test]
+fn delegate_respects_max_depth() {
+	new_test_ext().execute_with(|| {
+		LLM::transfer_from_vault(3, 1000).unwrap();
+		for acc in [1, 2, 3] {
+			assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(acc), 1000));
+		}
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(3), 4, 10));
+		assert_ok!(LLM::delegate(RuntimeOrigin::signed(2), 3, 10));
+		assert_noop!(
+			LLM::delegate(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::DelegationTooDeep
+		);
+	});
+}
+
+#[test]
+fn cant_politics_unlock_while_delegating() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(LLM::politics_lock(origin.clone(), 1000));
+		assert_ok!(LLM::delegate(origin.clone(), 3, 10));
+		assert_noop!(LLM::politics_unlock(origin.clone()), Error::<Test>::ActiveDelegation);
+		assert_ok!(LLM::undelegate(origin.clone()));
+		assert_ok!(LLM::politics_unlock(origin));
+	});
+}
+
 #[test]
 fn only_approved_accounts_can_call_treasury_llm_transfer() {
 	new_test_ext().execute_with(|| {
@@ -488,6 +666,203 @@ fn ensure_politics_allowed_succeeds_for_valid_citizen() {
 	});
 }
 
+fn setup_eresident(id: u64) {
+	let info = IdentityInfo {
+		additional: vec![
+			(
+				Data::Raw(b"eligible_on".to_vec().try_into().unwrap()),
+				Data::Raw(vec![0].try_into().unwrap()),
+			),
+			(
+				Data::Raw(b"eresident".to_vec().try_into().unwrap()),
+				Data::Raw(b"1".to_vec().try_into().unwrap()),
+			),
+		]
+		.try_into()
+		.unwrap(),
+		..Default::default()
+	};
+	Identity::set_identity(RuntimeOrigin::signed(id), Box::new(info.clone())).unwrap();
+	Identity::provide_judgement(
+		RuntimeOrigin::signed(0),
+		0,
+		id,
+		pallet_identity::Judgement::KnownGood,
+		BlakeTwo256::hash_of(&info),
+	)
+	.unwrap();
+}
+
+#[test]
+fn is_eresident_requires_lower_minimum() {
+	new_test_ext().execute_with(|| {
+		setup_eresident(10);
+		LLM::transfer_from_vault(10, 1000).unwrap();
+		assert!(!LLM::is_eresident(&10));
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(10), 999));
+		assert!(!LLM::is_eresident(&10));
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(10), 1));
+		assert!(LLM::is_eresident(&10));
+		assert!(!LLM::is_citizen(&10));
+	});
+}
+
+#[test]
+fn eresident_has_no_politics_rights() {
+	new_test_ext().execute_with(|| {
+		setup_eresident(10);
+		LLM::transfer_from_vault(10, 5000).unwrap();
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(10), 5000));
+		assert!(LLM::is_eresident(&10));
+		assert_noop!(LLM::ensure_politics_allowed(&10), Error::<Test>::NonCitizen);
+		assert_ok!(LLM::ensure_stocks_allowed(&10));
+	});
+}
+
+#[test]
+fn citizen_is_not_eresident() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(1), 5000));
+		assert!(LLM::is_citizen(&1));
+		assert!(!LLM::is_eresident(&1));
+	});
+}
+
+#[test]
+fn citizenship_origins_work() {
+	new_test_ext().execute_with(|| {
+		setup_eresident(10);
+		LLM::transfer_from_vault(10, 1000).unwrap();
+		assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(10), 1000));
+
+		assert_eq!(EnsureCitizen::<LLM, u64>::try_origin(RuntimeOrigin::signed(1)).ok(), Some(1));
+		assert!(EnsureCitizen::<LLM, u64>::try_origin(RuntimeOrigin::signed(10)).is_err());
+		assert!(EnsureCitizen::<LLM, u64>::try_origin(RuntimeOrigin::root()).is_err());
+
+		type Either = EnsureResidentOrCitizen<LLM, u64>;
+		assert_eq!(Either::try_origin(RuntimeOrigin::signed(1)).ok(), Some(1));
+		assert_eq!(Either::try_origin(RuntimeOrigin::signed(10)).ok(), Some(10));
+		assert!(Either::try_origin(RuntimeOrigin::signed(99)).is_err());
+	});
+}
+
+#[test]
+fn renew_citizenship_sets_expiry() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(LLM::politics_lock(origin.clone(), 5000));
+		assert_ok!(LLM::renew_citizenship(origin.clone()));
+		assert_eq!(LLM::citizenship_expiry(1), Some(101));
+		System::assert_last_event(Event::CitizenshipRenewed(1, 101).into());
+
+		System::set_block_number(50);
+		assert_ok!(LLM::renew_citizenship(origin));
+		assert_eq!(LLM::citizenship_expiry(1), Some(150));
+		LLM::on_idle(101, Weight::MAX);
+		assert_eq!(LLM::is_citizenship_expired(1), None);
+	});
+}
+
+#[test]
+fn renew_citizenship_requires_pooled_citizen() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(LLM::renew_citizenship(RuntimeOrigin::signed(99)), Error::<Test>::NonCitizen);
+		assert_noop!(LLM::renew_citizenship(RuntimeOrigin::signed(1)), Error::<Test>::NoPolLLM);
+		CitizenshipValidity::set(0);
+		assert_noop!(
+			LLM::renew_citizenship(RuntimeOrigin::signed(1)),
+			Error::<Test>::ExpiryDisabled
+		);
+	});
+}
+
+#[test]
+fn expired_citizenship_loses_politics_rights() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(LLM::politics_lock(origin.clone(), 5000));
+		assert_ok!(LLM::renew_citizenship(origin.clone()));
+
+		LLM::on_idle(100, Weight::MAX);
+		assert_ok!(LLM::ensure_politics_allowed(&1));
+
+		LLM::on_idle(101, Weight::MAX);
+		System::assert_has_event(Event::CitizenshipExpired(1).into());
+		assert_eq!(LLM::citizenship_expiry(1), None);
+		assert_noop!(LLM::ensure_politics_allowed(&1), Error::<Test>::CitizenshipExpired);
+		assert!(LLM::is_citizen(&1));
+		assert_eq!(LLMPolitics::<Test>::get(1), 5000);
+
+		assert_ok!(LLM::renew_citizenship(origin));
+		assert_ok!(LLM::ensure_politics_allowed(&1));
+	});
+}
+
+#[test]
+fn expiry_sweep_resumes_after_running_out_of_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(50);
+		for acc in [1, 2] {
+			assert_ok!(LLM::politics_lock(RuntimeOrigin::signed(acc), 5000));
+			assert_ok!(LLM::renew_citizenship(RuntimeOrigin::signed(acc)));
+		}
+		let tracked = CitizenshipExpiry::<Test>::iter().count();
+
+		let per_account = <() as WeightInfo>::expire_citizenship();
+		LLM::on_idle(150, per_account);
+		assert_eq!(CitizenshipExpiry::<Test>::iter().count(), tracked - 1);
+
+		LLM::on_idle(151, Weight::MAX);
+		assert_eq!(CitizenshipExpiry::<Test>::iter().count(), tracked - 2);
+		assert!(LLM::is_citizenship_expired(1).is_some());
+		assert!(LLM::is_citizenship_expired(2).is_some());
+	});
+}
+
+#[test]
+fn granted_citizenship_expires() {
+	new_test_ext().execute_with(|| {
+		// granted at block 1 by `new_test_ext`
+		assert_eq!(LLM::citizenship_expiry(3), Some(101));
+
+		LLM::on_idle(101, Weight::MAX);
+		System::assert_has_event(Event::CitizenshipExpired(3).into());
+		assert!(LLM::is_citizenship_expired(3).is_some());
+	});
+}
+
+#[test]
+fn losing_citizenship_clears_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(3)));
+		assert!(!LLM::is_citizen(&3));
+		assert_eq!(LLM::citizenship_expiry(3), None);
+
+		LLM::on_idle(101, Weight::MAX);
+		let expired: RuntimeEvent = Event::CitizenshipExpired(3).into();
+		assert!(System::events().iter().all(|record| record.event != expired));
+		assert_eq!(LLM::is_citizenship_expired(3), None);
+	});
+}
+
+#[test]
+fn migration_starts_validity_of_existing_citizens() {
+	new_test_ext().execute_with(|| {
+		let _ = CitizenshipExpiry::<Test>::clear(u32::MAX, None);
+		let _ = ExpiryQueue::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(4).put::<LLM>();
+		System::set_block_number(10);
+
+		crate::migrations::v5::Migration::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<LLM>(), 5);
+		assert_eq!(LLM::citizenship_expiry(3), Some(110));
+		assert_eq!(LLM::citizenship_expiry(99), None);
+		LLM::on_idle(110, Weight::MAX);
+		assert!(LLM::is_citizenship_expired(3).is_some());
+	});
+}
+
 fn assert_approx_eq(amount1: u64, amount2: u64, epsilon: u64) {
 	assert!(amount1 > amount2 - epsilon);
 	assert!(amount1 < amount2 + epsilon);
@@ -602,6 +977,64 @@ fn treasury_lld_transfer_calls_balances() {
 	});
 }
 
+#[test]
+fn only_courts_can_call_court_lld_transfer() {
+	new_test_ext().execute_with(|| {
+		let treasury = LLM::get_llm_treasury_account();
+		assert_noop!(
+			LLM::court_lld_transfer(RuntimeOrigin::signed(2), 3, 10),
+			Error::<Test>::NotCourt
+		);
+		assert_noop!(LLM::court_lld_transfer(RuntimeOrigin::root(), 3, 10), BadOrigin);
+		assert_ok!(LLM::court_lld_transfer(RuntimeOrigin::signed(1), 3, 10));
+		System::assert_has_event(
+			pallet_balances::Event::Transfer { from: treasury, to: 3, amount: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn court_lld_transfer_keeps_treasury_alive() {
+	new_test_ext().execute_with(|| {
+		let court = RuntimeOrigin::signed(1);
+		let treasury_balance = Balances::free_balance(LLM::get_llm_treasury_account());
+		assert!(LLM::court_lld_transfer(court, 3, treasury_balance).is_err());
+	});
+}
+
+#[test]
+fn only_courts_can_revoke_citizenship() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LLM::revoke_citizenship(RuntimeOrigin::signed(2), 3),
+			Error::<Test>::NotCourt
+		);
+		assert_noop!(LLM::revoke_citizenship(RuntimeOrigin::root(), 3), BadOrigin);
+		assert_noop!(
+			LLM::revoke_citizenship(RuntimeOrigin::signed(1), 99),
+			Error::<Test>::NonCitizen
+		);
+	});
+}
+
+#[test]
+fn revoke_citizenship_removes_judgements() {
+	new_test_ext().execute_with(|| {
+		let citizens = LLM::citizens_count();
+		assert!(LLM::is_citizen(&3));
+		assert_ok!(LLM::revoke_citizenship(RuntimeOrigin::signed(1), 3));
+		System::assert_last_event(Event::CitizenshipRevoked(3).into());
+
+		assert!(!LLM::is_citizen(&3));
+		assert_eq!(LLM::citizens_count(), citizens - 1);
+		assert!(Identity::identity(3).unwrap().judgements.is_empty());
+		assert_noop!(
+			LLM::revoke_citizenship(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::NonCitizen
+		);
+	});
+}
+
 #[test]
 fn transfer_trait_works() {
 	new_test_ext().execute_with(|| {
@@ -743,3 +1176,113 @@ fn force_updates_balances_correctly_to_locked() {
 		assert_eq!(LLMPolitics::<Test>::get(2), 2 + amount);
 	});
 }
+
+#[test]
+fn propose_llm_spend_holds_bond() {
+	new_test_ext().execute_with(|| {
+		let id = LLM::llm_id();
+		let bonds = LLM::get_llm_spend_bonds_account();
+		assert_noop!(
+			LLM::propose_llm_spend(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::InvalidAmount
+		);
+
+		assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(1), 1000, 3));
+		System::assert_last_event(Event::LLMSpendProposed(0, 1, 3, 1000).into());
+		assert_eq!(Assets::balance(id, 1), 5950);
+		assert_eq!(Assets::balance(id, bonds), 50);
+		assert_eq!(
+			LLMSpendProposals::<Test>::get(0),
+			Some(LLMSpendProposal { proposer: 1, value: 1000, beneficiary: 3, bond: 50 })
+		);
+
+		// 5% of 100 is below minimum bond
+		assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(2), 100, 3));
+		assert_eq!(LLMSpendProposals::<Test>::get(1).unwrap().bond, 10);
+		assert_eq!(LLM::llm_spend_proposal_count(), 2);
+	});
+}
+
+#[test]
+fn approve_llm_spend_works() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(1), 100, 3));
+		}
+		assert_noop!(LLM::approve_llm_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_noop!(
+			LLM::approve_llm_spend(RuntimeOrigin::root(), 4),
+			Error::<Test>::InvalidLLMSpendProposal
+		);
+
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::LLMSpendApproved(0).into());
+		assert_noop!(
+			LLM::approve_llm_spend(RuntimeOrigin::root(), 0),
+			Error::<Test>::LLMSpendAlreadyApproved
+		);
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 1));
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			LLM::approve_llm_spend(RuntimeOrigin::root(), 3),
+			Error::<Test>::TooManyLLMSpendApprovals
+		);
+		assert_eq!(LLMSpendApprovals::<Test>::get().into_inner(), vec![0, 1, 2]);
+	});
+}
+
+#[test]
+fn approved_llm_spends_are_paid_each_period() {
+	new_test_ext().execute_with(|| {
+		let id = LLM::llm_id();
+		let pot = LLM::get_llm_spend_pot_account();
+		let bonds = LLM::get_llm_spend_bonds_account();
+		assert_ok!(LLM::transfer_from_treasury(pot, 1500));
+		assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(1), 1000, 3));
+		assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(2), 1000, 4));
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 0));
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 1));
+
+		LLM::on_initialize(9);
+		assert_eq!(Assets::balance(id, 3), 0);
+
+		LLM::on_initialize(10);
+		System::assert_last_event(Event::LLMSpendPaid(0, 3, 1000).into());
+		assert_eq!(Assets::balance(id, 3), 1000);
+		assert_eq!(Assets::balance(id, 1), 6000);
+		assert_eq!(Assets::balance(id, pot), 500);
+		assert_eq!(LLMSpendProposals::<Test>::get(0), None);
+		// not enough LLM in pot, waits for next period
+		assert_eq!(Assets::balance(id, 4), 0);
+		assert_eq!(LLMSpendApprovals::<Test>::get().into_inner(), vec![1]);
+
+		assert_ok!(LLM::transfer_from_treasury(pot, 500));
+		LLM::on_initialize(20);
+		assert_eq!(Assets::balance(id, 4), 1000);
+		assert_eq!(Assets::balance(id, 2), 6000);
+		assert_eq!(Assets::balance(id, bonds), 0);
+		assert!(LLMSpendApprovals::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn reject_llm_spend_slashes_bond_to_pot() {
+	new_test_ext().execute_with(|| {
+		let id = LLM::llm_id();
+		let pot = LLM::get_llm_spend_pot_account();
+		assert_ok!(LLM::propose_llm_spend(RuntimeOrigin::signed(1), 1000, 3));
+		assert_ok!(LLM::approve_llm_spend(RuntimeOrigin::root(), 0));
+		assert_noop!(LLM::reject_llm_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+
+		assert_ok!(LLM::reject_llm_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::LLMSpendRejected(0, 50).into());
+		assert_eq!(Assets::balance(id, 1), 5950);
+		assert_eq!(Assets::balance(id, pot), 50);
+		assert_eq!(LLMSpendProposals::<Test>::get(0), None);
+		assert!(LLMSpendApprovals::<Test>::get().is_empty());
+		assert_noop!(
+			LLM::reject_llm_spend(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidLLMSpendProposal
+		);
+	});
+}
@@ -6,6 +6,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `kacper-HP-ProBook-445-G7`, CPU: `AMD Ryzen 7 4700U with Radeon Graphics`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! Functions marked NOT BENCHMARKED were added after this run and are
+//! estimated. Replace them with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_llm` run on
+//! reference hardware.
 
 // Executed Command:
 // target/release/substrate-node
@@ -39,6 +44,17 @@ pub trait WeightInfo {
 	fn remark(l: u32, ) -> Weight;
 	fn force_transfer() -> Weight;
 	fn set_courts(l: u32, ) -> Weight;
+	fn court_lld_transfer() -> Weight;
+	fn revoke_citizenship() -> Weight;
+	fn release_unpools(n: u32, ) -> Weight;
+	fn delegate(d: u32, ) -> Weight;
+	fn undelegate(d: u32, ) -> Weight;
+	fn renew_citizenship() -> Weight;
+	fn expire_citizenship() -> Weight;
+	fn propose_llm_spend() -> Weight;
+	fn approve_llm_spend() -> Weight;
+	fn reject_llm_spend() -> Weight;
+	fn spend_llm_funds(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_llm using the Substrate node and recommended hardware.
@@ -205,6 +221,180 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(241_677, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LLM::Courts` (r:1 w:0)
+	/// Proof: `LLM::Courts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn court_lld_transfer() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(68_614_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LLM::Courts` (r:1 w:0)
+	/// Proof: `LLM::Courts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::Citizens` (r:1 w:1)
+	/// Proof: `LLM::Citizens` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	fn revoke_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(60_103_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LLM::UnpoolQueue` (r:1 w:1)
+	/// Proof: `LLM::UnpoolQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::PendingUnpool` (r:256 w:256)
+	/// Proof: `LLM::PendingUnpool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:257 w:257)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn release_unpools(n: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(10_120_000, 3675)
+			.saturating_add(Weight::from_parts(52_367_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
+	/// Storage: `LLM::Delegations` (r:6 w:1)
+	/// Proof: `LLM::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::DelegatedPower` (r:6 w:5)
+	/// Proof: `LLM::DelegatedPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::LLMPolitics` (r:1 w:0)
+	/// Proof: `LLM::LLMPolitics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn delegate(d: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(58_214_000, 11003)
+			.saturating_add(Weight::from_parts(8_731_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(d.into()))
+	}
+	/// Storage: `LLM::Delegations` (r:6 w:1)
+	/// Proof: `LLM::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::DelegatedPower` (r:6 w:5)
+	/// Proof: `LLM::DelegatedPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:5 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn undelegate(d: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(24_872_000, 4771)
+			.saturating_add(Weight::from_parts(12_406_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 6270).saturating_mul(d.into()))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMPolitics` (r:1 w:0)
+	/// Proof: `LLM::LLMPolitics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::CitizenshipExpiry` (r:1 w:1)
+	/// Proof: `LLM::CitizenshipExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiryQueue` (r:0 w:2)
+	/// Proof: `LLM::ExpiryQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiredCitizenships` (r:0 w:1)
+	/// Proof: `LLM::ExpiredCitizenships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renew_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(42_671_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LLM::CitizenshipExpiry` (r:0 w:1)
+	/// Proof: `LLM::CitizenshipExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiredCitizenships` (r:0 w:1)
+	/// Proof: `LLM::ExpiredCitizenships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	fn expire_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(22_014_000, 7260)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposalCount` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposals` (r:0 w:1)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn propose_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(65_390_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LLM::LLMSpendProposals` (r:1 w:0)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	fn approve_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(15_402_000, 3573)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LLM::LLMSpendProposals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(59_872_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:201 w:201)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposals` (r:100 w:100)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:200 w:200)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn spend_llm_funds(n: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(11_874_000, 3675)
+			.saturating_add(Weight::from_parts(98_425_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -370,4 +560,178 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(241_677, 0).saturating_mul(l.into()))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LLM::Courts` (r:1 w:0)
+	/// Proof: `LLM::Courts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn court_lld_transfer() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(68_614_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LLM::Courts` (r:1 w:0)
+	/// Proof: `LLM::Courts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::Citizens` (r:1 w:1)
+	/// Proof: `LLM::Citizens` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	fn revoke_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(60_103_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LLM::UnpoolQueue` (r:1 w:1)
+	/// Proof: `LLM::UnpoolQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::PendingUnpool` (r:256 w:256)
+	/// Proof: `LLM::PendingUnpool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:257 w:257)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn release_unpools(n: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(10_120_000, 3675)
+			.saturating_add(Weight::from_parts(52_367_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
+	/// Storage: `LLM::Delegations` (r:6 w:1)
+	/// Proof: `LLM::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::DelegatedPower` (r:6 w:5)
+	/// Proof: `LLM::DelegatedPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::LLMPolitics` (r:1 w:0)
+	/// Proof: `LLM::LLMPolitics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn delegate(d: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(58_214_000, 11003)
+			.saturating_add(Weight::from_parts(8_731_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(d.into()))
+	}
+	/// Storage: `LLM::Delegations` (r:6 w:1)
+	/// Proof: `LLM::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::DelegatedPower` (r:6 w:5)
+	/// Proof: `LLM::DelegatedPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:5 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn undelegate(d: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(24_872_000, 4771)
+			.saturating_add(Weight::from_parts(12_406_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 6270).saturating_mul(d.into()))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMPolitics` (r:1 w:0)
+	/// Proof: `LLM::LLMPolitics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::CitizenshipExpiry` (r:1 w:1)
+	/// Proof: `LLM::CitizenshipExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiryQueue` (r:0 w:2)
+	/// Proof: `LLM::ExpiryQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiredCitizenships` (r:0 w:1)
+	/// Proof: `LLM::ExpiredCitizenships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renew_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(42_671_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LLM::CitizenshipExpiry` (r:0 w:1)
+	/// Proof: `LLM::CitizenshipExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LLM::ExpiredCitizenships` (r:0 w:1)
+	/// Proof: `LLM::ExpiredCitizenships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Democracy::VotingOf` (r:1 w:0)
+	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
+	fn expire_citizenship() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(22_014_000, 7260)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposalCount` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposals` (r:0 w:1)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn propose_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(65_390_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LLM::LLMSpendProposals` (r:1 w:0)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	fn approve_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(15_402_000, 3573)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LLM::LLMSpendProposals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_llm_spend() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(59_872_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LLM::LLMSpendApprovals` (r:1 w:1)
+	/// Proof: `LLM::LLMSpendApprovals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:201 w:201)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `LLM::LLMSpendProposals` (r:100 w:100)
+	/// Proof: `LLM::LLMSpendProposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:200 w:200)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn spend_llm_funds(n: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(11_874_000, 3675)
+			.saturating_add(Weight::from_parts(98_425_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
 }
@@ -43,7 +43,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - `LockedItemMetadata`: The metadata for the item is locked and cannot be modified.
 	/// - `NoPermission`: The caller does not have the required permission to set the metadata.
 	/// - `DepositExceeded`: The deposit amount exceeds the maximum allowed value.
-	pub(crate) fn do_set_item_metadata(
+	pub fn do_set_item_metadata(
 		maybe_check_origin: Option<T::AccountId>,
 		collection: T::CollectionId,
 		item: T::ItemId,
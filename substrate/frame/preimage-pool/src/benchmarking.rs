@@ -0,0 +1,67 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as PreimagePool;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::Get};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::{Bounded, One, Saturating};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let acc: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&acc, BalanceOf::<T>::max_value() / 4u32.into());
+	acc
+}
+
+fn max_len<T: Config>() -> Result<u32, BenchmarkError> {
+	T::DepositTiers::get()
+		.last()
+		.map(|(max_len, _)| *max_len)
+		.ok_or(BenchmarkError::Weightless)
+}
+
+fn noted<T: Config>() -> Result<(T::AccountId, H256), BenchmarkError> {
+	let depositor = funded::<T>("depositor", 0);
+	let bytes = vec![1u8; max_len::<T>()? as usize];
+	let hash = sp_io::hashing::blake2_256(&bytes).into();
+	assert_ok!(PreimagePool::<T>::note_preimage(
+		RawOrigin::Signed(depositor.clone()).into(),
+		bytes
+	));
+	Ok((depositor, hash))
+}
+
+benchmarks! {
+	note_preimage {
+		let s in 0 .. max_len::<T>()?;
+		let depositor = funded::<T>("depositor", 0);
+	}: _(RawOrigin::Signed(depositor), vec![1u8; s as usize])
+	verify {
+		assert_eq!(PreimagePool::<T>::stats().count, 1);
+	}
+
+	unnote_preimage {
+		let (depositor, hash) = noted::<T>()?;
+	}: _(RawOrigin::Signed(depositor), hash)
+	verify {
+		assert!(!Preimages::<T>::contains_key(hash));
+	}
+
+	cleanup {
+		let (_, hash) = noted::<T>()?;
+		let cleaner = funded::<T>("cleaner", 0);
+		let expired = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::ExpiryPeriod::get())
+			.saturating_add(One::one());
+		frame_system::Pallet::<T>::set_block_number(expired);
+	}: _(RawOrigin::Signed(cleaner), hash)
+	verify {
+		assert!(!Preimages::<T>::contains_key(hash));
+	}
+}
+
+impl_benchmark_test_suite!(PreimagePool, crate::mock::new_test_ext(), crate::mock::Test,);
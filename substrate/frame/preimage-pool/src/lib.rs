@@ -0,0 +1,279 @@
+//! # Liberland Preimage Pool Pallet
+//!
+//! ## Overview
+//!
+//! Preimage pool pallet is the citizen-facing way of noting preimages of
+//! referendum proposals. It stores preimages in the preimage pallet, but
+//! manages deposits and lifetime itself:
+//!
+//! * deposit depends on the size tier of the preimage, so small proposals
+//!   stay cheap while big blobs pay for the storage they take,
+//! * every preimage expires `ExpiryPeriod` blocks after it was noted,
+//! * expired preimages that aren't referenced (requested) by democracy or
+//!   scheduler can be cleaned up by anyone. Caller gets `CleanupBounty` share
+//!   of the deposit, the rest is returned to depositor,
+//! * depositor can unnote own preimage at any time and get the full deposit
+//!   back.
+//!
+//! Pool usage (count, bytes and deposits) is tracked in `Stats`.
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency in which deposits are reserved
+//! * `Preimages` - preimage storage, usually the preimage pallet
+//! * `DepositTiers` - list of `(max_len, deposit)` sorted by `max_len`.
+//!   Preimages longer than last tier are rejected.
+//! * `ExpiryPeriod` - number of blocks after which preimage expires
+//! * `CleanupBounty` - share of deposit paid to account cleaning up expired
+//!   preimage
+//! * `ReserveIdentifier` - identifier of named reserves
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `note_preimage`: Note preimage and reserve tiered deposit
+//! * `unnote_preimage`: Remove own preimage and return deposit
+//! * `cleanup`: Remove expired unreferenced preimage and collect bounty
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, NamedReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{QueryPreimage, StorePreimage},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H256;
+	use sp_runtime::{traits::Saturating, Permill};
+	use sp_std::{borrow::Cow, prelude::*};
+
+	type ReserveIdentifierOf<T> = <<T as Config>::Currency as NamedReservableCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::ReserveIdentifier;
+
+	pub type PooledPreimageOf<T> =
+		PooledPreimage<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for deposits
+		type Currency: NamedReservableCurrency<Self::AccountId>;
+
+		/// Storage of preimages
+		type Preimages: QueryPreimage + StorePreimage;
+
+		/// `(max_len, deposit)` tiers sorted by `max_len`
+		type DepositTiers: Get<Vec<(u32, BalanceOf<Self>)>>;
+
+		/// Number of blocks after which preimage expires
+		#[pallet::constant]
+		type ExpiryPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Share of deposit paid to account cleaning up expired preimage
+		#[pallet::constant]
+		type CleanupBounty: Get<Permill>;
+
+		/// Identifies reserves in Currency
+		#[pallet::constant]
+		type ReserveIdentifier: Get<&'static ReserveIdentifierOf<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Preimage is larger than the largest deposit tier
+		TooBig,
+		/// Preimage is already pooled
+		AlreadyNoted,
+		/// Preimage isn't pooled
+		NotNoted,
+		/// Caller isn't the depositor
+		NotDepositor,
+		/// Preimage didn't expire yet
+		NotExpired,
+		/// Preimage is referenced and can't be cleaned up
+		Requested,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Preimage noted
+		Noted { hash: H256, depositor: T::AccountId, deposit: BalanceOf<T>, len: u32 },
+		/// Preimage removed by depositor
+		Unnoted { hash: H256 },
+		/// Expired preimage cleaned up
+		CleanedUp { hash: H256, cleaner: T::AccountId, bounty: BalanceOf<T> },
+	}
+
+	/// Preimages noted through this pallet
+	#[pallet::storage]
+	#[pallet::getter(fn preimages)]
+	pub type Preimages<T: Config> = StorageMap<_, Identity, H256, PooledPreimageOf<T>, OptionQuery>;
+
+	/// Usage of the pool
+	#[pallet::storage]
+	#[pallet::getter(fn stats)]
+	pub type Stats<T: Config> = StorageValue<_, PoolStats<BalanceOf<T>>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Note preimage and reserve deposit of its size tier.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must
+		/// have funds to cover the deposit.
+		///
+		/// - `bytes`: The preimage
+		///
+		/// Emits `Noted`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::note_preimage(bytes.len() as u32))]
+		pub fn note_preimage(origin: OriginFor<T>, bytes: Vec<u8>) -> DispatchResult {
+			let depositor = ensure_signed(origin)?;
+			let len = bytes.len() as u32;
+			let deposit = Self::deposit_for(len).ok_or(Error::<T>::TooBig)?;
+			let hash: H256 = sp_io::hashing::blake2_256(&bytes).into();
+			ensure!(!Preimages::<T>::contains_key(hash), Error::<T>::AlreadyNoted);
+
+			T::Currency::reserve_named(T::ReserveIdentifier::get(), &depositor, deposit)?;
+			T::Preimages::note(Cow::from(bytes))?;
+
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ExpiryPeriod::get());
+			Preimages::<T>::insert(
+				hash,
+				PooledPreimageOf::<T> { depositor: depositor.clone(), deposit, len, expires_at },
+			);
+			Stats::<T>::mutate(|stats| {
+				stats.count.saturating_inc();
+				stats.bytes.saturating_accrue(len as u64);
+				stats.deposits.saturating_accrue(deposit);
+			});
+
+			Self::deposit_event(Event::Noted { hash, depositor, deposit, len });
+			Ok(())
+		}
+
+		/// Remove own preimage and get the deposit back.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the depositor.
+		///
+		/// - `hash`: Hash of the preimage
+		///
+		/// Emits `Unnoted`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unnote_preimage())]
+		pub fn unnote_preimage(origin: OriginFor<T>, hash: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let preimage = Self::preimages(hash).ok_or(Error::<T>::NotNoted)?;
+			ensure!(preimage.depositor == who, Error::<T>::NotDepositor);
+
+			Self::remove(hash, &preimage);
+			T::Currency::unreserve_named(T::ReserveIdentifier::get(), &who, preimage.deposit);
+
+			Self::deposit_event(Event::Unnoted { hash });
+			Ok(())
+		}
+
+		/// Remove expired preimage that isn't referenced by anything. Caller
+		/// receives `CleanupBounty` share of the deposit, rest is returned to
+		/// depositor.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `hash`: Hash of the preimage
+		///
+		/// Emits `CleanedUp`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cleanup())]
+		pub fn cleanup(origin: OriginFor<T>, hash: H256) -> DispatchResult {
+			let cleaner = ensure_signed(origin)?;
+			let preimage = Self::preimages(hash).ok_or(Error::<T>::NotNoted)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > preimage.expires_at, Error::<T>::NotExpired);
+
+			// noting through `StorePreimage` counts as a request itself, so
+			// other references are only visible after dropping ours - if
+			// there are any, the error reverts the removal
+			Self::remove(hash, &preimage);
+			ensure!(!T::Preimages::is_requested(&hash), Error::<T>::Requested);
+
+			let bounty = T::CleanupBounty::get() * preimage.deposit;
+			T::Currency::unreserve_named(
+				T::ReserveIdentifier::get(),
+				&preimage.depositor,
+				preimage.deposit,
+			);
+			T::Currency::transfer(
+				&preimage.depositor,
+				&cleaner,
+				bounty,
+				frame_support::traits::ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::CleanedUp { hash, cleaner, bounty });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Deposit for preimage of `len` bytes, `None` if it's too big
+		pub fn deposit_for(len: u32) -> Option<BalanceOf<T>> {
+			T::DepositTiers::get()
+				.into_iter()
+				.find(|(max_len, _)| len <= *max_len)
+				.map(|(_, deposit)| deposit)
+		}
+
+		fn remove(hash: H256, preimage: &PooledPreimageOf<T>) {
+			T::Preimages::unnote(&hash);
+			Preimages::<T>::remove(hash);
+			Stats::<T>::mutate(|stats| {
+				stats.count.saturating_dec();
+				stats.bytes.saturating_reduce(preimage.len as u64);
+				stats.deposits.saturating_reduce(preimage.deposit);
+			});
+		}
+	}
+}
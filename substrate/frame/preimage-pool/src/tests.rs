@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, PoolStats};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{NamedReservableCurrency, QueryPreimage},
+};
+use sp_core::H256;
+
+fn hash_of(bytes: &[u8]) -> H256 {
+	sp_io::hashing::blake2_256(bytes).into()
+}
+
+fn reserved(who: u64) -> u64 {
+	Balances::reserved_balance_named(ReserveIdentifier::get(), &who)
+}
+
+fn note(who: u64, bytes: &[u8]) -> H256 {
+	assert_ok!(PreimagePool::note_preimage(RuntimeOrigin::signed(who), bytes.to_vec()));
+	hash_of(bytes)
+}
+
+#[test]
+fn deposit_depends_on_size_tier() {
+	new_test_ext().execute_with(|| {
+		note(1, &[1u8; 8]);
+		assert_eq!(reserved(1), 2);
+		note(1, &[2u8; 9]);
+		assert_eq!(reserved(1), 12);
+		assert_noop!(
+			PreimagePool::note_preimage(RuntimeOrigin::signed(1), vec![3u8; 65]),
+			Error::<Test>::TooBig
+		);
+	});
+}
+
+#[test]
+fn note_preimage_stores_preimage() {
+	new_test_ext().execute_with(|| {
+		let hash = note(1, &[1u8; 8]);
+		assert_eq!(Preimage::len(&hash), Some(8));
+		let preimage = PreimagePool::preimages(hash).unwrap();
+		assert_eq!(preimage.depositor, 1);
+		assert_eq!(preimage.deposit, 2);
+		assert_eq!(preimage.len, 8);
+		assert_eq!(preimage.expires_at, 11);
+		assert_eq!(PreimagePool::stats(), PoolStats { count: 1, bytes: 8, deposits: 2 });
+		System::assert_last_event(Event::Noted { hash, depositor: 1, deposit: 2, len: 8 }.into());
+
+		assert_noop!(
+			PreimagePool::note_preimage(RuntimeOrigin::signed(2), vec![1u8; 8]),
+			Error::<Test>::AlreadyNoted
+		);
+	});
+}
+
+#[test]
+fn unnote_preimage_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		let hash = note(1, &[1u8; 8]);
+		assert_noop!(
+			PreimagePool::unnote_preimage(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::NotDepositor
+		);
+		assert_ok!(PreimagePool::unnote_preimage(RuntimeOrigin::signed(1), hash));
+		assert_eq!(reserved(1), 0);
+		assert_eq!(PreimagePool::preimages(hash), None);
+		assert_eq!(Preimage::len(&hash), None);
+		assert_eq!(PreimagePool::stats(), PoolStats::default());
+		assert_noop!(
+			PreimagePool::unnote_preimage(RuntimeOrigin::signed(1), hash),
+			Error::<Test>::NotNoted
+		);
+	});
+}
+
+#[test]
+fn cleanup_requires_expiry() {
+	new_test_ext().execute_with(|| {
+		let hash = note(1, &[1u8; 9]);
+		System::set_block_number(11);
+		assert_noop!(
+			PreimagePool::cleanup(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::NotExpired
+		);
+	});
+}
+
+#[test]
+fn cleanup_pays_bounty() {
+	new_test_ext().execute_with(|| {
+		let hash = note(1, &[1u8; 9]);
+		System::set_block_number(12);
+		let (before_1, before_2) = (Balances::free_balance(1), Balances::free_balance(2));
+		assert_ok!(PreimagePool::cleanup(RuntimeOrigin::signed(2), hash));
+		assert_eq!(reserved(1), 0);
+		assert_eq!(Balances::free_balance(1), before_1 + 8);
+		assert_eq!(Balances::free_balance(2), before_2 + 2);
+		assert_eq!(Preimage::len(&hash), None);
+		assert_eq!(PreimagePool::stats(), PoolStats::default());
+		System::assert_last_event(Event::CleanedUp { hash, cleaner: 2, bounty: 2 }.into());
+	});
+}
+
+#[test]
+fn cleanup_keeps_referenced_preimages() {
+	new_test_ext().execute_with(|| {
+		let hash = note(1, &[1u8; 9]);
+		<Preimage as QueryPreimage>::request(&hash);
+		System::set_block_number(12);
+		assert_noop!(
+			PreimagePool::cleanup(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::Requested
+		);
+		assert_eq!(Preimage::len(&hash), Some(9));
+	});
+}
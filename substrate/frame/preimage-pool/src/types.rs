@@ -0,0 +1,26 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PooledPreimage<AccountId, Balance, BlockNumber> {
+	/// Account that noted the preimage and holds the deposit
+	pub depositor: AccountId,
+	/// Deposit reserved from depositor, based on size tier
+	pub deposit: Balance,
+	/// Length of the preimage in bytes
+	pub len: u32,
+	/// Block after which unreferenced preimage can be cleaned up by anyone
+	pub expires_at: BlockNumber,
+}
+
+/// Usage of the preimage pool
+#[derive(Clone, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PoolStats<Balance> {
+	/// Number of pooled preimages
+	pub count: u32,
+	/// Total length of pooled preimages in bytes
+	pub bytes: u64,
+	/// Total deposits reserved for pooled preimages
+	pub deposits: Balance,
+}
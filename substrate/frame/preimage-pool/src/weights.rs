@@ -0,0 +1,62 @@
+//! Weights for pallet_preimage_pool
+//!
+//! NOT BENCHMARKED. Based on pallet_preimage weights of the matching calls plus
+//! this pallet's own storage accesses. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_preimage_pool` run
+//! on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_preimage_pool.
+pub trait WeightInfo {
+	fn note_preimage(s: u32, ) -> Weight;
+	fn unnote_preimage() -> Weight;
+	fn cleanup() -> Weight;
+}
+
+/// Weights for pallet_preimage_pool using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn note_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 3724)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn unnote_preimage() -> Weight {
+		Weight::from_parts(48_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn cleanup() -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn note_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 3724)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn unnote_preimage() -> Weight {
+		Weight::from_parts(48_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn cleanup() -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
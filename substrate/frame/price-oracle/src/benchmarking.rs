@@ -0,0 +1,61 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get},
+};
+use frame_system::RawOrigin;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn feeder_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::FeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn feeders<T: Config>(n: u32, offset: u32) -> FeedersOf<T> {
+	(0..n)
+		.map(|i| account("feeder", i + offset, SEED))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+/// Set `n` feeders, all of which submitted a price.
+fn set_up_feeders<T: Config>(n: u32) -> Result<FeedersOf<T>, BenchmarkError> {
+	let feeders = feeders::<T>(n, 0);
+	assert_ok!(Pallet::<T>::set_feeders(feeder_origin::<T>()?, feeders.clone()));
+	for (i, feeder) in feeders.iter().enumerate() {
+		let price = FixedU128::saturating_from_integer(i as u32 + 1);
+		assert_ok!(Pallet::<T>::submit_price(RawOrigin::Signed(feeder.clone()).into(), price));
+	}
+	Ok(feeders)
+}
+
+benchmarks! {
+	set_feeders {
+		let f in 1 .. T::MaxFeeders::get();
+		let origin = feeder_origin::<T>()?;
+		// worst case - all previous feeders are removed
+		set_up_feeders::<T>(T::MaxFeeders::get())?;
+		let new_feeders = feeders::<T>(f, T::MaxFeeders::get());
+	}: _<T::RuntimeOrigin>(origin, new_feeders.clone())
+	verify {
+		assert_eq!(Pallet::<T>::feeders(), new_feeders);
+	}
+
+	submit_price {
+		let f in 1 .. T::MaxFeeders::get();
+		let feeders = set_up_feeders::<T>(f)?;
+		let feeder = feeders[0].clone();
+		let price = FixedU128::saturating_from_integer(1000u32);
+	}: _(RawOrigin::Signed(feeder.clone()), price)
+	verify {
+		assert_eq!(Pallet::<T>::submissions(&feeder).unwrap().price, price);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
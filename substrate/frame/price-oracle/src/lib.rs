@@ -0,0 +1,275 @@
+//! # Liberland Price Oracle Pallet
+//!
+//! ## Overview
+//!
+//! Price oracle pallet aggregates LLM price in LLD submitted by feeders
+//! appointed by `FeederOrigin`, e.g. Congress:
+//!
+//! * each feeder keeps a single submission - its latest price and the block
+//!   it was submitted at,
+//! * submissions older than `MaxPriceAge` blocks are stale,
+//! * on every submission, the price is recalculated as median of fresh
+//!   submissions. It's only published if there are at least
+//!   `MinSubmissions` of them, and stays valid until fewer than
+//!   `MinSubmissions` would be fresh.
+//!
+//! Stale price is never used - conversions fail instead, so consumers can
+//! fall back to other means, e.g. charging fees in LLD.
+//!
+//! Pallet implements `ConversionToAssetBalance` from LLD to LLM, the
+//! conversion fungibles-based transaction payment adapters, e.g.
+//! `pallet_asset_tx_payment::FungiblesAdapter`, take. Liberland runtime
+//! doesn't charge fees with it yet - fees in assets are paid through
+//! `pallet_asset_conversion_tx_payment`, which prices them with asset
+//! conversion pools. Charging LLM fees at the oracle price needs a fee
+//! adapter using this conversion in place of the pool swap.
+//!
+//! ## Pallet Config
+//!
+//! * `AssetId` - type of asset ids used by `ConversionToAssetBalance`
+//! * `LLMAssetId` - asset id of LLM
+//! * `FeederOrigin` - origin that appoints feeders
+//! * `MaxFeeders` - maximum number of feeders
+//! * `MinSubmissions` - minimum number of fresh submissions for a valid price
+//! * `MaxPriceAge` - number of blocks after which submission is stale
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_feeders`: Replace the set of feeders
+//! * `submit_price`: Submit LLM price in LLD
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::tokens::ConversionToAssetBalance};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		FixedPointNumber, FixedPointOperand, FixedU128,
+	};
+	use sp_std::prelude::*;
+
+	pub type FeedersOf<T> =
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFeeders>;
+	pub type SubmissionOf<T> = Submission<BlockNumberFor<T>>;
+	pub type AggregatedPriceOf<T> = AggregatedPrice<BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type of asset ids used by `ConversionToAssetBalance`
+		type AssetId: Parameter;
+
+		/// Asset id of LLM
+		#[pallet::constant]
+		type LLMAssetId: Get<Self::AssetId>;
+
+		/// Origin that appoints feeders
+		type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of feeders
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+
+		/// Minimum number of fresh submissions for a valid price
+		#[pallet::constant]
+		type MinSubmissions: Get<u32>;
+
+		/// Number of blocks after which submission is stale
+		#[pallet::constant]
+		type MaxPriceAge: Get<BlockNumberFor<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sender isn't a feeder
+		NotFeeder,
+		/// Price must be non-zero
+		ZeroPrice,
+		/// Feeders list contains duplicates
+		DuplicateFeeder,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Set of feeders replaced
+		FeedersSet { feeders: FeedersOf<T> },
+		/// Feeder submitted price
+		PriceSubmitted { feeder: T::AccountId, price: FixedU128 },
+		/// Aggregated price updated
+		PriceUpdated { price: FixedU128, valid_until: BlockNumberFor<T> },
+	}
+
+	/// Accounts allowed to submit prices
+	#[pallet::storage]
+	#[pallet::getter(fn feeders)]
+	pub type Feeders<T: Config> = StorageValue<_, FeedersOf<T>, ValueQuery>;
+
+	/// Latest submission of each feeder
+	#[pallet::storage]
+	#[pallet::getter(fn submissions)]
+	pub type Submissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SubmissionOf<T>, OptionQuery>;
+
+	/// Median of fresh submissions, possibly already stale - use
+	/// [`Pallet::price`] to get valid price
+	#[pallet::storage]
+	#[pallet::getter(fn aggregated_price)]
+	pub type Price<T: Config> = StorageValue<_, AggregatedPriceOf<T>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Replace the set of feeders. Submissions of removed feeders are
+		/// dropped and the price is recalculated.
+		///
+		/// The dispatch origin of this call must be `FeederOrigin`.
+		///
+		/// - `feeders`: New set of feeders
+		///
+		/// Emits `FeedersSet`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_feeders(T::MaxFeeders::get()))]
+		pub fn set_feeders(origin: OriginFor<T>, feeders: FeedersOf<T>) -> DispatchResult {
+			T::FeederOrigin::ensure_origin(origin)?;
+			let mut sorted = feeders.clone().into_inner();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(sorted.len() == feeders.len(), Error::<T>::DuplicateFeeder);
+
+			for old in Self::feeders().iter().filter(|f| !feeders.contains(*f)) {
+				Submissions::<T>::remove(old);
+			}
+			Feeders::<T>::put(&feeders);
+			Self::aggregate(&feeders);
+
+			Self::deposit_event(Event::FeedersSet { feeders });
+			Ok(())
+		}
+
+		/// Submit LLM price in LLD, replacing sender's previous submission.
+		///
+		/// The dispatch origin of this call must be Signed by a feeder.
+		///
+		/// - `price`: Amount of LLD worth 1 LLM
+		///
+		/// Emits `PriceSubmitted` and `PriceUpdated` if there are enough
+		/// fresh submissions.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_price(T::MaxFeeders::get()))]
+		pub fn submit_price(origin: OriginFor<T>, price: FixedU128) -> DispatchResult {
+			let feeder = ensure_signed(origin)?;
+			let feeders = Self::feeders();
+			ensure!(feeders.contains(&feeder), Error::<T>::NotFeeder);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+
+			let submitted_at = frame_system::Pallet::<T>::block_number();
+			Submissions::<T>::insert(&feeder, Submission { price, submitted_at });
+			Self::deposit_event(Event::PriceSubmitted { feeder, price });
+			Self::aggregate(&feeders);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Valid LLM price in LLD, `None` if it's stale or there were never
+		/// enough submissions.
+		pub fn price() -> Option<FixedU128> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::aggregated_price().filter(|p| now < p.valid_until).map(|p| p.price)
+		}
+
+		/// Value of `llm` in LLD at current price.
+		pub fn llm_to_lld<B: FixedPointOperand>(llm: B) -> Option<B> {
+			Self::price()?.checked_mul_int(llm)
+		}
+
+		/// Value of `lld` in LLM at current price.
+		pub fn lld_to_llm<B: FixedPointOperand>(lld: B) -> Option<B> {
+			Self::price()?.reciprocal()?.checked_mul_int(lld)
+		}
+
+		/// Recalculate price from fresh submissions of `feeders`. Price is
+		/// removed if there aren't enough of them.
+		fn aggregate(feeders: &[T::AccountId]) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let max_age = T::MaxPriceAge::get();
+			let mut fresh: Vec<SubmissionOf<T>> = feeders
+				.iter()
+				.filter_map(|f| Self::submissions(f))
+				.filter(|s| s.submitted_at.saturating_add(max_age) > now)
+				.collect();
+
+			let min = T::MinSubmissions::get().max(1) as usize;
+			if fresh.len() < min {
+				Price::<T>::kill();
+				return
+			}
+
+			// price is valid as long as `min` newest submissions are fresh
+			fresh.sort_by(|a, b| b.submitted_at.cmp(&a.submitted_at));
+			let valid_until = fresh[min - 1].submitted_at.saturating_add(max_age);
+
+			let mut prices: Vec<FixedU128> = fresh.into_iter().map(|s| s.price).collect();
+			prices.sort();
+			let mid = prices.len() / 2;
+			let price = if prices.len() % 2 == 0 {
+				prices[mid - 1]
+					.saturating_add(prices[mid])
+					.saturating_mul(FixedU128::saturating_from_rational(1, 2))
+			} else {
+				prices[mid]
+			};
+
+			Price::<T>::put(AggregatedPrice { price, valid_until });
+			Self::deposit_event(Event::PriceUpdated { price, valid_until });
+		}
+	}
+
+	impl<T: Config, B: FixedPointOperand> ConversionToAssetBalance<B, T::AssetId, B> for Pallet<T> {
+		type Error = DispatchError;
+
+		/// Converts LLD to LLM at current price. Fails for other assets and
+		/// if price isn't valid.
+		fn to_asset_balance(balance: B, asset_id: T::AssetId) -> Result<B, DispatchError> {
+			if asset_id != T::LLMAssetId::get() {
+				return Err(DispatchError::Other("Price of asset unknown"))
+			}
+			Self::lld_to_llm(balance).ok_or(DispatchError::Other("LLM price unavailable"))
+		}
+	}
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::{mock::*, AggregatedPrice, Error, Event, FeedersOf};
+use frame_support::{assert_noop, assert_ok, traits::tokens::ConversionToAssetBalance};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+
+fn feeders(f: &[u64]) -> FeedersOf<Test> {
+	f.to_vec().try_into().unwrap()
+}
+
+fn price(p: u128) -> FixedU128 {
+	FixedU128::saturating_from_integer(p)
+}
+
+fn submit(feeder: u64, p: u128) {
+	assert_ok!(PriceOracle::submit_price(RuntimeOrigin::signed(feeder), price(p)));
+}
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+}
+
+fn setup() {
+	assert_ok!(PriceOracle::set_feeders(RuntimeOrigin::signed(1), feeders(&[2, 3, 4, 5])));
+}
+
+#[test]
+fn set_feeders_requires_feeder_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PriceOracle::set_feeders(RuntimeOrigin::signed(2), feeders(&[2])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PriceOracle::set_feeders(RuntimeOrigin::signed(1), feeders(&[2, 2])),
+			Error::<Test>::DuplicateFeeder
+		);
+		setup();
+		assert_eq!(PriceOracle::feeders(), feeders(&[2, 3, 4, 5]));
+		System::assert_last_event(Event::FeedersSet { feeders: feeders(&[2, 3, 4, 5]) }.into());
+	});
+}
+
+#[test]
+fn only_feeders_submit_prices() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_noop!(
+			PriceOracle::submit_price(RuntimeOrigin::signed(1), price(1)),
+			Error::<Test>::NotFeeder
+		);
+		assert_noop!(
+			PriceOracle::submit_price(RuntimeOrigin::signed(2), price(0)),
+			Error::<Test>::ZeroPrice
+		);
+		submit(2, 5);
+		assert_eq!(PriceOracle::submissions(2).unwrap().price, price(5));
+		System::assert_last_event(Event::PriceSubmitted { feeder: 2, price: price(5) }.into());
+	});
+}
+
+#[test]
+fn price_requires_min_submissions() {
+	new_test_ext().execute_with(|| {
+		setup();
+		submit(2, 5);
+		submit(3, 7);
+		assert_eq!(PriceOracle::price(), None);
+		submit(4, 6);
+		assert_eq!(PriceOracle::price(), Some(price(6)));
+		System::assert_last_event(Event::PriceUpdated { price: price(6), valid_until: 11 }.into());
+	});
+}
+
+#[test]
+fn price_is_median() {
+	new_test_ext().execute_with(|| {
+		setup();
+		submit(2, 5);
+		submit(3, 100);
+		submit(4, 6);
+		assert_eq!(PriceOracle::price(), Some(price(6)));
+		submit(5, 8);
+		assert_eq!(PriceOracle::price(), Some(price(7)));
+		// resubmission replaces previous price
+		submit(3, 1);
+		assert_eq!(PriceOracle::price(), Some(FixedU128::from_rational(11, 2)));
+	});
+}
+
+#[test]
+fn stale_submissions_are_ignored() {
+	new_test_ext().execute_with(|| {
+		setup();
+		submit(2, 5);
+		run_to_block(5);
+		submit(3, 7);
+		submit(4, 6);
+		// valid until submission of 2 is stale
+		assert_eq!(
+			PriceOracle::aggregated_price(),
+			Some(AggregatedPrice { price: price(6), valid_until: 11 })
+		);
+		run_to_block(10);
+		assert_eq!(PriceOracle::price(), Some(price(6)));
+		run_to_block(11);
+		assert_eq!(PriceOracle::price(), None);
+
+		// stale submission isn't counted
+		submit(5, 100);
+		assert_eq!(PriceOracle::price(), Some(price(7)));
+		assert_eq!(PriceOracle::aggregated_price().unwrap().valid_until, 15);
+	});
+}
+
+#[test]
+fn removed_feeders_submissions_are_dropped() {
+	new_test_ext().execute_with(|| {
+		setup();
+		submit(2, 5);
+		submit(3, 7);
+		submit(4, 6);
+		assert_ok!(PriceOracle::set_feeders(RuntimeOrigin::signed(1), feeders(&[3, 4, 5])));
+		assert!(PriceOracle::submissions(2).is_none());
+		assert_eq!(PriceOracle::price(), None);
+		submit(5, 9);
+		assert_eq!(PriceOracle::price(), Some(price(7)));
+	});
+}
+
+#[test]
+fn converts_lld_to_llm() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert!(PriceOracle::to_asset_balance(100u64, 1).is_err());
+		submit(2, 4);
+		submit(3, 4);
+		submit(4, 4);
+		assert_eq!(PriceOracle::to_asset_balance(100u64, 1), Ok(25));
+		assert_eq!(PriceOracle::llm_to_lld(25u64), Some(100));
+		assert!(PriceOracle::to_asset_balance(100u64, 2).is_err());
+		run_to_block(11);
+		assert!(PriceOracle::to_asset_balance(100u64, 1).is_err());
+	});
+}
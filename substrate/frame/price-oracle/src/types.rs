@@ -0,0 +1,22 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+/// Latest price submitted by a feeder
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Submission<BlockNumber> {
+	/// Amount of LLD worth 1 LLM
+	pub price: FixedU128,
+	/// Block the price was submitted at
+	pub submitted_at: BlockNumber,
+}
+
+/// Median of fresh submissions
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct AggregatedPrice<BlockNumber> {
+	/// Amount of LLD worth 1 LLM
+	pub price: FixedU128,
+	/// First block at which fewer than `MinSubmissions` submissions are
+	/// fresh
+	pub valid_until: BlockNumber,
+}
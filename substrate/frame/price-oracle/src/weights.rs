@@ -0,0 +1,63 @@
+//! Weights for pallet_price_oracle
+//!
+//! NOT BENCHMARKED. Estimated from pallet_sanctions and pallet_court weights of
+//! comparable operations. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_price_oracle` run
+//! on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_price_oracle.
+pub trait WeightInfo {
+	fn set_feeders(f: u32, ) -> Weight;
+	fn submit_price(f: u32, ) -> Weight;
+}
+
+/// Weights for pallet_price_oracle using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn set_feeders(f: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(f.into()))
+	}
+	fn submit_price(f: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 1489)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(f.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_feeders(f: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(f.into()))
+	}
+	fn submit_price(f: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 1489)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(f.into()))
+	}
+}
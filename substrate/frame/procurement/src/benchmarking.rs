@@ -0,0 +1,178 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Procurement;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, Hash};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const SALT: [u8; 32] = [1u8; 32];
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let acc: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&acc, BalanceOf::<T>::max_value() / 4u32.into());
+	acc
+}
+
+fn publish<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	let origin =
+		T::PublishOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	assert_ok!(Procurement::<T>::publish_tender(
+		origin.clone(),
+		T::Hashing::hash_of(&0u32),
+		BalanceOf::<T>::max_value(),
+		10u32.into(),
+		10u32.into(),
+	));
+	Ok(origin)
+}
+
+fn committed<T: Config>(amount: BalanceOf<T>) -> T::AccountId {
+	let vendor = funded::<T>("vendor", 0);
+	let commitment =
+		Procurement::<T>::commitment_of(&vendor, amount, T::Hashing::hash_of(&1u32), SALT);
+	assert_ok!(Procurement::<T>::commit_bid(
+		RawOrigin::Signed(vendor.clone()).into(),
+		0,
+		commitment
+	));
+	vendor
+}
+
+fn evaluated<T: Config>(origin: T::RuntimeOrigin, amount: BalanceOf<T>) -> T::AccountId {
+	let vendor = committed::<T>(amount);
+	frame_system::Pallet::<T>::set_block_number(12u32.into());
+	assert_ok!(Procurement::<T>::reveal_bid(
+		RawOrigin::Signed(vendor.clone()).into(),
+		0,
+		amount,
+		T::Hashing::hash_of(&1u32),
+		SALT,
+	));
+	frame_system::Pallet::<T>::set_block_number(22u32.into());
+	assert_ok!(Procurement::<T>::record_evaluation(
+		origin,
+		0,
+		vendor.clone(),
+		1,
+		T::Hashing::hash_of(&2u32),
+	));
+	vendor
+}
+
+fn milestones<T: Config>(m: u32, amount: BalanceOf<T>) -> MilestonesOf<T> {
+	let part = amount / m.into();
+	(0..m)
+		.map(|_| (part, BlockNumberFor::<T>::from(30u32)))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+benchmarks! {
+	publish_tender {
+		let origin =
+			T::PublishOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, T::Hashing::hash_of(&0u32), 1000u32.into(), 10u32.into(), 10u32.into())
+	verify {
+		assert!(Tenders::<T>::contains_key(0));
+	}
+
+	commit_bid {
+		publish::<T>()?;
+		let vendor = funded::<T>("vendor", 0);
+	}: _(RawOrigin::Signed(vendor.clone()), 0, T::Hashing::hash_of(&0u32))
+	verify {
+		assert!(Bids::<T>::contains_key(0, vendor));
+	}
+
+	reveal_bid {
+		publish::<T>()?;
+		let amount: BalanceOf<T> = 1000u32.into();
+		let vendor = committed::<T>(amount);
+		frame_system::Pallet::<T>::set_block_number(12u32.into());
+	}: _(RawOrigin::Signed(vendor.clone()), 0, amount, T::Hashing::hash_of(&1u32), SALT)
+	verify {
+		assert!(Procurement::<T>::bids(0, vendor).unwrap().revealed.is_some());
+	}
+
+	record_evaluation {
+		let origin = publish::<T>()?;
+		let amount: BalanceOf<T> = 1000u32.into();
+		let vendor = committed::<T>(amount);
+		frame_system::Pallet::<T>::set_block_number(12u32.into());
+		assert_ok!(Procurement::<T>::reveal_bid(
+			RawOrigin::Signed(vendor.clone()).into(),
+			0,
+			amount,
+			T::Hashing::hash_of(&1u32),
+			SALT,
+		));
+		frame_system::Pallet::<T>::set_block_number(22u32.into());
+	}: _<T::RuntimeOrigin>(origin, 0, vendor.clone(), 1, T::Hashing::hash_of(&2u32))
+	verify {
+		assert!(Evaluations::<T>::contains_key(0, vendor));
+	}
+
+	award_tender {
+		let m in 1 .. T::MaxMilestones::get();
+		let origin = publish::<T>()?;
+		let amount: BalanceOf<T> = (1000u32 * m).into();
+		let vendor = evaluated::<T>(origin.clone(), amount);
+		T::Currency::make_free_balance_be(
+			&T::TreasuryAccount::get(),
+			BalanceOf::<T>::max_value() / 4u32.into(),
+		);
+		let milestones = milestones::<T>(m, amount);
+	}: _<T::RuntimeOrigin>(origin, 0, vendor, milestones)
+	verify {
+		assert!(Awards::<T>::contains_key(0));
+	}
+
+	release_milestone {
+		let origin = publish::<T>()?;
+		let amount: BalanceOf<T> = 2000u32.into();
+		let vendor = evaluated::<T>(origin.clone(), amount);
+		T::Currency::make_free_balance_be(
+			&T::TreasuryAccount::get(),
+			BalanceOf::<T>::max_value() / 4u32.into(),
+		);
+		assert_ok!(Procurement::<T>::award_tender(origin.clone(), 0, vendor, milestones::<T>(2, amount)));
+		frame_system::Pallet::<T>::set_block_number(30u32.into());
+	}: _<T::RuntimeOrigin>(origin, 0, 0)
+	verify {
+		assert!(Procurement::<T>::awards(0).unwrap().milestones[0].paid);
+	}
+
+	cancel_tender {
+		let origin = publish::<T>()?;
+		let amount: BalanceOf<T> = 2000u32.into();
+		let vendor = evaluated::<T>(origin.clone(), amount);
+		T::Currency::make_free_balance_be(
+			&T::TreasuryAccount::get(),
+			BalanceOf::<T>::max_value() / 4u32.into(),
+		);
+		assert_ok!(Procurement::<T>::award_tender(origin.clone(), 0, vendor, milestones::<T>(2, amount)));
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert_eq!(Procurement::<T>::tenders(0).unwrap().status, TenderStatus::Cancelled);
+	}
+
+	slash_unrevealed_bid {
+		publish::<T>()?;
+		let vendor = committed::<T>(1000u32.into());
+		let caller = funded::<T>("caller", 0);
+		frame_system::Pallet::<T>::set_block_number(22u32.into());
+	}: _(RawOrigin::Signed(caller), 0, vendor.clone())
+	verify {
+		assert!(!Bids::<T>::contains_key(0, vendor));
+	}
+}
+
+impl_benchmark_test_suite!(Procurement, crate::mock::new_test_ext(), crate::mock::Test,);
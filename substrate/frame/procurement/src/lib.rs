@@ -0,0 +1,639 @@
+//! # Liberland Procurement Pallet
+//!
+//! ## Overview
+//!
+//! Procurement pallet moves government purchasing on-chain. Offices publish
+//! tenders, vendors submit sealed bids using a commit-reveal scheme, the
+//! publisher records evaluation of revealed bids and awards the tender. Award
+//! moves the awarded amount from treasury to this pallet's escrow account,
+//! from which it's paid out to the vendor milestone by milestone.
+//!
+//! ## Terminology
+//!
+//! * Tender - request for offers with a spec hash, budget cap and deadlines
+//! * Publisher - office (account) that published the tender
+//! * Commitment - `hash(vendor, amount, proposal_hash, salt)` submitted before
+//!   `bid_deadline`
+//! * Reveal - disclosure of `amount`, `proposal_hash` and `salt` between
+//!   `bid_deadline` and `reveal_deadline`
+//! * Milestone - part of the awarded amount, releasable after its due block
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency used for bid deposits and payouts
+//! * `PublishOrigin` - origin that can publish tenders - must return AccountId
+//!   on success
+//! * `TreasuryAccount` - account from which awarded tenders are funded
+//! * `PalletId` - PalletId used to derive escrow account
+//! * `BidDeposit` - deposit reserved on bid commitment, returned on reveal
+//! * `ReserveIdentifier` - identifier of named reserves
+//! * `MaxMilestones` - maximum number of milestones per award
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `publish_tender`: Publish new tender
+//! * `commit_bid`: Submit sealed bid
+//! * `reveal_bid`: Reveal sealed bid
+//! * `record_evaluation`: Record evaluation of a revealed bid
+//! * `award_tender`: Award tender to evaluated vendor and fund escrow
+//! * `release_milestone`: Pay out milestone to vendor
+//! * `cancel_tender`: Cancel tender and return unpaid funds to treasury
+//! * `slash_unrevealed_bid`: Slash deposit of a bid that was never revealed
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, NamedReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, Hash, Saturating, Zero},
+		ArithmeticError,
+	};
+	use sp_std::prelude::*;
+
+	type ReserveIdentifierOf<T> = <<T as Config>::Currency as NamedReservableCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::ReserveIdentifier;
+
+	pub type TenderOf<T> = Tender<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as frame_system::Config>::Hash,
+	>;
+	pub type BidOf<T> = Bid<BalanceOf<T>, <T as frame_system::Config>::Hash>;
+	pub type MilestonesOf<T> =
+		BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), <T as Config>::MaxMilestones>;
+	pub type AwardOf<T> = Award<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxMilestones,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for bid deposits and payouts
+		type Currency: NamedReservableCurrency<Self::AccountId>;
+
+		/// Origin that can publish tenders - must return AccountId on success
+		type PublishOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// Account that funds awarded tenders
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// PalletId used to derive escrow account
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Deposit reserved on bid commitment
+		#[pallet::constant]
+		type BidDeposit: Get<BalanceOf<Self>>;
+
+		/// Identifies reserves in Currency
+		#[pallet::constant]
+		type ReserveIdentifier: Get<&'static ReserveIdentifierOf<Self>>;
+
+		/// Maximum number of milestones per award
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Tender doesn't exist
+		TenderNotFound,
+		/// Bid doesn't exist
+		BidNotFound,
+		/// Vendor already submitted a bid for this tender
+		AlreadyBid,
+		/// Caller isn't the publisher of this tender
+		NotPublisher,
+		/// Action not allowed in current tender status
+		InvalidStatus,
+		/// Action not allowed at this point of tender's timeline
+		InvalidPhase,
+		/// Revealed data doesn't match commitment
+		InvalidReveal,
+		/// Bid was already revealed
+		AlreadyRevealed,
+		/// Bid wasn't revealed
+		NotRevealed,
+		/// Bid wasn't evaluated
+		NotEvaluated,
+		/// Bid exceeds tender's budget cap
+		OverBudget,
+		/// Milestones don't sum up to awarded amount
+		InvalidMilestones,
+		/// Milestone doesn't exist
+		MilestoneNotFound,
+		/// Milestone was already paid
+		MilestoneAlreadyPaid,
+		/// Milestone isn't due yet
+		MilestoneNotDue,
+		/// Invalid tender parameters
+		InvalidParameters,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New tender published
+		TenderPublished {
+			tender_id: TenderIndex,
+			publisher: T::AccountId,
+			spec_hash: T::Hash,
+			budget_cap: BalanceOf<T>,
+		},
+		/// Sealed bid submitted
+		BidCommitted { tender_id: TenderIndex, vendor: T::AccountId },
+		/// Bid revealed
+		BidRevealed { tender_id: TenderIndex, vendor: T::AccountId, amount: BalanceOf<T> },
+		/// Evaluation of bid recorded
+		BidEvaluated {
+			tender_id: TenderIndex,
+			vendor: T::AccountId,
+			score: u32,
+			report_hash: T::Hash,
+		},
+		/// Deposit of unrevealed bid slashed
+		BidSlashed { tender_id: TenderIndex, vendor: T::AccountId, amount: BalanceOf<T> },
+		/// Tender awarded and escrow funded from treasury
+		TenderAwarded { tender_id: TenderIndex, vendor: T::AccountId, amount: BalanceOf<T> },
+		/// Milestone paid out to vendor
+		MilestoneReleased { tender_id: TenderIndex, index: u32, amount: BalanceOf<T> },
+		/// All milestones paid out
+		TenderCompleted { tender_id: TenderIndex },
+		/// Tender cancelled, unpaid funds returned to treasury
+		TenderCancelled { tender_id: TenderIndex, refunded: BalanceOf<T> },
+	}
+
+	/// Tenders
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn tenders)]
+	pub type Tenders<T: Config> =
+		StorageMap<_, Twox64Concat, TenderIndex, TenderOf<T>, OptionQuery>;
+
+	/// Bids per tender and vendor
+	#[pallet::storage]
+	#[pallet::getter(fn bids)]
+	pub type Bids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TenderIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		BidOf<T>,
+		OptionQuery,
+	>;
+
+	/// Evaluations of revealed bids
+	#[pallet::storage]
+	#[pallet::getter(fn evaluations)]
+	pub type Evaluations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TenderIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		Evaluation<T::Hash>,
+		OptionQuery,
+	>;
+
+	/// Awards of tenders
+	#[pallet::storage]
+	#[pallet::getter(fn awards)]
+	pub type Awards<T: Config> = StorageMap<_, Twox64Concat, TenderIndex, AwardOf<T>, OptionQuery>;
+
+	/// Index of the next tender
+	#[pallet::storage]
+	#[pallet::getter(fn next_tender_id)]
+	pub type NextTenderId<T: Config> = StorageValue<_, TenderIndex, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Publish a new tender.
+		///
+		/// * `spec_hash` - hash of the specification document
+		/// * `budget_cap` - maximum amount that can be awarded
+		/// * `bid_period` - number of blocks during which bids can be committed
+		/// * `reveal_period` - number of blocks after `bid_period` during which
+		///   bids can be revealed
+		///
+		/// Emits `TenderPublished`.
+		///
+		/// Must be called by `PublishOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::publish_tender())]
+		pub fn publish_tender(
+			origin: OriginFor<T>,
+			spec_hash: T::Hash,
+			budget_cap: BalanceOf<T>,
+			bid_period: BlockNumberFor<T>,
+			reveal_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let publisher = T::PublishOrigin::ensure_origin(origin)?;
+			ensure!(
+				!bid_period.is_zero() && !reveal_period.is_zero() && !budget_cap.is_zero(),
+				Error::<T>::InvalidParameters
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let bid_deadline = now.saturating_add(bid_period);
+			let reveal_deadline = bid_deadline.saturating_add(reveal_period);
+
+			let tender_id = Self::next_tender_id();
+			NextTenderId::<T>::put(tender_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			Tenders::<T>::insert(
+				tender_id,
+				Tender {
+					publisher: publisher.clone(),
+					spec_hash,
+					budget_cap,
+					bid_deadline,
+					reveal_deadline,
+					status: TenderStatus::Open,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::TenderPublished {
+				tender_id,
+				publisher,
+				spec_hash,
+				budget_cap,
+			});
+			Ok(())
+		}
+
+		/// Submit a sealed bid. Reserves `BidDeposit`.
+		///
+		/// * `tender_id` - tender to bid on
+		/// * `commitment` - `hash(vendor, amount, proposal_hash, salt)`
+		///
+		/// Emits `BidCommitted`.
+		///
+		/// Must be called before tender's `bid_deadline`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::commit_bid())]
+		pub fn commit_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let vendor = ensure_signed(origin)?;
+			let tender = Self::tenders(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::InvalidStatus);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= tender.bid_deadline, Error::<T>::InvalidPhase);
+			ensure!(!Bids::<T>::contains_key(tender_id, &vendor), Error::<T>::AlreadyBid);
+
+			let deposit = T::BidDeposit::get();
+			T::Currency::reserve_named(T::ReserveIdentifier::get(), &vendor, deposit)?;
+			Bids::<T>::insert(tender_id, &vendor, Bid { commitment, deposit, revealed: None });
+
+			Self::deposit_event(Event::<T>::BidCommitted { tender_id, vendor });
+			Ok(())
+		}
+
+		/// Reveal a sealed bid. Unreserves the bid deposit.
+		///
+		/// * `tender_id` - tender the bid was submitted for
+		/// * `amount` - offered price
+		/// * `proposal_hash` - hash of the offer document
+		/// * `salt` - salt used when computing commitment
+		///
+		/// Emits `BidRevealed`.
+		///
+		/// Must be called after tender's `bid_deadline` and before its
+		/// `reveal_deadline`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::reveal_bid())]
+		pub fn reveal_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			amount: BalanceOf<T>,
+			proposal_hash: T::Hash,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let vendor = ensure_signed(origin)?;
+			let tender = Self::tenders(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::InvalidStatus);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now > tender.bid_deadline && now <= tender.reveal_deadline,
+				Error::<T>::InvalidPhase
+			);
+
+			let mut bid = Self::bids(tender_id, &vendor).ok_or(Error::<T>::BidNotFound)?;
+			ensure!(bid.revealed.is_none(), Error::<T>::AlreadyRevealed);
+			ensure!(
+				Self::commitment_of(&vendor, amount, proposal_hash, salt) == bid.commitment,
+				Error::<T>::InvalidReveal
+			);
+			ensure!(amount <= tender.budget_cap, Error::<T>::OverBudget);
+
+			T::Currency::unreserve_named(T::ReserveIdentifier::get(), &vendor, bid.deposit);
+			bid.deposit = Zero::zero();
+			bid.revealed = Some(RevealedBid { amount, proposal_hash });
+			Bids::<T>::insert(tender_id, &vendor, bid);
+
+			Self::deposit_event(Event::<T>::BidRevealed { tender_id, vendor, amount });
+			Ok(())
+		}
+
+		/// Record evaluation of a revealed bid. Can be called multiple times to
+		/// update the evaluation.
+		///
+		/// * `tender_id` - evaluated tender
+		/// * `vendor` - vendor whose bid was evaluated
+		/// * `score` - score given to the bid
+		/// * `report_hash` - hash of the evaluation report
+		///
+		/// Emits `BidEvaluated`.
+		///
+		/// Must be called by tender's publisher after `reveal_deadline`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::record_evaluation())]
+		pub fn record_evaluation(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			vendor: T::AccountId,
+			score: u32,
+			report_hash: T::Hash,
+		) -> DispatchResult {
+			let tender = Self::ensure_publisher(origin, tender_id)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::InvalidStatus);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > tender.reveal_deadline, Error::<T>::InvalidPhase);
+			let bid = Self::bids(tender_id, &vendor).ok_or(Error::<T>::BidNotFound)?;
+			ensure!(bid.revealed.is_some(), Error::<T>::NotRevealed);
+
+			Evaluations::<T>::insert(tender_id, &vendor, Evaluation { score, report_hash });
+			Self::deposit_event(Event::<T>::BidEvaluated { tender_id, vendor, score, report_hash });
+			Ok(())
+		}
+
+		/// Award tender to vendor. Transfers bid amount from treasury to
+		/// escrow and schedules milestones.
+		///
+		/// * `tender_id` - tender to award
+		/// * `vendor` - vendor whose evaluated bid wins
+		/// * `milestones` - `(amount, due block)` pairs, amounts must sum up to
+		///   the bid amount
+		///
+		/// Emits `TenderAwarded`.
+		///
+		/// Must be called by tender's publisher after `reveal_deadline`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::award_tender(milestones.len() as u32))]
+		pub fn award_tender(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			vendor: T::AccountId,
+			milestones: MilestonesOf<T>,
+		) -> DispatchResult {
+			let mut tender = Self::ensure_publisher(origin, tender_id)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::InvalidStatus);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > tender.reveal_deadline, Error::<T>::InvalidPhase);
+
+			let bid = Self::bids(tender_id, &vendor).ok_or(Error::<T>::BidNotFound)?;
+			let revealed = bid.revealed.ok_or(Error::<T>::NotRevealed)?;
+			ensure!(Evaluations::<T>::contains_key(tender_id, &vendor), Error::<T>::NotEvaluated);
+
+			let total = milestones
+				.iter()
+				.try_fold(BalanceOf::<T>::zero(), |acc, (amount, _)| acc.checked_add(amount))
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				!milestones.is_empty() && total == revealed.amount,
+				Error::<T>::InvalidMilestones
+			);
+
+			T::Currency::transfer(
+				&T::TreasuryAccount::get(),
+				&Self::escrow_account(),
+				revealed.amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let milestones: Vec<_> = milestones
+				.into_iter()
+				.map(|(amount, due)| Milestone { amount, due, paid: false })
+				.collect();
+			Awards::<T>::insert(
+				tender_id,
+				Award {
+					vendor: vendor.clone(),
+					amount: revealed.amount,
+					// can't fail - same length as input
+					milestones: milestones.try_into().map_err(|_| Error::<T>::InvalidMilestones)?,
+				},
+			);
+			tender.status = TenderStatus::Awarded;
+			Tenders::<T>::insert(tender_id, tender);
+
+			Self::deposit_event(Event::<T>::TenderAwarded {
+				tender_id,
+				vendor,
+				amount: revealed.amount,
+			});
+			Ok(())
+		}
+
+		/// Pay out milestone from escrow to awarded vendor.
+		///
+		/// * `tender_id` - awarded tender
+		/// * `index` - index of milestone
+		///
+		/// Emits `MilestoneReleased` and, after last milestone,
+		/// `TenderCompleted`.
+		///
+		/// Must be called by tender's publisher after milestone's due block.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::release_milestone())]
+		pub fn release_milestone(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			index: u32,
+		) -> DispatchResult {
+			let mut tender = Self::ensure_publisher(origin, tender_id)?;
+			ensure!(tender.status == TenderStatus::Awarded, Error::<T>::InvalidStatus);
+			let mut award = Self::awards(tender_id).ok_or(Error::<T>::InvalidStatus)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let milestone =
+				award.milestones.get_mut(index as usize).ok_or(Error::<T>::MilestoneNotFound)?;
+			ensure!(!milestone.paid, Error::<T>::MilestoneAlreadyPaid);
+			ensure!(now >= milestone.due, Error::<T>::MilestoneNotDue);
+
+			T::Currency::transfer(
+				&Self::escrow_account(),
+				&award.vendor,
+				milestone.amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			milestone.paid = true;
+			let amount = milestone.amount;
+
+			let completed = award.milestones.iter().all(|m| m.paid);
+			Awards::<T>::insert(tender_id, award);
+			Self::deposit_event(Event::<T>::MilestoneReleased { tender_id, index, amount });
+
+			if completed {
+				tender.status = TenderStatus::Completed;
+				Tenders::<T>::insert(tender_id, tender);
+				Self::deposit_event(Event::<T>::TenderCompleted { tender_id });
+			}
+			Ok(())
+		}
+
+		/// Cancel tender. If it was already awarded, unpaid milestones are
+		/// returned from escrow to treasury.
+		///
+		/// * `tender_id` - tender to cancel
+		///
+		/// Emits `TenderCancelled`.
+		///
+		/// Must be called by tender's publisher.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_tender())]
+		pub fn cancel_tender(origin: OriginFor<T>, tender_id: TenderIndex) -> DispatchResult {
+			let mut tender = Self::ensure_publisher(origin, tender_id)?;
+			let refunded = match tender.status {
+				TenderStatus::Open => Zero::zero(),
+				TenderStatus::Awarded => {
+					let award = Self::awards(tender_id).ok_or(Error::<T>::InvalidStatus)?;
+					let unpaid = award
+						.milestones
+						.iter()
+						.filter(|m| !m.paid)
+						.fold(BalanceOf::<T>::zero(), |acc, m| acc.saturating_add(m.amount));
+					T::Currency::transfer(
+						&Self::escrow_account(),
+						&T::TreasuryAccount::get(),
+						unpaid,
+						ExistenceRequirement::AllowDeath,
+					)?;
+					unpaid
+				},
+				_ => return Err(Error::<T>::InvalidStatus.into()),
+			};
+
+			tender.status = TenderStatus::Cancelled;
+			Tenders::<T>::insert(tender_id, tender);
+			Self::deposit_event(Event::<T>::TenderCancelled { tender_id, refunded });
+			Ok(())
+		}
+
+		/// Slash deposit of a bid that wasn't revealed before
+		/// `reveal_deadline`. Slashed funds go to treasury.
+		///
+		/// * `tender_id` - tender the bid was submitted for
+		/// * `vendor` - vendor that submitted the bid
+		///
+		/// Emits `BidSlashed`.
+		///
+		/// Can be called by anyone.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::slash_unrevealed_bid())]
+		pub fn slash_unrevealed_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+			vendor: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let tender = Self::tenders(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now > tender.reveal_deadline || tender.status == TenderStatus::Cancelled,
+				Error::<T>::InvalidPhase
+			);
+			let bid = Self::bids(tender_id, &vendor).ok_or(Error::<T>::BidNotFound)?;
+			ensure!(bid.revealed.is_none(), Error::<T>::AlreadyRevealed);
+
+			let (imbalance, _) = T::Currency::slash_reserved_named(
+				T::ReserveIdentifier::get(),
+				&vendor,
+				bid.deposit,
+			);
+			T::Currency::resolve_creating(&T::TreasuryAccount::get(), imbalance);
+			Bids::<T>::remove(tender_id, &vendor);
+
+			Self::deposit_event(Event::<T>::BidSlashed { tender_id, vendor, amount: bid.deposit });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding funds of awarded tenders
+		pub fn escrow_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Commitment that vendor has to submit in `commit_bid`
+		pub fn commitment_of(
+			vendor: &T::AccountId,
+			amount: BalanceOf<T>,
+			proposal_hash: T::Hash,
+			salt: [u8; 32],
+		) -> T::Hash {
+			T::Hashing::hash_of(&(vendor, amount, proposal_hash, salt))
+		}
+
+		fn ensure_publisher(
+			origin: OriginFor<T>,
+			tender_id: TenderIndex,
+		) -> Result<TenderOf<T>, DispatchError> {
+			let caller = T::PublishOrigin::ensure_origin(origin)?;
+			let tender = Self::tenders(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.publisher == caller, Error::<T>::NotPublisher);
+			Ok(tender)
+		}
+	}
+}
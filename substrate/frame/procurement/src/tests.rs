@@ -0,0 +1,334 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, MilestonesOf, TenderStatus};
+use frame_support::{assert_noop, assert_ok, traits::NamedReservableCurrency};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+const SALT: [u8; 32] = [7u8; 32];
+
+fn spec() -> H256 {
+	H256::repeat_byte(1)
+}
+
+fn offer() -> H256 {
+	H256::repeat_byte(2)
+}
+
+fn report() -> H256 {
+	H256::repeat_byte(3)
+}
+
+fn publish() {
+	assert_ok!(Procurement::publish_tender(RuntimeOrigin::signed(1), spec(), 500, 10, 10));
+}
+
+fn commit(vendor: u64, amount: u64) {
+	let commitment = Procurement::commitment_of(&vendor, amount, offer(), SALT);
+	assert_ok!(Procurement::commit_bid(RuntimeOrigin::signed(vendor), 0, commitment));
+}
+
+fn reveal(vendor: u64, amount: u64) {
+	assert_ok!(Procurement::reveal_bid(RuntimeOrigin::signed(vendor), 0, amount, offer(), SALT));
+}
+
+fn milestones(m: Vec<(u64, u64)>) -> MilestonesOf<Test> {
+	m.try_into().unwrap()
+}
+
+fn setup_awarded(amount: u64) {
+	publish();
+	commit(2, amount);
+	System::set_block_number(12);
+	reveal(2, amount);
+	System::set_block_number(22);
+	assert_ok!(Procurement::record_evaluation(RuntimeOrigin::signed(1), 0, 2, 80, report()));
+	assert_ok!(Procurement::award_tender(
+		RuntimeOrigin::signed(1),
+		0,
+		2,
+		milestones(vec![(amount / 2, 30), (amount - amount / 2, 40)])
+	));
+}
+
+#[test]
+fn publish_tender_works() {
+	new_test_ext().execute_with(|| {
+		publish();
+		let tender = Procurement::tenders(0).unwrap();
+		assert_eq!(tender.publisher, 1);
+		assert_eq!(tender.budget_cap, 500);
+		assert_eq!(tender.bid_deadline, 11);
+		assert_eq!(tender.reveal_deadline, 21);
+		assert_eq!(tender.status, TenderStatus::Open);
+		assert_eq!(Procurement::next_tender_id(), 1);
+		System::assert_last_event(
+			Event::<Test>::TenderPublished {
+				tender_id: 0,
+				publisher: 1,
+				spec_hash: spec(),
+				budget_cap: 500,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn publish_tender_verifies_origin_and_params() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Procurement::publish_tender(RuntimeOrigin::signed(2), spec(), 500, 10, 10),
+			BadOrigin
+		);
+		assert_noop!(
+			Procurement::publish_tender(RuntimeOrigin::signed(1), spec(), 500, 0, 10),
+			Error::<Test>::InvalidParameters
+		);
+		assert_noop!(
+			Procurement::publish_tender(RuntimeOrigin::signed(1), spec(), 0, 10, 10),
+			Error::<Test>::InvalidParameters
+		);
+	});
+}
+
+#[test]
+fn commit_bid_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		assert_eq!(Balances::reserved_balance_named(b"procurem", &2), 10);
+		System::assert_last_event(Event::<Test>::BidCommitted { tender_id: 0, vendor: 2 }.into());
+		assert_noop!(
+			Procurement::commit_bid(RuntimeOrigin::signed(2), 0, H256::zero()),
+			Error::<Test>::AlreadyBid
+		);
+	});
+}
+
+#[test]
+fn commit_bid_fails_after_deadline_or_without_funds() {
+	new_test_ext().execute_with(|| {
+		publish();
+		assert_noop!(
+			Procurement::commit_bid(RuntimeOrigin::signed(4), 0, H256::zero()),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			Procurement::commit_bid(RuntimeOrigin::signed(2), 1, H256::zero()),
+			Error::<Test>::TenderNotFound
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Procurement::commit_bid(RuntimeOrigin::signed(2), 0, H256::zero()),
+			Error::<Test>::InvalidPhase
+		);
+	});
+}
+
+#[test]
+fn reveal_bid_works() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		System::set_block_number(12);
+		reveal(2, 100);
+		assert_eq!(Balances::reserved_balance_named(b"procurem", &2), 0);
+		let bid = Procurement::bids(0, 2).unwrap();
+		assert_eq!(bid.revealed.unwrap().amount, 100);
+		System::assert_last_event(
+			Event::<Test>::BidRevealed { tender_id: 0, vendor: 2, amount: 100 }.into(),
+		);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(2), 0, 100, offer(), SALT),
+			Error::<Test>::AlreadyRevealed
+		);
+	});
+}
+
+#[test]
+fn reveal_bid_checks_phase_commitment_and_budget() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		commit(3, 600);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(2), 0, 100, offer(), SALT),
+			Error::<Test>::InvalidPhase
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(2), 0, 99, offer(), SALT),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(2), 0, 100, offer(), [0u8; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(3), 0, 600, offer(), SALT),
+			Error::<Test>::OverBudget
+		);
+		System::set_block_number(22);
+		assert_noop!(
+			Procurement::reveal_bid(RuntimeOrigin::signed(2), 0, 100, offer(), SALT),
+			Error::<Test>::InvalidPhase
+		);
+	});
+}
+
+#[test]
+fn record_evaluation_works() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		commit(3, 100);
+		System::set_block_number(12);
+		reveal(2, 100);
+		assert_noop!(
+			Procurement::record_evaluation(RuntimeOrigin::signed(1), 0, 2, 80, report()),
+			Error::<Test>::InvalidPhase
+		);
+		System::set_block_number(22);
+		assert_noop!(
+			Procurement::record_evaluation(RuntimeOrigin::signed(2), 0, 2, 80, report()),
+			BadOrigin
+		);
+		assert_noop!(
+			Procurement::record_evaluation(RuntimeOrigin::signed(1), 0, 3, 80, report()),
+			Error::<Test>::NotRevealed
+		);
+		assert_ok!(Procurement::record_evaluation(RuntimeOrigin::signed(1), 0, 2, 80, report()));
+		assert_eq!(Procurement::evaluations(0, 2).unwrap().score, 80);
+		System::assert_last_event(
+			Event::<Test>::BidEvaluated {
+				tender_id: 0,
+				vendor: 2,
+				score: 80,
+				report_hash: report(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn award_tender_funds_escrow() {
+	new_test_ext().execute_with(|| {
+		setup_awarded(100);
+		let escrow = Procurement::escrow_account();
+		assert_eq!(Balances::free_balance(escrow), 100);
+		assert_eq!(Balances::free_balance(99), 900);
+		assert_eq!(Procurement::tenders(0).unwrap().status, TenderStatus::Awarded);
+		let award = Procurement::awards(0).unwrap();
+		assert_eq!(award.vendor, 2);
+		assert_eq!(award.milestones.len(), 2);
+		System::assert_last_event(
+			Event::<Test>::TenderAwarded { tender_id: 0, vendor: 2, amount: 100 }.into(),
+		);
+	});
+}
+
+#[test]
+fn award_tender_validates_milestones_and_evaluation() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		System::set_block_number(12);
+		reveal(2, 100);
+		System::set_block_number(22);
+		assert_noop!(
+			Procurement::award_tender(RuntimeOrigin::signed(1), 0, 2, milestones(vec![(100, 30)])),
+			Error::<Test>::NotEvaluated
+		);
+		assert_ok!(Procurement::record_evaluation(RuntimeOrigin::signed(1), 0, 2, 80, report()));
+		assert_noop!(
+			Procurement::award_tender(RuntimeOrigin::signed(1), 0, 2, milestones(vec![(90, 30)])),
+			Error::<Test>::InvalidMilestones
+		);
+		assert_noop!(
+			Procurement::award_tender(RuntimeOrigin::signed(1), 0, 2, milestones(vec![])),
+			Error::<Test>::InvalidMilestones
+		);
+		assert_noop!(
+			Procurement::award_tender(RuntimeOrigin::signed(1), 0, 3, milestones(vec![(100, 30)])),
+			Error::<Test>::BidNotFound
+		);
+	});
+}
+
+#[test]
+fn release_milestone_pays_vendor_and_completes() {
+	new_test_ext().execute_with(|| {
+		setup_awarded(100);
+		assert_noop!(
+			Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::MilestoneNotDue
+		);
+		System::set_block_number(30);
+		assert_ok!(Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 0));
+		assert_eq!(Balances::free_balance(2), 150);
+		assert_noop!(
+			Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::MilestoneAlreadyPaid
+		);
+		assert_noop!(
+			Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::MilestoneNotFound
+		);
+
+		System::set_block_number(40);
+		assert_ok!(Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::free_balance(2), 200);
+		assert_eq!(Balances::free_balance(Procurement::escrow_account()), 0);
+		assert_eq!(Procurement::tenders(0).unwrap().status, TenderStatus::Completed);
+		System::assert_last_event(Event::<Test>::TenderCompleted { tender_id: 0 }.into());
+	});
+}
+
+#[test]
+fn cancel_tender_refunds_unpaid_milestones() {
+	new_test_ext().execute_with(|| {
+		setup_awarded(100);
+		System::set_block_number(30);
+		assert_ok!(Procurement::release_milestone(RuntimeOrigin::signed(1), 0, 0));
+		assert_ok!(Procurement::cancel_tender(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::free_balance(99), 950);
+		assert_eq!(Procurement::tenders(0).unwrap().status, TenderStatus::Cancelled);
+		System::assert_last_event(
+			Event::<Test>::TenderCancelled { tender_id: 0, refunded: 50 }.into(),
+		);
+		assert_noop!(
+			Procurement::cancel_tender(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::InvalidStatus
+		);
+	});
+}
+
+#[test]
+fn slash_unrevealed_bid_works() {
+	new_test_ext().execute_with(|| {
+		publish();
+		commit(2, 100);
+		commit(3, 100);
+		System::set_block_number(12);
+		reveal(3, 100);
+		assert_noop!(
+			Procurement::slash_unrevealed_bid(RuntimeOrigin::signed(4), 0, 2),
+			Error::<Test>::InvalidPhase
+		);
+		System::set_block_number(22);
+		assert_noop!(
+			Procurement::slash_unrevealed_bid(RuntimeOrigin::signed(4), 0, 3),
+			Error::<Test>::AlreadyRevealed
+		);
+		assert_ok!(Procurement::slash_unrevealed_bid(RuntimeOrigin::signed(4), 0, 2));
+		assert_eq!(Balances::reserved_balance_named(b"procurem", &2), 0);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_eq!(Balances::free_balance(99), 1010);
+		assert!(Procurement::bids(0, 2).is_none());
+		System::assert_last_event(
+			Event::<Test>::BidSlashed { tender_id: 0, vendor: 2, amount: 10 }.into(),
+		);
+	});
+}
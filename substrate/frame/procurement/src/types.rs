@@ -0,0 +1,74 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type TenderIndex = u32;
+
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum TenderStatus {
+	/// Accepting bid commitments until `bid_deadline`, reveals until
+	/// `reveal_deadline`, then waiting for evaluation and award
+	Open,
+	/// Awarded to a vendor, milestones are being paid out
+	Awarded,
+	/// All milestones paid
+	Completed,
+	/// Cancelled by publisher
+	Cancelled,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Tender<AccountId, Balance, BlockNumber, Hash> {
+	/// Office that published the tender
+	pub publisher: AccountId,
+	/// Hash of the off-chain specification document
+	pub spec_hash: Hash,
+	/// Maximum amount that can be awarded
+	pub budget_cap: Balance,
+	/// Last block at which bid commitments are accepted
+	pub bid_deadline: BlockNumber,
+	/// Last block at which bids can be revealed
+	pub reveal_deadline: BlockNumber,
+	pub status: TenderStatus,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct RevealedBid<Balance, Hash> {
+	/// Price offered by vendor
+	pub amount: Balance,
+	/// Hash of the off-chain offer document
+	pub proposal_hash: Hash,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Bid<Balance, Hash> {
+	/// `hash(vendor, amount, proposal_hash, salt)`
+	pub commitment: Hash,
+	/// Reserved until bid is revealed
+	pub deposit: Balance,
+	pub revealed: Option<RevealedBid<Balance, Hash>>,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Evaluation<Hash> {
+	pub score: u32,
+	/// Hash of the off-chain evaluation report
+	pub report_hash: Hash,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Milestone<Balance, BlockNumber> {
+	pub amount: Balance,
+	/// Milestone can't be released before this block
+	pub due: BlockNumber,
+	pub paid: bool,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxMilestones))]
+pub struct Award<AccountId, Balance, BlockNumber, MaxMilestones: Get<u32>> {
+	pub vendor: AccountId,
+	pub amount: Balance,
+	pub milestones: BoundedVec<Milestone<Balance, BlockNumber>, MaxMilestones>,
+}
@@ -0,0 +1,117 @@
+//! Weights for pallet_procurement
+//!
+//! NOT BENCHMARKED. These are estimates based on similar extrinsics of
+//! pallet_contracts_registry and pallet_office. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_procurement` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_procurement.
+pub trait WeightInfo {
+	fn publish_tender() -> Weight;
+	fn commit_bid() -> Weight;
+	fn reveal_bid() -> Weight;
+	fn record_evaluation() -> Weight;
+	fn award_tender(m: u32, ) -> Weight;
+	fn release_milestone() -> Weight;
+	fn cancel_tender() -> Weight;
+	fn slash_unrevealed_bid() -> Weight;
+}
+
+/// Weights for pallet_procurement using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn publish_tender() -> Weight {
+		Weight::from_parts(18_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn commit_bid() -> Weight {
+		Weight::from_parts(35_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(38_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn record_evaluation() -> Weight {
+		Weight::from_parts(20_000_000, 3583)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn award_tender(m: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 6196)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn release_milestone() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn cancel_tender() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn slash_unrevealed_bid() -> Weight {
+		Weight::from_parts(42_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn publish_tender() -> Weight {
+		Weight::from_parts(18_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn commit_bid() -> Weight {
+		Weight::from_parts(35_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(38_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn record_evaluation() -> Weight {
+		Weight::from_parts(20_000_000, 3583)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn award_tender(m: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 6196)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn release_milestone() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn cancel_tender() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn slash_unrevealed_bid() -> Weight {
+		Weight::from_parts(42_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
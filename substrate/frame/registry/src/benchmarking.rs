@@ -20,6 +20,33 @@ fn add_registries<T: Config<I>, I: 'static>(r: u32) -> DispatchResult {
 	Ok(())
 }
 
+/// Creates entity 0 owned by "owner" with `o` officers
+fn entity_with_officers<T: Config<I>, I: 'static>(o: u32) -> Vec<T::AccountId> {
+	let owner: T::AccountId = account("owner", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&owner, BalanceOf::<T, I>::max_value() / 2u32.into());
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(owner).into();
+	Registry::<T, I>::request_entity(origin.clone(), 0, get_data::<T, I>(1, 100), false).unwrap();
+	let officers: Vec<T::AccountId> = (0..o).map(|i| account("officer", i, SEED)).collect();
+	let with_roles =
+		officers.iter().map(|o| (o.clone(), OfficerRole::Director)).collect::<Vec<_>>();
+	Registry::<T, I>::set_officers(origin, 0u8.into(), with_roles.try_into().unwrap()).unwrap();
+	officers
+}
+
+/// Registers entity 0 at the last registry, returns its registrar and registry index
+fn registered_entity<T: Config<I>, I: 'static>() -> (T::RuntimeOrigin, RegistryIndex) {
+	add_registries::<T, I>(1).unwrap();
+	let registrar: T::RuntimeOrigin = RawOrigin::Signed(account("registrar", 1, SEED)).into();
+	let reg_idx: u32 = Registry::<T, I>::registrars().len() as u32 - 1;
+	let acc: T::AccountId = account("owner", 0, SEED);
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(acc.clone()).into();
+	let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value() / 2u32.into());
+	let data = get_data::<T, I>(1, 100);
+	Registry::<T, I>::request_entity(origin, reg_idx, data.clone(), false).unwrap();
+	Registry::<T, I>::register_entity(registrar.clone(), reg_idx, 0u8.into(), T::Hashing::hash_of(&data)).unwrap();
+	(registrar, reg_idx)
+}
+
 fn get_data<T: Config<I>, I: 'static>(b: u8, s: usize) -> T::EntityData {
 	let raw_data: Vec<u8> = [b].repeat((s - 2) as usize);
 	let raw_data = raw_data.encode();
@@ -204,6 +231,96 @@ benchmarks_instance_pallet! {
   verify {
 	assert!(matches!(Registry::<T, I>::requests(reg_idx, entity_id), Some(None)));
   }
+
+  set_officers {
+	let o in 0 .. T::MaxOfficers::get();
+	let acc: T::AccountId = account("owner", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&acc, BalanceOf::<T, I>::max_value() / 2u32.into());
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(acc.clone()).into();
+	Registry::<T, I>::request_entity(origin.clone(), 0, get_data::<T, I>(1, 100), false).unwrap();
+	let entity_id: T::EntityId = 0u8.into();
+	let officers: Vec<_> = (0..o).map(|i| (account("officer", i, SEED), OfficerRole::Director)).collect();
+	let officers: OfficersOf<T, I> = officers.try_into().unwrap();
+  }: _<T::RuntimeOrigin>(origin, entity_id.clone(), officers.clone())
+  verify {
+	assert_eq!(Registry::<T, I>::officers(entity_id), officers);
+  }
+
+  propose_share_operation {
+	let o in 2 .. T::MaxOfficers::get();
+	let officers = entity_with_officers::<T, I>(o);
+	let entity_id: T::EntityId = 0u8.into();
+	let operation = ShareOperation::Issue { to: account("holder", 0, SEED), units: 100 };
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(officers[0].clone()).into();
+  }: _<T::RuntimeOrigin>(origin, entity_id.clone(), operation)
+  verify {
+	assert!(Registry::<T, I>::share_proposals(entity_id, 0).is_some());
+  }
+
+  sign_share_operation {
+	let o in 2 .. T::MaxOfficers::get();
+	let officers = entity_with_officers::<T, I>(o);
+	let entity_id: T::EntityId = 0u8.into();
+	let holder: T::AccountId = account("holder", 0, SEED);
+	let operation = ShareOperation::Issue { to: holder.clone(), units: 100 };
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(officers[0].clone()).into();
+	Registry::<T, I>::propose_share_operation(origin, entity_id.clone(), operation).unwrap();
+	// one signature short of majority
+	for officer in &officers[1..(o / 2) as usize] {
+		let origin: T::RuntimeOrigin = RawOrigin::Signed(officer.clone()).into();
+		Registry::<T, I>::sign_share_operation(origin, entity_id.clone(), 0).unwrap();
+	}
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(officers[(o / 2) as usize].clone()).into();
+  }: _<T::RuntimeOrigin>(origin, entity_id.clone(), 0)
+  verify {
+	assert_eq!(Registry::<T, I>::shares(entity_id, holder), 100);
+  }
+
+  file_annual_report {
+	let (_, reg_idx) = registered_entity::<T, I>();
+	let entity_id: T::EntityId = 0u8.into();
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(account("owner", 0, SEED)).into();
+	let report = T::Hashing::hash_of(&b"report");
+  }: _<T::RuntimeOrigin>(origin, reg_idx, entity_id.clone(), report)
+  verify {
+	assert_eq!(Registry::<T, I>::filings(reg_idx, entity_id).unwrap().last_report, Some(report));
+  }
+
+  strike_off {
+	let (registrar, reg_idx) = registered_entity::<T, I>();
+	let entity_id: T::EntityId = 0u8.into();
+	let due = Registry::<T, I>::filings(reg_idx, entity_id.clone()).unwrap().due;
+	Registry::<T, I>::mark_delinquent(reg_idx, entity_id.clone());
+	frame_system::Pallet::<T>::set_block_number(due + T::StrikeOffDelay::get());
+  }: _<T::RuntimeOrigin>(registrar, reg_idx, entity_id.clone())
+  verify {
+	assert_eq!(Registry::<T, I>::registries(reg_idx, entity_id), None);
+  }
+
+  mark_delinquent {
+	let (_, reg_idx) = registered_entity::<T, I>();
+	let entity_id: T::EntityId = 0u8.into();
+  }: {
+	Registry::<T, I>::mark_delinquent(reg_idx, entity_id.clone());
+  }
+  verify {
+	assert!(matches!(
+		Registry::<T, I>::filings(reg_idx, entity_id).unwrap().status,
+		FilingStatus::Delinquent { .. }
+	));
+  }
+
+  cancel_share_operation {
+	let officers = entity_with_officers::<T, I>(2);
+	let entity_id: T::EntityId = 0u8.into();
+	let operation = ShareOperation::Issue { to: account("holder", 0, SEED), units: 100 };
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(officers[0].clone()).into();
+	Registry::<T, I>::propose_share_operation(origin, entity_id.clone(), operation).unwrap();
+	let owner: T::RuntimeOrigin = RawOrigin::Signed(account("owner", 0, SEED)).into();
+  }: _<T::RuntimeOrigin>(owner, entity_id.clone(), 0)
+  verify {
+	assert!(Registry::<T, I>::share_proposals(entity_id, 0).is_none());
+  }
 }
 
 impl_benchmark_test_suite!(Registry, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -16,6 +16,8 @@
 //! * Deposit - amount of Currency that gets reserved when requesting registration - refunded data
 //!   is removed
 //! * Owner - AccountId that can request registrations for Entity
+//! * Officer - AccountId acting for the Entity in given role, set by the Owner
+//! * Share ledger - number of share units held by each shareholder of Entity
 //!
 //! ## Entity Lifecycle
 //!
@@ -27,6 +29,37 @@
 //!    use `request_registration()` call.
 //! 4. Entity can be removed from Registry by the Registrar - deposit will be refunded.
 //!
+//! ## Officers and Shares
+//!
+//! Owner sets the list of Entity's officers with `set_officers()`. Officers
+//! (and the Owner) are the accounts allowed to act on Entity's behalf - see
+//! `is_officer()`.
+//!
+//! Every change of the share ledger (issuance of new shares or transfer
+//! between holders) must be signed by officers:
+//! 1. Officer proposes the change with `propose_share_operation()`, which
+//!    counts as their signature.
+//! 2. Other officers sign it with `sign_share_operation()`.
+//! 3. Once majority of current officers signed, the change is applied.
+//!
+//! Pending proposal can be canceled by its proposer or the Owner with
+//! `cancel_share_operation()`.
+//!
+//! ## Annual Reports
+//!
+//! Every Entity registered in a Registry has to file an annual report (hash
+//! of the report document) once per `ReportPeriod`:
+//! 1. `register_entity()` sets the first due block of the report.
+//! 2. Owner or officer files the report with `file_annual_report()`, which
+//!    sets the next due block `ReportPeriod` after filing.
+//! 3. Entities that miss the due block are marked `Delinquent` by an
+//!    `on_idle` job.
+//! 4. Registrar can strike off Entity that has been delinquent for at least
+//!    `StrikeOffDelay` with `strike_off()`, which removes it from the
+//!    Registry the same way `unregister()` does.
+//!
+//! Setting `ReportPeriod` to zero disables the filing calendar.
+//!
 //! ## Deposits
 //!
 //! Registry pallet requires deposits to cover the cost of storing data in
@@ -68,6 +101,9 @@
 //! * `EntityData` - type that will be used to store and process Entities data
 //! * `EntityId` - type that will be used to identify Entities - usually `u32` or bigger unsigned
 //!   int type
+//! * `MaxOfficers` - max number of officers of a single Entity
+//! * `ReportPeriod` - number of blocks between annual reports, zero disables reports
+//! * `StrikeOffDelay` - number of blocks Entity must be delinquent before it can be struck off
 //! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
 //!
 //! ## Genesis Config
@@ -86,6 +122,14 @@
 //! * `unregister`: Removes Entity from given Registry
 //! * `register_entity`: Adds Entity to the Registry
 //! * `set_registered_entity`: Updates Entity data in given Registry
+//! * `request_entity_unregister`: Requests removal of Entity from given Registry
+//! * `set_officers`: Sets officers of Entity
+//! * `propose_share_operation`: Proposes share issuance or transfer
+//! * `sign_share_operation`: Signs pending share operation, applies it once signed by majority
+//!   of officers
+//! * `cancel_share_operation`: Cancels pending share operation
+//! * `file_annual_report`: Records hash of Entity's annual report
+//! * `strike_off`: Removes long-delinquent Entity from Registry
 //!
 //!
 //! License: MIT
@@ -141,6 +185,60 @@ pub struct Registration<Balance, EntityData> {
 	pub editable_by_registrar: bool,
 }
 
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+/// Role of Entity's officer
+pub enum OfficerRole {
+	Director,
+	Secretary,
+	Treasurer,
+	Signatory,
+}
+
+/// Number of shares of an Entity
+pub type ShareUnits = u64;
+
+pub type ShareProposalIndex = u32;
+
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+/// Change of Entity's share ledger
+pub enum ShareOperation<AccountId> {
+	/// Issue new shares to `to`
+	Issue { to: AccountId, units: ShareUnits },
+	/// Move shares from `from` to `to`
+	Transfer { from: AccountId, to: AccountId, units: ShareUnits },
+}
+
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+/// Share operation waiting for officers' signatures
+pub struct ShareProposal<AccountId, Signatures> {
+	/// Officer that proposed the operation
+	pub proposer: AccountId,
+	/// Change to apply
+	pub operation: ShareOperation<AccountId>,
+	/// Officers that signed the operation, including the proposer
+	pub signatures: Signatures,
+}
+
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+/// Compliance status of Entity's annual reports
+pub enum FilingStatus<BlockNumber> {
+	/// All due reports were filed
+	Compliant,
+	/// Report due at `since` wasn't filed
+	Delinquent { since: BlockNumber },
+}
+
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+/// Annual report calendar of Entity in a Registry
+pub struct Filing<BlockNumber, Hash> {
+	/// Block by which next report must be filed
+	pub due: BlockNumber,
+	/// Hash of the last filed report
+	pub last_report: Option<Hash>,
+	/// Whether Entity missed a report
+	pub status: FilingStatus<BlockNumber>,
+}
+
 type BalanceOf<T, I> =
 	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -149,9 +247,9 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::{DispatchResult, *};
 	use frame_system::pallet_prelude::*;
-	use scale_info::prelude::vec::Vec;
+	use scale_info::prelude::{vec, vec::Vec};
 	use sp_runtime::{
-		traits::{AtLeast32BitUnsigned, CheckedAdd, Hash, MaybeSerializeDeserialize},
+		traits::{AtLeast32BitUnsigned, CheckedAdd, Hash, MaybeSerializeDeserialize, Zero},
 		Saturating,
 	};
 
@@ -161,6 +259,15 @@ pub mod pallet {
 
 	type RequestOf<T, I> = Request<BalanceOf<T, I>, <T as Config<I>>::EntityData>;
 	type RegistrationOf<T, I> = Registration<BalanceOf<T, I>, <T as Config<I>>::EntityData>;
+	pub type OfficersOf<T, I> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, OfficerRole),
+		<T as Config<I>>::MaxOfficers,
+	>;
+	type FilingOf<T> = Filing<BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
+	type ShareProposalOf<T, I> = ShareProposal<
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config<I>>::MaxOfficers>,
+	>;
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -208,6 +315,19 @@ pub mod pallet {
 		/// Type for identifying Entities
 		type EntityId: Parameter + Member + MaxEncodedLen + AtLeast32BitUnsigned + Default;
 
+		#[pallet::constant]
+		/// Maximum number of officers of a single Entity
+		type MaxOfficers: Get<u32>;
+
+		#[pallet::constant]
+		/// Number of blocks between annual reports. Zero disables reports.
+		type ReportPeriod: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// Number of blocks Entity must be delinquent before it can be struck
+		/// off
+		type StrikeOffDelay: Get<BlockNumberFor<Self>>;
+
 		/// WeightInfo
 		type WeightInfo: WeightInfo;
 	}
@@ -230,6 +350,24 @@ pub mod pallet {
 		EntityIsNone,
 		/// Trying to soft unregister company that do not apply for that
 		NotRequestedToUnregister,
+		/// Account is listed as officer more than once
+		DuplicateOfficer,
+		/// Sender isn't an officer of the Entity
+		NotOfficer,
+		/// Officer already signed this share operation
+		AlreadySigned,
+		/// Share operation doesn't exist
+		InvalidShareOperation,
+		/// Share operation would move or issue no shares
+		EmptyShareOperation,
+		/// Holder doesn't have enough shares for the transfer
+		InsufficientShares,
+		/// Total number of shares would overflow
+		TooManyShares,
+		/// Entity has no annual reports due in this Registry
+		NoFiling,
+		/// Entity isn't delinquent long enough to be struck off
+		NotDelinquent,
 	}
 
 	#[pallet::event]
@@ -245,6 +383,38 @@ pub mod pallet {
 		EntityRegistered { entity_id: T::EntityId, registry_index: RegistryIndex },
 		/// Entity was removed from Registry
 		EntityUnregistered { entity_id: T::EntityId, registry_index: RegistryIndex },
+		/// Owner changed officers of Entity
+		OfficersSet { entity_id: T::EntityId },
+		/// Officer proposed a share operation
+		ShareOperationProposed { entity_id: T::EntityId, proposal_index: ShareProposalIndex },
+		/// Officer signed a share operation
+		ShareOperationSigned {
+			entity_id: T::EntityId,
+			proposal_index: ShareProposalIndex,
+			officer: T::AccountId,
+		},
+		/// Share operation was canceled before it got enough signatures
+		ShareOperationCanceled { entity_id: T::EntityId, proposal_index: ShareProposalIndex },
+		/// New shares were issued
+		SharesIssued { entity_id: T::EntityId, to: T::AccountId, units: ShareUnits },
+		/// Shares were transferred between holders
+		SharesTransferred {
+			entity_id: T::EntityId,
+			from: T::AccountId,
+			to: T::AccountId,
+			units: ShareUnits,
+		},
+		/// Annual report was filed
+		AnnualReportFiled {
+			entity_id: T::EntityId,
+			registry_index: RegistryIndex,
+			report: T::Hash,
+			next_due: BlockNumberFor<T>,
+		},
+		/// Entity missed annual report deadline
+		EntityDelinquent { entity_id: T::EntityId, registry_index: RegistryIndex },
+		/// Delinquent Entity was removed from Registry
+		EntityStruckOff { entity_id: T::EntityId, registry_index: RegistryIndex },
 	}
 
 	#[pallet::storage]
@@ -301,6 +471,80 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn officers)]
+	/// Officers of Entity with their roles. See `set_officers`
+	pub(super) type Officers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::EntityId, OfficersOf<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn shares)]
+	/// Share ledger - number of Entity's shares held by given account
+	pub(super) type ShareLedger<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::EntityId,
+		Blake2_128Concat,
+		T::AccountId,
+		ShareUnits,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn total_shares)]
+	/// Total number of issued shares of Entity
+	pub(super) type TotalShares<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::EntityId, ShareUnits, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn share_proposals)]
+	/// Share operations waiting for officers' signatures
+	pub(super) type ShareProposals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::EntityId,
+		Twox64Concat,
+		ShareProposalIndex,
+		ShareProposalOf<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Next free ShareProposalIndex
+	pub(super) type NextShareProposalIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ShareProposalIndex, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn filings)]
+	/// Annual report calendar of Entities in given Registries. See
+	/// `file_annual_report`
+	pub(super) type Filings<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RegistryIndex,
+		Blake2_128Concat,
+		T::EntityId,
+		FilingOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Filings to check for missed deadline, by due block
+	pub(super) type FilingQueue<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(RegistryIndex, T::EntityId),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Next block of `FilingQueue` to be checked by `on_idle`
+	pub(super) type NextFilingCheck<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
 	// no getter on purpose - we dont want direct accesses
 	// use get_next_entity_id only!
 	#[pallet::storage]
@@ -351,10 +595,18 @@ pub mod pallet {
 						editable_by_registrar: *editable_by_registrar,
 					},
 				);
+				Pallet::<T, I>::schedule_first_filing(*reg_idx, entity_id);
 			}
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_overdue_filings(now, remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Add a new registry.
@@ -522,6 +774,7 @@ pub mod pallet {
 			}
 
 			Registries::<T, I>::remove(registry_index, &entity_id);
+			Self::remove_filing(registry_index, &entity_id);
 			Self::deposit_event(Event::EntityUnregistered { entity_id, registry_index });
 			Ok(())
 		}
@@ -581,6 +834,9 @@ pub mod pallet {
 					editable_by_registrar,
 				},
 			);
+			if !Filings::<T, I>::contains_key(&registry_index, &entity_id) {
+				Self::schedule_first_filing(registry_index, entity_id.clone());
+			}
 
 			Self::deposit_event(Event::EntityRegistered { entity_id, registry_index });
 
@@ -652,9 +908,374 @@ pub mod pallet {
 			Self::deposit_event(Event::RegistrationRequested { registry_index, entity_id });
 			Ok(())
 		}
+
+		/// Set officers of Entity. Replaces the previous list. Pending share
+		/// operations are kept, but only signatures of current officers count.
+		///
+		/// * `entity_id` - Entity to set officers of
+		/// * `officers` - officers with their roles
+		///
+		/// Emits `OfficersSet`.
+		///
+		/// Must be called by `EntityOrigin`
+		/// Must be called by Owner of the Entity
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_officers(officers.len() as u32))]
+		pub fn set_officers(
+			origin: OriginFor<T>,
+			entity_id: T::EntityId,
+			officers: OfficersOf<T, I>,
+		) -> DispatchResult {
+			let owner = T::EntityOrigin::ensure_origin(origin)?;
+			Self::ensure_entity_owner(&owner, &entity_id)?;
+
+			for (i, (officer, _)) in officers.iter().enumerate() {
+				ensure!(
+					!officers.iter().skip(i + 1).any(|(other, _)| other == officer),
+					Error::<T, I>::DuplicateOfficer
+				);
+			}
+
+			if officers.is_empty() {
+				Officers::<T, I>::remove(&entity_id);
+			} else {
+				Officers::<T, I>::insert(&entity_id, officers);
+			}
+
+			Self::deposit_event(Event::OfficersSet { entity_id });
+			Ok(())
+		}
+
+		/// Propose change of Entity's share ledger. Proposal is signed by the
+		/// caller and applied immediately if that's enough signatures.
+		///
+		/// * `entity_id` - Entity which shares will change
+		/// * `operation` - issuance or transfer of shares
+		///
+		/// Emits `ShareOperationProposed` and, if applied, `SharesIssued` or
+		/// `SharesTransferred`.
+		///
+		/// Must be called by `EntityOrigin`
+		/// Must be called by officer of the Entity
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::propose_share_operation(T::MaxOfficers::get()))]
+		pub fn propose_share_operation(
+			origin: OriginFor<T>,
+			entity_id: T::EntityId,
+			operation: ShareOperation<T::AccountId>,
+		) -> DispatchResult {
+			let officer = T::EntityOrigin::ensure_origin(origin)?;
+			let officers = Self::officers(&entity_id);
+			ensure!(officers.iter().any(|(o, _)| o == &officer), Error::<T, I>::NotOfficer);
+			let units = match &operation {
+				ShareOperation::Issue { units, .. } => *units,
+				ShareOperation::Transfer { from, to, units } => {
+					ensure!(from != to, Error::<T, I>::EmptyShareOperation);
+					*units
+				},
+			};
+			ensure!(units > 0, Error::<T, I>::EmptyShareOperation);
+
+			let proposal_index = NextShareProposalIndex::<T, I>::get();
+			NextShareProposalIndex::<T, I>::put(proposal_index.saturating_add(1));
+			// can't fail - officers list has the same bound and isn't empty
+			let signatures = BoundedVec::truncate_from(vec![officer.clone()]);
+			let proposal = ShareProposal { proposer: officer, operation, signatures };
+
+			Self::deposit_event(Event::ShareOperationProposed {
+				entity_id: entity_id.clone(),
+				proposal_index,
+			});
+			Self::apply_or_store(entity_id, proposal_index, proposal, &officers)
+		}
+
+		/// Sign pending share operation. Applies it once signed by majority of
+		/// current officers.
+		///
+		/// * `entity_id` - Entity which shares will change
+		/// * `proposal_index` - index of pending operation
+		///
+		/// Emits `ShareOperationSigned` and, if applied, `SharesIssued` or
+		/// `SharesTransferred`.
+		///
+		/// Must be called by `EntityOrigin`
+		/// Must be called by officer of the Entity
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::sign_share_operation(T::MaxOfficers::get()))]
+		pub fn sign_share_operation(
+			origin: OriginFor<T>,
+			entity_id: T::EntityId,
+			proposal_index: ShareProposalIndex,
+		) -> DispatchResult {
+			let officer = T::EntityOrigin::ensure_origin(origin)?;
+			let officers = Self::officers(&entity_id);
+			ensure!(officers.iter().any(|(o, _)| o == &officer), Error::<T, I>::NotOfficer);
+			let mut proposal = Self::share_proposals(&entity_id, proposal_index)
+				.ok_or(Error::<T, I>::InvalidShareOperation)?;
+			ensure!(!proposal.signatures.contains(&officer), Error::<T, I>::AlreadySigned);
+
+			// signatures of removed officers don't count, make room for current ones
+			proposal.signatures.retain(|s| officers.iter().any(|(o, _)| o == s));
+			// can't fail - officer isn't in signatures yet and all others are officers too
+			proposal
+				.signatures
+				.try_push(officer.clone())
+				.map_err(|_| Error::<T, I>::NotOfficer)?;
+
+			Self::deposit_event(Event::ShareOperationSigned {
+				entity_id: entity_id.clone(),
+				proposal_index,
+				officer,
+			});
+			Self::apply_or_store(entity_id, proposal_index, proposal, &officers)
+		}
+
+		/// Cancel pending share operation.
+		///
+		/// * `entity_id` - Entity of the operation
+		/// * `proposal_index` - index of pending operation
+		///
+		/// Emits `ShareOperationCanceled`.
+		///
+		/// Must be called by `EntityOrigin`
+		/// Must be called by the proposer or Owner of the Entity
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cancel_share_operation())]
+		pub fn cancel_share_operation(
+			origin: OriginFor<T>,
+			entity_id: T::EntityId,
+			proposal_index: ShareProposalIndex,
+		) -> DispatchResult {
+			let sender = T::EntityOrigin::ensure_origin(origin)?;
+			let proposal = Self::share_proposals(&entity_id, proposal_index)
+				.ok_or(Error::<T, I>::InvalidShareOperation)?;
+			if proposal.proposer != sender {
+				Self::ensure_entity_owner(&sender, &entity_id)?;
+			}
+
+			ShareProposals::<T, I>::remove(&entity_id, proposal_index);
+
+			Self::deposit_event(Event::ShareOperationCanceled { entity_id, proposal_index });
+			Ok(())
+		}
+
+		/// File Entity's annual report. Clears delinquency and sets the next
+		/// due block `ReportPeriod` from now.
+		///
+		/// * `registry_index` - Registry where Entity is registered
+		/// * `entity_id` - Entity filing the report
+		/// * `report` - hash of the report document
+		///
+		/// Emits `AnnualReportFiled`.
+		///
+		/// Must be called by `EntityOrigin`
+		/// Must be called by Owner or officer of the Entity
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::file_annual_report())]
+		pub fn file_annual_report(
+			origin: OriginFor<T>,
+			registry_index: RegistryIndex,
+			entity_id: T::EntityId,
+			report: T::Hash,
+		) -> DispatchResult {
+			let sender = T::EntityOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_officer(&entity_id, &sender), Error::<T, I>::NotOfficer);
+			let mut filing =
+				Self::filings(registry_index, &entity_id).ok_or(Error::<T, I>::NoFiling)?;
+
+			let next_due =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ReportPeriod::get());
+			FilingQueue::<T, I>::remove(filing.due, (registry_index, &entity_id));
+			FilingQueue::<T, I>::insert(next_due, (registry_index, &entity_id), ());
+			filing.due = next_due;
+			filing.last_report = Some(report);
+			filing.status = FilingStatus::Compliant;
+			Filings::<T, I>::insert(registry_index, &entity_id, filing);
+
+			Self::deposit_event(Event::AnnualReportFiled {
+				entity_id,
+				registry_index,
+				report,
+				next_due,
+			});
+			Ok(())
+		}
+
+		/// Remove Entity that has been delinquent for at least
+		/// `StrikeOffDelay` from the Registry.
+		///
+		/// * `registry_index` - Registry index to remove from
+		/// * `entity_id` - delinquent Entity
+		///
+		/// Will refund deposit of stored data.
+		///
+		/// Emits `EntityStruckOff`.
+		///
+		/// Must be called by `RegistrarOrigin`
+		/// Must be called by Registrar of the Registry
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::strike_off())]
+		pub fn strike_off(
+			origin: OriginFor<T>,
+			registry_index: RegistryIndex,
+			entity_id: T::EntityId,
+		) -> DispatchResult {
+			let sender = T::RegistrarOrigin::ensure_origin(origin)?;
+			Self::ensure_registrar(&sender, registry_index)?;
+
+			let filing =
+				Self::filings(registry_index, &entity_id).ok_or(Error::<T, I>::NoFiling)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			match filing.status {
+				FilingStatus::Delinquent { since }
+					if since.saturating_add(T::StrikeOffDelay::get()) <= now => {},
+				_ => return Err(Error::<T, I>::NotDelinquent.into()),
+			}
+
+			if let Some(Registration { deposit, .. }) = Self::registries(registry_index, &entity_id)
+			{
+				let owner = Self::entity_owner(&entity_id).ok_or(Error::<T, I>::InvalidEntity)?;
+				T::Currency::unreserve_named(T::ReserveIdentifier::get(), &owner, deposit);
+			}
+			Registries::<T, I>::remove(registry_index, &entity_id);
+			Self::remove_filing(registry_index, &entity_id);
+
+			Self::deposit_event(Event::EntityStruckOff { entity_id, registry_index });
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Checks if account can act on behalf of Entity - it's either the
+		/// Owner or one of the officers
+		pub fn is_officer(entity_id: &T::EntityId, who: &T::AccountId) -> bool {
+			Self::entity_owner(entity_id).as_ref() == Some(who)
+				|| Self::officers(entity_id).iter().any(|(officer, _)| officer == who)
+		}
+
+		fn schedule_first_filing(registry_index: RegistryIndex, entity_id: T::EntityId) {
+			let period = T::ReportPeriod::get();
+			if period.is_zero() {
+				return;
+			}
+			let due = frame_system::Pallet::<T>::block_number().saturating_add(period);
+			FilingQueue::<T, I>::insert(due, (registry_index, &entity_id), ());
+			Filings::<T, I>::insert(
+				registry_index,
+				entity_id,
+				Filing { due, last_report: None, status: FilingStatus::Compliant },
+			);
+		}
+
+		fn remove_filing(registry_index: RegistryIndex, entity_id: &T::EntityId) {
+			if let Some(filing) = Filings::<T, I>::take(registry_index, entity_id) {
+				FilingQueue::<T, I>::remove(filing.due, (registry_index, entity_id));
+			}
+		}
+
+		/// Mark Entities that missed report deadline up to block `now` as
+		/// delinquent, as long as `remaining_weight` allows. Checked blocks are
+		/// tracked in `NextFilingCheck`, so sweep picks up where it stopped.
+		fn sweep_overdue_filings(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if T::ReportPeriod::get().is_zero() {
+				return Weight::zero();
+			}
+
+			let per_block = T::DbWeight::get().reads_writes(1, 1);
+			let per_filing = T::WeightInfo::mark_delinquent();
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+			let mut cursor = NextFilingCheck::<T, I>::get();
+			if cursor.is_zero() {
+				cursor = now;
+			}
+
+			// deadline is the due block itself, so only blocks before `now` are overdue
+			while cursor < now && remaining_weight.all_gte(used + per_block + per_filing) {
+				used += per_block;
+				let mut queue = FilingQueue::<T, I>::drain_prefix(cursor);
+				let mut exhausted = false;
+				loop {
+					if !remaining_weight.all_gte(used + per_filing) {
+						exhausted = true;
+						break;
+					}
+					match queue.next() {
+						Some(((registry_index, entity_id), ())) => {
+							used += per_filing;
+							Self::mark_delinquent(registry_index, entity_id);
+						},
+						None => break,
+					}
+				}
+				if exhausted {
+					break;
+				}
+				cursor = cursor.saturating_add(1u8.into());
+			}
+
+			NextFilingCheck::<T, I>::put(cursor);
+			used
+		}
+
+		pub(crate) fn mark_delinquent(registry_index: RegistryIndex, entity_id: T::EntityId) {
+			let marked = Filings::<T, I>::mutate(registry_index, &entity_id, |filing| {
+				filing
+					.as_mut()
+					.map(|filing| filing.status = FilingStatus::Delinquent { since: filing.due })
+					.is_some()
+			});
+			if marked {
+				Self::deposit_event(Event::EntityDelinquent { entity_id, registry_index });
+			}
+		}
+
+		/// Applies the operation if signed by majority of `officers`, stores
+		/// it as pending otherwise
+		fn apply_or_store(
+			entity_id: T::EntityId,
+			proposal_index: ShareProposalIndex,
+			proposal: ShareProposalOf<T, I>,
+			officers: &OfficersOf<T, I>,
+		) -> DispatchResult {
+			let signed = proposal
+				.signatures
+				.iter()
+				.filter(|s| officers.iter().any(|(o, _)| o == *s))
+				.count();
+			if signed <= officers.len() / 2 {
+				ShareProposals::<T, I>::insert(&entity_id, proposal_index, proposal);
+				return Ok(());
+			}
+
+			ShareProposals::<T, I>::remove(&entity_id, proposal_index);
+			match proposal.operation {
+				ShareOperation::Issue { to, units } => {
+					TotalShares::<T, I>::try_mutate(&entity_id, |total| -> DispatchResult {
+						*total = total.checked_add(units).ok_or(Error::<T, I>::TooManyShares)?;
+						Ok(())
+					})?;
+					ShareLedger::<T, I>::mutate(&entity_id, &to, |held| {
+						*held = held.saturating_add(units)
+					});
+					Self::deposit_event(Event::SharesIssued { entity_id, to, units });
+				},
+				ShareOperation::Transfer { from, to, units } => {
+					let held = Self::shares(&entity_id, &from);
+					ensure!(held >= units, Error::<T, I>::InsufficientShares);
+					if held == units {
+						ShareLedger::<T, I>::remove(&entity_id, &from);
+					} else {
+						ShareLedger::<T, I>::insert(&entity_id, &from, held - units);
+					}
+					ShareLedger::<T, I>::mutate(&entity_id, &to, |held| {
+						*held = held.saturating_add(units)
+					});
+					Self::deposit_event(Event::SharesTransferred { entity_id, from, to, units });
+				},
+			}
+			Ok(())
+		}
+
 		fn calculate_deposit(data: &T::EntityData) -> BalanceOf<T, I> {
 			let data_len = data.encoded_size() as u32;
 			let required_deposit = T::BaseDeposit::get()
@@ -95,6 +95,9 @@ impl pallet_registry::Config for Test {
 	type RegistrarOrigin = EnsureSigned<u64>;
 	type EntityOrigin = EnsureSigned<u64>;
 	type ReserveIdentifier = ReserveIdentifier;
+	type MaxOfficers = ConstU32<3>;
+	type ReportPeriod = ConstU64<10>;
+	type StrikeOffDelay = ConstU64<5>;
 	type WeightInfo = ();
 }
 
@@ -110,6 +113,9 @@ impl pallet_registry::Config<pallet_registry::Instance2> for Test {
 	type RegistrarOrigin = EnsureSigned<u64>;
 	type EntityOrigin = EnsureSigned<u64>;
 	type ReserveIdentifier = ReserveIdentifier;
+	type MaxOfficers = ConstU32<3>;
+	type ReportPeriod = ConstU64<10>;
+	type StrikeOffDelay = ConstU64<5>;
 	type WeightInfo = ();
 }
 
@@ -170,6 +176,9 @@ impl pallet_registry::Config<pallet_registry::Instance3> for Test {
 	type RegistrarOrigin = EnsureSignedOrMembers;
 	type EntityOrigin = EnsureSignedOrMembers;
 	type ReserveIdentifier = ReserveIdentifier;
+	type MaxOfficers = ConstU32<3>;
+	type ReportPeriod = ConstU64<10>;
+	type StrikeOffDelay = ConstU64<5>;
 	type WeightInfo = ();
 }
 
@@ -185,6 +194,9 @@ impl pallet_registry::Config<pallet_registry::Instance4> for Test {
 	type RegistrarOrigin = EnsureSigned<u64>;
 	type EntityOrigin = EnsureSigned<u64>;
 	type ReserveIdentifier = ReserveIdentifier;
+	type MaxOfficers = ConstU32<3>;
+	type ReportPeriod = ConstU64<10>;
+	type StrikeOffDelay = ConstU64<5>;
 	type WeightInfo = ();
 }
 
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
-use crate::{mock::*, Error, Event, Registration, Request, Requests};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, Error, Event, Filing, FilingStatus, OfficerRole, Registration, Request, Requests,
+	ShareOperation,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use sp_runtime::traits::{BadOrigin, Hash};
 
 type DataOf<T> = <T as pallet_registry::Config>::EntityData;
@@ -1182,3 +1185,344 @@ fn request_entity_unregister_can_not_unregister_no_existing() {
 		assert_eq!(Registry::registries(0, 0), None);
 	})
 }
+
+/* officers and shares */
+fn entity_with_officers(officers: Vec<u64>) {
+	let data: DataOf<Test> = vec![1, 2, 3].try_into().unwrap();
+	assert_ok!(Registry::request_entity(RuntimeOrigin::signed(0), 0, data, false));
+	let officers: Vec<_> = officers.into_iter().map(|o| (o, OfficerRole::Director)).collect();
+	assert_ok!(Registry::set_officers(RuntimeOrigin::signed(0), 0, officers.try_into().unwrap()));
+}
+
+#[test]
+fn set_officers_works() {
+	new_test_ext().execute_with(|| {
+		let data: DataOf<Test> = vec![1, 2, 3].try_into().unwrap();
+		assert_ok!(Registry::request_entity(RuntimeOrigin::signed(0), 0, data, false));
+		let officers: Vec<_> = vec![(1, OfficerRole::Director), (2, OfficerRole::Secretary)];
+
+		assert_noop!(
+			Registry::set_officers(
+				RuntimeOrigin::signed(1),
+				0,
+				officers.clone().try_into().unwrap()
+			),
+			Error::<Test>::InvalidEntity
+		);
+		assert_noop!(
+			Registry::set_officers(
+				RuntimeOrigin::signed(0),
+				0,
+				vec![(1, OfficerRole::Director), (1, OfficerRole::Treasurer)]
+					.try_into()
+					.unwrap()
+			),
+			Error::<Test>::DuplicateOfficer
+		);
+		assert!(!Registry::is_officer(&0, &1));
+
+		assert_ok!(Registry::set_officers(
+			RuntimeOrigin::signed(0),
+			0,
+			officers.clone().try_into().unwrap()
+		));
+		System::assert_last_event(Event::<Test>::OfficersSet { entity_id: 0 }.into());
+		assert_eq!(Registry::officers(0).into_inner(), officers);
+		assert!(Registry::is_officer(&0, &0));
+		assert!(Registry::is_officer(&0, &1));
+		assert!(Registry::is_officer(&0, &2));
+		assert!(!Registry::is_officer(&0, &3));
+		assert!(!Registry::is_officer(&1, &1));
+	});
+}
+
+#[test]
+fn propose_share_operation_validates_operation() {
+	new_test_ext().execute_with(|| {
+		entity_with_officers(vec![1, 2]);
+		let issue = ShareOperation::Issue { to: 5, units: 10 };
+
+		// owner isn't an officer unless listed
+		assert_noop!(
+			Registry::propose_share_operation(RuntimeOrigin::signed(0), 0, issue.clone()),
+			Error::<Test>::NotOfficer
+		);
+		assert_noop!(
+			Registry::propose_share_operation(
+				RuntimeOrigin::signed(1),
+				0,
+				ShareOperation::Issue { to: 5, units: 0 }
+			),
+			Error::<Test>::EmptyShareOperation
+		);
+		assert_noop!(
+			Registry::propose_share_operation(
+				RuntimeOrigin::signed(1),
+				0,
+				ShareOperation::Transfer { from: 5, to: 5, units: 10 }
+			),
+			Error::<Test>::EmptyShareOperation
+		);
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue));
+		System::assert_last_event(
+			Event::<Test>::ShareOperationProposed { entity_id: 0, proposal_index: 0 }.into(),
+		);
+	});
+}
+
+#[test]
+fn share_operation_requires_majority_of_officers() {
+	new_test_ext().execute_with(|| {
+		entity_with_officers(vec![1, 2, 3]);
+		let issue = ShareOperation::Issue { to: 5, units: 100 };
+
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue));
+		assert_eq!(Registry::total_shares(0), 0);
+		assert_noop!(
+			Registry::sign_share_operation(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::AlreadySigned
+		);
+		assert_noop!(
+			Registry::sign_share_operation(RuntimeOrigin::signed(4), 0, 0),
+			Error::<Test>::NotOfficer
+		);
+
+		assert_ok!(Registry::sign_share_operation(RuntimeOrigin::signed(2), 0, 0));
+		System::assert_last_event(
+			Event::<Test>::SharesIssued { entity_id: 0, to: 5, units: 100 }.into(),
+		);
+		assert_eq!(Registry::shares(0, 5), 100);
+		assert_eq!(Registry::total_shares(0), 100);
+		assert_eq!(Registry::share_proposals(0, 0), None);
+	});
+}
+
+#[test]
+fn signatures_of_removed_officers_dont_count() {
+	new_test_ext().execute_with(|| {
+		entity_with_officers(vec![1, 2, 3]);
+		let issue = ShareOperation::Issue { to: 5, units: 100 };
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue));
+
+		let officers = vec![(2, OfficerRole::Director), (3, OfficerRole::Director)];
+		assert_ok!(Registry::set_officers(
+			RuntimeOrigin::signed(0),
+			0,
+			officers.try_into().unwrap()
+		));
+		assert_ok!(Registry::sign_share_operation(RuntimeOrigin::signed(2), 0, 0));
+		assert_eq!(Registry::total_shares(0), 0);
+		assert_eq!(Registry::share_proposals(0, 0).unwrap().signatures.into_inner(), vec![2]);
+
+		assert_ok!(Registry::sign_share_operation(RuntimeOrigin::signed(3), 0, 0));
+		assert_eq!(Registry::total_shares(0), 100);
+	});
+}
+
+#[test]
+fn share_transfer_works() {
+	new_test_ext().execute_with(|| {
+		entity_with_officers(vec![1]);
+		let issue = ShareOperation::Issue { to: 5, units: 10 };
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue));
+		assert_eq!(Registry::shares(0, 5), 10);
+
+		assert_noop!(
+			Registry::propose_share_operation(
+				RuntimeOrigin::signed(1),
+				0,
+				ShareOperation::Transfer { from: 5, to: 6, units: 11 }
+			),
+			Error::<Test>::InsufficientShares
+		);
+		assert_ok!(Registry::propose_share_operation(
+			RuntimeOrigin::signed(1),
+			0,
+			ShareOperation::Transfer { from: 5, to: 6, units: 4 }
+		));
+		System::assert_last_event(
+			Event::<Test>::SharesTransferred { entity_id: 0, from: 5, to: 6, units: 4 }.into(),
+		);
+		assert_eq!(Registry::shares(0, 5), 6);
+		assert_eq!(Registry::shares(0, 6), 4);
+
+		assert_ok!(Registry::propose_share_operation(
+			RuntimeOrigin::signed(1),
+			0,
+			ShareOperation::Transfer { from: 5, to: 6, units: 6 }
+		));
+		assert!(!crate::ShareLedger::<Test>::contains_key(0, 5));
+		assert_eq!(Registry::shares(0, 6), 10);
+		assert_eq!(Registry::total_shares(0), 10);
+	});
+}
+
+#[test]
+fn cancel_share_operation_works() {
+	new_test_ext().execute_with(|| {
+		entity_with_officers(vec![1, 2, 3]);
+		let issue = ShareOperation::Issue { to: 5, units: 10 };
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue.clone()));
+		assert_ok!(Registry::propose_share_operation(RuntimeOrigin::signed(1), 0, issue));
+
+		assert_noop!(
+			Registry::cancel_share_operation(RuntimeOrigin::signed(2), 0, 0),
+			Error::<Test>::InvalidEntity
+		);
+		assert_ok!(Registry::cancel_share_operation(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(
+			Event::<Test>::ShareOperationCanceled { entity_id: 0, proposal_index: 0 }.into(),
+		);
+		assert_ok!(Registry::cancel_share_operation(RuntimeOrigin::signed(0), 0, 1));
+		assert_noop!(
+			Registry::cancel_share_operation(RuntimeOrigin::signed(0), 0, 1),
+			Error::<Test>::InvalidShareOperation
+		);
+	});
+}
+
+/* annual reports */
+fn registered_entity() {
+	let data: DataOf<Test> = vec![1, 2, 3].try_into().unwrap();
+	let hash = HashingOf::<Test>::hash_of(&data);
+	assert_ok!(Registry::add_registry(RuntimeOrigin::root(), 1));
+	assert_ok!(Registry::request_entity(RuntimeOrigin::signed(0), 0, data, false));
+	assert_ok!(Registry::register_entity(RuntimeOrigin::signed(1), 0, 0, hash));
+}
+
+#[test]
+fn register_entity_schedules_annual_report() {
+	new_test_ext().execute_with(|| {
+		registered_entity();
+		assert_eq!(
+			Registry::filings(0, 0),
+			Some(Filing { due: 11, last_report: None, status: FilingStatus::Compliant })
+		);
+
+		// updated registration keeps the calendar
+		System::set_block_number(5);
+		let data: DataOf<Test> = vec![4].try_into().unwrap();
+		let hash = HashingOf::<Test>::hash_of(&data);
+		assert_ok!(Registry::request_registration(RuntimeOrigin::signed(0), 0, 0, data, false));
+		assert_ok!(Registry::register_entity(RuntimeOrigin::signed(1), 0, 0, hash));
+		assert_eq!(Registry::filings(0, 0).unwrap().due, 11);
+
+		assert_ok!(Registry::unregister(RuntimeOrigin::signed(1), 0, 0, false));
+		assert_eq!(Registry::filings(0, 0), None);
+	});
+}
+
+#[test]
+fn file_annual_report_works() {
+	new_test_ext().execute_with(|| {
+		registered_entity();
+		let officers = vec![(2, OfficerRole::Secretary)];
+		assert_ok!(Registry::set_officers(
+			RuntimeOrigin::signed(0),
+			0,
+			officers.try_into().unwrap()
+		));
+		let report = HashingOf::<Test>::hash_of(&b"report");
+
+		assert_noop!(
+			Registry::file_annual_report(RuntimeOrigin::signed(3), 0, 0, report),
+			Error::<Test>::NotOfficer
+		);
+		assert_noop!(
+			Registry::file_annual_report(RuntimeOrigin::signed(0), 1, 0, report),
+			Error::<Test>::NoFiling
+		);
+
+		System::set_block_number(8);
+		assert_ok!(Registry::file_annual_report(RuntimeOrigin::signed(2), 0, 0, report));
+		System::assert_last_event(
+			Event::<Test>::AnnualReportFiled {
+				entity_id: 0,
+				registry_index: 0,
+				report,
+				next_due: 18,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Registry::filings(0, 0),
+			Some(Filing { due: 18, last_report: Some(report), status: FilingStatus::Compliant })
+		);
+
+		// old deadline is no longer checked
+		Registry::on_idle(11, Weight::MAX);
+		Registry::on_idle(12, Weight::MAX);
+		assert_eq!(Registry::filings(0, 0).unwrap().status, FilingStatus::Compliant);
+	});
+}
+
+#[test]
+fn on_idle_marks_overdue_entities_delinquent() {
+	new_test_ext().execute_with(|| {
+		registered_entity();
+		Registry::on_idle(10, Weight::MAX);
+		Registry::on_idle(11, Weight::MAX);
+		assert_eq!(Registry::filings(0, 0).unwrap().status, FilingStatus::Compliant);
+
+		Registry::on_idle(12, Weight::MAX);
+		System::assert_last_event(
+			Event::<Test>::EntityDelinquent { entity_id: 0, registry_index: 0 }.into(),
+		);
+		assert_eq!(Registry::filings(0, 0).unwrap().status, FilingStatus::Delinquent { since: 11 });
+
+		// late filing clears delinquency
+		System::set_block_number(13);
+		let report = HashingOf::<Test>::hash_of(&b"report");
+		assert_ok!(Registry::file_annual_report(RuntimeOrigin::signed(0), 0, 0, report));
+		assert_eq!(
+			Registry::filings(0, 0),
+			Some(Filing { due: 23, last_report: Some(report), status: FilingStatus::Compliant })
+		);
+	});
+}
+
+#[test]
+fn on_idle_respects_weight_limit() {
+	new_test_ext().execute_with(|| {
+		registered_entity();
+		Registry::on_idle(10, Weight::MAX);
+		Registry::on_idle(20, Weight::zero());
+		assert_eq!(Registry::filings(0, 0).unwrap().status, FilingStatus::Compliant);
+
+		// picks up where it stopped
+		Registry::on_idle(21, Weight::MAX);
+		assert_eq!(Registry::filings(0, 0).unwrap().status, FilingStatus::Delinquent { since: 11 });
+	});
+}
+
+#[test]
+fn strike_off_works() {
+	new_test_ext().execute_with(|| {
+		registered_entity();
+		assert_eq!(Balances::reserved_balance(0), 9);
+		assert_noop!(
+			Registry::strike_off(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::NotDelinquent
+		);
+
+		System::set_block_number(12);
+		Registry::on_idle(12, Weight::MAX);
+		assert_noop!(
+			Registry::strike_off(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::NotDelinquent
+		);
+
+		System::set_block_number(16);
+		assert_noop!(
+			Registry::strike_off(RuntimeOrigin::signed(0), 0, 0),
+			Error::<Test>::InvalidRegistry
+		);
+		assert_ok!(Registry::strike_off(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(
+			Event::<Test>::EntityStruckOff { entity_id: 0, registry_index: 0 }.into(),
+		);
+		assert_eq!(Registry::registries(0, 0), None);
+		assert_eq!(Registry::filings(0, 0), None);
+		assert_eq!(Balances::reserved_balance(0), 0);
+	});
+}
@@ -6,6 +6,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `michallustyk-HP-ProBook-445-G7`, CPU: `AMD Ryzen 7 4700U with Radeon Graphics`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! Functions marked NOT BENCHMARKED were added after this run and are
+//! estimated. Replace them with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_registry` run on
+//! reference hardware.
 
 // Executed Command:
 // target/release/substrate
@@ -38,6 +43,13 @@ pub trait WeightInfo {
 	fn register_entity(r: u32, s: u32, ) -> Weight;
 	fn set_registered_entity(r: u32, s: u32, ) -> Weight;
 	fn request_entity_unregister() -> Weight;
+	fn set_officers(o: u32, ) -> Weight;
+	fn propose_share_operation(o: u32, ) -> Weight;
+	fn sign_share_operation(o: u32, ) -> Weight;
+	fn cancel_share_operation() -> Weight;
+	fn file_annual_report() -> Weight;
+	fn strike_off() -> Weight;
+	fn mark_delinquent() -> Weight;
 }
 
 /// Weights for pallet_registry using the Substrate node and recommended hardware.
@@ -195,6 +207,96 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Officers (r:0 w:1)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// The range of component `o` is `[0, 10]`.
+	fn set_officers(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(19_487_520, 3517)
+			.saturating_add(Weight::from_parts(402_613, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: CompanyRegistry Officers (r:1 w:0)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry NextShareProposalIndex (r:1 w:1)
+	/// Proof: CompanyRegistry NextShareProposalIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareProposals (r:0 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// The range of component `o` is `[2, 10]`.
+	fn propose_share_operation(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(23_815_294, 3926)
+			.saturating_add(Weight::from_parts(187_305, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: CompanyRegistry Officers (r:1 w:0)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareProposals (r:1 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry TotalShares (r:1 w:1)
+	/// Proof: CompanyRegistry TotalShares (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareLedger (r:1 w:1)
+	/// Proof: CompanyRegistry ShareLedger (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `o` is `[2, 10]`.
+	fn sign_share_operation(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(33_140_877, 3926)
+			.saturating_add(Weight::from_parts(521_890, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: CompanyRegistry ShareProposals (r:1 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_share_operation() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(21_003_000, 3926)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry FilingQueue (r:0 w:2)
+	/// Proof: CompanyRegistry FilingQueue (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn file_annual_report() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(22_461_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: CompanyRegistry Registrars (r:1 w:0)
+	/// Proof: CompanyRegistry Registrars (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Registries (r:1 w:1)
+	/// Proof: CompanyRegistry Registries (max_values: None, max_size: Some(8252), added: 10727, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry FilingQueue (r:0 w:1)
+	/// Proof: CompanyRegistry FilingQueue (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn strike_off() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(58_210_000, 11717)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	fn mark_delinquent() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(12_652_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -351,4 +453,94 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Officers (r:0 w:1)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// The range of component `o` is `[0, 10]`.
+	fn set_officers(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(19_487_520, 3517)
+			.saturating_add(Weight::from_parts(402_613, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: CompanyRegistry Officers (r:1 w:0)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry NextShareProposalIndex (r:1 w:1)
+	/// Proof: CompanyRegistry NextShareProposalIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareProposals (r:0 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// The range of component `o` is `[2, 10]`.
+	fn propose_share_operation(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(23_815_294, 3926)
+			.saturating_add(Weight::from_parts(187_305, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: CompanyRegistry Officers (r:1 w:0)
+	/// Proof: CompanyRegistry Officers (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareProposals (r:1 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry TotalShares (r:1 w:1)
+	/// Proof: CompanyRegistry TotalShares (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry ShareLedger (r:1 w:1)
+	/// Proof: CompanyRegistry ShareLedger (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `o` is `[2, 10]`.
+	fn sign_share_operation(o: u32, ) -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(33_140_877, 3926)
+			.saturating_add(Weight::from_parts(521_890, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: CompanyRegistry ShareProposals (r:1 w:1)
+	/// Proof: CompanyRegistry ShareProposals (max_values: None, max_size: Some(461), added: 2936, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_share_operation() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(21_003_000, 3926)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry FilingQueue (r:0 w:2)
+	/// Proof: CompanyRegistry FilingQueue (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn file_annual_report() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(22_461_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: CompanyRegistry Registrars (r:1 w:0)
+	/// Proof: CompanyRegistry Registrars (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry Registries (r:1 w:1)
+	/// Proof: CompanyRegistry Registries (max_values: None, max_size: Some(8252), added: 10727, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry EntityOwner (r:1 w:0)
+	/// Proof: CompanyRegistry EntityOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: CompanyRegistry FilingQueue (r:0 w:1)
+	/// Proof: CompanyRegistry FilingQueue (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn strike_off() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(58_210_000, 11717)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: CompanyRegistry Filings (r:1 w:1)
+	/// Proof: CompanyRegistry Filings (max_values: None, max_size: Some(95), added: 2570, mode: MaxEncodedLen)
+	fn mark_delinquent() -> Weight {
+		// NOT BENCHMARKED, estimated from comparable calls
+		Weight::from_parts(12_652_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,31 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v1::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::EnsureOriginWithArg};
+
+fn admin_origin<T: Config>(key: &KeyOf<T>) -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::AdminOrigin::try_successful_origin(key).map_err(|_| BenchmarkError::Weightless)
+}
+
+benchmarks! {
+	set_parameter {
+		let parameter = T::BenchmarkHelper::parameter();
+		let origin = admin_origin::<T>(&parameter.key())?;
+	}: _<T::RuntimeOrigin>(origin, parameter.clone())
+	verify {
+		assert_eq!(Pallet::<T>::parameter(parameter.key()), Some(parameter));
+	}
+
+	reset_parameter {
+		let parameter = T::BenchmarkHelper::parameter();
+		let key = parameter.key();
+		let origin = admin_origin::<T>(&key)?;
+		assert_ok!(Pallet::<T>::set_parameter(origin.clone(), parameter));
+	}: _<T::RuntimeOrigin>(origin, key.clone())
+	verify {
+		assert_eq!(Pallet::<T>::parameter(key), None);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,159 @@
+//! # Liberland Runtime Parameters Pallet
+//!
+//! ## Overview
+//!
+//! Runtime parameters pallet stores governance overrides of values that
+//! would otherwise be compile-time constants, e.g. citizenship LLM minimum or
+//! length of council terms:
+//!
+//! * runtime declares its parameters with [`define_parameters!`] - each has
+//!   a name, a type, a default and optionally a validity check,
+//! * `AdminOrigin` sets and resets values, one parameter at a time. It gets
+//!   the key of the parameter, so the runtime can require a stronger origin
+//!   for sensitive parameters,
+//! * for every parameter, macro generates a type implementing `Get`, which
+//!   returns the stored value or the default if none is set. Pallets read
+//!   parameters through it like any other constant.
+//!
+//! Parameters are typed - the runtime's parameter enum holds the value along
+//! with its key, so a value of a wrong type can't be stored.
+//!
+//! ## Pallet Config
+//!
+//! * `Parameter` - runtime's parameter enum, usually generated by
+//!   [`define_parameters!`]
+//! * `AdminOrigin` - origin that sets and resets given parameter
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_parameter`: Override value of a parameter
+//! * `reset_parameter`: Remove override, restoring the default
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+/// Used by [`define_parameters!`], not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+	pub use codec;
+	pub use frame_support::traits::Get;
+	pub use scale_info;
+	pub use sp_runtime::RuntimeDebug;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::EnsureOriginWithArg};
+	use frame_system::pallet_prelude::*;
+
+	pub type KeyOf<T> = <<T as Config>::Parameter as RuntimeParameter>::Key;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Runtime's parameters along with their values
+		type Parameter: RuntimeParameter;
+
+		/// Origin that sets and resets given parameter
+		type AdminOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, KeyOf<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::Parameter>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Value rejected by parameter's validity check
+		InvalidValue,
+		/// Parameter has no value set
+		NotSet,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Parameter value set
+		ParameterSet { parameter: T::Parameter },
+		/// Parameter value removed, default applies
+		ParameterReset { key: KeyOf<T> },
+	}
+
+	/// Values set by `AdminOrigin`. Parameters without value use defaults
+	/// declared by the runtime.
+	#[pallet::storage]
+	#[pallet::getter(fn parameter)]
+	pub type Parameters<T: Config> =
+		StorageMap<_, Blake2_128Concat, KeyOf<T>, T::Parameter, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set value of a parameter, replacing the previous one.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin` for the
+		/// parameter's key.
+		///
+		/// - `parameter`: Parameter along with its new value
+		///
+		/// Emits `ParameterSet`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_parameter())]
+		pub fn set_parameter(origin: OriginFor<T>, parameter: T::Parameter) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin, &parameter.key())?;
+			ensure!(parameter.is_valid(), Error::<T>::InvalidValue);
+
+			Parameters::<T>::insert(parameter.key(), &parameter);
+			Self::deposit_event(Event::ParameterSet { parameter });
+			Ok(())
+		}
+
+		/// Remove value of a parameter, so its default applies again.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin` for `key`.
+		///
+		/// - `key`: Parameter to reset
+		///
+		/// Emits `ParameterReset`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::reset_parameter())]
+		pub fn reset_parameter(origin: OriginFor<T>, key: KeyOf<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin, &key)?;
+			ensure!(Parameters::<T>::contains_key(&key), Error::<T>::NotSet);
+
+			Parameters::<T>::remove(&key);
+			Self::deposit_event(Event::ParameterReset { key });
+			Ok(())
+		}
+	}
+}
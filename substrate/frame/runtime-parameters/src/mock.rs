@@ -0,0 +1,116 @@
+#![cfg(test)]
+pub use crate as pallet_runtime_parameters;
+
+use frame_support::{
+	ord_parameter_types,
+	traits::{ConstU64, EnsureOrigin, EnsureOriginWithArg},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_core::{ConstU16, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+ord_parameter_types! {
+	pub const Admin: u64 = 1;
+}
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		RuntimeParameters: pallet_runtime_parameters,
+	}
+);
+
+impl frame_system::Config for Test {
+	type AccountData = ();
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Block = Block;
+	type Nonce = u64;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<42>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+pallet_runtime_parameters::define_parameters! {
+	/// Parameters of the mock runtime
+	pub enum MockParameter, MockParameterKey for RuntimeParameters {
+		/// Period, must be non-zero
+		Period: u64 = 10, valid if |v| *v > 0;
+		/// Any amount
+		Amount: u128 = 100;
+		/// Set only by Root
+		Guarded: u64 = 1;
+	}
+}
+
+/// Root sets `Guarded`, admin sets everything else
+pub struct MockAdminOrigin;
+
+impl EnsureOriginWithArg<RuntimeOrigin, MockParameterKey> for MockAdminOrigin {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin, key: &MockParameterKey) -> Result<(), RuntimeOrigin> {
+		match key {
+			MockParameterKey::Guarded => EnsureRoot::<u64>::try_origin(o),
+			_ => EnsureSignedBy::<Admin, u64>::try_origin(o).map(|_| ()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(key: &MockParameterKey) -> Result<RuntimeOrigin, ()> {
+		match key {
+			MockParameterKey::Guarded => EnsureRoot::<u64>::try_successful_origin(),
+			_ => EnsureSignedBy::<Admin, u64>::try_successful_origin(),
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_runtime_parameters::BenchmarkHelper<MockParameter> for MockBenchmarkHelper {
+	fn parameter() -> MockParameter {
+		MockParameter::Period(20)
+	}
+}
+
+impl pallet_runtime_parameters::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Parameter = MockParameter;
+	type AdminOrigin = MockAdminOrigin;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+	});
+	ext
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, RuntimeParameter};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::DispatchError;
+
+#[test]
+fn defaults_apply_until_set() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Period::get(), 10);
+		assert_eq!(Amount::get(), 100);
+
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameter::Period(20)
+		));
+		assert_eq!(Period::get(), 20);
+		assert_eq!(Amount::get(), 100);
+		System::assert_last_event(
+			Event::ParameterSet { parameter: MockParameter::Period(20) }.into(),
+		);
+
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameter::Period(30)
+		));
+		assert_eq!(Period::get(), 30);
+	});
+}
+
+#[test]
+fn set_requires_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeParameters::set_parameter(RuntimeOrigin::signed(2), MockParameter::Period(20)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RuntimeParameters::reset_parameter(RuntimeOrigin::signed(2), MockParameterKey::Period),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn admin_origin_depends_on_key() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeParameters::set_parameter(RuntimeOrigin::signed(1), MockParameter::Guarded(2)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RuntimeParameters::set_parameter(RuntimeOrigin::root(), MockParameter::Period(20)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::root(),
+			MockParameter::Guarded(2)
+		));
+		assert_eq!(Guarded::get(), 2);
+
+		assert_noop!(
+			RuntimeParameters::reset_parameter(RuntimeOrigin::signed(1), MockParameterKey::Guarded),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RuntimeParameters::reset_parameter(
+			RuntimeOrigin::root(),
+			MockParameterKey::Guarded
+		));
+		assert_eq!(Guarded::get(), 1);
+	});
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert!(!MockParameter::Period(0).is_valid());
+		assert!(MockParameter::Amount(0).is_valid());
+		assert_noop!(
+			RuntimeParameters::set_parameter(RuntimeOrigin::signed(1), MockParameter::Period(0)),
+			Error::<Test>::InvalidValue
+		);
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameter::Amount(0)
+		));
+		assert_eq!(Amount::get(), 0);
+	});
+}
+
+#[test]
+fn reset_restores_default() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeParameters::reset_parameter(RuntimeOrigin::signed(1), MockParameterKey::Amount),
+			Error::<Test>::NotSet
+		);
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameter::Amount(5)
+		));
+		assert_ok!(RuntimeParameters::set_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameter::Period(5)
+		));
+
+		assert_ok!(RuntimeParameters::reset_parameter(
+			RuntimeOrigin::signed(1),
+			MockParameterKey::Amount
+		));
+		assert_eq!(Amount::get(), 100);
+		assert_eq!(Period::get(), 5);
+		assert_eq!(RuntimeParameters::parameter(MockParameterKey::Amount), None);
+		System::assert_last_event(Event::ParameterReset { key: MockParameterKey::Amount }.into());
+	});
+}
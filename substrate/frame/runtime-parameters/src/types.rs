@@ -0,0 +1,138 @@
+use codec::MaxEncodedLen;
+use frame_support::Parameter;
+
+/// Parameter of the runtime along with its value, usually generated by
+/// [`define_parameters!`](crate::define_parameters).
+pub trait RuntimeParameter: Parameter + MaxEncodedLen {
+	/// Identifies parameter regardless of its value
+	type Key: Parameter + MaxEncodedLen;
+
+	/// Key of this parameter
+	fn key(&self) -> Self::Key;
+
+	/// Whether value can be set, e.g. periods used as divisors must be
+	/// non-zero
+	fn is_valid(&self) -> bool;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<P> {
+	/// Any valid parameter
+	fn parameter() -> P;
+}
+
+/// Declare runtime parameters stored by the pallet.
+///
+/// Generates:
+/// * parameter enum implementing [`RuntimeParameter`], with a variant holding
+///   the value of each parameter,
+/// * key enum, with a variant for each parameter,
+/// * for each parameter, a type implementing `Get` - it returns the value
+///   stored in `$pallet` or the default if none is set.
+///
+/// Default is evaluated on every read without a stored value. Optional
+/// `valid if` closure gets a reference to the new value and rejects it by
+/// returning `false`.
+///
+/// ```ignore
+/// define_parameters! {
+/// 	pub enum LiberlandParameter, LiberlandParameterKey for RuntimeParameters {
+/// 		/// Length of council term
+/// 		ElectionTermDuration: BlockNumber = 90 * DAYS, valid if |v| *v > 0;
+/// 	}
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_parameters {
+	(
+		$(#[$attr:meta])*
+		pub enum $param:ident, $key:ident for $pallet:ty {
+			$(
+				$(#[$vattr:meta])*
+				$name:ident: $ty:ty = $default:expr $(, valid if |$v:ident| $check:expr)?;
+			)*
+		}
+	) => {
+		$(#[$attr])*
+		#[derive(
+			Clone,
+			PartialEq,
+			Eq,
+			$crate::__private::codec::Encode,
+			$crate::__private::codec::Decode,
+			$crate::__private::codec::MaxEncodedLen,
+			$crate::__private::scale_info::TypeInfo,
+			$crate::__private::RuntimeDebug,
+		)]
+		#[codec(crate = $crate::__private::codec)]
+		#[scale_info(crate = $crate::__private::scale_info)]
+		pub enum $param {
+			$(
+				$(#[$vattr])*
+				$name($ty),
+			)*
+		}
+
+		#[doc = concat!("Keys of [`", stringify!($param), "`]")]
+		#[derive(
+			Clone,
+			PartialEq,
+			Eq,
+			$crate::__private::codec::Encode,
+			$crate::__private::codec::Decode,
+			$crate::__private::codec::MaxEncodedLen,
+			$crate::__private::scale_info::TypeInfo,
+			$crate::__private::RuntimeDebug,
+		)]
+		#[codec(crate = $crate::__private::codec)]
+		#[scale_info(crate = $crate::__private::scale_info)]
+		pub enum $key {
+			$(
+				$(#[$vattr])*
+				$name,
+			)*
+		}
+
+		impl $crate::RuntimeParameter for $param {
+			type Key = $key;
+
+			fn key(&self) -> $key {
+				match self {
+					$(Self::$name(_) => $key::$name,)*
+				}
+			}
+
+			fn is_valid(&self) -> bool {
+				match self {
+					$(Self::$name(_value) => $crate::__parameter_valid!(_value $(, $v, $check)?),)*
+				}
+			}
+		}
+
+		$(
+			$(#[$vattr])*
+			pub struct $name;
+
+			impl $crate::__private::Get<$ty> for $name {
+				fn get() -> $ty {
+					match <$pallet>::parameter($key::$name) {
+						Some($param::$name(value)) => value,
+						_ => $default,
+					}
+				}
+			}
+		)*
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parameter_valid {
+	($value:ident) => {
+		true
+	};
+	($value:ident, $v:ident, $check:expr) => {{
+		let $v = $value;
+		$check
+	}};
+}
@@ -0,0 +1,51 @@
+//! Weights for pallet_runtime_parameters
+//!
+//! NOT BENCHMARKED. Estimated from pallet_price_oracle weights of comparable
+//! operations. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_runtime_parameters`
+//! run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_runtime_parameters.
+pub trait WeightInfo {
+	fn set_parameter() -> Weight;
+	fn reset_parameter() -> Weight;
+}
+
+/// Weights for pallet_runtime_parameters using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `RuntimeParameters::Parameters` (r:0 w:1)
+	fn set_parameter() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RuntimeParameters::Parameters` (r:1 w:1)
+	fn reset_parameter() -> Weight {
+		Weight::from_parts(15_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `RuntimeParameters::Parameters` (r:0 w:1)
+	fn set_parameter() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RuntimeParameters::Parameters` (r:1 w:1)
+	fn reset_parameter() -> Weight {
+		Weight::from_parts(15_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
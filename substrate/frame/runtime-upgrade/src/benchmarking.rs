@@ -0,0 +1,58 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as RuntimeUpgrade;
+use frame_benchmarking::v1::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, StorePreimage},
+};
+use sp_core::H256;
+use sp_std::{borrow::Cow, prelude::*};
+
+// `enact` isn't benchmarked - it needs a real runtime to pass `set_code`
+// checks. Its weight only covers bookkeeping, `set_code` is charged with
+// `frame_system` weights.
+
+fn staged<T: Config>() -> Result<H256, BenchmarkError> {
+	let origin = T::StageOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let code_hash = T::Preimages::note(Cow::from(vec![1u8; 1024]))?;
+	assert_ok!(RuntimeUpgrade::<T>::stage(origin, code_hash));
+	Ok(code_hash)
+}
+
+fn approved<T: Config>() -> Result<H256, BenchmarkError> {
+	let code_hash = staged::<T>()?;
+	let origin =
+		T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	assert_ok!(RuntimeUpgrade::<T>::approve(origin));
+	Ok(code_hash)
+}
+
+benchmarks! {
+	stage {
+		let origin = T::StageOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let code_hash = T::Preimages::note(Cow::from(vec![1u8; 1024]))?;
+	}: _<T::RuntimeOrigin>(origin, code_hash)
+	verify {
+		assert_eq!(RuntimeUpgrade::<T>::staged().unwrap().code_hash, code_hash);
+	}
+
+	approve {
+		staged::<T>()?;
+		let origin = T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(RuntimeUpgrade::<T>::staged().unwrap().enact_at.is_some());
+	}
+
+	cancel {
+		approved::<T>()?;
+		let origin = T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(RuntimeUpgrade::<T>::staged().is_none());
+	}
+}
+
+impl_benchmark_test_suite!(RuntimeUpgrade, crate::mock::new_test_ext(), crate::mock::Test,);
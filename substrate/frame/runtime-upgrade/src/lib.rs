@@ -0,0 +1,271 @@
+//! # Liberland Runtime Upgrade Pallet
+//!
+//! ## Overview
+//!
+//! Runtime upgrade pallet makes runtime upgrades auditable on-chain instead
+//! of dispatching `set_code` directly with Root origin:
+//!
+//! * new runtime code is noted as a preimage, e.g. through
+//!   `pallet_preimage_pool`,
+//! * `StageOrigin`, e.g. a Congress member, stages the upgrade by the hash of
+//!   the code. Only one upgrade can be staged at a time,
+//! * `ApproveOrigin`, e.g. Congress majority, approves it. Approved upgrade
+//!   is scheduled `EnactmentDelay` blocks later, so that everyone can review
+//!   the code before it's enacted,
+//! * during the delay `CancelOrigin` can cancel the upgrade,
+//! * scheduler enacts the upgrade with Root origin. Code goes through
+//!   `frame_system::Pallet::set_code`, so it must keep the spec name and
+//!   increase the spec version.
+//!
+//! If enactment fails, the upgrade stays staged until it's cancelled.
+//!
+//! ## Pallet Config
+//!
+//! * `RuntimeCall` - the overarching call type, enactment is scheduled as
+//!   this type
+//! * `PalletsOrigin` - the caller origin, enactment is scheduled with its
+//!   Root variant
+//! * `Scheduler` - scheduler enacting approved upgrades
+//! * `Preimages` - storage of runtime code preimages
+//! * `StageOrigin` - origin that stages upgrades
+//! * `ApproveOrigin` - origin that approves staged upgrades
+//! * `CancelOrigin` - origin that cancels staged upgrades
+//! * `EnactmentDelay` - number of blocks between approval and enactment
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `stage`: Stage upgrade to noted runtime code
+//! * `approve`: Approve staged upgrade and schedule its enactment
+//! * `cancel`: Drop staged upgrade
+//! * `enact`: Set the approved runtime code, dispatched by scheduler
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			schedule::{v3::Named as ScheduleNamed, DispatchTime},
+			QueryPreimage, StorePreimage,
+		},
+	};
+	use frame_system::{pallet_prelude::*, WeightInfo as _};
+	use sp_core::H256;
+	use sp_runtime::traits::Saturating;
+
+	const UPGRADE_ID: [u8; 8] = *b"rtupgrad";
+
+	pub type CallOf<T> = <T as Config>::RuntimeCall;
+	pub type StagedUpgradeOf<T> = StagedUpgrade<BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type.
+		type RuntimeCall: Parameter
+			+ From<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The caller origin, overarching type of all pallets origins.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// Scheduler enacting approved upgrades
+		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self>, Self::PalletsOrigin>;
+
+		/// Storage of runtime code preimages
+		type Preimages: QueryPreimage + StorePreimage;
+
+		/// Origin that stages upgrades
+		type StageOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that approves staged upgrades
+		type ApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that cancels staged upgrades
+		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of blocks between approval and enactment
+		#[pallet::constant]
+		type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Another upgrade is already staged
+		AlreadyStaged,
+		/// No upgrade is staged
+		NothingStaged,
+		/// Runtime code preimage isn't noted
+		PreimageMissing,
+		/// Staged upgrade is already approved
+		AlreadyApproved,
+		/// Staged upgrade isn't approved
+		NotApproved,
+		/// Enactment delay didn't pass yet
+		TooEarly,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Upgrade staged
+		Staged { code_hash: H256, code_len: u32 },
+		/// Upgrade approved and scheduled
+		Approved { code_hash: H256, enact_at: BlockNumberFor<T> },
+		/// Upgrade cancelled
+		Cancelled { code_hash: H256 },
+		/// Runtime code set
+		Enacted { code_hash: H256 },
+	}
+
+	/// Upgrade waiting for approval or enactment
+	#[pallet::storage]
+	#[pallet::getter(fn staged)]
+	pub type Staged<T: Config> = StorageValue<_, StagedUpgradeOf<T>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stage upgrade to runtime code noted as preimage. Preimage is
+		/// requested until the upgrade is enacted or cancelled.
+		///
+		/// The dispatch origin of this call must be `StageOrigin`.
+		///
+		/// - `code_hash`: Hash of the new runtime code
+		///
+		/// Emits `Staged`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::stage())]
+		pub fn stage(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+			T::StageOrigin::ensure_origin(origin)?;
+			ensure!(!Staged::<T>::exists(), Error::<T>::AlreadyStaged);
+			let code_len = T::Preimages::len(&code_hash).ok_or(Error::<T>::PreimageMissing)?;
+
+			T::Preimages::request(&code_hash);
+			Staged::<T>::put(StagedUpgrade { code_hash, code_len, enact_at: None });
+
+			Self::deposit_event(Event::Staged { code_hash, code_len });
+			Ok(())
+		}
+
+		/// Approve staged upgrade and schedule its enactment `EnactmentDelay`
+		/// blocks from now.
+		///
+		/// The dispatch origin of this call must be `ApproveOrigin`.
+		///
+		/// Emits `Approved`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(origin: OriginFor<T>) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let mut upgrade = Self::staged().ok_or(Error::<T>::NothingStaged)?;
+			ensure!(upgrade.enact_at.is_none(), Error::<T>::AlreadyApproved);
+
+			let enact_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+			let call = T::Preimages::bound(CallOf::<T>::from(Call::<T>::enact {}))?;
+			T::Scheduler::schedule_named(
+				Self::task_name(upgrade.code_hash),
+				DispatchTime::At(enact_at),
+				None,
+				63,
+				frame_system::RawOrigin::Root.into(),
+				call,
+			)?;
+			upgrade.enact_at = Some(enact_at);
+			Staged::<T>::put(upgrade);
+
+			Self::deposit_event(Event::Approved { code_hash: upgrade.code_hash, enact_at });
+			Ok(())
+		}
+
+		/// Drop staged upgrade, whether it's approved or not.
+		///
+		/// The dispatch origin of this call must be `CancelOrigin`.
+		///
+		/// Emits `Cancelled`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>) -> DispatchResult {
+			T::CancelOrigin::ensure_origin(origin)?;
+			let upgrade = Staged::<T>::take().ok_or(Error::<T>::NothingStaged)?;
+
+			if upgrade.enact_at.is_some() {
+				// enactment may have already been dispatched and failed
+				let _ = T::Scheduler::cancel_named(Self::task_name(upgrade.code_hash));
+			}
+			T::Preimages::unrequest(&upgrade.code_hash);
+
+			Self::deposit_event(Event::Cancelled { code_hash: upgrade.code_hash });
+			Ok(())
+		}
+
+		/// Set the approved runtime code once enactment delay passed.
+		///
+		/// The dispatch origin of this call must be _Root_. It's dispatched by
+		/// the scheduler on approval.
+		///
+		/// Emits `Enacted`.
+		#[pallet::call_index(3)]
+		#[pallet::weight((
+			T::WeightInfo::enact()
+				.saturating_add(<T as frame_system::Config>::SystemWeightInfo::set_code()),
+			DispatchClass::Operational,
+		))]
+		pub fn enact(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin.clone())?;
+			let upgrade = Staged::<T>::take().ok_or(Error::<T>::NothingStaged)?;
+			let enact_at = upgrade.enact_at.ok_or(Error::<T>::NotApproved)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= enact_at, Error::<T>::TooEarly);
+
+			let code = T::Preimages::fetch(&upgrade.code_hash, Some(upgrade.code_len))
+				.map_err(|_| Error::<T>::PreimageMissing)?;
+			frame_system::Pallet::<T>::set_code(origin, code.into_owned()).map_err(|e| e.error)?;
+			T::Preimages::unrequest(&upgrade.code_hash);
+
+			Self::deposit_event(Event::Enacted { code_hash: upgrade.code_hash });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn task_name(code_hash: H256) -> [u8; 32] {
+			(UPGRADE_ID, code_hash).using_encoded(sp_io::hashing::blake2_256)
+		}
+	}
+}
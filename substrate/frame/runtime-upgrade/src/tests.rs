@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, StagedUpgrade};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnInitialize, QueryPreimage},
+};
+use sp_core::{storage::well_known_keys, H256};
+use sp_runtime::DispatchError;
+
+const CODE: &[u8] = b"new runtime code";
+
+fn code_hash() -> H256 {
+	sp_io::hashing::blake2_256(CODE).into()
+}
+
+fn note_code() {
+	assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(4), CODE.to_vec()));
+}
+
+fn stage() {
+	note_code();
+	assert_ok!(RuntimeUpgrade::stage(RuntimeOrigin::signed(1), code_hash()));
+}
+
+fn run_to_block(n: u64) {
+	for b in System::block_number() + 1..=n {
+		System::set_block_number(b);
+		Scheduler::on_initialize(b);
+	}
+}
+
+fn current_code() -> Option<Vec<u8>> {
+	sp_io::storage::get(well_known_keys::CODE).map(|c| c.to_vec())
+}
+
+#[test]
+fn stage_requires_noted_preimage() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::stage(RuntimeOrigin::signed(2), code_hash()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RuntimeUpgrade::stage(RuntimeOrigin::signed(1), code_hash()),
+			Error::<Test>::PreimageMissing
+		);
+		stage();
+		assert_eq!(
+			RuntimeUpgrade::staged(),
+			Some(StagedUpgrade {
+				code_hash: code_hash(),
+				code_len: CODE.len() as u32,
+				enact_at: None
+			})
+		);
+		assert!(Preimage::is_requested(&code_hash()));
+		System::assert_last_event(
+			Event::Staged { code_hash: code_hash(), code_len: CODE.len() as u32 }.into(),
+		);
+		assert_noop!(
+			RuntimeUpgrade::stage(RuntimeOrigin::signed(1), code_hash()),
+			Error::<Test>::AlreadyStaged
+		);
+	});
+}
+
+#[test]
+fn approved_upgrade_is_enacted_after_delay() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::approve(RuntimeOrigin::signed(2)),
+			Error::<Test>::NothingStaged
+		);
+		stage();
+		assert_noop!(RuntimeUpgrade::approve(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(RuntimeUpgrade::approve(RuntimeOrigin::signed(2)));
+		assert_eq!(RuntimeUpgrade::staged().unwrap().enact_at, Some(11));
+		System::assert_last_event(Event::Approved { code_hash: code_hash(), enact_at: 11 }.into());
+		assert_noop!(
+			RuntimeUpgrade::approve(RuntimeOrigin::signed(2)),
+			Error::<Test>::AlreadyApproved
+		);
+
+		run_to_block(10);
+		assert_eq!(current_code(), None);
+		run_to_block(11);
+		assert_eq!(current_code(), Some(CODE.to_vec()));
+		System::assert_has_event(frame_system::Event::CodeUpdated.into());
+		System::assert_has_event(Event::Enacted { code_hash: code_hash() }.into());
+		assert_eq!(RuntimeUpgrade::staged(), None);
+		assert!(!Preimage::is_requested(&code_hash()));
+	});
+}
+
+#[test]
+fn enact_requires_approval_and_delay() {
+	new_test_ext().execute_with(|| {
+		stage();
+		assert_noop!(RuntimeUpgrade::enact(RuntimeOrigin::signed(2)), DispatchError::BadOrigin);
+		assert_noop!(RuntimeUpgrade::enact(RuntimeOrigin::root()), Error::<Test>::NotApproved);
+		assert_ok!(RuntimeUpgrade::approve(RuntimeOrigin::signed(2)));
+		run_to_block(5);
+		assert_noop!(RuntimeUpgrade::enact(RuntimeOrigin::root()), Error::<Test>::TooEarly);
+	});
+}
+
+#[test]
+fn cancelled_upgrade_is_not_enacted() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::cancel(RuntimeOrigin::signed(3)),
+			Error::<Test>::NothingStaged
+		);
+		stage();
+		assert_ok!(RuntimeUpgrade::approve(RuntimeOrigin::signed(2)));
+		assert_noop!(RuntimeUpgrade::cancel(RuntimeOrigin::signed(2)), DispatchError::BadOrigin);
+		assert_ok!(RuntimeUpgrade::cancel(RuntimeOrigin::signed(3)));
+		System::assert_last_event(Event::Cancelled { code_hash: code_hash() }.into());
+		assert_eq!(RuntimeUpgrade::staged(), None);
+		assert!(!Preimage::is_requested(&code_hash()));
+
+		run_to_block(11);
+		assert_eq!(current_code(), None);
+
+		// another upgrade can be staged
+		assert_ok!(RuntimeUpgrade::stage(RuntimeOrigin::signed(1), code_hash()));
+	});
+}
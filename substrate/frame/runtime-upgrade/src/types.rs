@@ -0,0 +1,15 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+
+/// Runtime upgrade waiting for approval or enactment
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct StagedUpgrade<BlockNumber> {
+	/// Hash of the new runtime code preimage
+	pub code_hash: H256,
+	/// Length of the new runtime code
+	pub code_len: u32,
+	/// Block at which approved upgrade is enacted, `None` until approved
+	pub enact_at: Option<BlockNumber>,
+}
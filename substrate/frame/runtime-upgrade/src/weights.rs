@@ -0,0 +1,72 @@
+//! Weights for pallet_runtime_upgrade
+//!
+//! NOT BENCHMARKED. Estimated from pallet_democracy and pallet_preimage_pool
+//! weights of comparable operations. `enact` covers only the bookkeeping -
+//! `set_code` itself is charged with `frame_system` weights. Replace with
+//! output of `substrate/scripts/run_liberland_benchmarks.sh -p
+//! pallet_runtime_upgrade` run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_runtime_upgrade.
+pub trait WeightInfo {
+	fn stage() -> Weight;
+	fn approve() -> Weight;
+	fn cancel() -> Weight;
+	fn enact() -> Weight;
+}
+
+/// Weights for pallet_runtime_upgrade using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn stage() -> Weight {
+		Weight::from_parts(25_000_000, 3556)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(40_000_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn cancel() -> Weight {
+		Weight::from_parts(45_000_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn enact() -> Weight {
+		Weight::from_parts(30_000_000, 3556)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn stage() -> Weight {
+		Weight::from_parts(25_000_000, 3556)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(40_000_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn cancel() -> Weight {
+		Weight::from_parts(45_000_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn enact() -> Weight {
+		Weight::from_parts(30_000_000, 3556)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
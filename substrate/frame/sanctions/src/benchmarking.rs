@@ -0,0 +1,73 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{fungible::Mutate, EnsureOriginWithArg, Get, Hooks},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Bounded, Saturating};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const FLAGS: FreezeFlags = FreezeFlags { lld: true, llm: true };
+
+fn sanction_origin<T: Config>(who: &T::AccountId) -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::SanctionOrigin::try_successful_origin(who).map_err(|_| BenchmarkError::Weightless)
+}
+
+fn funded<T: Config>(i: u32) -> T::AccountId {
+	let who: T::AccountId = account("sanctioned", i, SEED);
+	<T as Config>::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+fn case<T: Config>() -> CaseRefOf<T> {
+	vec![1u8; T::MaxCaseRefLen::get() as usize].try_into().unwrap()
+}
+
+fn expiry<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number().saturating_add(10u32.into())
+}
+
+benchmarks! {
+	sanction {
+		let who = funded::<T>(0);
+		let origin = sanction_origin::<T>(&who)?;
+		// worst case - replacing sanction with different expiry
+		assert_ok!(Pallet::<T>::sanction(origin.clone(), who.clone(), FLAGS, case::<T>(), Some(expiry::<T>())));
+		let expires_at = Some(expiry::<T>().saturating_add(1u32.into()));
+	}: _<T::RuntimeOrigin>(origin, who.clone(), FLAGS, case::<T>(), expires_at)
+	verify {
+		assert_eq!(Pallet::<T>::sanctions(&who).unwrap().expires_at, expires_at);
+	}
+
+	lift_sanction {
+		let who = funded::<T>(0);
+		let origin = sanction_origin::<T>(&who)?;
+		assert_ok!(Pallet::<T>::sanction(origin.clone(), who.clone(), FLAGS, case::<T>(), Some(expiry::<T>())));
+	}: _<T::RuntimeOrigin>(origin, who.clone())
+	verify {
+		assert!(Pallet::<T>::sanctions(&who).is_none());
+	}
+
+	on_initialize {
+		let e in 0 .. T::MaxExpiriesPerBlock::get();
+		let at = expiry::<T>();
+		for i in 0..e {
+			let who = funded::<T>(i);
+			let origin = sanction_origin::<T>(&who)?;
+			assert_ok!(Pallet::<T>::sanction(origin, who, FLAGS, case::<T>(), Some(at)));
+		}
+		frame_system::Pallet::<T>::set_block_number(at);
+	}: {
+		Pallet::<T>::on_initialize(at);
+	}
+	verify {
+		assert!(Pallet::<T>::expiring(at).is_empty());
+		assert_eq!(Sanctions::<T>::iter().count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
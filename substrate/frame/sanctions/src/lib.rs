@@ -0,0 +1,274 @@
+//! # Liberland Sanctions Pallet
+//!
+//! ## Overview
+//!
+//! Sanctions pallet keeps a registry of accounts frozen pending judgement.
+//! Each entry has freeze flags, reference of the case it was ordered in and
+//! optional expiry block:
+//!
+//! * with `lld` flag, whole LLD balance of the account is frozen using
+//!   `Currency`'s freeze with `FreezeIdentifier` id,
+//! * with `llm` flag, whole LLM balance of the account is frozen. This
+//!   pallet has to be configured as `pallet_assets::Config::Freezer` for
+//!   that.
+//!
+//! Sanctioned accounts can't send frozen tokens, but can still receive them.
+//! Entries with expiry block are lifted automatically in `on_initialize`.
+//!
+//! Note that LLD freeze is stored on the account's balance, so it's dropped
+//! if the account is reaped. Sanction LLD accounts after they're funded.
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - LLD, has to support freezes
+//! * `FreezeIdentifier` - id of the LLD freeze
+//! * `LLMAssetId` - asset id of LLM
+//! * `SanctionOrigin` - origin that sanctions given account and lifts its
+//!   sanction, e.g. Congress or court ruling against the account
+//! * `MaxCaseRefLen` - maximum length of case reference
+//! * `MaxExpiriesPerBlock` - maximum number of sanctions expiring at a single
+//!   block
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `sanction`: Freeze account or update its sanction
+//! * `lift_sanction`: Unfreeze account
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::fungible;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{InspectFreeze, MutateFreeze},
+			EnsureOriginWithArg,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Bounded;
+
+	pub type CaseRefOf<T> = BoundedVec<u8, <T as Config>::MaxCaseRefLen>;
+	pub type SanctionOf<T> = Sanction<BlockNumberFor<T>, <T as Config>::MaxCaseRefLen>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_assets::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// LLD
+		type Currency: MutateFreeze<Self::AccountId> + fungible::Mutate<Self::AccountId>;
+
+		/// Id of the LLD freeze
+		#[pallet::constant]
+		type FreezeIdentifier: Get<
+			<<Self as Config>::Currency as InspectFreeze<Self::AccountId>>::Id,
+		>;
+
+		/// Asset id of LLM
+		#[pallet::constant]
+		type LLMAssetId: Get<Self::AssetId>;
+
+		/// Origin that sanctions given account and lifts its sanction
+		type SanctionOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, Self::AccountId>;
+
+		/// Maximum length of case reference
+		#[pallet::constant]
+		type MaxCaseRefLen: Get<u32>;
+
+		/// Maximum number of sanctions expiring at a single block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sanction must freeze LLD, LLM or both
+		NoFlags,
+		/// Expiry must be in the future
+		InvalidExpiry,
+		/// Account isn't sanctioned
+		NotSanctioned,
+		/// Too many sanctions already expire at given block
+		TooManyExpiries,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Account sanctioned or its sanction updated
+		Sanctioned {
+			who: T::AccountId,
+			flags: FreezeFlags,
+			case: CaseRefOf<T>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// Sanction lifted by `SanctionOrigin`
+		SanctionLifted { who: T::AccountId },
+		/// Sanction lifted at its expiry block
+		SanctionExpired { who: T::AccountId },
+	}
+
+	/// Sanctioned accounts
+	#[pallet::storage]
+	#[pallet::getter(fn sanctions)]
+	pub type Sanctions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SanctionOf<T>, OptionQuery>;
+
+	/// Accounts with sanctions expiring at given block
+	#[pallet::storage]
+	#[pallet::getter(fn expiring)]
+	pub type Expiring<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Lifts sanctions expiring at this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = Expiring::<T>::take(now);
+			for who in expiring.iter() {
+				if Self::sanctions(who).map_or(false, |s| s.expires_at == Some(now)) {
+					Self::unfreeze(who);
+					Self::deposit_event(Event::SanctionExpired { who: who.clone() });
+				}
+			}
+			<T as Config>::WeightInfo::on_initialize(expiring.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Freeze account's LLD and/or LLM. If the account is already
+		/// sanctioned, its sanction is replaced.
+		///
+		/// The dispatch origin of this call must be `SanctionOrigin` for `who`.
+		///
+		/// - `who`: Account to sanction
+		/// - `flags`: Tokens to freeze
+		/// - `case`: Reference of the case the sanction was ordered in
+		/// - `expires_at`: Block at which sanction is lifted automatically
+		///
+		/// Emits `Sanctioned`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::sanction())]
+		pub fn sanction(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			flags: FreezeFlags,
+			case: CaseRefOf<T>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::SanctionOrigin::ensure_origin(origin, &who)?;
+			ensure!(!flags.is_empty(), Error::<T>::NoFlags);
+
+			if let Some(previous) = Self::sanctions(&who).and_then(|s| s.expires_at) {
+				Expiring::<T>::mutate(previous, |expiring| expiring.retain(|a| *a != who));
+			}
+			if let Some(at) = expires_at {
+				ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+				Expiring::<T>::try_append(at, &who).map_err(|_| Error::<T>::TooManyExpiries)?;
+			}
+
+			if flags.lld {
+				<T as Config>::Currency::set_freeze(
+					&T::FreezeIdentifier::get(),
+					&who,
+					BalanceOf::<T>::max_value(),
+				)?;
+			} else {
+				<T as Config>::Currency::thaw(&T::FreezeIdentifier::get(), &who)?;
+			}
+			Sanctions::<T>::insert(&who, SanctionOf::<T> { flags, case: case.clone(), expires_at });
+
+			Self::deposit_event(Event::Sanctioned { who, flags, case, expires_at });
+			Ok(())
+		}
+
+		/// Lift account's sanction before it expires.
+		///
+		/// The dispatch origin of this call must be `SanctionOrigin` for `who`.
+		///
+		/// - `who`: Sanctioned account
+		///
+		/// Emits `SanctionLifted`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::lift_sanction())]
+		pub fn lift_sanction(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::SanctionOrigin::ensure_origin(origin, &who)?;
+			let sanction = Self::sanctions(&who).ok_or(Error::<T>::NotSanctioned)?;
+
+			if let Some(at) = sanction.expires_at {
+				Expiring::<T>::mutate(at, |expiring| expiring.retain(|a| *a != who));
+			}
+			Self::unfreeze(&who);
+
+			Self::deposit_event(Event::SanctionLifted { who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn unfreeze(who: &T::AccountId) {
+			Sanctions::<T>::remove(who);
+			// thawing account without a freeze is a no-op
+			let _ = <T as Config>::Currency::thaw(&T::FreezeIdentifier::get(), who);
+		}
+	}
+
+	impl<T: Config> pallet_assets::FrozenBalance<T::AssetId, T::AccountId, T::Balance> for Pallet<T> {
+		/// Whole LLM balance of sanctioned accounts is frozen.
+		fn frozen_balance(asset: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+			let frozen = Self::sanctions(who).map_or(false, |s| s.flags.llm);
+			if asset != T::LLMAssetId::get() || !frozen {
+				return None
+			}
+			Some(pallet_assets::Pallet::<T>::balance(asset, who))
+		}
+
+		fn died(_: T::AssetId, _: &T::AccountId) {}
+	}
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::{mock::*, CaseRefOf, Error, Event, FreezeFlags};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::{DispatchError, TokenError};
+
+const LLD: FreezeFlags = FreezeFlags { lld: true, llm: false };
+const LLM: FreezeFlags = FreezeFlags { lld: false, llm: true };
+const BOTH: FreezeFlags = FreezeFlags { lld: true, llm: true };
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		Sanctions::on_initialize(next);
+	}
+}
+
+fn case(c: &[u8]) -> CaseRefOf<Test> {
+	c.to_vec().try_into().unwrap()
+}
+
+fn sanction(who: u64, flags: FreezeFlags, expires_at: Option<u64>) {
+	assert_ok!(Sanctions::sanction(
+		RuntimeOrigin::signed(1),
+		who,
+		flags,
+		case(b"case"),
+		expires_at
+	));
+}
+
+fn send_lld(from: u64, to: u64, value: u64) -> Result<(), DispatchError> {
+	Balances::transfer_keep_alive(RuntimeOrigin::signed(from), to, value).map_err(|e| e.into())
+}
+
+fn send_llm(from: u64, to: u64, amount: u64) -> Result<(), DispatchError> {
+	Assets::transfer(RuntimeOrigin::signed(from), 1.into(), to, amount)
+}
+
+#[test]
+fn sanction_requires_sanction_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Sanctions::sanction(RuntimeOrigin::signed(2), 3, BOTH, case(b"case"), None),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Sanctions::lift_sanction(RuntimeOrigin::signed(2), 3),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sanction_validates() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Sanctions::sanction(RuntimeOrigin::signed(1), 2, Default::default(), case(b"c"), None),
+			Error::<Test>::NoFlags
+		);
+		assert_noop!(
+			Sanctions::sanction(RuntimeOrigin::signed(1), 2, BOTH, case(b"c"), Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		sanction(2, BOTH, Some(5));
+		sanction(3, BOTH, Some(5));
+		assert_noop!(
+			Sanctions::sanction(RuntimeOrigin::signed(1), 4, BOTH, case(b"c"), Some(5)),
+			Error::<Test>::TooManyExpiries
+		);
+	});
+}
+
+#[test]
+fn sanction_works() {
+	new_test_ext().execute_with(|| {
+		sanction(2, BOTH, Some(5));
+		let sanction = Sanctions::sanctions(2).unwrap();
+		assert_eq!(sanction.flags, BOTH);
+		assert_eq!(sanction.case, case(b"case"));
+		assert_eq!(sanction.expires_at, Some(5));
+		assert_eq!(Sanctions::expiring(5).into_inner(), vec![2]);
+		System::assert_last_event(
+			Event::Sanctioned { who: 2, flags: BOTH, case: case(b"case"), expires_at: Some(5) }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn frozen_lld_cant_be_sent() {
+	new_test_ext().execute_with(|| {
+		sanction(2, LLD, None);
+		assert_noop!(send_lld(2, 3, 10), TokenError::Frozen);
+		assert_ok!(send_lld(3, 2, 10));
+		assert_eq!(Balances::free_balance(2), 110);
+		assert_ok!(send_llm(2, 3, 10));
+	});
+}
+
+#[test]
+fn frozen_llm_cant_be_sent() {
+	new_test_ext().execute_with(|| {
+		sanction(2, LLM, None);
+		assert_noop!(send_llm(2, 3, 10), pallet_assets::Error::<Test>::BalanceLow);
+		assert_ok!(send_llm(3, 2, 10));
+		assert_eq!(Assets::balance(1, 2), 110);
+		// other assets aren't frozen
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 2.into(), 3, 10));
+		assert_ok!(send_lld(2, 3, 10));
+	});
+}
+
+#[test]
+fn sanction_can_be_updated() {
+	new_test_ext().execute_with(|| {
+		sanction(2, BOTH, Some(5));
+		sanction(2, LLM, Some(7));
+		assert!(Sanctions::expiring(5).is_empty());
+		assert_eq!(Sanctions::expiring(7).into_inner(), vec![2]);
+		assert_ok!(send_lld(2, 3, 10));
+		assert_noop!(send_llm(2, 3, 10), pallet_assets::Error::<Test>::BalanceLow);
+
+		sanction(2, LLD, None);
+		assert!(Sanctions::expiring(7).is_empty());
+		assert_noop!(send_lld(2, 3, 10), TokenError::Frozen);
+		assert_ok!(send_llm(2, 3, 10));
+	});
+}
+
+#[test]
+fn lift_sanction_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Sanctions::lift_sanction(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NotSanctioned
+		);
+		sanction(2, BOTH, Some(5));
+		assert_ok!(Sanctions::lift_sanction(RuntimeOrigin::signed(1), 2));
+		assert!(Sanctions::sanctions(2).is_none());
+		assert!(Sanctions::expiring(5).is_empty());
+		System::assert_last_event(Event::SanctionLifted { who: 2 }.into());
+		assert_ok!(send_lld(2, 3, 10));
+		assert_ok!(send_llm(2, 3, 10));
+	});
+}
+
+#[test]
+fn sanctions_expire() {
+	new_test_ext().execute_with(|| {
+		sanction(2, BOTH, Some(5));
+		run_to_block(4);
+		assert_noop!(send_lld(2, 3, 10), TokenError::Frozen);
+		run_to_block(5);
+		assert!(Sanctions::sanctions(2).is_none());
+		System::assert_last_event(Event::SanctionExpired { who: 2 }.into());
+		assert_ok!(send_lld(2, 3, 10));
+		assert_ok!(send_llm(2, 3, 10));
+	});
+}
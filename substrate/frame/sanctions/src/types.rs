@@ -0,0 +1,33 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Tokens a sanctioned account can't send
+#[derive(
+	Clone, Copy, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq,
+)]
+pub struct FreezeFlags {
+	/// Freeze whole LLD balance
+	pub lld: bool,
+	/// Freeze whole LLM balance
+	pub llm: bool,
+}
+
+impl FreezeFlags {
+	pub fn is_empty(&self) -> bool {
+		!self.lld && !self.llm
+	}
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[scale_info(skip_type_params(MaxCaseRefLen))]
+pub struct Sanction<BlockNumber, MaxCaseRefLen: Get<u32>> {
+	pub flags: FreezeFlags,
+	/// Reference of the case the sanction was ordered in, e.g. court case
+	/// index or document hash
+	pub case: BoundedVec<u8, MaxCaseRefLen>,
+	/// Block at which sanction is lifted automatically, `None` if it stays
+	/// until lifted by `SanctionOrigin`
+	pub expires_at: Option<BlockNumber>,
+}
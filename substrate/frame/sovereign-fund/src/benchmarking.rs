@@ -0,0 +1,79 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as SovereignFund;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get, Hooks},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Bounded, Saturating};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn spend_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn fund_pot<T: Config>() {
+	T::Currency::make_free_balance_be(
+		&SovereignFund::<T>::pot_account(),
+		BalanceOf::<T>::max_value() / 4u32.into(),
+	);
+}
+
+fn payout_block<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number().saturating_add(10u32.into())
+}
+
+/// Schedules `n` spends of existential deposit at `payout_block`
+fn scheduled<T: Config>(n: u32) -> Result<(), BenchmarkError> {
+	let origin = spend_origin::<T>()?;
+	for i in 0..n {
+		assert_ok!(SovereignFund::<T>::schedule_spend(
+			origin.clone(),
+			account("beneficiary", i, SEED),
+			T::Currency::minimum_balance(),
+			payout_block::<T>(),
+		));
+	}
+	Ok(())
+}
+
+benchmarks! {
+	schedule_spend {
+		scheduled::<T>(T::MaxPayoutsPerBlock::get().saturating_sub(1))?;
+		let origin = spend_origin::<T>()?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let amount = T::Currency::minimum_balance();
+		let payout_at = payout_block::<T>();
+	}: _<T::RuntimeOrigin>(origin, beneficiary, amount, payout_at)
+	verify {
+		assert_eq!(SovereignFund::<T>::payouts(payout_at).len() as u32, T::MaxPayoutsPerBlock::get());
+	}
+
+	cancel_spend {
+		scheduled::<T>(T::MaxPayoutsPerBlock::get())?;
+		let origin = spend_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert!(!Spends::<T>::contains_key(0));
+	}
+
+	on_initialize {
+		let p in 0 .. T::MaxPayoutsPerBlock::get();
+		fund_pot::<T>();
+		scheduled::<T>(p)?;
+		let payout_at = payout_block::<T>();
+		frame_system::Pallet::<T>::set_block_number(payout_at);
+	}: {
+		SovereignFund::<T>::on_initialize(payout_at);
+	}
+	verify {
+		assert_eq!(Spends::<T>::iter().count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(SovereignFund, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,304 @@
+//! # Liberland Sovereign Fund Pallet
+//!
+//! ## Overview
+//!
+//! Sovereign fund pallet holds a pot of LLD, separate from the treasury,
+//! that can only be spent within fixed rules:
+//!
+//! * spends are scheduled by `SpendOrigin` (Congress) for a future block,
+//! * payouts are made in `on_initialize` of the scheduled block,
+//! * no more than `MaxSpendPerPeriod` is paid out in a single spend period.
+//!   Payouts that don't fit under the cap are deferred to the first block of
+//!   the next period,
+//! * scheduled spends can be cancelled by `SpendOrigin` before they're paid.
+//!
+//! Anyone can fund the pot by transferring LLD to `Pallet::pot_account()`.
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency held by the fund
+//! * `PalletId` - PalletId used to derive pot account
+//! * `SpendOrigin` - origin allowed to schedule and cancel spends
+//! * `SpendPeriod` - length of spend period in blocks
+//! * `MaxSpendPerPeriod` - maximum amount paid out in a single spend period
+//! * `MaxPayoutsPerBlock` - maximum number of payouts scheduled for a single
+//!   block
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `schedule_spend`: Schedule payout from the pot
+//! * `cancel_spend`: Cancel scheduled payout
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::Currency;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	pub type SpendOf<T> =
+		Spend<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency held by the fund
+		type Currency: Currency<Self::AccountId>;
+
+		/// PalletId used to derive pot account
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin that can schedule and cancel spends
+		type SpendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Length of spend period in blocks
+		#[pallet::constant]
+		type SpendPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum amount paid out in a single spend period
+		#[pallet::constant]
+		type MaxSpendPerPeriod: Get<BalanceOf<Self>>;
+
+		/// Maximum number of payouts scheduled for a single block
+		#[pallet::constant]
+		type MaxPayoutsPerBlock: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Payout block must be in the future
+		PayoutInPast,
+		/// Amount is above `MaxSpendPerPeriod` and could never be paid
+		AboveSpendCap,
+		/// Too many payouts already scheduled for this block
+		TooManyPayouts,
+		/// Spend doesn't exist or was already paid
+		UnknownSpend,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Spend scheduled
+		SpendScheduled {
+			index: SpendIndex,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			payout_at: BlockNumberFor<T>,
+		},
+		/// Scheduled spend cancelled
+		SpendCancelled { index: SpendIndex },
+		/// Spend paid out of the pot
+		SpendPaid { index: SpendIndex, beneficiary: T::AccountId, amount: BalanceOf<T> },
+		/// Spend didn't fit under current period's cap and was moved to the
+		/// next period
+		SpendDeferred { index: SpendIndex, payout_at: BlockNumberFor<T> },
+		/// Payout failed and spend was dropped - usually because pot doesn't
+		/// have enough funds
+		SpendFailed { index: SpendIndex },
+	}
+
+	/// Number of spends ever scheduled, used as index of the next one
+	#[pallet::storage]
+	#[pallet::getter(fn spend_count)]
+	pub type SpendCount<T: Config> = StorageValue<_, SpendIndex, ValueQuery>;
+
+	/// Spends waiting for payout
+	#[pallet::storage]
+	#[pallet::getter(fn spends)]
+	pub type Spends<T: Config> = StorageMap<_, Twox64Concat, SpendIndex, SpendOf<T>, OptionQuery>;
+
+	/// Indexes of spends to pay out at given block
+	#[pallet::storage]
+	#[pallet::getter(fn payouts)]
+	pub type Payouts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<SpendIndex, T::MaxPayoutsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Amount paid out in the current spend period
+	#[pallet::storage]
+	#[pallet::getter(fn spent_in_period)]
+	pub type SpentInPeriod<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Resets the cap at the start of each spend period and pays out
+		/// spends scheduled for this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			if Self::is_period_start(now) {
+				SpentInPeriod::<T>::kill();
+			}
+
+			let payouts = Payouts::<T>::take(now);
+			for index in payouts.iter() {
+				if let Some(spend) = Spends::<T>::get(index) {
+					Self::pay(*index, spend, now);
+				}
+			}
+			T::WeightInfo::on_initialize(payouts.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule payout from the pot.
+		///
+		/// The dispatch origin of this call must be `SpendOrigin`.
+		///
+		/// - `beneficiary`: Account receiving the payout
+		/// - `amount`: Amount to pay, must not exceed `MaxSpendPerPeriod`
+		/// - `payout_at`: Future block at which payout will be made
+		///
+		/// Emits `SpendScheduled`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::schedule_spend())]
+		pub fn schedule_spend(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			payout_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::SpendOrigin::ensure_origin(origin)?;
+			ensure!(
+				payout_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::PayoutInPast
+			);
+			ensure!(amount <= T::MaxSpendPerPeriod::get(), Error::<T>::AboveSpendCap);
+
+			let index = Self::spend_count();
+			Payouts::<T>::try_append(payout_at, index).map_err(|_| Error::<T>::TooManyPayouts)?;
+			Spends::<T>::insert(
+				index,
+				SpendOf::<T> { beneficiary: beneficiary.clone(), amount, payout_at },
+			);
+			SpendCount::<T>::put(index.saturating_add(1));
+
+			Self::deposit_event(Event::SpendScheduled { index, beneficiary, amount, payout_at });
+			Ok(())
+		}
+
+		/// Cancel scheduled payout.
+		///
+		/// The dispatch origin of this call must be `SpendOrigin`.
+		///
+		/// - `index`: Index of the spend
+		///
+		/// Emits `SpendCancelled`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::cancel_spend())]
+		pub fn cancel_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::SpendOrigin::ensure_origin(origin)?;
+			let spend = Spends::<T>::take(index).ok_or(Error::<T>::UnknownSpend)?;
+			Payouts::<T>::mutate(spend.payout_at, |payouts| payouts.retain(|i| *i != index));
+
+			Self::deposit_event(Event::SpendCancelled { index });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the fund's pot
+		pub fn pot_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		fn is_period_start(block: BlockNumberFor<T>) -> bool {
+			let period = T::SpendPeriod::get();
+			!period.is_zero() && (block % period).is_zero()
+		}
+
+		fn next_period_start(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let period = T::SpendPeriod::get();
+			if period.is_zero() {
+				return block.saturating_add(1u32.into());
+			}
+			block.saturating_sub(block % period).saturating_add(period)
+		}
+
+		fn pay(index: SpendIndex, spend: SpendOf<T>, now: BlockNumberFor<T>) {
+			let spent = Self::spent_in_period().saturating_add(spend.amount);
+			if spent > T::MaxSpendPerPeriod::get() {
+				return Self::defer(index, spend, now);
+			}
+
+			Spends::<T>::remove(index);
+			let res = T::Currency::transfer(
+				&Self::pot_account(),
+				&spend.beneficiary,
+				spend.amount,
+				ExistenceRequirement::KeepAlive,
+			);
+			if res.is_err() {
+				Self::deposit_event(Event::SpendFailed { index });
+				return;
+			}
+
+			SpentInPeriod::<T>::put(spent);
+			Self::deposit_event(Event::SpendPaid {
+				index,
+				beneficiary: spend.beneficiary,
+				amount: spend.amount,
+			});
+		}
+
+		fn defer(index: SpendIndex, mut spend: SpendOf<T>, now: BlockNumberFor<T>) {
+			let payout_at = Self::next_period_start(now);
+			if Payouts::<T>::try_append(payout_at, index).is_err() {
+				Spends::<T>::remove(index);
+				Self::deposit_event(Event::SpendFailed { index });
+				return;
+			}
+
+			spend.payout_at = payout_at;
+			Spends::<T>::insert(index, spend);
+			Self::deposit_event(Event::SpendDeferred { index, payout_at });
+		}
+	}
+}
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, Spend};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Hooks},
+};
+use sp_runtime::DispatchError;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		SovereignFund::on_initialize(next);
+	}
+}
+
+fn schedule(beneficiary: u64, amount: u64, payout_at: u64) {
+	assert_ok!(SovereignFund::schedule_spend(
+		RuntimeOrigin::signed(1),
+		beneficiary,
+		amount,
+		payout_at
+	));
+}
+
+#[test]
+fn schedule_spend_requires_spend_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SovereignFund::schedule_spend(RuntimeOrigin::signed(2), 2, 10, 5),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn schedule_spend_validates_params() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SovereignFund::schedule_spend(RuntimeOrigin::signed(1), 2, 10, 1),
+			Error::<Test>::PayoutInPast
+		);
+		assert_noop!(
+			SovereignFund::schedule_spend(RuntimeOrigin::signed(1), 2, 101, 5),
+			Error::<Test>::AboveSpendCap
+		);
+		schedule(2, 10, 5);
+		schedule(3, 10, 5);
+		assert_noop!(
+			SovereignFund::schedule_spend(RuntimeOrigin::signed(1), 2, 10, 5),
+			Error::<Test>::TooManyPayouts
+		);
+	});
+}
+
+#[test]
+fn schedule_spend_works() {
+	new_test_ext().execute_with(|| {
+		schedule(2, 10, 5);
+		assert_eq!(
+			SovereignFund::spends(0),
+			Some(Spend { beneficiary: 2, amount: 10, payout_at: 5 })
+		);
+		assert_eq!(SovereignFund::payouts(5).into_inner(), vec![0]);
+		assert_eq!(SovereignFund::spend_count(), 1);
+		System::assert_last_event(
+			Event::<Test>::SpendScheduled { index: 0, beneficiary: 2, amount: 10, payout_at: 5 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn spend_is_paid_at_payout_block() {
+	new_test_ext().execute_with(|| {
+		schedule(2, 10, 5);
+		run_to_block(4);
+		assert_eq!(Balances::free_balance(2), 100);
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(2), 110);
+		assert_eq!(Balances::free_balance(pot()), 990);
+		assert_eq!(SovereignFund::spends(0), None);
+		assert_eq!(SovereignFund::spent_in_period(), 10);
+		System::assert_last_event(
+			Event::<Test>::SpendPaid { index: 0, beneficiary: 2, amount: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn spends_above_period_cap_are_deferred() {
+	new_test_ext().execute_with(|| {
+		schedule(2, 60, 5);
+		schedule(3, 60, 6);
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 160);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(SovereignFund::spends(1).unwrap().payout_at, 10);
+		System::assert_last_event(Event::<Test>::SpendDeferred { index: 1, payout_at: 10 }.into());
+
+		run_to_block(10);
+		assert_eq!(Balances::free_balance(3), 160);
+		assert_eq!(SovereignFund::spent_in_period(), 60);
+	});
+}
+
+#[test]
+fn cancel_spend_works() {
+	new_test_ext().execute_with(|| {
+		schedule(2, 10, 5);
+		assert_noop!(
+			SovereignFund::cancel_spend(RuntimeOrigin::signed(2), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(SovereignFund::cancel_spend(RuntimeOrigin::signed(1), 0));
+		assert_eq!(SovereignFund::spends(0), None);
+		assert!(SovereignFund::payouts(5).is_empty());
+		System::assert_last_event(Event::<Test>::SpendCancelled { index: 0 }.into());
+		assert_noop!(
+			SovereignFund::cancel_spend(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::UnknownSpend
+		);
+
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(2), 100);
+	});
+}
+
+#[test]
+fn spend_fails_if_pot_is_short() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&pot(), 50);
+		schedule(2, 60, 5);
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(2), 100);
+		assert_eq!(SovereignFund::spends(0), None);
+		assert_eq!(SovereignFund::spent_in_period(), 0);
+		System::assert_last_event(Event::<Test>::SpendFailed { index: 0 }.into());
+	});
+}
@@ -0,0 +1,16 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type SpendIndex = u32;
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Spend<AccountId, Balance, BlockNumber> {
+	/// Account receiving the payout
+	pub beneficiary: AccountId,
+	/// Amount paid out of the fund's pot
+	pub amount: Balance,
+	/// Block at which payout will be attempted. Moves to the start of next
+	/// spend period if current period's cap doesn't allow the payout.
+	pub payout_at: BlockNumber,
+}
@@ -0,0 +1,68 @@
+//! Weights for pallet_sovereign_fund
+//!
+//! NOT BENCHMARKED. Estimated from pallet_treasury weights of comparable
+//! operations plus this pallet's own storage accesses. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_sovereign_fund` run
+//! on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_sovereign_fund.
+pub trait WeightInfo {
+	fn schedule_spend() -> Weight;
+	fn cancel_spend() -> Weight;
+	fn on_initialize(p: u32, ) -> Weight;
+}
+
+/// Weights for pallet_sovereign_fund using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn schedule_spend() -> Weight {
+		Weight::from_parts(18_000_000, 3024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn cancel_spend() -> Weight {
+		Weight::from_parts(17_000_000, 3024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn on_initialize(p: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 3024)
+			.saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn schedule_spend() -> Weight {
+		Weight::from_parts(18_000_000, 3024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn cancel_spend() -> Weight {
+		Weight::from_parts(17_000_000, 3024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn on_initialize(p: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 3024)
+			.saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+	}
+}
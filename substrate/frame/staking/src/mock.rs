@@ -245,6 +245,7 @@ parameter_types! {
 	pub const TOTALLLM: u64 = 70000000u64;
 	pub const PRERELEASELLM: u64 = 7000000u64;
 	pub const CitizenshipMinimum: u64 = 5000u64;
+	pub const EResidentMinimum: u64 = 1000u64;
 	pub const UnlockFactor: Permill = Permill::from_percent(10);
 	pub const AssetId: u32 = 1;
 	pub const AssetName: &'static str = "LiberTest Merit";
@@ -261,13 +262,24 @@ impl pallet_llm::Config for Test {
 	type TotalSupply = TOTALLLM;
 	type PreReleasedAmount = PRERELEASELLM;
 	type CitizenshipMinimumPooledLLM = CitizenshipMinimum;
+	type EResidentMinimumPooledLLM = EResidentMinimum;
 	type UnlockFactor = UnlockFactor;
 	type AssetId = AssetId;
 	type AssetName = AssetName;
 	type AssetSymbol = AssetSymbol;
 	type InflationEventInterval = InflationEventInterval;
 	type InflationEventReleaseFactor = InflationEventReleaseFactor;
+	type UnpoolCooldown = ConstU64<0>;
+	type MaxUnpoolsPerBlock = ConstU32<1>;
+	type MaxDelegationDepth = ConstU32<1>;
+	type CitizenshipValidity = ConstU64<0>;
+	type LLMSpendApproveOrigin = EnsureRoot<u64>;
+	type LLMSpendProposalBond = ();
+	type LLMSpendProposalBondMinimum = ();
+	type LLMSpendPeriod = ();
+	type MaxLLMSpendApprovals = ConstU32<0>;
 	type OnLLMPoliticsUnlock = ();
+	type OnCitizenshipChange = ();
 	type SenateOrigin = EnsureRoot<u64>;
 	type WeightInfo = ();
 	type MaxCourts = ConstU32<100>;
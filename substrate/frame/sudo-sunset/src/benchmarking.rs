@@ -0,0 +1,74 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as SudoSunset;
+use codec::Encode;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::prelude::*;
+
+/// Cheap call of maximal encoded length
+fn call<T: Config>() -> Box<<T as Config>::RuntimeCall> {
+	let overhead = frame_system::Call::<T>::remark { remark: vec![] }.encode().len() as u32 + 4;
+	let len = T::MaxCallLen::get().saturating_sub(overhead);
+	let call: <T as frame_system::Config>::RuntimeCall =
+		frame_system::Call::<T>::remark { remark: vec![1u8; len as usize] }.into();
+	Box::new(call.into())
+}
+
+fn proposed<T: Config>() -> Result<T::Hash, BenchmarkError> {
+	let origin =
+		T::CongressOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let call = call::<T>();
+	let motion_hash = T::Hashing::hash_of(&call);
+	assert_ok!(SudoSunset::<T>::propose(origin, call));
+	Ok(motion_hash)
+}
+
+benchmarks! {
+	propose {
+		let origin = T::CongressOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = call::<T>();
+		let motion_hash = T::Hashing::hash_of(&call);
+	}: _<T::RuntimeOrigin>(origin, call)
+	verify {
+		assert!(Motions::<T>::contains_key(motion_hash));
+	}
+
+	approve {
+		let motion_hash = proposed::<T>()?;
+		let origin = T::TechnicalOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, motion_hash)
+	verify {
+		assert!(!Motions::<T>::contains_key(motion_hash));
+	}
+
+	veto {
+		let motion_hash = proposed::<T>()?;
+		let origin = T::SenateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, motion_hash)
+	verify {
+		assert!(!Motions::<T>::contains_key(motion_hash));
+	}
+
+	close {
+		let motion_hash = proposed::<T>()?;
+		let closed_at = frame_system::Pallet::<T>::block_number().saturating_add(T::VetoWindow::get());
+		frame_system::Pallet::<T>::set_block_number(closed_at);
+		let caller: T::AccountId = account("caller", 0, 0);
+	}: _(RawOrigin::Signed(caller), motion_hash)
+	verify {
+		assert!(!Motions::<T>::contains_key(motion_hash));
+	}
+
+	retire_sudo {
+		let origin = T::RetireOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(SudoSunset::<T>::sudo_retired());
+	}
+}
+
+impl_benchmark_test_suite!(SudoSunset, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,331 @@
+//! # Liberland Sudo Sunset Pallet
+//!
+//! ## Overview
+//!
+//! Sudo sunset pallet lets the chain retire sudo while keeping an emergency
+//! path to Root origin:
+//!
+//! * while sudo is active, both sudo and the emergency committee work,
+//! * `RetireOrigin`, e.g. sudo itself, calls `retire_sudo`. There's no way to
+//!   revert it - runtime's call filter rejects all sudo calls from then on,
+//! * emergency committee is formed by Congress, Senate and Technical
+//!   Committee. Any of them proposes an emergency call, others approve it.
+//!   Call is dispatched with Root origin once 2 of 3 bodies approve it,
+//! * Senate approves implicitly by not vetoing the motion for `VetoWindow`
+//!   blocks. After that, anyone can close the motion. Explicit approval of
+//!   the second body, e.g. Technical Committee fast-tracking a Congress
+//!   motion, dispatches the call right away. Motions proposed by Senate
+//!   always need explicit approval of another body,
+//! * Senate can veto a motion until it's dispatched.
+//!
+//! ## Pallet Config
+//!
+//! * `RuntimeCall` - the overarching call type, emergency calls are of this
+//!   type
+//! * `CongressOrigin` - origin of Congress as a body, e.g. supermajority
+//! * `SenateOrigin` - origin of Senate as a body
+//! * `TechnicalOrigin` - origin of Technical Committee as a body
+//! * `RetireOrigin` - origin that retires sudo
+//! * `VetoWindow` - number of blocks after which Senate implicitly approves
+//!   a motion
+//! * `MaxCallLen` - maximum length of encoded emergency call
+//! * `MaxCallWeight` - maximum dispatch weight of emergency call
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `propose`: Propose emergency call as one of the bodies
+//! * `approve`: Approve emergency call as one of the bodies
+//! * `veto`: Drop emergency call as Senate
+//! * `close`: Dispatch emergency call Senate didn't veto in time
+//! * `retire_sudo`: Disable sudo for good
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::{Decode, Encode};
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+	};
+	use frame_system::{pallet_prelude::*, RawOrigin};
+	use sp_runtime::traits::{Dispatchable, Hash, Saturating};
+	use sp_std::prelude::*;
+
+	pub type CallOf<T> = BoundedVec<u8, <T as Config>::MaxCallLen>;
+	pub type MotionOf<T> = Motion<CallOf<T>, BlockNumberFor<T>>;
+
+	/// Number of bodies that must approve an emergency call
+	const APPROVALS_THRESHOLD: usize = 2;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, emergency calls are of this type
+		type RuntimeCall: Parameter
+			+ GetDispatchInfo
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Origin of Congress as a body
+		type CongressOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin of Senate as a body
+		type SenateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin of Technical Committee as a body
+		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that retires sudo
+		type RetireOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of blocks after which Senate implicitly approves a motion
+		#[pallet::constant]
+		type VetoWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum length of encoded emergency call
+		#[pallet::constant]
+		type MaxCallLen: Get<u32>;
+
+		/// Maximum dispatch weight of emergency call
+		#[pallet::constant]
+		type MaxCallWeight: Get<Weight>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sudo was already retired
+		AlreadyRetired,
+		/// Same call is already proposed
+		DuplicateMotion,
+		/// Motion doesn't exist or was already closed
+		UnknownMotion,
+		/// Body already approved the motion
+		AlreadyApproved,
+		/// Veto window didn't pass yet
+		TooEarly,
+		/// Senate approved the motion explicitly, so it needs approval of
+		/// another body
+		SenateApproved,
+		/// Encoded call is longer than `MaxCallLen`
+		CallTooLong,
+		/// Call's dispatch weight exceeds `MaxCallWeight`
+		CallTooHeavy,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Emergency call proposed
+		Proposed { motion_hash: T::Hash, body: Body },
+		/// Body approved emergency call
+		Approved { motion_hash: T::Hash, body: Body },
+		/// Senate vetoed emergency call
+		Vetoed { motion_hash: T::Hash },
+		/// Emergency call dispatched with Root origin
+		Executed { motion_hash: T::Hash, result: DispatchResult },
+		/// Sudo retired for good
+		SudoRetired,
+	}
+
+	/// Whether sudo was retired
+	#[pallet::storage]
+	#[pallet::getter(fn sudo_retired)]
+	pub type SudoRetired<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Emergency calls waiting for approvals, by hash of the call
+	#[pallet::storage]
+	#[pallet::getter(fn motions)]
+	pub type Motions<T: Config> = StorageMap<_, Identity, T::Hash, MotionOf<T>, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose emergency call, approving it in the name of the proposing
+		/// body.
+		///
+		/// The dispatch origin of this call must be `CongressOrigin`,
+		/// `SenateOrigin` or `TechnicalOrigin`.
+		///
+		/// - `call`: Call dispatched with Root origin once approved
+		///
+		/// Emits `Proposed`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::propose())]
+		pub fn propose(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let body = Self::ensure_body(origin)?;
+			ensure!(
+				call.get_dispatch_info().weight.all_lte(T::MaxCallWeight::get()),
+				Error::<T>::CallTooHeavy
+			);
+			let motion_hash = T::Hashing::hash_of(&call);
+			ensure!(!Motions::<T>::contains_key(motion_hash), Error::<T>::DuplicateMotion);
+			let call: CallOf<T> = call.encode().try_into().map_err(|_| Error::<T>::CallTooLong)?;
+
+			let proposed_at = frame_system::Pallet::<T>::block_number();
+			let approvals = BoundedVec::truncate_from(vec![body]);
+			Motions::<T>::insert(motion_hash, Motion { call, proposed_at, approvals });
+
+			Self::deposit_event(Event::Proposed { motion_hash, body });
+			Ok(())
+		}
+
+		/// Approve emergency call in the name of a body. Call is dispatched
+		/// if it's the second body to approve.
+		///
+		/// The dispatch origin of this call must be `CongressOrigin`,
+		/// `SenateOrigin` or `TechnicalOrigin`.
+		///
+		/// - `motion_hash`: Hash of the proposed call
+		///
+		/// Emits `Approved` and `Executed` if call was dispatched.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::approve().saturating_add(T::MaxCallWeight::get()))]
+		pub fn approve(origin: OriginFor<T>, motion_hash: T::Hash) -> DispatchResultWithPostInfo {
+			let body = Self::ensure_body(origin)?;
+			let mut motion = Self::motions(motion_hash).ok_or(Error::<T>::UnknownMotion)?;
+			ensure!(!motion.approvals.contains(&body), Error::<T>::AlreadyApproved);
+			motion.approvals.try_push(body).map_err(|_| Error::<T>::AlreadyApproved)?;
+			Self::deposit_event(Event::Approved { motion_hash, body });
+
+			let mut weight = T::WeightInfo::approve();
+			if motion.approvals.len() >= APPROVALS_THRESHOLD {
+				weight.saturating_accrue(Self::execute(motion_hash, motion));
+			} else {
+				Motions::<T>::insert(motion_hash, motion);
+			}
+			Ok(Some(weight).into())
+		}
+
+		/// Drop emergency call before it's dispatched.
+		///
+		/// The dispatch origin of this call must be `SenateOrigin`.
+		///
+		/// - `motion_hash`: Hash of the proposed call
+		///
+		/// Emits `Vetoed`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::veto())]
+		pub fn veto(origin: OriginFor<T>, motion_hash: T::Hash) -> DispatchResult {
+			T::SenateOrigin::ensure_origin(origin)?;
+			ensure!(Motions::<T>::contains_key(motion_hash), Error::<T>::UnknownMotion);
+			Motions::<T>::remove(motion_hash);
+
+			Self::deposit_event(Event::Vetoed { motion_hash });
+			Ok(())
+		}
+
+		/// Dispatch emergency call that Senate didn't veto within
+		/// `VetoWindow`, counting it as Senate's approval. Motions proposed
+		/// by Senate need explicit approval of another body.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `motion_hash`: Hash of the proposed call
+		///
+		/// Emits `Executed`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close().saturating_add(T::MaxCallWeight::get()))]
+		pub fn close(origin: OriginFor<T>, motion_hash: T::Hash) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let motion = Self::motions(motion_hash).ok_or(Error::<T>::UnknownMotion)?;
+			ensure!(!motion.approvals.contains(&Body::Senate), Error::<T>::SenateApproved);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= motion.proposed_at.saturating_add(T::VetoWindow::get()),
+				Error::<T>::TooEarly
+			);
+
+			let weight = T::WeightInfo::close().saturating_add(Self::execute(motion_hash, motion));
+			Ok(Some(weight).into())
+		}
+
+		/// Disable sudo for good. Runtime is expected to filter out sudo
+		/// calls once [`Pallet::sudo_retired`] is set.
+		///
+		/// The dispatch origin of this call must be `RetireOrigin`.
+		///
+		/// Emits `SudoRetired`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::retire_sudo())]
+		pub fn retire_sudo(origin: OriginFor<T>) -> DispatchResult {
+			T::RetireOrigin::ensure_origin(origin)?;
+			ensure!(!Self::sudo_retired(), Error::<T>::AlreadyRetired);
+			SudoRetired::<T>::put(true);
+
+			Self::deposit_event(Event::SudoRetired);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Body that `origin` speaks for.
+		fn ensure_body(origin: OriginFor<T>) -> Result<Body, DispatchError> {
+			let origin = match T::CongressOrigin::try_origin(origin) {
+				Ok(_) => return Ok(Body::Congress),
+				Err(origin) => origin,
+			};
+			let origin = match T::SenateOrigin::try_origin(origin) {
+				Ok(_) => return Ok(Body::Senate),
+				Err(origin) => origin,
+			};
+			T::TechnicalOrigin::ensure_origin(origin)?;
+			Ok(Body::TechnicalCommittee)
+		}
+
+		/// Dispatches emergency call with Root origin and removes the motion.
+		/// Returns weight of the dispatched call.
+		fn execute(motion_hash: T::Hash, motion: MotionOf<T>) -> Weight {
+			Motions::<T>::remove(motion_hash);
+			// call was decoded on proposal, it can only fail after a runtime
+			// upgrade
+			let (result, weight) = match <T as Config>::RuntimeCall::decode(&mut &motion.call[..]) {
+				Ok(call) => {
+					let info = call.get_dispatch_info();
+					let res = call.dispatch(RawOrigin::Root.into());
+					let weight = extract_actual_weight(&res, &info);
+					(res.map(|_| ()).map_err(|e| e.error), weight)
+				},
+				Err(_) => (Err(DispatchError::Other("Undecodable call")), Weight::zero()),
+			};
+
+			Self::deposit_event(Event::Executed { motion_hash, result });
+			weight
+		}
+	}
+}
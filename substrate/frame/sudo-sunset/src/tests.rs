@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use crate::{mock::*, Body, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
+
+const KEY: &[u8] = b"key";
+
+fn set_storage(value: &[u8]) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::set_storage {
+		items: vec![(KEY.to_vec(), value.to_vec())],
+	}))
+}
+
+fn stored() -> Option<Vec<u8>> {
+	sp_io::storage::get(KEY).map(|v| v.to_vec())
+}
+
+fn propose(body: u64, call: Box<RuntimeCall>) -> H256 {
+	let motion_hash = BlakeTwo256::hash_of(&call);
+	assert_ok!(SudoSunset::propose(RuntimeOrigin::signed(body), call));
+	motion_hash
+}
+
+#[test]
+fn propose_requires_body() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SudoSunset::propose(RuntimeOrigin::signed(4), set_storage(b"value")),
+			DispatchError::BadOrigin
+		);
+		let motion_hash = propose(1, set_storage(b"value"));
+		let motion = SudoSunset::motions(motion_hash).unwrap();
+		assert_eq!(motion.proposed_at, 1);
+		assert_eq!(motion.approvals.into_inner(), vec![Body::Congress]);
+		System::assert_last_event(Event::Proposed { motion_hash, body: Body::Congress }.into());
+
+		assert_noop!(
+			SudoSunset::propose(RuntimeOrigin::signed(3), set_storage(b"value")),
+			Error::<Test>::DuplicateMotion
+		);
+		assert_noop!(
+			SudoSunset::propose(RuntimeOrigin::signed(3), set_storage(&[1u8; 64])),
+			Error::<Test>::CallTooLong
+		);
+	});
+}
+
+#[test]
+fn second_body_dispatches_call() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = propose(1, set_storage(b"value"));
+		assert_noop!(
+			SudoSunset::approve(RuntimeOrigin::signed(1), motion_hash),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_ok!(SudoSunset::approve(RuntimeOrigin::signed(3), motion_hash));
+		System::assert_has_event(
+			Event::Approved { motion_hash, body: Body::TechnicalCommittee }.into(),
+		);
+		System::assert_last_event(Event::Executed { motion_hash, result: Ok(()) }.into());
+		assert_eq!(stored(), Some(b"value".to_vec()));
+		assert_eq!(SudoSunset::motions(motion_hash), None);
+	});
+}
+
+#[test]
+fn call_is_dispatched_with_root_origin() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: vec![1],
+		}));
+		let motion_hash = propose(2, call);
+		assert_ok!(SudoSunset::approve(RuntimeOrigin::signed(1), motion_hash));
+		System::assert_last_event(
+			Event::Executed { motion_hash, result: Err(DispatchError::BadOrigin) }.into(),
+		);
+	});
+}
+
+#[test]
+fn senate_vetoes_motion() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = propose(1, set_storage(b"value"));
+		assert_noop!(
+			SudoSunset::veto(RuntimeOrigin::signed(1), motion_hash),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(SudoSunset::veto(RuntimeOrigin::signed(2), motion_hash));
+		System::assert_last_event(Event::Vetoed { motion_hash }.into());
+		assert_noop!(
+			SudoSunset::approve(RuntimeOrigin::signed(3), motion_hash),
+			Error::<Test>::UnknownMotion
+		);
+		assert_eq!(stored(), None);
+	});
+}
+
+#[test]
+fn close_requires_veto_window() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = propose(3, set_storage(b"value"));
+		System::set_block_number(10);
+		assert_noop!(
+			SudoSunset::close(RuntimeOrigin::signed(4), motion_hash),
+			Error::<Test>::TooEarly
+		);
+		System::set_block_number(11);
+		assert_ok!(SudoSunset::close(RuntimeOrigin::signed(4), motion_hash));
+		System::assert_last_event(Event::Executed { motion_hash, result: Ok(()) }.into());
+		assert_eq!(stored(), Some(b"value".to_vec()));
+		assert_noop!(
+			SudoSunset::close(RuntimeOrigin::signed(4), motion_hash),
+			Error::<Test>::UnknownMotion
+		);
+	});
+}
+
+#[test]
+fn senate_motion_needs_another_body() {
+	new_test_ext().execute_with(|| {
+		let motion_hash = propose(2, set_storage(b"value"));
+		System::set_block_number(11);
+		assert_noop!(
+			SudoSunset::close(RuntimeOrigin::signed(4), motion_hash),
+			Error::<Test>::SenateApproved
+		);
+		assert_ok!(SudoSunset::approve(RuntimeOrigin::signed(1), motion_hash));
+		assert_eq!(stored(), Some(b"value".to_vec()));
+	});
+}
+
+#[test]
+fn retire_sudo_is_one_way() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SudoSunset::retire_sudo(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+		assert!(!SudoSunset::sudo_retired());
+		assert_ok!(SudoSunset::retire_sudo(RuntimeOrigin::root()));
+		assert!(SudoSunset::sudo_retired());
+		System::assert_last_event(Event::SudoRetired.into());
+		assert_noop!(SudoSunset::retire_sudo(RuntimeOrigin::root()), Error::<Test>::AlreadyRetired);
+	});
+}
@@ -0,0 +1,23 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Bodies forming the emergency committee
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum Body {
+	Congress,
+	Senate,
+	TechnicalCommittee,
+}
+
+/// Emergency call waiting for approvals of the committee
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Motion<Call, BlockNumber> {
+	/// SCALE-encoded call dispatched with Root origin
+	pub call: Call,
+	/// Block the motion was proposed at
+	pub proposed_at: BlockNumber,
+	/// Bodies that explicitly approved the motion
+	pub approvals: BoundedVec<Body, ConstU32<3>>,
+}
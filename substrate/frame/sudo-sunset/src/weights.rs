@@ -0,0 +1,83 @@
+//! Weights for pallet_sudo_sunset
+//!
+//! NOT BENCHMARKED. Estimated from pallet_court and pallet_collective weights
+//! of comparable operations. `approve` and `close` don't include the dispatched
+//! call, it's added on execution. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_sudo_sunset` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_sudo_sunset.
+pub trait WeightInfo {
+	fn propose() -> Weight;
+	fn approve() -> Weight;
+	fn veto() -> Weight;
+	fn close() -> Weight;
+	fn retire_sudo() -> Weight;
+}
+
+/// Weights for pallet_sudo_sunset using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn propose() -> Weight {
+		Weight::from_parts(30_000_000, 5117)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(35_000_000, 5117)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn veto() -> Weight {
+		Weight::from_parts(20_000_000, 5117)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn close() -> Weight {
+		Weight::from_parts(35_000_000, 5117)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn retire_sudo() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn propose() -> Weight {
+		Weight::from_parts(30_000_000, 5117)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(35_000_000, 5117)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn veto() -> Weight {
+		Weight::from_parts(20_000_000, 5117)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn close() -> Weight {
+		Weight::from_parts(35_000_000, 5117)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn retire_sudo() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ValidatorOnboarding;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let acc: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&acc, BalanceOf::<T>::max_value() / 4u32.into());
+	acc
+}
+
+fn applied<T: Config>() -> T::AccountId {
+	let applicant = funded::<T>("applicant", 0);
+	assert_ok!(ValidatorOnboarding::<T>::apply(
+		RawOrigin::Signed(applicant.clone()).into(),
+		applicant.clone(),
+		T::Hashing::hash_of(&0u32),
+	));
+	applicant
+}
+
+fn candidate<T: Config>() -> Result<(T::RuntimeOrigin, T::AccountId), BenchmarkError> {
+	let origin =
+		T::ReviewOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let applicant = applied::<T>();
+	assert_ok!(ValidatorOnboarding::<T>::approve(origin.clone(), applicant.clone()));
+	Ok((origin, applicant))
+}
+
+benchmarks! {
+	apply {
+		let applicant = funded::<T>("applicant", 0);
+	}: _(RawOrigin::Signed(applicant.clone()), applicant.clone(), T::Hashing::hash_of(&0u32))
+	verify {
+		assert!(Applications::<T>::contains_key(applicant));
+	}
+
+	withdraw_application {
+		let applicant = applied::<T>();
+	}: _(RawOrigin::Signed(applicant.clone()))
+	verify {
+		assert!(!Applications::<T>::contains_key(applicant));
+	}
+
+	approve {
+		let origin = T::ReviewOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let applicant = applied::<T>();
+	}: _<T::RuntimeOrigin>(origin, applicant.clone())
+	verify {
+		assert!(Candidates::<T>::contains_key(applicant));
+	}
+
+	reject {
+		let origin = T::ReviewOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let applicant = applied::<T>();
+	}: _<T::RuntimeOrigin>(origin, applicant.clone())
+	verify {
+		assert!(!Applications::<T>::contains_key(applicant));
+	}
+
+	remove_candidate {
+		let (origin, candidate) = candidate::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, candidate.clone())
+	verify {
+		assert!(!Candidates::<T>::contains_key(candidate));
+	}
+}
+
+impl_benchmark_test_suite!(ValidatorOnboarding, crate::mock::new_test_ext(), crate::mock::Test,);
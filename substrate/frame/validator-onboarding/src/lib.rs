@@ -0,0 +1,333 @@
+//! # Liberland Validator Onboarding Pallet
+//!
+//! ## Overview
+//!
+//! Validator onboarding pallet lets prospective validators apply on-chain.
+//! Applicant links the account holding its identity, submits hash of its
+//! infrastructure attestation and reserves a bond. Review origin (technical
+//! committee) approves or rejects the application:
+//!
+//! * approved applicants get their bond back and are added to the candidate
+//!   list,
+//! * rejected applicants get their bond back minus the processing fee, which
+//!   is sent to `FeeDestination`.
+//!
+//! Candidate list is a plain list of vetted accounts - it doesn't grant
+//! invulnerability or a guaranteed seat, candidates still have to bond and
+//! get elected via staking. Runtime can use it to gate `validate` via the
+//! `Contains` implementation of this pallet.
+//!
+//! ## Terminology
+//!
+//! * Applicant - account that submitted an application, usually a stash
+//! * Candidate - applicant whose application was approved
+//! * Processing fee - part of the bond kept on rejection
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency in which bonds are reserved
+//! * `ApplicationBond` - bond reserved on application
+//! * `ProcessingFee` - part of the bond slashed on rejection
+//! * `FeeDestination` - handler of slashed processing fees
+//! * `ReserveIdentifier` - identifier of named reserves
+//! * `ReviewOrigin` - origin that approves and rejects applications and
+//!   removes candidates
+//! * `MaxCandidates` - maximum number of candidates
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `apply`: Submit application and reserve bond
+//! * `withdraw_application`: Withdraw pending application, bond is returned
+//! * `approve`: Approve application and add applicant to candidates
+//! * `reject`: Reject application and slash processing fee
+//! * `remove_candidate`: Remove candidate from the list
+//! * `resign`: Remove self from the candidate list
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Contains, Currency, NamedReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::OnUnbalanced};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+
+	type ReserveIdentifierOf<T> = <<T as Config>::Currency as NamedReservableCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::ReserveIdentifier;
+
+	pub type ApplicationOf<T> = Application<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as frame_system::Config>::Hash,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for application bonds
+		type Currency: NamedReservableCurrency<Self::AccountId>;
+
+		/// Bond reserved on application
+		#[pallet::constant]
+		type ApplicationBond: Get<BalanceOf<Self>>;
+
+		/// Part of the bond kept on rejection
+		#[pallet::constant]
+		type ProcessingFee: Get<BalanceOf<Self>>;
+
+		/// Handler of slashed processing fees
+		type FeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Identifies reserves in Currency
+		#[pallet::constant]
+		type ReserveIdentifier: Get<&'static ReserveIdentifierOf<Self>>;
+
+		/// Origin that reviews applications and manages candidates
+		type ReviewOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of candidates
+		#[pallet::constant]
+		type MaxCandidates: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account already has a pending application
+		AlreadyApplied,
+		/// Account is already a candidate
+		AlreadyCandidate,
+		/// Application doesn't exist
+		ApplicationNotFound,
+		/// Account isn't a candidate
+		NotCandidate,
+		/// Maximum number of candidates reached
+		TooManyCandidates,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Application submitted
+		Applied { applicant: T::AccountId, identity: T::AccountId, attestation_hash: T::Hash },
+		/// Application withdrawn by applicant
+		ApplicationWithdrawn { applicant: T::AccountId },
+		/// Application approved, applicant added to candidates
+		Approved { applicant: T::AccountId },
+		/// Application rejected, processing fee slashed
+		Rejected { applicant: T::AccountId, fee: BalanceOf<T> },
+		/// Candidate removed by review origin or resigned
+		CandidateRemoved { candidate: T::AccountId },
+	}
+
+	/// Pending applications
+	#[pallet::storage]
+	#[pallet::getter(fn applications)]
+	pub type Applications<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ApplicationOf<T>, OptionQuery>;
+
+	/// Approved candidates and block of their approval
+	#[pallet::storage]
+	#[pallet::getter(fn candidates)]
+	pub type Candidates<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Number of entries in `Candidates`
+	#[pallet::storage]
+	#[pallet::getter(fn candidates_count)]
+	pub type CandidatesCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Apply to become a validator candidate.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must
+		/// have funds to cover `ApplicationBond`.
+		///
+		/// - `identity`: Account holding applicant's on-chain identity
+		/// - `attestation_hash`: Hash of infrastructure attestation document
+		///
+		/// Emits `Applied`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::apply())]
+		pub fn apply(
+			origin: OriginFor<T>,
+			identity: T::AccountId,
+			attestation_hash: T::Hash,
+		) -> DispatchResult {
+			let applicant = ensure_signed(origin)?;
+			ensure!(!Applications::<T>::contains_key(&applicant), Error::<T>::AlreadyApplied);
+			ensure!(!Candidates::<T>::contains_key(&applicant), Error::<T>::AlreadyCandidate);
+
+			let bond = T::ApplicationBond::get();
+			T::Currency::reserve_named(T::ReserveIdentifier::get(), &applicant, bond)?;
+
+			let application = ApplicationOf::<T> {
+				identity: identity.clone(),
+				attestation_hash,
+				bond,
+				submitted_at: frame_system::Pallet::<T>::block_number(),
+			};
+			Applications::<T>::insert(&applicant, application);
+
+			Self::deposit_event(Event::Applied { applicant, identity, attestation_hash });
+			Ok(())
+		}
+
+		/// Withdraw pending application. Bond is returned in full.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the applicant.
+		///
+		/// Emits `ApplicationWithdrawn`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::withdraw_application())]
+		pub fn withdraw_application(origin: OriginFor<T>) -> DispatchResult {
+			let applicant = ensure_signed(origin)?;
+			let application =
+				Applications::<T>::take(&applicant).ok_or(Error::<T>::ApplicationNotFound)?;
+			T::Currency::unreserve_named(T::ReserveIdentifier::get(), &applicant, application.bond);
+
+			Self::deposit_event(Event::ApplicationWithdrawn { applicant });
+			Ok(())
+		}
+
+		/// Approve application. Bond is returned and applicant becomes a
+		/// candidate.
+		///
+		/// The dispatch origin of this call must be `ReviewOrigin`.
+		///
+		/// - `applicant`: Account that submitted the application
+		///
+		/// Emits `Approved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(origin: OriginFor<T>, applicant: T::AccountId) -> DispatchResult {
+			T::ReviewOrigin::ensure_origin(origin)?;
+			let count = Self::candidates_count();
+			ensure!(count < T::MaxCandidates::get(), Error::<T>::TooManyCandidates);
+			let application =
+				Applications::<T>::take(&applicant).ok_or(Error::<T>::ApplicationNotFound)?;
+
+			T::Currency::unreserve_named(T::ReserveIdentifier::get(), &applicant, application.bond);
+			Candidates::<T>::insert(&applicant, frame_system::Pallet::<T>::block_number());
+			CandidatesCount::<T>::put(count.saturating_add(1));
+
+			Self::deposit_event(Event::Approved { applicant });
+			Ok(())
+		}
+
+		/// Reject application. `ProcessingFee` is slashed from the bond and
+		/// the rest is returned.
+		///
+		/// The dispatch origin of this call must be `ReviewOrigin`.
+		///
+		/// - `applicant`: Account that submitted the application
+		///
+		/// Emits `Rejected`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::reject())]
+		pub fn reject(origin: OriginFor<T>, applicant: T::AccountId) -> DispatchResult {
+			T::ReviewOrigin::ensure_origin(origin)?;
+			let application =
+				Applications::<T>::take(&applicant).ok_or(Error::<T>::ApplicationNotFound)?;
+
+			let fee = T::ProcessingFee::get().min(application.bond);
+			let (imbalance, _) =
+				T::Currency::slash_reserved_named(T::ReserveIdentifier::get(), &applicant, fee);
+			T::FeeDestination::on_unbalanced(imbalance);
+			T::Currency::unreserve_named(
+				T::ReserveIdentifier::get(),
+				&applicant,
+				application.bond.saturating_sub(fee),
+			);
+
+			Self::deposit_event(Event::Rejected { applicant, fee });
+			Ok(())
+		}
+
+		/// Remove candidate from the list.
+		///
+		/// The dispatch origin of this call must be `ReviewOrigin`.
+		///
+		/// - `candidate`: Candidate to remove
+		///
+		/// Emits `CandidateRemoved`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_candidate())]
+		pub fn remove_candidate(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			T::ReviewOrigin::ensure_origin(origin)?;
+			Self::do_remove_candidate(candidate)
+		}
+
+		/// Remove self from the candidate list.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the candidate.
+		///
+		/// Emits `CandidateRemoved`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::remove_candidate())]
+		pub fn resign(origin: OriginFor<T>) -> DispatchResult {
+			let candidate = ensure_signed(origin)?;
+			Self::do_remove_candidate(candidate)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_remove_candidate(candidate: T::AccountId) -> DispatchResult {
+			Candidates::<T>::take(&candidate).ok_or(Error::<T>::NotCandidate)?;
+			CandidatesCount::<T>::mutate(|c| *c = c.saturating_sub(1));
+			Self::deposit_event(Event::CandidateRemoved { candidate });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+	fn contains(account: &T::AccountId) -> bool {
+		Candidates::<T>::contains_key(account)
+	}
+}
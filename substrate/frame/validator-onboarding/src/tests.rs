@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, Currency, NamedReservableCurrency},
+};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+fn attestation() -> H256 {
+	H256::repeat_byte(1)
+}
+
+fn reserved(who: u64) -> u64 {
+	Balances::reserved_balance_named(ReserveIdentifier::get(), &who)
+}
+
+fn apply(applicant: u64) {
+	assert_ok!(ValidatorOnboarding::apply(
+		RuntimeOrigin::signed(applicant),
+		applicant,
+		attestation()
+	));
+}
+
+#[test]
+fn apply_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		assert_eq!(reserved(2), 50);
+		let application = ValidatorOnboarding::applications(2).unwrap();
+		assert_eq!(application.identity, 2);
+		assert_eq!(application.attestation_hash, attestation());
+		assert_eq!(application.bond, 50);
+		System::assert_last_event(
+			Event::Applied { applicant: 2, identity: 2, attestation_hash: attestation() }.into(),
+		);
+	});
+}
+
+#[test]
+fn apply_validates() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		assert_noop!(
+			ValidatorOnboarding::apply(RuntimeOrigin::signed(2), 2, attestation()),
+			Error::<Test>::AlreadyApplied
+		);
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 2));
+		assert_noop!(
+			ValidatorOnboarding::apply(RuntimeOrigin::signed(2), 2, attestation()),
+			Error::<Test>::AlreadyCandidate
+		);
+		assert_noop!(
+			ValidatorOnboarding::apply(RuntimeOrigin::signed(5), 5, attestation()),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn withdraw_application_returns_bond() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		assert_ok!(ValidatorOnboarding::withdraw_application(RuntimeOrigin::signed(2)));
+		assert_eq!(reserved(2), 0);
+		assert_eq!(Balances::free_balance(2), 100);
+		assert_eq!(ValidatorOnboarding::applications(2), None);
+		assert_noop!(
+			ValidatorOnboarding::withdraw_application(RuntimeOrigin::signed(2)),
+			Error::<Test>::ApplicationNotFound
+		);
+	});
+}
+
+#[test]
+fn only_review_origin_can_review() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		assert_noop!(ValidatorOnboarding::approve(RuntimeOrigin::signed(2), 2), BadOrigin);
+		assert_noop!(ValidatorOnboarding::reject(RuntimeOrigin::signed(2), 2), BadOrigin);
+		assert_noop!(ValidatorOnboarding::remove_candidate(RuntimeOrigin::signed(2), 2), BadOrigin);
+	});
+}
+
+#[test]
+fn approve_adds_candidate_and_returns_bond() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 2));
+		assert_eq!(reserved(2), 0);
+		assert_eq!(Balances::free_balance(2), 100);
+		assert_eq!(ValidatorOnboarding::candidates(2), Some(1));
+		assert_eq!(ValidatorOnboarding::candidates_count(), 1);
+		assert!(ValidatorOnboarding::contains(&2));
+		System::assert_last_event(Event::Approved { applicant: 2 }.into());
+		assert_noop!(
+			ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::ApplicationNotFound
+		);
+	});
+}
+
+#[test]
+fn approve_respects_max_candidates() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		apply(3);
+		apply(4);
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 2));
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 3));
+		assert_noop!(
+			ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 4),
+			Error::<Test>::TooManyCandidates
+		);
+	});
+}
+
+#[test]
+fn reject_slashes_processing_fee() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		let issuance = Balances::total_issuance();
+		assert_ok!(ValidatorOnboarding::reject(RuntimeOrigin::signed(1), 2));
+		assert_eq!(reserved(2), 0);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_eq!(Balances::total_issuance(), issuance - 10);
+		assert_eq!(ValidatorOnboarding::applications(2), None);
+		assert!(!ValidatorOnboarding::contains(&2));
+		System::assert_last_event(Event::Rejected { applicant: 2, fee: 10 }.into());
+	});
+}
+
+#[test]
+fn candidates_can_be_removed() {
+	new_test_ext().execute_with(|| {
+		apply(2);
+		apply(3);
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 2));
+		assert_ok!(ValidatorOnboarding::approve(RuntimeOrigin::signed(1), 3));
+
+		assert_ok!(ValidatorOnboarding::remove_candidate(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::CandidateRemoved { candidate: 2 }.into());
+		assert_ok!(ValidatorOnboarding::resign(RuntimeOrigin::signed(3)));
+		assert_eq!(ValidatorOnboarding::candidates_count(), 0);
+		assert_noop!(
+			ValidatorOnboarding::resign(RuntimeOrigin::signed(3)),
+			Error::<Test>::NotCandidate
+		);
+	});
+}
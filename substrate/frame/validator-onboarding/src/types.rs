@@ -0,0 +1,16 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct Application<AccountId, Balance, BlockNumber, Hash> {
+	/// Account holding applicant's on-chain identity
+	pub identity: AccountId,
+	/// Hash of the off-chain infrastructure attestation (hardware, hosting,
+	/// monitoring, key management)
+	pub attestation_hash: Hash,
+	/// Bond reserved from applicant
+	pub bond: Balance,
+	/// Block at which application was submitted
+	pub submitted_at: BlockNumber,
+}
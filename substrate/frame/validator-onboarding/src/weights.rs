@@ -0,0 +1,81 @@
+//! Weights for pallet_validator_onboarding
+//!
+//! NOT BENCHMARKED. Estimated from storage accesses of each call. Replace with
+//! output of `substrate/scripts/run_liberland_benchmarks.sh -p
+//! pallet_validator_onboarding` run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_validator_onboarding.
+pub trait WeightInfo {
+	fn apply() -> Weight;
+	fn withdraw_application() -> Weight;
+	fn approve() -> Weight;
+	fn reject() -> Weight;
+	fn remove_candidate() -> Weight;
+}
+
+/// Weights for pallet_validator_onboarding using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn apply() -> Weight {
+		Weight::from_parts(34_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn withdraw_application() -> Weight {
+		Weight::from_parts(31_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(36_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn reject() -> Weight {
+		Weight::from_parts(52_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(14_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn apply() -> Weight {
+		Weight::from_parts(34_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn withdraw_application() -> Weight {
+		Weight::from_parts(31_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(36_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn reject() -> Weight {
+		Weight::from_parts(52_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(14_000_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
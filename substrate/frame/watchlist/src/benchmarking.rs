@@ -0,0 +1,74 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Watchlist;
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{assert_ok, traits::Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let acc: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&acc, BalanceOf::<T>::max_value() / 4u32.into());
+	acc
+}
+
+fn label<T: Config>() -> LabelOf<T> {
+	vec![b'x'; T::MaxLabelLen::get() as usize].try_into().unwrap()
+}
+
+fn requested<T: Config>() -> (T::AccountId, T::AccountId) {
+	let watched: T::AccountId = account("watched", 0, SEED);
+	let watcher = funded::<T>("watcher", 0);
+	assert_ok!(Watchlist::<T>::request_watch(
+		RawOrigin::Signed(watcher.clone()).into(),
+		watched.clone(),
+		label::<T>(),
+	));
+	(watched, watcher)
+}
+
+fn active<T: Config>() -> (T::AccountId, T::AccountId) {
+	let (watched, watcher) = requested::<T>();
+	assert_ok!(Watchlist::<T>::accept_watch(
+		RawOrigin::Signed(watched.clone()).into(),
+		watcher.clone(),
+	));
+	(watched, watcher)
+}
+
+benchmarks! {
+	request_watch {
+		let watched: T::AccountId = account("watched", 0, SEED);
+		let watcher = funded::<T>("watcher", 0);
+	}: _(RawOrigin::Signed(watcher.clone()), watched.clone(), label::<T>())
+	verify {
+		assert!(Watches::<T>::contains_key(watched, watcher));
+	}
+
+	accept_watch {
+		let (watched, watcher) = requested::<T>();
+	}: _(RawOrigin::Signed(watched.clone()), watcher.clone())
+	verify {
+		assert_eq!(Watchlist::<T>::watches(watched, watcher).unwrap().status, WatchStatus::Active);
+	}
+
+	revoke_watch {
+		let (watched, watcher) = active::<T>();
+	}: _(RawOrigin::Signed(watched.clone()), watcher.clone())
+	verify {
+		assert!(!Watches::<T>::contains_key(watched, watcher));
+	}
+
+	withdraw_watch {
+		let (watched, watcher) = active::<T>();
+	}: _(RawOrigin::Signed(watcher.clone()), watched.clone())
+	verify {
+		assert!(!Watches::<T>::contains_key(watched, watcher));
+	}
+}
+
+impl_benchmark_test_suite!(Watchlist, crate::mock::new_test_ext(), crate::mock::Test,);
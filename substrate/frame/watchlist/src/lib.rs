@@ -0,0 +1,308 @@
+//! # Liberland Watchlist Pallet
+//!
+//! ## Overview
+//!
+//! Watchlist pallet lets institutions (banks, auditors, exchanges) register
+//! watch-only interest in accounts, typically their own treasuries. Watches
+//! give no control rights over the watched account - they're only a public,
+//! consented registry that indexers and notification gateways use to decide
+//! which accounts to index first and whom to notify about their activity.
+//!
+//! Watch is requested by the watcher and becomes active only after the
+//! watched account accepts it. Watched account can revoke any watch at any
+//! time, watcher can withdraw its own watch.
+//!
+//! ## Terminology
+//!
+//! * Watcher - institution interested in activity of an account
+//! * Watched account - account being monitored, manages its watchers
+//! * Watch - relation between watcher and watched account, `Requested` until
+//!   accepted, then `Active`
+//!
+//! ## Pallet Config
+//!
+//! * `Currency` - currency in which watch deposits are reserved
+//! * `WatchDeposit` - deposit reserved from watcher for each watch
+//! * `ReserveIdentifier` - identifier of named reserves
+//! * `MaxWatchers` - maximum number of watches (requested and active) per
+//!   watched account
+//! * `MaxLabelLen` - maximum length of watcher's label
+//! * `WeightInfo` - see [Substrate docs](https://docs.substrate.io/reference/how-to-guides/weights/use-custom-weights/)
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `request_watch`: Request watch of an account
+//! * `accept_watch`: Accept watch requested by watcher
+//! * `revoke_watch`: Revoke or reject watch, called by watched account
+//! * `withdraw_watch`: Withdraw watch, called by watcher
+//!
+//! ### Public functions
+//!
+//! * `active_watchers`: Watchers with active watch of an account
+//! * `watched_accounts`: Accounts actively watched by a watcher
+//!
+//! Both are exposed by the node runtime via `WatchlistApi` runtime API.
+//!
+//! License: MIT
+/*
+Copyright © 2024 Liberland
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, NamedReservableCurrency};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	type ReserveIdentifierOf<T> = <<T as Config>::Currency as NamedReservableCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::ReserveIdentifier;
+
+	pub type LabelOf<T> = BoundedVec<u8, <T as Config>::MaxLabelLen>;
+	pub type WatchOf<T> = Watch<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::MaxLabelLen>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for watch deposits
+		type Currency: NamedReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved from watcher for each watch
+		#[pallet::constant]
+		type WatchDeposit: Get<BalanceOf<Self>>;
+
+		/// Identifies reserves in Currency
+		#[pallet::constant]
+		type ReserveIdentifier: Get<&'static ReserveIdentifierOf<Self>>;
+
+		/// Maximum number of watches per watched account
+		#[pallet::constant]
+		type MaxWatchers: Get<u32>;
+
+		/// Maximum length of watcher's label
+		#[pallet::constant]
+		type MaxLabelLen: Get<u32>;
+
+		/// WeightInfo
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account can't watch itself
+		CannotWatchSelf,
+		/// Watch already exists
+		AlreadyWatching,
+		/// Watch doesn't exist
+		WatchNotFound,
+		/// Watch is already active
+		AlreadyActive,
+		/// Watched account reached maximum number of watchers
+		TooManyWatchers,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Watcher requested watch of an account
+		WatchRequested { watched: T::AccountId, watcher: T::AccountId, label: LabelOf<T> },
+		/// Watched account accepted watch. Indexers should start prioritizing
+		/// `watched` and gateways should start notifying `watcher`.
+		WatchActivated { watched: T::AccountId, watcher: T::AccountId },
+		/// Watch revoked by watched account or withdrawn by watcher
+		WatchRemoved { watched: T::AccountId, watcher: T::AccountId },
+	}
+
+	/// Watches per watched account and watcher
+	#[pallet::storage]
+	#[pallet::getter(fn watches)]
+	pub type Watches<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		WatchOf<T>,
+		OptionQuery,
+	>;
+
+	/// Reverse index of `Watches` - watched accounts per watcher
+	#[pallet::storage]
+	pub type WatchedBy<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of watches (requested and active) per watched account
+	#[pallet::storage]
+	#[pallet::getter(fn watchers_count)]
+	pub type WatchersCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request watch of an account. Watch becomes active once `watched`
+		/// accepts it.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must
+		/// have funds to cover `WatchDeposit`.
+		///
+		/// - `watched`: Account to watch
+		/// - `label`: Human readable name of the watcher
+		///
+		/// Emits `WatchRequested`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::request_watch())]
+		pub fn request_watch(
+			origin: OriginFor<T>,
+			watched: T::AccountId,
+			label: LabelOf<T>,
+		) -> DispatchResult {
+			let watcher = ensure_signed(origin)?;
+			ensure!(watcher != watched, Error::<T>::CannotWatchSelf);
+			ensure!(!Watches::<T>::contains_key(&watched, &watcher), Error::<T>::AlreadyWatching);
+			let count = Self::watchers_count(&watched);
+			ensure!(count < T::MaxWatchers::get(), Error::<T>::TooManyWatchers);
+
+			let deposit = T::WatchDeposit::get();
+			T::Currency::reserve_named(T::ReserveIdentifier::get(), &watcher, deposit)?;
+
+			let watch = WatchOf::<T> {
+				label: label.clone(),
+				status: WatchStatus::Requested,
+				deposit,
+				since: frame_system::Pallet::<T>::block_number(),
+			};
+			Watches::<T>::insert(&watched, &watcher, watch);
+			WatchedBy::<T>::insert(&watcher, &watched, ());
+			WatchersCount::<T>::insert(&watched, count.saturating_add(1));
+
+			Self::deposit_event(Event::WatchRequested { watched, watcher, label });
+			Ok(())
+		}
+
+		/// Accept watch requested by `watcher`.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the watched
+		/// account.
+		///
+		/// - `watcher`: Account that requested the watch
+		///
+		/// Emits `WatchActivated`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::accept_watch())]
+		pub fn accept_watch(origin: OriginFor<T>, watcher: T::AccountId) -> DispatchResult {
+			let watched = ensure_signed(origin)?;
+			Watches::<T>::try_mutate(&watched, &watcher, |maybe_watch| -> DispatchResult {
+				let watch = maybe_watch.as_mut().ok_or(Error::<T>::WatchNotFound)?;
+				ensure!(watch.status == WatchStatus::Requested, Error::<T>::AlreadyActive);
+				watch.status = WatchStatus::Active;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::WatchActivated { watched, watcher });
+			Ok(())
+		}
+
+		/// Revoke active watch or reject requested one. Watcher's deposit is
+		/// returned.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the watched
+		/// account.
+		///
+		/// - `watcher`: Account that requested the watch
+		///
+		/// Emits `WatchRemoved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::revoke_watch())]
+		pub fn revoke_watch(origin: OriginFor<T>, watcher: T::AccountId) -> DispatchResult {
+			let watched = ensure_signed(origin)?;
+			Self::remove_watch(watched, watcher)
+		}
+
+		/// Withdraw own watch, requested or active. Deposit is returned.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the watcher.
+		///
+		/// - `watched`: Watched account
+		///
+		/// Emits `WatchRemoved`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::withdraw_watch())]
+		pub fn withdraw_watch(origin: OriginFor<T>, watched: T::AccountId) -> DispatchResult {
+			let watcher = ensure_signed(origin)?;
+			Self::remove_watch(watched, watcher)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Watchers with active watch of `watched`
+		pub fn active_watchers(watched: T::AccountId) -> Vec<(T::AccountId, LabelOf<T>)> {
+			Watches::<T>::iter_prefix(watched)
+				.filter(|(_, watch)| watch.status == WatchStatus::Active)
+				.map(|(watcher, watch)| (watcher, watch.label))
+				.collect()
+		}
+
+		/// Accounts actively watched by `watcher`
+		pub fn watched_accounts(watcher: T::AccountId) -> Vec<T::AccountId> {
+			WatchedBy::<T>::iter_key_prefix(&watcher)
+				.filter(|watched| {
+					Self::watches(watched, &watcher)
+						.map_or(false, |watch| watch.status == WatchStatus::Active)
+				})
+				.collect()
+		}
+
+		fn remove_watch(watched: T::AccountId, watcher: T::AccountId) -> DispatchResult {
+			let watch = Watches::<T>::take(&watched, &watcher).ok_or(Error::<T>::WatchNotFound)?;
+			WatchedBy::<T>::remove(&watcher, &watched);
+			WatchersCount::<T>::mutate_exists(&watched, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+			T::Currency::unreserve_named(T::ReserveIdentifier::get(), &watcher, watch.deposit);
+
+			Self::deposit_event(Event::WatchRemoved { watched, watcher });
+			Ok(())
+		}
+	}
+}
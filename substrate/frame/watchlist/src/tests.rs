@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, LabelOf, WatchStatus, WatchersCount};
+use frame_support::{assert_noop, assert_ok, traits::NamedReservableCurrency};
+
+fn label(l: &[u8]) -> LabelOf<Test> {
+	l.to_vec().try_into().unwrap()
+}
+
+fn reserved(who: u64) -> u64 {
+	Balances::reserved_balance_named(ReserveIdentifier::get(), &who)
+}
+
+fn request(watcher: u64, watched: u64) {
+	assert_ok!(Watchlist::request_watch(RuntimeOrigin::signed(watcher), watched, label(b"bank")));
+}
+
+fn activate(watcher: u64, watched: u64) {
+	request(watcher, watched);
+	assert_ok!(Watchlist::accept_watch(RuntimeOrigin::signed(watched), watcher));
+}
+
+#[test]
+fn request_watch_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		request(2, 1);
+		let watch = Watchlist::watches(1, 2).unwrap();
+		assert_eq!(watch.status, WatchStatus::Requested);
+		assert_eq!(watch.deposit, 10);
+		assert_eq!(reserved(2), 10);
+		assert_eq!(Watchlist::watchers_count(1), 1);
+		System::assert_last_event(
+			Event::WatchRequested { watched: 1, watcher: 2, label: label(b"bank") }.into(),
+		);
+	});
+}
+
+#[test]
+fn request_watch_validates() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Watchlist::request_watch(RuntimeOrigin::signed(1), 1, label(b"me")),
+			Error::<Test>::CannotWatchSelf
+		);
+		request(2, 1);
+		assert_noop!(
+			Watchlist::request_watch(RuntimeOrigin::signed(2), 1, label(b"again")),
+			Error::<Test>::AlreadyWatching
+		);
+		assert_noop!(
+			Watchlist::request_watch(RuntimeOrigin::signed(5), 1, label(b"poor")),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		request(3, 1);
+		assert_noop!(
+			Watchlist::request_watch(RuntimeOrigin::signed(4), 1, label(b"third")),
+			Error::<Test>::TooManyWatchers
+		);
+	});
+}
+
+#[test]
+fn only_watched_account_can_accept() {
+	new_test_ext().execute_with(|| {
+		request(2, 1);
+		assert_noop!(
+			Watchlist::accept_watch(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::WatchNotFound
+		);
+		assert_noop!(
+			Watchlist::accept_watch(RuntimeOrigin::signed(3), 2),
+			Error::<Test>::WatchNotFound
+		);
+		assert_ok!(Watchlist::accept_watch(RuntimeOrigin::signed(1), 2));
+		assert_eq!(Watchlist::watches(1, 2).unwrap().status, WatchStatus::Active);
+		System::assert_last_event(Event::WatchActivated { watched: 1, watcher: 2 }.into());
+		assert_noop!(
+			Watchlist::accept_watch(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::AlreadyActive
+		);
+	});
+}
+
+#[test]
+fn revoke_watch_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		activate(2, 1);
+		assert_noop!(
+			Watchlist::revoke_watch(RuntimeOrigin::signed(3), 2),
+			Error::<Test>::WatchNotFound
+		);
+		assert_ok!(Watchlist::revoke_watch(RuntimeOrigin::signed(1), 2));
+		assert_eq!(Watchlist::watches(1, 2), None);
+		assert_eq!(reserved(2), 0);
+		assert!(!WatchersCount::<Test>::contains_key(1));
+		System::assert_last_event(Event::WatchRemoved { watched: 1, watcher: 2 }.into());
+	});
+}
+
+#[test]
+fn revoke_rejects_requested_watch() {
+	new_test_ext().execute_with(|| {
+		request(2, 1);
+		assert_ok!(Watchlist::revoke_watch(RuntimeOrigin::signed(1), 2));
+		assert_eq!(Watchlist::watches(1, 2), None);
+		assert_eq!(reserved(2), 0);
+	});
+}
+
+#[test]
+fn withdraw_watch_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		activate(2, 1);
+		request(2, 3);
+		assert_eq!(reserved(2), 20);
+		assert_ok!(Watchlist::withdraw_watch(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Watchlist::watches(1, 2), None);
+		assert_eq!(reserved(2), 10);
+		assert_noop!(
+			Watchlist::withdraw_watch(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::WatchNotFound
+		);
+	});
+}
+
+#[test]
+fn queries_return_only_active_watches() {
+	new_test_ext().execute_with(|| {
+		activate(2, 1);
+		request(3, 1);
+		activate(2, 4);
+		request(2, 3);
+
+		assert_eq!(Watchlist::active_watchers(1), vec![(2, label(b"bank"))]);
+		let mut watched = Watchlist::watched_accounts(2);
+		watched.sort();
+		assert_eq!(watched, vec![1, 4]);
+		assert_eq!(Watchlist::watched_accounts(3), Vec::<u64>::new());
+	});
+}
@@ -0,0 +1,24 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum WatchStatus {
+	/// Requested by watcher, waiting for watched account's acceptance
+	Requested,
+	/// Accepted by watched account
+	Active,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[scale_info(skip_type_params(MaxLabelLen))]
+pub struct Watch<Balance, BlockNumber, MaxLabelLen: Get<u32>> {
+	/// Human readable name of the watcher, e.g. institution name
+	pub label: BoundedVec<u8, MaxLabelLen>,
+	pub status: WatchStatus,
+	/// Deposit reserved from watcher
+	pub deposit: Balance,
+	/// Block at which watch was requested
+	pub since: BlockNumber,
+}
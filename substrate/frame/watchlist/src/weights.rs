@@ -0,0 +1,71 @@
+//! Weights for pallet_watchlist
+//!
+//! NOT BENCHMARKED. Estimated from reserve/unreserve extrinsics of
+//! pallet_contracts_registry. Replace with output of
+//! `substrate/scripts/run_liberland_benchmarks.sh -p pallet_watchlist` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_watchlist.
+pub trait WeightInfo {
+	fn request_watch() -> Weight;
+	fn accept_watch() -> Weight;
+	fn revoke_watch() -> Weight;
+	fn withdraw_watch() -> Weight;
+}
+
+/// Weights for pallet_watchlist using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn request_watch() -> Weight {
+		Weight::from_parts(34_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn accept_watch() -> Weight {
+		Weight::from_parts(16_000_000, 3627)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn revoke_watch() -> Weight {
+		Weight::from_parts(33_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn withdraw_watch() -> Weight {
+		Weight::from_parts(33_000_000, 3724)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn request_watch() -> Weight {
+		Weight::from_parts(34_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn accept_watch() -> Weight {
+		Weight::from_parts(16_000_000, 3627)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn revoke_watch() -> Weight {
+		Weight::from_parts(33_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn withdraw_watch() -> Weight {
+		Weight::from_parts(33_000_000, 3724)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -0,0 +1,109 @@
+#!/usr/bin/env bash
+
+# Regenerates weights of pallets kept in this repository and of the Liberland
+# chain extension with the standard `benchmark pallet` subcommand. Upstream
+# pallets used from polkadot-sdk are skipped, as their weight files aren't
+# part of this repository.
+#
+# Run from the repository root, on reference hardware:
+#
+#   ./substrate/scripts/run_liberland_benchmarks.sh [-b] [-t] [-p <pallet>]
+#
+# -b  skip the build
+# -t  build the testnet runtime, which adds testnet-only pallets (faucet)
+# -p  benchmark a single pallet, e.g. `-p pallet_llm`
+
+while getopts 'btp:' flag; do
+  case "${flag}" in
+    b)
+      skip_build='true'
+      ;;
+    t)
+      testnet='true'
+      ;;
+    p)
+      only_pallet="${OPTARG}"
+      ;;
+    *)
+      echo "Bad options. Check Script."
+      exit 1
+      ;;
+  esac
+done
+
+FEATURES="runtime-benchmarks"
+if [ "$testnet" = true ]
+then
+  FEATURES="$FEATURES,kitchensink-runtime/testnet-runtime"
+fi
+
+if [ "$skip_build" != true ]
+then
+  echo "[+] Compiling node with $FEATURES..."
+  cargo build --release --locked -p node-cli --features="$FEATURES" || exit 1
+fi
+
+NODE=./target/release/substrate-node
+
+# Weight file of the pallet in this repository.
+weight_file() {
+  case "$1" in
+    liberland_extension_runtime)
+      echo "liberland-extension/runtime/src/weights.rs"
+      ;;
+    *)
+      echo "substrate/frame/$(echo "${1#pallet_}" | tr '_' '-')/src/weights.rs"
+      ;;
+  esac
+}
+
+if [ -n "$only_pallet" ]
+then
+  PALLETS=("$only_pallet")
+else
+  PALLETS=($(
+    $NODE benchmark pallet --list --chain=dev |\
+      tail -n+2 |\
+      cut -d',' -f1 |\
+      sort |\
+      uniq
+  ))
+fi
+
+ERR_FILE="benchmarking_errors.txt"
+rm -f $ERR_FILE
+
+for PALLET in "${PALLETS[@]}"; do
+  WEIGHT_FILE="$(weight_file "$PALLET")"
+  if [ ! -f "$WEIGHT_FILE" ]
+  then
+    echo "[+] Skipping $PALLET, no weight file in this repository"
+    continue
+  fi
+  echo "[+] Benchmarking $PALLET with weight file $WEIGHT_FILE"
+
+  OUTPUT=$(
+    $NODE benchmark pallet \
+    --chain=dev \
+    --steps=20 \
+    --repeat=10 \
+    --pallet="$PALLET" \
+    --extrinsic="*" \
+    --wasm-execution=compiled \
+    --heap-pages=4096 \
+    --output="$WEIGHT_FILE" \
+    --template=./.maintain/frame-weight-template.hbs 2>&1
+  )
+  if [ $? -ne 0 ]; then
+    echo "$OUTPUT" >> "$ERR_FILE"
+    echo "[-] Failed to benchmark $PALLET. Error written to $ERR_FILE; continuing..."
+  fi
+done
+
+if [ -f "$ERR_FILE" ]; then
+  echo "[-] Some benchmarks failed. See: $ERR_FILE"
+  exit 1
+else
+  echo "[+] All benchmarks passed."
+  exit 0
+fi